@@ -185,7 +185,7 @@ fn parse_dt(s: &str) -> PyResult<DateTime<Utc>> {
                 .map(|ei| {
                     let start = parse_dt(&ei.start)?;
                     let end = parse_dt(&ei.end)?;
-                    Ok(ExpandedEvent { start, end })
+                    Ok(ExpandedEvent::new(start, end))
                 })
                 .collect();
             Ok(EventStream {
@@ -261,7 +261,7 @@ fn parse_dt(s: &str) -> PyResult<DateTime<Utc>> {
                 .map(|ei| {
                     let start = parse_dt(&ei.start)?;
                     let end = parse_dt(&ei.end)?;
-                    Ok(ExpandedEvent { start, end })
+                    Ok(ExpandedEvent::new(start, end))
                 })
                 .collect();
             Ok(EventStream {
@@ -406,7 +406,10 @@ fn resolve_relative_with_options(
         _ => truth_engine::temporal::WeekStartDay::Monday,
     };
 
-    let options = truth_engine::temporal::ResolveOptions { week_start };
+    let options = truth_engine::temporal::ResolveOptions {
+        week_start,
+        ..Default::default()
+    };
 
     let result = truth_engine::temporal::resolve_relative_with_options(
         anchor_dt, expression, timezone, &options,
@@ -89,7 +89,7 @@ fn parse_events_json(json: &str) -> Result<Vec<ExpandedEvent>, JsValue> {
         .map(|input| {
             let start = parse_datetime(&input.start)?;
             let end = parse_datetime(&input.end)?;
-            Ok(ExpandedEvent { start, end })
+            Ok(ExpandedEvent::new(start, end))
         })
         .collect()
 }
@@ -257,7 +257,7 @@ pub fn merge_availability(
                 .map(|ei| {
                     let start = parse_datetime(&ei.start)?;
                     let end = parse_datetime(&ei.end)?;
-                    Ok(ExpandedEvent { start, end })
+                    Ok(ExpandedEvent::new(start, end))
                 })
                 .collect();
             Ok(truth_engine::EventStream {
@@ -327,7 +327,7 @@ pub fn find_first_free_across(
                 .map(|ei| {
                     let start = parse_datetime(&ei.start)?;
                     let end = parse_datetime(&ei.end)?;
-                    Ok(ExpandedEvent { start, end })
+                    Ok(ExpandedEvent::new(start, end))
                 })
                 .collect();
             Ok(truth_engine::EventStream {
@@ -441,6 +441,7 @@ pub fn resolve_relative_with_options(
             Some("sunday") => truth_engine::temporal::WeekStartDay::Sunday,
             _ => truth_engine::temporal::WeekStartDay::Monday,
         },
+        ..Default::default()
     };
 
     let result = truth_engine::temporal::resolve_relative_with_options(
@@ -0,0 +1,67 @@
+//! Organization-wide availability: merging hundreds of attendees' calendars
+//! over a quarter. Compare a default build with `--features rayon`.
+//!
+//! ```bash
+//! cargo bench -p truth-engine --bench availability
+//! cargo bench -p truth-engine --bench availability --features rayon
+//! ```
+
+use chrono::{Duration, TimeZone, Utc};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use truth_engine::{find_free_slots, merge_availability, EventStream, ExpandedEvent, PrivacyLevel};
+
+/// `people` calendars of `days` days, each with a few meetings a day at
+/// staggered times.
+fn org(people: usize, days: i64) -> Vec<EventStream> {
+    let start = Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap();
+    (0..people)
+        .map(|p| {
+            let p = p as i64;
+            let events = (0..days)
+                .flat_map(|d| {
+                    (0..5).map(move |m| {
+                        let begin = start
+                            + Duration::days(d)
+                            + Duration::minutes(8 * 60 + (p * 7 + m * 97) % (9 * 60));
+                        ExpandedEvent::new(begin, begin + Duration::minutes(30 + (p + m) % 4 * 15))
+                    })
+                })
+                .collect();
+            EventStream {
+                stream_id: format!("person-{p}"),
+                events,
+            }
+        })
+        .collect()
+}
+
+fn bench_availability(c: &mut Criterion) {
+    let streams = org(500, 90);
+    let start = Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap();
+    let end = start + Duration::days(90);
+    let all: Vec<ExpandedEvent> = streams.iter().flat_map(|s| s.events.clone()).collect();
+
+    let mut group = c.benchmark_group("org_500_quarter");
+    group.sample_size(20);
+    group.bench_function("merge_availability_full", |b| {
+        b.iter(|| black_box(merge_availability(&streams, start, end, PrivacyLevel::Full)))
+    });
+    group.bench_function("merge_availability_opaque", |b| {
+        b.iter(|| {
+            black_box(merge_availability(
+                &streams,
+                start,
+                end,
+                PrivacyLevel::Opaque,
+            ))
+        })
+    });
+    group.bench_function("find_free_slots", |b| {
+        b.iter(|| black_box(find_free_slots(&all, start, end)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_availability);
+criterion_main!(benches);
@@ -0,0 +1,62 @@
+//! RRULE expansion on the bitmask fast path against the general `rrule`
+//! crate path, for typical business rules.
+//!
+//! ```bash
+//! cargo bench -p truth-engine --bench expansion
+//! ```
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+use truth_engine::expander::{CompiledRule, OccurrenceBuffer};
+
+/// name, rrule, dtstart
+const RULES: [(&str, &str, &str); 5] = [
+    (
+        "weekdays",
+        "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR",
+        "2026-01-05T09:00:00",
+    ),
+    (
+        "weekly_mo_we_fr",
+        "FREQ=WEEKLY;BYDAY=MO,WE,FR",
+        "2026-01-05T10:00:00",
+    ),
+    (
+        "biweekly_tu",
+        "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU",
+        "2026-01-06T14:00:00",
+    ),
+    (
+        "monthly_last_friday",
+        "FREQ=MONTHLY;BYDAY=-1FR",
+        "2026-01-30T16:00:00",
+    ),
+    (
+        "monthly_15th_and_last",
+        "FREQ=MONTHLY;BYMONTHDAY=15,-1",
+        "2026-01-15T12:00:00",
+    ),
+];
+
+fn bench_expansion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("expand_into");
+    let mut buffer = OccurrenceBuffer::with_capacity(500);
+    for (name, rrule, dtstart) in RULES {
+        // Unbounded, so each expansion generates the 500-instance limit.
+        let fast = CompiledRule::new(rrule, dtstart, "America/New_York", None, None, &[]).unwrap();
+        assert!(fast.is_accelerated(), "{name} is not accelerated");
+        let general = fast.clone().without_acceleration();
+        for (path, rule) in [("bitmask", &fast), ("rrule", &general)] {
+            group.bench_with_input(BenchmarkId::new(path, name), rule, |b, rule| {
+                b.iter(|| {
+                    buffer.clear();
+                    black_box(rule.expand_into(&mut buffer, 30))
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_expansion);
+criterion_main!(benches);
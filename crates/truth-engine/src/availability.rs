@@ -6,15 +6,30 @@
 //!
 //! This module is the core of the "Unified Availability Graph" — it computes the
 //! single source of truth for a user's availability across all their calendars.
+//! [`ChunkedAvailability`] computes the same over multi-year windows one time
+//! bucket at a time, with bounded memory. [`core_hours`] looks back over a
+//! group's history for the weekly hours when they are usually all free.
 
-use chrono::{DateTime, Utc};
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Utc, Weekday};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::business::BusinessCalendar;
+use crate::error::{Result, TruthError};
 use crate::expander::ExpandedEvent;
-use crate::freebusy::{self, FreeSlot};
+use crate::freebusy::{self, FreeSlot, LabeledSlot, SlotLabelOptions};
+use crate::interval_tree::IntervalTree;
+use crate::temporal::interval::{self, TimeInterval};
+use crate::temporal::parse_timezone;
 
 /// A named event stream from a single calendar source.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EventStream {
     /// Opaque identifier for this stream (e.g., "work-google", "personal-icloud").
     pub stream_id: String,
@@ -23,7 +38,9 @@ pub struct EventStream {
 }
 
 /// Privacy level for availability output.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum PrivacyLevel {
     /// Show time ranges and source count per busy block.
     Full,
@@ -34,7 +51,9 @@ pub enum PrivacyLevel {
 }
 
 /// A merged busy block in the unified availability view.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BusyBlock {
     /// Start of the busy period.
     pub start: DateTime<Utc>,
@@ -46,7 +65,9 @@ pub struct BusyBlock {
 }
 
 /// Unified availability result after merging N event streams.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UnifiedAvailability {
     /// Merged busy blocks (sorted by start, non-overlapping).
     pub busy: Vec<BusyBlock>,
@@ -60,6 +81,69 @@ pub struct UnifiedAvailability {
     pub privacy: PrivacyLevel,
 }
 
+impl UnifiedAvailability {
+    /// Check a proposed slot `[start, end)` against the busy blocks: free
+    /// when none overlaps it, otherwise blocked by those that do.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidArguments`] if `end` is before `start`,
+    /// or if the slot is not within the window, where availability is
+    /// unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use truth_engine::{merge_availability, EventStream, ExpandedEvent, PrivacyLevel};
+    ///
+    /// let at = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+    /// let streams = [EventStream {
+    ///     stream_id: "work".to_string(),
+    ///     events: vec![ExpandedEvent::new(at(9), at(10))],
+    /// }];
+    /// let availability = merge_availability(&streams, at(8), at(18), PrivacyLevel::Opaque);
+    /// assert!(availability.is_slot_free(at(10), at(11)).unwrap().free);
+    /// assert_eq!(availability.is_slot_free(at(8), at(12)).unwrap().blocking.len(), 1);
+    /// assert!(availability.is_slot_free(at(17), at(19)).is_err());
+    /// ```
+    pub fn is_slot_free(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<freebusy::SlotCheck<BusyBlock>> {
+        let slot = freebusy::proposed_slot(start, end)?;
+        if start < self.window_start || end > self.window_end {
+            return Err(TruthError::InvalidArguments(format!(
+                "slot {start} to {end} is outside the availability window {} to {}",
+                self.window_start, self.window_end
+            )));
+        }
+        // Blocks are sorted and disjoint: those overlapping the slot follow
+        // every block ending by its start and precede any starting at its end.
+        let first = self.busy.partition_point(|b| b.end <= slot.start);
+        let blocking: Vec<BusyBlock> = self.busy[first..]
+            .iter()
+            .take_while(|b| b.start < slot.end)
+            .cloned()
+            .collect();
+        Ok(freebusy::SlotCheck {
+            free: blocking.is_empty(),
+            blocking,
+        })
+    }
+
+    /// The free slots with their times in each display timezone, as from
+    /// [`label_slots`](freebusy::label_slots).
+    ///
+    /// # Errors
+    ///
+    /// As for [`label_slots`](freebusy::label_slots).
+    pub fn labeled_free(&self, options: &SlotLabelOptions) -> Result<Vec<LabeledSlot>> {
+        freebusy::label_slots(&self.free, options)
+    }
+}
+
 /// Merge N event streams into unified availability within a time window.
 ///
 /// All events from all streams are flattened, clipped to the window, and merged
@@ -68,6 +152,9 @@ pub struct UnifiedAvailability {
 /// When `privacy` is `Opaque`, `source_count` is set to 0 on all busy blocks —
 /// no information about how many calendars contributed leaks through.
 ///
+/// With the `rayon` feature, streams are clipped, busy periods sorted, and
+/// source counts computed in parallel; the result is the same.
+///
 /// # Arguments
 ///
 /// * `streams` — The event streams to merge (from different calendars/providers).
@@ -99,14 +186,9 @@ pub fn merge_availability(
         };
     }
 
-    // Flatten all events from all streams into a single list.
-    let all_events: Vec<ExpandedEvent> = streams
-        .iter()
-        .flat_map(|s| s.events.iter().cloned())
-        .collect();
-
-    // Compute merged busy periods using the existing freebusy algorithm.
-    let merged_intervals = freebusy::merge_busy_periods(&all_events, window_start, window_end);
+    // Clip every event from every stream to the window and merge them into
+    // busy periods.
+    let merged_intervals = merge_stream_periods(streams, window_start, window_end);
 
     // Build busy blocks with source count tracking.
     let busy: Vec<BusyBlock> = if privacy == PrivacyLevel::Full {
@@ -116,16 +198,16 @@ pub fn merge_availability(
         // For Opaque privacy, source_count is always 0.
         merged_intervals
             .iter()
-            .map(|(start, end)| BusyBlock {
-                start: *start,
-                end: *end,
+            .map(|interval| BusyBlock {
+                start: interval.start,
+                end: interval.end,
                 source_count: 0,
             })
             .collect()
     };
 
-    // Compute free slots from the merged intervals.
-    let free = freebusy::find_free_slots(&all_events, window_start, window_end);
+    // The free slots are the gaps between the busy periods.
+    let free = freebusy::free_slots_between(&merged_intervals, window_start, window_end);
 
     UnifiedAvailability {
         busy,
@@ -136,6 +218,31 @@ pub fn merge_availability(
     }
 }
 
+/// Like [`merge_availability`], but free slots are limited to working time on
+/// `calendar`. Busy blocks are unchanged, so meetings outside working hours
+/// still show as busy.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`](crate::TruthError::InvalidTimezone)
+/// if the calendar's timezone is unknown.
+pub fn merge_business_availability(
+    streams: &[EventStream],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    privacy: PrivacyLevel,
+    calendar: &BusinessCalendar,
+) -> Result<UnifiedAvailability> {
+    let mut availability = merge_availability(streams, window_start, window_end, privacy);
+    let all_events: Vec<ExpandedEvent> = streams
+        .iter()
+        .flat_map(|s| s.events.iter().cloned())
+        .collect();
+    availability.free =
+        freebusy::find_business_free_slots(&all_events, window_start, window_end, calendar)?;
+    Ok(availability)
+}
+
 /// Find the first free slot of at least `min_duration_minutes` across N merged
 /// event streams.
 ///
@@ -155,37 +262,541 @@ pub fn find_first_free_across(
     freebusy::find_first_free_slot(&all_events, window_start, window_end, min_duration_minutes)
 }
 
+/// One member of a group for [`core_hours`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Participant {
+    /// The participant's events over the history window; the `stream_id`
+    /// names the participant in [`CoreHoursBlock::participants`].
+    pub stream: EventStream,
+    /// The participant's working hours, timezone, and days off.
+    pub calendar: BusinessCalendar,
+}
+
+/// Options for [`core_hours`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CoreHoursOptions {
+    /// How many participants must be available for a slot to be core hours;
+    /// `None` for all of them.
+    pub quorum: Option<usize>,
+    /// The share, from 0 to 1, of a weekly slot's occurrences in the window
+    /// that a participant must have spent within working hours and free to
+    /// count as available in it.
+    pub min_free_ratio: f64,
+    /// Length of the weekly slots, in minutes. Must divide a day evenly.
+    pub slot_minutes: u32,
+    /// IANA timezone the weekly intervals are read in.
+    pub timezone: String,
+}
+
+impl Default for CoreHoursOptions {
+    /// Every participant, free in three of four weeks, in 30-minute slots, in UTC.
+    fn default() -> Self {
+        Self {
+            quorum: None,
+            min_free_ratio: 0.75,
+            slot_minutes: 30,
+            timezone: "UTC".to_string(),
+        }
+    }
+}
+
+/// A recurring weekly interval of a group's core hours.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CoreHoursBlock {
+    pub weekday: Weekday,
+    /// Local start time in [`CoreHoursOptions::timezone`].
+    pub start: NaiveTime,
+    /// Length of the block; it may run past midnight, but not past Sunday.
+    pub duration_minutes: i64,
+    /// Stream ids of the participants available throughout the block, in
+    /// input order.
+    pub participants: Vec<String>,
+}
+
+/// The group's core hours: the recurring weekly intervals, in
+/// `options.timezone`, when at least a quorum of `participants` are
+/// typically within their working hours and free.
+///
+/// The week is cut into slots of `options.slot_minutes`. Each occurrence of
+/// a slot that falls entirely within `[window_start, window_end)` is checked
+/// against each participant's working intervals (from their calendar, in
+/// their own timezone) and events. A participant is available in a slot when
+/// at least `options.min_free_ratio` of its occurrences were working time
+/// without events; slots that never occur in the window are never core
+/// hours. Consecutive slots with the same available participants are joined
+/// into one block. Blocks are ordered from Monday.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidArguments`] if the window is empty, there
+/// are no participants, the quorum is 0 or more than the participants, the
+/// ratio is outside 0 to 1, or the slot length does not divide a day, and
+/// [`TruthError::InvalidTimezone`] for an unknown timezone.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveTime, TimeZone, Utc, Weekday};
+/// use truth_engine::availability::{core_hours, CoreHoursOptions, EventStream, Participant};
+/// use truth_engine::business::BusinessCalendar;
+///
+/// let participant = |id: &str, timezone: &str| Participant {
+///     stream: EventStream { stream_id: id.to_string(), events: vec![] },
+///     calendar: BusinessCalendar { timezone: timezone.to_string(), ..BusinessCalendar::default() },
+/// };
+/// let group = [participant("ana", "Europe/London"), participant("ben", "America/New_York")];
+/// // Two weeks of February, when London is on UTC and New York five hours behind.
+/// let start = Utc.with_ymd_and_hms(2026, 2, 2, 0, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2026, 2, 16, 0, 0, 0).unwrap();
+///
+/// let blocks = core_hours(&group, start, end, &CoreHoursOptions::default()).unwrap();
+/// assert_eq!(blocks.len(), 5);
+/// assert_eq!(blocks[0].weekday, Weekday::Mon);
+/// assert_eq!(blocks[0].start, NaiveTime::from_hms_opt(14, 0, 0).unwrap());
+/// assert_eq!(blocks[0].duration_minutes, 180);
+/// ```
+pub fn core_hours(
+    participants: &[Participant],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    options: &CoreHoursOptions,
+) -> Result<Vec<CoreHoursBlock>> {
+    let quorum = options.quorum.unwrap_or(participants.len());
+    let invalid = |reason: String| Err(TruthError::InvalidArguments(reason));
+    if window_start >= window_end {
+        return invalid(format!(
+            "window end ({window_end}) must be after its start ({window_start})"
+        ));
+    }
+    if participants.is_empty() {
+        return invalid("core hours need at least one participant".to_string());
+    }
+    if quorum == 0 || quorum > participants.len() {
+        return invalid(format!(
+            "quorum {quorum} must be between 1 and the {} participants",
+            participants.len()
+        ));
+    }
+    if !(0.0..=1.0).contains(&options.min_free_ratio) {
+        return invalid(format!(
+            "min_free_ratio {} must be between 0 and 1",
+            options.min_free_ratio
+        ));
+    }
+    let slot_minutes = options.slot_minutes;
+    if slot_minutes == 0 || 1440 % slot_minutes != 0 {
+        return invalid(format!(
+            "slot_minutes {slot_minutes} must divide a day evenly"
+        ));
+    }
+    let tz = parse_timezone(&options.timezone)?;
+    let window = TimeInterval {
+        start: window_start,
+        end: window_end,
+    };
+
+    // Each participant's working time in the window, less their events.
+    let open: Vec<Vec<TimeInterval>> = participants
+        .iter()
+        .map(|p| {
+            let busy: Vec<TimeInterval> = p
+                .stream
+                .events
+                .iter()
+                .filter_map(|e| TimeInterval::from(e).clamp_to(&window))
+                .collect();
+            let working = p.calendar.working_intervals(window_start, window_end)?;
+            Ok(interval::merge(
+                working.iter().flat_map(|w| w.subtract_all(&busy)),
+            ))
+        })
+        .collect::<Result<_>>()?;
+
+    let per_day = (1440 / slot_minutes) as usize;
+    let slot_length = Duration::minutes(i64::from(slot_minutes));
+    let mut occurrences = vec![0u32; 7 * per_day];
+    let mut free = vec![vec![0u32; 7 * per_day]; participants.len()];
+    let mut date = window_start.with_timezone(&tz).date_naive();
+    let last = window_end.with_timezone(&tz).date_naive();
+    while date <= last {
+        let midnight = date.and_time(NaiveTime::MIN);
+        for k in 0..per_day {
+            // Slots in a DST gap do not occur that day; in a repeated hour,
+            // only the first pass counts.
+            let Some(start) = tz
+                .from_local_datetime(&(midnight + slot_length * k as i32))
+                .earliest()
+            else {
+                continue;
+            };
+            let start = start.with_timezone(&Utc);
+            let slot = TimeInterval {
+                start,
+                end: start + slot_length,
+            };
+            if !window.contains_interval(&slot) {
+                continue;
+            }
+            let bin = date.weekday().num_days_from_monday() as usize * per_day + k;
+            occurrences[bin] += 1;
+            for (counts, open) in free.iter_mut().zip(&open) {
+                let i = open.partition_point(|o| o.end <= slot.start);
+                if open.get(i).is_some_and(|o| o.contains_interval(&slot)) {
+                    counts[bin] += 1;
+                }
+            }
+        }
+        date = match date.succ_opt() {
+            Some(d) => d,
+            None => break,
+        };
+    }
+
+    let mut blocks: Vec<CoreHoursBlock> = Vec::new();
+    let mut previous_bin = None;
+    for bin in 0..7 * per_day {
+        let seen = occurrences[bin];
+        let available: Vec<String> = participants
+            .iter()
+            .zip(&free)
+            .filter(|(_, counts)| {
+                seen > 0 && f64::from(counts[bin]) >= options.min_free_ratio * f64::from(seen)
+            })
+            .map(|(p, _)| p.stream.stream_id.clone())
+            .collect();
+        if available.len() < quorum {
+            continue;
+        }
+        match blocks.last_mut() {
+            Some(block)
+                if previous_bin.map(|b| b + 1) == Some(bin) && block.participants == available =>
+            {
+                block.duration_minutes += i64::from(slot_minutes);
+            }
+            _ => blocks.push(CoreHoursBlock {
+                weekday: Weekday::try_from((bin / per_day) as u8).expect("day of week"),
+                start: NaiveTime::MIN + slot_length * (bin % per_day) as i32,
+                duration_minutes: i64::from(slot_minutes),
+                participants: available,
+            }),
+        }
+        previous_bin = Some(bin);
+    }
+    Ok(blocks)
+}
+
+/// Every stream's events clipped to the window and merged into sorted,
+/// disjoint busy periods. With the `rayon` feature, streams are clipped and
+/// the periods sorted in parallel.
+fn merge_stream_periods(
+    streams: &[EventStream],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Vec<TimeInterval> {
+    let window = TimeInterval {
+        start: window_start,
+        end: window_end,
+    };
+    let clip = |stream: &EventStream| -> Vec<TimeInterval> {
+        stream
+            .events
+            .iter()
+            .filter_map(|e| TimeInterval::from(e).clamp_to(&window))
+            .collect()
+    };
+    #[cfg(feature = "rayon")]
+    let clipped: Vec<TimeInterval> = streams.par_iter().flat_map_iter(clip).collect();
+    #[cfg(not(feature = "rayon"))]
+    let clipped: Vec<TimeInterval> = streams.iter().flat_map(clip).collect();
+    interval::merge(clipped)
+}
+
 /// Compute busy blocks with per-block source counts.
 ///
 /// For each merged interval, count how many distinct streams contributed at least
 /// one event that overlaps with that interval.
 fn compute_busy_blocks_with_sources(
     streams: &[EventStream],
-    merged_intervals: &[(DateTime<Utc>, DateTime<Utc>)],
+    merged_intervals: &[TimeInterval],
     window_start: DateTime<Utc>,
     window_end: DateTime<Utc>,
 ) -> Vec<BusyBlock> {
-    merged_intervals
-        .iter()
-        .map(|(interval_start, interval_end)| {
-            // Count how many streams have at least one event overlapping this interval.
-            let source_count = streams
-                .iter()
-                .filter(|stream| {
-                    stream.events.iter().any(|event| {
-                        // Clip event to window first.
-                        let ev_start = event.start.max(window_start);
-                        let ev_end = event.end.min(window_end);
-                        // Check overlap with the merged interval.
-                        ev_start < *interval_end && ev_end > *interval_start
-                    })
-                })
-                .count();
-            BusyBlock {
-                start: *interval_start,
-                end: *interval_end,
-                source_count,
-            }
+    busy_sources(streams, merged_intervals, window_start, window_end)
+        .into_iter()
+        .zip(merged_intervals)
+        .map(|(sources, interval)| BusyBlock {
+            start: interval.start,
+            end: interval.end,
+            source_count: sources.len(),
         })
         .collect()
 }
+
+/// For each merged interval, the indices (sorted) of the streams with at
+/// least one event, clipped to the window, overlapping it.
+fn busy_sources(
+    streams: &[EventStream],
+    merged_intervals: &[TimeInterval],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Vec<Vec<usize>> {
+    let window = TimeInterval {
+        start: window_start,
+        end: window_end,
+    };
+    // Each event clipped to the window, tagged with its stream.
+    let index: IntervalTree<usize> = streams
+        .iter()
+        .enumerate()
+        .flat_map(|(n, stream)| {
+            stream.events.iter().filter_map(move |event| {
+                TimeInterval::from(event).clamp_to(&window).map(|e| (e, n))
+            })
+        })
+        .collect();
+    let sources_of = |interval: &TimeInterval| -> Vec<usize> {
+        let mut sources: Vec<usize> = index
+            .overlapping(interval)
+            .into_iter()
+            .map(|(_, &n)| n)
+            .collect();
+        sources.sort_unstable();
+        sources.dedup();
+        sources
+    };
+    #[cfg(feature = "rayon")]
+    return merged_intervals.par_iter().map(sources_of).collect();
+    #[cfg(not(feature = "rayon"))]
+    merged_intervals.iter().map(sources_of).collect()
+}
+
+/// Busy blocks and free slots finished by one step of a
+/// [`ChunkedAvailability`]: none of them can change as later chunks arrive.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AvailabilityChunk {
+    /// Finished busy blocks, sorted by start.
+    pub busy: Vec<BusyBlock>,
+    /// Finished free slots, sorted by start.
+    pub free: Vec<FreeSlot>,
+}
+
+/// [`merge_availability`] over a window too large to hold every event at
+/// once, computed one time bucket (e.g., a week) at a time.
+///
+/// Ask [`next_chunk`](Self::next_chunk) for the next bucket, load the events
+/// overlapping it from every stream, and [`push`](Self::push) them; each push
+/// returns the busy blocks and free slots it finished, so results can be
+/// written out as they come. [`finish`](Self::finish) returns the rest. Only
+/// the busy block at the end of the latest chunk, which may continue into the
+/// next, is held between chunks.
+///
+/// Busy blocks spanning chunk boundaries are joined, so the blocks and slots
+/// are those [`merge_availability`] returns for the whole window. Streams are
+/// identified by `stream_id` across chunks; a stream may be missing from
+/// chunks where it has no events.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use truth_engine::availability::{ChunkedAvailability, EventStream, PrivacyLevel};
+/// use truth_engine::ExpandedEvent;
+///
+/// let start = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+/// let end = start + Duration::days(28);
+/// // A week-long offsite straddling the first chunk boundary.
+/// let offsite = ExpandedEvent::new(start + Duration::days(5), start + Duration::days(12));
+/// let load = |from, to| -> Vec<EventStream> {
+///     let events = [offsite.clone()]
+///         .into_iter()
+///         .filter(|e| e.start < to && from < e.end)
+///         .collect();
+///     vec![EventStream { stream_id: "work".into(), events }]
+/// };
+///
+/// let mut chunked =
+///     ChunkedAvailability::new(start, end, PrivacyLevel::Full, Duration::weeks(1)).unwrap();
+/// let mut busy = Vec::new();
+/// while let Some((from, to)) = chunked.next_chunk() {
+///     busy.extend(chunked.push(&load(from, to)).busy);
+/// }
+/// busy.extend(chunked.finish().busy);
+/// assert_eq!(busy.len(), 1);
+/// assert_eq!((busy[0].start, busy[0].end), (offsite.start, offsite.end));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChunkedAvailability {
+    window_end: DateTime<Utc>,
+    privacy: PrivacyLevel,
+    chunk: Duration,
+    /// Start of the next chunk.
+    cursor: DateTime<Utc>,
+    /// Where the free time not yet returned starts.
+    free_from: DateTime<Utc>,
+    /// The latest busy block and the streams in it, which may continue into
+    /// the next chunk.
+    open: Option<(TimeInterval, BTreeSet<String>)>,
+}
+
+impl ChunkedAvailability {
+    /// Merge availability over `window_start..window_end` in chunks of
+    /// `chunk`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidArguments`] if `chunk` is not positive.
+    pub fn new(
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+        privacy: PrivacyLevel,
+        chunk: Duration,
+    ) -> Result<Self> {
+        if chunk <= Duration::zero() {
+            return Err(TruthError::InvalidArguments(format!(
+                "chunk must be positive, got {} seconds",
+                chunk.num_seconds()
+            )));
+        }
+        Ok(Self {
+            window_end,
+            privacy,
+            chunk,
+            cursor: window_start,
+            free_from: window_start,
+            open: None,
+        })
+    }
+
+    /// The bounds of the chunk to load and [`push`](Self::push) next, or
+    /// `None` once the window is covered.
+    pub fn next_chunk(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        (self.cursor < self.window_end).then(|| {
+            let end = self
+                .cursor
+                .checked_add_signed(self.chunk)
+                .map_or(self.window_end, |end| end.min(self.window_end));
+            (self.cursor, end)
+        })
+    }
+
+    /// Merge `streams`, the events overlapping the chunk from
+    /// [`next_chunk`](Self::next_chunk) (events outside it are ignored), and
+    /// move on to the next chunk.
+    pub fn push(&mut self, streams: &[EventStream]) -> AvailabilityChunk {
+        let mut done = AvailabilityChunk::default();
+        let Some((start, end)) = self.next_chunk() else {
+            return done;
+        };
+        self.cursor = end;
+
+        let all_events: Vec<ExpandedEvent> = streams
+            .iter()
+            .flat_map(|s| s.events.iter().cloned())
+            .collect();
+        let merged = freebusy::merge_busy_periods(&all_events, start, end);
+        let sources = match self.privacy {
+            PrivacyLevel::Full => busy_sources(streams, &merged, start, end),
+            PrivacyLevel::Opaque => vec![Vec::new(); merged.len()],
+        };
+        for (interval, sources) in merged.into_iter().zip(sources) {
+            let ids = sources.into_iter().map(|n| streams[n].stream_id.clone());
+            match &mut self.open {
+                // Touching blocks merge, as within a chunk.
+                Some((open, open_ids)) if interval.start <= open.end => {
+                    open.end = open.end.max(interval.end);
+                    open_ids.extend(ids);
+                }
+                _ => {
+                    self.close(&mut done);
+                    self.free_until(interval.start, &mut done);
+                    self.open = Some((interval, ids.collect()));
+                }
+            }
+        }
+        if self
+            .open
+            .as_ref()
+            .is_some_and(|(open, _)| open.end < self.cursor)
+        {
+            self.close(&mut done);
+        }
+        done
+    }
+
+    /// The busy blocks and free slots not yet returned, including those in
+    /// any part of the window never pushed (which counts as free).
+    pub fn finish(mut self) -> AvailabilityChunk {
+        let mut done = AvailabilityChunk::default();
+        self.close(&mut done);
+        self.free_until(self.window_end, &mut done);
+        done
+    }
+
+    /// Finish the open busy block.
+    fn close(&mut self, done: &mut AvailabilityChunk) {
+        let Some((block, ids)) = self.open.take() else {
+            return;
+        };
+        done.busy.push(BusyBlock {
+            start: block.start,
+            end: block.end,
+            source_count: ids.len(),
+        });
+        self.free_from = block.end;
+    }
+
+    fn free_until(&mut self, until: DateTime<Utc>, done: &mut AvailabilityChunk) {
+        if self.free_from < until {
+            done.free.push(FreeSlot {
+                start: self.free_from,
+                end: until,
+                duration_minutes: (until - self.free_from).num_minutes(),
+            });
+            self.free_from = until;
+        }
+    }
+}
+
+/// [`merge_availability`] over `window_start..window_end`, loading events
+/// one chunk at a time with `load(chunk_start, chunk_end)`, which returns
+/// the events of every stream overlapping the chunk. See
+/// [`ChunkedAvailability`].
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidArguments`] if `chunk` is not positive, or
+/// the first error from `load`.
+pub fn merge_availability_chunked(
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    privacy: PrivacyLevel,
+    chunk: Duration,
+    mut load: impl FnMut(DateTime<Utc>, DateTime<Utc>) -> Result<Vec<EventStream>>,
+) -> Result<UnifiedAvailability> {
+    let mut chunked = ChunkedAvailability::new(window_start, window_end, privacy, chunk)?;
+    let (mut busy, mut free) = (Vec::new(), Vec::new());
+    while let Some((start, end)) = chunked.next_chunk() {
+        let done = chunked.push(&load(start, end)?);
+        busy.extend(done.busy);
+        free.extend(done.free);
+    }
+    let done = chunked.finish();
+    busy.extend(done.busy);
+    free.extend(done.free);
+    Ok(UnifiedAvailability {
+        busy,
+        free,
+        window_start,
+        window_end,
+        privacy,
+    })
+}
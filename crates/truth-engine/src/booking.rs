@@ -0,0 +1,296 @@
+//! Holds, confirmations, and expiry on top of availability.
+//!
+//! A scheduling agent that finds a free slot usually cannot book it at once:
+//! it offers the slot, waits for an answer, and must keep anyone else from
+//! taking it meanwhile. [`BookingLedger`] is that state machine. A booking
+//! starts as a hold with a deadline, is confirmed or released, and expires
+//! if the deadline passes first. Active bookings block time in
+//! [`BookingLedger::availability`], so later queries never offer a held
+//! slot twice.
+//!
+//! The ledger never reads the clock: every call that depends on time takes
+//! `now`, so the same calls always produce the same state.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::availability::{merge_availability, EventStream, PrivacyLevel, UnifiedAvailability};
+use crate::error::{Result, TruthError};
+use crate::expander::ExpandedEvent;
+
+/// The `stream_id` of the stream [`BookingLedger::stream`] builds.
+pub const BOOKINGS_STREAM: &str = "bookings";
+
+/// Where a booking is in its lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum BookingState {
+    /// Reserved until `expires_at`, awaiting confirmation.
+    Held {
+        expires_at: DateTime<Utc>,
+    },
+    Confirmed,
+    /// Released by the caller, from either a hold or a confirmation.
+    Released,
+    /// The hold's deadline passed before it was confirmed.
+    Expired,
+}
+
+/// One booking of a slot.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Booking {
+    pub id: String,
+    pub slot: ExpandedEvent,
+    pub state: BookingState,
+}
+
+impl Booking {
+    /// Whether the booking blocks its slot at `now`: confirmed, or held
+    /// with a deadline still ahead.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        match self.state {
+            BookingState::Held { expires_at } => expires_at > now,
+            BookingState::Confirmed => true,
+            BookingState::Released | BookingState::Expired => false,
+        }
+    }
+}
+
+/// Every booking, by id. Serializable, so an agent can persist it between calls.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use truth_engine::booking::{BookingLedger, BookingState};
+/// use truth_engine::ExpandedEvent;
+///
+/// let now = Utc.with_ymd_and_hms(2026, 3, 2, 8, 0, 0).unwrap();
+/// let slot = ExpandedEvent::new(
+///     Utc.with_ymd_and_hms(2026, 3, 2, 14, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2026, 3, 2, 14, 30, 0).unwrap(),
+/// );
+/// let mut ledger = BookingLedger::new();
+/// ledger.hold("intro-call", slot.clone(), now + Duration::minutes(15), now, &[]).unwrap();
+///
+/// // A second agent cannot take the held slot...
+/// assert!(ledger.hold("other", slot.clone(), now + Duration::minutes(15), now, &[]).is_err());
+///
+/// // ...until the hold lapses.
+/// let later = now + Duration::minutes(20);
+/// assert_eq!(ledger.expire(later), vec!["intro-call".to_string()]);
+/// assert_eq!(ledger.get("intro-call").unwrap().state, BookingState::Expired);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BookingLedger {
+    bookings: BTreeMap<String, Booking>,
+}
+
+impl BookingLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hold `slot` under a new `id` until `expires_at`.
+    ///
+    /// The slot must not overlap another active booking or any event in
+    /// `busy` (the caller's calendars); touching end to end is fine.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidArguments`] if the slot is empty or
+    /// reversed or `expires_at` is not after `now`, [`TruthError::Booking`]
+    /// if `id` is taken, and [`TruthError::SlotConflict`] if the slot is not
+    /// free.
+    pub fn hold(
+        &mut self,
+        id: &str,
+        slot: ExpandedEvent,
+        expires_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+        busy: &[EventStream],
+    ) -> Result<&Booking> {
+        if slot.end <= slot.start {
+            return Err(TruthError::InvalidArguments(format!(
+                "booking slot must end after it starts ({} to {})",
+                slot.start.to_rfc3339(),
+                slot.end.to_rfc3339()
+            )));
+        }
+        if expires_at <= now {
+            return Err(TruthError::InvalidArguments(format!(
+                "hold deadline {} is not after now",
+                expires_at.to_rfc3339()
+            )));
+        }
+        if self.bookings.contains_key(id) {
+            return Err(TruthError::Booking(format!(
+                "booking '{}' already exists",
+                id
+            )));
+        }
+        if let Some(other) = self
+            .active(now)
+            .into_iter()
+            .find(|b| overlaps(&b.slot, &slot))
+        {
+            return Err(TruthError::SlotConflict(format!(
+                "slot overlaps booking '{}'",
+                other.id
+            )));
+        }
+        if let Some(stream) = busy
+            .iter()
+            .find(|s| s.events.iter().any(|e| overlaps(e, &slot)))
+        {
+            return Err(TruthError::SlotConflict(format!(
+                "slot is busy in stream '{}'",
+                stream.stream_id
+            )));
+        }
+        let booking = Booking {
+            id: id.to_string(),
+            slot,
+            state: BookingState::Held { expires_at },
+        };
+        Ok(self.bookings.entry(id.to_string()).or_insert(booking))
+    }
+
+    /// Confirm a hold. Confirming a confirmed booking is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::Booking`] if there is no such booking or it was
+    /// released or has expired. A hold whose deadline has passed is marked
+    /// expired.
+    pub fn confirm(&mut self, id: &str, now: DateTime<Utc>) -> Result<&Booking> {
+        let booking = self.booking_mut(id)?;
+        match booking.state {
+            BookingState::Held { expires_at } if expires_at > now => {
+                booking.state = BookingState::Confirmed;
+            }
+            BookingState::Held { .. } => {
+                booking.state = BookingState::Expired;
+                return Err(TruthError::Booking(format!("hold '{}' has expired", id)));
+            }
+            BookingState::Confirmed => {}
+            BookingState::Released | BookingState::Expired => {
+                return Err(TruthError::Booking(format!(
+                    "booking '{}' is no longer held",
+                    id
+                )));
+            }
+        }
+        Ok(booking)
+    }
+
+    /// Release a hold or a confirmed booking, freeing its slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::Booking`] if there is no such booking or it was
+    /// already released or has expired. A hold whose deadline has passed is
+    /// marked expired.
+    pub fn release(&mut self, id: &str, now: DateTime<Utc>) -> Result<&Booking> {
+        let booking = self.booking_mut(id)?;
+        match booking.state {
+            BookingState::Held { expires_at } if expires_at > now => {
+                booking.state = BookingState::Released;
+            }
+            BookingState::Held { .. } => {
+                booking.state = BookingState::Expired;
+                return Err(TruthError::Booking(format!("hold '{}' has expired", id)));
+            }
+            BookingState::Confirmed => {
+                booking.state = BookingState::Released;
+            }
+            BookingState::Released | BookingState::Expired => {
+                return Err(TruthError::Booking(format!(
+                    "booking '{}' is not active",
+                    id
+                )));
+            }
+        }
+        Ok(booking)
+    }
+
+    /// Mark every hold whose deadline is at or before `now` as expired, and
+    /// return their ids in id order.
+    ///
+    /// Queries already treat such holds as inactive; this records it.
+    pub fn expire(&mut self, now: DateTime<Utc>) -> Vec<String> {
+        let mut expired = Vec::new();
+        for booking in self.bookings.values_mut() {
+            if matches!(booking.state, BookingState::Held { expires_at } if expires_at <= now) {
+                booking.state = BookingState::Expired;
+                expired.push(booking.id.clone());
+            }
+        }
+        expired
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Booking> {
+        self.bookings.get(id)
+    }
+
+    /// Every booking, in any state, in id order.
+    pub fn bookings(&self) -> impl Iterator<Item = &Booking> {
+        self.bookings.values()
+    }
+
+    /// The bookings that block time at `now`, sorted by slot start.
+    pub fn active(&self, now: DateTime<Utc>) -> Vec<&Booking> {
+        let mut active: Vec<&Booking> = self
+            .bookings
+            .values()
+            .filter(|b| b.is_active(now))
+            .collect();
+        active.sort_by_key(|b| (b.slot.start, b.slot.end));
+        active
+    }
+
+    /// The active bookings as an [`EventStream`] named [`BOOKINGS_STREAM`].
+    pub fn stream(&self, now: DateTime<Utc>) -> EventStream {
+        EventStream {
+            stream_id: BOOKINGS_STREAM.to_string(),
+            events: self
+                .active(now)
+                .into_iter()
+                .map(|b| b.slot.clone())
+                .collect(),
+        }
+    }
+
+    /// [`merge_availability`](crate::merge_availability) over `streams` plus
+    /// the active bookings.
+    pub fn availability(
+        &self,
+        streams: &[EventStream],
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+        privacy: PrivacyLevel,
+        now: DateTime<Utc>,
+    ) -> UnifiedAvailability {
+        let mut all = streams.to_vec();
+        all.push(self.stream(now));
+        merge_availability(&all, window_start, window_end, privacy)
+    }
+
+    fn booking_mut(&mut self, id: &str) -> Result<&mut Booking> {
+        self.bookings
+            .get_mut(id)
+            .ok_or_else(|| TruthError::Booking(format!("no booking '{}'", id)))
+    }
+}
+
+fn overlaps(a: &ExpandedEvent, b: &ExpandedEvent) -> bool {
+    a.start < b.end && b.start < a.end
+}
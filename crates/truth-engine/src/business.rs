@@ -0,0 +1,275 @@
+//! Business calendars: work week, working hours, holidays, and closures.
+//!
+//! A [`BusinessCalendar`] bundles everything that decides whether a moment
+//! is working time, so free/busy, availability, business-day expressions,
+//! and the planner all take one value instead of the same three or four
+//! parameters each:
+//!
+//! - [`find_business_free_slots`](crate::freebusy::find_business_free_slots)
+//! - [`merge_business_availability`](crate::availability::merge_business_availability)
+//! - [`ResolveOptions::business_calendar`](crate::temporal::ResolveOptions::business_calendar)
+//! - [`PlanOptions::calendar`](crate::planner::PlanOptions::calendar)
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::temporal::interval::TimeInterval;
+use crate::temporal::{parse_timezone, working_window, WorkingHours};
+
+/// A run of consecutive non-working days, such as a year-end shutdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Closure {
+    pub name: String,
+    /// First closed day.
+    pub start: NaiveDate,
+    /// Last closed day (inclusive).
+    pub end: NaiveDate,
+}
+
+/// When an organization works.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use truth_engine::business::{BusinessCalendar, Closure};
+///
+/// let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+/// let calendar = BusinessCalendar {
+///     holidays: [date(12, 25)].into(),
+///     closures: vec![Closure { name: "Shutdown".into(), start: date(12, 28), end: date(12, 31) }],
+///     ..BusinessCalendar::default()
+/// };
+///
+/// // Thursday, December 24 + 1 business day skips the holiday, the weekend,
+/// // and the shutdown.
+/// assert_eq!(calendar.add_business_days(date(12, 24), 1), Some(NaiveDate::from_ymd_opt(2027, 1, 1).unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct BusinessCalendar {
+    /// Working days (the work week) and the daily working-hours window.
+    pub working_hours: WorkingHours,
+    /// IANA timezone the working hours are read in.
+    pub timezone: String,
+    /// Single non-working dates.
+    pub holidays: BTreeSet<NaiveDate>,
+    pub closures: Vec<Closure>,
+}
+
+impl Default for BusinessCalendar {
+    /// Default working hours in UTC, with no holidays or closures.
+    fn default() -> Self {
+        Self::from(WorkingHours::default())
+    }
+}
+
+impl From<WorkingHours> for BusinessCalendar {
+    /// A UTC calendar with these working hours and no holidays or closures.
+    fn from(working_hours: WorkingHours) -> Self {
+        Self {
+            working_hours,
+            timezone: "UTC".to_string(),
+            holidays: BTreeSet::new(),
+            closures: Vec::new(),
+        }
+    }
+}
+
+impl BusinessCalendar {
+    /// Whether `date` is a working day: in the work week, not a holiday, and
+    /// not inside a closure.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        self.working_hours.days.contains(&date.weekday())
+            && !self.holidays.contains(&date)
+            && !self
+                .closures
+                .iter()
+                .any(|c| c.start <= date && date <= c.end)
+    }
+
+    /// The date `n` business days after `date` (before, for negative `n`).
+    /// `date` itself is never counted. `None` if the work week is empty or
+    /// no business day is found within ten years.
+    pub fn add_business_days(&self, date: NaiveDate, n: i64) -> Option<NaiveDate> {
+        if self.working_hours.days.is_empty() {
+            return None;
+        }
+        let step = if n >= 0 { 1 } else { -1 };
+        let mut remaining = n.abs();
+        let mut current = date;
+        let mut idle = 0;
+        while remaining > 0 {
+            current = current.checked_add_signed(chrono::Duration::days(step))?;
+            if self.is_business_day(current) {
+                remaining -= 1;
+                idle = 0;
+            } else {
+                idle += 1;
+                if idle > 3660 {
+                    return None;
+                }
+            }
+        }
+        Some(current)
+    }
+
+    /// Business days in `[from, to)`, negated when `to` is before `from`.
+    pub fn business_days_between(&self, from: NaiveDate, to: NaiveDate) -> i64 {
+        let (lo, hi, sign) = if from <= to {
+            (from, to, 1)
+        } else {
+            (to, from, -1)
+        };
+        let count = lo
+            .iter_days()
+            .take_while(|d| *d < hi)
+            .filter(|d| self.is_business_day(*d))
+            .count() as i64;
+        sign * count
+    }
+
+    /// The working time within `[window_start, window_end)`, as UTC
+    /// intervals sorted by start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidTimezone`](crate::TruthError::InvalidTimezone)
+    /// for an unknown `timezone`.
+    pub fn working_intervals(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> Result<Vec<TimeInterval>> {
+        let tz = parse_timezone(&self.timezone)?;
+        let window = TimeInterval {
+            start: window_start,
+            end: window_end,
+        };
+        let mut out = Vec::new();
+        if window_start >= window_end {
+            return Ok(out);
+        }
+        let mut date = window_start.with_timezone(&tz).date_naive();
+        let last = window_end.with_timezone(&tz).date_naive();
+        while date <= last {
+            if self.is_business_day(date) {
+                if let Some((start, end)) = working_window(date, &tz, &self.working_hours) {
+                    out.extend((TimeInterval { start, end }).clamp_to(&window));
+                }
+            }
+            date = match date.succ_opt() {
+                Some(d) => d,
+                None => break,
+            };
+        }
+        Ok(out)
+    }
+
+    /// Whether `at` is working time.
+    ///
+    /// # Errors
+    ///
+    /// As for [`working_intervals`](Self::working_intervals).
+    pub fn is_open(&self, at: DateTime<Utc>) -> Result<bool> {
+        Ok(!self
+            .working_intervals(at, at + chrono::Duration::seconds(1))?
+            .is_empty())
+    }
+
+    /// The instant `amount` of working time after `at` (before it, for a
+    /// negative `amount`), pausing over nights, weekends, holidays, and
+    /// closures.
+    ///
+    /// Outside working hours the count starts at the next opening (going
+    /// back, the previous close). A result that lands exactly on a close is
+    /// that close, not the next opening; going back, an opening stays an
+    /// opening. `None` if no such working time falls within ten years.
+    ///
+    /// # Errors
+    ///
+    /// As for [`working_intervals`](Self::working_intervals).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use truth_engine::business::BusinessCalendar;
+    ///
+    /// let at = |d, h| Utc.with_ymd_and_hms(2026, 3, d, h, 0, 0).unwrap();
+    /// let calendar = BusinessCalendar::default(); // 09:00–17:00 UTC, Monday to Friday
+    /// // Friday 15:00 + 6 working hours: 2 on Friday, 4 on Monday.
+    /// let later = calendar.add_business_time(at(6, 15), Duration::hours(6)).unwrap();
+    /// assert_eq!(later, Some(at(9, 13)));
+    /// let earlier = calendar.add_business_time(at(9, 13), Duration::hours(-6)).unwrap();
+    /// assert_eq!(earlier, Some(at(6, 15)));
+    /// ```
+    pub fn add_business_time(
+        &self,
+        at: DateTime<Utc>,
+        amount: chrono::Duration,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let forward = amount >= chrono::Duration::zero();
+        let mut left = amount.abs();
+        if left.is_zero() {
+            return Ok(Some(at));
+        }
+        let horizon = chrono::Duration::days(3660);
+        let chunk = chrono::Duration::days(31);
+        let mut cursor = at;
+        if forward {
+            let limit = at + horizon;
+            while cursor < limit {
+                let to = (cursor + chunk).min(limit);
+                for interval in self.working_intervals(cursor, to)? {
+                    if left <= interval.duration() {
+                        return Ok(Some(interval.start + left));
+                    }
+                    left -= interval.duration();
+                }
+                cursor = to;
+            }
+        } else {
+            let limit = at - horizon;
+            while cursor > limit {
+                let from = (cursor - chunk).max(limit);
+                for interval in self.working_intervals(from, cursor)?.iter().rev() {
+                    if left <= interval.duration() {
+                        return Ok(Some(interval.end - left));
+                    }
+                    left -= interval.duration();
+                }
+                cursor = from;
+            }
+        }
+        Ok(None)
+    }
+
+    /// Seconds of working time between `from` and `to`, negated when `to`
+    /// is before `from`.
+    ///
+    /// # Errors
+    ///
+    /// As for [`working_intervals`](Self::working_intervals).
+    pub fn business_seconds_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<i64> {
+        let (lo, hi, sign) = if from <= to {
+            (from, to, 1)
+        } else {
+            (to, from, -1)
+        };
+        let total: i64 = self
+            .working_intervals(lo, hi)?
+            .iter()
+            .map(|i| i.duration().num_seconds())
+            .sum();
+        Ok(sign * total)
+    }
+}
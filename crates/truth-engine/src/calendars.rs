@@ -0,0 +1,410 @@
+//! Conversions between Gregorian dates and the Hebrew, Islamic civil, and
+//! Chinese calendars.
+//!
+//! [`CalendarDate`] holds a date in one of these calendars and converts to
+//! and from [`NaiveDate`]. The Hebrew calendar is the fixed arithmetic
+//! calendar; the Islamic civil calendar is the tabular one (leap years 2, 5,
+//! 7, 10, 13, 16, 18, 21, 24, 26, 29 of each 30-year cycle, epoch July 16,
+//! 622), so results can differ by a day or two from sighting-based dates;
+//! the Chinese calendar is computed astronomically for Beijing time.
+//!
+//! Dates in these calendars traditionally begin at sunset. Conversions here
+//! are day-for-day: the Gregorian date returned is the civil day that
+//! mostly overlaps the calendar day.
+//!
+//! [`resolve_relative`](crate::temporal::resolve_relative) understands
+//! Hebrew and Islamic dates by month name ("15 Nisan 5787", "1 Ramadan")
+//! and "Chinese New Year 2027".
+
+use std::fmt;
+use std::str::FromStr;
+
+use calendrical_calculations::chinese_based::{self, Chinese, YearBounds};
+use calendrical_calculations::hebrew::BookHebrew;
+use calendrical_calculations::islamic;
+use calendrical_calculations::rata_die::RataDie;
+use chrono::{Datelike, NaiveDate};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, TruthError};
+
+/// A non-Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum CalendarSystem {
+    Hebrew,
+    /// The tabular Islamic calendar with the civil (Friday) epoch.
+    IslamicCivil,
+    Chinese,
+}
+
+/// A date in a [`CalendarSystem`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use truth_engine::calendars::{CalendarDate, CalendarSystem};
+///
+/// let passover: CalendarDate = "15 Nisan 5787".parse().unwrap();
+/// assert_eq!(passover.to_gregorian().unwrap(), NaiveDate::from_ymd_opt(2027, 4, 22).unwrap());
+///
+/// let date = NaiveDate::from_ymd_opt(2026, 2, 17).unwrap();
+/// let new_year = CalendarDate::from_gregorian(date, CalendarSystem::Chinese).unwrap();
+/// assert_eq!((new_year.year, new_year.month, new_year.day), (2026, 1, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CalendarDate {
+    pub calendar: CalendarSystem,
+    /// Hebrew: anno mundi (5787). Islamic: anno Hegirae (1448). Chinese: the
+    /// Gregorian year the lunar year begins in.
+    pub year: i32,
+    /// Hebrew: 1 = Nisan … 12 = Adar (Adar I in leap years), 13 = Adar II.
+    /// Islamic: 1 = Muharram … 12 = Dhu al-Hijjah. Chinese: 1–12.
+    pub month: u8,
+    /// Chinese only: the leap month that repeats `month`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub leap_month: bool,
+    pub day: u8,
+}
+
+const HEBREW_MONTHS: [&str; 13] = [
+    "Nisan", "Iyyar", "Sivan", "Tammuz", "Av", "Elul", "Tishrei", "Heshvan", "Kislev", "Tevet",
+    "Shevat", "Adar", "Adar II",
+];
+
+const ISLAMIC_MONTHS: [&str; 12] = [
+    "Muharram",
+    "Safar",
+    "Rabi al-Awwal",
+    "Rabi al-Thani",
+    "Jumada al-Ula",
+    "Jumada al-Akhirah",
+    "Rajab",
+    "Shaban",
+    "Ramadan",
+    "Shawwal",
+    "Dhu al-Qadah",
+    "Dhu al-Hijjah",
+];
+
+impl CalendarDate {
+    /// The date in `calendar` for a Gregorian date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidDatetime`] for a Chinese date more than
+    /// 10,000 years from the epoch of the computation, where the astronomy
+    /// is meaningless.
+    pub fn from_gregorian(date: NaiveDate, calendar: CalendarSystem) -> Result<Self> {
+        let rd = RataDie::new(date.num_days_from_ce() as i64);
+        Ok(match calendar {
+            CalendarSystem::Hebrew => {
+                let h = BookHebrew::book_hebrew_from_fixed(rd);
+                Self {
+                    calendar,
+                    year: h.year,
+                    month: h.month,
+                    leap_month: false,
+                    day: h.day,
+                }
+            }
+            CalendarSystem::IslamicCivil => {
+                let (year, month, day) =
+                    islamic::tabular_islamic_from_fixed(rd, islamic::ISLAMIC_EPOCH_FRIDAY);
+                Self {
+                    calendar,
+                    year,
+                    month,
+                    leap_month: false,
+                    day,
+                }
+            }
+            CalendarSystem::Chinese => {
+                if !chinese_based::WELL_BEHAVED_ASTRONOMICAL_RANGE.contains(&rd) {
+                    return Err(out_of_range(date));
+                }
+                let c = chinese_based::chinese_based_date_from_fixed::<Chinese>(rd);
+                let year = gregorian(c.year_bounds.new_year)?.year();
+                // `c.month` counts the leap month; `leap` is its position.
+                let (month, leap_month) = match c.leap_month.map(|l| l.get()) {
+                    Some(leap) if c.month == leap => (c.month - 1, true),
+                    Some(leap) if c.month > leap => (c.month - 1, false),
+                    _ => (c.month, false),
+                };
+                Self {
+                    calendar,
+                    year,
+                    month,
+                    leap_month,
+                    day: c.day,
+                }
+            }
+        })
+    }
+
+    /// The Gregorian date of this date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidDatetime`] if the date does not exist —
+    /// a 30th day of a 29-day month, Adar II in a common Hebrew year, a leap
+    /// month the Chinese year does not have — or is out of range.
+    pub fn to_gregorian(&self) -> Result<NaiveDate> {
+        let months = match self.calendar {
+            CalendarSystem::Hebrew => 13,
+            CalendarSystem::IslamicCivil | CalendarSystem::Chinese => 12,
+        };
+        if !(1..=months).contains(&self.month)
+            || !(1..=30).contains(&self.day)
+            || (self.leap_month && self.calendar != CalendarSystem::Chinese)
+        {
+            return Err(self.nonexistent());
+        }
+        let rd = match self.calendar {
+            CalendarSystem::Hebrew => BookHebrew::fixed_from_book_hebrew(BookHebrew {
+                year: self.year,
+                month: self.month,
+                day: self.day,
+            }),
+            CalendarSystem::IslamicCivil => islamic::fixed_from_tabular_islamic(
+                self.year,
+                self.month,
+                self.day,
+                islamic::ISLAMIC_EPOCH_FRIDAY,
+            ),
+            CalendarSystem::Chinese => {
+                let mid_year = NaiveDate::from_ymd_opt(self.year, 7, 1)
+                    .filter(|d| (-9000..9000).contains(&d.year()))
+                    .ok_or_else(|| self.nonexistent())?;
+                let bounds = YearBounds::compute::<Chinese>(RataDie::new(
+                    mid_year.num_days_from_ce() as i64,
+                ));
+                let leap = bounds.is_leap().then(|| {
+                    chinese_based::get_leap_month_from_new_year::<Chinese>(bounds.new_year)
+                });
+                let ordinal = match leap {
+                    Some(leap) if self.leap_month && self.month + 1 == leap => leap,
+                    _ if self.leap_month => return Err(self.nonexistent()),
+                    Some(leap) if self.month >= leap => self.month + 1,
+                    _ => self.month,
+                };
+                bounds.new_year
+                    + chinese_based::days_until_month::<Chinese>(bounds.new_year, ordinal) as i64
+                    + self.day as i64
+                    - 1
+            }
+        };
+        let date = gregorian(rd)?;
+        // Day and month overflow land in a different date; reject it.
+        if Self::from_gregorian(date, self.calendar)? != *self {
+            return Err(self.nonexistent());
+        }
+        Ok(date)
+    }
+
+    /// The month's name, or `None` for Chinese months, which are numbered.
+    /// Hebrew month 12 is "Adar" here, also in leap years, where it is
+    /// Adar I.
+    pub fn month_name(&self) -> Option<&'static str> {
+        let index = usize::from(self.month).checked_sub(1)?;
+        match self.calendar {
+            CalendarSystem::Hebrew => HEBREW_MONTHS.get(index).copied(),
+            CalendarSystem::IslamicCivil => ISLAMIC_MONTHS.get(index).copied(),
+            CalendarSystem::Chinese => None,
+        }
+    }
+
+    fn nonexistent(&self) -> TruthError {
+        TruthError::InvalidDatetime(format!("{self} does not exist"))
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    /// "15 Nisan 5787", "1 Ramadan 1448", or "2026 month 8 day 15" (with
+    /// "leap month" for a Chinese leap month).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.month_name() {
+            Some(name) => write!(f, "{} {} {}", self.day, name, self.year),
+            None => {
+                let leap = if self.leap_month { "leap " } else { "" };
+                write!(
+                    f,
+                    "{} {leap}month {} day {}",
+                    self.year, self.month, self.day
+                )
+            }
+        }
+    }
+}
+
+impl FromStr for CalendarDate {
+    type Err = TruthError;
+
+    /// Parse a Hebrew or Islamic date with a month name: "15 Nisan 5787",
+    /// "1 Ramadan 1448 AH", "14 Adar II 5787". Case, apostrophes, and
+    /// hyphens are ignored. A bare "Adar" in a Hebrew leap year is Adar II.
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || TruthError::InvalidDatetime(format!("not a calendar date: '{s}'"));
+        let parsed = parse_named(&normalize(s)).ok_or_else(invalid)?;
+        let year = parsed.year.ok_or_else(invalid)?;
+        Ok(parsed.at_year(year))
+    }
+}
+
+/// A month named in an expression, before the year is known.
+struct NamedDate {
+    calendar: CalendarSystem,
+    month: u8,
+    day: u8,
+    /// "Adar" without I or II: Adar II in leap years.
+    plain_adar: bool,
+    year: Option<i32>,
+}
+
+impl NamedDate {
+    fn at_year(&self, year: i32) -> CalendarDate {
+        let month = if self.plain_adar && BookHebrew::is_hebrew_leap_year(year) {
+            13
+        } else {
+            self.month
+        };
+        CalendarDate {
+            calendar: self.calendar,
+            year,
+            month,
+            leap_month: false,
+            day: self.day,
+        }
+    }
+}
+
+/// Resolve "15 nisan 5787", "1 ramadan" (the next one on or after `today`),
+/// or "chinese new year [2027]" to a Gregorian date.
+#[cfg(feature = "resolver")]
+pub(crate) fn resolve_named_date(s: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let s = normalize(s);
+    let named = match s
+        .strip_prefix("chinese new year")
+        .or_else(|| s.strip_prefix("lunar new year"))
+    {
+        Some(rest) => NamedDate {
+            calendar: CalendarSystem::Chinese,
+            month: 1,
+            day: 1,
+            plain_adar: false,
+            year: match rest.trim() {
+                "" => None,
+                year => Some(year.parse().ok()?),
+            },
+        },
+        None => parse_named(&s)?,
+    };
+    if let Some(year) = named.year {
+        return named.at_year(year).to_gregorian().ok();
+    }
+    let current = CalendarDate::from_gregorian(today, named.calendar)
+        .ok()?
+        .year;
+    (current..=current + 1)
+        .filter_map(|year| named.at_year(year).to_gregorian().ok())
+        .find(|date| *date >= today)
+}
+
+/// Lowercase, with apostrophes dropped and hyphens as spaces.
+fn normalize(s: &str) -> String {
+    s.to_lowercase()
+        .replace(['\'', '’', 'ʼ', 'ʻ'], "")
+        .replace('-', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// "<day> <month name> [<year> [am|ah]]".
+fn parse_named(s: &str) -> Option<NamedDate> {
+    let (day, rest) = s.split_once(' ')?;
+    let day = day
+        .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .parse::<u8>()
+        .ok()?;
+    let (month, year) = match rest.rsplit_once(' ') {
+        Some((month, year)) if year.chars().all(|c| c.is_ascii_digit()) => {
+            (month, Some(year.parse().ok()?))
+        }
+        Some((month, "ah" | "am")) => {
+            let (month, year) = month.rsplit_once(' ')?;
+            (month, Some(year.parse().ok()?))
+        }
+        _ => (rest, None),
+    };
+    let (calendar, month, plain_adar) = match month {
+        "adar" => (CalendarSystem::Hebrew, 12, true),
+        "adar i" | "adar 1" | "adar aleph" => (CalendarSystem::Hebrew, 12, false),
+        "adar ii" | "adar 2" | "adar bet" | "adar beit" => (CalendarSystem::Hebrew, 13, false),
+        _ => {
+            if let Some(m) = hebrew_month(month) {
+                (CalendarSystem::Hebrew, m, false)
+            } else {
+                (CalendarSystem::IslamicCivil, islamic_month(month)?, false)
+            }
+        }
+    };
+    Some(NamedDate {
+        calendar,
+        month,
+        day,
+        plain_adar,
+        year,
+    })
+}
+
+fn hebrew_month(name: &str) -> Option<u8> {
+    Some(match name {
+        "nisan" | "nissan" => 1,
+        "iyyar" | "iyar" => 2,
+        "sivan" => 3,
+        "tammuz" | "tamuz" => 4,
+        "av" | "ab" => 5,
+        "elul" => 6,
+        "tishrei" | "tishri" => 7,
+        "heshvan" | "cheshvan" | "marheshvan" | "marcheshvan" => 8,
+        "kislev" => 9,
+        "tevet" | "teveth" => 10,
+        "shevat" | "shvat" | "shebat" => 11,
+        _ => return None,
+    })
+}
+
+fn islamic_month(name: &str) -> Option<u8> {
+    Some(match name {
+        "muharram" => 1,
+        "safar" => 2,
+        "rabi al awwal" | "rabi i" | "rabi ul awwal" => 3,
+        "rabi al thani" | "rabi al akhir" | "rabi ii" | "rabi ul akhir" => 4,
+        "jumada al ula" | "jumada al awwal" | "jumada i" => 5,
+        "jumada al akhirah" | "jumada al thani" | "jumada ii" => 6,
+        "rajab" => 7,
+        "shaban" => 8,
+        "ramadan" | "ramadhan" => 9,
+        "shawwal" => 10,
+        "dhu al qadah" | "dhu al qidah" | "dhul qadah" | "dhul qidah" => 11,
+        "dhu al hijjah" | "dhul hijjah" => 12,
+        _ => return None,
+    })
+}
+
+fn gregorian(rd: RataDie) -> Result<NaiveDate> {
+    i32::try_from(rd.to_i64_date())
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .ok_or_else(|| TruthError::InvalidDatetime("date out of range".to_string()))
+}
+
+fn out_of_range(date: NaiveDate) -> TruthError {
+    TruthError::InvalidDatetime(format!("{date} is out of range for the Chinese calendar"))
+}
@@ -0,0 +1,144 @@
+//! Arrow record batches of expansion and availability results (`arrow` feature).
+//!
+//! Analytics pipelines that compute utilization over millions of occurrences
+//! want columns, not JSON. [`streams_to_record_batch`] and
+//! [`availability_to_record_batch`] lay results out as Arrow
+//! [`RecordBatch`]es with UTC microsecond timestamps, and [`write_ipc`]
+//! writes batches as an Arrow IPC file that pandas, Polars, DuckDB, and
+//! Spark read directly.
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef, Int64Array, RecordBatch, StringArray, TimestampMicrosecondArray, UInt64Array,
+};
+use arrow_ipc::writer::FileWriter;
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+use chrono::{DateTime, Utc};
+
+use crate::availability::{EventStream, UnifiedAvailability};
+use crate::error::{Result, TruthError};
+use crate::expander::ExpandedEvent;
+
+fn timestamp_type() -> DataType {
+    DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+}
+
+/// Schema of [`streams_to_record_batch`]: `stream_id`, `start`, `end`,
+/// `duration_minutes`.
+pub fn events_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("stream_id", DataType::Utf8, false),
+        Field::new("start", timestamp_type(), false),
+        Field::new("end", timestamp_type(), false),
+        Field::new("duration_minutes", DataType::Int64, false),
+    ]))
+}
+
+/// Schema of [`availability_to_record_batch`]: `kind` (`"busy"` or
+/// `"free"`), `start`, `end`, `duration_minutes`, and `source_count` (null
+/// for free slots).
+pub fn availability_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("kind", DataType::Utf8, false),
+        Field::new("start", timestamp_type(), false),
+        Field::new("end", timestamp_type(), false),
+        Field::new("duration_minutes", DataType::Int64, false),
+        Field::new("source_count", DataType::UInt64, true),
+    ]))
+}
+
+/// One row per event across `streams`, in stream order.
+///
+/// # Errors
+///
+/// Returns [`TruthError::Export`] if Arrow rejects the batch.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::columnar::streams_to_record_batch;
+/// use truth_engine::{expand_rrule, EventStream};
+///
+/// let events = expand_rrule("FREQ=DAILY", "2026-03-02T09:00:00", 30, "UTC", None, Some(5)).unwrap();
+/// let stream = EventStream { stream_id: "standup".to_string(), events };
+/// let batch = streams_to_record_batch(&[stream]).unwrap();
+/// assert_eq!(batch.num_rows(), 5);
+/// assert_eq!(batch.schema().field(1).name(), "start");
+/// ```
+pub fn streams_to_record_batch(streams: &[EventStream]) -> Result<RecordBatch> {
+    let rows = streams
+        .iter()
+        .flat_map(|s| s.events.iter().map(move |e| (s.stream_id.as_str(), e)));
+    let (ids, events): (Vec<&str>, Vec<&ExpandedEvent>) = rows.unzip();
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from(ids)),
+        timestamps(events.iter().map(|e| e.start)),
+        timestamps(events.iter().map(|e| e.end)),
+        Arc::new(Int64Array::from_iter_values(
+            events.iter().map(|e| (e.end - e.start).num_minutes()),
+        )),
+    ];
+    RecordBatch::try_new(events_schema(), columns).map_err(export_error)
+}
+
+/// One row per busy block, then one per free slot.
+///
+/// # Errors
+///
+/// Returns [`TruthError::Export`] if Arrow rejects the batch.
+pub fn availability_to_record_batch(availability: &UnifiedAvailability) -> Result<RecordBatch> {
+    let busy = availability
+        .busy
+        .iter()
+        .map(|b| ("busy", b.start, b.end, Some(b.source_count as u64)));
+    let free = availability
+        .free
+        .iter()
+        .map(|f| ("free", f.start, f.end, None));
+    let rows: Vec<_> = busy.chain(free).collect();
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(rows.iter().map(|r| r.0))),
+        timestamps(rows.iter().map(|r| r.1)),
+        timestamps(rows.iter().map(|r| r.2)),
+        Arc::new(Int64Array::from_iter_values(
+            rows.iter().map(|r| (r.2 - r.1).num_minutes()),
+        )),
+        Arc::new(UInt64Array::from_iter(rows.iter().map(|r| r.3))),
+    ];
+    RecordBatch::try_new(availability_schema(), columns).map_err(export_error)
+}
+
+/// Write `batches` as an Arrow IPC file. All batches must share a schema;
+/// an empty slice writes nothing.
+///
+/// # Errors
+///
+/// Returns [`TruthError::Export`] if the schemas differ or writing fails.
+pub fn write_ipc<W: Write>(writer: W, batches: &[RecordBatch]) -> Result<()> {
+    let Some(first) = batches.first() else {
+        return Ok(());
+    };
+    let mut file = FileWriter::try_new(writer, &first.schema()).map_err(export_error)?;
+    for batch in batches {
+        if batch.schema() != first.schema() {
+            return Err(TruthError::Export(
+                "record batches in one IPC file must share a schema".to_string(),
+            ));
+        }
+        file.write(batch).map_err(export_error)?;
+    }
+    file.finish().map_err(export_error)
+}
+
+fn timestamps(values: impl Iterator<Item = DateTime<Utc>>) -> ArrayRef {
+    Arc::new(
+        TimestampMicrosecondArray::from_iter_values(values.map(|t| t.timestamp_micros()))
+            .with_timezone("UTC"),
+    )
+}
+
+fn export_error(e: ArrowError) -> TruthError {
+    TruthError::Export(e.to_string())
+}
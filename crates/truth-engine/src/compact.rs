@@ -0,0 +1,424 @@
+//! A compact event representation for very large calendars.
+//!
+//! An [`EventStream`] owns its id, and availability and conflict detection
+//! build interval indexes of `DateTime`s on top of the events. At a million
+//! occurrences that overhead dominates. A [`CompactCalendar`] stores every
+//! event as a [`CompactEvent`]: Unix-second bounds and a 4-byte index into an
+//! interned [`StringTable`] of stream ids, in one flat vector. Its free/busy,
+//! availability, and conflict methods work on the integers directly and return
+//! what the free functions return for the same streams (of events that do
+//! not end before they start).
+//!
+//! Bounds are whole seconds; sub-second precision is dropped on conversion.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::availability::{BusyBlock, EventStream, PrivacyLevel, UnifiedAvailability};
+use crate::conflict::Conflict;
+use crate::expander::{ExpandedEvent, OccurrenceBuffer};
+use crate::freebusy::FreeSlot;
+
+/// An index into a [`StringTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StringId(u32);
+
+impl StringId {
+    /// The id's position in its table.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Interned strings, each stored once and referred to by a [`StringId`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StringTable {
+    strings: Vec<Box<str>>,
+    ids: HashMap<Box<str>, StringId>,
+}
+
+impl StringTable {
+    /// An empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of `s`, adding it if it is new.
+    ///
+    /// # Panics
+    ///
+    /// If the table already holds `u32::MAX` strings.
+    pub fn intern(&mut self, s: &str) -> StringId {
+        if let Some(&id) = self.ids.get(s) {
+            return id;
+        }
+        let id = StringId(u32::try_from(self.strings.len()).expect("fewer than 2^32 strings"));
+        self.strings.push(s.into());
+        self.ids.insert(s.into(), id);
+        id
+    }
+
+    /// The id of `s`, if it has been interned.
+    pub fn get(&self, s: &str) -> Option<StringId> {
+        self.ids.get(s).copied()
+    }
+
+    /// The string with `id`.
+    ///
+    /// # Panics
+    ///
+    /// If `id` is not from this table.
+    pub fn resolve(&self, id: StringId) -> &str {
+        &self.strings[id.index()]
+    }
+
+    /// Number of distinct strings.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Whether the table holds no strings.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Every string, in order of id.
+    pub fn iter(&self) -> impl Iterator<Item = (StringId, &str)> {
+        self.strings
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (StringId(i as u32), &**s))
+    }
+}
+
+/// An event as Unix-second bounds and the id of its stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompactEvent {
+    /// Start, in Unix seconds.
+    pub start: i64,
+    /// End, in Unix seconds.
+    pub end: i64,
+    /// The stream the event belongs to.
+    pub stream: StringId,
+}
+
+impl CompactEvent {
+    /// `event` in `stream`, truncated to whole seconds.
+    pub fn from_event(event: &ExpandedEvent, stream: StringId) -> Self {
+        Self {
+            start: event.start.timestamp(),
+            end: event.end.timestamp(),
+            stream,
+        }
+    }
+
+    /// The event as an [`ExpandedEvent`]. Bounds outside chrono's range
+    /// clamp to it.
+    pub fn to_event(&self) -> ExpandedEvent {
+        ExpandedEvent::new(instant(self.start), instant(self.end))
+    }
+
+    /// Whether the events share time (half-open, as
+    /// [`TimeInterval::overlaps`](crate::temporal::interval::TimeInterval::overlaps)).
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+/// Events from many streams, stored compactly.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::compact::CompactCalendar;
+/// use truth_engine::expander::{CompiledRule, OccurrenceBuffer};
+/// use truth_engine::{merge_availability, PrivacyLevel};
+///
+/// let rule =
+///     CompiledRule::new("FREQ=DAILY", "2026-01-01T09:00:00", "UTC", None, Some(365), &[]).unwrap();
+/// let mut buffer = OccurrenceBuffer::new();
+/// rule.expand_into(&mut buffer, 60);
+///
+/// let mut calendar = CompactCalendar::new();
+/// calendar.extend_from_buffer("standup", &buffer);
+/// assert_eq!(calendar.len(), 365);
+///
+/// let start = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2026, 3, 8, 0, 0, 0).unwrap();
+/// let availability = calendar.availability(start, end, PrivacyLevel::Full);
+/// assert_eq!(availability.busy.len(), 7);
+/// let streams = calendar.to_streams();
+/// assert_eq!(availability, merge_availability(&streams, start, end, PrivacyLevel::Full));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactCalendar {
+    strings: StringTable,
+    events: Vec<CompactEvent>,
+}
+
+impl CompactCalendar {
+    /// An empty calendar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every event of `streams`, in order. Streams sharing an id become one
+    /// stream.
+    pub fn from_streams(streams: &[EventStream]) -> Self {
+        let mut calendar = Self::new();
+        for stream in streams {
+            let id = calendar.strings.intern(&stream.stream_id);
+            calendar.events.extend(
+                stream
+                    .events
+                    .iter()
+                    .map(|event| CompactEvent::from_event(event, id)),
+            );
+        }
+        calendar
+    }
+
+    /// Add `event` to the stream `stream_id`.
+    pub fn push(&mut self, stream_id: &str, event: &ExpandedEvent) {
+        let id = self.strings.intern(stream_id);
+        self.events.push(CompactEvent::from_event(event, id));
+    }
+
+    /// Add every occurrence in `buffer` to the stream `stream_id`.
+    pub fn extend_from_buffer(&mut self, stream_id: &str, buffer: &OccurrenceBuffer) {
+        let id = self.strings.intern(stream_id);
+        self.events.extend(
+            buffer
+                .starts
+                .iter()
+                .zip(&buffer.ends)
+                .map(|(&start, &end)| CompactEvent {
+                    start,
+                    end,
+                    stream: id,
+                }),
+        );
+    }
+
+    /// Number of events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether the calendar has no events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Every event, in the order added.
+    pub fn events(&self) -> &[CompactEvent] {
+        &self.events
+    }
+
+    /// The stream ids.
+    pub fn strings(&self) -> &StringTable {
+        &self.strings
+    }
+
+    /// The events of the stream `stream_id`, in the order added.
+    pub fn stream_events(&self, stream_id: &str) -> Vec<ExpandedEvent> {
+        match self.strings.get(stream_id) {
+            Some(id) => self.stream(id).map(|e| e.to_event()).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// The calendar as one [`EventStream`] per stream id, in order of first
+    /// appearance.
+    pub fn to_streams(&self) -> Vec<EventStream> {
+        let mut streams: Vec<EventStream> = self
+            .strings
+            .iter()
+            .map(|(_, id)| EventStream {
+                stream_id: id.to_string(),
+                events: Vec::new(),
+            })
+            .collect();
+        for event in &self.events {
+            streams[event.stream.index()].events.push(event.to_event());
+        }
+        streams
+    }
+
+    /// [`find_free_slots`](crate::freebusy::find_free_slots) over every
+    /// stream.
+    pub fn free_slots(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> Vec<FreeSlot> {
+        let (start, end) = (window_start.timestamp(), window_end.timestamp());
+        let busy = self.busy(start, end);
+        gaps(&busy, start, end)
+            .map(|(start, end)| FreeSlot {
+                start: instant(start),
+                end: instant(end),
+                duration_minutes: (end - start) / 60,
+            })
+            .collect()
+    }
+
+    /// [`merge_availability`](crate::availability::merge_availability) over
+    /// every stream.
+    pub fn availability(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+        privacy: PrivacyLevel,
+    ) -> UnifiedAvailability {
+        let (start, end) = (window_start.timestamp(), window_end.timestamp());
+        let busy = self.busy(start, end);
+        let sources = match privacy {
+            PrivacyLevel::Full => self.source_counts(&busy, start, end),
+            PrivacyLevel::Opaque => vec![0; busy.len()],
+        };
+        UnifiedAvailability {
+            busy: busy
+                .iter()
+                .zip(sources)
+                .map(|(&(start, end), source_count)| BusyBlock {
+                    start: instant(start),
+                    end: instant(end),
+                    source_count,
+                })
+                .collect(),
+            free: self.free_slots(window_start, window_end),
+            window_start,
+            window_end,
+            privacy,
+        }
+    }
+
+    /// [`find_conflicts`](crate::conflict::find_conflicts) between the
+    /// streams `stream_a` and `stream_b`. A stream that does not exist has
+    /// no events.
+    pub fn conflicts(&self, stream_a: &str, stream_b: &str) -> Vec<Conflict> {
+        let (Some(a), Some(b)) = (self.strings.get(stream_a), self.strings.get(stream_b)) else {
+            return Vec::new();
+        };
+        let a: Vec<CompactEvent> = self.stream(a).collect();
+        let b: Vec<CompactEvent> = self.stream(b).collect();
+
+        // Sweep both lists by start, pairing each event with the other side's
+        // events still running.
+        let mut starts: Vec<(i64, bool, usize)> = a
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.start, true, i))
+            .chain(b.iter().enumerate().map(|(j, e)| (e.start, false, j)))
+            .collect();
+        starts.sort_unstable();
+        let mut pairs: Vec<(usize, usize)> = Vec::new();
+        let (mut active_a, mut active_b): (Vec<usize>, Vec<usize>) = (Vec::new(), Vec::new());
+        for (start, in_a, i) in starts {
+            active_a.retain(|&k| a[k].end > start);
+            active_b.retain(|&k| b[k].end > start);
+            if in_a {
+                pairs.extend(
+                    active_b
+                        .iter()
+                        .filter(|&&j| a[i].overlaps(&b[j]))
+                        .map(|&j| (i, j)),
+                );
+                active_a.push(i);
+            } else {
+                pairs.extend(
+                    active_a
+                        .iter()
+                        .filter(|&&k| a[k].overlaps(&b[i]))
+                        .map(|&k| (k, i)),
+                );
+                active_b.push(i);
+            }
+        }
+        pairs.sort_unstable();
+
+        pairs
+            .into_iter()
+            .map(|(i, j)| {
+                let overlap = a[i].end.min(b[j].end) - a[i].start.max(b[j].start);
+                Conflict {
+                    event_a: a[i].to_event(),
+                    event_b: b[j].to_event(),
+                    overlap_minutes: overlap / 60,
+                }
+            })
+            .collect()
+    }
+
+    fn stream(&self, id: StringId) -> impl Iterator<Item = CompactEvent> + '_ {
+        self.events.iter().copied().filter(move |e| e.stream == id)
+    }
+
+    /// Events clipped to `start..end`, empty ones dropped, merged into
+    /// sorted disjoint busy periods.
+    fn busy(&self, start: i64, end: i64) -> Vec<(i64, i64)> {
+        let mut clipped: Vec<(i64, i64)> = self
+            .events
+            .iter()
+            .map(|e| (e.start.max(start), e.end.min(end)))
+            .filter(|(s, e)| s < e)
+            .collect();
+        clipped.sort_unstable();
+        let mut merged: Vec<(i64, i64)> = Vec::with_capacity(clipped.len());
+        for (s, e) in clipped {
+            match merged.last_mut() {
+                Some(last) if s <= last.1 => last.1 = last.1.max(e),
+                _ => merged.push((s, e)),
+            }
+        }
+        merged
+    }
+
+    /// For each busy period, how many streams have an event (clipped to the
+    /// window) overlapping it.
+    fn source_counts(&self, busy: &[(i64, i64)], start: i64, end: i64) -> Vec<usize> {
+        // Each event overlaps at most one of the disjoint periods.
+        let mut hits: Vec<(usize, StringId)> = self
+            .events
+            .iter()
+            .filter(|e| e.start < end && start < e.end)
+            .filter_map(|e| {
+                let (s, t) = (e.start.max(start), e.end.min(end));
+                let k = busy.partition_point(|&(b, _)| b <= s).checked_sub(1)?;
+                (s < busy[k].1 && t > busy[k].0).then_some((k, e.stream))
+            })
+            .collect();
+        hits.sort_unstable();
+        hits.dedup();
+        let mut counts = vec![0; busy.len()];
+        for (k, _) in hits {
+            counts[k] += 1;
+        }
+        counts
+    }
+}
+
+/// The gaps between sorted disjoint `busy` periods within `start..end`.
+fn gaps(busy: &[(i64, i64)], start: i64, end: i64) -> impl Iterator<Item = (i64, i64)> + '_ {
+    let mut cursor = start;
+    busy.iter()
+        .map(|&(s, e)| (s, e))
+        .chain(std::iter::once((end, end)))
+        .filter_map(move |(s, e)| {
+            let gap = (cursor < s).then_some((cursor, s));
+            cursor = cursor.max(e);
+            gap
+        })
+}
+
+/// An instant from Unix seconds, clamped to chrono's range.
+fn instant(seconds: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(seconds, 0).unwrap_or(if seconds < 0 {
+        DateTime::<Utc>::MIN_UTC
+    } else {
+        DateTime::<Utc>::MAX_UTC
+    })
+}
@@ -1,12 +1,27 @@
 //! Detect overlapping events in expanded schedules.
 //!
-//! Performs pairwise comparison between two event lists to find time overlaps.
+//! Indexes one event list in an [`IntervalTree`] and queries it with each
+//! event of the other to find time overlaps.
 //! Adjacent events (where one ends exactly when another starts) are NOT conflicts.
+//! [`conflict_matrix`] summarizes the overlap between every pair of event
+//! streams (attendees or calendars) over a window in one call.
+//! [`find_series_conflicts`] compares recurring series by their effective
+//! occurrences, with moved and cancelled instances applied.
 
-use crate::expander::ExpandedEvent;
+use crate::availability::EventStream;
+use crate::error::Result;
+use crate::expander::{ExpandedEvent, RecurringSeries};
+use crate::freebusy::merge_busy_periods;
+use crate::interval_tree::IntervalTree;
+use crate::temporal::interval::TimeInterval;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A detected conflict between two events.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Conflict {
     pub event_a: ExpandedEvent,
     pub event_b: ExpandedEvent,
@@ -19,22 +34,32 @@ pub struct Conflict {
 /// The overlap duration is `min(a.end, b.end) - max(a.start, b.start)`.
 ///
 /// Adjacent events where one ends exactly when another starts are NOT conflicts.
+///
+/// Conflicts are ordered by `events_a`, then `events_b`, as in the input.
 pub fn find_conflicts(events_a: &[ExpandedEvent], events_b: &[ExpandedEvent]) -> Vec<Conflict> {
+    let index: IntervalTree<usize> = events_b
+        .iter()
+        .enumerate()
+        .map(|(j, b)| (TimeInterval::from(b), j))
+        .collect();
     let mut conflicts = Vec::new();
 
     for a in events_a {
-        for b in events_b {
-            // Two intervals overlap iff a.start < b.end AND b.start < a.end.
-            // This excludes the adjacent case where a.end == b.start.
-            if a.start < b.end && b.start < a.end {
-                let overlap_start = a.start.max(b.start);
-                let overlap_end = a.end.min(b.end);
-                let overlap_minutes = (overlap_end - overlap_start).num_minutes();
-
+        let a_interval = TimeInterval::from(a);
+        let mut hits: Vec<(TimeInterval, usize)> = index
+            .overlapping(&a_interval)
+            .into_iter()
+            .map(|(b_interval, &j)| (*b_interval, j))
+            .collect();
+        hits.sort_unstable_by_key(|&(_, j)| j);
+        for (b_interval, j) in hits {
+            // Half-open intervals: the adjacent case where a.end == b.start
+            // is not an overlap.
+            if let Some(overlap) = a_interval.intersect(&b_interval) {
                 conflicts.push(Conflict {
                     event_a: a.clone(),
-                    event_b: b.clone(),
-                    overlap_minutes,
+                    event_b: events_b[j].clone(),
+                    overlap_minutes: overlap.duration().num_minutes(),
                 });
             }
         }
@@ -42,3 +67,186 @@ pub fn find_conflicts(events_a: &[ExpandedEvent], events_b: &[ExpandedEvent]) ->
 
     conflicts
 }
+
+/// [`find_conflicts`] between two sets of recurring series over a window,
+/// comparing their effective occurrences: a moved occurrence conflicts at
+/// its new times and a cancelled one not at all.
+///
+/// Conflicts are ordered by the occurrences of `series_a`, then of
+/// `series_b`, each sorted by start across its series.
+///
+/// # Errors
+///
+/// As for [`RecurringSeries::occurrences`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::conflict::find_series_conflicts;
+/// use truth_engine::expander::{OccurrenceOverride, RecurringSeries};
+/// use truth_engine::ExpandedEvent;
+///
+/// let at = |d, h| Utc.with_ymd_and_hms(2026, 3, d, h, 0, 0).unwrap();
+/// let series = |rrule: &str, dtstart: &str| RecurringSeries {
+///     rrule: rrule.to_string(),
+///     dtstart: dtstart.to_string(),
+///     duration_minutes: 60,
+///     timezone: "UTC".to_string(),
+///     exdates: vec![],
+///     rdates: vec![],
+///     overrides: vec![],
+/// };
+/// let mut review = series("FREQ=WEEKLY;BYDAY=MO", "2026-03-02T10:00:00");
+/// let sync = series("FREQ=DAILY", "2026-03-02T10:30:00");
+/// // Monday's review moves to the afternoon, clear of the 10:30 sync.
+/// review.overrides.push(OccurrenceOverride {
+///     recurrence_id: "2026-03-09T10:00:00".to_string(),
+///     event: Some(ExpandedEvent::new(at(9, 14), at(9, 15))),
+/// });
+/// let conflicts = find_series_conflicts(&[review], &[sync], at(2, 0), at(16, 0)).unwrap();
+/// assert_eq!(conflicts.len(), 1);
+/// assert_eq!(conflicts[0].event_a.start, at(2, 10));
+/// ```
+pub fn find_series_conflicts(
+    series_a: &[RecurringSeries],
+    series_b: &[RecurringSeries],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Result<Vec<Conflict>> {
+    let occurrences = |series: &[RecurringSeries]| -> Result<Vec<ExpandedEvent>> {
+        let mut all = Vec::new();
+        for s in series {
+            all.extend(s.occurrences(window_start, window_end)?);
+        }
+        all.sort_by_key(|e| (e.start, e.end));
+        Ok(all)
+    };
+    Ok(find_conflicts(
+        &occurrences(series_a)?,
+        &occurrences(series_b)?,
+    ))
+}
+
+/// Overlapping minutes between every pair of event streams over a window.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ConflictMatrix {
+    /// The streams' ids, in input order: the rows and columns of
+    /// `overlap_minutes`.
+    pub stream_ids: Vec<String>,
+    /// `overlap_minutes[i][j]`: minutes in the window when streams `i` and
+    /// `j` are both busy. Symmetric; the diagonal holds each stream's own
+    /// busy minutes.
+    pub overlap_minutes: Vec<Vec<i64>>,
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+}
+
+impl ConflictMatrix {
+    /// Overlapping minutes between the streams with ids `a` and `b`, or
+    /// `None` if either is not in the matrix.
+    pub fn overlap(&self, a: &str, b: &str) -> Option<i64> {
+        let index = |id| self.stream_ids.iter().position(|s| s == id);
+        Some(self.overlap_minutes[index(a)?][index(b)?])
+    }
+
+    /// Pairs of distinct streams that overlap, as `(a, b, minutes)` with
+    /// `a` before `b` in input order, most overlap first.
+    pub fn clashes(&self) -> Vec<(String, String, i64)> {
+        let mut pairs = Vec::new();
+        for (i, row) in self.overlap_minutes.iter().enumerate() {
+            for (j, &minutes) in row.iter().enumerate().skip(i + 1) {
+                if minutes > 0 {
+                    pairs.push((
+                        self.stream_ids[i].clone(),
+                        self.stream_ids[j].clone(),
+                        minutes,
+                    ));
+                }
+            }
+        }
+        pairs.sort_by_key(|pair| std::cmp::Reverse(pair.2));
+        pairs
+    }
+}
+
+/// Overlapping minutes between every pair of `streams` within
+/// `[window_start, window_end)`.
+///
+/// Each stream's events are merged first, so events overlapping within one
+/// stream are not counted twice: an entry is the time both streams are
+/// busy, not the sum of [`find_conflicts`] overlaps.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::conflict::conflict_matrix;
+/// use truth_engine::{EventStream, ExpandedEvent};
+///
+/// let at = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+/// let stream = |id: &str, hours: &[(u32, u32)]| EventStream {
+///     stream_id: id.to_string(),
+///     events: hours
+///         .iter()
+///         .map(|&(s, e)| ExpandedEvent::new(at(s), at(e)))
+///         .collect(),
+/// };
+/// let matrix = conflict_matrix(
+///     &[
+///         stream("alice", &[(9, 11)]),
+///         stream("bob", &[(10, 12)]),
+///         stream("carol", &[(13, 14)]),
+///     ],
+///     at(8),
+///     at(18),
+/// );
+/// assert_eq!(matrix.overlap("alice", "bob"), Some(60));
+/// assert_eq!(matrix.overlap("bob", "carol"), Some(0));
+/// assert_eq!(matrix.clashes(), vec![("alice".to_string(), "bob".to_string(), 60)]);
+/// ```
+pub fn conflict_matrix(
+    streams: &[EventStream],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> ConflictMatrix {
+    let busy: Vec<Vec<TimeInterval>> = streams
+        .iter()
+        .map(|s| merge_busy_periods(&s.events, window_start, window_end))
+        .collect();
+
+    let mut overlap_minutes = vec![vec![0; streams.len()]; streams.len()];
+    for i in 0..streams.len() {
+        for j in i..streams.len() {
+            let minutes = overlap(&busy[i], &busy[j]).num_minutes();
+            overlap_minutes[i][j] = minutes;
+            overlap_minutes[j][i] = minutes;
+        }
+    }
+
+    ConflictMatrix {
+        stream_ids: streams.iter().map(|s| s.stream_id.clone()).collect(),
+        overlap_minutes,
+        window_start,
+        window_end,
+    }
+}
+
+/// Total overlap of two sorted, disjoint interval lists.
+fn overlap(a: &[TimeInterval], b: &[TimeInterval]) -> chrono::Duration {
+    let (mut i, mut j) = (0, 0);
+    let mut total = chrono::Duration::zero();
+    while i < a.len() && j < b.len() {
+        if let Some(both) = a[i].intersect(&b[j]) {
+            total += both.duration();
+        }
+        if a[i].end < b[j].end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    total
+}
@@ -0,0 +1,298 @@
+//! A reusable engine context: configuration plus caches of parsed inputs.
+//!
+//! The free functions parse their timezone names and RRULEs on every call.
+//! Services making millions of calls against a handful of zones and rules
+//! can hold a [`TruthContext`] instead: it resolves each timezone name once,
+//! keeps compiled rules for repeat expansions, and carries the options
+//! (business calendar, anchors, fiscal and term calendars, locale) that
+//! would otherwise be passed to every call. Its methods mirror the free
+//! functions and return the same results.
+//!
+//! The context is `Send + Sync`; share one across threads behind an `Arc`.
+
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+use crate::error::{Result, TruthError};
+use crate::expander::{CompiledRule, ExpandedEvent, UntilOptions};
+use crate::freebusy::{self, FreeSlot};
+use crate::temporal::{
+    adjust_parsed, convert_with_fields, parse_datetime, parse_timezone, AdjustedTimestamp,
+    ConvertedDatetime, InputMode, OutputFields, ResolveOptions,
+};
+#[cfg(feature = "resolver")]
+use crate::temporal::{resolve_in_timezone, ResolvedDatetime};
+
+/// Compiled rules kept before the cache is cleared and starts over.
+const RULE_CACHE_CAPACITY: usize = 1024;
+
+/// Configuration and caches shared across engine calls.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::TruthContext;
+///
+/// let ctx = TruthContext::new();
+/// let converted = ctx.convert_timezone("2026-03-15T14:00:00Z", "America/New_York").unwrap();
+/// assert_eq!(converted.utc_offset, "-04:00");
+///
+/// # #[cfg(feature = "resolver")] {
+/// let anchor = Utc.with_ymd_and_hms(2026, 2, 17, 9, 0, 0).unwrap();
+/// let resolved = ctx.resolve_relative(anchor, "tomorrow at 3pm", "America/New_York").unwrap();
+/// assert_eq!(resolved.resolved_utc, "2026-02-18T20:00:00+00:00");
+/// # }
+///
+/// // The second expansion reuses the compiled rule.
+/// for _ in 0..2 {
+///     let events = ctx
+///         .expand_rrule("FREQ=WEEKLY;BYDAY=MO", "2026-03-02T09:00:00", 30, "America/New_York", None, Some(4))
+///         .unwrap();
+///     assert_eq!(events.len(), 4);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct TruthContext {
+    options: ResolveOptions,
+    input: InputMode,
+    zones: RwLock<HashMap<String, Tz>>,
+    rules: RwLock<HashMap<RuleKey, CompiledRule>>,
+}
+
+/// Everything that determines a compiled rule.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct RuleKey {
+    rrule: String,
+    dtstart: String,
+    timezone: Tz,
+    until: Option<String>,
+    count: Option<u32>,
+    exdates: Vec<String>,
+}
+
+impl TruthContext {
+    /// A context with default options and strict input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A context resolving expressions with `options`.
+    pub fn with_options(options: ResolveOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Accept datetime inputs per `input` (see [`InputMode`]).
+    pub fn with_input_mode(mut self, input: InputMode) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// The options expressions are resolved with.
+    pub fn options(&self) -> &ResolveOptions {
+        &self.options
+    }
+
+    /// Resolve a timezone name or unambiguous abbreviation, as every method
+    /// taking a `timezone` does, parsing each distinct name once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidTimezone`] or
+    /// [`TruthError::AmbiguousTimezone`] as the free functions do.
+    pub fn timezone(&self, name: &str) -> Result<Tz> {
+        if let Some(tz) = read(&self.zones).get(name) {
+            return Ok(*tz);
+        }
+        let tz = parse_timezone(name)?;
+        write(&self.zones).insert(name.to_string(), tz);
+        Ok(tz)
+    }
+
+    /// [`convert_timezone`](crate::convert_timezone), in the context's input mode.
+    ///
+    /// # Errors
+    ///
+    /// As for [`convert_timezone`](crate::convert_timezone).
+    pub fn convert_timezone(&self, datetime: &str, timezone: &str) -> Result<ConvertedDatetime> {
+        let dt = parse_datetime(datetime, self.input)?;
+        let tz = self.timezone(timezone)?;
+        Ok(convert_with_fields(dt, &tz, OutputFields::Full))
+    }
+
+    /// [`convert_instants`](crate::convert_instants).
+    ///
+    /// # Errors
+    ///
+    /// As for [`convert_instants`](crate::convert_instants).
+    pub fn convert_instants(
+        &self,
+        instants: &[DateTime<Utc>],
+        timezone: &str,
+        fields: OutputFields,
+    ) -> Result<Vec<ConvertedDatetime>> {
+        let tz = self.timezone(timezone)?;
+        Ok(instants
+            .iter()
+            .map(|&dt| convert_with_fields(dt, &tz, fields))
+            .collect())
+    }
+
+    /// [`adjust_timestamp`](crate::adjust_timestamp), in the context's input
+    /// mode, with working time (`bh`, `bm`) counted on the business calendar
+    /// or working hours in its options.
+    ///
+    /// # Errors
+    ///
+    /// As for [`adjust_timestamp`](crate::adjust_timestamp).
+    pub fn adjust_timestamp(
+        &self,
+        datetime: &str,
+        adjustment: &str,
+        timezone: &str,
+    ) -> Result<AdjustedTimestamp> {
+        let dt = parse_datetime(datetime, self.input)?;
+        let tz = self.timezone(timezone)?;
+        let calendar = self.options.working_time(&tz);
+        adjust_parsed(datetime, dt, adjustment, &tz, Some(&calendar))
+    }
+
+    /// [`resolve_relative_with_options`](crate::resolve_relative_with_options)
+    /// with the context's options.
+    ///
+    /// # Errors
+    ///
+    /// As for [`resolve_relative`](crate::resolve_relative).
+    #[cfg(feature = "resolver")]
+    pub fn resolve_relative(
+        &self,
+        anchor: DateTime<Utc>,
+        expression: &str,
+        timezone: &str,
+    ) -> Result<ResolvedDatetime> {
+        let tz = self.timezone(timezone)?;
+        resolve_in_timezone(anchor, expression, &tz, &self.options)
+    }
+
+    /// [`expand_rrule`](crate::expand_rrule).
+    ///
+    /// # Errors
+    ///
+    /// As for [`expand_rrule`](crate::expand_rrule).
+    pub fn expand_rrule(
+        &self,
+        rrule: &str,
+        dtstart: &str,
+        duration_minutes: u32,
+        timezone: &str,
+        until: Option<&str>,
+        count: Option<u32>,
+    ) -> Result<Vec<ExpandedEvent>> {
+        self.expand_rrule_with_exdates(
+            rrule,
+            dtstart,
+            duration_minutes,
+            timezone,
+            until,
+            count,
+            &[],
+        )
+    }
+
+    /// [`expand_rrule_with_exdates`](crate::expand_rrule_with_exdates),
+    /// reusing the compiled rule when the same rule, DTSTART, timezone,
+    /// bounds, and EXDATEs were expanded before.
+    ///
+    /// # Errors
+    ///
+    /// As for [`expand_rrule_with_exdates`](crate::expand_rrule_with_exdates).
+    #[allow(clippy::too_many_arguments)]
+    pub fn expand_rrule_with_exdates(
+        &self,
+        rrule: &str,
+        dtstart: &str,
+        duration_minutes: u32,
+        timezone: &str,
+        until: Option<&str>,
+        count: Option<u32>,
+        exdates: &[&str],
+    ) -> Result<Vec<ExpandedEvent>> {
+        if rrule.is_empty() {
+            return Err(TruthError::InvalidRule("empty RRULE string".into()));
+        }
+        if count == Some(0) {
+            return Ok(Vec::new());
+        }
+        let tz = self.timezone(timezone)?;
+        let key = RuleKey {
+            rrule: rrule.to_string(),
+            dtstart: dtstart.to_string(),
+            timezone: tz,
+            until: until.map(str::to_string),
+            count,
+            exdates: exdates.iter().map(|d| d.to_string()).collect(),
+        };
+        if let Some(rule) = read(&self.rules).get(&key) {
+            return Ok(rule.expand(duration_minutes));
+        }
+        let rule = CompiledRule::compile(
+            rrule,
+            dtstart,
+            tz,
+            until,
+            count,
+            exdates,
+            &UntilOptions::default(),
+        )?;
+        let events = rule.expand(duration_minutes);
+        let mut rules = write(&self.rules);
+        if rules.len() >= RULE_CACHE_CAPACITY {
+            rules.clear();
+        }
+        rules.insert(key, rule);
+        Ok(events)
+    }
+
+    /// [`find_business_free_slots`](crate::freebusy::find_business_free_slots)
+    /// on the business calendar in the context's options.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidArguments`] if the options have no
+    /// business calendar, otherwise as for
+    /// [`find_business_free_slots`](crate::freebusy::find_business_free_slots).
+    pub fn find_business_free_slots(
+        &self,
+        events: &[ExpandedEvent],
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> Result<Vec<FreeSlot>> {
+        let calendar = self.options.business_calendar.as_ref().ok_or_else(|| {
+            TruthError::InvalidArguments("the context has no business calendar".to_string())
+        })?;
+        freebusy::find_business_free_slots(events, window_start, window_end, calendar)
+    }
+
+    /// Drop every cached timezone and compiled rule.
+    pub fn clear_caches(&self) {
+        write(&self.zones).clear();
+        write(&self.rules).clear();
+    }
+}
+
+// A panic while holding a lock cannot leave a cache half-updated (each
+// update is a single insert or clear), so poisoning is ignored.
+
+fn read<T>(lock: &RwLock<T>) -> std::sync::RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn write<T>(lock: &RwLock<T>) -> std::sync::RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(PoisonError::into_inner)
+}
@@ -1,7 +1,12 @@
 //! DST transition policies for recurring events.
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Policy for handling events that fall during DST transitions.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum DstPolicy {
     /// Skip instances that fall in the DST gap (e.g., 2:30 AM during spring forward)
     Skip,
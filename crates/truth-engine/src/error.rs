@@ -1,15 +1,28 @@
 //! Error types for truth-engine operations.
 
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum TruthError {
     #[error("Invalid RRULE: {0}")]
-    InvalidRule(String),
+    InvalidRule(ParseError),
 
     #[error("Invalid timezone: {0}")]
     InvalidTimezone(String),
 
+    #[error(
+        "Ambiguous timezone abbreviation '{abbreviation}': could be {}",
+        candidates.join(", ")
+    )]
+    AmbiguousTimezone {
+        abbreviation: String,
+        candidates: Vec<String>,
+    },
+
     #[error("Invalid datetime: {0}")]
     InvalidDatetime(String),
 
@@ -17,13 +30,209 @@ pub enum TruthError {
     InvalidDuration(String),
 
     #[error("Invalid expression: {0}")]
-    InvalidExpression(String),
+    InvalidExpression(ParseError),
+
+    #[error("Invalid format pattern: {0}")]
+    InvalidFormat(String),
+
+    #[error("Invalid iCalendar data: {0}")]
+    InvalidCalendar(String),
+
+    #[error("Invalid rotation: {0}")]
+    InvalidRotation(String),
+
+    #[error("Invalid fiscal calendar: {0}")]
+    InvalidFiscalCalendar(String),
+
+    #[error("Invalid term calendar: {0}")]
+    InvalidTermCalendar(String),
+
+    #[error("Invalid market calendar: {0}")]
+    InvalidMarket(String),
 
     #[error("Expansion error: {0}")]
     Expansion(String),
 
     #[error("Availability error: {0}")]
     Availability(String),
+
+    #[error("Booking error: {0}")]
+    Booking(String),
+
+    #[error("Slot conflict: {0}")]
+    SlotConflict(String),
+
+    #[error("Export error: {0}")]
+    Export(String),
+
+    #[error("Unknown tool: {0}")]
+    UnknownTool(String),
+
+    #[error("Invalid tool arguments: {0}")]
+    InvalidArguments(String),
+}
+
+impl TruthError {
+    /// Whose problem the error is, for mapping to a protocol's error codes.
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            TruthError::InvalidRule(_)
+            | TruthError::InvalidTimezone(_)
+            | TruthError::AmbiguousTimezone { .. }
+            | TruthError::InvalidDatetime(_)
+            | TruthError::InvalidDuration(_)
+            | TruthError::InvalidExpression(_)
+            | TruthError::InvalidCalendar(_)
+            | TruthError::Booking(_)
+            | TruthError::UnknownTool(_)
+            | TruthError::InvalidArguments(_) => ErrorClass::Input,
+            TruthError::InvalidFormat(_)
+            | TruthError::InvalidRotation(_)
+            | TruthError::InvalidFiscalCalendar(_)
+            | TruthError::InvalidTermCalendar(_)
+            | TruthError::InvalidMarket(_) => ErrorClass::Configuration,
+            TruthError::SlotConflict(_) => ErrorClass::Conflict,
+            TruthError::Expansion(_) | TruthError::Availability(_) | TruthError::Export(_) => {
+                ErrorClass::Internal
+            }
+        }
+    }
+
+    /// Whether the same call may succeed if retried unchanged. The engine is
+    /// deterministic, so only slot conflicts qualify: the booking that holds
+    /// the slot may be released or expire. Other booking errors (an unknown
+    /// or taken id, a lapsed hold) repeat for the same call.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, TruthError::SlotConflict(_))
+    }
+
+    /// Where in the input an RRULE or expression failed to parse, when the
+    /// engine could tell.
+    pub fn span(&self) -> Option<&ParseSpan> {
+        match self {
+            TruthError::InvalidRule(e) | TruthError::InvalidExpression(e) => e.span.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Who has to act on a [`TruthError`]: the caller, whoever configured the
+/// calendars, formats, and markets the engine was set up with, or the
+/// engine's maintainers. Conflicts need nobody to act, only time or another
+/// caller to free the slot.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::error::ErrorClass;
+/// use truth_engine::expand_rrule;
+///
+/// let err = expand_rrule("FREQ=SOMETIMES", "2026-01-01T09:00:00", 30, "UTC", None, None)
+///     .unwrap_err();
+/// assert_eq!(err.class(), ErrorClass::Input);
+/// assert_eq!(err.class().http_status(), 400);
+/// assert!(!err.is_retryable());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ErrorClass {
+    /// The request was wrong: malformed or out-of-range arguments, or an
+    /// operation the current state does not allow.
+    Input,
+    /// A calendar, rotation, market, or format definition the engine was
+    /// given is invalid.
+    Configuration,
+    /// The engine could not complete a valid request.
+    Internal,
+    /// The request was valid, but the slot it asks for is taken by another
+    /// booking or busy in the caller's calendars.
+    Conflict,
+}
+
+impl ErrorClass {
+    /// The HTTP status for an error of this class: 400 for input errors,
+    /// 409 for conflicts, 500 otherwise.
+    pub fn http_status(self) -> u16 {
+        match self {
+            ErrorClass::Input => 400,
+            ErrorClass::Conflict => 409,
+            ErrorClass::Configuration | ErrorClass::Internal => 500,
+        }
+    }
+
+    /// The JSON-RPC error code, as used by MCP: -32602 (invalid params) for
+    /// input errors, -32000 (the first implementation-defined server error)
+    /// for conflicts, -32603 (internal error) otherwise.
+    pub fn json_rpc_code(self) -> i32 {
+        match self {
+            ErrorClass::Input => -32602,
+            ErrorClass::Conflict => -32000,
+            ErrorClass::Configuration | ErrorClass::Internal => -32603,
+        }
+    }
+}
+
+/// Why an RRULE or expression was rejected, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ParseError {
+    pub message: String,
+    /// The offending token, when the error can be pinned to one.
+    pub span: Option<ParseSpan>,
+}
+
+impl ParseError {
+    /// An error at `token`, which starts `offset` bytes into `input`.
+    pub(crate) fn at(message: impl Into<String>, input: &str, offset: usize, token: &str) -> Self {
+        Self {
+            message: message.into(),
+            span: Some(ParseSpan {
+                offset,
+                char_offset: input[..offset].chars().count(),
+                token: token.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if let Some(span) = &self.span {
+            write!(f, " (at character {}: '{}')", span.char_offset, span.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<String> for ParseError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            span: None,
+        }
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        message.to_string().into()
+    }
+}
+
+/// The offending token of a [`ParseError`] and where it starts in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ParseSpan {
+    /// Byte offset of the token.
+    pub offset: usize,
+    /// Character (Unicode scalar) offset of the token, for UIs.
+    pub char_offset: usize,
+    pub token: String,
 }
 
 pub type Result<T> = std::result::Result<T, TruthError>;
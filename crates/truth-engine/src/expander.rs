@@ -3,24 +3,93 @@
 //! Wraps the `rrule` crate (v0.14) and `chrono-tz` to provide deterministic expansion
 //! of RFC 5545 recurrence rules with correct DST handling.
 
-use crate::error::{Result, TruthError};
-use chrono::{DateTime, Duration, Utc};
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use crate::error::{ParseError, Result, TruthError};
+use crate::temporal::{format_short_in, ClockFormat, Locale};
+use crate::warnings::{Warned, Warning, WarningKind};
+use chrono::offset::LocalResult;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
 use rrule::RRuleSet;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+mod bitset;
 
 /// A single expanded event instance with start and end times.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Events expanded from a recurrence also say which occurrence they are, so
+/// callers can refer to a specific instance ("the 5th session, which was
+/// rescheduled"). Events built by hand, and those derived from several
+/// events (such as merged busy time), leave these fields at their defaults.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ExpandedEvent {
     pub start: DateTime<Utc>,
     pub end: DateTime<Utc>,
+    /// Position in the unmodified series (rule instances and RDATEs by
+    /// original start, from 0), which stays the same when other occurrences
+    /// are removed, moved, or cancelled. Set by expansions that count from
+    /// DTSTART; `None` for events that are not occurrences of a series, for
+    /// an override that matches no occurrence, and for windowed expansions
+    /// that skip ahead to the window.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub occurrence_index: Option<usize>,
+    /// The occurrence was added by an RDATE rather than the rule.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
+    pub is_rdate: bool,
+    /// The occurrence's times come from an override.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
+    pub is_override: bool,
+    /// Where the occurrence started before an override moved it; `None`
+    /// when it starts at its original time.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub original_start: Option<DateTime<Utc>>,
+}
+
+impl ExpandedEvent {
+    /// An event from `start` to `end` with no occurrence metadata.
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self {
+            start,
+            end,
+            ..Self::default()
+        }
+    }
+
+    /// The same event as occurrence `index` of a series.
+    fn occurrence(start: DateTime<Utc>, end: DateTime<Utc>, index: usize) -> Self {
+        Self {
+            occurrence_index: Some(index),
+            ..Self::new(start, end)
+        }
+    }
 }
 
+#[cfg(feature = "serde")]
+fn is_false(b: &bool) -> bool {
+    !b
+}
+
+/// Instances generated when neither `count` nor `until` bounds a series.
+const EXPANSION_LIMIT: u16 = 500;
+
 /// Expand an RRULE string into concrete datetime instances.
 ///
 /// # Arguments
 /// - `rrule` -- RFC 5545 RRULE string (e.g., "FREQ=WEEKLY;BYDAY=TU,TH")
 /// - `dtstart` -- Local datetime string (e.g., "2026-02-17T14:00:00")
 /// - `duration_minutes` -- Duration of each instance in minutes
-/// - `timezone` -- IANA timezone (e.g., "America/Los_Angeles")
+/// - `timezone` -- IANA timezone (e.g., "America/Los_Angeles") or unambiguous abbreviation
 /// - `until` -- Optional end boundary for expansion (local datetime string)
 /// - `count` -- Optional maximum number of instances (overrides COUNT in rrule)
 ///
@@ -55,14 +124,15 @@ pub fn expand_rrule(
 /// - `rrule` -- RFC 5545 RRULE string (e.g., "FREQ=WEEKLY;BYDAY=TU,TH")
 /// - `dtstart` -- Local datetime string (e.g., "2026-02-17T14:00:00")
 /// - `duration_minutes` -- Duration of each instance in minutes
-/// - `timezone` -- IANA timezone (e.g., "America/Los_Angeles")
+/// - `timezone` -- IANA timezone (e.g., "America/Los_Angeles") or unambiguous abbreviation
 /// - `until` -- Optional end boundary for expansion (local datetime string)
 /// - `count` -- Optional maximum number of instances (overrides COUNT in rrule)
 /// - `exdates` -- Slice of local datetime strings to exclude (same format as `dtstart`)
 ///
 /// # Errors
 /// Returns `TruthError::InvalidRule` if the RRULE string is empty or unparseable.
-/// Returns `TruthError::InvalidTimezone` if the timezone is not a valid IANA identifier.
+/// Returns `TruthError::InvalidTimezone` if the timezone is not a valid IANA identifier,
+/// or `TruthError::AmbiguousTimezone` if it is an abbreviation shared by several zones.
 pub fn expand_rrule_with_exdates(
     rrule: &str,
     dtstart: &str,
@@ -74,7 +144,7 @@ pub fn expand_rrule_with_exdates(
 ) -> Result<Vec<ExpandedEvent>> {
     // Validate inputs.
     if rrule.is_empty() {
-        return Err(TruthError::InvalidRule("empty RRULE string".to_string()));
+        return Err(TruthError::InvalidRule("empty RRULE string".into()));
     }
 
     // Short-circuit: caller explicitly wants zero instances.
@@ -82,47 +152,1475 @@ pub fn expand_rrule_with_exdates(
         return Ok(Vec::new());
     }
 
-    // Validate timezone by parsing it as a chrono-tz Tz.
-    let _tz: chrono_tz::Tz = timezone
-        .parse()
-        .map_err(|_| TruthError::InvalidTimezone(timezone.to_string()))?;
+    // Validate timezone by parsing it as a chrono-tz Tz. Abbreviations such as
+    // "PST" resolve to their IANA zone, whose name is what goes into TZID.
+    let tz = crate::temporal::parse_timezone(timezone)?;
+    let options = UntilOptions::default();
+    Ok(
+        CompiledRule::compile(rrule, dtstart, tz, until, count, exdates, &options)?
+            .expand(duration_minutes),
+    )
+}
+
+/// How an UNTIL bound is read.
+///
+/// RFC 5545 keeps an occurrence that starts exactly at UNTIL and wants UNTIL
+/// in UTC whenever DTSTART has a timezone, but calendar providers also write
+/// date-only and floating values and disagree on what they mean. Match the
+/// provider a rule came from so a synced series has the same occurrences on
+/// both sides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct UntilOptions {
+    /// Keep an occurrence that starts exactly at the bound (RFC 5545).
+    pub inclusive: bool,
+    /// Where in its day a date-only UNTIL ("20260430") falls.
+    pub date_value: UntilDate,
+    /// The zone of a date-only or floating UNTIL (one without "Z").
+    pub floating_zone: UntilZone,
+}
 
-    // Convert the dtstart from "2026-02-17T14:00:00" to iCalendar format "20260217T140000".
-    let dtstart_ical = dtstart.replace(['-', ':'], "");
+impl Default for UntilOptions {
+    fn default() -> Self {
+        Self {
+            inclusive: true,
+            date_value: UntilDate::EndOfDay,
+            floating_zone: UntilZone::Dtstart,
+        }
+    }
+}
+
+/// Where in its day a date-only UNTIL falls.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum UntilDate {
+    /// 23:59:59, so every occurrence on the date is kept.
+    #[default]
+    EndOfDay,
+    /// Midnight, so the series ends as the date begins.
+    StartOfDay,
+}
+
+/// The zone a date-only or floating UNTIL is local to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum UntilZone {
+    /// DTSTART's timezone.
+    #[default]
+    Dtstart,
+    /// UTC, as if the value ended in "Z".
+    Utc,
+}
+
+/// An UNTIL bound as it was applied to a rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AppliedUntil {
+    /// The UNTIL value from the rule, or the `until` argument in iCalendar
+    /// form ("20260430T170000").
+    pub value: String,
+    /// The instant the value was read as.
+    pub instant: DateTime<Utc>,
+    /// The options it was read with.
+    pub options: UntilOptions,
+}
+
+/// Occurrences from [`expand_rrule_with_until`], with the bound they stop at.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct UntilExpansion {
+    pub events: Vec<ExpandedEvent>,
+    /// The UNTIL bound, when the rule or the `until` argument has one.
+    pub until: Option<AppliedUntil>,
+}
+
+/// [`expand_rrule_with_exdates`] with UNTIL read as `options` says, for
+/// matching a provider's expansion of the same rule.
+///
+/// [`expand_rrule_with_exdates`] reads UNTIL with the default options.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::expander::{expand_rrule_with_until, UntilDate, UntilOptions};
+///
+/// let options = UntilOptions { date_value: UntilDate::StartOfDay, ..Default::default() };
+/// let expanded = expand_rrule_with_until(
+///     "FREQ=DAILY;UNTIL=20260305",
+///     "2026-03-01T09:00:00",
+///     30,
+///     "America/New_York",
+///     None,
+///     None,
+///     &[],
+///     &options,
+/// )
+/// .unwrap();
+/// // Midnight on March 5 comes before that day's 09:00 occurrence.
+/// assert_eq!(expanded.events.len(), 4);
+/// assert_eq!(expanded.until.unwrap().instant.to_rfc3339(), "2026-03-05T05:00:00+00:00");
+/// ```
+///
+/// # Errors
+///
+/// As for [`expand_rrule_with_exdates`].
+#[allow(clippy::too_many_arguments)]
+pub fn expand_rrule_with_until(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    until: Option<&str>,
+    count: Option<u32>,
+    exdates: &[&str],
+    options: &UntilOptions,
+) -> Result<UntilExpansion> {
+    if rrule.is_empty() {
+        return Err(TruthError::InvalidRule("empty RRULE string".into()));
+    }
+    let tz = crate::temporal::parse_timezone(timezone)?;
+    let rule = CompiledRule::compile(rrule, dtstart, tz, until, count, exdates, options)?;
+    let events = if count == Some(0) {
+        Vec::new()
+    } else {
+        rule.expand(duration_minutes)
+    };
+    Ok(UntilExpansion {
+        events,
+        until: rule.until,
+    })
+}
 
-    // Build the RRULE text block. We may need to inject COUNT or UNTIL.
-    let mut rrule_str = rrule.to_string();
+/// An RRULE parsed together with its DTSTART, timezone, EXDATEs, and any
+/// COUNT or UNTIL bound, ready to expand repeatedly.
+///
+/// Parsing dominates the cost of expanding a short series, so batch
+/// workloads compile each rule once. [`expand_into`](Self::expand_into)
+/// then appends occurrences to a reusable [`OccurrenceBuffer`] without
+/// allocating per call once the buffer has grown.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::expander::{CompiledRule, OccurrenceBuffer};
+///
+/// let rule = CompiledRule::new(
+///     "FREQ=WEEKLY;BYDAY=MO,WE,FR",
+///     "2026-03-02T09:00:00",
+///     "America/New_York",
+///     None,
+///     Some(6),
+///     &["2026-03-04T09:00:00"],
+/// )
+/// .unwrap();
+/// let mut buffer = OccurrenceBuffer::new();
+/// assert_eq!(rule.expand_into(&mut buffer, 30), 5);
+/// assert_eq!(buffer.ends[0] - buffer.starts[0], 30 * 60);
+/// let starts: Vec<_> = rule.expand(30).iter().map(|e| e.start.timestamp()).collect();
+/// assert_eq!(buffer.starts, starts);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompiledRule {
+    /// The rule without its EXDATEs, so instances keep their positions.
+    set: RRuleSet,
+    /// EXDATE instants as sorted Unix timestamps.
+    exdates: Vec<i64>,
+    /// Bitmask plan for the rule, when it is simple enough to have one.
+    fast: Option<bitset::FastRule>,
+    /// The UNTIL bound, as read when the rule was compiled.
+    until: Option<AppliedUntil>,
+    count: Option<u32>,
+    /// Most occurrences to generate.
+    limit: u16,
+}
 
-    // If the caller provides an external `count`, inject it into the RRULE
-    // (unless the RRULE already has a COUNT).
-    if let Some(c) = count {
-        if !rrule_str.to_uppercase().contains("COUNT=") {
-            rrule_str = format!("{};COUNT={}", rrule_str, c);
+impl CompiledRule {
+    /// Compile a rule with the arguments [`expand_rrule_with_exdates`] takes,
+    /// less the duration, which is given per expansion.
+    ///
+    /// # Errors
+    ///
+    /// As for [`expand_rrule_with_exdates`].
+    pub fn new(
+        rrule: &str,
+        dtstart: &str,
+        timezone: &str,
+        until: Option<&str>,
+        count: Option<u32>,
+        exdates: &[&str],
+    ) -> Result<Self> {
+        if rrule.is_empty() {
+            return Err(TruthError::InvalidRule("empty RRULE string".into()));
         }
+        let tz = crate::temporal::parse_timezone(timezone)?;
+        Self::compile(
+            rrule,
+            dtstart,
+            tz,
+            until,
+            count,
+            exdates,
+            &UntilOptions::default(),
+        )
     }
 
-    // If the caller provides an `until`, inject it into the RRULE.
-    // The rrule crate requires UNTIL and DTSTART to share the same timezone.
-    // For UTC, UNTIL must end with "Z"; for other timezones, use bare local time.
-    if let Some(until_str) = until {
-        if !rrule_str.to_uppercase().contains("UNTIL=") {
-            let mut until_ical = until_str.replace(['-', ':'], "");
-            if timezone == "UTC" {
-                until_ical.push('Z');
+    /// [`new`](Self::new) with the timezone already resolved.
+    pub(crate) fn compile(
+        rrule: &str,
+        dtstart: &str,
+        tz: Tz,
+        until: Option<&str>,
+        count: Option<u32>,
+        exdates: &[&str],
+        until_options: &UntilOptions,
+    ) -> Result<Self> {
+        // Convert the dtstart from "2026-02-17T14:00:00" to iCalendar format "20260217T140000".
+        let dtstart_ical = ical_local(dtstart);
+
+        // Build the RRULE text block. We may need to inject COUNT or UNTIL.
+        let mut rrule_str = rrule.to_string();
+
+        // If the caller provides an external `count`, inject it into the RRULE
+        // (unless the RRULE already has a COUNT).
+        if let Some(c) = count {
+            if !rrule_str.to_uppercase().contains("COUNT=") {
+                rrule_str = format!("{};COUNT={}", rrule_str, c);
+            }
+        }
+
+        // If the caller provides an `until`, inject it into the RRULE as a
+        // floating local time; `bound_until` then rewrites it in UTC.
+        if let Some(until_str) = until {
+            if !rrule_str.to_uppercase().contains("UNTIL=") {
+                rrule_str = format!("{};UNTIL={}", rrule_str, ical_local(until_str));
             }
-            rrule_str = format!("{};UNTIL={}", rrule_str, until_ical);
+        }
+        check_rrule_syntax(&rrule_str)?;
+        let (rrule_str, applied_until) = bound_until(&rrule_str, tz, until_options);
+
+        let set = rrule_set(&rrule_str, &dtstart_ical, tz, exdates)?;
+
+        // Determine the max count for expansion to prevent unbounded expansion.
+        // When we have exdates, we need a higher limit because the rrule crate's
+        // `.all(limit)` counts BEFORE exdate filtering, so we may need more raw
+        // instances to get `count` results after exclusion. Add exdate count as buffer.
+        let exdate_buffer = exdates.len() as u16;
+        let limit: u16 = count
+            .map(|c| (c as u16).saturating_add(exdate_buffer))
+            .unwrap_or(EXPANSION_LIMIT);
+
+        let mut excluded: Vec<i64> = set.get_exdate().iter().map(|d| d.timestamp()).collect();
+        excluded.sort_unstable();
+        Ok(Self {
+            fast: bitset::FastRule::new(&set, tz, &rrule_str),
+            set: set.set_exdates(Vec::new()).limit(),
+            exdates: excluded,
+            until: applied_until,
+            count,
+            limit,
+        })
+    }
+
+    /// The rule's UNTIL bound and how it was read, when it has one.
+    pub fn until(&self) -> Option<&AppliedUntil> {
+        self.until.as_ref()
+    }
+
+    /// Whether the rule expands on the bitmask fast path: a DAILY, WEEKLY,
+    /// or MONTHLY rule whose only parts besides INTERVAL, COUNT, UNTIL, and
+    /// WKST are BYDAY and (for MONTHLY) BYMONTHDAY.
+    pub fn is_accelerated(&self) -> bool {
+        self.fast.is_some()
+    }
+
+    /// The same rule expanded by the general `rrule` crate path, for
+    /// comparing the two (the results are identical).
+    pub fn without_acceleration(mut self) -> Self {
+        self.fast = None;
+        self
+    }
+
+    /// The rule's occurrences, each lasting `duration_minutes`, as
+    /// [`expand_rrule_with_exdates`] returns them.
+    ///
+    /// Each occurrence's `occurrence_index` counts the instances EXDATEs
+    /// removed, so it is its position in the unmodified series.
+    pub fn expand(&self, duration_minutes: u32) -> Vec<ExpandedEvent> {
+        let duration = Duration::minutes(duration_minutes as i64);
+        let event = |(index, start): (usize, i64)| {
+            let start = timestamp(start);
+            ExpandedEvent::occurrence(start, start + duration, index)
+        };
+        match &self.fast {
+            Some(fast) => fast
+                .occurrences()
+                .indexed()
+                .take(self.most())
+                .map(event)
+                .collect(),
+            None => self.instances().take(self.most()).map(event).collect(),
+        }
+    }
+
+    /// Append the rule's occurrences, each lasting `duration_minutes`, to
+    /// `buffer`, returning how many were added.
+    pub fn expand_into(&self, buffer: &mut OccurrenceBuffer, duration_minutes: u32) -> usize {
+        let duration = i64::from(duration_minutes) * 60;
+        let before = buffer.len();
+        let mut push = |start: i64| {
+            buffer.starts.push(start);
+            buffer.ends.push(start + duration);
+        };
+        match &self.fast {
+            Some(fast) => fast.occurrences().take(self.most()).for_each(push),
+            None => self
+                .instances()
+                .take(self.most())
+                .for_each(|(_, start)| push(start)),
+        }
+        buffer.len() - before
+    }
+
+    /// The `rrule` crate path's instances less EXDATEs, each with its
+    /// position among all the rule's instances.
+    fn instances(&self) -> impl Iterator<Item = (usize, i64)> + '_ {
+        let mut exdates = self.exdates.iter().peekable();
+        (&self.set)
+            .into_iter()
+            .map(|dt| dt.timestamp())
+            .enumerate()
+            .filter(move |&(_, start)| {
+                while exdates.next_if(|&&ex| ex < start).is_some() {}
+                exdates.peek() != Some(&&start)
+            })
+    }
+
+    /// Most occurrences to return: the caller's count, within the limit.
+    fn most(&self) -> usize {
+        match self.count {
+            Some(c) => (c as usize).min(self.limit as usize),
+            None => self.limit as usize,
         }
     }
+}
+
+/// Occurrences as parallel arrays of Unix timestamps in whole seconds
+/// (structure of arrays), for bulk expansion into reused storage.
+///
+/// Expansion appends; call [`clear`](Self::clear) to reuse the buffer, which
+/// keeps its capacity. Occurrence bounds are always whole seconds, so the
+/// timestamps are exact.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OccurrenceBuffer {
+    /// Start of each occurrence.
+    pub starts: Vec<i64>,
+    /// End of each occurrence, at the same index as its start.
+    pub ends: Vec<i64>,
+}
+
+impl OccurrenceBuffer {
+    /// An empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An empty buffer with room for `capacity` occurrences.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            starts: Vec::with_capacity(capacity),
+            ends: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Number of occurrences.
+    pub fn len(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Whether the buffer holds no occurrences.
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+
+    /// Remove every occurrence, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.starts.clear();
+        self.ends.clear();
+    }
+
+    /// The occurrence at `index`. The buffer holds only bounds, so the
+    /// event carries no occurrence metadata.
+    pub fn get(&self, index: usize) -> Option<ExpandedEvent> {
+        Some(ExpandedEvent::new(
+            timestamp(*self.starts.get(index)?),
+            timestamp(*self.ends.get(index)?),
+        ))
+    }
+
+    /// Every occurrence, in order, without occurrence metadata.
+    pub fn iter(&self) -> impl Iterator<Item = ExpandedEvent> + '_ {
+        self.starts
+            .iter()
+            .zip(&self.ends)
+            .map(|(&start, &end)| ExpandedEvent::new(timestamp(start), timestamp(end)))
+    }
+}
+
+/// An instant from Unix seconds produced by expansion, which are always in
+/// chrono's range.
+fn timestamp(seconds: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(seconds, 0).expect("expanded timestamps are in range")
+}
+
+/// [`expand_rrule_with_exdates`], also reporting what it would otherwise
+/// pass over silently:
+/// - [`WarningKind::Truncated`] when expansion stopped at its limit of 500
+///   instances with more to come (pass `count` or `until` to bound it).
+/// - [`WarningKind::DstShifted`] for an occurrence whose local time fell in a
+///   spring-forward gap and was moved past it, and
+///   [`WarningKind::DstAmbiguous`] for one whose local time occurs twice.
+///   Gaps are detected for rules without BYHOUR, BYMINUTE, or BYSECOND,
+///   whose occurrences all keep DTSTART's time of day.
+/// - [`WarningKind::UnmatchedExdate`] for an EXDATE that is not an
+///   occurrence of the rule, so excluded nothing.
+///
+/// # Errors
+/// As for [`expand_rrule_with_exdates`].
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::expander::expand_rrule_with_warnings;
+/// use truth_engine::warnings::WarningKind;
+///
+/// // US clocks spring forward at 02:00 on March 8, 2026.
+/// let expanded = expand_rrule_with_warnings(
+///     "FREQ=DAILY;COUNT=3",
+///     "2026-03-07T02:30:00",
+///     30,
+///     "America/New_York",
+///     None,
+///     None,
+///     &["2026-03-09T09:00:00"],
+/// )
+/// .unwrap();
+/// assert_eq!(expanded.result.len(), 3);
+/// let kinds: Vec<_> = expanded.warnings.iter().map(|w| w.kind).collect();
+/// assert_eq!(kinds, [WarningKind::DstShifted, WarningKind::UnmatchedExdate]);
+/// ```
+pub fn expand_rrule_with_warnings(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    until: Option<&str>,
+    count: Option<u32>,
+    exdates: &[&str],
+) -> Result<Warned<Vec<ExpandedEvent>>> {
+    let events = expand_rrule_with_exdates(
+        rrule,
+        dtstart,
+        duration_minutes,
+        timezone,
+        until,
+        count,
+        exdates,
+    )?;
+    let tz = crate::temporal::parse_timezone(timezone)?;
+    let start = parse_local(dtstart)?;
+    let mut warnings = Vec::new();
+
+    // Every instance the rule generates, before EXDATEs, and one more when
+    // unbounded, to see whether the limit cut the series short.
+    let probe = count.or(Some(EXPANSION_LIMIT as u32 + 1));
+    let mut generated = expand_rrule(rrule, dtstart, 0, timezone, until, probe)?;
+    let truncated = count.is_none() && generated.len() > EXPANSION_LIMIT as usize;
+    if truncated {
+        generated.truncate(EXPANSION_LIMIT as usize);
+        warnings.push(Warning::new(
+            WarningKind::Truncated,
+            format!(
+                "expansion stopped at {EXPANSION_LIMIT} instances; pass count or until to bound the series"
+            ),
+        ));
+    }
+
+    let upper = rrule.to_ascii_uppercase();
+    let fixed_time = !["BYHOUR=", "BYMINUTE=", "BYSECOND="]
+        .iter()
+        .any(|part| upper.contains(part))
+        && !["SECONDLY", "MINUTELY", "HOURLY"]
+            .iter()
+            .any(|freq| upper.contains(&format!("FREQ={freq}")));
+    for event in &events {
+        let local = event.start.with_timezone(&tz);
+        let naive = local.naive_local();
+        if fixed_time && naive.time() != start.time() {
+            warnings.push(Warning::new(
+                WarningKind::DstShifted,
+                format!(
+                    "{} {} does not exist in {} (clocks spring forward); the occurrence was moved to {}",
+                    naive.date(),
+                    start.time(),
+                    tz.name(),
+                    naive.time()
+                ),
+            ));
+        } else if let LocalResult::Ambiguous(earlier, later) = tz.from_local_datetime(&naive) {
+            let used = if local == earlier { "earlier" } else { "later" };
+            warnings.push(Warning::new(
+                WarningKind::DstAmbiguous,
+                format!(
+                    "{naive} occurs twice in {} (clocks fall back, {} then {}); the {used} was used",
+                    tz.name(),
+                    earlier.offset(),
+                    later.offset()
+                ),
+            ));
+        }
+    }
+
+    let generated_locals: Vec<NaiveDateTime> = generated
+        .iter()
+        .map(|g| g.start.with_timezone(&tz).naive_local())
+        .collect();
+    let last = generated_locals.last().copied();
+    for exdate in exdates {
+        let local = parse_local(exdate)?;
+        let beyond_limit = truncated && last.is_some_and(|last| local > last);
+        if !beyond_limit && !generated_locals.contains(&local) {
+            warnings.push(Warning::new(
+                WarningKind::UnmatchedExdate,
+                format!("EXDATE {exdate} is not an occurrence of the rule; it excluded nothing"),
+            ));
+        }
+    }
+
+    Ok(Warned {
+        result: events,
+        warnings,
+    })
+}
+
+/// An upcoming occurrence with a short human-readable label, from [`preview`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PreviewOccurrence {
+    pub start: DateTime<Utc>,
+    /// The start in the rule's timezone, as RFC 3339.
+    pub local: String,
+    /// The local start for display: "Tue, Mar 3 at 9:00 AM EST", with the
+    /// year added for occurrences in a later year than the first.
+    pub label: String,
+}
+
+/// The first `n` occurrences of a rule, each labeled for a confirmation
+/// dialog ("Tue, Mar 3 at 9:00 AM EST"), in English.
+///
+/// Arguments are as for [`expand_rrule`]; a COUNT or UNTIL in the rule
+/// still ends the series, so fewer than `n` may be returned.
+///
+/// # Errors
+/// As for [`expand_rrule`].
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::expander::preview;
+///
+/// let next = preview("FREQ=WEEKLY;BYDAY=TU,TH", "2026-03-03T09:00:00", "America/New_York", 3)
+///     .unwrap();
+/// let labels: Vec<_> = next.iter().map(|o| o.label.as_str()).collect();
+/// assert_eq!(
+///     labels,
+///     ["Tue, Mar 3 at 9:00 AM EST", "Thu, Mar 5 at 9:00 AM EST", "Tue, Mar 10 at 9:00 AM EDT"]
+/// );
+/// ```
+pub fn preview(
+    rrule: &str,
+    dtstart: &str,
+    timezone: &str,
+    n: u32,
+) -> Result<Vec<PreviewOccurrence>> {
+    preview_in(rrule, dtstart, timezone, n, Locale::English, None)
+}
+
+/// [`preview`] with labels in `locale`, with times on `clock` or, when
+/// `None`, the locale's [default clock](Locale::default_clock).
+///
+/// # Errors
+/// As for [`expand_rrule`].
+pub fn preview_in(
+    rrule: &str,
+    dtstart: &str,
+    timezone: &str,
+    n: u32,
+    locale: Locale,
+    clock: Option<ClockFormat>,
+) -> Result<Vec<PreviewOccurrence>> {
+    let tz = crate::temporal::parse_timezone(timezone)?;
+    let clock = clock.unwrap_or(locale.default_clock());
+    let events = expand_rrule(rrule, dtstart, 0, timezone, None, Some(n))?;
+    let first_year = events.first().map(|e| e.start.with_timezone(&tz).year());
+    Ok(events
+        .into_iter()
+        .map(|event| {
+            let local = event.start.with_timezone(&tz);
+            let with_year = Some(local.year()) != first_year;
+            PreviewOccurrence {
+                start: event.start,
+                local: local.to_rfc3339(),
+                label: format_short_in(&local, locale, clock, with_year),
+            }
+        })
+        .collect())
+}
+
+/// Occurrences of an RRULE overlapping `[window_start, window_end)`, however
+/// far the window is from DTSTART.
+///
+/// Unlike [`expand_rrule_with_exdates`], which counts instances from DTSTART
+/// and stops at its expansion limit, this skips straight to the window, so
+/// long-running series stay correct. Arguments are as for
+/// [`expand_rrule_with_exdates`].
+pub(crate) fn expand_rrule_between(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    exdates: &[&str],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Result<Vec<ExpandedEvent>> {
+    if rrule.is_empty() {
+        return Err(TruthError::InvalidRule("empty RRULE string".into()));
+    }
+    let tz = crate::temporal::parse_timezone(timezone)?;
+    let duration = Duration::minutes(duration_minutes as i64);
+    if window_start >= window_end {
+        return Ok(Vec::new());
+    }
+
+    // Instances starting up to one duration before the window still overlap it.
+    let utc = |dt: DateTime<Utc>| dt.with_timezone(&rrule::Tz::UTC);
+    let instances = rrule_set(rrule, &ical_local(dtstart), tz, exdates)?
+        .after(utc(window_start - duration))
+        .before(utc(window_end))
+        .all(u16::MAX);
+    Ok(instances
+        .dates
+        .into_iter()
+        .map(|dt| {
+            let start = dt.with_timezone(&Utc);
+            ExpandedEvent::new(start, start + duration)
+        })
+        .filter(|e| e.start < window_end && e.end > window_start)
+        .collect())
+}
+
+/// EXDATE and RDATE lists in canonical form, from [`normalize_exception_dates`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExceptionDates {
+    /// EXDATEs that each remove an occurrence, as local datetimes
+    /// (`"2026-03-09T09:00:00"`), sorted and without duplicates; ready for
+    /// [`expand_rrule_with_exdates`].
+    pub exdates: Vec<String>,
+    /// RDATEs that each add an occurrence the rule does not produce, as
+    /// local datetimes, sorted and without duplicates.
+    pub rdates: Vec<String>,
+}
+
+/// Check EXDATE and RDATE lists against a rule and return them in
+/// canonical form, with a [`Warning`] for each problem found.
+///
+/// Values may be local datetimes in the rule's timezone, in extended
+/// (`"2026-03-09T09:00:00"`) or iCalendar (`"20260309T090000"`) form; UTC
+/// times (`"20260309T140000Z"`) or RFC 3339 times with an offset, converted
+/// to the rule's timezone; or dates (`"2026-03-09"`, `"20260309"`). A date
+/// EXDATE removes every occurrence on that day; a date RDATE adds one at
+/// DTSTART's time of day.
+///
+/// Warnings:
+/// - [`WarningKind::InvalidDate`] for a value that does not parse; it is dropped.
+/// - [`WarningKind::UnmatchedExdate`] for an EXDATE that removes no
+///   occurrence; it is dropped.
+/// - [`WarningKind::DuplicateDate`] for a value that normalizes to one
+///   already listed, or an RDATE the rule already produces; it is dropped.
+/// - [`WarningKind::MixedValueTypes`], once per list, when a list mixes
+///   dates, local times, and UTC times, or holds dates at all (DTSTART is a
+///   date-time, and RFC 5545 requires the two to match).
+///
+/// # Errors
+/// As for [`expand_rrule`], and `TruthError::InvalidDatetime` if `dtstart`
+/// is not a local datetime.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::expander::normalize_exception_dates;
+/// use truth_engine::warnings::WarningKind;
+///
+/// let checked = normalize_exception_dates(
+///     "FREQ=WEEKLY;BYDAY=MO",
+///     "2026-03-02T09:00:00",
+///     "America/New_York",
+///     &["20260309T090000", "2026-03-09T09:00:00", "2026-03-10T09:00:00"],
+///     &["20260311T130000Z"],
+/// )
+/// .unwrap();
+/// assert_eq!(checked.result.exdates, ["2026-03-09T09:00:00"]);
+/// assert_eq!(checked.result.rdates, ["2026-03-11T09:00:00"]);
+/// let kinds: Vec<_> = checked.warnings.iter().map(|w| w.kind).collect();
+/// assert_eq!(kinds, [WarningKind::DuplicateDate, WarningKind::UnmatchedExdate]);
+/// ```
+pub fn normalize_exception_dates(
+    rrule: &str,
+    dtstart: &str,
+    timezone: &str,
+    exdates: &[&str],
+    rdates: &[&str],
+) -> Result<Warned<ExceptionDates>> {
+    if rrule.is_empty() {
+        return Err(TruthError::InvalidRule("empty RRULE string".into()));
+    }
+    let tz = crate::temporal::parse_timezone(timezone)?;
+    let start = parse_local(dtstart)?;
+    let mut warnings = Vec::new();
+    let exdates = parse_exception_list("EXDATE", exdates, &tz, &mut warnings);
+    let rdates = parse_exception_list("RDATE", rdates, &tz, &mut warnings);
+
+    // Every occurrence up to the latest date listed, before EXDATEs apply.
+    let latest = exdates
+        .iter()
+        .chain(&rdates)
+        .map(|(_, value)| match value {
+            ExceptionValue::Date(date) => date.and_time(NaiveTime::MIN) + Duration::days(1),
+            ExceptionValue::Local(local) => *local,
+        })
+        .max();
+    let set = rrule_set(rrule, &ical_local(dtstart), tz, &[])?;
+    let occurrences: Vec<NaiveDateTime> = match latest {
+        // Past the latest local time in any timezone.
+        Some(latest) => set
+            .before(
+                Utc.from_utc_datetime(&(latest + Duration::days(2)))
+                    .with_timezone(&rrule::Tz::UTC),
+            )
+            .all(u16::MAX)
+            .dates
+            .iter()
+            .map(|dt| dt.naive_local())
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let mut normalized = ExceptionDates::default();
+    let mut seen = BTreeSet::new();
+    for (raw, value) in exdates {
+        let removed: Vec<NaiveDateTime> = match value {
+            ExceptionValue::Local(local) => occurrences
+                .iter()
+                .copied()
+                .filter(|o| *o == local)
+                .collect(),
+            ExceptionValue::Date(date) => occurrences
+                .iter()
+                .copied()
+                .filter(|o| o.date() == date)
+                .collect(),
+        };
+        let mut new = false;
+        for occurrence in &removed {
+            new |= seen.insert(*occurrence);
+        }
+        if removed.is_empty() {
+            warnings.push(Warning::new(
+                WarningKind::UnmatchedExdate,
+                format!("EXDATE {raw} is not an occurrence of the rule; it was dropped"),
+            ));
+        } else if !new {
+            warnings.push(Warning::new(
+                WarningKind::DuplicateDate,
+                format!("EXDATE {raw} repeats an earlier EXDATE; it was dropped"),
+            ));
+        }
+    }
+    normalized.exdates = seen.into_iter().map(format_local).collect();
+
+    let mut seen = BTreeSet::new();
+    for (raw, value) in rdates {
+        let added = match value {
+            ExceptionValue::Local(local) => local,
+            ExceptionValue::Date(date) => date.and_time(start.time()),
+        };
+        let message = if occurrences.contains(&added) {
+            "is already an occurrence of the rule"
+        } else if !seen.insert(added) {
+            "repeats an earlier RDATE"
+        } else {
+            continue;
+        };
+        warnings.push(Warning::new(
+            WarningKind::DuplicateDate,
+            format!("RDATE {raw} {message}; it was dropped"),
+        ));
+    }
+    normalized.rdates = seen.into_iter().map(format_local).collect();
+
+    Ok(Warned {
+        result: normalized,
+        warnings,
+    })
+}
+
+/// A parsed EXDATE or RDATE value.
+#[derive(Debug, Clone, Copy)]
+enum ExceptionValue {
+    Date(NaiveDate),
+    /// A local time in the rule's timezone; UTC values are converted.
+    Local(NaiveDateTime),
+}
+
+/// Parse each value of an EXDATE or RDATE list, warning about values that
+/// do not parse and about mixed value types.
+fn parse_exception_list<'a>(
+    property: &str,
+    values: &[&'a str],
+    tz: &Tz,
+    warnings: &mut Vec<Warning>,
+) -> Vec<(&'a str, ExceptionValue)> {
+    // Value types seen: date, local date-time, UTC date-time.
+    let mut types = [false; 3];
+    let mut parsed = Vec::new();
+    for raw in values {
+        let value = raw.trim();
+        let local = |s: &str| {
+            NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S")
+                .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S"))
+                .ok()
+        };
+        let utc = value
+            .strip_suffix(['Z', 'z'])
+            .and_then(local)
+            .map(|naive| Utc.from_utc_datetime(&naive))
+            .or_else(|| {
+                DateTime::parse_from_rfc3339(value)
+                    .ok()
+                    .map(|dt| dt.with_timezone(&Utc))
+            });
+        let (kind, value) = if let Some(utc) = utc {
+            (
+                2,
+                ExceptionValue::Local(utc.with_timezone(tz).naive_local()),
+            )
+        } else if let Some(local) = local(value) {
+            (1, ExceptionValue::Local(local))
+        } else if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .or_else(|_| NaiveDate::parse_from_str(value, "%Y%m%d"))
+        {
+            (0, ExceptionValue::Date(date))
+        } else {
+            warnings.push(Warning::new(
+                WarningKind::InvalidDate,
+                format!("{property} {raw:?} is not a date or date-time; it was dropped"),
+            ));
+            continue;
+        };
+        types[kind] = true;
+        parsed.push((*raw, value));
+    }
+    let names: Vec<&str> = ["dates", "local date-times", "UTC date-times"]
+        .into_iter()
+        .zip(types)
+        .filter_map(|(name, seen)| seen.then_some(name))
+        .collect();
+    let problem = match names[..] {
+        [_, _, ..] => format!("mix {}", names.join(" and ")),
+        ["dates"] => "are dates for a series of date-times".to_string(),
+        _ => return parsed,
+    };
+    warnings.push(Warning::new(
+        WarningKind::MixedValueTypes,
+        format!(
+            "{property} values {problem}; all were normalized to local date-times in {}",
+            tz.name()
+        ),
+    ));
+    parsed
+}
+
+/// One series of a [`SeriesSplit`]: what to store as its RRULE, DTSTART, and
+/// EXDATEs. Duration and timezone are those of the series that was split.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SeriesPart {
+    pub rrule: String,
+    /// Local datetime, "YYYY-MM-DDTHH:MM:SS".
+    pub dtstart: String,
+    pub exdates: Vec<String>,
+}
+
+/// A recurring series split at one occurrence, for "this and following
+/// events" edits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SeriesSplit {
+    /// The original series, ending with the occurrence before the split.
+    /// `None` when splitting at the first occurrence: `following` then
+    /// replaces the whole series.
+    pub original: Option<SeriesPart>,
+    /// The split occurrence and every one after it, as a new series.
+    pub following: SeriesPart,
+}
+
+/// One occurrence taken out of its series, to be edited on its own.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct OccurrenceException {
+    /// The occurrence's original local start: its RECURRENCE-ID.
+    pub recurrence_id: String,
+    /// The occurrence as the series produced it, for the caller to edit.
+    pub event: ExpandedEvent,
+    /// The series' EXDATEs with `recurrence_id` added, so the series no
+    /// longer produces the occurrence (for stores without overrides).
+    pub exdates: Vec<String>,
+}
+
+/// Split a series at the occurrence starting at local time `at`.
+///
+/// Together the two series produce exactly the occurrences of the original:
+/// - A COUNT is divided between them: the original keeps the occurrences
+///   before `at`, the following series the rest.
+/// - Otherwise the original gets an UNTIL (in UTC) of its last occurrence
+///   before `at`, and the following series keeps the original's end.
+///
+/// EXDATEs go with the series whose occurrence they exclude. Arguments are
+/// as for [`expand_rrule_with_exdates`].
+///
+/// # Errors
+/// Returns `TruthError::InvalidRule` if the RRULE is empty or unparseable,
+/// or if `at` is not an occurrence of the series (including one removed by
+/// an EXDATE). Returns `TruthError::InvalidDatetime` if `dtstart`, `at`, or
+/// an EXDATE is not a local datetime.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::expander::split_series;
+///
+/// let split = split_series(
+///     "FREQ=WEEKLY;BYDAY=TU;COUNT=10",
+///     "2026-03-03T10:00:00",
+///     "America/New_York",
+///     &[],
+///     "2026-03-24T10:00:00",
+/// )
+/// .unwrap();
+/// assert_eq!(split.original.unwrap().rrule, "FREQ=WEEKLY;BYDAY=TU;COUNT=3");
+/// assert_eq!(split.following.rrule, "FREQ=WEEKLY;BYDAY=TU;COUNT=7");
+/// assert_eq!(split.following.dtstart, "2026-03-24T10:00:00");
+/// ```
+pub fn split_series(
+    rrule: &str,
+    dtstart: &str,
+    timezone: &str,
+    exdates: &[&str],
+    at: &str,
+) -> Result<SeriesSplit> {
+    let tz = crate::temporal::parse_timezone(timezone)?;
+    let occurrence = find_occurrence(rrule, dtstart, &tz, exdates, at)?;
+    let (before, after) = partition_exdates(exdates, occurrence.local)?;
+    let following_dtstart = format_local(occurrence.local);
+
+    let mut parts: Vec<&str> = rrule.split(';').filter(|part| !part.is_empty()).collect();
+    let count = parts
+        .iter()
+        .position(|part| part.to_uppercase().starts_with("COUNT="));
+    let (original_rule, following_rule) = match count {
+        Some(i) => {
+            let total: usize = parts[i][6..]
+                .parse()
+                .map_err(|_| TruthError::InvalidRule(format!("invalid {}", parts[i]).into()))?;
+            let kept = format!("COUNT={}", occurrence.index);
+            let rest = format!("COUNT={}", total - occurrence.index);
+            let mut original = parts.clone();
+            original[i] = &kept;
+            parts[i] = &rest;
+            (original.join(";"), parts.join(";"))
+        }
+        None => {
+            // UNTIL is in UTC whenever DTSTART has a TZID (RFC 5545 §3.3.10).
+            let until = occurrence
+                .previous
+                .unwrap_or_default()
+                .format("%Y%m%dT%H%M%SZ");
+            let mut original: Vec<String> = parts
+                .iter()
+                .filter(|part| !part.to_uppercase().starts_with("UNTIL="))
+                .map(|part| part.to_string())
+                .collect();
+            original.push(format!("UNTIL={until}"));
+            (original.join(";"), parts.join(";"))
+        }
+    };
+
+    Ok(SeriesSplit {
+        original: occurrence.previous.map(|_| SeriesPart {
+            rrule: original_rule,
+            dtstart: dtstart.to_string(),
+            exdates: before,
+        }),
+        following: SeriesPart {
+            rrule: following_rule,
+            dtstart: following_dtstart,
+            exdates: after,
+        },
+    })
+}
+
+/// Detach the occurrence starting at local time `at` from its series as a
+/// single-instance exception. Arguments are as for
+/// [`expand_rrule_with_exdates`].
+///
+/// # Errors
+/// As for [`split_series`].
+pub fn materialize_exception(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    exdates: &[&str],
+    at: &str,
+) -> Result<OccurrenceException> {
+    let tz = crate::temporal::parse_timezone(timezone)?;
+    let occurrence = find_occurrence(rrule, dtstart, &tz, exdates, at)?;
+    let recurrence_id = format_local(occurrence.local);
+    let mut all: Vec<String> = exdates.iter().map(|x| x.to_string()).collect();
+    all.push(recurrence_id.clone());
+    Ok(OccurrenceException {
+        recurrence_id,
+        event: ExpandedEvent::new(
+            occurrence.start,
+            occurrence.start + Duration::minutes(duration_minutes as i64),
+        ),
+        exdates: all,
+    })
+}
+
+/// A recurring series with its overridden occurrences, as calendar APIs
+/// return a series master and its RECURRENCE-ID instances.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RecurringSeries {
+    /// RFC 5545 RRULE, e.g. "FREQ=WEEKLY;BYDAY=TU,TH".
+    pub rrule: String,
+    /// Local datetime, "YYYY-MM-DDTHH:MM:SS".
+    pub dtstart: String,
+    pub duration_minutes: u32,
+    /// IANA timezone or unambiguous abbreviation of `dtstart`.
+    pub timezone: String,
+    /// Local datetimes of removed occurrences.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub exdates: Vec<String>,
+    /// Local datetimes of occurrences added outside the rule (RDATE).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rdates: Vec<String>,
+    /// Moved and cancelled occurrences.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub overrides: Vec<OccurrenceOverride>,
+}
+
+/// One occurrence of a [`RecurringSeries`] changed on its own.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct OccurrenceOverride {
+    /// The occurrence's original local start: its RECURRENCE-ID.
+    pub recurrence_id: String,
+    /// The occurrence's new times, or `None` when it is cancelled.
+    pub event: Option<ExpandedEvent>,
+}
+
+impl RecurringSeries {
+    /// The series' effective occurrences that overlap `[window_start,
+    /// window_end)`, sorted by start: the rule's occurrences less EXDATEs,
+    /// with each overridden occurrence replaced by its new times or, when
+    /// cancelled, removed.
+    ///
+    /// An override replaces the occurrence whose original start is its
+    /// RECURRENCE-ID wherever either one falls, so an occurrence moved into
+    /// the window is included and one moved out of it is not. As in
+    /// [`Calendar::expand`](crate::interop::ics::Calendar::expand), an
+    /// override that matches no occurrence still adds its event.
+    ///
+    /// Each occurrence carries its position in the series, whether an RDATE
+    /// added it, and whether an override changed it and from where.
+    ///
+    /// # Errors
+    ///
+    /// As for [`expand_rrule_with_exdates`]; also
+    /// [`TruthError::InvalidDatetime`] for a RECURRENCE-ID or RDATE that is
+    /// not a local datetime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use truth_engine::expander::{OccurrenceOverride, RecurringSeries};
+    /// use truth_engine::ExpandedEvent;
+    ///
+    /// let at = |d, h| Utc.with_ymd_and_hms(2026, 3, d, h, 0, 0).unwrap();
+    /// let standup = RecurringSeries {
+    ///     rrule: "FREQ=DAILY".to_string(),
+    ///     dtstart: "2026-03-02T09:00:00".to_string(),
+    ///     duration_minutes: 30,
+    ///     timezone: "UTC".to_string(),
+    ///     exdates: vec![],
+    ///     rdates: vec![],
+    ///     overrides: vec![
+    ///         OccurrenceOverride {
+    ///             recurrence_id: "2026-03-03T09:00:00".to_string(),
+    ///             event: Some(ExpandedEvent::new(at(3, 11), at(3, 12))),
+    ///         },
+    ///         OccurrenceOverride { recurrence_id: "2026-03-04T09:00:00".to_string(), event: None },
+    ///     ],
+    /// };
+    /// let occurrences = standup.occurrences(at(2, 0), at(5, 0)).unwrap();
+    /// let starts: Vec<_> = occurrences.iter().map(|o| o.start).collect();
+    /// assert_eq!(starts, [at(2, 9), at(3, 11)]);
+    /// // The second standup, moved from 09:00 to 11:00.
+    /// assert_eq!(occurrences[1].occurrence_index, Some(1));
+    /// assert!(occurrences[1].is_override);
+    /// assert_eq!(occurrences[1].original_start, Some(at(3, 9)));
+    /// ```
+    pub fn occurrences(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> Result<Vec<ExpandedEvent>> {
+        let tz = crate::temporal::parse_timezone(&self.timezone)?;
+        let local = |instant: DateTime<Utc>| instant.with_timezone(&tz).naive_local();
+        let length = Duration::minutes(i64::from(self.duration_minutes));
+        let overrides = self
+            .overrides
+            .iter()
+            .map(|o| Ok((parse_local(&o.recurrence_id)?, o)))
+            .collect::<Result<Vec<_>>>()?;
+        let rdates = self
+            .rdates
+            .iter()
+            .map(|r| parse_local(r))
+            .collect::<Result<BTreeSet<NaiveDateTime>>>()?;
+
+        // Expand far enough to place every override moved into the window.
+        let last = overrides
+            .iter()
+            .map(|(id, _)| *id)
+            .chain(Some(local(window_end)))
+            .max()
+            .unwrap_or_else(|| local(window_end));
+        let until = format_local(last);
+        let expand = |exdates: &[&str]| {
+            expand_rrule_with_exdates(
+                &self.rrule,
+                &self.dtstart,
+                self.duration_minutes,
+                &self.timezone,
+                Some(&until),
+                None,
+                exdates,
+            )
+        };
+        let exdates: Vec<&str> = self.exdates.iter().map(String::as_str).collect();
+        let kept: BTreeSet<DateTime<Utc>> = expand(&exdates)?.iter().map(|e| e.start).collect();
+        let rule_instances = expand(&[])?;
+
+        // The unmodified series: rule instances and RDATEs by original start.
+        let mut series: Vec<(NaiveDateTime, ExpandedEvent, bool)> = rule_instances
+            .into_iter()
+            .map(|e| (local(e.start), e, false))
+            .collect();
+        for &rdate in &rdates {
+            if series.iter().any(|(l, _, _)| *l == rdate) {
+                continue;
+            }
+            if let Some(start) = crate::temporal::local_to_utc(&tz, rdate) {
+                let event = ExpandedEvent::new(start, start + length);
+                series.push((rdate, event, true));
+            }
+        }
+        series.sort_by_key(|(_, e, _)| e.start);
+        let excluded: BTreeSet<NaiveDateTime> = self
+            .exdates
+            .iter()
+            .filter_map(|x| parse_local(x).ok())
+            .collect();
+
+        let mut occurrences = Vec::new();
+        let mut applied = BTreeSet::new();
+        for (index, (original, event, is_rdate)) in series.into_iter().enumerate() {
+            let removed = if is_rdate {
+                excluded.contains(&original)
+            } else {
+                !kept.contains(&event.start)
+            };
+            if removed {
+                continue;
+            }
+            let (event, original_start) = match overrides.iter().find(|(id, _)| *id == original) {
+                Some((_, o)) => {
+                    applied.insert(original);
+                    let Some(moved) = &o.event else { continue };
+                    (
+                        ExpandedEvent::new(moved.start, moved.end),
+                        Some(event.start),
+                    )
+                }
+                None => (event, None),
+            };
+            let is_override = original_start.is_some();
+            let original_start = original_start.filter(|start| *start != event.start);
+            occurrences.push(ExpandedEvent {
+                occurrence_index: Some(index),
+                is_rdate,
+                is_override,
+                original_start,
+                ..event
+            });
+        }
+        // Overrides that match no occurrence still add their event.
+        for (id, o) in &overrides {
+            let Some(event) = &o.event else { continue };
+            if applied.contains(id) {
+                continue;
+            }
+            occurrences.push(ExpandedEvent {
+                occurrence_index: None,
+                is_rdate: false,
+                is_override: true,
+                original_start: crate::temporal::local_to_utc(&tz, *id)
+                    .filter(|start| *start != event.start),
+                ..ExpandedEvent::new(event.start, event.end)
+            });
+        }
+        occurrences.retain(|o| o.start < window_end && window_start < o.end);
+        occurrences.sort_by_key(|o| (o.start, o.end));
+        Ok(occurrences)
+    }
+}
+
+/// Expand many series over one window in a single call.
+///
+/// Each series succeeds or fails on its own, so one malformed rule, date,
+/// or timezone does not discard the others. Results are returned in input
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::expander::{expand_series_batch, RecurringSeries};
+///
+/// let series = |rrule: &str| RecurringSeries {
+///     rrule: rrule.to_string(),
+///     dtstart: "2026-03-02T09:00:00".to_string(),
+///     duration_minutes: 30,
+///     timezone: "UTC".to_string(),
+///     exdates: vec![],
+///     rdates: vec![],
+///     overrides: vec![],
+/// };
+/// let window = (
+///     Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap(),
+/// );
+/// let results = expand_series_batch(
+///     &[series("FREQ=DAILY"), series("FREQ=SOMETIMES"), series("FREQ=WEEKLY")],
+///     window.0,
+///     window.1,
+/// );
+/// assert_eq!(results[0].as_ref().unwrap().len(), 7);
+/// assert!(results[1].is_err());
+/// assert_eq!(results[2].as_ref().unwrap().len(), 1);
+/// ```
+pub fn expand_series_batch(
+    series: &[RecurringSeries],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Vec<Result<Vec<ExpandedEvent>>> {
+    series
+        .iter()
+        .map(|s| s.occurrences(window_start, window_end))
+        .collect()
+}
+
+/// An occurrence located within its series' RRULE instances.
+struct Occurrence {
+    local: NaiveDateTime,
+    start: DateTime<Utc>,
+    /// How many RRULE instances (ignoring EXDATEs) come before it.
+    index: usize,
+    /// The start of the instance before it.
+    previous: Option<DateTime<Utc>>,
+}
+
+/// Find the occurrence starting at local time `at`; it must not be excluded.
+fn find_occurrence(
+    rrule: &str,
+    dtstart: &str,
+    tz: &chrono_tz::Tz,
+    exdates: &[&str],
+    at: &str,
+) -> Result<Occurrence> {
+    if rrule.is_empty() {
+        return Err(TruthError::InvalidRule("empty RRULE string".into()));
+    }
+    parse_local(dtstart)?;
+    let local = parse_local(at)?;
+    let not_found =
+        || TruthError::InvalidRule(format!("{at} is not an occurrence of the series").into());
+    let end = crate::temporal::local_to_utc(tz, local).ok_or_else(not_found)? + Duration::days(1);
+
+    // EXDATEs do not count towards COUNT, so locate among the raw instances.
+    let instances = rrule_set(rrule, &ical_local(dtstart), *tz, &[])?
+        .before(end.with_timezone(&rrule::Tz::UTC))
+        .all(u16::MAX)
+        .dates;
+    let locals: Vec<NaiveDateTime> = instances.iter().map(|dt| dt.naive_local()).collect();
+    let index = locals
+        .iter()
+        .position(|l| *l == local)
+        .ok_or_else(not_found)?;
+    if exdates.iter().any(|x| parse_local(x).ok() == Some(local)) {
+        return Err(TruthError::InvalidRule(
+            format!("{at} is removed from the series by an EXDATE").into(),
+        ));
+    }
+    Ok(Occurrence {
+        local,
+        start: instances[index].with_timezone(&Utc),
+        index,
+        previous: index
+            .checked_sub(1)
+            .map(|i| instances[i].with_timezone(&Utc)),
+    })
+}
+
+/// EXDATEs before local time `at`, and those at or after it.
+fn partition_exdates(exdates: &[&str], at: NaiveDateTime) -> Result<(Vec<String>, Vec<String>)> {
+    let mut before = Vec::new();
+    let mut after = Vec::new();
+    for exdate in exdates {
+        if parse_local(exdate)? < at {
+            before.push(exdate.to_string());
+        } else {
+            after.push(exdate.to_string());
+        }
+    }
+    Ok((before, after))
+}
+
+fn parse_local(datetime: &str) -> Result<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(datetime, "%Y-%m-%dT%H:%M:%S")
+        .map_err(|e| TruthError::InvalidDatetime(format!("{datetime}: {e}")))
+}
+
+fn format_local(datetime: NaiveDateTime) -> String {
+    datetime.format("%Y-%m-%dT%H:%M:%S").to_string()
+}
+
+/// "2026-02-17T14:00:00" → "20260217T140000".
+fn ical_local(datetime: &str) -> String {
+    datetime.replace(['-', ':'], "")
+}
+
+/// Rewrite the UNTIL part of `rrule` as the UTC instant `options` reads it
+/// as, which is the only form the `rrule` crate accepts. A value that does
+/// not parse is left as it is.
+fn bound_until(rrule: &str, tz: Tz, options: &UntilOptions) -> (String, Option<AppliedUntil>) {
+    let mut applied = None;
+    let parts: Vec<String> = rrule
+        .split(';')
+        .map(|part| {
+            let Some((name, value)) = part.split_once('=') else {
+                return part.to_string();
+            };
+            if !name.eq_ignore_ascii_case("UNTIL") {
+                return part.to_string();
+            }
+            let Some(instant) = until_instant(value, tz, options) else {
+                return part.to_string();
+            };
+            applied = Some(AppliedUntil {
+                value: value.to_string(),
+                instant,
+                options: *options,
+            });
+            let last_start = if options.inclusive {
+                instant
+            } else {
+                instant - Duration::seconds(1)
+            };
+            format!("{name}={}", last_start.format("%Y%m%dT%H%M%SZ"))
+        })
+        .collect();
+    (parts.join(";"), applied)
+}
+
+/// The instant an UNTIL value stands for under `options`.
+fn until_instant(value: &str, tz: Tz, options: &UntilOptions) -> Option<DateTime<Utc>> {
+    let upper = value.to_ascii_uppercase();
+    if let Some(utc) = upper.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(naive.and_utc());
+    }
+    let naive = match NaiveDateTime::parse_from_str(&upper, "%Y%m%dT%H%M%S") {
+        Ok(naive) => naive,
+        Err(_) => {
+            let date = NaiveDate::parse_from_str(&upper, "%Y%m%d").ok()?;
+            let time = match options.date_value {
+                UntilDate::EndOfDay => NaiveTime::from_hms_opt(23, 59, 59)?,
+                UntilDate::StartOfDay => NaiveTime::MIN,
+            };
+            date.and_time(time)
+        }
+    };
+    match options.floating_zone {
+        UntilZone::Dtstart => crate::temporal::local_to_utc(&tz, naive),
+        UntilZone::Utc => Some(naive.and_utc()),
+    }
+}
+
+/// Parse DTSTART (iCalendar local form) in `timezone`, the RRULE, and any
+/// EXDATEs (as given to [`expand_rrule_with_exdates`]) into an [`RRuleSet`].
+fn rrule_set(rrule: &str, dtstart_ical: &str, tz: Tz, exdates: &[&str]) -> Result<RRuleSet> {
+    check_rrule_syntax(rrule)?;
+    let rrule = numbered_byday(rrule)?;
+    let (rrule, _) = bound_until(&rrule, tz, &UntilOptions::default());
+    let timezone = tz.name();
 
     // Build the full iCalendar RRULE text with DTSTART and optional EXDATE lines.
     let mut rrule_text = format!(
         "DTSTART;TZID={}:{}\nRRULE:{}",
-        timezone, dtstart_ical, rrule_str
+        timezone, dtstart_ical, rrule
     );
 
     // Append EXDATE lines if any exclusion dates were provided.
     if !exdates.is_empty() {
-        let exdate_icals: Vec<String> = exdates.iter().map(|d| d.replace(['-', ':'], "")).collect();
+        let exdate_icals: Vec<String> = exdates.iter().map(|d| ical_local(d)).collect();
         rrule_text.push_str(&format!(
             "\nEXDATE;TZID={}:{}",
             timezone,
@@ -130,41 +1628,224 @@ pub fn expand_rrule_with_exdates(
         ));
     }
 
-    // Parse and expand.
-    let rrule_set: RRuleSet = rrule_text
+    rrule_text
         .parse()
-        .map_err(|e| TruthError::InvalidRule(format!("{}", e)))?;
-
-    // Determine the max count for expansion to prevent unbounded expansion.
-    // When we have exdates, we need a higher limit because the rrule crate's
-    // `.all(limit)` counts BEFORE exdate filtering, so we may need more raw
-    // instances to get `count` results after exclusion. Add exdate count as buffer.
-    let exdate_buffer = exdates.len() as u16;
-    let max_count: u16 = count
-        .map(|c| (c as u16).saturating_add(exdate_buffer))
-        .unwrap_or(500);
-
-    let instances = rrule_set.all(max_count);
-    let duration = Duration::minutes(duration_minutes as i64);
+        .map_err(|e| TruthError::InvalidRule(format!("{}", e).into()))
+}
 
-    let mut events: Vec<ExpandedEvent> = instances
-        .dates
-        .into_iter()
-        .map(|dt| {
-            let start_utc: DateTime<Utc> = dt.with_timezone(&Utc);
-            ExpandedEvent {
-                start: start_utc,
-                end: start_utc + duration,
+/// Check the syntax of each RRULE part, so a malformed part or value is
+/// reported with its position. Rules that parse but are inconsistent (e.g.,
+/// BYWEEKNO outside a YEARLY rule) are left to the `rrule` crate.
+fn check_rrule_syntax(rrule: &str) -> Result<()> {
+    let error = |message: String, offset: usize, token: &str| {
+        TruthError::InvalidRule(ParseError::at(message, rrule, offset, token))
+    };
+    let mut seen: Vec<String> = Vec::new();
+    let mut offset = 0;
+    for part in rrule.split(';') {
+        let start = offset;
+        offset += part.len() + 1;
+        if part.is_empty() {
+            continue;
+        }
+        let Some((name, value)) = part.split_once('=') else {
+            return Err(error(
+                format!("expected NAME=VALUE, got '{part}'"),
+                start,
+                part,
+            ));
+        };
+        let upper = name.to_ascii_uppercase();
+        if seen.contains(&upper) {
+            return Err(error(format!("{name} appears more than once"), start, part));
+        }
+        let value_start = start + name.len() + 1;
+        let bad_value = |why: &str| error(format!("{why} in {name}"), value_start, value);
+        let list = |min: i64, max: i64, signed: bool| -> Result<()> {
+            let mut item_start = value_start;
+            for item in value.split(',') {
+                let n = item
+                    .strip_prefix(['+', '-'])
+                    .filter(|_| signed)
+                    .unwrap_or(item);
+                let valid = !n.is_empty()
+                    && n.chars().all(|c| c.is_ascii_digit())
+                    && n.parse::<i64>().is_ok_and(|n| (min..=max).contains(&n));
+                if !valid {
+                    let range = if signed {
+                        format!("±{min} to ±{max}")
+                    } else {
+                        format!("{min} to {max}")
+                    };
+                    return Err(error(
+                        format!("'{item}' is not {range} in {name}"),
+                        item_start,
+                        item,
+                    ));
+                }
+                item_start += item.len() + 1;
             }
-        })
-        .collect();
+            Ok(())
+        };
+        match upper.as_str() {
+            "FREQ" => {
+                const FREQS: [&str; 7] = [
+                    "SECONDLY", "MINUTELY", "HOURLY", "DAILY", "WEEKLY", "MONTHLY", "YEARLY",
+                ];
+                if !FREQS.contains(&value.to_ascii_uppercase().as_str()) {
+                    return Err(bad_value("unknown frequency"));
+                }
+            }
+            "INTERVAL" | "COUNT" => {
+                if !value.chars().all(|c| c.is_ascii_digit()) || value.parse::<u32>().is_err() {
+                    return Err(bad_value("expected a whole number"));
+                }
+            }
+            "UNTIL" => {
+                let digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+                let date_time = value.strip_suffix(['Z', 'z']).unwrap_or(value);
+                let valid = match date_time.split_once(['T', 't']) {
+                    Some((date, time)) => {
+                        date.len() == 8 && digits(date) && time.len() == 6 && digits(time)
+                    }
+                    None => value.len() == 8 && digits(value),
+                };
+                if !valid {
+                    return Err(bad_value("expected YYYYMMDD or YYYYMMDDTHHMMSS[Z]"));
+                }
+            }
+            "WKST" => {
+                if !is_weekday_code(value) {
+                    return Err(bad_value("unknown weekday"));
+                }
+            }
+            "BYDAY" => {
+                let mut item_start = value_start;
+                for item in value.split(',') {
+                    let split = item.len().saturating_sub(2);
+                    let (ordinal, day) = item.split_at(split);
+                    let n = ordinal.strip_prefix(['+', '-']).unwrap_or(ordinal);
+                    let valid_ordinal = ordinal.is_empty()
+                        || (!n.is_empty()
+                            && n.chars().all(|c| c.is_ascii_digit())
+                            && n.parse::<u32>().is_ok_and(|n| (1..=53).contains(&n)));
+                    if !item.is_char_boundary(split) || !is_weekday_code(day) || !valid_ordinal {
+                        return Err(error(
+                            format!("'{item}' is not a weekday (e.g., MO, 2TU, -1FR) in BYDAY"),
+                            item_start,
+                            item,
+                        ));
+                    }
+                    item_start += item.len() + 1;
+                }
+            }
+            "BYMONTH" => list(1, 12, false)?,
+            "BYMONTHDAY" => list(1, 31, true)?,
+            "BYYEARDAY" | "BYSETPOS" => list(1, 366, true)?,
+            "BYWEEKNO" => list(1, 53, true)?,
+            "BYHOUR" => list(0, 23, false)?,
+            "BYMINUTE" => list(0, 59, false)?,
+            "BYSECOND" => list(0, 60, false)?,
+            _ => {
+                return Err(error(format!("unknown RRULE part '{name}'"), start, name));
+            }
+        }
+        seen.push(upper);
+    }
+    Ok(())
+}
+
+/// Rewrite BYDAY for the `rrule` crate when it mixes numbered ("1MO") and
+/// plain ("FR") entries, and reject numbered entries RFC 5545 does not allow.
+///
+/// RFC 5545 reads "1MO,FR" as the first Monday and every Friday, but the
+/// crate intersects the two kinds and the rule matches nothing. A plain
+/// weekday is every numbered occurrence of it in the month (1 to 5), or in
+/// the year (1 to 53) for a YEARLY rule without BYMONTH, so each one is
+/// spelled out that way. Numbered entries outside MONTHLY and YEARLY rules,
+/// or next to BYWEEKNO, would otherwise expand to unrelated days.
+fn numbered_byday(rrule: &str) -> Result<Cow<'_, str>> {
+    let mut freq = String::new();
+    let mut has_by_month = false;
+    let mut has_by_week_no = false;
+    let mut byday = None;
+    let mut offset = 0;
+    for part in rrule.split(';') {
+        let start = offset;
+        offset += part.len() + 1;
+        let Some((name, value)) = part.split_once('=') else {
+            continue;
+        };
+        match name.to_ascii_uppercase().as_str() {
+            "FREQ" => freq = value.to_ascii_uppercase(),
+            "BYMONTH" => has_by_month = true,
+            "BYWEEKNO" => has_by_week_no = true,
+            "BYDAY" => byday = Some((start, part, start + name.len() + 1, value)),
+            _ => {}
+        }
+    }
+    let Some((part_start, part, value_start, value)) = byday else {
+        return Ok(Cow::Borrowed(rrule));
+    };
+    let is_numbered = |item: &str| item.len() > 2;
+    let Some(first_numbered) = value.split(',').find(|item| is_numbered(item)) else {
+        return Ok(Cow::Borrowed(rrule));
+    };
+
+    let numbered_offset = value_start + value.find(first_numbered).unwrap_or(0);
+    let reject = |message: &str| {
+        TruthError::InvalidRule(ParseError::at(
+            message,
+            rrule,
+            numbered_offset,
+            first_numbered,
+        ))
+    };
+    let max = match freq.as_str() {
+        "MONTHLY" => 5,
+        "YEARLY" if has_by_week_no => {
+            return Err(reject(
+                "numbered BYDAY values cannot be combined with BYWEEKNO",
+            ))
+        }
+        "YEARLY" if has_by_month => 5,
+        "YEARLY" => 53,
+        _ => {
+            return Err(reject(
+                "numbered BYDAY values are allowed only in MONTHLY and YEARLY rules",
+            ))
+        }
+    };
+    if value.split(',').all(is_numbered) {
+        return Ok(Cow::Borrowed(rrule));
+    }
 
-    // If the caller specified an external count limit, truncate to that many results.
-    // (EXDATE filtering by the rrule crate may have already reduced the count, but
-    // the `.all()` limit is a pre-filter cap, not a post-filter cap.)
-    if let Some(c) = count {
-        events.truncate(c as usize);
+    let mut items: Vec<String> = Vec::new();
+    for item in value.split(',') {
+        let spelled: Vec<String> = if is_numbered(item) {
+            vec![item.to_ascii_uppercase()]
+        } else {
+            let day = item.to_ascii_uppercase();
+            (1..=max).map(|n| format!("{n}{day}")).collect()
+        };
+        for item in spelled {
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
     }
+    let part_end = part_start + part.len();
+    Ok(Cow::Owned(format!(
+        "{}BYDAY={}{}",
+        &rrule[..part_start],
+        items.join(","),
+        &rrule[part_end..]
+    )))
+}
 
-    Ok(events)
+/// Whether `s` is a two-letter RFC 5545 weekday ("MO" to "SU").
+fn is_weekday_code(s: &str) -> bool {
+    ["MO", "TU", "WE", "TH", "FR", "SA", "SU"]
+        .iter()
+        .any(|d| d.eq_ignore_ascii_case(s))
 }
@@ -0,0 +1,352 @@
+//! Bitmask expansion for common DAILY, WEEKLY, and MONTHLY rules.
+//!
+//! The `rrule` crate builds every period's candidate days and filters them
+//! through each BYxxx part in turn. When the only filters are BYDAY and
+//! BYMONTHDAY, a period's matching days can instead be computed in a few word
+//! operations, as libical does: a week is a 7-bit mask of weekdays and a
+//! month a 31-bit mask of days, and iterating a period walks its set bits.
+//!
+//! [`FastRule`] reproduces the crate's output exactly: local times in DST
+//! gaps and folds resolve the same way, expansion starts at DTSTART and stops
+//! at UNTIL or COUNT (which EXDATEs do not replenish), and a rule that never
+//! matches gives up after as many empty periods. Rules with any other part
+//! stay on the general path.
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use chrono_tz::Tz;
+use rrule::{Frequency, NWeekday, RRule, RRuleSet};
+
+/// Consecutive periods without an occurrence after which the `rrule` crate
+/// stops expanding; mirrored so both paths end together.
+const MAX_EMPTY_PERIODS: u32 = 100_000;
+
+/// Last year the `rrule` crate expands into.
+const MAX_YEAR: i32 = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Period {
+    Day,
+    Week,
+    Month,
+}
+
+/// A rule simple enough to expand with day bitmasks.
+#[derive(Debug, Clone)]
+pub(super) struct FastRule {
+    period: Period,
+    /// Days, weeks, or months between periods.
+    interval: u32,
+    tz: Tz,
+    /// DTSTART as local time.
+    start: NaiveDateTime,
+    /// DTSTART as a Unix timestamp.
+    start_ts: i64,
+    /// Days from Monday to the first day of a week (WKST).
+    week_start: u32,
+    /// BYDAY weekdays without an ordinal, bit 0 for Monday.
+    weekdays: u8,
+    /// BYDAY weekdays with an ordinal, as (ordinal, days from Monday). Like
+    /// the `rrule` crate, a day must match these and `weekdays` (when each
+    /// is given), as well as `month_days`.
+    ordinals: Vec<(i32, u32)>,
+    /// BYMONTHDAY days, negative ones counting from the month's end.
+    month_days: Vec<i32>,
+    count: Option<u32>,
+    until: Option<i64>,
+    /// EXDATE timestamps, sorted.
+    exdates: Vec<i64>,
+}
+
+impl FastRule {
+    /// The fast plan for `set` (parsed from `rrule` in `tz`), if every part
+    /// of the rule is one it handles.
+    pub(super) fn new(set: &RRuleSet, tz: Tz, rrule: &str) -> Option<Self> {
+        let [rule] = set.get_rrule().as_slice() else {
+            return None;
+        };
+        if !set.get_rdate().is_empty() || !set.get_exrule().is_empty() {
+            return None;
+        }
+        let dtstart = set.get_dt_start();
+        let start = dtstart.naive_local();
+        let period = match rule.get_freq() {
+            Frequency::Daily => Period::Day,
+            Frequency::Weekly => Period::Week,
+            Frequency::Monthly => Period::Month,
+            _ => return None,
+        };
+        if rule.get_interval() == 0 || !keeps_start_time(rule, start.time()) {
+            return None;
+        }
+        if !rule.get_by_set_pos().is_empty()
+            || !rule.get_by_month().is_empty()
+            || !rule.get_by_year_day().is_empty()
+            || !rule.get_by_week_no().is_empty()
+        {
+            return None;
+        }
+
+        // Negative BYMONTHDAY values have no getter, so read the rule text.
+        let by_month_day = raw_month_days(rrule);
+        let mut weekdays = 0u8;
+        let mut ordinals = Vec::new();
+        for day in rule.get_by_weekday() {
+            match *day {
+                NWeekday::Every(weekday) => weekdays |= 1 << weekday.num_days_from_monday(),
+                NWeekday::Nth(n, weekday) if period == Period::Month => {
+                    ordinals.push((i32::from(n), weekday.num_days_from_monday()));
+                }
+                NWeekday::Nth(..) => return None,
+            }
+        }
+        let month_days = match period {
+            Period::Month => by_month_day.unwrap_or_else(|| {
+                // With neither BYDAY nor BYMONTHDAY, the crate fills in
+                // DTSTART's day.
+                rule.get_by_month_day()
+                    .iter()
+                    .map(|&d| i32::from(d))
+                    .collect()
+            }),
+            _ if by_month_day.is_some() => return None,
+            _ => Vec::new(),
+        };
+        if period == Period::Day && weekdays == 0 {
+            weekdays = 0x7f;
+        }
+
+        let mut exdates: Vec<i64> = set.get_exdate().iter().map(|d| d.timestamp()).collect();
+        exdates.sort_unstable();
+        Some(Self {
+            period,
+            interval: u32::from(rule.get_interval()),
+            tz,
+            start,
+            start_ts: dtstart.timestamp(),
+            week_start: rule.get_week_start().num_days_from_monday(),
+            weekdays,
+            ordinals,
+            month_days,
+            count: rule.get_count(),
+            until: rule.get_until().map(|u| u.timestamp()),
+            exdates,
+        })
+    }
+
+    /// Occurrence start timestamps, in order.
+    pub(super) fn occurrences(&self) -> Occurrences<'_> {
+        let date = self.start.date();
+        let first = match self.period {
+            Period::Day => date,
+            Period::Week => {
+                let into_week = (date.weekday().num_days_from_monday() + 7 - self.week_start) % 7;
+                date - Days::new(u64::from(into_week))
+            }
+            Period::Month => date.with_day(1).expect("every month has a first day"),
+        };
+        Occurrences {
+            rule: self,
+            period: Some(first),
+            days: 0,
+            base: first,
+            remaining: self.count,
+            empty: 0,
+            exdate: 0,
+            seen: 0,
+            done: self.count == Some(0),
+        }
+    }
+
+    /// Days of the period starting on `first` that match, bit `i` for the
+    /// day `i` days after `first`.
+    fn mask(&self, first: NaiveDate) -> u32 {
+        match self.period {
+            Period::Day => u32::from(self.weekdays >> first.weekday().num_days_from_monday()) & 1,
+            Period::Week => u32::from(rotate_week(self.weekdays, self.week_start)),
+            Period::Month => self.month_mask(first),
+        }
+    }
+
+    fn month_mask(&self, first: NaiveDate) -> u32 {
+        let length = days_in_month(first);
+        let all = (1u32 << length) - 1;
+        let first_weekday = first.weekday().num_days_from_monday();
+        let mut days = all;
+        if self.weekdays != 0 {
+            let week = u32::from(rotate_week(self.weekdays, first_weekday));
+            days &= week | week << 7 | week << 14 | week << 21 | week << 28;
+        }
+        if !self.ordinals.is_empty() {
+            let mut nth = 0u32;
+            for &(n, weekday) in &self.ordinals {
+                let first_match = (weekday + 7 - first_weekday) % 7;
+                let last_match = first_match + (length - 1 - first_match) / 7 * 7;
+                let day = if n > 0 {
+                    i64::from(first_match) + 7 * i64::from(n - 1)
+                } else {
+                    i64::from(last_match) - 7 * i64::from(-n - 1)
+                };
+                if (0..i64::from(length)).contains(&day) {
+                    nth |= 1 << day;
+                }
+            }
+            days &= nth;
+        }
+        if !self.month_days.is_empty() {
+            let length = length as i32;
+            let mut matching = 0u32;
+            for &day in &self.month_days {
+                let index = if day > 0 { day - 1 } else { length + day };
+                if (0..length).contains(&index) {
+                    matching |= 1 << index;
+                }
+            }
+            days &= matching;
+        }
+        days & all
+    }
+
+    /// The period after the one starting on `first`.
+    fn next_period(&self, first: NaiveDate) -> Option<NaiveDate> {
+        let next = match self.period {
+            Period::Day => first.checked_add_days(Days::new(u64::from(self.interval))),
+            Period::Week => first.checked_add_days(Days::new(7 * u64::from(self.interval))),
+            Period::Month => first.checked_add_months(Months::new(self.interval)),
+        };
+        next.filter(|date| date.year() <= MAX_YEAR)
+    }
+}
+
+/// Iterator over a [`FastRule`]'s occurrence start timestamps.
+#[derive(Debug, Clone)]
+pub(super) struct Occurrences<'a> {
+    rule: &'a FastRule,
+    /// The next period to expand, if any.
+    period: Option<NaiveDate>,
+    /// Matching days of the current period not yet visited.
+    days: u32,
+    /// First day of the current period.
+    base: NaiveDate,
+    remaining: Option<u32>,
+    /// Periods expanded since the last occurrence.
+    empty: u32,
+    /// Index of the first EXDATE not yet passed.
+    exdate: usize,
+    /// Instances passed so far, including those EXDATEs removed.
+    seen: usize,
+    done: bool,
+}
+
+impl<'a> Occurrences<'a> {
+    /// Each occurrence with its position among all the rule's instances,
+    /// counting those EXDATEs removed.
+    pub(super) fn indexed(mut self) -> impl Iterator<Item = (usize, i64)> + 'a {
+        std::iter::from_fn(move || {
+            let start = self.next()?;
+            Some((self.seen - 1, start))
+        })
+    }
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        let rule = self.rule;
+        loop {
+            if self.done {
+                return None;
+            }
+            if self.days == 0 {
+                let Some(first) = self.period else {
+                    self.done = true;
+                    return None;
+                };
+                self.empty += 1;
+                if self.empty >= MAX_EMPTY_PERIODS {
+                    self.done = true;
+                    return None;
+                }
+                self.base = first;
+                self.days = rule.mask(first);
+                self.period = rule.next_period(first);
+                continue;
+            }
+
+            let offset = self.days.trailing_zeros();
+            self.days &= self.days - 1;
+            let date = self.base + Days::new(u64::from(offset));
+            let Some(start) = local_start(rule.tz, date, rule.start.time()) else {
+                continue;
+            };
+            let start = start.timestamp();
+            if rule.until.is_some_and(|until| start > until) {
+                self.done = true;
+                return None;
+            }
+            if start < rule.start_ts {
+                continue;
+            }
+            self.empty = 0;
+            self.seen += 1;
+            if let Some(remaining) = &mut self.remaining {
+                *remaining -= 1;
+                self.done = *remaining == 0;
+            }
+            while rule.exdates.get(self.exdate).is_some_and(|&ex| ex < start) {
+                self.exdate += 1;
+            }
+            if rule.exdates.get(self.exdate) == Some(&start) {
+                continue;
+            }
+            return Some(start);
+        }
+    }
+}
+
+/// Whether the rule's occurrences all fall at DTSTART's time of day, as
+/// when it has no BYHOUR, BYMINUTE, or BYSECOND of its own.
+fn keeps_start_time(rule: &RRule, time: NaiveTime) -> bool {
+    rule.get_by_hour() == [time.hour() as u8]
+        && rule.get_by_minute() == [time.minute() as u8]
+        && rule.get_by_second() == [time.second() as u8]
+}
+
+/// The BYMONTHDAY values in `rrule`, if it has the part. The syntax has
+/// already been checked.
+fn raw_month_days(rrule: &str) -> Option<Vec<i32>> {
+    rrule.split(';').find_map(|part| {
+        let (name, value) = part.split_once('=')?;
+        name.eq_ignore_ascii_case("BYMONTHDAY")
+            .then(|| value.split(',').filter_map(|d| d.parse().ok()).collect())
+    })
+}
+
+/// `weekdays` (bit 0 for Monday) rotated so bit 0 is the weekday `from`
+/// days after Monday.
+fn rotate_week(weekdays: u8, from: u32) -> u8 {
+    let week = u32::from(weekdays);
+    (((week >> from) | (week << (7 - from))) & 0x7f) as u8
+}
+
+fn days_in_month(first: NaiveDate) -> u32 {
+    match first.checked_add_months(Months::new(1)) {
+        Some(next) => (next - first).num_days() as u32,
+        None => 31,
+    }
+}
+
+/// `date` at `time` in `tz`, resolved as the `rrule` crate does: a time in
+/// a fold or gap is measured from local midnight, so a fold takes its
+/// earlier instant and a gap moves forward by its length. Days whose
+/// midnight is itself not a single instant have no occurrence.
+fn local_start(tz: Tz, date: NaiveDate, time: NaiveTime) -> Option<DateTime<Tz>> {
+    if let Some(start) = date.and_time(time).and_local_timezone(tz).single() {
+        return Some(start);
+    }
+    let midnight = date
+        .and_time(NaiveTime::MIN)
+        .and_local_timezone(tz)
+        .single()?;
+    midnight.checked_add_signed(time - NaiveTime::MIN)
+}
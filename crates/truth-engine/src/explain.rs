@@ -0,0 +1,606 @@
+//! Explanations: the steps behind an expansion, a free slot, or a resolved
+//! expression.
+//!
+//! Explaining is opt-in, as it costs extra work and output. Each result
+//! carries a list of [`TraceStep`]s naming what decided it and how:
+//!
+//! - [`explain_expansion`] — which RRULE parts generated each occurrence,
+//!   and which occurrences EXDATE removed
+//! - [`explain_free_slots`] — which busy blocks (and which events in them)
+//!   bound each free slot, and why a slot is too short
+//! - [`ResolveOptions::explain`](crate::temporal::ResolveOptions::explain) —
+//!   which parser matched an expression, in
+//!   [`ResolvedDatetime::trace`](crate::temporal::ResolvedDatetime::trace)
+
+use chrono::{DateTime, Datelike, NaiveDateTime, Timelike, Utc, Weekday};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, TruthError};
+use crate::expander::{expand_rrule, expand_rrule_with_exdates, ExpandedEvent};
+use crate::freebusy::FreeSlot;
+use crate::temporal::interval::TimeInterval;
+use crate::temporal::parse_timezone;
+
+/// One step of an explanation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TraceStep {
+    /// What made the decision: an RRULE part (`"BYDAY"`), a parser
+    /// (`"parser:business_day"`), a busy block (`"busy"`), the window.
+    pub source: String,
+    /// What it decided, in words.
+    pub detail: String,
+    /// Indices into the caller's input this step refers to, such as the
+    /// events making up a busy block.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub refs: Vec<usize>,
+}
+
+impl TraceStep {
+    pub(crate) fn new(source: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            detail: detail.into(),
+            refs: Vec::new(),
+        }
+    }
+}
+
+/// An expanded occurrence and why it exists.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExplainedOccurrence {
+    pub event: ExpandedEvent,
+    /// 1-based position in the series the rule generates, counting
+    /// occurrences EXDATE removes.
+    pub instance: usize,
+    pub trace: Vec<TraceStep>,
+}
+
+/// The result of [`explain_expansion`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExpansionExplanation {
+    /// The occurrences [`expand_rrule_with_exdates`] returns, in order.
+    pub occurrences: Vec<ExplainedOccurrence>,
+    /// Occurrences the rule generated that EXDATE removed.
+    pub excluded: Vec<ExplainedOccurrence>,
+}
+
+/// Expand an RRULE as [`expand_rrule_with_exdates`] does, explaining each
+/// occurrence: the period of FREQ/INTERVAL it falls in, the BY* values it
+/// matched, and its place against COUNT and UNTIL.
+///
+/// # Errors
+///
+/// As for [`expand_rrule_with_exdates`].
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::explain::explain_expansion;
+///
+/// let explained = explain_expansion(
+///     "FREQ=MONTHLY;BYDAY=-1FR;COUNT=3",
+///     "2026-01-30T16:00:00",
+///     30,
+///     "America/New_York",
+///     None,
+///     None,
+///     &["2026-02-27T16:00:00"],
+/// )
+/// .unwrap();
+/// assert_eq!(explained.occurrences.len(), 2);
+/// let byday = explained.occurrences[1].trace.iter().find(|s| s.source == "BYDAY").unwrap();
+/// assert_eq!(byday.detail, "-1FR: the last Friday of the month");
+/// assert_eq!(explained.excluded[0].instance, 2);
+/// ```
+pub fn explain_expansion(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    until: Option<&str>,
+    count: Option<u32>,
+    exdates: &[&str],
+) -> Result<ExpansionExplanation> {
+    let events = expand_rrule_with_exdates(
+        rrule,
+        dtstart,
+        duration_minutes,
+        timezone,
+        until,
+        count,
+        exdates,
+    )?;
+    let generated = expand_rrule(rrule, dtstart, duration_minutes, timezone, until, count)?;
+    let tz = parse_timezone(timezone)?;
+    let start = NaiveDateTime::parse_from_str(dtstart, "%Y-%m-%dT%H:%M:%S")
+        .map_err(|e| TruthError::InvalidDatetime(format!("{dtstart}: {e}")))?;
+    let parts = RuleParts::parse(rrule, until, count);
+    let total = generated.len();
+
+    let explain = |event: &ExpandedEvent, instance: usize| {
+        let local = event.start.with_timezone(&tz).naive_local();
+        ExplainedOccurrence {
+            event: event.clone(),
+            instance,
+            trace: parts.explain(start, local, instance, total),
+        }
+    };
+    let instance_of = |event: &ExpandedEvent| {
+        generated
+            .iter()
+            .position(|g| g.start == event.start)
+            .map_or(0, |i| i + 1)
+    };
+    let occurrences = events.iter().map(|e| explain(e, instance_of(e))).collect();
+    let excluded = generated
+        .iter()
+        .enumerate()
+        .filter(|(_, g)| !events.iter().any(|e| e.start == g.start))
+        .filter_map(|(i, g)| {
+            let local = g.start.with_timezone(&tz).naive_local();
+            let exdate = exdates.iter().find(|x| {
+                NaiveDateTime::parse_from_str(x, "%Y-%m-%dT%H:%M:%S").ok() == Some(local)
+            })?;
+            let mut occurrence = explain(g, i + 1);
+            occurrence.trace.push(TraceStep::new(
+                "EXDATE",
+                format!("removed by EXDATE {exdate}"),
+            ));
+            Some(occurrence)
+        })
+        .collect();
+    Ok(ExpansionExplanation {
+        occurrences,
+        excluded,
+    })
+}
+
+/// The parts of an RRULE, by name, as written.
+struct RuleParts {
+    parts: Vec<(String, String)>,
+    /// COUNT from the call, when the rule has none.
+    count: Option<u32>,
+    /// UNTIL from the call, when the rule has none.
+    until: Option<String>,
+}
+
+impl RuleParts {
+    fn parse(rrule: &str, until: Option<&str>, count: Option<u32>) -> Self {
+        let parts: Vec<(String, String)> = rrule
+            .trim_start_matches("RRULE:")
+            .split(';')
+            .filter_map(|p| p.split_once('='))
+            .map(|(k, v)| (k.trim().to_uppercase(), v.trim().to_uppercase()))
+            .collect();
+        let has = |key: &str| parts.iter().any(|(k, _)| k == key);
+        Self {
+            count: count.filter(|_| !has("COUNT")),
+            until: until.filter(|_| !has("UNTIL")).map(str::to_string),
+            parts,
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&str> {
+        self.parts
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn explain(
+        &self,
+        start: NaiveDateTime,
+        local: NaiveDateTime,
+        instance: usize,
+        total: usize,
+    ) -> Vec<TraceStep> {
+        let mut trace = Vec::new();
+        if local == start {
+            trace.push(TraceStep::new(
+                "DTSTART",
+                "the first occurrence, at DTSTART",
+            ));
+        }
+        if let Some(freq) = self.get("FREQ") {
+            let interval: i64 = self
+                .get("INTERVAL")
+                .and_then(|i| i.parse().ok())
+                .unwrap_or(1);
+            let (unit, elapsed) = match freq {
+                "YEARLY" => ("year", (local.year() - start.year()) as i64),
+                "MONTHLY" => (
+                    "month",
+                    (local.year() - start.year()) as i64 * 12 + local.month() as i64
+                        - start.month() as i64,
+                ),
+                "WEEKLY" => {
+                    let week_start = self.week_start();
+                    let monday = |d: NaiveDateTime| {
+                        d.date().week(week_start).first_day().num_days_from_ce() as i64
+                    };
+                    ("week", (monday(local) - monday(start)) / 7)
+                }
+                "DAILY" => ("day", (local.date() - start.date()).num_days()),
+                "HOURLY" => ("hour", (local - start).num_hours()),
+                "MINUTELY" => ("minute", (local - start).num_minutes()),
+                _ => ("second", (local - start).num_seconds()),
+            };
+            let every = if interval == 1 {
+                format!("every {unit}")
+            } else {
+                format!("every {interval} {unit}s")
+            };
+            trace.push(TraceStep::new(
+                "FREQ",
+                format!(
+                    "{freq}: {every}; this is {unit} {} after DTSTART's",
+                    elapsed.max(0)
+                ),
+            ));
+        }
+        for (key, value) in &self.parts {
+            if let Some(detail) = self.explain_by(key, value, local) {
+                trace.push(TraceStep::new(key.as_str(), detail));
+            }
+        }
+        if let Some(setpos) = self.get("BYSETPOS") {
+            trace.push(TraceStep::new(
+                "BYSETPOS",
+                format!("{setpos}: chosen by position among the period's candidates"),
+            ));
+        }
+        let count = self
+            .get("COUNT")
+            .and_then(|c| c.parse::<u32>().ok())
+            .or(self.count);
+        if let Some(count) = count {
+            trace.push(TraceStep::new(
+                "COUNT",
+                format!("occurrence {instance} of {count}"),
+            ));
+        } else if instance > 0 {
+            trace.push(TraceStep::new(
+                "instance",
+                format!("occurrence {instance} of the {total} expanded"),
+            ));
+        }
+        if let Some(until) = self.get("UNTIL").map(str::to_string).or(self.until.clone()) {
+            trace.push(TraceStep::new("UNTIL", format!("on or before {until}")));
+        }
+        trace
+    }
+
+    /// Which value of a BY* part `local` matched.
+    fn explain_by(&self, key: &str, value: &str, local: NaiveDateTime) -> Option<String> {
+        let values = value.split(',');
+        let numbers = || values.clone().filter_map(|v| v.parse::<i64>().ok());
+        let date = local.date();
+        match key {
+            "BYMONTH" => {
+                let month = numbers().find(|m| *m == date.month() as i64)?;
+                let name = chrono::Month::try_from(month as u8).ok()?.name();
+                Some(format!("{month}: {name}"))
+            }
+            "BYMONTHDAY" => {
+                let last = days_in_month(date) as i64;
+                let day = date.day() as i64;
+                let matched = numbers().find(|d| *d == day || *d == day - last - 1)?;
+                Some(if matched < 0 {
+                    format!("{matched}: day {day}, counted from the end of the month")
+                } else {
+                    format!("{matched}: day {day} of the month")
+                })
+            }
+            "BYYEARDAY" => {
+                let days = if date.leap_year() { 366 } else { 365 };
+                let ordinal = date.ordinal() as i64;
+                let matched = numbers().find(|d| *d == ordinal || *d == ordinal - days - 1)?;
+                Some(format!("{matched}: day {ordinal} of the year"))
+            }
+            "BYWEEKNO" => {
+                let week = date.iso_week().week() as i64;
+                let matched = numbers().find(|w| *w == week || *w == week - 53)?;
+                Some(format!("{matched}: week {week} of the year"))
+            }
+            "BYDAY" => values.map(|v| self.explain_byday(v, local)).find_map(|d| d),
+            "BYHOUR" => numbers()
+                .find(|h| *h == local.hour() as i64)
+                .map(|h| format!("{h}: hour {h}")),
+            "BYMINUTE" => numbers()
+                .find(|m| *m == local.minute() as i64)
+                .map(|m| format!("{m}: minute {m}")),
+            "BYSECOND" => numbers()
+                .find(|s| *s == local.second() as i64)
+                .map(|s| format!("{s}: second {s}")),
+            _ => None,
+        }
+    }
+
+    fn explain_byday(&self, value: &str, local: NaiveDateTime) -> Option<String> {
+        let split = value.len().checked_sub(2)?;
+        let (ordinal, day) = value.split_at(split);
+        let weekday = match day {
+            "MO" => Weekday::Mon,
+            "TU" => Weekday::Tue,
+            "WE" => Weekday::Wed,
+            "TH" => Weekday::Thu,
+            "FR" => Weekday::Fri,
+            "SA" => Weekday::Sat,
+            "SU" => Weekday::Sun,
+            _ => return None,
+        };
+        let date = local.date();
+        if date.weekday() != weekday {
+            return None;
+        }
+        let name = weekday_name(weekday);
+        if ordinal.is_empty() {
+            return Some(format!("{value}: {name}"));
+        }
+        let n: i64 = ordinal.trim_start_matches('+').parse().ok()?;
+        // The ordinal counts within the month for monthly rules and yearly
+        // rules with BYMONTH, otherwise within the year.
+        let in_month = self.get("FREQ") == Some("MONTHLY") || self.get("BYMONTH").is_some();
+        let (position, total, scope) = if in_month {
+            let position = (date.day() as i64 - 1) / 7 + 1;
+            let total = position + (days_in_month(date) as i64 - date.day() as i64) / 7;
+            (position, total, "month")
+        } else {
+            let days = if date.leap_year() { 366 } else { 365 };
+            let position = (date.ordinal() as i64 - 1) / 7 + 1;
+            let total = position + (days - date.ordinal() as i64) / 7;
+            (position, total, "year")
+        };
+        let from_end = total - position + 1;
+        if n > 0 && n == position {
+            Some(format!(
+                "{value}: the {} {name} of the {scope}",
+                ordinal_word(n)
+            ))
+        } else if n == -1 && from_end == 1 {
+            Some(format!("{value}: the last {name} of the {scope}"))
+        } else if n < 0 && -n == from_end {
+            Some(format!(
+                "{value}: the {} last {name} of the {scope}",
+                ordinal_word(-n)
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn week_start(&self) -> Weekday {
+        match self.get("WKST") {
+            Some("SU") => Weekday::Sun,
+            Some("TU") => Weekday::Tue,
+            Some("WE") => Weekday::Wed,
+            Some("TH") => Weekday::Thu,
+            Some("FR") => Weekday::Fri,
+            Some("SA") => Weekday::Sat,
+            _ => Weekday::Mon,
+        }
+    }
+}
+
+fn days_in_month(date: chrono::NaiveDate) -> u32 {
+    let (y, m) = (date.year(), date.month());
+    let next = if m == 12 {
+        chrono::NaiveDate::from_ymd_opt(y + 1, 1, 1)
+    } else {
+        chrono::NaiveDate::from_ymd_opt(y, m + 1, 1)
+    };
+    next.and_then(|n| n.pred_opt()).map_or(31, |d| d.day())
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+fn ordinal_word(n: i64) -> String {
+    match n {
+        1 => "first".to_string(),
+        2 => "second".to_string(),
+        3 => "third".to_string(),
+        4 => "fourth".to_string(),
+        5 => "fifth".to_string(),
+        _ => format!("{n}th"),
+    }
+}
+
+/// A merged busy block and the events it came from.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExplainedBusy {
+    /// Start of the block, clipped to the window.
+    pub start: DateTime<Utc>,
+    /// End of the block, clipped to the window.
+    pub end: DateTime<Utc>,
+    /// Indices of the input events that overlap or touch to form the block.
+    pub events: Vec<usize>,
+}
+
+/// A free gap and what bounds it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExplainedSlot {
+    pub slot: FreeSlot,
+    /// Whether the gap is at least the minimum duration asked for.
+    pub fits: bool,
+    pub trace: Vec<TraceStep>,
+}
+
+/// The result of [`explain_free_slots`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FreeSlotExplanation {
+    /// Every gap, as [`find_free_slots`](crate::freebusy::find_free_slots)
+    /// returns them, whether or not it fits.
+    pub slots: Vec<ExplainedSlot>,
+    /// The busy blocks between the gaps, in order.
+    pub busy: Vec<ExplainedBusy>,
+}
+
+/// Free slots in `[window_start, window_end)` as
+/// [`find_free_slots`](crate::freebusy::find_free_slots) computes them,
+/// with the busy blocks that bound each one and whether it lasts
+/// `min_duration_minutes`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::explain::explain_free_slots;
+/// use truth_engine::ExpandedEvent;
+///
+/// let at = |h, m| Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap();
+/// let events = [
+///     ExpandedEvent::new(at(10, 0), at(11, 0)),
+///     ExpandedEvent::new(at(10, 30), at(11, 30)),
+///     ExpandedEvent::new(at(11, 45), at(12, 0)),
+/// ];
+/// let explained = explain_free_slots(&events, at(9, 0), at(13, 0), 30);
+/// assert_eq!(explained.busy[0].events, vec![0, 1]);
+/// // 11:30–11:45 is too short.
+/// assert!(!explained.slots[1].fits);
+/// ```
+pub fn explain_free_slots(
+    events: &[ExpandedEvent],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    min_duration_minutes: i64,
+) -> FreeSlotExplanation {
+    let window = TimeInterval {
+        start: window_start,
+        end: window_end,
+    };
+    let mut clipped: Vec<(usize, TimeInterval)> = events
+        .iter()
+        .enumerate()
+        .filter_map(|(i, e)| Some((i, TimeInterval::from(e).clamp_to(&window)?)))
+        .filter(|(_, span)| !span.is_empty())
+        .collect();
+    clipped.sort_by_key(|(i, span)| (*span, *i));
+
+    let mut busy: Vec<ExplainedBusy> = Vec::new();
+    for (i, span) in clipped {
+        match busy.last_mut() {
+            Some(block) if span.start <= block.end => {
+                block.end = block.end.max(span.end);
+                block.events.push(i);
+            }
+            _ => busy.push(ExplainedBusy {
+                start: span.start,
+                end: span.end,
+                events: vec![i],
+            }),
+        }
+    }
+    for block in &mut busy {
+        block.events.sort_unstable();
+    }
+
+    let mut slots = Vec::new();
+    if window_start < window_end {
+        let mut cursor = window_start;
+        let mut previous: Option<usize> = None;
+        for next in (0..busy.len()).map(Some).chain([None]) {
+            let end = next.map_or(window_end, |b| busy[b].start);
+            if cursor < end {
+                slots.push(explain_slot(
+                    cursor,
+                    end,
+                    previous.map(|b| &busy[b]),
+                    next.map(|b| &busy[b]),
+                    min_duration_minutes,
+                ));
+            }
+            if let Some(b) = next {
+                cursor = busy[b].end;
+                previous = Some(b);
+            }
+        }
+    }
+    FreeSlotExplanation { slots, busy }
+}
+
+fn explain_slot(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    after: Option<&ExplainedBusy>,
+    before: Option<&ExplainedBusy>,
+    min_duration_minutes: i64,
+) -> ExplainedSlot {
+    let duration_minutes = (end - start).num_minutes();
+    let events = |block: &ExplainedBusy| {
+        let list: Vec<String> = block.events.iter().map(|i| i.to_string()).collect();
+        let noun = if list.len() == 1 { "event" } else { "events" };
+        format!("{noun} {}", list.join(", "))
+    };
+    let bound = |source: &str, detail: String, block: Option<&ExplainedBusy>| TraceStep {
+        source: source.to_string(),
+        detail,
+        refs: block.map_or_else(Vec::new, |b| b.events.clone()),
+    };
+    let mut trace = vec![
+        match after {
+            Some(b) => bound(
+                "busy",
+                format!("starts at {} when {} end", start.to_rfc3339(), events(b)),
+                after,
+            ),
+            None => bound(
+                "window",
+                format!("starts at the window start, {}", start.to_rfc3339()),
+                None,
+            ),
+        },
+        match before {
+            Some(b) => bound(
+                "busy",
+                format!("ends at {} when {} begin", end.to_rfc3339(), events(b)),
+                before,
+            ),
+            None => bound(
+                "window",
+                format!("ends at the window end, {}", end.to_rfc3339()),
+                None,
+            ),
+        },
+    ];
+    let fits = duration_minutes >= min_duration_minutes;
+    if !fits {
+        trace.push(TraceStep::new(
+            "min_duration",
+            format!(
+                "{duration_minutes} minutes is shorter than the {min_duration_minutes} required"
+            ),
+        ));
+    }
+    ExplainedSlot {
+        slot: FreeSlot {
+            start,
+            end,
+            duration_minutes,
+        },
+        fits,
+        trace,
+    }
+}
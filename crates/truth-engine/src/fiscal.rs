@@ -0,0 +1,241 @@
+//! Week-based fiscal calendars (4-4-5, 4-5-4, 5-4-4, and custom layouts).
+//!
+//! Retail and finance years end on a fixed weekday near the end of a month
+//! rather than on a month end, and are split into periods of whole weeks.
+//! A [`FiscalCalendar`] describes one such layout and answers where each
+//! period starts and ends and which period a date falls in. Set it as
+//! [`ResolveOptions::fiscal_calendar`](crate::temporal::ResolveOptions::fiscal_calendar)
+//! to resolve expressions like "end of P7" or "start of next period".
+//!
+//! A fiscal year has 52 weeks, or 53 when the year-end rule lands a week
+//! later; the extra week goes to the last period. Fiscal years are named by
+//! the calendar year of their year-end month (FY2026 ends in January 2026
+//! for a January year end), as with
+//! [`ResolveOptions::fiscal_year_start`](crate::temporal::ResolveOptions::fiscal_year_start).
+
+use chrono::{Datelike, Duration, Month, NaiveDate, Weekday};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, TruthError};
+
+/// Where a fiscal year ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum FiscalYearEnd {
+    /// On the last `weekday` of `month` (the year may end up to six days
+    /// before the month does).
+    LastWeekday {
+        weekday: Weekday,
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+        month: Month,
+    },
+    /// On the `weekday` closest to the last day of `month` (up to three days
+    /// before or after it).
+    NearestWeekday {
+        weekday: Weekday,
+        #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+        month: Month,
+    },
+}
+
+/// One period of a fiscal year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FiscalPeriod {
+    /// The fiscal year the period belongs to.
+    pub year: i32,
+    /// 1-based period number ("P7" is 7).
+    pub number: u32,
+    /// First day of the period.
+    pub start: NaiveDate,
+    /// Last day of the period (inclusive).
+    pub end: NaiveDate,
+}
+
+impl FiscalPeriod {
+    /// Length of the period in weeks.
+    pub fn weeks(&self) -> i64 {
+        ((self.end - self.start).num_days() + 1) / 7
+    }
+}
+
+/// A week-based fiscal calendar.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Month, NaiveDate, Weekday};
+/// use truth_engine::fiscal::{FiscalCalendar, FiscalYearEnd};
+///
+/// // Years end on the last Saturday of January.
+/// let calendar = FiscalCalendar::four_four_five(FiscalYearEnd::LastWeekday {
+///     weekday: Weekday::Sat,
+///     month: Month::January,
+/// });
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// // FY2026 runs from January 26, 2025 to January 31, 2026: a 53-week year.
+/// assert_eq!(calendar.year_start(2026), Some(date(2025, 1, 26)));
+/// assert_eq!(calendar.year_end(2026), Some(date(2026, 1, 31)));
+/// assert_eq!(calendar.weeks_in_year(2026), Some(53));
+///
+/// let p = calendar.period_of(date(2025, 3, 15)).unwrap();
+/// assert_eq!((p.year, p.number, p.start, p.end), (2026, 2, date(2025, 2, 23), date(2025, 3, 22)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FiscalCalendar {
+    pub year_end: FiscalYearEnd,
+    /// Weeks in each period, in order. They should add up to 52; the last
+    /// period always runs to the year end.
+    pub period_weeks: Vec<u32>,
+}
+
+impl FiscalCalendar {
+    /// A calendar with a custom period layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidFiscalCalendar`] unless every period has
+    /// at least one week and the periods add up to 52 weeks.
+    pub fn new(year_end: FiscalYearEnd, period_weeks: Vec<u32>) -> Result<Self> {
+        if period_weeks.contains(&0) {
+            return Err(TruthError::InvalidFiscalCalendar(
+                "every period needs at least one week".to_string(),
+            ));
+        }
+        let total: u32 = period_weeks.iter().sum();
+        if total != 52 {
+            return Err(TruthError::InvalidFiscalCalendar(format!(
+                "periods add up to {total} weeks, not 52"
+            )));
+        }
+        Ok(Self {
+            year_end,
+            period_weeks,
+        })
+    }
+
+    /// Quarters of 4, 4, and 5 weeks.
+    pub fn four_four_five(year_end: FiscalYearEnd) -> Self {
+        Self::quarterly(year_end, [4, 4, 5])
+    }
+
+    /// Quarters of 4, 5, and 4 weeks.
+    pub fn four_five_four(year_end: FiscalYearEnd) -> Self {
+        Self::quarterly(year_end, [4, 5, 4])
+    }
+
+    /// Quarters of 5, 4, and 4 weeks.
+    pub fn five_four_four(year_end: FiscalYearEnd) -> Self {
+        Self::quarterly(year_end, [5, 4, 4])
+    }
+
+    fn quarterly(year_end: FiscalYearEnd, quarter: [u32; 3]) -> Self {
+        Self {
+            year_end,
+            period_weeks: quarter.repeat(4),
+        }
+    }
+
+    /// Last day of fiscal year `year`.
+    pub fn year_end(&self, year: i32) -> Option<NaiveDate> {
+        let (weekday, month, nearest) = match self.year_end {
+            FiscalYearEnd::LastWeekday { weekday, month } => (weekday, month, false),
+            FiscalYearEnd::NearestWeekday { weekday, month } => (weekday, month, true),
+        };
+        let last = last_day_of_month(year, month)?;
+        // Days back from the month end to the previous `weekday`.
+        let back = (last.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+        Some(if nearest && back > 3 {
+            last + Duration::days(7 - back as i64)
+        } else {
+            last - Duration::days(back as i64)
+        })
+    }
+
+    /// First day of fiscal year `year`.
+    pub fn year_start(&self, year: i32) -> Option<NaiveDate> {
+        self.year_end(year - 1)?.succ_opt()
+    }
+
+    /// Weeks in fiscal year `year`: 52, or 53 in a long year.
+    pub fn weeks_in_year(&self, year: i32) -> Option<i64> {
+        Some(((self.year_end(year)? - self.year_start(year)?).num_days() + 1) / 7)
+    }
+
+    /// The periods of fiscal year `year`, in order.
+    pub fn periods(&self, year: i32) -> Option<Vec<FiscalPeriod>> {
+        let end = self.year_end(year)?;
+        let mut start = self.year_start(year)?;
+        let mut periods = Vec::with_capacity(self.period_weeks.len());
+        for (i, weeks) in self.period_weeks.iter().enumerate() {
+            if start > end {
+                break;
+            }
+            let last = i + 1 == self.period_weeks.len();
+            let period_end = if last {
+                end
+            } else {
+                (start + Duration::weeks(*weeks as i64) - Duration::days(1)).min(end)
+            };
+            periods.push(FiscalPeriod {
+                year,
+                number: i as u32 + 1,
+                start,
+                end: period_end,
+            });
+            start = period_end.succ_opt()?;
+        }
+        Some(periods)
+    }
+
+    /// Period `number` (1-based) of fiscal year `year`.
+    pub fn period(&self, year: i32, number: u32) -> Option<FiscalPeriod> {
+        let index = (number as usize).checked_sub(1)?;
+        self.periods(year)?.get(index).copied()
+    }
+
+    /// The fiscal year containing `date`.
+    pub fn year_of(&self, date: NaiveDate) -> Option<i32> {
+        let year = date.year();
+        if date > self.year_end(year)? {
+            Some(year + 1)
+        } else if date < self.year_start(year)? {
+            Some(year - 1)
+        } else {
+            Some(year)
+        }
+    }
+
+    /// The period containing `date` — the current period, for today's date.
+    pub fn period_of(&self, date: NaiveDate) -> Option<FiscalPeriod> {
+        self.periods(self.year_of(date)?)?
+            .into_iter()
+            .find(|p| p.start <= date && date <= p.end)
+    }
+
+    /// The period `delta` periods after the one containing `date` (before,
+    /// for negative `delta`), crossing fiscal years as needed.
+    pub fn shift_period(&self, date: NaiveDate, delta: i32) -> Option<FiscalPeriod> {
+        let current = self.period_of(date)?;
+        let count = self.period_weeks.len() as i64;
+        let index = current.year as i64 * count + current.number as i64 - 1 + delta as i64;
+        let year = i32::try_from(index.div_euclid(count)).ok()?;
+        self.period(year, index.rem_euclid(count) as u32 + 1)
+    }
+}
+
+fn last_day_of_month(year: i32, month: Month) -> Option<NaiveDate> {
+    let m = month.number_from_month();
+    let next = if m == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, m + 1, 1)
+    };
+    next?.pred_opt()
+}
@@ -2,94 +2,205 @@
 //!
 //! Sorts events by start time, merges overlapping busy periods, then computes
 //! the gaps between merged periods within a given time window.
+//! [`find_business_free_slots`] keeps only the gaps in working time.
+//! [`normalize_busy`] exposes the merging step, with a tolerance for
+//! near-adjacent events, for callers pre-processing raw feeds.
+//! [`is_slot_free`] checks a single proposed slot against the events.
+//! [`label_slots`] renders slots in display timezones for presenting them.
 
+use crate::business::BusinessCalendar;
+use crate::error::{Result, TruthError};
 use crate::expander::ExpandedEvent;
-use chrono::{DateTime, Utc};
+use crate::temporal::interval::{self, TimeInterval};
+use crate::temporal::{format_short_in, format_time_in, parse_timezone, ClockFormat, Locale};
+use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// A free time slot.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FreeSlot {
     pub start: DateTime<Utc>,
     pub end: DateTime<Utc>,
     pub duration_minutes: i64,
 }
 
-/// Merge overlapping or adjacent busy periods, clipped to the given window.
+/// Whether a proposed slot is free, and what blocks it if not.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SlotCheck<T> {
+    /// Whether nothing overlaps the slot.
+    pub free: bool,
+    /// What overlaps the slot, by start time. Events that only touch the
+    /// slot (ending as it starts, or starting as it ends) do not block it.
+    pub blocking: Vec<T>,
+}
+
+/// Check a proposed slot `[start, end)` against `events`: free when no
+/// event overlaps it, otherwise blocked by the overlapping events.
 ///
-/// Returns a sorted, non-overlapping list of (start, end) intervals.
-pub(crate) fn merge_busy_periods(
+/// Overlap is as in [`find_conflicts`](crate::find_conflicts), so the
+/// answer matches running conflict detection with the slot added, without
+/// building the extended list.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidArguments`] if `end` is before `start`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::freebusy::is_slot_free;
+/// use truth_engine::ExpandedEvent;
+///
+/// let at = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+/// let events = [
+///     ExpandedEvent::new(at(9), at(10)),
+///     ExpandedEvent::new(at(11), at(12)),
+/// ];
+/// assert!(is_slot_free(at(10), at(11), &events).unwrap().free);
+/// let check = is_slot_free(at(9), at(11), &events).unwrap();
+/// assert!(!check.free);
+/// assert_eq!(check.blocking, [events[0].clone()]);
+/// ```
+pub fn is_slot_free(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
     events: &[ExpandedEvent],
-    window_start: DateTime<Utc>,
-    window_end: DateTime<Utc>,
-) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
-    // Collect events clipped to the window, discarding events entirely outside.
-    let mut intervals: Vec<(DateTime<Utc>, DateTime<Utc>)> = events
+) -> Result<SlotCheck<ExpandedEvent>> {
+    let slot = proposed_slot(start, end)?;
+    let mut blocking: Vec<ExpandedEvent> = events
         .iter()
-        .filter(|e| e.start < window_end && e.end > window_start)
-        .map(|e| (e.start.max(window_start), e.end.min(window_end)))
+        .filter(|e| TimeInterval::from(*e).overlaps(&slot))
+        .cloned()
         .collect();
+    blocking.sort_by_key(|e| e.start);
+    Ok(SlotCheck {
+        free: blocking.is_empty(),
+        blocking,
+    })
+}
 
-    if intervals.is_empty() {
-        return Vec::new();
+/// The slot `[start, end)`, rejecting one that ends before it starts.
+pub(crate) fn proposed_slot(start: DateTime<Utc>, end: DateTime<Utc>) -> Result<TimeInterval> {
+    if end < start {
+        return Err(TruthError::InvalidArguments(format!(
+            "slot ends ({end}) before it starts ({start})"
+        )));
     }
+    Ok(TimeInterval { start, end })
+}
 
-    // Sort by start time (then by end time for stability).
-    intervals.sort_by_key(|&(start, end)| (start, end));
-
-    // Merge overlapping intervals.
-    let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
-    for (start, end) in intervals {
-        if let Some(last) = merged.last_mut() {
-            if start <= last.1 {
-                // Overlapping or adjacent — extend the current interval.
-                last.1 = last.1.max(end);
-                continue;
-            }
-        }
-        merged.push((start, end));
-    }
+/// Merge overlapping or adjacent busy periods, clipped to the given window.
+///
+/// Returns a sorted, non-overlapping list of intervals.
+pub(crate) fn merge_busy_periods(
+    events: &[ExpandedEvent],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Vec<TimeInterval> {
+    let window = TimeInterval {
+        start: window_start,
+        end: window_end,
+    };
+    let clip = |e: &ExpandedEvent| TimeInterval::from(e).clamp_to(&window);
+    #[cfg(feature = "rayon")]
+    let clipped: Vec<TimeInterval> = events.par_iter().filter_map(clip).collect();
+    #[cfg(not(feature = "rayon"))]
+    let clipped: Vec<TimeInterval> = events.iter().filter_map(clip).collect();
+    interval::merge(clipped)
+}
 
-    merged
+/// Normalize a raw busy feed: merge overlapping events and events separated
+/// by at most `tolerance_minutes`, clipped to the window.
+///
+/// Returns sorted, non-overlapping busy blocks; the gaps closed by the
+/// tolerance count as busy. With a tolerance of 0 only overlapping and
+/// touching events merge, as in [`find_free_slots`]. A negative tolerance
+/// is treated as 0.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::freebusy::normalize_busy;
+/// use truth_engine::ExpandedEvent;
+///
+/// let at = |h, m| Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap();
+/// let feed = [
+///     ExpandedEvent::new(at(8, 0), at(9, 30)),
+///     ExpandedEvent::new(at(9, 33), at(10, 0)),
+///     ExpandedEvent::new(at(11, 0), at(12, 0)),
+/// ];
+/// let busy = normalize_busy(&feed, at(9, 0), at(17, 0), 5);
+/// assert_eq!(busy.len(), 2);
+/// assert_eq!((busy[0].start, busy[0].end), (at(9, 0), at(10, 0)));
+/// ```
+pub fn normalize_busy(
+    events: &[ExpandedEvent],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    tolerance_minutes: i64,
+) -> Vec<ExpandedEvent> {
+    let window = TimeInterval {
+        start: window_start,
+        end: window_end,
+    };
+    interval::merge_within(
+        events
+            .iter()
+            .filter_map(|e| TimeInterval::from(e).clamp_to(&window)),
+        Duration::minutes(tolerance_minutes),
+    )
+    .into_iter()
+    .map(|busy| ExpandedEvent::new(busy.start, busy.end))
+    .collect()
 }
 
 /// Find free time slots within a given time window, given a list of busy events.
 ///
 /// Events may overlap -- overlapping busy periods are merged before computing gaps.
-/// Returns free slots sorted by start time.
+/// Returns free slots sorted by start time. With the `rayon` feature, events
+/// are clipped and sorted in parallel.
 pub fn find_free_slots(
     events: &[ExpandedEvent],
     window_start: DateTime<Utc>,
     window_end: DateTime<Utc>,
 ) -> Vec<FreeSlot> {
-    let merged = merge_busy_periods(events, window_start, window_end);
+    let busy = merge_busy_periods(events, window_start, window_end);
+    free_slots_between(&busy, window_start, window_end)
+}
 
-    let mut free_slots = Vec::new();
+/// The gaps within the window between sorted, disjoint `busy` periods
+/// inside it, as from [`merge_busy_periods`].
+pub(crate) fn free_slots_between(
+    busy: &[TimeInterval],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Vec<FreeSlot> {
+    let mut free = Vec::with_capacity(busy.len() + 1);
     let mut cursor = window_start;
-
-    for (busy_start, busy_end) in &merged {
-        if cursor < *busy_start {
-            let duration_minutes = (*busy_start - cursor).num_minutes();
-            free_slots.push(FreeSlot {
+    let end = TimeInterval {
+        start: window_end,
+        end: window_end,
+    };
+    for period in busy.iter().chain([&end]) {
+        if cursor < period.start {
+            free.push(FreeSlot {
                 start: cursor,
-                end: *busy_start,
-                duration_minutes,
+                end: period.start,
+                duration_minutes: (period.start - cursor).num_minutes(),
             });
         }
-        cursor = cursor.max(*busy_end);
+        cursor = cursor.max(period.end);
     }
-
-    // Trailing free slot after the last busy period.
-    if cursor < window_end {
-        let duration_minutes = (window_end - cursor).num_minutes();
-        free_slots.push(FreeSlot {
-            start: cursor,
-            end: window_end,
-            duration_minutes,
-        });
-    }
-
-    free_slots
+    free
 }
 
 /// Find the first free slot of at least `min_duration_minutes` within the window.
@@ -106,3 +217,163 @@ pub fn find_first_free_slot(
         .into_iter()
         .find(|slot| slot.duration_minutes >= min_duration_minutes)
 }
+
+/// Free slots within a window that fall in working time on `calendar`:
+/// working hours on business days, skipping holidays and closures.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`](crate::TruthError::InvalidTimezone)
+/// if the calendar's timezone is unknown.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::business::BusinessCalendar;
+/// use truth_engine::freebusy::find_business_free_slots;
+/// use truth_engine::ExpandedEvent;
+///
+/// let at = |d, h| Utc.with_ymd_and_hms(2026, 3, d, h, 0, 0).unwrap();
+/// let busy = [ExpandedEvent::new(at(2, 9), at(2, 16))];
+/// // Monday to Tuesday: the end of Monday's working day, then all of Tuesday's.
+/// let free = find_business_free_slots(&busy, at(2, 0), at(4, 0), &BusinessCalendar::default()).unwrap();
+/// assert_eq!(free.len(), 2);
+/// assert_eq!((free[0].start, free[0].end), (at(2, 16), at(2, 17)));
+/// ```
+pub fn find_business_free_slots(
+    events: &[ExpandedEvent],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    calendar: &BusinessCalendar,
+) -> Result<Vec<FreeSlot>> {
+    let busy: Vec<TimeInterval> = events.iter().map(TimeInterval::from).collect();
+    Ok(calendar
+        .working_intervals(window_start, window_end)?
+        .iter()
+        .flat_map(|working| working.subtract_all(&busy))
+        .map(|free| FreeSlot {
+            start: free.start,
+            end: free.end,
+            duration_minutes: free.duration().num_minutes(),
+        })
+        .collect())
+}
+
+/// Display timezones and formatting for [`label_slots`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SlotLabelOptions {
+    /// IANA timezones or unambiguous abbreviations, in display order.
+    pub timezones: Vec<String>,
+    /// Language for weekday and month names.
+    pub locale: Locale,
+    /// 12- or 24-hour clock; `None` for the locale's usual one.
+    pub clock: Option<ClockFormat>,
+}
+
+/// A slot's times in one display timezone.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SlotLabel {
+    /// The IANA timezone.
+    pub timezone: String,
+    /// The slot's start in the timezone (RFC 3339 with offset).
+    pub start_local: String,
+    /// The slot's end in the timezone (RFC 3339 with offset).
+    pub end_local: String,
+    /// The slot as a short phrase ("Mon, Mar 2 at 10:00 AM EST – 11:00 AM EST").
+    pub label: String,
+}
+
+/// A free slot with its times in each display timezone.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LabeledSlot {
+    pub slot: FreeSlot,
+    /// One label per display timezone, in the order given.
+    pub labels: Vec<SlotLabel>,
+    /// The start time in every display timezone ("10:00 EST / 16:00 CET").
+    pub summary: String,
+}
+
+/// Render each slot's times in the display timezones of `options`, so a
+/// slot can be offered to people in several regions as it is.
+///
+/// The end of a slot repeats the date only when it falls on a later local
+/// day than the start.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] or
+/// [`TruthError::AmbiguousTimezone`] for a display timezone that is not a
+/// known zone or unambiguous abbreviation.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::freebusy::{find_free_slots, label_slots, SlotLabelOptions};
+/// use truth_engine::temporal::ClockFormat;
+///
+/// let at = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+/// let free = find_free_slots(&[], at(15), at(16));
+/// let options = SlotLabelOptions {
+///     timezones: vec!["America/New_York".to_string(), "Europe/Berlin".to_string()],
+///     clock: Some(ClockFormat::TwentyFourHour),
+///     ..Default::default()
+/// };
+/// let labeled = label_slots(&free, &options).unwrap();
+/// assert_eq!(labeled[0].summary, "10:00 EST / 16:00 CET");
+/// assert_eq!(labeled[0].labels[1].label, "Mon, Mar 2 at 16:00 CET – 17:00 CET");
+/// ```
+pub fn label_slots(slots: &[FreeSlot], options: &SlotLabelOptions) -> Result<Vec<LabeledSlot>> {
+    let zones = options
+        .timezones
+        .iter()
+        .map(|name| parse_timezone(name))
+        .collect::<Result<Vec<_>>>()?;
+    let clock = options
+        .clock
+        .unwrap_or_else(|| options.locale.default_clock());
+    Ok(slots
+        .iter()
+        .map(|slot| {
+            let labels: Vec<SlotLabel> = zones
+                .iter()
+                .map(|tz| {
+                    let start = slot.start.with_timezone(tz);
+                    let end = slot.end.with_timezone(tz);
+                    let end_label = if end.date_naive() == start.date_naive() {
+                        format_time_in(&end, clock)
+                    } else {
+                        format_short_in(&end, options.locale, clock, false)
+                    };
+                    SlotLabel {
+                        timezone: tz.name().to_string(),
+                        start_local: start.to_rfc3339(),
+                        end_local: end.to_rfc3339(),
+                        label: format!(
+                            "{} – {end_label}",
+                            format_short_in(&start, options.locale, clock, false)
+                        ),
+                    }
+                })
+                .collect();
+            let summary = zones
+                .iter()
+                .map(|tz| format_time_in(&slot.start.with_timezone(tz), clock))
+                .collect::<Vec<_>>()
+                .join(" / ");
+            LabeledSlot {
+                slot: slot.clone(),
+                labels,
+                summary,
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,23 @@
+//! Conversion between the engine's types and standard calendar formats.
+//!
+//! - [`ics`] — iCalendar (RFC 5545) text: VEVENTs into events the expander,
+//!   conflict, and free/busy modules consume, and events and busy blocks back
+//!   into VEVENT and VFREEBUSY text
+//! - `jcal` — jCal (RFC 7265), the JSON form of the same data; requires the
+//!   `serde` feature
+//! - `graph` — Microsoft Graph events, recurrence patterns, and exceptions;
+//!   requires the `serde` feature
+//! - [`caldav`] — CalDAV free-busy-query request bodies and responses
+//! - [`diff`] — classified changes between two versions of a calendar, for sync
+//! - [`itip`] — iTIP (RFC 5546) REQUEST, REPLY, CANCEL, and COUNTER messages
+//! - [`tzdist`] — timezone rules loaded at runtime from a tzdist (RFC 7808) service
+
+pub mod caldav;
+pub mod diff;
+#[cfg(feature = "serde")]
+pub mod graph;
+pub mod ics;
+pub mod itip;
+#[cfg(feature = "serde")]
+pub mod jcal;
+pub mod tzdist;
@@ -0,0 +1,99 @@
+//! CalDAV free/busy queries (RFC 4791 §7.10).
+//!
+//! The engine does no I/O: [`freebusy_query`] builds the XML body to send as
+//! a `REPORT` request (with `Depth: 1` and
+//! `Content-Type: application/xml; charset=utf-8`) against a calendar
+//! collection, and [`freebusy_stream`] turns the `text/calendar` VFREEBUSY
+//! response into an [`EventStream`] ready for
+//! [`merge_availability`](crate::merge_availability).
+
+use chrono::{DateTime, Utc};
+
+use super::ics::parse_freebusy;
+use crate::availability::{BusyBlock, EventStream};
+use crate::error::Result;
+use crate::expander::ExpandedEvent;
+
+/// Build a `CALDAV:free-busy-query` REPORT body for `[window_start, window_end)`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::interop::caldav::freebusy_query;
+///
+/// let body = freebusy_query(
+///     Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap(),
+/// );
+/// assert!(body.contains(r#"<C:time-range start="20260302T000000Z" end="20260309T000000Z"/>"#));
+/// ```
+pub fn freebusy_query(window_start: DateTime<Utc>, window_end: DateTime<Utc>) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\" ?>\n\
+         <C:free-busy-query xmlns:C=\"urn:ietf:params:xml:ns:caldav\">\n  \
+         <C:time-range start=\"{}\" end=\"{}\"/>\n\
+         </C:free-busy-query>\n",
+        window_start.format("%Y%m%dT%H%M%SZ"),
+        window_end.format("%Y%m%dT%H%M%SZ"),
+    )
+}
+
+/// Parse a free-busy-query response into merged busy blocks.
+///
+/// Each block has a `source_count` of 1: the server has already collapsed
+/// the collection's events, so the blocks come from a single source.
+///
+/// # Errors
+///
+/// Fails as [`parse_freebusy`] does on malformed iCalendar data.
+pub fn parse_freebusy_report(body: &str) -> Result<Vec<BusyBlock>> {
+    Ok(parse_freebusy(body)?
+        .into_iter()
+        .map(|interval| BusyBlock {
+            start: interval.start,
+            end: interval.end,
+            source_count: 1,
+        })
+        .collect())
+}
+
+/// Parse a free-busy-query response into an [`EventStream`], one event per
+/// busy period, for merging with other calendars.
+///
+/// # Errors
+///
+/// Fails as [`parse_freebusy`] does on malformed iCalendar data.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::interop::caldav::freebusy_stream;
+/// use truth_engine::{merge_availability, PrivacyLevel};
+///
+/// let stream = freebusy_stream(
+///     "work-caldav",
+///     "BEGIN:VCALENDAR\r\nBEGIN:VFREEBUSY\r\n\
+///      FREEBUSY:20260302T140000Z/PT1H\r\n\
+///      END:VFREEBUSY\r\nEND:VCALENDAR\r\n",
+/// )
+/// .unwrap();
+/// let availability = merge_availability(
+///     &[stream],
+///     Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2026, 3, 2, 17, 0, 0).unwrap(),
+///     PrivacyLevel::Full,
+/// );
+/// assert_eq!(availability.busy.len(), 1);
+/// assert_eq!(availability.free.len(), 2);
+/// ```
+pub fn freebusy_stream(stream_id: &str, body: &str) -> Result<EventStream> {
+    Ok(EventStream {
+        stream_id: stream_id.to_string(),
+        events: parse_freebusy(body)?
+            .into_iter()
+            .map(|interval| ExpandedEvent::new(interval.start, interval.end))
+            .collect(),
+    })
+}
@@ -0,0 +1,398 @@
+//! Classified differences between two versions of a calendar.
+//!
+//! Sync agents need to know *what* changed between two fetches of a
+//! calendar — an occurrence moved, a series cut short, a new exception —
+//! not that two ICS blobs differ. [`diff_calendars`] matches events by UID
+//! and overrides by RECURRENCE-ID, compares occurrences as instants, and
+//! reports each difference as a [`Change`]. The result depends only on the
+//! two calendars, so the same edit is always classified the same way.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+
+use super::ics::{Calendar, DateTimeValue, Event, EventStatus};
+use crate::error::Result;
+use crate::expander::ExpandedEvent;
+use crate::temporal::parse_timezone;
+
+/// One classified difference for the event with `uid`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub uid: String,
+    pub kind: ChangeKind,
+}
+
+/// What changed. Occurrences are named by their `original` start: the
+/// series slot they occupy, which RECURRENCE-ID and EXDATE refer to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+    /// The event or series is new.
+    Added,
+    /// The event or series is gone.
+    Removed,
+    /// The whole event or series became STATUS:CANCELLED.
+    Cancelled,
+    /// The whole event or series is no longer cancelled.
+    Restored,
+    /// DTSTART moved to another zone. `None` is floating or all-day time.
+    TimezoneChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    /// One occurrence (or a non-recurring event) has a new time.
+    OccurrenceMoved {
+        original: DateTime<Utc>,
+        from: ExpandedEvent,
+        to: ExpandedEvent,
+    },
+    /// One occurrence was cancelled, by EXDATE or a cancelled override.
+    OccurrenceCancelled { original: DateTime<Utc> },
+    /// A cancelled occurrence is back.
+    OccurrenceRestored { original: DateTime<Utc> },
+    /// The series now ends earlier; `removed` are the lost trailing starts.
+    SeriesTruncated { removed: Vec<DateTime<Utc>> },
+    /// The series now ends later; `added` are the new trailing starts.
+    SeriesExtended { added: Vec<DateTime<Utc>> },
+    /// The recurrence changed in some other way.
+    RuleChanged {
+        added: Vec<DateTime<Utc>>,
+        removed: Vec<DateTime<Utc>>,
+    },
+    /// A new override that keeps its occurrence's time (for example, a
+    /// changed summary for one meeting).
+    ExceptionAdded { original: DateTime<Utc> },
+    /// An override was dropped; the occurrence follows the series again.
+    ExceptionRemoved { original: DateTime<Utc> },
+    /// SUMMARY, DESCRIPTION, LOCATION, or TRANSP changed, on the series
+    /// (`original` is `None`) or on one override.
+    DetailsChanged {
+        original: Option<DateTime<Utc>>,
+        fields: Vec<&'static str>,
+    },
+}
+
+/// The changes from `old` to `new`, ordered by UID, with series-level
+/// changes before per-occurrence ones and those ordered by original start.
+///
+/// Events are matched by UID; events without one are ignored. Each
+/// difference is reported once, by the most specific kind: a new override
+/// that moves its occurrence is [`ChangeKind::OccurrenceMoved`], not also
+/// [`ChangeKind::ExceptionAdded`], and a change of zone alone is
+/// [`ChangeKind::TimezoneChanged`] even though every instant shifts.
+/// Series are compared up to `until`; floating and all-day values are read
+/// in `default_timezone`.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`](crate::TruthError::InvalidTimezone)
+/// for an unknown TZID or `default_timezone`, or
+/// [`TruthError::InvalidRule`](crate::TruthError::InvalidRule) for an
+/// unparseable RRULE.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::interop::diff::{diff_calendars, ChangeKind};
+/// use truth_engine::interop::ics::parse_ics;
+///
+/// let series = |rrule: &str| {
+///     parse_ics(&format!(
+///         "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:standup\n\
+///          DTSTART:20260302T090000Z\nDURATION:PT15M\nRRULE:{rrule}\n\
+///          END:VEVENT\nEND:VCALENDAR\n"
+///     ))
+///     .unwrap()
+/// };
+/// let until = Utc.with_ymd_and_hms(2026, 12, 31, 0, 0, 0).unwrap();
+/// let changes =
+///     diff_calendars(&series("FREQ=DAILY;COUNT=5"), &series("FREQ=DAILY;COUNT=3"), until, "UTC")
+///         .unwrap();
+/// assert_eq!(changes.len(), 1);
+/// let ChangeKind::SeriesTruncated { removed } = &changes[0].kind else { panic!() };
+/// assert_eq!(removed[0], Utc.with_ymd_and_hms(2026, 3, 5, 9, 0, 0).unwrap());
+/// ```
+pub fn diff_calendars(
+    old: &Calendar,
+    new: &Calendar,
+    until: DateTime<Utc>,
+    default_timezone: &str,
+) -> Result<Vec<Change>> {
+    let default_tz = parse_timezone(default_timezone)?;
+    let old = by_uid(old);
+    let new = by_uid(new);
+    let uids: BTreeSet<&str> = old.keys().chain(new.keys()).copied().collect();
+    let mut changes = Vec::new();
+    for uid in uids {
+        let empty = Group::default();
+        let old = old.get(uid).unwrap_or(&empty);
+        let new = new.get(uid).unwrap_or(&empty);
+        let diff = GroupDiff {
+            until,
+            default_tz,
+            kinds: Vec::new(),
+        };
+        changes.extend(diff.run(old, new)?.into_iter().map(|kind| Change {
+            uid: uid.to_string(),
+            kind,
+        }));
+    }
+    Ok(changes)
+}
+
+/// A series master (or single event) and its overrides.
+#[derive(Default)]
+struct Group<'a> {
+    master: Option<&'a Event>,
+    overrides: Vec<&'a Event>,
+}
+
+fn by_uid(calendar: &Calendar) -> BTreeMap<&str, Group<'_>> {
+    let mut groups: BTreeMap<&str, Group> = BTreeMap::new();
+    for event in &calendar.events {
+        let Some(uid) = &event.uid else { continue };
+        let group = groups.entry(uid.as_str()).or_default();
+        if event.recurrence_id.is_some() {
+            group.overrides.push(event);
+        } else {
+            group.master.get_or_insert(event);
+        }
+    }
+    groups
+}
+
+struct GroupDiff {
+    until: DateTime<Utc>,
+    default_tz: Tz,
+    kinds: Vec<ChangeKind>,
+}
+
+impl GroupDiff {
+    fn run(mut self, old: &Group, new: &Group) -> Result<Vec<ChangeKind>> {
+        match (old.master, new.master) {
+            (None, Some(_)) => self.kinds.push(ChangeKind::Added),
+            (Some(_), None) => self.kinds.push(ChangeKind::Removed),
+            (Some(old), Some(new)) => self.masters(old, new)?,
+            (None, None) => {}
+        }
+        if old.master.is_some() != new.master.is_some() {
+            // Overrides of an added or removed series go with it.
+            return Ok(self.kinds);
+        }
+        let mut per_occurrence = self.exdates(old.master, new.master)?;
+        per_occurrence.extend(self.overrides(old, new)?);
+        per_occurrence.sort_by_key(|(original, _)| *original);
+        self.kinds
+            .extend(per_occurrence.into_iter().map(|(_, kind)| kind));
+        Ok(self.kinds)
+    }
+
+    fn masters(&mut self, old: &Event, new: &Event) -> Result<()> {
+        let old_cancelled = old.status == EventStatus::Cancelled;
+        let new_cancelled = new.status == EventStatus::Cancelled;
+        if old_cancelled != new_cancelled {
+            self.kinds.push(if new_cancelled {
+                ChangeKind::Cancelled
+            } else {
+                ChangeKind::Restored
+            });
+        }
+        let zone_changed = zone_name(&old.start) != zone_name(&new.start);
+        if zone_changed {
+            self.kinds.push(ChangeKind::TimezoneChanged {
+                from: zone_name(&old.start),
+                to: zone_name(&new.start),
+            });
+        }
+        let rescheduled = old.rrule != new.rrule
+            || wall_clock(&old.start) != wall_clock(&new.start)
+            || old.length(&self.default_tz)? != new.length(&self.default_tz)?
+            || old.rdates != new.rdates;
+        if rescheduled {
+            if old.rrule.is_none() && new.rrule.is_none() {
+                let from = old.single_occurrence(&self.default_tz)?;
+                let to = new.single_occurrence(&self.default_tz)?;
+                if let (Some(from), Some(to)) = (from, to) {
+                    if from != to {
+                        self.kinds.push(ChangeKind::OccurrenceMoved {
+                            original: from.start,
+                            from,
+                            to,
+                        });
+                    }
+                }
+            } else {
+                self.series(old, new)?;
+            }
+        }
+        self.kinds.extend(detail_change(None, old, new));
+        Ok(())
+    }
+
+    /// Classify a changed recurrence by comparing both series' starts,
+    /// ignoring EXDATEs (which are reported one by one).
+    fn series(&mut self, old: &Event, new: &Event) -> Result<()> {
+        let old = self.base_starts(old)?;
+        let new = self.base_starts(new)?;
+        let kind = if old == new {
+            return Ok(());
+        } else if old.starts_with(&new) {
+            ChangeKind::SeriesTruncated {
+                removed: old[new.len()..].to_vec(),
+            }
+        } else if new.starts_with(&old) {
+            ChangeKind::SeriesExtended {
+                added: new[old.len()..].to_vec(),
+            }
+        } else {
+            let old_set: BTreeSet<_> = old.iter().collect();
+            let new_set: BTreeSet<_> = new.iter().collect();
+            ChangeKind::RuleChanged {
+                added: new_set.difference(&old_set).map(|t| **t).collect(),
+                removed: old_set.difference(&new_set).map(|t| **t).collect(),
+            }
+        };
+        self.kinds.push(kind);
+        Ok(())
+    }
+
+    fn base_starts(&self, event: &Event) -> Result<Vec<DateTime<Utc>>> {
+        let mut base = event.clone();
+        base.exdates.clear();
+        Ok(base
+            .occurrences(self.until, &self.default_tz)?
+            .into_iter()
+            .map(|o| o.start)
+            .collect())
+    }
+
+    fn exdates(
+        &self,
+        old: Option<&Event>,
+        new: Option<&Event>,
+    ) -> Result<Vec<(DateTime<Utc>, ChangeKind)>> {
+        let old = self.exdate_instants(old)?;
+        let new = self.exdate_instants(new)?;
+        let cancelled = new
+            .difference(&old)
+            .map(|t| (*t, ChangeKind::OccurrenceCancelled { original: *t }));
+        let restored = old
+            .difference(&new)
+            .map(|t| (*t, ChangeKind::OccurrenceRestored { original: *t }));
+        Ok(cancelled.chain(restored).collect())
+    }
+
+    fn exdate_instants(&self, event: Option<&Event>) -> Result<BTreeSet<DateTime<Utc>>> {
+        let mut out = BTreeSet::new();
+        for exdate in event.iter().flat_map(|e| &e.exdates) {
+            out.extend(exdate.to_utc(&self.default_tz)?);
+        }
+        Ok(out)
+    }
+
+    fn overrides(&self, old: &Group, new: &Group) -> Result<Vec<(DateTime<Utc>, ChangeKind)>> {
+        let old_by_id = self.overrides_by_id(old)?;
+        let new_by_id = self.overrides_by_id(new)?;
+        let mut out = Vec::new();
+        for (original, event) in &new_by_id {
+            let original = *original;
+            let cancelled = event.status == EventStatus::Cancelled;
+            let now = event.single_occurrence(&self.default_tz)?;
+            let Some(before) = old_by_id.get(&original) else {
+                let slot = self.slot(new.master, original)?;
+                let kind = match now {
+                    _ if cancelled => ChangeKind::OccurrenceCancelled { original },
+                    Some(to) if to != slot => ChangeKind::OccurrenceMoved {
+                        original,
+                        from: slot,
+                        to,
+                    },
+                    _ => ChangeKind::ExceptionAdded { original },
+                };
+                out.push((original, kind));
+                continue;
+            };
+            let was_cancelled = before.status == EventStatus::Cancelled;
+            if cancelled != was_cancelled {
+                let kind = if cancelled {
+                    ChangeKind::OccurrenceCancelled { original }
+                } else {
+                    ChangeKind::OccurrenceRestored { original }
+                };
+                out.push((original, kind));
+                continue;
+            }
+            if let (Some(from), Some(to)) = (before.single_occurrence(&self.default_tz)?, now) {
+                if from != to {
+                    out.push((original, ChangeKind::OccurrenceMoved { original, from, to }));
+                }
+            }
+            if let Some(kind) = detail_change(Some(original), before, event) {
+                out.push((original, kind));
+            }
+        }
+        for original in old_by_id.keys() {
+            if !new_by_id.contains_key(original) {
+                let original = *original;
+                out.push((original, ChangeKind::ExceptionRemoved { original }));
+            }
+        }
+        Ok(out)
+    }
+
+    fn overrides_by_id<'a>(&self, group: &Group<'a>) -> Result<BTreeMap<DateTime<Utc>, &'a Event>> {
+        let mut out = BTreeMap::new();
+        for event in &group.overrides {
+            let id = event.recurrence_id.as_ref().expect("override");
+            if let Some(original) = id.to_utc(&self.default_tz)? {
+                out.insert(original, *event);
+            }
+        }
+        Ok(out)
+    }
+
+    /// The series occurrence an override starting at `original` replaces.
+    fn slot(&self, master: Option<&Event>, original: DateTime<Utc>) -> Result<ExpandedEvent> {
+        let length = match master {
+            Some(master) => master.length(&self.default_tz)?,
+            None => chrono::Duration::zero(),
+        };
+        Ok(ExpandedEvent::new(original, original + length))
+    }
+}
+
+fn detail_change(original: Option<DateTime<Utc>>, old: &Event, new: &Event) -> Option<ChangeKind> {
+    let mut fields = Vec::new();
+    if old.summary != new.summary {
+        fields.push("SUMMARY");
+    }
+    if old.description != new.description {
+        fields.push("DESCRIPTION");
+    }
+    if old.location != new.location {
+        fields.push("LOCATION");
+    }
+    if old.transparency != new.transparency {
+        fields.push("TRANSP");
+    }
+    (!fields.is_empty()).then_some(ChangeKind::DetailsChanged { original, fields })
+}
+
+fn zone_name(value: &DateTimeValue) -> Option<String> {
+    match value {
+        DateTimeValue::Utc(_) => Some("UTC".to_string()),
+        DateTimeValue::Local { tzid, .. } => tzid.clone(),
+        DateTimeValue::Date(_) => None,
+    }
+}
+
+/// The value's local date and time, whatever zone it is in.
+fn wall_clock(value: &DateTimeValue) -> NaiveDateTime {
+    match value {
+        DateTimeValue::Utc(dt) => dt.naive_utc(),
+        DateTimeValue::Local { datetime, .. } => *datetime,
+        DateTimeValue::Date(d) => d.and_time(NaiveTime::MIN),
+    }
+}
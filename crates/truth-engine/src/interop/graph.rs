@@ -0,0 +1,891 @@
+//! Microsoft Graph calendar events.
+//!
+//! [`GraphEvent`] mirrors the parts of Graph's `event` resource that affect
+//! time: start/end with their `timeZone`, `isAllDay`, `showAs`, `isCancelled`,
+//! the `recurrence` pattern and range, and the `type` / `seriesMasterId` /
+//! `originalStart` fields that tie exceptions to their series. Deserialize
+//! Graph JSON into it (unknown fields are ignored), then:
+//!
+//! - [`from_graph`] builds an [`ics::Calendar`](super::ics::Calendar): series
+//!   masters carry an RRULE, exceptions become RECURRENCE-ID overrides, so
+//!   [`Calendar::expand`](super::ics::Calendar::expand) applies them like any
+//!   other calendar.
+//! - [`to_graph`] goes the other way, for writing computed schedules back.
+//! - [`recurrence_to_rrule`] / [`rrule_to_recurrence`] convert just the
+//!   recurrence.
+//!
+//! Graph reports Windows zone names ("Pacific Standard Time") unless asked
+//! for IANA ones; both are accepted. Output uses the Windows name when one
+//! maps to the zone.
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::ics::{invalid, Calendar, DateTimeValue, Event, EventStatus, Transparency};
+use crate::error::{Result, TruthError};
+use crate::temporal::{local_to_utc, parse_timezone};
+
+/// A Graph `event`, reduced to its scheduling fields.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GraphEvent {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub subject: Option<String>,
+    #[cfg_attr(feature = "serde", serde(rename = "type", default))]
+    pub event_type: GraphEventType,
+    /// For occurrences and exceptions: the id of the series master.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub series_master_id: Option<String>,
+    pub start: DateTimeTimeZone,
+    pub end: DateTimeTimeZone,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub is_all_day: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub is_cancelled: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub show_as: ShowAs,
+    /// For exceptions: the start the occurrence had before it was changed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub original_start: Option<DateTime<Utc>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub recurrence: Option<PatternedRecurrence>,
+}
+
+/// Graph's `dateTimeTimeZone`: a wall-clock time and the zone it is in.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct DateTimeTimeZone {
+    pub date_time: NaiveDateTime,
+    /// A Windows or IANA zone name, or `"UTC"`.
+    pub time_zone: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum GraphEventType {
+    #[default]
+    SingleInstance,
+    Occurrence,
+    Exception,
+    SeriesMaster,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum ShowAs {
+    Free,
+    Tentative,
+    #[default]
+    Busy,
+    Oof,
+    WorkingElsewhere,
+    Unknown,
+}
+
+/// Graph's `patternedRecurrence`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PatternedRecurrence {
+    pub pattern: RecurrencePattern,
+    pub range: RecurrenceRange,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct RecurrencePattern {
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub pattern_type: PatternType,
+    #[cfg_attr(feature = "serde", serde(default = "one"))]
+    pub interval: u32,
+    /// Month of the year (1–12) for yearly patterns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub month: u32,
+    /// Day of the month for absolute monthly and yearly patterns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub day_of_month: u32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub days_of_week: Vec<DayOfWeek>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub first_day_of_week: DayOfWeek,
+    /// Which of `days_of_week` in the month, for relative patterns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub index: WeekIndex,
+}
+
+fn one() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum PatternType {
+    Daily,
+    Weekly,
+    AbsoluteMonthly,
+    RelativeMonthly,
+    AbsoluteYearly,
+    RelativeYearly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum DayOfWeek {
+    #[default]
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum WeekIndex {
+    #[default]
+    First,
+    Second,
+    Third,
+    Fourth,
+    Last,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct RecurrenceRange {
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub range_type: RangeType,
+    pub start_date: NaiveDate,
+    /// Last day of the series (inclusive) for [`RangeType::EndDate`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_date: Option<NaiveDate>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub recurrence_time_zone: Option<String>,
+    /// Occurrence count for [`RangeType::Numbered`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub number_of_occurrences: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum RangeType {
+    EndDate,
+    NoEnd,
+    Numbered,
+}
+
+// ── Graph → engine ──────────────────────────────────────────────────────────
+
+/// Build a [`Calendar`] from Graph events.
+///
+/// Series masters become recurring events (UID = the Graph id); exceptions
+/// become overrides of their master (UID = `seriesMasterId`, RECURRENCE-ID =
+/// `originalStart`). Generated occurrences are dropped when their master is
+/// present, since expanding the master reproduces them, and kept as single
+/// events otherwise (as in a `calendarView` response). `showAs: free` maps to
+/// TRANSP:TRANSPARENT, `tentative` to STATUS:TENTATIVE, and `isCancelled` to
+/// STATUS:CANCELLED.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] for an unknown zone,
+/// [`TruthError::InvalidCalendar`] for an exception without `originalStart`,
+/// or [`TruthError::InvalidRule`] for an inconsistent recurrence.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::interop::graph::{from_graph, GraphEvent};
+///
+/// let master: GraphEvent = serde_json::from_str(r#"{
+///     "id": "AAMk-1",
+///     "type": "seriesMaster",
+///     "start": {"dateTime": "2026-03-02T09:00:00.0000000", "timeZone": "Pacific Standard Time"},
+///     "end": {"dateTime": "2026-03-02T09:30:00.0000000", "timeZone": "Pacific Standard Time"},
+///     "recurrence": {
+///         "pattern": {"type": "weekly", "interval": 1, "daysOfWeek": ["monday", "thursday"]},
+///         "range": {"type": "numbered", "startDate": "2026-03-02", "numberOfOccurrences": 4}
+///     }
+/// }"#).unwrap();
+/// let calendar = from_graph(&[master]).unwrap();
+/// let until = Utc.with_ymd_and_hms(2026, 12, 31, 0, 0, 0).unwrap();
+/// let occurrences = calendar.expand(until, "UTC").unwrap();
+/// assert_eq!(occurrences.len(), 4);
+/// assert_eq!(occurrences[1].start, Utc.with_ymd_and_hms(2026, 3, 5, 17, 0, 0).unwrap());
+/// ```
+pub fn from_graph(events: &[GraphEvent]) -> Result<Calendar> {
+    let has_master = |id: &Option<String>| {
+        id.is_some()
+            && events
+                .iter()
+                .any(|e| e.event_type == GraphEventType::SeriesMaster && e.id == *id)
+    };
+    let mut calendar = Calendar::default();
+    for graph in events {
+        let event = match graph.event_type {
+            GraphEventType::Occurrence if has_master(&graph.series_master_id) => continue,
+            GraphEventType::Exception if has_master(&graph.series_master_id) => {
+                let original = graph.original_start.ok_or_else(|| {
+                    invalid(format!(
+                        "exception {} has no originalStart",
+                        graph.id.as_deref().unwrap_or("?")
+                    ))
+                })?;
+                Event {
+                    uid: graph.series_master_id.clone(),
+                    recurrence_id: Some(DateTimeValue::Utc(original)),
+                    ..engine_event(graph)?
+                }
+            }
+            _ => engine_event(graph)?,
+        };
+        calendar.events.push(event);
+    }
+    Ok(calendar)
+}
+
+/// One Graph event as an engine event, ignoring series linkage.
+fn engine_event(graph: &GraphEvent) -> Result<Event> {
+    let (start, end) = if graph.is_all_day {
+        (
+            DateTimeValue::Date(graph.start.date_time.date()),
+            DateTimeValue::Date(graph.end.date_time.date()),
+        )
+    } else {
+        (
+            value_from_graph(&graph.start)?,
+            value_from_graph(&graph.end)?,
+        )
+    };
+    let rrule = match (&graph.recurrence, graph.event_type) {
+        (Some(recurrence), GraphEventType::SeriesMaster | GraphEventType::SingleInstance) => {
+            let zone = recurrence
+                .range
+                .recurrence_time_zone
+                .as_deref()
+                .unwrap_or(&graph.start.time_zone);
+            Some(recurrence_to_rrule(recurrence, zone)?)
+        }
+        _ => None,
+    };
+    Ok(Event {
+        uid: graph.id.clone(),
+        summary: graph.subject.clone(),
+        description: None,
+        location: None,
+        start,
+        end: Some(end),
+        duration: None,
+        rrule,
+        exdates: Vec::new(),
+        rdates: Vec::new(),
+        recurrence_id: None,
+        transparency: match graph.show_as {
+            ShowAs::Free => Transparency::Transparent,
+            _ => Transparency::Opaque,
+        },
+        status: match (graph.is_cancelled, graph.show_as) {
+            (true, _) => EventStatus::Cancelled,
+            (false, ShowAs::Tentative) => EventStatus::Tentative,
+            _ => EventStatus::Confirmed,
+        },
+        alarms: Vec::new(),
+    })
+}
+
+fn value_from_graph(value: &DateTimeTimeZone) -> Result<DateTimeValue> {
+    let tz = graph_zone(&value.time_zone)?;
+    Ok(if tz == Tz::UTC {
+        DateTimeValue::Utc(value.date_time.and_utc())
+    } else {
+        DateTimeValue::Local {
+            datetime: value.date_time,
+            tzid: Some(tz.name().to_string()),
+        }
+    })
+}
+
+/// Convert a Graph recurrence to an RRULE, reading `endDate` in `timezone`.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] for an unknown zone, or
+/// [`TruthError::InvalidRule`] if a pattern lacks the fields its type needs
+/// (e.g., a weekly pattern with no `daysOfWeek`).
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::interop::graph::recurrence_to_rrule;
+///
+/// let recurrence = serde_json::from_str(r#"{
+///     "pattern": {"type": "relativeMonthly", "interval": 1,
+///                 "daysOfWeek": ["tuesday"], "index": "third"},
+///     "range": {"type": "noEnd", "startDate": "2026-02-17"}
+/// }"#).unwrap();
+/// assert_eq!(
+///     recurrence_to_rrule(&recurrence, "America/Los_Angeles").unwrap(),
+///     "FREQ=MONTHLY;BYDAY=TU;BYSETPOS=3"
+/// );
+/// ```
+pub fn recurrence_to_rrule(recurrence: &PatternedRecurrence, timezone: &str) -> Result<String> {
+    let pattern = &recurrence.pattern;
+    let days = || -> Result<String> {
+        if pattern.days_of_week.is_empty() {
+            return Err(TruthError::InvalidRule(
+                format!("{:?} pattern has no daysOfWeek", pattern.pattern_type).into(),
+            ));
+        }
+        Ok(pattern
+            .days_of_week
+            .iter()
+            .map(|d| weekday_code(d.weekday()))
+            .collect::<Vec<_>>()
+            .join(","))
+    };
+    let index = match pattern.index {
+        WeekIndex::First => 1,
+        WeekIndex::Second => 2,
+        WeekIndex::Third => 3,
+        WeekIndex::Fourth => 4,
+        WeekIndex::Last => -1,
+    };
+
+    let mut parts = vec![format!(
+        "FREQ={}",
+        match pattern.pattern_type {
+            PatternType::Daily => "DAILY",
+            PatternType::Weekly => "WEEKLY",
+            PatternType::AbsoluteMonthly | PatternType::RelativeMonthly => "MONTHLY",
+            PatternType::AbsoluteYearly | PatternType::RelativeYearly => "YEARLY",
+        }
+    )];
+    if pattern.interval > 1 {
+        parts.push(format!("INTERVAL={}", pattern.interval));
+    }
+    match pattern.pattern_type {
+        PatternType::Daily => {}
+        PatternType::Weekly => {
+            parts.push(format!("BYDAY={}", days()?));
+            parts.push(format!(
+                "WKST={}",
+                weekday_code(pattern.first_day_of_week.weekday())
+            ));
+        }
+        PatternType::AbsoluteMonthly => parts.push(format!("BYMONTHDAY={}", pattern.day_of_month)),
+        PatternType::RelativeMonthly => {
+            parts.push(format!("BYDAY={}", days()?));
+            parts.push(format!("BYSETPOS={}", index));
+        }
+        PatternType::AbsoluteYearly => {
+            parts.push(format!("BYMONTH={}", pattern.month));
+            parts.push(format!("BYMONTHDAY={}", pattern.day_of_month));
+        }
+        PatternType::RelativeYearly => {
+            parts.push(format!("BYMONTH={}", pattern.month));
+            parts.push(format!("BYDAY={}", days()?));
+            parts.push(format!("BYSETPOS={}", index));
+        }
+    }
+
+    let range = &recurrence.range;
+    match range.range_type {
+        RangeType::NoEnd => {}
+        RangeType::Numbered => parts.push(format!("COUNT={}", range.number_of_occurrences)),
+        RangeType::EndDate => {
+            let end = range
+                .end_date
+                .ok_or_else(|| TruthError::InvalidRule("endDate range has no endDate".into()))?;
+            let tz = graph_zone(timezone)?;
+            // The end date is inclusive: the series runs through its last second.
+            let last = end.and_hms_opt(23, 59, 59).unwrap_or_default();
+            let until = local_to_utc(&tz, last).unwrap_or_else(|| last.and_utc());
+            parts.push(format!("UNTIL={}", until.format("%Y%m%dT%H%M%SZ")));
+        }
+    }
+    Ok(parts.join(";"))
+}
+
+// ── Engine → Graph ──────────────────────────────────────────────────────────
+
+/// Convert a [`Calendar`] to Graph events.
+///
+/// Recurring events become series masters, overrides become exceptions of
+/// their series, and everything else a single instance. Graph has no
+/// equivalent of EXDATE on the series itself, so each EXDATE becomes a
+/// cancelled exception the caller can delete. Floating and all-day values
+/// are placed in `default_timezone`.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] for an unknown zone, or
+/// [`TruthError::InvalidRule`] for an RRULE Graph cannot express (see
+/// [`rrule_to_recurrence`]).
+pub fn to_graph(calendar: &Calendar, default_timezone: &str) -> Result<Vec<GraphEvent>> {
+    let default_tz = parse_timezone(default_timezone)?;
+    let mut out = Vec::new();
+    for event in &calendar.events {
+        let length = event.length(&default_tz)?;
+        let all_day = matches!(event.start, DateTimeValue::Date(_));
+        let start = value_to_graph(&event.start, &default_tz)?;
+        let end = match &event.end {
+            Some(end) => value_to_graph(end, &default_tz)?,
+            None => DateTimeTimeZone {
+                date_time: start.date_time + length,
+                time_zone: start.time_zone.clone(),
+            },
+        };
+        let mut graph = GraphEvent {
+            id: event.uid.clone(),
+            subject: event.summary.clone(),
+            event_type: GraphEventType::SingleInstance,
+            series_master_id: None,
+            start,
+            end,
+            is_all_day: all_day,
+            is_cancelled: event.status == EventStatus::Cancelled,
+            show_as: match (event.transparency, event.status) {
+                (Transparency::Transparent, _) => ShowAs::Free,
+                (_, EventStatus::Tentative) => ShowAs::Tentative,
+                _ => ShowAs::Busy,
+            },
+            original_start: None,
+            recurrence: None,
+        };
+
+        if let Some(id) = &event.recurrence_id {
+            graph.id = None;
+            graph.event_type = GraphEventType::Exception;
+            graph.series_master_id = event.uid.clone();
+            graph.original_start = id.to_utc(&default_tz)?;
+        } else if let Some(rrule) = &event.rrule {
+            let zone = event.start.zone(&default_tz)?;
+            let first = event.start.to_utc(&default_tz)?.ok_or_else(|| {
+                TruthError::InvalidDatetime("series start does not exist in its zone".into())
+            })?;
+            graph.event_type = GraphEventType::SeriesMaster;
+            graph.recurrence = Some(rrule_to_recurrence(
+                rrule,
+                first.with_timezone(&zone).date_naive(),
+                zone.name(),
+            )?);
+            for exdate in &event.exdates {
+                let Some(at) = exdate.to_utc(&default_tz)? else {
+                    continue;
+                };
+                let local = at.with_timezone(&zone).naive_local();
+                let value = |dt: NaiveDateTime| DateTimeTimeZone {
+                    date_time: dt,
+                    time_zone: graph_zone_name(&zone),
+                };
+                out.push(GraphEvent {
+                    id: None,
+                    event_type: GraphEventType::Exception,
+                    series_master_id: event.uid.clone(),
+                    start: value(local),
+                    end: value(local + length),
+                    is_cancelled: true,
+                    original_start: Some(at),
+                    recurrence: None,
+                    ..graph.clone()
+                });
+            }
+        }
+        out.push(graph);
+    }
+    // Masters before their exceptions, as Graph expects when creating.
+    out.sort_by_key(|g| g.event_type == GraphEventType::Exception);
+    Ok(out)
+}
+
+fn value_to_graph(value: &DateTimeValue, default_tz: &Tz) -> Result<DateTimeTimeZone> {
+    Ok(match value {
+        DateTimeValue::Date(d) => DateTimeTimeZone {
+            date_time: d.and_time(NaiveTime::MIN),
+            time_zone: graph_zone_name(default_tz),
+        },
+        DateTimeValue::Utc(dt) => DateTimeTimeZone {
+            date_time: dt.naive_utc(),
+            time_zone: "UTC".to_string(),
+        },
+        DateTimeValue::Local { datetime, .. } => DateTimeTimeZone {
+            date_time: *datetime,
+            time_zone: graph_zone_name(&value.zone(default_tz)?),
+        },
+    })
+}
+
+/// Convert an RRULE to a Graph recurrence for a series starting on `start`
+/// in `timezone`.
+///
+/// Graph patterns cover daily, weekly, and monthly/yearly by day of month or
+/// by "nth weekday"; a rule outside that (hourly frequencies, BYHOUR, several
+/// month days, ordinals other than 1–4 and last) is rejected rather than
+/// approximated.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidRule`] for rules Graph cannot express, or
+/// [`TruthError::InvalidTimezone`] for an unknown zone.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use truth_engine::interop::graph::{rrule_to_recurrence, PatternType, WeekIndex};
+///
+/// let start = NaiveDate::from_ymd_opt(2026, 2, 27).unwrap();
+/// let recurrence = rrule_to_recurrence("FREQ=MONTHLY;BYDAY=-1FR;COUNT=6", start, "UTC").unwrap();
+/// assert_eq!(recurrence.pattern.pattern_type, PatternType::RelativeMonthly);
+/// assert_eq!(recurrence.pattern.index, WeekIndex::Last);
+/// assert_eq!(recurrence.range.number_of_occurrences, 6);
+/// ```
+pub fn rrule_to_recurrence(
+    rrule: &str,
+    start: NaiveDate,
+    timezone: &str,
+) -> Result<PatternedRecurrence> {
+    let unsupported = |what: &str| {
+        TruthError::InvalidRule(
+            format!("Graph recurrence cannot express {} in '{}'", what, rrule).into(),
+        )
+    };
+    let tz = graph_zone(timezone)?;
+
+    let mut freq = None;
+    let mut interval = 1;
+    let mut by_day: Vec<(Option<i32>, Weekday)> = Vec::new();
+    let mut by_month_day = None;
+    let mut by_month = None;
+    let mut set_pos = None;
+    let mut wkst = Weekday::Mon;
+    let mut range = RecurrenceRange {
+        range_type: RangeType::NoEnd,
+        start_date: start,
+        end_date: None,
+        recurrence_time_zone: Some(graph_zone_name(&tz)),
+        number_of_occurrences: 0,
+    };
+    let number = |v: &str| {
+        v.parse::<i32>().map_err(|_| {
+            TruthError::InvalidRule(format!("'{}' is not a number in '{}'", v, rrule).into())
+        })
+    };
+
+    for part in rrule.split(';').filter(|p| !p.is_empty()) {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| TruthError::InvalidRule(format!("malformed part '{}'", part).into()))?;
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => freq = Some(value.to_ascii_uppercase()),
+            "INTERVAL" => interval = number(value)?.max(1) as u32,
+            "COUNT" => {
+                range.range_type = RangeType::Numbered;
+                range.number_of_occurrences = number(value)?.max(0) as u32;
+            }
+            "UNTIL" => {
+                let date = parse_until(value, &tz).ok_or_else(|| {
+                    TruthError::InvalidRule(format!("bad UNTIL '{}'", value).into())
+                })?;
+                range.range_type = RangeType::EndDate;
+                range.end_date = Some(date);
+            }
+            "BYDAY" => {
+                for day in value.split(',') {
+                    by_day.push(parse_by_day(day).ok_or_else(|| unsupported(day))?);
+                }
+            }
+            "BYMONTHDAY" if !value.contains(',') => by_month_day = Some(number(value)?),
+            "BYMONTH" if !value.contains(',') => by_month = Some(number(value)?),
+            "BYSETPOS" if !value.contains(',') => set_pos = Some(number(value)?),
+            "WKST" => {
+                wkst = parse_by_day(value)
+                    .filter(|(n, _)| n.is_none())
+                    .map(|(_, d)| d)
+                    .ok_or_else(|| unsupported(part))?
+            }
+            _ => return Err(unsupported(part)),
+        }
+    }
+
+    let freq =
+        freq.ok_or_else(|| TruthError::InvalidRule(format!("no FREQ in '{}'", rrule).into()))?;
+    // "FREQ=DAILY;BYDAY=MO,...,FR" is a weekly pattern in Graph.
+    let weekly_days = matches!(freq.as_str(), "DAILY" if !by_day.is_empty() && interval == 1);
+    let days: Vec<DayOfWeek> = by_day.iter().map(|(_, d)| DayOfWeek::from(*d)).collect();
+
+    // A relative pattern's position: BYSETPOS, or the shared ordinal on BYDAY.
+    let ordinal = match (set_pos, by_day.first().and_then(|(n, _)| *n)) {
+        (Some(pos), None) if by_day.iter().all(|(n, _)| n.is_none()) => Some(pos),
+        (None, Some(n)) if by_day.iter().all(|(m, _)| *m == Some(n)) => Some(n),
+        (None, None) => None,
+        _ => return Err(unsupported("mixed BYDAY ordinals")),
+    };
+    let index = ordinal
+        .map(|n| match n {
+            1 => Ok(WeekIndex::First),
+            2 => Ok(WeekIndex::Second),
+            3 => Ok(WeekIndex::Third),
+            4 => Ok(WeekIndex::Fourth),
+            -1 => Ok(WeekIndex::Last),
+            _ => Err(unsupported(&format!("position {}", n))),
+        })
+        .transpose()?;
+
+    let mut pattern = RecurrencePattern {
+        pattern_type: PatternType::Daily,
+        interval,
+        month: 0,
+        day_of_month: 0,
+        days_of_week: Vec::new(),
+        first_day_of_week: DayOfWeek::from(wkst),
+        index: index.unwrap_or_default(),
+    };
+    let month_day = by_month_day.unwrap_or(start.day() as i32);
+    if !(1..=31).contains(&month_day) {
+        return Err(unsupported(&format!("BYMONTHDAY={}", month_day)));
+    }
+    match freq.as_str() {
+        "DAILY" if weekly_days => {
+            pattern.pattern_type = PatternType::Weekly;
+            pattern.days_of_week = days;
+        }
+        "DAILY" if by_day.is_empty() && by_month_day.is_none() && by_month.is_none() => {}
+        "WEEKLY" if index.is_none() && by_month_day.is_none() && by_month.is_none() => {
+            pattern.pattern_type = PatternType::Weekly;
+            pattern.days_of_week = if days.is_empty() {
+                vec![DayOfWeek::from(start.weekday())]
+            } else {
+                days
+            };
+        }
+        "MONTHLY" | "YEARLY" if by_month.is_none() || freq == "YEARLY" => {
+            let yearly = freq == "YEARLY";
+            pattern.month = if yearly {
+                by_month.unwrap_or(start.month() as i32) as u32
+            } else {
+                0
+            };
+            if by_day.is_empty() {
+                pattern.pattern_type = if yearly {
+                    PatternType::AbsoluteYearly
+                } else {
+                    PatternType::AbsoluteMonthly
+                };
+                pattern.day_of_month = month_day as u32;
+            } else if index.is_some() && by_month_day.is_none() {
+                pattern.pattern_type = if yearly {
+                    PatternType::RelativeYearly
+                } else {
+                    PatternType::RelativeMonthly
+                };
+                pattern.days_of_week = days;
+            } else {
+                return Err(unsupported("BYDAY without a position"));
+            }
+        }
+        other => return Err(unsupported(&format!("FREQ={} with these parts", other))),
+    }
+    Ok(PatternedRecurrence { pattern, range })
+}
+
+/// `MO` / `3TU` / `-1FR` → (ordinal, weekday).
+fn parse_by_day(s: &str) -> Option<(Option<i32>, Weekday)> {
+    let s = s.trim();
+    let (ordinal, code) = s.split_at(s.len().checked_sub(2)?);
+    let weekday = match code.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    };
+    let ordinal = match ordinal {
+        "" => None,
+        n => Some(n.trim_start_matches('+').parse().ok()?),
+    };
+    Some((ordinal, weekday))
+}
+
+/// The local date of an UNTIL value (`20260401T040000Z`, `20260401T000000`, `20260401`).
+fn parse_until(value: &str, tz: &Tz) -> Option<NaiveDate> {
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(naive.and_utc().with_timezone(tz).date_naive());
+    }
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .map(|dt| dt.date())
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y%m%d"))
+        .ok()
+}
+
+fn weekday_code(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+impl DayOfWeek {
+    /// The corresponding `chrono` weekday.
+    pub fn weekday(self) -> Weekday {
+        match self {
+            Self::Sunday => Weekday::Sun,
+            Self::Monday => Weekday::Mon,
+            Self::Tuesday => Weekday::Tue,
+            Self::Wednesday => Weekday::Wed,
+            Self::Thursday => Weekday::Thu,
+            Self::Friday => Weekday::Fri,
+            Self::Saturday => Weekday::Sat,
+        }
+    }
+}
+
+impl From<Weekday> for DayOfWeek {
+    fn from(day: Weekday) -> Self {
+        match day {
+            Weekday::Sun => Self::Sunday,
+            Weekday::Mon => Self::Monday,
+            Weekday::Tue => Self::Tuesday,
+            Weekday::Wed => Self::Wednesday,
+            Weekday::Thu => Self::Thursday,
+            Weekday::Fri => Self::Friday,
+            Weekday::Sat => Self::Saturday,
+        }
+    }
+}
+
+// ── Zone names ──────────────────────────────────────────────────────────────
+
+/// Windows zone names and their primary IANA zones (CLDR `windowsZones`,
+/// territory 001), for the zones Graph commonly reports.
+const WINDOWS_ZONES: &[(&str, &str)] = &[
+    ("Dateline Standard Time", "Etc/GMT+12"),
+    ("UTC-11", "Etc/GMT+11"),
+    ("Hawaiian Standard Time", "Pacific/Honolulu"),
+    ("Alaskan Standard Time", "America/Anchorage"),
+    ("Pacific Standard Time", "America/Los_Angeles"),
+    ("US Mountain Standard Time", "America/Phoenix"),
+    ("Mountain Standard Time", "America/Denver"),
+    ("Central America Standard Time", "America/Guatemala"),
+    ("Central Standard Time", "America/Chicago"),
+    ("Central Standard Time (Mexico)", "America/Mexico_City"),
+    ("Canada Central Standard Time", "America/Regina"),
+    ("SA Pacific Standard Time", "America/Bogota"),
+    ("Eastern Standard Time", "America/New_York"),
+    ("US Eastern Standard Time", "America/Indiana/Indianapolis"),
+    ("Atlantic Standard Time", "America/Halifax"),
+    ("SA Western Standard Time", "America/La_Paz"),
+    ("Newfoundland Standard Time", "America/St_Johns"),
+    ("E. South America Standard Time", "America/Sao_Paulo"),
+    ("Argentina Standard Time", "America/Argentina/Buenos_Aires"),
+    ("UTC", "UTC"),
+    ("GMT Standard Time", "Europe/London"),
+    ("Greenwich Standard Time", "Atlantic/Reykjavik"),
+    ("W. Europe Standard Time", "Europe/Berlin"),
+    ("Central Europe Standard Time", "Europe/Budapest"),
+    ("Romance Standard Time", "Europe/Paris"),
+    ("Central European Standard Time", "Europe/Warsaw"),
+    ("W. Central Africa Standard Time", "Africa/Lagos"),
+    ("GTB Standard Time", "Europe/Bucharest"),
+    ("FLE Standard Time", "Europe/Kyiv"),
+    ("Israel Standard Time", "Asia/Jerusalem"),
+    ("Egypt Standard Time", "Africa/Cairo"),
+    ("South Africa Standard Time", "Africa/Johannesburg"),
+    ("Russian Standard Time", "Europe/Moscow"),
+    ("Turkey Standard Time", "Europe/Istanbul"),
+    ("Arab Standard Time", "Asia/Riyadh"),
+    ("Arabian Standard Time", "Asia/Dubai"),
+    ("Iran Standard Time", "Asia/Tehran"),
+    ("Pakistan Standard Time", "Asia/Karachi"),
+    ("India Standard Time", "Asia/Kolkata"),
+    ("Nepal Standard Time", "Asia/Kathmandu"),
+    ("Bangladesh Standard Time", "Asia/Dhaka"),
+    ("SE Asia Standard Time", "Asia/Bangkok"),
+    ("China Standard Time", "Asia/Shanghai"),
+    ("Singapore Standard Time", "Asia/Singapore"),
+    ("Taipei Standard Time", "Asia/Taipei"),
+    ("W. Australia Standard Time", "Australia/Perth"),
+    ("Tokyo Standard Time", "Asia/Tokyo"),
+    ("Korea Standard Time", "Asia/Seoul"),
+    ("Cen. Australia Standard Time", "Australia/Adelaide"),
+    ("AUS Central Standard Time", "Australia/Darwin"),
+    ("E. Australia Standard Time", "Australia/Brisbane"),
+    ("AUS Eastern Standard Time", "Australia/Sydney"),
+    ("New Zealand Standard Time", "Pacific/Auckland"),
+];
+
+/// Resolve a Graph `timeZone`: a Windows name, else an IANA name.
+fn graph_zone(name: &str) -> Result<Tz> {
+    match WINDOWS_ZONES
+        .iter()
+        .find(|(windows, _)| windows.eq_ignore_ascii_case(name.trim()))
+    {
+        Some((_, iana)) => parse_timezone(iana),
+        None => parse_timezone(name.trim()),
+    }
+}
+
+/// The name to send Graph for a zone: its Windows name when it has one.
+fn graph_zone_name(tz: &Tz) -> String {
+    WINDOWS_ZONES
+        .iter()
+        .find(|(_, iana)| *iana == tz.name())
+        .map_or_else(|| tz.name().to_string(), |(windows, _)| windows.to_string())
+}
@@ -0,0 +1,820 @@
+//! iCalendar (RFC 5545) parsing and generation.
+//!
+//! [`parse_ics`] reads the VEVENT components of a VCALENDAR into [`Event`]s,
+//! and [`Calendar::expand`] / [`Calendar::busy`] turn them into the
+//! [`ExpandedEvent`]s that [`find_conflicts`](crate::find_conflicts),
+//! [`find_free_slots`](crate::find_free_slots), and
+//! [`merge_availability`](crate::merge_availability) take — applying RRULE,
+//! RDATE, EXDATE, RECURRENCE-ID overrides, STATUS, and TRANSP the way a
+//! calendar client would.
+//!
+//! TZID parameters must name IANA zones (or unambiguous abbreviations);
+//! VTIMEZONE definitions are not read here (see
+//! [`tzdist`](super::tzdist) for loading them as zone rules). Floating and
+//! all-day values are placed in the timezone the caller supplies.
+//!
+//! [`write_ics`] serializes a [`Calendar`] back to text — including one built
+//! from computed occurrences with [`Calendar::from_occurrences`] — and
+//! [`write_freebusy`] turns busy blocks into a VFREEBUSY, which
+//! [`parse_freebusy`] reads back.
+//!
+//! VALARMs are kept on their event, and [`Calendar::alarms`] computes when
+//! they fire across a series.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+
+use crate::error::{Result, TruthError};
+use crate::expander::{expand_rrule_with_exdates, ExpandedEvent};
+use crate::temporal::interval::{merge, TimeInterval};
+use crate::temporal::{local_to_utc, parse_timezone};
+use alarm::AlarmBuilder;
+
+mod alarm;
+mod write;
+
+pub use alarm::{Alarm, AlarmAction, AlarmFire, AlarmTrigger, TriggerRelation};
+#[cfg(feature = "serde")]
+pub(crate) use write::Lines;
+pub(crate) use write::{escape_text, write_calendar};
+pub use write::{write_freebusy, write_ics};
+
+/// The events of a parsed VCALENDAR.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Calendar {
+    /// Every VEVENT, in document order. Overrides (events with a
+    /// [`recurrence_id`](Event::recurrence_id)) are listed alongside the series
+    /// they modify.
+    pub events: Vec<Event>,
+}
+
+/// One VEVENT.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    /// UID — shared by a recurring series and its overrides.
+    pub uid: Option<String>,
+    /// SUMMARY, unescaped.
+    pub summary: Option<String>,
+    /// DESCRIPTION, unescaped.
+    pub description: Option<String>,
+    /// LOCATION, unescaped.
+    pub location: Option<String>,
+    /// DTSTART.
+    pub start: DateTimeValue,
+    /// DTEND, if given.
+    pub end: Option<DateTimeValue>,
+    /// DURATION, if given instead of DTEND.
+    pub duration: Option<chrono::Duration>,
+    /// RRULE value (e.g., `"FREQ=WEEKLY;BYDAY=TU,TH"`).
+    pub rrule: Option<String>,
+    /// EXDATE values, across all EXDATE lines.
+    pub exdates: Vec<DateTimeValue>,
+    /// RDATE values, across all RDATE lines. For PERIOD values only the start is kept.
+    pub rdates: Vec<DateTimeValue>,
+    /// RECURRENCE-ID: the original start of the occurrence this event replaces.
+    pub recurrence_id: Option<DateTimeValue>,
+    /// TRANSP.
+    pub transparency: Transparency,
+    /// STATUS.
+    pub status: EventStatus,
+    /// VALARM components.
+    pub alarms: Vec<Alarm>,
+}
+
+/// A DATE or DATE-TIME property value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateTimeValue {
+    /// A DATE value (`20260217`) — an all-day event.
+    Date(NaiveDate),
+    /// A UTC DATE-TIME (`20260217T140000Z`).
+    Utc(DateTime<Utc>),
+    /// A local DATE-TIME, in the `TZID` zone or floating when `tzid` is `None`.
+    Local {
+        datetime: NaiveDateTime,
+        tzid: Option<String>,
+    },
+}
+
+/// Whether an event blocks time (TRANSP).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Transparency {
+    /// Busy time (the RFC 5545 default).
+    #[default]
+    Opaque,
+    /// Shown on the calendar but does not block time.
+    Transparent,
+}
+
+/// An event's STATUS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventStatus {
+    Tentative,
+    /// Also used when STATUS is absent.
+    #[default]
+    Confirmed,
+    Cancelled,
+}
+
+/// Parse iCalendar text into a [`Calendar`].
+///
+/// Folded lines are unfolded and both CRLF and bare LF line endings are
+/// accepted. VALARMs inside an event are kept in [`Event::alarms`]; other
+/// components (VTIMEZONE, VTODO) are skipped, as are unknown properties.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidCalendar`] if a component is left open or
+/// closed out of order, a VEVENT has no DTSTART, or a property line is
+/// malformed, or a VALARM has no TRIGGER; [`TruthError::InvalidDatetime`] or
+/// [`TruthError::InvalidDuration`] for an unparseable value.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::interop::ics::parse_ics;
+///
+/// let calendar = parse_ics(
+///     "BEGIN:VCALENDAR\r\n\
+///      BEGIN:VEVENT\r\n\
+///      UID:standup@example.com\r\n\
+///      DTSTART;TZID=America/New_York:20260216T090000\r\n\
+///      DURATION:PT15M\r\n\
+///      RRULE:FREQ=DAILY;COUNT=5\r\n\
+///      END:VEVENT\r\n\
+///      END:VCALENDAR\r\n",
+/// )
+/// .unwrap();
+/// assert_eq!(calendar.events.len(), 1);
+/// assert_eq!(calendar.events[0].rrule.as_deref(), Some("FREQ=DAILY;COUNT=5"));
+/// ```
+pub fn parse_ics(input: &str) -> Result<Calendar> {
+    let mut calendar = Calendar::default();
+    let mut stack: Vec<String> = Vec::new();
+    let mut current: Option<EventBuilder> = None;
+    let mut alarm: Option<AlarmBuilder> = None;
+
+    for prop in content_lines(input)? {
+        match prop.name.as_str() {
+            "BEGIN" => {
+                let component = prop.value.to_ascii_uppercase();
+                if component == "VEVENT" && stack.last().map(String::as_str) != Some("VEVENT") {
+                    current = Some(EventBuilder::default());
+                }
+                if component == "VALARM" && stack.last().map(String::as_str) == Some("VEVENT") {
+                    alarm = Some(AlarmBuilder::default());
+                }
+                stack.push(component);
+            }
+            "END" => {
+                let component = prop.value.to_ascii_uppercase();
+                if stack.pop().as_deref() != Some(component.as_str()) {
+                    return Err(invalid(format!("unexpected END:{}", prop.value)));
+                }
+                if component == "VALARM" {
+                    if let (Some(builder), Some(event)) = (alarm.take(), current.as_mut()) {
+                        event.alarms.push(builder.build()?);
+                    }
+                }
+                if component == "VEVENT" && !stack.iter().any(|c| c == "VEVENT") {
+                    if let Some(builder) = current.take() {
+                        calendar.events.push(builder.build()?);
+                    }
+                }
+            }
+            _ if stack.last().map(String::as_str) == Some("VEVENT") => {
+                if let Some(builder) = current.as_mut() {
+                    builder.apply(&prop)?;
+                }
+            }
+            _ if stack.last().map(String::as_str) == Some("VALARM") => {
+                if let Some(builder) = alarm.as_mut() {
+                    builder.apply(&prop)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    if let Some(open) = stack.pop() {
+        return Err(invalid(format!("BEGIN:{} is never closed", open)));
+    }
+    Ok(calendar)
+}
+
+/// Read the busy periods of every VFREEBUSY in iCalendar text.
+///
+/// FREEBUSY values with `FBTYPE=FREE` are skipped; BUSY (the default),
+/// BUSY-TENTATIVE, and BUSY-UNAVAILABLE all count as busy. Periods may be
+/// `start/end` or `start/duration`. The result is sorted and merged.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidCalendar`] for malformed lines or periods that
+/// are not in UTC, [`TruthError::InvalidDatetime`] or
+/// [`TruthError::InvalidDuration`] for unparseable period bounds.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::interop::ics::parse_freebusy;
+///
+/// let busy = parse_freebusy(
+///     "BEGIN:VCALENDAR\r\nBEGIN:VFREEBUSY\r\n\
+///      FREEBUSY:20260302T140000Z/PT1H,20260302T150000Z/20260302T153000Z\r\n\
+///      END:VFREEBUSY\r\nEND:VCALENDAR\r\n",
+/// )
+/// .unwrap();
+/// assert_eq!(busy.len(), 1);
+/// assert_eq!(busy[0].duration(), chrono::Duration::minutes(90));
+/// ```
+pub fn parse_freebusy(input: &str) -> Result<Vec<TimeInterval>> {
+    let mut stack: Vec<String> = Vec::new();
+    let mut busy = Vec::new();
+    for prop in content_lines(input)? {
+        match prop.name.as_str() {
+            "BEGIN" => stack.push(prop.value.to_ascii_uppercase()),
+            "END" => {
+                stack.pop();
+            }
+            "FREEBUSY" if stack.last().map(String::as_str) == Some("VFREEBUSY") => {
+                if prop
+                    .param("FBTYPE")
+                    .is_some_and(|t| t.eq_ignore_ascii_case("FREE"))
+                {
+                    continue;
+                }
+                for period in prop.value.split(',').filter(|p| !p.trim().is_empty()) {
+                    busy.push(parse_period(period)?);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(merge(busy))
+}
+
+fn parse_period(period: &str) -> Result<TimeInterval> {
+    let (start, end) = period
+        .split_once('/')
+        .ok_or_else(|| invalid(format!("'{}' is not a period", period)))?;
+    let DateTimeValue::Utc(start) = parse_value(start, None, false)? else {
+        return Err(invalid(format!("period '{}' is not in UTC", period)));
+    };
+    let end = if end.trim_start_matches(['+', '-']).starts_with(['P', 'p']) {
+        start + parse_ics_duration(end)?
+    } else {
+        match parse_value(end, None, false)? {
+            DateTimeValue::Utc(end) => end,
+            _ => return Err(invalid(format!("period '{}' is not in UTC", period))),
+        }
+    };
+    TimeInterval::new(start, end)
+}
+
+impl Calendar {
+    /// Every occurrence of every non-cancelled event starting no later than
+    /// `until`, sorted by start.
+    ///
+    /// Recurring series are expanded with their RDATEs added and EXDATEs
+    /// removed. An override (an event with RECURRENCE-ID) replaces the
+    /// occurrence of its series that originally started at that instant; a
+    /// cancelled override removes it. Floating and all-day values are read in
+    /// `default_timezone`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidTimezone`] for an unknown TZID or
+    /// `default_timezone`, or [`TruthError::InvalidRule`] for an unparseable RRULE.
+    pub fn expand(
+        &self,
+        until: DateTime<Utc>,
+        default_timezone: &str,
+    ) -> Result<Vec<ExpandedEvent>> {
+        self.collect(until, default_timezone, |_| true)
+    }
+
+    /// Like [`expand`](Self::expand), but keeps only occurrences that block
+    /// time: transparent (TRANSP:TRANSPARENT) events are dropped.
+    ///
+    /// # Errors
+    ///
+    /// As for [`expand`](Self::expand).
+    pub fn busy(&self, until: DateTime<Utc>, default_timezone: &str) -> Result<Vec<ExpandedEvent>> {
+        self.collect(until, default_timezone, |e| {
+            e.transparency == Transparency::Opaque
+        })
+    }
+
+    fn collect(
+        &self,
+        until: DateTime<Utc>,
+        default_timezone: &str,
+        keep: impl Fn(&Event) -> bool,
+    ) -> Result<Vec<ExpandedEvent>> {
+        let default_tz = parse_timezone(default_timezone)?;
+        Ok(self
+            .sourced_occurrences(until, &default_tz, keep)?
+            .into_iter()
+            .map(|(_, occurrence)| occurrence)
+            .collect())
+    }
+
+    /// Occurrences as [`collect`](Self::collect) finds them, each with the
+    /// index of the event it came from.
+    fn sourced_occurrences(
+        &self,
+        until: DateTime<Utc>,
+        default_tz: &Tz,
+        keep: impl Fn(&Event) -> bool,
+    ) -> Result<Vec<(usize, ExpandedEvent)>> {
+        let default_tz = *default_tz;
+        let mut out = Vec::new();
+        for (index, event) in self.events.iter().enumerate() {
+            if event.status == EventStatus::Cancelled || !keep(event) {
+                continue;
+            }
+            if event.recurrence_id.is_some() {
+                out.extend(
+                    event
+                        .single_occurrence(&default_tz)?
+                        .filter(|o| o.start <= until)
+                        .map(|o| (index, o)),
+                );
+                continue;
+            }
+            let mut occurrences = event.occurrences(until, &default_tz)?;
+            for replaced in self.overrides_of(event) {
+                if let Some(id) = &replaced.recurrence_id {
+                    let original = id.to_utc(&default_tz)?;
+                    occurrences.retain(|o| Some(o.start) != original);
+                }
+            }
+            out.extend(occurrences.into_iter().map(|o| (index, o)));
+        }
+        out.sort_by_key(|(_, e)| (e.start, e.end));
+        Ok(out)
+    }
+
+    /// The overrides belonging to a series master.
+    fn overrides_of<'a>(&'a self, master: &'a Event) -> impl Iterator<Item = &'a Event> + 'a {
+        self.events.iter().filter(move |e| {
+            e.recurrence_id.is_some() && master.uid.is_some() && e.uid == master.uid
+        })
+    }
+}
+
+impl Event {
+    /// Whether the event blocks time: not cancelled and not transparent.
+    pub fn is_busy(&self) -> bool {
+        self.status != EventStatus::Cancelled && self.transparency == Transparency::Opaque
+    }
+
+    /// The event's length: DTEND − DTSTART, else DURATION, else one day for
+    /// all-day events and zero otherwise (RFC 5545 §3.6.1).
+    pub fn length(&self, default_tz: &Tz) -> Result<chrono::Duration> {
+        if let Some(end) = &self.end {
+            let (Some(start), Some(end)) =
+                (self.start.to_utc(default_tz)?, end.to_utc(default_tz)?)
+            else {
+                return Ok(chrono::Duration::zero());
+            };
+            return Ok(end - start);
+        }
+        Ok(self.duration.unwrap_or(match self.start {
+            DateTimeValue::Date(_) => chrono::Duration::days(1),
+            _ => chrono::Duration::zero(),
+        }))
+    }
+
+    /// This event's own occurrences (its series, RDATEs, minus EXDATEs),
+    /// without considering overrides.
+    pub(crate) fn occurrences(
+        &self,
+        until: DateTime<Utc>,
+        default_tz: &Tz,
+    ) -> Result<Vec<ExpandedEvent>> {
+        let Some(rrule) = &self.rrule else {
+            let mut single: Vec<ExpandedEvent> =
+                self.single_occurrence(default_tz)?.into_iter().collect();
+            single.extend(self.rdate_occurrences(default_tz)?);
+            return self.finish(single, until, default_tz);
+        };
+
+        let tz = self.start.zone(default_tz)?;
+        let format = |naive: NaiveDateTime| naive.format("%Y-%m-%dT%H:%M:%S").to_string();
+        let start_local = self.start.local_in(&tz)?;
+        let exdates: Vec<String> = self
+            .exdates
+            .iter()
+            .filter_map(|x| match x {
+                DateTimeValue::Date(d) => Some(d.and_time(start_local.time())),
+                other => other.local_in(&tz).ok(),
+            })
+            .map(format)
+            .collect();
+        let exdate_refs: Vec<&str> = exdates.iter().map(String::as_str).collect();
+
+        // Bound open-ended series by `until` so the expansion limit is not hit.
+        let mut rrule = rrule_with_utc_until(rrule, &tz);
+        let upper = rrule.to_ascii_uppercase();
+        if !upper.contains("COUNT=") && !upper.contains("UNTIL=") {
+            rrule.push_str(&format!(";UNTIL={}", until.format("%Y%m%dT%H%M%SZ")));
+        }
+
+        let mut occurrences = expand_rrule_with_exdates(
+            &rrule,
+            &format(start_local),
+            0,
+            tz.name(),
+            None,
+            None,
+            &exdate_refs,
+        )?;
+        let length = self.length(default_tz)?;
+        for o in &mut occurrences {
+            o.end = o.start + length;
+        }
+        occurrences.extend(self.rdate_occurrences(default_tz)?);
+        self.finish(occurrences, until, default_tz)
+    }
+
+    /// DTSTART as a single occurrence.
+    pub(crate) fn single_occurrence(&self, default_tz: &Tz) -> Result<Option<ExpandedEvent>> {
+        let length = self.length(default_tz)?;
+        Ok(self
+            .start
+            .to_utc(default_tz)?
+            .map(|start| ExpandedEvent::new(start, start + length)))
+    }
+
+    fn rdate_occurrences(&self, default_tz: &Tz) -> Result<Vec<ExpandedEvent>> {
+        let length = self.length(default_tz)?;
+        let mut out = Vec::new();
+        for rdate in &self.rdates {
+            if let Some(start) = rdate.to_utc(default_tz)? {
+                out.push(ExpandedEvent::new(start, start + length));
+            }
+        }
+        Ok(out)
+    }
+
+    /// Sort, deduplicate, drop EXDATEs (which also apply to RDATEs), and cut at `until`.
+    fn finish(
+        &self,
+        mut occurrences: Vec<ExpandedEvent>,
+        until: DateTime<Utc>,
+        default_tz: &Tz,
+    ) -> Result<Vec<ExpandedEvent>> {
+        let mut excluded = Vec::new();
+        for x in &self.exdates {
+            excluded.extend(x.to_utc(default_tz)?);
+        }
+        occurrences.retain(|o| o.start <= until && !excluded.contains(&o.start));
+        occurrences.sort_by_key(|o| o.start);
+        occurrences.dedup_by_key(|o| o.start);
+        Ok(occurrences)
+    }
+}
+
+impl DateTimeValue {
+    /// The zone this value is expressed in: its TZID, UTC, or `default_tz` for
+    /// floating and DATE values.
+    pub fn zone(&self, default_tz: &Tz) -> Result<Tz> {
+        match self {
+            Self::Utc(_) => Ok(Tz::UTC),
+            Self::Local {
+                tzid: Some(tzid), ..
+            } => parse_timezone(tzid),
+            Self::Local { tzid: None, .. } | Self::Date(_) => Ok(*default_tz),
+        }
+    }
+
+    /// The instant this value names. DATE values are midnight in `default_tz`.
+    /// `None` only for a local time that cannot be placed in its zone.
+    pub fn to_utc(&self, default_tz: &Tz) -> Result<Option<DateTime<Utc>>> {
+        match self {
+            Self::Utc(dt) => Ok(Some(*dt)),
+            Self::Local { datetime, .. } => Ok(local_to_utc(&self.zone(default_tz)?, *datetime)),
+            Self::Date(d) => Ok(local_to_utc(default_tz, d.and_time(NaiveTime::MIN))),
+        }
+    }
+
+    /// The wall-clock time of this value in `tz`.
+    fn local_in(&self, tz: &Tz) -> Result<NaiveDateTime> {
+        match self {
+            Self::Utc(dt) => Ok(dt.with_timezone(tz).naive_local()),
+            Self::Local {
+                datetime,
+                tzid: Some(id),
+            } => {
+                let own = parse_timezone(id)?;
+                if own == *tz {
+                    return Ok(*datetime);
+                }
+                local_to_utc(&own, *datetime)
+                    .map(|dt| dt.with_timezone(tz).naive_local())
+                    .ok_or_else(|| TruthError::InvalidDatetime(datetime.to_string()))
+            }
+            Self::Local {
+                datetime,
+                tzid: None,
+            } => Ok(*datetime),
+            Self::Date(d) => Ok(d.and_time(NaiveTime::MIN)),
+        }
+    }
+}
+
+/// RFC 5545 requires a UTC UNTIL when DTSTART has a TZID, and the expander
+/// enforces it. Rewrite the local and DATE forms that real feeds also contain
+/// (a DATE UNTIL covers that whole day in `tz`).
+fn rrule_with_utc_until(rrule: &str, tz: &Tz) -> String {
+    rrule
+        .split(';')
+        .map(|part| match part.split_once('=') {
+            Some((key, value)) if key.eq_ignore_ascii_case("UNTIL") => {
+                let local = match parse_value(value, None, false) {
+                    Ok(DateTimeValue::Local { datetime, .. }) => datetime,
+                    Ok(DateTimeValue::Date(d)) => d.and_hms_opt(23, 59, 59).unwrap_or_default(),
+                    _ => return part.to_string(),
+                };
+                match local_to_utc(tz, local) {
+                    Some(utc) => format!("UNTIL={}", utc.format("%Y%m%dT%H%M%SZ")),
+                    None => part.to_string(),
+                }
+            }
+            _ => part.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+pub(crate) fn invalid(message: String) -> TruthError {
+    TruthError::InvalidCalendar(message)
+}
+
+// ── Content lines ───────────────────────────────────────────────────────────
+
+/// Unfold iCalendar text and parse each non-empty line.
+pub(crate) fn content_lines(input: &str) -> Result<Vec<ContentLine>> {
+    input
+        .replace("\r\n ", "")
+        .replace("\r\n\t", "")
+        .replace("\n ", "")
+        .replace("\n\t", "")
+        .lines()
+        .map(|l| l.trim_end_matches('\r'))
+        .filter(|l| !l.is_empty())
+        .map(ContentLine::parse)
+        .collect()
+}
+
+/// `NAME;PARAM=value:VALUE`, with the name and parameter names uppercased.
+pub(crate) struct ContentLine {
+    pub(crate) name: String,
+    pub(crate) params: Vec<(String, String)>,
+    pub(crate) value: String,
+}
+
+impl ContentLine {
+    fn parse(line: &str) -> Result<Self> {
+        // The value starts at the first ':' outside a quoted parameter value.
+        let mut quoted = false;
+        let colon = line
+            .char_indices()
+            .find(|&(_, c)| {
+                if c == '"' {
+                    quoted = !quoted;
+                }
+                c == ':' && !quoted
+            })
+            .map(|(i, _)| i)
+            .ok_or_else(|| invalid(format!("no value in '{}'", line)))?;
+        let (head, value) = (&line[..colon], &line[colon + 1..]);
+
+        let mut parts = split_unquoted(head, ';').into_iter();
+        let name = parts.next().unwrap_or_default().to_ascii_uppercase();
+        if name.is_empty() {
+            return Err(invalid(format!("no property name in '{}'", line)));
+        }
+        let params = parts
+            .map(|p| match p.split_once('=') {
+                Some((k, v)) => Ok((k.to_ascii_uppercase(), v.trim_matches('"').to_string())),
+                None => Err(invalid(format!("malformed parameter '{}'", p))),
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            name,
+            params,
+            value: value.to_string(),
+        })
+    }
+
+    pub(crate) fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+fn split_unquoted(s: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quoted = false;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if c == '"' {
+            quoted = !quoted;
+        } else if c == sep && !quoted {
+            parts.push(&s[start..i]);
+            start = i + 1;
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// Undo TEXT escaping (`\n`, `\,`, `\;`, `\\`).
+pub(crate) fn unescape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+// ── Values ──────────────────────────────────────────────────────────────────
+
+pub(crate) fn parse_value(value: &str, tzid: Option<&str>, is_date: bool) -> Result<DateTimeValue> {
+    let value = value.trim();
+    let bad = || {
+        TruthError::InvalidDatetime(format!("'{}' is not an iCalendar date or date-time", value))
+    };
+    if is_date || value.len() == 8 {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map(DateTimeValue::Date)
+            .map_err(|_| bad());
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").map_err(|_| bad())?;
+        return Ok(DateTimeValue::Utc(naive.and_utc()));
+    }
+    let datetime = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| bad())?;
+    Ok(DateTimeValue::Local {
+        datetime,
+        tzid: tzid.map(|t| t.trim_start_matches('/').to_string()),
+    })
+}
+
+/// Parse every value of a (possibly comma-separated) date property.
+fn parse_values(prop: &ContentLine) -> Result<Vec<DateTimeValue>> {
+    let is_date = prop
+        .param("VALUE")
+        .is_some_and(|v| v.eq_ignore_ascii_case("DATE"));
+    prop.value
+        .split(',')
+        .filter(|v| !v.trim().is_empty())
+        // PERIOD values ("start/end" or "start/duration") keep their start.
+        .map(|v| {
+            parse_value(
+                v.split('/').next().unwrap_or(v),
+                prop.param("TZID"),
+                is_date,
+            )
+        })
+        .collect()
+}
+
+/// Parse an RFC 5545 DURATION value (`PT1H30M`, `P1D`, `-P2W`).
+fn parse_ics_duration(value: &str) -> Result<chrono::Duration> {
+    let bad = || TruthError::InvalidDuration(format!("'{}' is not an iCalendar duration", value));
+    let v = value.trim();
+    let (sign, v) = match v.as_bytes().first() {
+        Some(b'-') => (-1, &v[1..]),
+        Some(b'+') => (1, &v[1..]),
+        _ => (1, v),
+    };
+    let v = v.strip_prefix(['P', 'p']).ok_or_else(bad)?;
+    let mut total = chrono::Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+    let mut any = false;
+    for c in v.chars() {
+        match c.to_ascii_uppercase() {
+            'T' if number.is_empty() => in_time = true,
+            d if d.is_ascii_digit() => number.push(d),
+            unit => {
+                let n: i64 = number.parse().map_err(|_| bad())?;
+                number.clear();
+                total += match (unit, in_time) {
+                    ('W', false) => chrono::Duration::weeks(n),
+                    ('D', false) => chrono::Duration::days(n),
+                    ('H', true) => chrono::Duration::hours(n),
+                    ('M', true) => chrono::Duration::minutes(n),
+                    ('S', true) => chrono::Duration::seconds(n),
+                    _ => return Err(bad()),
+                };
+                any = true;
+            }
+        }
+    }
+    if !any || !number.is_empty() {
+        return Err(bad());
+    }
+    Ok(total * sign)
+}
+
+// ── VEVENT assembly ─────────────────────────────────────────────────────────
+
+#[derive(Default)]
+struct EventBuilder {
+    uid: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    start: Option<DateTimeValue>,
+    end: Option<DateTimeValue>,
+    duration: Option<chrono::Duration>,
+    rrule: Option<String>,
+    exdates: Vec<DateTimeValue>,
+    rdates: Vec<DateTimeValue>,
+    recurrence_id: Option<DateTimeValue>,
+    transparency: Transparency,
+    status: EventStatus,
+    alarms: Vec<Alarm>,
+}
+
+impl EventBuilder {
+    fn apply(&mut self, prop: &ContentLine) -> Result<()> {
+        let single = |prop: &ContentLine| -> Result<DateTimeValue> {
+            parse_values(prop)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| invalid(format!("{} has no value", prop.name)))
+        };
+        match prop.name.as_str() {
+            "UID" => self.uid = Some(prop.value.clone()),
+            "SUMMARY" => self.summary = Some(unescape_text(&prop.value)),
+            "DESCRIPTION" => self.description = Some(unescape_text(&prop.value)),
+            "LOCATION" => self.location = Some(unescape_text(&prop.value)),
+            "DTSTART" => self.start = Some(single(prop)?),
+            "DTEND" => self.end = Some(single(prop)?),
+            "DURATION" => self.duration = Some(parse_ics_duration(&prop.value)?),
+            "RRULE" => self.rrule = Some(prop.value.clone()),
+            "EXDATE" => self.exdates.extend(parse_values(prop)?),
+            "RDATE" => self.rdates.extend(parse_values(prop)?),
+            "RECURRENCE-ID" => self.recurrence_id = Some(single(prop)?),
+            "TRANSP" => {
+                self.transparency = if prop.value.eq_ignore_ascii_case("TRANSPARENT") {
+                    Transparency::Transparent
+                } else {
+                    Transparency::Opaque
+                }
+            }
+            "STATUS" => {
+                self.status = match prop.value.to_ascii_uppercase().as_str() {
+                    "TENTATIVE" => EventStatus::Tentative,
+                    "CANCELLED" => EventStatus::Cancelled,
+                    _ => EventStatus::Confirmed,
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn build(self) -> Result<Event> {
+        let start = self.start.ok_or_else(|| {
+            invalid(format!(
+                "VEVENT {}has no DTSTART",
+                self.uid
+                    .as_deref()
+                    .map(|u| format!("'{}' ", u))
+                    .unwrap_or_default()
+            ))
+        })?;
+        Ok(Event {
+            uid: self.uid,
+            summary: self.summary,
+            description: self.description,
+            location: self.location,
+            start,
+            end: self.end,
+            duration: self.duration,
+            rrule: self.rrule,
+            exdates: self.exdates,
+            rdates: self.rdates,
+            recurrence_id: self.recurrence_id,
+            transparency: self.transparency,
+            status: self.status,
+            alarms: self.alarms,
+        })
+    }
+}
@@ -0,0 +1,254 @@
+//! VALARM components and their fire times.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::{
+    invalid, parse_ics_duration, parse_value, unescape_text, Calendar, ContentLine, DateTimeValue,
+};
+use crate::error::Result;
+use crate::expander::ExpandedEvent;
+use crate::temporal::parse_timezone;
+
+/// One VALARM of an event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alarm {
+    /// ACTION.
+    pub action: AlarmAction,
+    /// TRIGGER.
+    pub trigger: AlarmTrigger,
+    /// REPEAT: additional firings after the first, spaced by `repeat_interval`.
+    pub repeat: u32,
+    /// DURATION between repeated firings. RFC 5545 requires it whenever
+    /// `repeat` is non-zero; without it the alarm fires once.
+    pub repeat_interval: Option<Duration>,
+    /// DESCRIPTION, unescaped.
+    pub description: Option<String>,
+}
+
+/// An alarm's ACTION.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlarmAction {
+    Audio,
+    Display,
+    Email,
+    /// Any other (e.g. `X-`) action, uppercased.
+    Other(String),
+}
+
+/// When an alarm fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlarmTrigger {
+    /// An offset from each occurrence's start or end (negative = before).
+    Relative {
+        offset: Duration,
+        related: TriggerRelation,
+    },
+    /// A fixed instant. For a recurring event it fires once, not per occurrence.
+    Absolute(DateTime<Utc>),
+}
+
+/// What a relative trigger's offset is measured from (RELATED).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TriggerRelation {
+    #[default]
+    Start,
+    End,
+}
+
+/// One concrete firing of an alarm.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlarmFire {
+    /// When the alarm fires.
+    pub at: DateTime<Utc>,
+    /// The occurrence the alarm is for.
+    pub occurrence: ExpandedEvent,
+    /// UID of the event.
+    pub uid: Option<String>,
+    /// SUMMARY of the event.
+    pub summary: Option<String>,
+    pub action: AlarmAction,
+    /// The alarm's DESCRIPTION.
+    pub description: Option<String>,
+    /// 0 for the initial firing, then 1..=REPEAT for repetitions.
+    pub repetition: u32,
+}
+
+impl Calendar {
+    /// Every alarm firing in `[window_start, window_end)`, sorted by time.
+    ///
+    /// Alarms are computed per occurrence, so EXDATEs, cancelled overrides,
+    /// and moved overrides (which carry their own VALARMs) are honored the
+    /// same way [`expand`](Self::expand) honors them. Occurrences starting
+    /// after `window_end` are considered when an alarm fires before its
+    /// occurrence.
+    ///
+    /// # Errors
+    ///
+    /// As for [`expand`](Self::expand).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use truth_engine::interop::ics::parse_ics;
+    ///
+    /// let calendar = parse_ics(
+    ///     "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:standup\r\n\
+    ///      DTSTART:20260302T140000Z\r\nDURATION:PT15M\r\nRRULE:FREQ=DAILY;COUNT=3\r\n\
+    ///      BEGIN:VALARM\r\nACTION:DISPLAY\r\nTRIGGER:-PT10M\r\nEND:VALARM\r\n\
+    ///      END:VEVENT\r\nEND:VCALENDAR\r\n",
+    /// )
+    /// .unwrap();
+    /// let fires = calendar
+    ///     .alarms(
+    ///         Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap(),
+    ///         Utc.with_ymd_and_hms(2026, 3, 4, 0, 0, 0).unwrap(),
+    ///         "UTC",
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(fires.len(), 2);
+    /// assert_eq!(fires[1].at, Utc.with_ymd_and_hms(2026, 3, 3, 13, 50, 0).unwrap());
+    /// ```
+    pub fn alarms(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+        default_timezone: &str,
+    ) -> Result<Vec<AlarmFire>> {
+        let default_tz = parse_timezone(default_timezone)?;
+        // Look far enough past the window for alarms that fire before their occurrence.
+        let lead = self
+            .events
+            .iter()
+            .flat_map(|e| &e.alarms)
+            .filter_map(|a| match a.trigger {
+                AlarmTrigger::Relative { offset, .. } => Some(-offset),
+                AlarmTrigger::Absolute(_) => None,
+            })
+            .max()
+            .unwrap_or_else(Duration::zero)
+            .max(Duration::zero());
+
+        let mut absolute_seen = HashSet::new();
+        let mut fires = Vec::new();
+        for (index, occurrence) in
+            self.sourced_occurrences(window_end + lead, &default_tz, |_| true)?
+        {
+            let event = &self.events[index];
+            for (alarm_index, alarm) in event.alarms.iter().enumerate() {
+                let first = match alarm.trigger {
+                    AlarmTrigger::Relative {
+                        offset,
+                        related: TriggerRelation::Start,
+                    } => occurrence.start + offset,
+                    AlarmTrigger::Relative {
+                        offset,
+                        related: TriggerRelation::End,
+                    } => occurrence.end + offset,
+                    AlarmTrigger::Absolute(at) => {
+                        if !absolute_seen.insert((index, alarm_index)) {
+                            continue;
+                        }
+                        at
+                    }
+                };
+                let repeats = match alarm.repeat_interval {
+                    Some(interval) if interval > Duration::zero() => alarm.repeat,
+                    _ => 0,
+                };
+                for repetition in 0..=repeats {
+                    let at = first
+                        + alarm.repeat_interval.unwrap_or_else(Duration::zero) * repetition as i32;
+                    if at >= window_start && at < window_end {
+                        fires.push(AlarmFire {
+                            at,
+                            occurrence: occurrence.clone(),
+                            uid: event.uid.clone(),
+                            summary: event.summary.clone(),
+                            action: alarm.action.clone(),
+                            description: alarm.description.clone(),
+                            repetition,
+                        });
+                    }
+                }
+            }
+        }
+        fires.sort_by_key(|f| (f.at, f.occurrence.start, f.repetition));
+        Ok(fires)
+    }
+}
+
+// ── VALARM assembly ─────────────────────────────────────────────────────────
+
+#[derive(Default)]
+pub(super) struct AlarmBuilder {
+    action: Option<AlarmAction>,
+    trigger: Option<AlarmTrigger>,
+    repeat: u32,
+    repeat_interval: Option<Duration>,
+    description: Option<String>,
+}
+
+impl AlarmBuilder {
+    pub(super) fn apply(&mut self, prop: &ContentLine) -> Result<()> {
+        match prop.name.as_str() {
+            "ACTION" => {
+                self.action = Some(match prop.value.to_ascii_uppercase().as_str() {
+                    "AUDIO" => AlarmAction::Audio,
+                    "DISPLAY" => AlarmAction::Display,
+                    "EMAIL" => AlarmAction::Email,
+                    other => AlarmAction::Other(other.to_string()),
+                })
+            }
+            "TRIGGER" => {
+                let absolute = prop
+                    .param("VALUE")
+                    .is_some_and(|v| v.eq_ignore_ascii_case("DATE-TIME"));
+                self.trigger = Some(if absolute {
+                    match parse_value(&prop.value, None, false)? {
+                        DateTimeValue::Utc(at) => AlarmTrigger::Absolute(at),
+                        _ => {
+                            return Err(invalid(format!(
+                                "absolute TRIGGER '{}' is not in UTC",
+                                prop.value
+                            )))
+                        }
+                    }
+                } else {
+                    AlarmTrigger::Relative {
+                        offset: parse_ics_duration(&prop.value)?,
+                        related: match prop.param("RELATED") {
+                            Some(r) if r.eq_ignore_ascii_case("END") => TriggerRelation::End,
+                            _ => TriggerRelation::Start,
+                        },
+                    }
+                });
+            }
+            "REPEAT" => {
+                self.repeat = prop
+                    .value
+                    .trim()
+                    .parse()
+                    .map_err(|_| invalid(format!("REPEAT '{}' is not a count", prop.value)))?
+            }
+            "DURATION" => self.repeat_interval = Some(parse_ics_duration(&prop.value)?),
+            "DESCRIPTION" => self.description = Some(unescape_text(&prop.value)),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub(super) fn build(self) -> Result<Alarm> {
+        Ok(Alarm {
+            action: self.action.unwrap_or(AlarmAction::Display),
+            trigger: self
+                .trigger
+                .ok_or_else(|| invalid("VALARM has no TRIGGER".to_string()))?,
+            repeat: self.repeat,
+            repeat_interval: self.repeat_interval,
+            description: self.description,
+        })
+    }
+}
@@ -0,0 +1,491 @@
+//! iCalendar (RFC 5545) generation.
+//!
+//! Output uses CRLF line endings and folds lines at 75 octets. Every TZID that
+//! appears in a value gets a VTIMEZONE built from the IANA rules, with yearly
+//! STANDARD/DAYLIGHT observances as in effect from the year before the
+//! earliest value in that zone.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc, Weekday};
+use chrono_tz::{OffsetComponents, OffsetName, Tz};
+
+use super::{
+    Alarm, AlarmAction, AlarmTrigger, Calendar, DateTimeValue, Event, EventStatus, Transparency,
+    TriggerRelation,
+};
+use crate::availability::BusyBlock;
+use crate::error::Result;
+use crate::expander::ExpandedEvent;
+use crate::temporal::parse_timezone;
+
+const PRODID: &str = "-//Temporal Cortex//Truth Engine//EN";
+
+impl Calendar {
+    /// A calendar with one VEVENT per occurrence, expressed in `timezone`
+    /// (UTC values when `timezone` is `"UTC"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidTimezone`](crate::TruthError::InvalidTimezone)
+    /// if the timezone is invalid.
+    pub fn from_occurrences(events: &[ExpandedEvent], timezone: &str) -> Result<Self> {
+        let tz = parse_timezone(timezone)?;
+        let value = |dt: DateTime<Utc>| {
+            if tz == Tz::UTC {
+                DateTimeValue::Utc(dt)
+            } else {
+                DateTimeValue::Local {
+                    datetime: dt.with_timezone(&tz).naive_local(),
+                    tzid: Some(tz.name().to_string()),
+                }
+            }
+        };
+        let events = events
+            .iter()
+            .map(|e| Event {
+                uid: None,
+                summary: None,
+                description: None,
+                location: None,
+                start: value(e.start),
+                end: Some(value(e.end)),
+                duration: None,
+                rrule: None,
+                exdates: Vec::new(),
+                rdates: Vec::new(),
+                recurrence_id: None,
+                transparency: Transparency::Opaque,
+                status: EventStatus::Confirmed,
+                alarms: Vec::new(),
+            })
+            .collect();
+        Ok(Self { events })
+    }
+}
+
+/// Serialize a [`Calendar`] as iCalendar text.
+///
+/// `dtstamp` is written as each event's DTSTAMP (the engine never reads the
+/// clock). Events without a UID get one derived from their position and start.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`](crate::TruthError::InvalidTimezone)
+/// if a TZID does not name a known zone.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::interop::ics::{parse_ics, write_ics};
+///
+/// let calendar = parse_ics(
+///     "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:a@example.com\r\n\
+///      DTSTART;TZID=Europe/Berlin:20260302T090000\r\nDURATION:PT1H\r\n\
+///      END:VEVENT\r\nEND:VCALENDAR\r\n",
+/// )
+/// .unwrap();
+/// let stamp = Utc.with_ymd_and_hms(2026, 2, 18, 12, 0, 0).unwrap();
+/// let text = write_ics(&calendar, stamp).unwrap();
+/// assert!(text.contains("BEGIN:VTIMEZONE\r\nTZID:Europe/Berlin\r\n"));
+/// assert!(text.contains("DTSTART;TZID=Europe/Berlin:20260302T090000\r\n"));
+/// assert_eq!(parse_ics(&text).unwrap(), calendar);
+/// ```
+pub fn write_ics(calendar: &Calendar, dtstamp: DateTime<Utc>) -> Result<String> {
+    write_calendar(calendar, dtstamp, None, &[])
+}
+
+/// [`write_ics`] with an optional METHOD and extra content lines added to
+/// every VEVENT.
+pub(crate) fn write_calendar(
+    calendar: &Calendar,
+    dtstamp: DateTime<Utc>,
+    method: Option<&str>,
+    extra: &[String],
+) -> Result<String> {
+    let mut out = Lines::default();
+    out.push("BEGIN:VCALENDAR");
+    out.push("VERSION:2.0");
+    out.push(&format!("PRODID:{}", PRODID));
+    out.push("CALSCALE:GREGORIAN");
+    if let Some(method) = method {
+        out.push(&format!("METHOD:{}", method));
+    }
+    for (tzid, year) in referenced_zones(calendar) {
+        write_vtimezone(&mut out, &tzid, year)?;
+    }
+    for (index, event) in calendar.events.iter().enumerate() {
+        write_event(&mut out, event, index, dtstamp, extra);
+    }
+    out.push("END:VCALENDAR");
+    Ok(out.0)
+}
+
+/// Serialize busy blocks as a VFREEBUSY covering `window_start`..`window_end`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::interop::ics::write_freebusy;
+/// use truth_engine::BusyBlock;
+///
+/// let at = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+/// let busy = [BusyBlock { start: at(14), end: at(15), source_count: 2 }];
+/// let text = write_freebusy(&busy, at(0), at(23), at(12));
+/// assert!(text.contains("FREEBUSY;FBTYPE=BUSY:20260302T140000Z/20260302T150000Z\r\n"));
+/// ```
+pub fn write_freebusy(
+    busy: &[BusyBlock],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    dtstamp: DateTime<Utc>,
+) -> String {
+    let mut out = Lines::default();
+    out.push("BEGIN:VCALENDAR");
+    out.push("VERSION:2.0");
+    out.push(&format!("PRODID:{}", PRODID));
+    out.push("BEGIN:VFREEBUSY");
+    out.push(&format!(
+        "UID:freebusy-{}-{}@truth-engine",
+        utc_text(window_start),
+        utc_text(window_end)
+    ));
+    out.push(&format!("DTSTAMP:{}", utc_text(dtstamp)));
+    out.push(&format!("DTSTART:{}", utc_text(window_start)));
+    out.push(&format!("DTEND:{}", utc_text(window_end)));
+    for block in busy {
+        out.push(&format!(
+            "FREEBUSY;FBTYPE=BUSY:{}/{}",
+            utc_text(block.start),
+            utc_text(block.end)
+        ));
+    }
+    out.push("END:VFREEBUSY");
+    out.push("END:VCALENDAR");
+    out.0
+}
+
+fn write_event(
+    out: &mut Lines,
+    event: &Event,
+    index: usize,
+    dtstamp: DateTime<Utc>,
+    extra: &[String],
+) {
+    out.push("BEGIN:VEVENT");
+    let uid = event.uid.clone().unwrap_or_else(|| {
+        let start = match &event.start {
+            DateTimeValue::Date(d) => d.format("%Y%m%d").to_string(),
+            DateTimeValue::Utc(dt) => utc_text(*dt),
+            DateTimeValue::Local { datetime, .. } => datetime.format("%Y%m%dT%H%M%S").to_string(),
+        };
+        format!("{}-{}@truth-engine", index, start)
+    });
+    out.push(&format!("UID:{}", uid));
+    out.push(&format!("DTSTAMP:{}", utc_text(dtstamp)));
+    if let Some(id) = &event.recurrence_id {
+        out.push(&date_property("RECURRENCE-ID", id));
+    }
+    out.push(&date_property("DTSTART", &event.start));
+    if let Some(end) = &event.end {
+        out.push(&date_property("DTEND", end));
+    } else if let Some(duration) = event.duration {
+        out.push(&format!("DURATION:{}", duration_text(duration)));
+    }
+    if let Some(rrule) = &event.rrule {
+        out.push(&format!("RRULE:{}", rrule));
+    }
+    for rdate in &event.rdates {
+        out.push(&date_property("RDATE", rdate));
+    }
+    for exdate in &event.exdates {
+        out.push(&date_property("EXDATE", exdate));
+    }
+    for (name, text) in [
+        ("SUMMARY", &event.summary),
+        ("DESCRIPTION", &event.description),
+        ("LOCATION", &event.location),
+    ] {
+        if let Some(text) = text {
+            out.push(&format!("{}:{}", name, escape_text(text)));
+        }
+    }
+    match event.status {
+        EventStatus::Confirmed => {}
+        EventStatus::Tentative => out.push("STATUS:TENTATIVE"),
+        EventStatus::Cancelled => out.push("STATUS:CANCELLED"),
+    }
+    if event.transparency == Transparency::Transparent {
+        out.push("TRANSP:TRANSPARENT");
+    }
+    for line in extra {
+        out.push(line);
+    }
+    for alarm in &event.alarms {
+        write_alarm(out, alarm);
+    }
+    out.push("END:VEVENT");
+}
+
+fn write_alarm(out: &mut Lines, alarm: &Alarm) {
+    out.push("BEGIN:VALARM");
+    out.push(&format!(
+        "ACTION:{}",
+        match &alarm.action {
+            AlarmAction::Audio => "AUDIO",
+            AlarmAction::Display => "DISPLAY",
+            AlarmAction::Email => "EMAIL",
+            AlarmAction::Other(action) => action,
+        }
+    ));
+    out.push(&match alarm.trigger {
+        AlarmTrigger::Relative {
+            offset,
+            related: TriggerRelation::Start,
+        } => format!("TRIGGER:{}", duration_text(offset)),
+        AlarmTrigger::Relative {
+            offset,
+            related: TriggerRelation::End,
+        } => format!("TRIGGER;RELATED=END:{}", duration_text(offset)),
+        AlarmTrigger::Absolute(at) => format!("TRIGGER;VALUE=DATE-TIME:{}", utc_text(at)),
+    });
+    if let Some(interval) = alarm.repeat_interval {
+        out.push(&format!("DURATION:{}", duration_text(interval)));
+        out.push(&format!("REPEAT:{}", alarm.repeat));
+    }
+    if let Some(description) = &alarm.description {
+        out.push(&format!("DESCRIPTION:{}", escape_text(description)));
+    }
+    out.push("END:VALARM");
+}
+
+/// Each TZID used by the calendar and the earliest year it is used in.
+fn referenced_zones(calendar: &Calendar) -> BTreeMap<String, i32> {
+    let mut zones = BTreeMap::new();
+    for event in &calendar.events {
+        let values = std::iter::once(&event.start)
+            .chain(&event.end)
+            .chain(&event.recurrence_id)
+            .chain(&event.exdates)
+            .chain(&event.rdates);
+        for value in values {
+            if let DateTimeValue::Local {
+                datetime,
+                tzid: Some(tzid),
+            } = value
+            {
+                let year = zones.entry(tzid.clone()).or_insert(datetime.year());
+                *year = (*year).min(datetime.year());
+            }
+        }
+    }
+    zones
+}
+
+fn write_vtimezone(out: &mut Lines, tzid: &str, year: i32) -> Result<()> {
+    let tz = parse_timezone(tzid)?;
+    out.push("BEGIN:VTIMEZONE");
+    out.push(&format!("TZID:{}", tzid));
+    // Start a year early so values before the first transition of `year`
+    // fall after an observance onset.
+    let transitions = transitions_in(&tz, year - 1);
+    if transitions.is_empty() {
+        let jan1 = NaiveDate::from_ymd_opt(year, 1, 1)
+            .unwrap_or_default()
+            .and_time(chrono::NaiveTime::MIN);
+        let offset = tz.offset_from_utc_datetime(&jan1);
+        out.push("BEGIN:STANDARD");
+        out.push("DTSTART:19700101T000000");
+        out.push(&format!(
+            "TZOFFSETFROM:{}",
+            offset_text(offset.fix().local_minus_utc())
+        ));
+        out.push(&format!(
+            "TZOFFSETTO:{}",
+            offset_text(offset.fix().local_minus_utc())
+        ));
+        if let Some(name) = offset.abbreviation() {
+            out.push(&format!("TZNAME:{}", name));
+        }
+        out.push("END:STANDARD");
+    }
+    for (at, from, to) in transitions {
+        let kind = if to.dst_offset().is_zero() {
+            "STANDARD"
+        } else {
+            "DAYLIGHT"
+        };
+        let from_secs = from.fix().local_minus_utc();
+        // Observance onsets are in local time before the transition.
+        let onset = at.naive_utc() + chrono::Duration::seconds(i64::from(from_secs));
+        out.push(&format!("BEGIN:{}", kind));
+        out.push(&format!("DTSTART:{}", onset.format("%Y%m%dT%H%M%S")));
+        out.push(&format!("RRULE:FREQ=YEARLY;{}", yearly_rule(onset)));
+        out.push(&format!("TZOFFSETFROM:{}", offset_text(from_secs)));
+        out.push(&format!(
+            "TZOFFSETTO:{}",
+            offset_text(to.fix().local_minus_utc())
+        ));
+        if let Some(name) = to.abbreviation() {
+            out.push(&format!("TZNAME:{}", name));
+        }
+        out.push(&format!("END:{}", kind));
+    }
+    out.push("END:VTIMEZONE");
+    Ok(())
+}
+
+type ZoneOffset = <Tz as TimeZone>::Offset;
+
+/// UTC-offset changes during `year`: the instant, and the offsets before and after.
+fn transitions_in(tz: &Tz, year: i32) -> Vec<(DateTime<Utc>, ZoneOffset, ZoneOffset)> {
+    let offset_at = |t: DateTime<Utc>| tz.offset_from_utc_datetime(&t.naive_utc());
+    let Some(start) = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).single() else {
+        return Vec::new();
+    };
+    let mut transitions = Vec::new();
+    let mut day = start;
+    while day.year() == year {
+        let next = day + chrono::Duration::days(1);
+        let (before, after) = (offset_at(day), offset_at(next));
+        if before.fix() != after.fix() {
+            // Narrow to the second: `lo` has the old offset, `hi` the new.
+            let (mut lo, mut hi) = (day, next);
+            while hi - lo > chrono::Duration::seconds(1) {
+                let mid = lo + (hi - lo) / 2;
+                if offset_at(mid).fix() == before.fix() {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            transitions.push((hi, before, after));
+        }
+        day = next;
+    }
+    transitions
+}
+
+/// `BYMONTH=3;BYDAY=2SU` for the second Sunday of March; `-1` for the last.
+fn yearly_rule(onset: NaiveDateTime) -> String {
+    let date = onset.date();
+    let days_in_month = NaiveDate::from_ymd_opt(date.year(), date.month(), 1)
+        .and_then(|first| first.checked_add_months(chrono::Months::new(1)))
+        .map_or(31, |next| (next - chrono::Duration::days(1)).day());
+    let ordinal = if date.day() + 7 > days_in_month {
+        -1
+    } else {
+        ((date.day() - 1) / 7 + 1) as i32
+    };
+    let weekday = match date.weekday() {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    };
+    format!("BYMONTH={};BYDAY={}{}", date.month(), ordinal, weekday)
+}
+
+fn date_property(name: &str, value: &DateTimeValue) -> String {
+    match value {
+        DateTimeValue::Date(d) => format!("{};VALUE=DATE:{}", name, d.format("%Y%m%d")),
+        DateTimeValue::Utc(dt) => format!("{}:{}", name, utc_text(*dt)),
+        DateTimeValue::Local { datetime, tzid } => {
+            let text = datetime.format("%Y%m%dT%H%M%S");
+            match tzid {
+                Some(tzid) => format!("{};TZID={}:{}", name, tzid, text),
+                None => format!("{}:{}", name, text),
+            }
+        }
+    }
+}
+
+fn utc_text(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// `+0100`, `-0330`, or `+053728` for offsets with seconds.
+fn offset_text(secs: i32) -> String {
+    let sign = if secs < 0 { '-' } else { '+' };
+    let secs = secs.unsigned_abs();
+    let (h, m, s) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    if s == 0 {
+        format!("{}{:02}{:02}", sign, h, m)
+    } else {
+        format!("{}{:02}{:02}{:02}", sign, h, m, s)
+    }
+}
+
+/// RFC 5545 DURATION text (`PT1H30M`, `P2W`, `-P1D`).
+fn duration_text(duration: chrono::Duration) -> String {
+    let sign = if duration < chrono::Duration::zero() {
+        "-"
+    } else {
+        ""
+    };
+    let secs = duration.num_seconds().unsigned_abs();
+    if secs > 0 && secs.is_multiple_of(7 * 86_400) {
+        return format!("{}P{}W", sign, secs / (7 * 86_400));
+    }
+    let (days, h, m, s) = (
+        secs / 86_400,
+        secs % 86_400 / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+    );
+    let mut text = format!("{}P", sign);
+    if days > 0 {
+        text.push_str(&format!("{}D", days));
+    }
+    if h > 0 || m > 0 || s > 0 || days == 0 {
+        text.push('T');
+        if h > 0 {
+            text.push_str(&format!("{}H", h));
+        }
+        if m > 0 {
+            text.push_str(&format!("{}M", m));
+        }
+        if s > 0 || (h == 0 && m == 0) {
+            text.push_str(&format!("{}S", s));
+        }
+    }
+    text
+}
+
+/// TEXT escaping: backslash, semicolon, comma, newline.
+pub(crate) fn escape_text(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Content lines joined with CRLF, folded at 75 octets.
+#[derive(Default)]
+pub(crate) struct Lines(pub(crate) String);
+
+impl Lines {
+    pub(crate) fn push(&mut self, line: &str) {
+        let mut limit = 75;
+        let mut rest = line;
+        while rest.len() > limit {
+            let mut cut = limit;
+            while !rest.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            self.0.push_str(&rest[..cut]);
+            self.0.push_str("\r\n ");
+            rest = &rest[cut..];
+            // Continuation lines start with the folding space.
+            limit = 74;
+        }
+        self.0.push_str(rest);
+        self.0.push_str("\r\n");
+    }
+}
@@ -0,0 +1,408 @@
+//! iTIP (RFC 5546) scheduling messages.
+//!
+//! A [`SchedulingMessage`] is one METHOD plus the event it is about, with
+//! the ORGANIZER, ATTENDEEs, and SEQUENCE that iTIP adds to a plain VEVENT.
+//! [`invitation`] builds a REQUEST from a [`FreeSlot`] the engine found; the
+//! REPLY, CANCEL, and COUNTER an attendee or organizer sends next are derived
+//! from it, so UID, SEQUENCE, and the original times carry over correctly.
+//! [`write_itip`] and [`parse_itip`] convert to and from iCalendar text.
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::ics::{
+    content_lines, escape_text, invalid, parse_ics, unescape_text, write_calendar, Calendar,
+    ContentLine, DateTimeValue, Event, EventStatus, Transparency,
+};
+use crate::error::{Result, TruthError};
+use crate::freebusy::FreeSlot;
+
+/// An iTIP METHOD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Invite attendees, or update an existing invitation.
+    Request,
+    /// An attendee's answer to a REQUEST.
+    Reply,
+    /// The organizer cancels the event.
+    Cancel,
+    /// An attendee proposes different times.
+    Counter,
+}
+
+/// An attendee's participation status (PARTSTAT).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartStat {
+    #[default]
+    NeedsAction,
+    Accepted,
+    Declined,
+    Tentative,
+    Delegated,
+}
+
+/// An ORGANIZER or ATTENDEE. PARTSTAT and RSVP are only written for attendees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Participant {
+    /// Email address, without the `mailto:` prefix.
+    pub email: String,
+    /// Display name (CN).
+    pub name: Option<String>,
+    pub partstat: PartStat,
+    /// Whether a reply is requested (RSVP).
+    pub rsvp: bool,
+}
+
+impl Participant {
+    /// A participant with no name who has not responded.
+    pub fn new(email: &str) -> Self {
+        Self {
+            email: email.to_string(),
+            name: None,
+            partstat: PartStat::NeedsAction,
+            rsvp: false,
+        }
+    }
+}
+
+/// One iTIP message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchedulingMessage {
+    pub method: Method,
+    /// The event, with its UID.
+    pub event: Event,
+    pub organizer: Participant,
+    pub attendees: Vec<Participant>,
+    /// SEQUENCE: incremented by the organizer on each significant revision.
+    pub sequence: u32,
+    /// COMMENT, e.g. the reason for a counter-proposal.
+    pub comment: Option<String>,
+}
+
+/// Build a REQUEST for a meeting of `duration_minutes` at the start of `slot`.
+///
+/// Attendees are asked to RSVP and start out as NEEDS-ACTION.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDuration`] if the meeting does not fit in
+/// the slot or its length is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::interop::itip::{invitation, write_itip, Participant};
+/// use truth_engine::FreeSlot;
+///
+/// let slot = FreeSlot {
+///     start: Utc.with_ymd_and_hms(2026, 3, 2, 14, 0, 0).unwrap(),
+///     end: Utc.with_ymd_and_hms(2026, 3, 2, 16, 0, 0).unwrap(),
+///     duration_minutes: 120,
+/// };
+/// let request = invitation(
+///     &slot,
+///     30,
+///     "sync-42@example.com",
+///     "Design sync",
+///     Participant::new("alice@example.com"),
+///     vec![Participant::new("bob@example.com")],
+/// )
+/// .unwrap();
+/// let text = write_itip(&request, slot.start).unwrap();
+/// assert!(text.contains("METHOD:REQUEST\r\n"));
+/// assert!(text.contains("DTEND:20260302T143000Z\r\n"));
+/// assert!(text.contains("ATTENDEE;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:bob@example.com\r\n"));
+/// ```
+pub fn invitation(
+    slot: &FreeSlot,
+    duration_minutes: i64,
+    uid: &str,
+    summary: &str,
+    organizer: Participant,
+    attendees: Vec<Participant>,
+) -> Result<SchedulingMessage> {
+    let end = slot.start + Duration::minutes(duration_minutes);
+    if duration_minutes <= 0 || end > slot.end {
+        return Err(TruthError::InvalidDuration(format!(
+            "a {}-minute meeting does not fit in a {}-minute slot",
+            duration_minutes, slot.duration_minutes
+        )));
+    }
+    Ok(SchedulingMessage {
+        method: Method::Request,
+        event: Event {
+            uid: Some(uid.to_string()),
+            summary: Some(summary.to_string()),
+            description: None,
+            location: None,
+            start: DateTimeValue::Utc(slot.start),
+            end: Some(DateTimeValue::Utc(end)),
+            duration: None,
+            rrule: None,
+            exdates: Vec::new(),
+            rdates: Vec::new(),
+            recurrence_id: None,
+            transparency: Transparency::Opaque,
+            status: EventStatus::Confirmed,
+            alarms: Vec::new(),
+        },
+        organizer,
+        attendees: attendees
+            .into_iter()
+            .map(|a| Participant {
+                partstat: PartStat::NeedsAction,
+                rsvp: true,
+                ..a
+            })
+            .collect(),
+        sequence: 0,
+        comment: None,
+    })
+}
+
+impl SchedulingMessage {
+    /// The REPLY `attendee` sends to this request: only that attendee, with
+    /// the given status, about the same UID, SEQUENCE, and times.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidCalendar`] if `attendee` is not invited.
+    pub fn reply(&self, attendee: &str, partstat: PartStat) -> Result<SchedulingMessage> {
+        let me = self.attendee(attendee)?;
+        Ok(SchedulingMessage {
+            method: Method::Reply,
+            attendees: vec![Participant {
+                partstat,
+                rsvp: false,
+                ..me.clone()
+            }],
+            comment: None,
+            ..self.clone()
+        })
+    }
+
+    /// The CANCEL the organizer sends for this event, with SEQUENCE bumped.
+    pub fn cancel(&self) -> SchedulingMessage {
+        let mut event = self.event.clone();
+        event.status = EventStatus::Cancelled;
+        SchedulingMessage {
+            method: Method::Cancel,
+            event,
+            sequence: self.sequence + 1,
+            comment: None,
+            ..self.clone()
+        }
+    }
+
+    /// A COUNTER from `attendee` proposing the event at the start of `slot`,
+    /// keeping its length.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidCalendar`] if `attendee` is not invited,
+    /// or [`TruthError::InvalidDuration`] if the event does not fit in `slot`.
+    pub fn counter(
+        &self,
+        attendee: &str,
+        slot: &FreeSlot,
+        comment: Option<&str>,
+    ) -> Result<SchedulingMessage> {
+        let me = self.attendee(attendee)?;
+        let length = self.event.length(&chrono_tz::UTC)?;
+        if slot.start + length > slot.end {
+            return Err(TruthError::InvalidDuration(format!(
+                "a {}-minute event does not fit in a {}-minute slot",
+                length.num_minutes(),
+                slot.duration_minutes
+            )));
+        }
+        let mut event = self.event.clone();
+        event.start = DateTimeValue::Utc(slot.start);
+        event.end = Some(DateTimeValue::Utc(slot.start + length));
+        event.duration = None;
+        Ok(SchedulingMessage {
+            method: Method::Counter,
+            event,
+            attendees: vec![Participant {
+                rsvp: false,
+                ..me.clone()
+            }],
+            comment: comment.map(str::to_string),
+            ..self.clone()
+        })
+    }
+
+    /// The event's start, for messages whose times are in UTC or a named zone.
+    pub fn start(&self) -> Result<Option<DateTime<Utc>>> {
+        self.event.start.to_utc(&chrono_tz::UTC)
+    }
+
+    fn attendee(&self, email: &str) -> Result<&Participant> {
+        self.attendees
+            .iter()
+            .find(|a| a.email.eq_ignore_ascii_case(email))
+            .ok_or_else(|| invalid(format!("{} is not an attendee", email)))
+    }
+}
+
+/// Serialize a message as an iCalendar object with METHOD.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] if the event has an unknown TZID.
+pub fn write_itip(message: &SchedulingMessage, dtstamp: DateTime<Utc>) -> Result<String> {
+    let mut extra = vec![
+        format!("SEQUENCE:{}", message.sequence),
+        participant_line("ORGANIZER", &message.organizer, false),
+    ];
+    extra.extend(
+        message
+            .attendees
+            .iter()
+            .map(|a| participant_line("ATTENDEE", a, true)),
+    );
+    if let Some(comment) = &message.comment {
+        extra.push(format!("COMMENT:{}", escape_text(comment)));
+    }
+    let calendar = Calendar {
+        events: vec![message.event.clone()],
+    };
+    write_calendar(
+        &calendar,
+        dtstamp,
+        Some(method_text(message.method)),
+        &extra,
+    )
+}
+
+/// Parse an iTIP message: METHOD, and the first VEVENT with its ORGANIZER,
+/// ATTENDEEs, SEQUENCE, and COMMENT.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidCalendar`] if METHOD is missing or not one
+/// of REQUEST, REPLY, CANCEL, COUNTER, or there is no VEVENT or ORGANIZER;
+/// otherwise as [`parse_ics`].
+pub fn parse_itip(input: &str) -> Result<SchedulingMessage> {
+    let event = parse_ics(input)?
+        .events
+        .into_iter()
+        .next()
+        .ok_or_else(|| invalid("iTIP message has no VEVENT".to_string()))?;
+
+    let mut method = None;
+    let mut organizer = None;
+    let mut attendees = Vec::new();
+    let mut sequence = 0;
+    let mut comment = None;
+    let mut depth = Vec::new();
+    let mut events_seen = 0;
+    for prop in content_lines(input)? {
+        match prop.name.as_str() {
+            "BEGIN" => {
+                if prop.value.eq_ignore_ascii_case("VEVENT") {
+                    events_seen += 1;
+                }
+                depth.push(prop.value.to_ascii_uppercase());
+            }
+            "END" => {
+                depth.pop();
+            }
+            "METHOD" if depth.len() == 1 => {
+                method = Some(match prop.value.to_ascii_uppercase().as_str() {
+                    "REQUEST" => Method::Request,
+                    "REPLY" => Method::Reply,
+                    "CANCEL" => Method::Cancel,
+                    "COUNTER" => Method::Counter,
+                    other => return Err(invalid(format!("unsupported METHOD {}", other))),
+                })
+            }
+            _ if events_seen == 1 && depth.last().map(String::as_str) == Some("VEVENT") => {
+                match prop.name.as_str() {
+                    "ORGANIZER" => organizer = Some(participant(&prop)),
+                    "ATTENDEE" => attendees.push(participant(&prop)),
+                    "SEQUENCE" => sequence = prop.value.trim().parse().unwrap_or(0),
+                    "COMMENT" => comment = Some(unescape_text(&prop.value)),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(SchedulingMessage {
+        method: method.ok_or_else(|| invalid("iTIP message has no METHOD".to_string()))?,
+        event,
+        organizer: organizer.ok_or_else(|| invalid("iTIP message has no ORGANIZER".to_string()))?,
+        attendees,
+        sequence,
+        comment,
+    })
+}
+
+fn method_text(method: Method) -> &'static str {
+    match method {
+        Method::Request => "REQUEST",
+        Method::Reply => "REPLY",
+        Method::Cancel => "CANCEL",
+        Method::Counter => "COUNTER",
+    }
+}
+
+fn partstat_text(partstat: PartStat) -> &'static str {
+    match partstat {
+        PartStat::NeedsAction => "NEEDS-ACTION",
+        PartStat::Accepted => "ACCEPTED",
+        PartStat::Declined => "DECLINED",
+        PartStat::Tentative => "TENTATIVE",
+        PartStat::Delegated => "DELEGATED",
+    }
+}
+
+fn participant_line(name: &str, p: &Participant, with_status: bool) -> String {
+    let mut line = name.to_string();
+    if let Some(cn) = &p.name {
+        // Parameter values containing separators must be quoted; quotes
+        // themselves cannot appear (RFC 5545 §3.1).
+        let cn = cn.replace('"', "'");
+        if cn.contains([',', ':', ';']) {
+            line.push_str(&format!(";CN=\"{}\"", cn));
+        } else {
+            line.push_str(&format!(";CN={}", cn));
+        }
+    }
+    if with_status {
+        line.push_str(&format!(";PARTSTAT={}", partstat_text(p.partstat)));
+        if p.rsvp {
+            line.push_str(";RSVP=TRUE");
+        }
+    }
+    line.push_str(&format!(":mailto:{}", p.email));
+    line
+}
+
+fn participant(prop: &ContentLine) -> Participant {
+    let email = prop.value.trim();
+    let email = if email.len() >= 7 && email[..7].eq_ignore_ascii_case("mailto:") {
+        &email[7..]
+    } else {
+        email
+    };
+    Participant {
+        email: email.to_string(),
+        name: prop.param("CN").map(str::to_string),
+        partstat: match prop
+            .param("PARTSTAT")
+            .map(str::to_ascii_uppercase)
+            .as_deref()
+        {
+            Some("ACCEPTED") => PartStat::Accepted,
+            Some("DECLINED") => PartStat::Declined,
+            Some("TENTATIVE") => PartStat::Tentative,
+            Some("DELEGATED") => PartStat::Delegated,
+            _ => PartStat::NeedsAction,
+        },
+        rsvp: prop
+            .param("RSVP")
+            .is_some_and(|v| v.eq_ignore_ascii_case("TRUE")),
+    }
+}
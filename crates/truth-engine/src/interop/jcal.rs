@@ -0,0 +1,474 @@
+//! jCal (RFC 7265): iCalendar as JSON.
+//!
+//! jCal is a mechanical mapping of iCalendar: a component becomes
+//! `[name, properties, subcomponents]` and a property
+//! `[name, parameters, type, value...]`. [`ics_to_jcal`] and [`jcal_to_ics`]
+//! implement that mapping once; the event and free/busy helpers go through the
+//! iCalendar reader and writer in [`ics`](super::ics), so the JSON and text
+//! forms of the same data always agree.
+
+use chrono::{DateTime, Utc};
+use serde_json::{json, Map, Value};
+
+use super::ics::{
+    self, content_lines, escape_text, invalid, unescape_text, Calendar, ContentLine, Lines,
+};
+use crate::availability::BusyBlock;
+use crate::error::Result;
+use crate::temporal::interval::TimeInterval;
+
+/// Read events from a jCal `vcalendar` (see [`ics::parse_ics`]).
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidCalendar`](crate::TruthError::InvalidCalendar)
+/// if the JSON is not jCal, or any error [`ics::parse_ics`] reports.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use truth_engine::interop::jcal::parse_jcal;
+///
+/// let jcal = json!(["vcalendar", [], [
+///     ["vevent", [
+///         ["uid", {}, "text", "standup@example.com"],
+///         ["dtstart", {"tzid": "Europe/Berlin"}, "date-time", "2026-03-02T09:00:00"],
+///         ["duration", {}, "duration", "PT15M"],
+///         ["rrule", {}, "recur", {"freq": "DAILY", "count": 5}]
+///     ], []]
+/// ]]);
+/// let calendar = parse_jcal(&jcal).unwrap();
+/// assert_eq!(calendar.events[0].rrule.as_deref(), Some("FREQ=DAILY;COUNT=5"));
+/// ```
+pub fn parse_jcal(jcal: &Value) -> Result<Calendar> {
+    ics::parse_ics(&jcal_to_ics(jcal)?)
+}
+
+/// Write a [`Calendar`] as jCal (see [`ics::write_ics`]).
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`](crate::TruthError::InvalidTimezone)
+/// if a TZID does not name a known zone.
+pub fn write_jcal(calendar: &Calendar, dtstamp: DateTime<Utc>) -> Result<Value> {
+    ics_to_jcal(&ics::write_ics(calendar, dtstamp)?)
+}
+
+/// Read the busy periods of a jCal `vfreebusy` (see [`ics::parse_freebusy`]).
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidCalendar`](crate::TruthError::InvalidCalendar)
+/// if the JSON is not jCal, or any error [`ics::parse_freebusy`] reports.
+pub fn parse_freebusy_jcal(jcal: &Value) -> Result<Vec<TimeInterval>> {
+    ics::parse_freebusy(&jcal_to_ics(jcal)?)
+}
+
+/// Write busy blocks as a jCal `vfreebusy` (see [`ics::write_freebusy`]).
+///
+/// # Errors
+///
+/// Does not fail for blocks produced by the engine; the `Result` covers the
+/// text-to-JSON mapping.
+pub fn write_freebusy_jcal(
+    busy: &[BusyBlock],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    dtstamp: DateTime<Utc>,
+) -> Result<Value> {
+    ics_to_jcal(&ics::write_freebusy(
+        busy,
+        window_start,
+        window_end,
+        dtstamp,
+    ))
+}
+
+/// Convert iCalendar text to jCal. Returns the first top-level component
+/// (normally the `vcalendar`).
+///
+/// Values are typed per RFC 7265: dates become `2026-03-02`, date-times
+/// `2026-03-02T09:00:00Z`, RRULEs objects, periods `[start, end]` pairs, and
+/// TEXT values are unescaped. Properties the mapping does not know are typed
+/// `unknown` and kept verbatim.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidCalendar`](crate::TruthError::InvalidCalendar)
+/// for malformed lines, unbalanced BEGIN/END, or text with no component.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use truth_engine::interop::jcal::ics_to_jcal;
+///
+/// let jcal = ics_to_jcal(
+///     "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\n\
+///      DTSTART;VALUE=DATE:20260302\r\nSUMMARY:Offsite\\, day 1\r\n\
+///      END:VEVENT\r\nEND:VCALENDAR\r\n",
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     jcal,
+///     json!(["vcalendar", [], [["vevent", [
+///         ["dtstart", {}, "date", "2026-03-02"],
+///         ["summary", {}, "text", "Offsite, day 1"]
+///     ], []]]])
+/// );
+/// ```
+pub fn ics_to_jcal(ics: &str) -> Result<Value> {
+    // (name, properties, subcomponents) for each open component.
+    let mut stack: Vec<(String, Vec<Value>, Vec<Value>)> = Vec::new();
+    let mut root = None;
+    for line in content_lines(ics)? {
+        match line.name.as_str() {
+            "BEGIN" => stack.push((line.value.to_ascii_lowercase(), Vec::new(), Vec::new())),
+            "END" => {
+                let (name, properties, components) = stack
+                    .pop()
+                    .filter(|(name, ..)| name.eq_ignore_ascii_case(&line.value))
+                    .ok_or_else(|| invalid(format!("unexpected END:{}", line.value)))?;
+                let component = json!([name, properties, components]);
+                match stack.last_mut() {
+                    Some((_, _, siblings)) => siblings.push(component),
+                    None => {
+                        root.get_or_insert(component);
+                    }
+                }
+            }
+            _ => {
+                let (_, properties, _) = stack
+                    .last_mut()
+                    .ok_or_else(|| invalid(format!("{} is outside any component", line.name)))?;
+                properties.push(property_to_jcal(&line));
+            }
+        }
+    }
+    if let Some((name, ..)) = stack.pop() {
+        return Err(invalid(format!(
+            "BEGIN:{} is never closed",
+            name.to_ascii_uppercase()
+        )));
+    }
+    root.ok_or_else(|| invalid("no component found".to_string()))
+}
+
+/// Convert jCal to iCalendar text (CRLF line endings, folded at 75 octets).
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidCalendar`](crate::TruthError::InvalidCalendar)
+/// if a component or property does not have the jCal array shape.
+pub fn jcal_to_ics(jcal: &Value) -> Result<String> {
+    let mut out = Lines::default();
+    write_component(&mut out, jcal)?;
+    Ok(out.0)
+}
+
+// ── Text → JSON ─────────────────────────────────────────────────────────────
+
+fn property_to_jcal(line: &ContentLine) -> Value {
+    let value_type = value_type(line);
+    let params: Map<String, Value> = line
+        .params
+        .iter()
+        .filter(|(k, _)| k != "VALUE")
+        .map(|(k, v)| (k.to_ascii_lowercase(), Value::String(v.clone())))
+        .collect();
+    let mut property = vec![
+        json!(line.name.to_ascii_lowercase()),
+        Value::Object(params),
+        json!(value_type),
+    ];
+    let raw = line.value.as_str();
+    match value_type {
+        "text" => property.push(json!(unescape_text(raw))),
+        "recur" => property.push(recur_to_jcal(raw)),
+        "integer" => property.push(raw.trim().parse::<i64>().map_or(json!(raw), |n| json!(n))),
+        "boolean" => property.push(json!(raw.eq_ignore_ascii_case("TRUE"))),
+        "float" => property.push(match raw.split_once(';') {
+            // GEO is a structured pair.
+            Some((lat, lon)) => json!([float_or_text(lat), float_or_text(lon)]),
+            None => float_or_text(raw),
+        }),
+        "date" | "date-time" => property.extend(raw.split(',').map(|v| json!(iso_datetime(v)))),
+        "period" => property.extend(raw.split(',').map(|v| match v.split_once('/') {
+            Some((start, end)) => json!([iso_datetime(start), iso_datetime(end)]),
+            None => json!(v),
+        })),
+        "utc-offset" => property.push(json!(iso_offset(raw))),
+        _ => property.push(json!(raw)),
+    }
+    Value::Array(property)
+}
+
+/// The jCal type of a property's value: its VALUE parameter, else the
+/// property's RFC 5545 default.
+fn value_type(line: &ContentLine) -> &'static str {
+    if let Some(value) = line.param("VALUE") {
+        return VALUE_TYPES
+            .iter()
+            .find(|t| t.eq_ignore_ascii_case(value))
+            .copied()
+            .unwrap_or("unknown");
+    }
+    match default_type(&line.name) {
+        "date-time" if is_date(line.value.split(',').next().unwrap_or_default()) => "date",
+        other => other,
+    }
+}
+
+const VALUE_TYPES: [&str; 14] = [
+    "binary",
+    "boolean",
+    "cal-address",
+    "date",
+    "date-time",
+    "duration",
+    "float",
+    "integer",
+    "period",
+    "recur",
+    "text",
+    "time",
+    "uri",
+    "utc-offset",
+];
+
+fn default_type(name: &str) -> &'static str {
+    match name {
+        "DTSTART" | "DTEND" | "DUE" | "RECURRENCE-ID" | "EXDATE" | "RDATE" | "DTSTAMP"
+        | "CREATED" | "LAST-MODIFIED" | "COMPLETED" => "date-time",
+        "DURATION" | "TRIGGER" => "duration",
+        "RRULE" | "EXRULE" => "recur",
+        "FREEBUSY" => "period",
+        "TZOFFSETFROM" | "TZOFFSETTO" => "utc-offset",
+        "GEO" => "float",
+        "PRIORITY" | "SEQUENCE" | "PERCENT-COMPLETE" | "REPEAT" => "integer",
+        "URL" | "TZURL" | "ATTACH" => "uri",
+        "ATTENDEE" | "ORGANIZER" => "cal-address",
+        "ACTION" | "CALSCALE" | "CATEGORIES" | "CLASS" | "COMMENT" | "CONTACT" | "DESCRIPTION"
+        | "LOCATION" | "METHOD" | "PRODID" | "RELATED-TO" | "REQUEST-STATUS" | "RESOURCES"
+        | "STATUS" | "SUMMARY" | "TRANSP" | "TZID" | "TZNAME" | "UID" | "VERSION" => "text",
+        _ => "unknown",
+    }
+}
+
+fn is_date(value: &str) -> bool {
+    value.len() == 8 && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// `20260302` → `2026-03-02`; `20260302T090000Z` → `2026-03-02T09:00:00Z`.
+/// Anything else (e.g., a period's duration) is returned unchanged.
+fn iso_datetime(value: &str) -> String {
+    let b = value.as_bytes();
+    let digits =
+        |r: std::ops::Range<usize>| b.get(r).is_some_and(|s| s.iter().all(u8::is_ascii_digit));
+    if is_date(value) {
+        return format!("{}-{}-{}", &value[..4], &value[4..6], &value[6..]);
+    }
+    let utc = value.ends_with('Z');
+    if (value.len() == 15 || (value.len() == 16 && utc))
+        && digits(0..8)
+        && b[8] == b'T'
+        && digits(9..15)
+    {
+        return format!(
+            "{}-{}-{}T{}:{}:{}{}",
+            &value[..4],
+            &value[4..6],
+            &value[6..8],
+            &value[9..11],
+            &value[11..13],
+            &value[13..15],
+            if utc { "Z" } else { "" }
+        );
+    }
+    value.to_string()
+}
+
+/// `-0500` → `-05:00`; `+053728` → `+05:37:28`.
+fn iso_offset(value: &str) -> String {
+    let (sign, digits) = value.split_at_checked(1).unwrap_or(("", value));
+    match digits.len() {
+        4 => format!("{}{}:{}", sign, &digits[..2], &digits[2..]),
+        6 => format!(
+            "{}{}:{}:{}",
+            sign,
+            &digits[..2],
+            &digits[2..4],
+            &digits[4..]
+        ),
+        _ => value.to_string(),
+    }
+}
+
+fn float_or_text(value: &str) -> Value {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+        .map_or_else(|| json!(value), Value::Number)
+}
+
+/// RRULE parts become keys; numeric parts become numbers and multi-valued
+/// parts arrays (`{"freq": "WEEKLY", "byday": ["TU", "TH"]}`).
+fn recur_to_jcal(value: &str) -> Value {
+    let mut recur = Map::new();
+    for part in value.split(';').filter(|p| !p.is_empty()) {
+        let (key, value) = part.split_once('=').unwrap_or((part, ""));
+        let key = key.to_ascii_lowercase();
+        let numeric = matches!(
+            key.as_str(),
+            "count"
+                | "interval"
+                | "bysecond"
+                | "byminute"
+                | "byhour"
+                | "bymonthday"
+                | "byyearday"
+                | "byweekno"
+                | "bymonth"
+                | "bysetpos"
+        );
+        let item = |v: &str| match v.parse::<i64>() {
+            Ok(n) if numeric => json!(n),
+            _ if key == "until" => json!(iso_datetime(v)),
+            _ => json!(v),
+        };
+        let values: Vec<Value> = value.split(',').map(item).collect();
+        let entry = match <[Value; 1]>::try_from(values) {
+            Ok([single]) => single,
+            Err(many) => Value::Array(many),
+        };
+        recur.insert(key, entry);
+    }
+    Value::Object(recur)
+}
+
+// ── JSON → text ─────────────────────────────────────────────────────────────
+
+fn write_component(out: &mut Lines, component: &Value) -> Result<()> {
+    let Some([Value::String(name), Value::Array(properties), Value::Array(components)]) =
+        component.as_array().map(Vec::as_slice)
+    else {
+        return Err(invalid(format!(
+            "not a jCal component: {}",
+            truncate(component)
+        )));
+    };
+    let name = name.to_ascii_uppercase();
+    out.push(&format!("BEGIN:{}", name));
+    for property in properties {
+        out.push(&property_to_ics(property)?);
+    }
+    for component in components {
+        write_component(out, component)?;
+    }
+    out.push(&format!("END:{}", name));
+    Ok(())
+}
+
+fn property_to_ics(property: &Value) -> Result<String> {
+    let bad = || invalid(format!("not a jCal property: {}", truncate(property)));
+    let Some([Value::String(name), Value::Object(params), Value::String(value_type), values @ ..]) =
+        property.as_array().map(Vec::as_slice)
+    else {
+        return Err(bad());
+    };
+    if values.is_empty() {
+        return Err(bad());
+    }
+    let name = name.to_ascii_uppercase();
+    let mut line = name.clone();
+    for (key, value) in params {
+        let value = match value {
+            Value::Array(items) => items.iter().map(scalar_text).collect::<Vec<_>>().join(","),
+            other => scalar_text(other),
+        };
+        let quoted = value.contains([':', ';', ',']);
+        line.push_str(&format!(
+            ";{}={}",
+            key.to_ascii_uppercase(),
+            if quoted {
+                format!("\"{}\"", value)
+            } else {
+                value
+            }
+        ));
+    }
+    if value_type != default_type(&name) && value_type != "unknown" {
+        line.push_str(&format!(";VALUE={}", value_type.to_ascii_uppercase()));
+    }
+
+    let text: Vec<String> = values
+        .iter()
+        .map(|v| match (value_type.as_str(), v) {
+            ("text", Value::String(s)) => Ok(escape_text(s)),
+            ("recur", Value::Object(recur)) => Ok(recur_to_ics(recur)),
+            ("date" | "date-time", Value::String(s)) => Ok(compact(s)),
+            ("period", Value::Array(pair)) => match pair.as_slice() {
+                [Value::String(start), Value::String(end)] => {
+                    Ok(format!("{}/{}", compact(start), compact(end)))
+                }
+                _ => Err(bad()),
+            },
+            ("utc-offset", Value::String(s)) => Ok(s.replace(':', "")),
+            ("float", Value::Array(pair)) => {
+                Ok(pair.iter().map(scalar_text).collect::<Vec<_>>().join(";"))
+            }
+            ("boolean", Value::Bool(b)) => Ok(if *b { "TRUE" } else { "FALSE" }.to_string()),
+            (_, Value::Array(_) | Value::Object(_)) => Err(bad()),
+            (_, scalar) => Ok(scalar_text(scalar)),
+        })
+        .collect::<Result<_>>()?;
+    Ok(format!("{}:{}", line, text.join(",")))
+}
+
+fn recur_to_ics(recur: &Map<String, Value>) -> String {
+    // FREQ leads, as RFC 5545 recommends for compatibility.
+    let mut parts: Vec<(&String, &Value)> = recur.iter().collect();
+    parts.sort_by_key(|(key, _)| *key != "freq");
+    parts
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                Value::Array(items) => items.iter().map(scalar_text).collect::<Vec<_>>().join(","),
+                other => scalar_text(other),
+            };
+            let value = if key == "until" {
+                compact(&value)
+            } else {
+                value
+            };
+            format!("{}={}", key.to_ascii_uppercase(), value)
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// `2026-03-02T09:00:00Z` → `20260302T090000Z`. Durations pass through.
+fn compact(value: &str) -> String {
+    if value.starts_with(['P', '+', '-']) {
+        return value.to_string();
+    }
+    value.replace(['-', ':'], "")
+}
+
+fn scalar_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn truncate(value: &Value) -> String {
+    let text = value.to_string();
+    match text.char_indices().nth(60) {
+        Some((cut, _)) => format!("{}…", &text[..cut]),
+        None => text,
+    }
+}
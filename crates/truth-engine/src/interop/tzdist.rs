@@ -0,0 +1,511 @@
+//! Timezone data from a tzdist (RFC 7808) service.
+//!
+//! The engine's built-in zones come from the tz database compiled into
+//! `chrono-tz`. A deployment that must pick up rule changes without a new
+//! build can fetch VTIMEZONE definitions from a tzdist server and load them
+//! into a [`ZoneStore`], which converts instants with those rules.
+//!
+//! As in [`caldav`](super::caldav), the engine does no I/O: [`zones_path`]
+//! and [`zone_path`] build the request paths under the service's context
+//! path (discovered at [`WELL_KNOWN_PATH`]), [`parse_zone_list`] reads the
+//! `/zones` listing, and [`ZoneStore::load`] reads the `text/calendar` body
+//! of a zone lookup. [`ZoneStore::changed`] compares a later listing with
+//! the loaded etags to find zones that need fetching again.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Offset, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::ics::{content_lines, invalid, parse_value, DateTimeValue};
+use crate::error::{Result, TruthError};
+use crate::expander::expand_rrule;
+use crate::temporal::ConvertedDatetime;
+
+/// Where a tzdist service advertises its context path (RFC 7808 §4.2.1).
+pub const WELL_KNOWN_PATH: &str = "/.well-known/timezone";
+
+/// Recurring observances are expanded up to this instant; later instants
+/// keep the offset of the last transition before it.
+const EXPANSION_HORIZON: &str = "2200-01-01T00:00:00";
+
+/// The `/zones` listing of a tzdist service.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ZoneList {
+    /// Opaque token to send as `changedsince` on the next listing request.
+    pub synctoken: Option<String>,
+    pub zones: Vec<ZoneInfo>,
+}
+
+/// One entry of a [`ZoneList`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ZoneInfo {
+    pub tzid: String,
+    /// Changes whenever the zone's data changes.
+    pub etag: Option<String>,
+    #[cfg_attr(feature = "serde", serde(rename = "last-modified"))]
+    pub last_modified: Option<String>,
+    /// Other identifiers that name this zone (e.g. `US/Eastern`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub aliases: Vec<String>,
+}
+
+/// Path of the zone listing under `context`, optionally only the zones
+/// changed since a previous listing's [`synctoken`](ZoneList::synctoken).
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::interop::tzdist::zones_path;
+///
+/// assert_eq!(zones_path("/tzdist", None), "/tzdist/zones");
+/// assert_eq!(zones_path("/tzdist/", Some("2026-02-18T12:00:00Z")), "/tzdist/zones?changedsince=2026-02-18T12%3A00%3A00Z");
+/// ```
+pub fn zones_path(context: &str, changed_since: Option<&str>) -> String {
+    let base = format!("{}/zones", context.trim_end_matches('/'));
+    match changed_since {
+        Some(token) => format!("{}?changedsince={}", base, percent_encode(token)),
+        None => base,
+    }
+}
+
+/// Path of one zone's VTIMEZONE under `context`. The TZID is a single path
+/// segment, so its slashes are percent-encoded.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::interop::tzdist::zone_path;
+///
+/// assert_eq!(zone_path("/tzdist", "America/New_York"), "/tzdist/zones/America%2FNew_York");
+/// ```
+pub fn zone_path(context: &str, tzid: &str) -> String {
+    format!(
+        "{}/zones/{}",
+        context.trim_end_matches('/'),
+        percent_encode(tzid)
+    )
+}
+
+/// Parse the JSON body of a `/zones` response. Requires the `serde` feature.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidCalendar`] if the body is not a zone listing.
+#[cfg(feature = "serde")]
+pub fn parse_zone_list(body: &str) -> Result<ZoneList> {
+    serde_json::from_str(body).map_err(|e| invalid(format!("invalid tzdist zone list: {}", e)))
+}
+
+/// The offset and name in effect from a transition on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Observance {
+    /// Seconds east of UTC (TZOFFSETTO).
+    pub utc_offset: i32,
+    /// TZNAME, e.g. `EDT`.
+    pub abbreviation: Option<String>,
+    /// Whether this is a DAYLIGHT observance.
+    pub dst: bool,
+}
+
+/// A change of observance at an instant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transition {
+    pub at: DateTime<Utc>,
+    pub observance: Observance,
+}
+
+/// The rules of one zone, read from a VTIMEZONE.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneRules {
+    pub tzid: String,
+    /// In effect before the first transition.
+    initial: Observance,
+    /// Sorted by instant.
+    transitions: Vec<Transition>,
+}
+
+impl ZoneRules {
+    /// Every transition, in order.
+    pub fn transitions(&self) -> &[Transition] {
+        &self.transitions
+    }
+
+    /// The observance in effect at `instant`.
+    pub fn observance_at(&self, instant: DateTime<Utc>) -> &Observance {
+        match self.transitions.partition_point(|t| t.at <= instant) {
+            0 => &self.initial,
+            n => &self.transitions[n - 1].observance,
+        }
+    }
+
+    /// The UTC offset at `instant`.
+    pub fn offset_at(&self, instant: DateTime<Utc>) -> FixedOffset {
+        fixed(self.observance_at(instant).utc_offset)
+    }
+
+    /// `instant` on this zone's wall clock.
+    pub fn to_local(&self, instant: DateTime<Utc>) -> DateTime<FixedOffset> {
+        instant.with_timezone(&self.offset_at(instant))
+    }
+
+    /// The instant a wall-clock time names, taking the earlier instant for
+    /// ambiguous (fall-back) times and shifting forward past spring-forward
+    /// gaps, as the engine does for built-in zones.
+    pub fn to_utc(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        // Offsets a day either side bracket any single transition.
+        let naive_utc = local.and_utc();
+        let before = self
+            .offset_at(naive_utc - chrono::Duration::days(1))
+            .local_minus_utc();
+        let after = self
+            .offset_at(naive_utc + chrono::Duration::days(1))
+            .local_minus_utc();
+        let candidate = |offset: i32| naive_utc - chrono::Duration::seconds(i64::from(offset));
+        [before, after]
+            .into_iter()
+            .map(candidate)
+            .filter(|&utc| {
+                let offset = self.offset_at(utc).local_minus_utc();
+                candidate(offset) == utc
+            })
+            .min()
+            // In a gap, reading the time with the earlier offset lands just
+            // past the transition by the length of the gap.
+            .unwrap_or_else(|| candidate(before))
+    }
+}
+
+/// Zones loaded from a tzdist service, looked up by TZID or alias.
+#[derive(Debug, Clone, Default)]
+pub struct ZoneStore {
+    zones: HashMap<String, ZoneRules>,
+    etags: HashMap<String, String>,
+    aliases: HashMap<String, String>,
+    synctoken: Option<String>,
+}
+
+impl ZoneStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load every VTIMEZONE in a zone lookup response, replacing earlier
+    /// rules for the same TZIDs, and return the TZIDs loaded. `etag` is the
+    /// response's `ETag` header, compared by [`changed`](Self::changed).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidCalendar`] if the body has no VTIMEZONE
+    /// or an observance lacks DTSTART or a valid TZOFFSETFROM/TZOFFSETTO, or
+    /// [`TruthError::InvalidRule`] for an unexpandable RRULE.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    /// use truth_engine::interop::tzdist::ZoneStore;
+    ///
+    /// let mut store = ZoneStore::new();
+    /// store
+    ///     .load(
+    ///         "BEGIN:VCALENDAR\r\nBEGIN:VTIMEZONE\r\nTZID:Europe/Berlin\r\n\
+    ///          BEGIN:DAYLIGHT\r\nDTSTART:19810329T020000\r\n\
+    ///          RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r\n\
+    ///          TZOFFSETFROM:+0100\r\nTZOFFSETTO:+0200\r\nTZNAME:CEST\r\nEND:DAYLIGHT\r\n\
+    ///          BEGIN:STANDARD\r\nDTSTART:19961027T030000\r\n\
+    ///          RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r\n\
+    ///          TZOFFSETFROM:+0200\r\nTZOFFSETTO:+0100\r\nTZNAME:CET\r\nEND:STANDARD\r\n\
+    ///          END:VTIMEZONE\r\nEND:VCALENDAR\r\n",
+    ///         Some("\"2026a\""),
+    ///     )
+    ///     .unwrap();
+    /// let berlin = store.get("Europe/Berlin").unwrap();
+    /// let local = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// assert_eq!(berlin.to_utc(local), Utc.with_ymd_and_hms(2026, 7, 1, 10, 0, 0).unwrap());
+    /// ```
+    pub fn load(&mut self, body: &str, etag: Option<&str>) -> Result<Vec<String>> {
+        let rules = parse_vtimezones(body)?;
+        if rules.is_empty() {
+            return Err(invalid("tzdist response has no VTIMEZONE".to_string()));
+        }
+        Ok(rules
+            .into_iter()
+            .map(|zone| {
+                let tzid = zone.tzid.clone();
+                match etag {
+                    Some(etag) => self.etags.insert(tzid.clone(), etag.to_string()),
+                    None => self.etags.remove(&tzid),
+                };
+                self.zones.insert(tzid.clone(), zone);
+                tzid
+            })
+            .collect())
+    }
+
+    /// Record a listing's aliases and synctoken, for lookups by alias and
+    /// the next `changedsince` request.
+    pub fn record_listing(&mut self, list: &ZoneList) {
+        for zone in &list.zones {
+            for alias in &zone.aliases {
+                self.aliases.insert(alias.clone(), zone.tzid.clone());
+            }
+        }
+        if list.synctoken.is_some() {
+            self.synctoken = list.synctoken.clone();
+        }
+    }
+
+    /// The synctoken of the last recorded listing.
+    pub fn synctoken(&self) -> Option<&str> {
+        self.synctoken.as_deref()
+    }
+
+    /// Loaded zones whose etag in `list` differs from the one they were
+    /// loaded with, i.e. the zones to fetch again.
+    pub fn changed<'a>(&self, list: &'a ZoneList) -> Vec<&'a str> {
+        list.zones
+            .iter()
+            .filter(|z| {
+                self.zones.contains_key(&z.tzid)
+                    && z.etag.as_deref() != self.etags.get(&z.tzid).map(String::as_str)
+            })
+            .map(|z| z.tzid.as_str())
+            .collect()
+    }
+
+    /// The rules for a TZID or a recorded alias.
+    pub fn get(&self, tzid: &str) -> Option<&ZoneRules> {
+        self.zones
+            .get(tzid)
+            .or_else(|| self.zones.get(self.aliases.get(tzid)?))
+    }
+
+    /// [`convert_timezone`](crate::temporal::convert_timezone) using the
+    /// loaded rules for `tzid`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidDatetime`] if `datetime` is not RFC 3339,
+    /// or [`TruthError::InvalidTimezone`] if no zone named `tzid` is loaded.
+    pub fn convert(&self, datetime: &str, tzid: &str) -> Result<ConvertedDatetime> {
+        let instant = DateTime::parse_from_rfc3339(datetime)
+            .map_err(|e| TruthError::InvalidDatetime(format!("'{}': {}", datetime, e)))?
+            .with_timezone(&Utc);
+        let zone = self.get(tzid).ok_or_else(|| {
+            TruthError::InvalidTimezone(format!("{} is not a loaded tzdist zone", tzid))
+        })?;
+        let local = zone.to_local(instant);
+        Ok(ConvertedDatetime {
+            utc: instant.to_rfc3339(),
+            local: local.to_rfc3339(),
+            timezone: zone.tzid.clone().into(),
+            utc_offset: local.offset().to_string(),
+            dst_active: zone.observance_at(instant).dst,
+            renderings: None,
+        })
+    }
+}
+
+// ── VTIMEZONE parsing ───────────────────────────────────────────────────────
+
+/// One STANDARD or DAYLIGHT sub-component.
+#[derive(Default)]
+struct ObservanceBuilder {
+    dst: bool,
+    dtstart: Option<NaiveDateTime>,
+    offset_from: Option<i32>,
+    offset_to: Option<i32>,
+    name: Option<String>,
+    rrule: Option<String>,
+    rdates: Vec<NaiveDateTime>,
+}
+
+/// Every VTIMEZONE in `input`, with its observances expanded into transitions.
+fn parse_vtimezones(input: &str) -> Result<Vec<ZoneRules>> {
+    let mut zones = Vec::new();
+    let mut tzid: Option<String> = None;
+    let mut observances = Vec::new();
+    let mut current: Option<ObservanceBuilder> = None;
+    let mut in_zone = false;
+    for prop in content_lines(input)? {
+        let name = prop.name.as_str();
+        let value = prop.value.trim();
+        match (name, current.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VTIMEZONE") => {
+                in_zone = true;
+                tzid = None;
+                observances.clear();
+            }
+            ("BEGIN", None) if in_zone => {
+                current = Some(ObservanceBuilder {
+                    dst: value.eq_ignore_ascii_case("DAYLIGHT"),
+                    ..ObservanceBuilder::default()
+                })
+            }
+            ("END", Some(_)) => observances.push(current.take().unwrap_or_default()),
+            ("END", None) if in_zone && value.eq_ignore_ascii_case("VTIMEZONE") => {
+                in_zone = false;
+                let tzid = tzid
+                    .take()
+                    .ok_or_else(|| invalid("VTIMEZONE has no TZID".to_string()))?;
+                zones.push(build_rules(tzid, std::mem::take(&mut observances))?);
+            }
+            ("TZID", None) if in_zone => tzid = Some(value.to_string()),
+            ("DTSTART", Some(o)) => o.dtstart = Some(local_value(value)?),
+            ("TZOFFSETFROM", Some(o)) => o.offset_from = Some(parse_offset(value)?),
+            ("TZOFFSETTO", Some(o)) => o.offset_to = Some(parse_offset(value)?),
+            ("TZNAME", Some(o)) => o.name = Some(value.to_string()),
+            ("RRULE", Some(o)) => o.rrule = Some(value.to_string()),
+            ("RDATE", Some(o)) => {
+                for v in value.split(',').filter(|v| !v.trim().is_empty()) {
+                    o.rdates.push(local_value(v)?);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(zones)
+}
+
+fn build_rules(tzid: String, observances: Vec<ObservanceBuilder>) -> Result<ZoneRules> {
+    let mut transitions: Vec<(Transition, i32)> = Vec::new();
+    for o in observances {
+        let missing = |what: &str| invalid(format!("{} observance has no {}", tzid, what));
+        let dtstart = o.dtstart.ok_or_else(|| missing("DTSTART"))?;
+        let from = o.offset_from.ok_or_else(|| missing("TZOFFSETFROM"))?;
+        let to = o.offset_to.ok_or_else(|| missing("TZOFFSETTO"))?;
+        let observance = Observance {
+            utc_offset: to,
+            abbreviation: o.name,
+            dst: o.dst,
+        };
+
+        // Onsets are wall-clock times in the offset being left. Expanding
+        // them as if they were UTC keeps the RRULE's local day arithmetic;
+        // a UTC UNTIL is shifted onto the same clock.
+        let mut onsets = vec![dtstart];
+        if let Some(rrule) = &o.rrule {
+            let rrule = shift_until(rrule, from);
+            let dtstart = dtstart.format("%Y-%m-%dT%H:%M:%S").to_string();
+            // RFC 5545 forbids COUNT together with UNTIL.
+            let horizon =
+                (!rrule.to_ascii_uppercase().contains("COUNT=")).then_some(EXPANSION_HORIZON);
+            onsets = expand_rrule(&rrule, &dtstart, 0, "UTC", horizon, None)?
+                .into_iter()
+                .map(|e| e.start.naive_utc())
+                .collect();
+        }
+        onsets.extend(o.rdates);
+        for onset in onsets {
+            let at = (onset - chrono::Duration::seconds(i64::from(from))).and_utc();
+            transitions.push((
+                Transition {
+                    at,
+                    observance: observance.clone(),
+                },
+                from,
+            ));
+        }
+    }
+    transitions.sort_by_key(|(t, _)| t.at);
+    transitions.dedup_by_key(|(t, _)| t.at);
+    let Some((first, from)) = transitions.first() else {
+        return Err(invalid(format!("{} has no STANDARD or DAYLIGHT", tzid)));
+    };
+    let initial = Observance {
+        utc_offset: *from,
+        abbreviation: None,
+        dst: false,
+    };
+    // The earliest onset is usually the zone's baseline rather than a change.
+    let initial = if first.observance.utc_offset == *from {
+        first.observance.clone()
+    } else {
+        initial
+    };
+    Ok(ZoneRules {
+        tzid,
+        initial,
+        transitions: transitions.into_iter().map(|(t, _)| t).collect(),
+    })
+}
+
+/// A DTSTART or RDATE of an observance as a wall-clock time.
+fn local_value(value: &str) -> Result<NaiveDateTime> {
+    Ok(match parse_value(value, None, false)? {
+        DateTimeValue::Local { datetime, .. } => datetime,
+        DateTimeValue::Utc(at) => at.naive_utc(),
+        DateTimeValue::Date(d) => d.and_time(chrono::NaiveTime::MIN),
+    })
+}
+
+/// Rewrite a UTC `UNTIL` as the wall-clock time at offset `from`.
+fn shift_until(rrule: &str, from: i32) -> String {
+    rrule
+        .split(';')
+        .map(|part| match part.split_once('=') {
+            Some((key, value)) if key.eq_ignore_ascii_case("UNTIL") => {
+                match parse_value(value, None, false) {
+                    Ok(DateTimeValue::Utc(at)) => format!(
+                        "UNTIL={}Z",
+                        (at + chrono::Duration::seconds(i64::from(from))).format("%Y%m%dT%H%M%S")
+                    ),
+                    Ok(DateTimeValue::Date(d)) => format!("UNTIL={}", until_date(d)),
+                    _ => part.to_string(),
+                }
+            }
+            _ => part.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn until_date(date: NaiveDate) -> String {
+    format!("{}T235959Z", date.format("%Y%m%d"))
+}
+
+/// Parse a UTC-OFFSET value (`-0500`, `+0530`, `+013045`) into seconds.
+fn parse_offset(value: &str) -> Result<i32> {
+    let bad = || invalid(format!("'{}' is not a UTC offset", value));
+    let (sign, digits) = match value.as_bytes().first() {
+        Some(b'+') => (1, &value[1..]),
+        Some(b'-') => (-1, &value[1..]),
+        _ => return Err(bad()),
+    };
+    if !(digits.len() == 4 || digits.len() == 6) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(bad());
+    }
+    let field = |i: usize| digits.get(i..i + 2).map_or(Ok(0), str::parse::<i32>);
+    let (h, m, s) = (
+        field(0).map_err(|_| bad())?,
+        field(2).map_err(|_| bad())?,
+        field(4).map_err(|_| bad())?,
+    );
+    if h > 23 || m > 59 || s > 59 {
+        return Err(bad());
+    }
+    Ok(sign * (h * 3600 + m * 60 + s))
+}
+
+fn fixed(secs: i32) -> FixedOffset {
+    // Offsets are checked to be under a day when parsed.
+    FixedOffset::east_opt(secs).unwrap_or_else(|| Utc.fix())
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters.
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -0,0 +1,198 @@
+//! An index of time intervals for stabbing and range queries.
+//!
+//! Conflict detection and availability both ask "which of these intervals
+//! overlap that one?" many times over the same set. [`IntervalTree`] answers
+//! in `O(log n + k)` for `k` matches instead of scanning every interval, with
+//! the same half-open conventions as [`TimeInterval`]: an interval overlaps
+//! another only if they share time, and contains its start but not its end.
+//!
+//! The tree is built once from its entries and is immutable; for a set that
+//! changes, see [`EventStore`](crate::store::EventStore).
+
+use chrono::{DateTime, Utc};
+
+use crate::temporal::interval::TimeInterval;
+
+/// Intervals, each with a value, indexed for overlap queries.
+///
+/// Entries are kept sorted by interval, forming an implicit balanced binary
+/// tree (the middle entry of each range is that subtree's root) augmented
+/// with the latest end in each subtree. Queries return matches in order of
+/// start, then end.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::interval_tree::IntervalTree;
+/// use truth_engine::temporal::interval::TimeInterval;
+///
+/// let at = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+/// let meeting = |start, end| TimeInterval::new(at(start), at(end)).unwrap();
+/// let tree: IntervalTree<&str> = [
+///     (meeting(9, 10), "standup"),
+///     (meeting(9, 12), "workshop"),
+///     (meeting(13, 14), "review"),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let at_930: Vec<_> = tree.stabbing(at(9) + chrono::Duration::minutes(30));
+/// assert_eq!(at_930.len(), 2);
+/// let afternoon = tree.overlapping(&meeting(12, 18));
+/// assert_eq!(afternoon.iter().map(|(_, name)| **name).collect::<Vec<_>>(), ["review"]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalTree<T> {
+    entries: Vec<(TimeInterval, T)>,
+    /// `max_end[i]`: the latest end in the subtree rooted at entry `i`.
+    max_end: Vec<DateTime<Utc>>,
+}
+
+impl<T> IntervalTree<T> {
+    /// Index `entries`. Intervals may overlap or repeat.
+    pub fn new(entries: impl IntoIterator<Item = (TimeInterval, T)>) -> Self {
+        let mut entries: Vec<(TimeInterval, T)> = entries.into_iter().collect();
+        entries.sort_by_key(|(interval, _)| *interval);
+        let mut max_end: Vec<DateTime<Utc>> = entries.iter().map(|(i, _)| i.end).collect();
+        fill_max_end(&mut max_end, 0, entries.len());
+        Self { entries, max_end }
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the tree has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Every entry, in order of start, then end.
+    pub fn iter(&self) -> impl Iterator<Item = (&TimeInterval, &T)> {
+        self.entries
+            .iter()
+            .map(|(interval, value)| (interval, value))
+    }
+
+    /// Entries whose interval contains `instant`.
+    pub fn stabbing(&self, instant: DateTime<Utc>) -> Vec<(&TimeInterval, &T)> {
+        let mut found = Vec::new();
+        self.walk(
+            0,
+            self.entries.len(),
+            instant,
+            &|start| start <= instant,
+            &mut |i| {
+                found.push(i);
+                true
+            },
+        );
+        self.resolve(found)
+    }
+
+    /// Entries whose interval overlaps `range` (shares time with it, as in
+    /// [`TimeInterval::overlaps`]).
+    pub fn overlapping(&self, range: &TimeInterval) -> Vec<(&TimeInterval, &T)> {
+        let mut found = Vec::new();
+        self.walk(
+            0,
+            self.entries.len(),
+            range.start,
+            &|start| start < range.end,
+            &mut |i| {
+                found.push(i);
+                true
+            },
+        );
+        self.resolve(found)
+    }
+
+    /// Whether any entry overlaps `range`; stops at the first.
+    pub fn overlaps_any(&self, range: &TimeInterval) -> bool {
+        let mut any = false;
+        self.walk(
+            0,
+            self.entries.len(),
+            range.start,
+            &|start| start < range.end,
+            &mut |_| {
+                any = true;
+                false
+            },
+        );
+        any
+    }
+
+    fn resolve(&self, found: Vec<usize>) -> Vec<(&TimeInterval, &T)> {
+        found
+            .into_iter()
+            .map(|i| (&self.entries[i].0, &self.entries[i].1))
+            .collect()
+    }
+
+    /// Visit, in order, entries in `lo..hi` that end after `end_after` and
+    /// whose start satisfies `start_ok`, which must hold for a prefix of the
+    /// sorted starts. Returns false once `visit` does.
+    fn walk(
+        &self,
+        lo: usize,
+        hi: usize,
+        end_after: DateTime<Utc>,
+        start_ok: &impl Fn(DateTime<Utc>) -> bool,
+        visit: &mut impl FnMut(usize) -> bool,
+    ) -> bool {
+        if lo >= hi {
+            return true;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if self.max_end[mid] <= end_after {
+            return true;
+        }
+        if !self.walk(lo, mid, end_after, start_ok, visit) {
+            return false;
+        }
+        let interval = &self.entries[mid].0;
+        if !start_ok(interval.start) {
+            // Every later entry starts no earlier.
+            return true;
+        }
+        if interval.end > end_after && !visit(mid) {
+            return false;
+        }
+        self.walk(mid + 1, hi, end_after, start_ok, visit)
+    }
+}
+
+impl<T> Default for IntervalTree<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            max_end: Vec::new(),
+        }
+    }
+}
+
+impl<T> FromIterator<(TimeInterval, T)> for IntervalTree<T> {
+    fn from_iter<I: IntoIterator<Item = (TimeInterval, T)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Set each subtree root in `lo..hi` to the latest end below it, returning
+/// the subtree's.
+fn fill_max_end(max_end: &mut [DateTime<Utc>], lo: usize, hi: usize) -> Option<DateTime<Utc>> {
+    if lo >= hi {
+        return None;
+    }
+    let mid = lo + (hi - lo) / 2;
+    let left = fill_max_end(max_end, lo, mid);
+    let right = fill_max_end(max_end, mid + 1, hi);
+    let latest = [left, right]
+        .into_iter()
+        .flatten()
+        .fold(max_end[mid], DateTime::max);
+    max_end[mid] = latest;
+    Some(latest)
+}
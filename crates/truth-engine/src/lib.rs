@@ -11,30 +11,103 @@
 //!
 //! - [`expander`] — RRULE string → list of concrete datetime instances
 //! - [`dst`] — DST transition policies (skip, shift, etc.)
+//! - [`business`] — Business calendars: work week, working hours, holidays, and closures in one value
+//! - [`calendars`] — Hebrew, Islamic civil, and Chinese dates to and from Gregorian; requires the `calendars` feature
+//! - [`compact`] — Compact events (Unix-second bounds, interned stream ids) with free/busy, availability, and conflicts for calendars of millions of occurrences
 //! - [`conflict`] — Detect overlapping events in expanded schedules
+//! - [`context`] — `TruthContext`: options plus cached timezones and compiled rules, shared across calls
+//! - [`explain`] — Opt-in traces of why an occurrence, a free slot, or a resolved expression came out as it did
+//! - [`fiscal`] — Week-based fiscal calendars (4-4-5, 4-5-4, custom): period boundaries and the period containing a date
 //! - [`freebusy`] — Compute free time slots from event lists
-//! - [`availability`] — Merge N event streams into unified busy/free with privacy control
-//! - [`temporal`] — Timezone conversion, duration computation, timestamp adjustment, relative datetime resolution
+//! - [`markets`] — Exchange sessions, market holidays, and early closes: is the market open, next open and close, trading days
+//! - [`planner`] — Pack tasks with durations, deadlines, and priorities into free working time
+//! - [`intervals`] — Interval tree for stabbing and range queries over sets of time intervals
+//! - [`interop`] — Read and write calendar formats (iCalendar, jCal, Microsoft Graph, CalDAV)
+//! - [`availability`] — Merge N event streams into unified busy/free with privacy control; a group's recurring core hours from their history
+//! - [`booking`] — Holds with deadlines, confirmation, release, and expiry; active bookings block availability
+//! - [`query`] — `ScheduleQuery`: events or streams, window, working hours, buffers, and privacy set once, then conflicts, free slots, the first common slot, reschedule suggestions for a conflicted event, or a placement for a batch of new meetings
+//! - [`provenance`] — Engine version, tzdb version, and options hash stamped on results for cache invalidation
+//! - [`rotations`] — On-call rotations and follow-the-sun shifts with overrides and swaps; who is on call when
+//! - [`sla`] — SLA deadlines and time remaining in working time, with pauses for waiting on the customer
+//! - [`store`] — Indexed in-memory events and series with range queries feeding conflicts, free/busy, and availability
+//! - [`travel`] — Travel-time providers, travel conflicts, and travel buffers for located events
+//! - [`terms`] — Academic terms with breaks and reading weeks: term lookup, teaching days, and availability blackouts
+//! - [`timeline`] — Lay out event streams as lanes, clipped bars, conflict markers, and free gaps for charts
+//! - [`temporal`] — Timezone conversion, duration computation, timestamp adjustment, relative datetime resolution (the last requires the `resolver` feature)
+//! - [`warnings`] — Non-fatal issues (truncation, DST adjustments, unmatched EXDATEs) returned alongside results
 //! - [`error`] — Error types
+//! - `columnar` — Arrow record batches and IPC files of expansion and availability results; requires the `arrow` feature
+//! - `proto` — Protobuf (prost) mirrors of the result types; requires the `protobuf` feature
+//! - `testing` — Proptest strategies and `Arbitrary` impls for valid RRULEs, event sets, and expressions; requires the `test-support` feature
+//! - `tools` — Tool descriptors (name, input schema, dispatch) for RPC layers; requires the `schemars` and `resolver` features
 
 pub mod availability;
+pub mod booking;
+pub mod business;
+#[cfg(feature = "calendars")]
+pub mod calendars;
+#[cfg(feature = "arrow")]
+pub mod columnar;
+pub mod compact;
 pub mod conflict;
+pub mod context;
 pub mod dst;
 pub mod error;
 pub mod expander;
+pub mod explain;
+pub mod fiscal;
 pub mod freebusy;
+pub mod interop;
+pub mod interval_tree;
+pub mod markets;
+pub mod planner;
+#[cfg(feature = "protobuf")]
+pub mod proto;
+pub mod provenance;
+pub mod query;
+pub mod rotations;
+pub mod sla;
+pub mod store;
 pub mod temporal;
+pub mod terms;
+#[cfg(feature = "test-support")]
+pub mod testing;
+pub mod timeline;
+#[cfg(all(feature = "schemars", feature = "resolver"))]
+pub mod tools;
+pub mod travel;
+pub mod warnings;
 
 pub use availability::{
     find_first_free_across, merge_availability, BusyBlock, EventStream, PrivacyLevel,
     UnifiedAvailability,
 };
 pub use conflict::find_conflicts;
-pub use error::TruthError;
+pub use context::TruthContext;
+pub use error::{ErrorClass, ParseError, ParseSpan, TruthError};
 pub use expander::{expand_rrule, expand_rrule_with_exdates, ExpandedEvent};
-pub use freebusy::{find_free_slots, FreeSlot};
+pub use freebusy::{find_free_slots, is_slot_free, FreeSlot, SlotCheck};
+pub use query::{BatchPlacement, MeetingRequest, RescheduleSuggestion, RestRule, ScheduleQuery};
 pub use temporal::{
-    adjust_timestamp, compute_duration, convert_timezone, resolve_relative,
-    resolve_relative_with_options, AdjustedTimestamp, ConvertedDatetime, DurationInfo,
-    ResolveOptions, ResolvedDatetime, WeekStartDay,
+    adjust_timestamp, adjust_timestamp_dt, adjust_timestamp_with_calendar,
+    adjust_timestamp_with_mode, adjust_to_next, adjust_to_next_with_mode, compute_duration,
+    compute_duration_dt, compute_duration_with_mode, convert_instants, convert_timezone,
+    convert_timezone_batch, convert_timezone_batch_with_mode, convert_timezone_dt,
+    convert_timezone_multi, convert_timezone_multi_with_mode, convert_timezone_with_mode,
+    date_from_day_of_year, date_from_julian_day, day_of_year, dst_advisories, find_timezone,
+    find_timezones_by_country, julian_day_number, lookup_timezone_abbreviation, next_matching,
+    overlap_window, parse_datetime, parse_iso_week_date, round_timestamp, round_timestamp_dt,
+    round_timestamp_with_mode, time_until_next, week_number, world_clock, AdjustedTimestamp,
+    AnchorRule, BareHourPolicy, BusinessDuration, ClockFormat, ConvertedDatetime, DayOfMonthPolicy,
+    DstAdvisory, DstAdvisoryKind, DstWarning, DstWarningKind, Duration, DurationFormat,
+    DurationInfo, ExpressionParser, FormatOptions, InputMode, LocalWindow, Locale,
+    MidpointConvention, OutputFields, OverlapWindow, ParserRegistry, PatternDays, RecurringAnchor,
+    Renderings, ResolutionGranularity, ResolveOptions, ResolvedDatetime, RoundingGranularity,
+    RoundingMode, SimplePattern, TimeUntil, TimeUntilOptions, TimezoneAbbreviation, TypoCorrection,
+    WeekNumber, WeekScheme, WeekStartDay, WorkingHours, WorldClockEntry,
+};
+#[cfg(feature = "resolver")]
+pub use temporal::{
+    resolve_relative, resolve_relative_batch, resolve_relative_dt, resolve_relative_with_options,
+    time_until,
 };
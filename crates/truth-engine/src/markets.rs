@@ -0,0 +1,596 @@
+//! Market calendars: exchange sessions, holidays, and early closes.
+//!
+//! A [`MarketCalendar`] describes when an exchange trades: its sessions in
+//! local time, the rules that generate its holidays and half days, and
+//! one-off closures. It answers whether the market is open at an instant,
+//! when it next opens or closes, and how many trading days lie between two
+//! dates. [`MarketCalendar::builtin`] provides the rules of major exchanges;
+//! [`is_market_open`] is the one-call form.
+//!
+//! Holiday rules are evaluated for any year, so built-in calendars reflect
+//! the published rules rather than a bundled list of dates. Unscheduled
+//! closures (national days of mourning, weather) are listed in
+//! [`MarketCalendar::closures`] and must be added as they are announced;
+//! a one-off move of a recurring holiday is not modelled.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, TruthError};
+use crate::temporal::interval::TimeInterval;
+use crate::temporal::{local_to_utc, parse_timezone};
+
+/// A trading window in the exchange's local time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Session {
+    pub open: NaiveTime,
+    pub close: NaiveTime,
+}
+
+/// A date that recurs every year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DateRule {
+    /// The same month and day each year.
+    Fixed { month: u32, day: u32 },
+    /// The `n`-th `weekday` of `month` (`-1` is the last), moved `offset`
+    /// days: the day after Thanksgiving is the fourth Thursday of November
+    /// with offset 1.
+    NthWeekday {
+        month: u32,
+        weekday: Weekday,
+        n: i32,
+        #[cfg_attr(feature = "serde", serde(default))]
+        offset: i64,
+    },
+    /// `offset` days from (Western) Easter Sunday: Good Friday is -2.
+    Easter { offset: i64 },
+}
+
+impl DateRule {
+    /// The date in `year`, or `None` if it does not exist.
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            DateRule::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            DateRule::NthWeekday {
+                month,
+                weekday,
+                n,
+                offset,
+            } => {
+                let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                let last = NaiveDate::from_ymd_opt(year, month + 1, 1)
+                    .or_else(|| NaiveDate::from_ymd_opt(year + 1, 1, 1))?
+                    .pred_opt()?;
+                let date = crate::terms::nth_weekday_between(first, last, weekday, n)?;
+                date.checked_add_signed(Duration::days(offset))
+            }
+            DateRule::Easter { offset } => easter(year)?.checked_add_signed(Duration::days(offset)),
+        }
+    }
+}
+
+/// Where a holiday is observed when it falls on a weekend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Observance {
+    /// Not moved; a weekend holiday is simply lost.
+    #[default]
+    Actual,
+    /// Sunday moves to Monday; Saturday is not observed.
+    SundayToMonday,
+    /// Saturday moves to Friday, Sunday to Monday.
+    NearestWeekday,
+    /// The holiday moves to the next weekday that is not already a holiday
+    /// (UK substitute days: Christmas on Saturday is observed on Monday,
+    /// Boxing Day on the Tuesday).
+    NextFreeWeekday,
+}
+
+/// A recurring market holiday.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MarketHoliday {
+    pub name: String,
+    pub rule: DateRule,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub observance: Observance,
+    /// First year the holiday is observed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub since: Option<i32>,
+}
+
+/// A recurring shortened trading day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EarlyClose {
+    pub name: String,
+    /// Applies only when the date is a trading day.
+    pub rule: DateRule,
+    /// Local time trading ends.
+    pub close: NaiveTime,
+}
+
+/// When an exchange trades.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use truth_engine::markets::{is_market_open, MarketCalendar};
+///
+/// // 10:00 in New York on Friday, November 27, 2026, the day after Thanksgiving.
+/// let at = Utc.with_ymd_and_hms(2026, 11, 27, 15, 0, 0).unwrap();
+/// assert!(is_market_open(at, "NYSE").unwrap());
+///
+/// // The market closes early at 13:00 and reopens on Monday.
+/// let nyse = MarketCalendar::builtin("NYSE").unwrap();
+/// assert_eq!(nyse.next_close(at).unwrap(), Some(Utc.with_ymd_and_hms(2026, 11, 27, 18, 0, 0).unwrap()));
+/// assert_eq!(nyse.next_open(at).unwrap(), Some(Utc.with_ymd_and_hms(2026, 11, 30, 14, 30, 0).unwrap()));
+///
+/// let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+/// assert_eq!(nyse.holiday(date(11, 26)), Some("Thanksgiving Day"));
+/// assert_eq!(nyse.trading_days_between(date(11, 23), date(11, 30)), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MarketCalendar {
+    /// Exchange code ("NYSE").
+    pub code: String,
+    /// IANA timezone the sessions are read in.
+    pub timezone: String,
+    /// Weekdays the exchange trades on.
+    pub trading_days: Vec<Weekday>,
+    /// Trading windows of a regular day, in order (more than one when the
+    /// exchange breaks for lunch).
+    pub sessions: Vec<Session>,
+    /// Recurring holidays, applied in order (which matters for
+    /// [`Observance::NextFreeWeekday`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub holidays: Vec<MarketHoliday>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub early_closes: Vec<EarlyClose>,
+    /// One-off closures by date, with their reason.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub closures: BTreeMap<NaiveDate, String>,
+}
+
+impl MarketCalendar {
+    /// The calendar of a built-in exchange, by code or MIC, case-insensitive:
+    /// `NYSE` (`XNYS`), `NASDAQ` (`XNAS`), and `LSE` (`XLON`). Regular
+    /// sessions only; no pre- or post-market trading.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidMarket`] for an unknown code.
+    pub fn builtin(code: &str) -> Result<Self> {
+        match code.to_ascii_uppercase().as_str() {
+            "NYSE" | "XNYS" => Ok(us_equities("NYSE")),
+            "NASDAQ" | "XNAS" => Ok(us_equities("NASDAQ")),
+            "LSE" | "XLON" => Ok(london()),
+            _ => Err(TruthError::InvalidMarket(format!(
+                "unknown market '{code}'"
+            ))),
+        }
+    }
+
+    /// The holiday or closure `date` falls on, by name. Weekends are not
+    /// holidays.
+    pub fn holiday(&self, date: NaiveDate) -> Option<&str> {
+        if let Some(reason) = self.closures.get(&date) {
+            return Some(reason);
+        }
+        self.observed_holidays(date.year()).remove(&date)
+    }
+
+    /// Whether the exchange trades on `date`.
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        self.trading_days.contains(&date.weekday()) && self.holiday(date).is_none()
+    }
+
+    /// The early closing time on `date`, if it is a shortened trading day.
+    pub fn early_close(&self, date: NaiveDate) -> Option<NaiveTime> {
+        if !self.is_trading_day(date) {
+            return None;
+        }
+        self.early_closes
+            .iter()
+            .filter(|e| e.rule.date_in(date.year()) == Some(date))
+            .map(|e| e.close)
+            .min()
+    }
+
+    /// The sessions on `date` as UTC intervals, cut short on an early-close
+    /// day; empty on a non-trading day.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidTimezone`] for an unknown `timezone`,
+    /// and [`TruthError::InvalidMarket`] if a session does not close after
+    /// it opens or sessions overlap.
+    pub fn sessions_on(&self, date: NaiveDate) -> Result<Vec<TimeInterval>> {
+        let tz = self.checked_timezone()?;
+        Ok(self.local_sessions(date, &tz))
+    }
+
+    /// Trading time within `[window_start, window_end)`, as UTC intervals
+    /// sorted by start.
+    ///
+    /// # Errors
+    ///
+    /// As for [`sessions_on`](Self::sessions_on).
+    pub fn trading_intervals(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> Result<Vec<TimeInterval>> {
+        let tz = self.checked_timezone()?;
+        let window = TimeInterval {
+            start: window_start,
+            end: window_end,
+        };
+        let mut out = Vec::new();
+        if window_start >= window_end {
+            return Ok(out);
+        }
+        let first = window_start.with_timezone(&tz).date_naive();
+        let last = window_end.with_timezone(&tz).date_naive();
+        for date in first.iter_days().take_while(|d| *d <= last) {
+            out.extend(
+                self.local_sessions(date, &tz)
+                    .iter()
+                    .filter_map(|s| s.clamp_to(&window)),
+            );
+        }
+        Ok(out)
+    }
+
+    /// Whether the market is trading at `at`.
+    ///
+    /// # Errors
+    ///
+    /// As for [`sessions_on`](Self::sessions_on).
+    pub fn is_open(&self, at: DateTime<Utc>) -> Result<bool> {
+        Ok(!self
+            .trading_intervals(at, at + Duration::seconds(1))?
+            .is_empty())
+    }
+
+    /// The first session open after `at`, or `None` if the market does not
+    /// open within ten years.
+    ///
+    /// # Errors
+    ///
+    /// As for [`sessions_on`](Self::sessions_on).
+    pub fn next_open(&self, at: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+        self.next_boundary(at, |s| s.start)
+    }
+
+    /// The first session close after `at` — the end of the current session
+    /// while the market is open — or `None` if there is none within ten
+    /// years.
+    ///
+    /// # Errors
+    ///
+    /// As for [`sessions_on`](Self::sessions_on).
+    pub fn next_close(&self, at: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+        self.next_boundary(at, |s| s.end)
+    }
+
+    /// Trading days in `[from, to)`, negated when `to` is before `from`.
+    pub fn trading_days_between(&self, from: NaiveDate, to: NaiveDate) -> i64 {
+        let (lo, hi, sign) = if from <= to {
+            (from, to, 1)
+        } else {
+            (to, from, -1)
+        };
+        let mut holidays = BTreeMap::new();
+        for year in lo.year()..=hi.year() {
+            holidays.extend(self.observed_holidays(year));
+        }
+        let count = lo
+            .iter_days()
+            .take_while(|d| *d < hi)
+            .filter(|d| {
+                self.trading_days.contains(&d.weekday())
+                    && !holidays.contains_key(d)
+                    && !self.closures.contains_key(d)
+            })
+            .count() as i64;
+        sign * count
+    }
+
+    fn next_boundary(
+        &self,
+        at: DateTime<Utc>,
+        edge: impl Fn(&TimeInterval) -> DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let tz = self.checked_timezone()?;
+        let first = at.with_timezone(&tz).date_naive();
+        for date in first.iter_days().take(3660) {
+            if let Some(found) = self
+                .local_sessions(date, &tz)
+                .iter()
+                .map(&edge)
+                .find(|t| *t > at)
+            {
+                return Ok(Some(found));
+            }
+        }
+        Ok(None)
+    }
+
+    fn checked_timezone(&self) -> Result<Tz> {
+        let tz = parse_timezone(&self.timezone)?;
+        if self.sessions.iter().any(|s| s.close <= s.open)
+            || self.sessions.windows(2).any(|w| w[1].open < w[0].close)
+        {
+            return Err(TruthError::InvalidMarket(format!(
+                "sessions of {} must be in order, each closing after it opens",
+                self.code
+            )));
+        }
+        Ok(tz)
+    }
+
+    fn local_sessions(&self, date: NaiveDate, tz: &Tz) -> Vec<TimeInterval> {
+        if !self.is_trading_day(date) {
+            return Vec::new();
+        }
+        let close_by = self.early_close(date);
+        self.sessions
+            .iter()
+            .filter_map(|s| {
+                let close = close_by.map_or(s.close, |c| c.min(s.close));
+                if close <= s.open {
+                    return None;
+                }
+                Some(TimeInterval {
+                    start: local_to_utc(tz, date.and_time(s.open))?,
+                    end: local_to_utc(tz, date.and_time(close))?,
+                })
+            })
+            .collect()
+    }
+
+    /// Observed holiday dates in `year`, by name. Rules for the years on
+    /// either side are applied too, as an observance can cross New Year.
+    fn observed_holidays(&self, year: i32) -> BTreeMap<NaiveDate, &str> {
+        let mut observed = BTreeMap::new();
+        for y in year - 1..=year + 1 {
+            for holiday in &self.holidays {
+                if holiday.since.is_some_and(|since| y < since) {
+                    continue;
+                }
+                let Some(date) = holiday.rule.date_in(y) else {
+                    continue;
+                };
+                if let Some(date) = self.observe(date, holiday.observance, &observed) {
+                    observed.insert(date, holiday.name.as_str());
+                }
+            }
+        }
+        observed.retain(|date, _| date.year() == year);
+        observed
+    }
+
+    fn observe(
+        &self,
+        date: NaiveDate,
+        observance: Observance,
+        taken: &BTreeMap<NaiveDate, &str>,
+    ) -> Option<NaiveDate> {
+        let weekend = |d: &NaiveDate| matches!(d.weekday(), Weekday::Sat | Weekday::Sun);
+        let observed = match (observance, date.weekday()) {
+            // Also past a substitute day already taken: Christmas on Sunday
+            // moves to Monday, pushing Boxing Day to Tuesday.
+            (Observance::NextFreeWeekday, _) => date
+                .iter_days()
+                .take(7)
+                .find(|d| !weekend(d) && !taken.contains_key(d))?,
+            _ if !weekend(&date) => date,
+            (Observance::Actual, _) => return None,
+            (Observance::SundayToMonday, Weekday::Sat) => return None,
+            (Observance::NearestWeekday, Weekday::Sat) => date.pred_opt()?,
+            (Observance::SundayToMonday | Observance::NearestWeekday, _) => date.succ_opt()?,
+        };
+        self.trading_days
+            .contains(&observed.weekday())
+            .then_some(observed)
+    }
+}
+
+/// Whether the built-in market `market` (see [`MarketCalendar::builtin`])
+/// is trading at `at`.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidMarket`] for an unknown market.
+pub fn is_market_open(at: DateTime<Utc>, market: &str) -> Result<bool> {
+    MarketCalendar::builtin(market)?.is_open(at)
+}
+
+/// Easter Sunday in `year` (Gregorian computus).
+fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let h = (19 * a + b - b / 4 - (b - (b + 8) / 25 + 1) / 3 + 15).rem_euclid(30);
+    let l = (32 + 2 * (b % 4) + 2 * (c / 4) - h - c % 4).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let n = h + l - 7 * m + 114;
+    NaiveDate::from_ymd_opt(year, (n / 31) as u32, (n % 31 + 1) as u32)
+}
+
+const WEEKDAYS: [Weekday; 5] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+];
+
+fn time(h: u32, m: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, m, 0).expect("valid time")
+}
+
+fn holiday(name: &str, rule: DateRule, observance: Observance) -> MarketHoliday {
+    MarketHoliday {
+        name: name.to_string(),
+        rule,
+        observance,
+        since: None,
+    }
+}
+
+fn nth(month: u32, weekday: Weekday, n: i32) -> DateRule {
+    DateRule::NthWeekday {
+        month,
+        weekday,
+        n,
+        offset: 0,
+    }
+}
+
+/// NYSE and Nasdaq share a holiday schedule.
+fn us_equities(code: &str) -> MarketCalendar {
+    use Observance::{NearestWeekday, SundayToMonday};
+    let fixed = |month, day| DateRule::Fixed { month, day };
+    let early = |name: &str, rule| EarlyClose {
+        name: name.to_string(),
+        rule,
+        close: time(13, 0),
+    };
+    MarketCalendar {
+        code: code.to_string(),
+        timezone: "America/New_York".to_string(),
+        trading_days: WEEKDAYS.to_vec(),
+        sessions: vec![Session {
+            open: time(9, 30),
+            close: time(16, 0),
+        }],
+        holidays: vec![
+            // A Saturday New Year's Day is not moved into the old year.
+            holiday("New Year's Day", fixed(1, 1), SundayToMonday),
+            MarketHoliday {
+                since: Some(1998),
+                ..holiday(
+                    "Martin Luther King Jr. Day",
+                    nth(1, Weekday::Mon, 3),
+                    NearestWeekday,
+                )
+            },
+            holiday(
+                "Washington's Birthday",
+                nth(2, Weekday::Mon, 3),
+                NearestWeekday,
+            ),
+            holiday(
+                "Good Friday",
+                DateRule::Easter { offset: -2 },
+                NearestWeekday,
+            ),
+            holiday("Memorial Day", nth(5, Weekday::Mon, -1), NearestWeekday),
+            MarketHoliday {
+                since: Some(2022),
+                ..holiday("Juneteenth", fixed(6, 19), NearestWeekday)
+            },
+            holiday("Independence Day", fixed(7, 4), NearestWeekday),
+            holiday("Labor Day", nth(9, Weekday::Mon, 1), NearestWeekday),
+            holiday("Thanksgiving Day", nth(11, Weekday::Thu, 4), NearestWeekday),
+            holiday("Christmas Day", fixed(12, 25), NearestWeekday),
+        ],
+        early_closes: vec![
+            early("Independence Day eve", fixed(7, 3)),
+            early(
+                "Day after Thanksgiving",
+                DateRule::NthWeekday {
+                    month: 11,
+                    weekday: Weekday::Thu,
+                    n: 4,
+                    offset: 1,
+                },
+            ),
+            early("Christmas Eve", fixed(12, 24)),
+        ],
+        closures: BTreeMap::from([(
+            NaiveDate::from_ymd_opt(2025, 1, 9).expect("valid date"),
+            "National Day of Mourning for President Jimmy Carter".to_string(),
+        )]),
+    }
+}
+
+fn london() -> MarketCalendar {
+    use Observance::NextFreeWeekday;
+    let fixed = |month, day| DateRule::Fixed { month, day };
+    let early = |name: &str, rule| EarlyClose {
+        name: name.to_string(),
+        rule,
+        close: time(12, 30),
+    };
+    MarketCalendar {
+        code: "LSE".to_string(),
+        timezone: "Europe/London".to_string(),
+        trading_days: WEEKDAYS.to_vec(),
+        sessions: vec![Session {
+            open: time(8, 0),
+            close: time(16, 30),
+        }],
+        holidays: vec![
+            holiday("New Year's Day", fixed(1, 1), NextFreeWeekday),
+            holiday(
+                "Good Friday",
+                DateRule::Easter { offset: -2 },
+                NextFreeWeekday,
+            ),
+            holiday(
+                "Easter Monday",
+                DateRule::Easter { offset: 1 },
+                NextFreeWeekday,
+            ),
+            holiday(
+                "Early May bank holiday",
+                nth(5, Weekday::Mon, 1),
+                NextFreeWeekday,
+            ),
+            holiday(
+                "Spring bank holiday",
+                nth(5, Weekday::Mon, -1),
+                NextFreeWeekday,
+            ),
+            holiday(
+                "Summer bank holiday",
+                nth(8, Weekday::Mon, -1),
+                NextFreeWeekday,
+            ),
+            holiday("Christmas Day", fixed(12, 25), NextFreeWeekday),
+            holiday("Boxing Day", fixed(12, 26), NextFreeWeekday),
+        ],
+        early_closes: vec![
+            early("Christmas Eve", fixed(12, 24)),
+            early("New Year's Eve", fixed(12, 31)),
+        ],
+        closures: [
+            ((2022, 6, 3), "Platinum Jubilee bank holiday"),
+            ((2022, 9, 19), "State Funeral of Queen Elizabeth II"),
+            ((2023, 5, 8), "Coronation bank holiday"),
+        ]
+        .into_iter()
+        .map(|((y, m, d), reason)| {
+            let date = NaiveDate::from_ymd_opt(y, m, d).expect("valid date");
+            (date, reason.to_string())
+        })
+        .collect(),
+    }
+}
@@ -0,0 +1,343 @@
+//! Time-blocking: fit a task list into free working time.
+//!
+//! [`plan_tasks`] takes tasks with durations, deadlines, and priorities plus
+//! the busy events of a calendar, and packs each task into the earliest
+//! free working-hours gap that fits it — keeping a buffer around every
+//! event and block and a cap on planned minutes per day. Tasks are placed
+//! whole, one at a time in a fixed order, so the same inputs always give
+//! the same plan. [`plan_tasks_with_travel`] also keeps room to travel
+//! between a task's location and its neighbours'.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::business::BusinessCalendar;
+use crate::error::{Result, TruthError};
+use crate::expander::ExpandedEvent;
+use crate::temporal::interval::{merge, TimeInterval};
+use crate::temporal::{parse_timezone, working_window};
+use crate::travel::{LocatedEvent, TravelTimeProvider};
+
+/// A piece of work to schedule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Task {
+    pub id: String,
+    /// Length of the block to reserve, in minutes.
+    pub duration_minutes: u32,
+    /// The block must end at or before this instant.
+    pub deadline: Option<DateTime<Utc>>,
+    /// Higher values are placed first.
+    pub priority: i32,
+    /// Where the work happens, for
+    /// [`plan_tasks_with_travel`]; `None` needs no travel.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub location: Option<String>,
+}
+
+/// Constraints on where blocks may go.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PlanOptions {
+    /// Blocks lie entirely within the calendar's working hours, on its
+    /// business days.
+    pub calendar: BusinessCalendar,
+    /// Minimum gap, in minutes, kept between a block and any event or other block.
+    pub buffer_minutes: u32,
+    /// Most minutes of blocks planned on one local day.
+    pub max_minutes_per_day: Option<u32>,
+}
+
+impl Default for PlanOptions {
+    /// The default business calendar, no buffer, no daily cap.
+    fn default() -> Self {
+        Self {
+            calendar: BusinessCalendar::default(),
+            buffer_minutes: 0,
+            max_minutes_per_day: None,
+        }
+    }
+}
+
+/// A proposed block of time for one task.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PlannedBlock {
+    pub task_id: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// Why a task could not be placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum UnplacedReason {
+    /// A gap exists in the window, but only after the deadline.
+    DeadlineTooSoon,
+    /// No gap in the window is long enough.
+    NoRoom,
+}
+
+/// A task left out of the plan.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct UnplacedTask {
+    pub task_id: String,
+    pub reason: UnplacedReason,
+}
+
+/// The result of [`plan_tasks`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Plan {
+    /// Placed blocks, sorted by start.
+    pub blocks: Vec<PlannedBlock>,
+    /// Tasks that did not fit, in placement order.
+    pub unplaced: Vec<UnplacedTask>,
+}
+
+/// Pack `tasks` into the free working time of `[window_start, window_end)`.
+///
+/// Tasks are placed by descending priority, then earliest deadline (tasks
+/// without one last), then id; each goes in the earliest gap that fits it
+/// whole before its deadline, after the buffer around `busy` events and
+/// blocks already placed, and within the daily cap.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] for an unknown
+/// `options.calendar.timezone`, or [`TruthError::InvalidDuration`] for a task with a
+/// zero duration.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::planner::{plan_tasks, PlanOptions, Task};
+/// use truth_engine::ExpandedEvent;
+///
+/// let at = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+/// let busy = [ExpandedEvent::new(at(9), at(12))];
+/// let tasks = [Task {
+///     id: "report".to_string(),
+///     duration_minutes: 120,
+///     deadline: None,
+///     priority: 1,
+///     location: None,
+/// }];
+/// let plan = plan_tasks(&tasks, &busy, at(0), at(23), &PlanOptions::default()).unwrap();
+/// assert_eq!((plan.blocks[0].start, plan.blocks[0].end), (at(12), at(14)));
+/// ```
+pub fn plan_tasks(
+    tasks: &[Task],
+    busy: &[ExpandedEvent],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    options: &PlanOptions,
+) -> Result<Plan> {
+    let busy: Vec<LocatedEvent> = busy
+        .iter()
+        .map(|event| LocatedEvent {
+            event: event.clone(),
+            location: None,
+        })
+        .collect();
+    plan(tasks, &busy, window_start, window_end, options, None)
+}
+
+/// Like [`plan_tasks`], but a located task also keeps the travel time to
+/// and from each located event or block around it (or the buffer, if
+/// longer).
+///
+/// Travel into a neighbour is looked up as leaving at the neighbour's start,
+/// since the task's own end is not yet known.
+///
+/// # Errors
+///
+/// As for [`plan_tasks`].
+pub fn plan_tasks_with_travel(
+    tasks: &[Task],
+    busy: &[LocatedEvent],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    options: &PlanOptions,
+    provider: &dyn TravelTimeProvider,
+) -> Result<Plan> {
+    plan(
+        tasks,
+        busy,
+        window_start,
+        window_end,
+        options,
+        Some(provider),
+    )
+}
+
+fn plan(
+    tasks: &[Task],
+    busy: &[LocatedEvent],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    options: &PlanOptions,
+    provider: Option<&dyn TravelTimeProvider>,
+) -> Result<Plan> {
+    let calendar = &options.calendar;
+    let tz = parse_timezone(&calendar.timezone)?;
+    if let Some(task) = tasks.iter().find(|t| t.duration_minutes == 0) {
+        return Err(TruthError::InvalidDuration(format!(
+            "task '{}' has a zero duration",
+            task.id
+        )));
+    }
+
+    // Working-hours windows per local business day, clipped to the planning
+    // window.
+    let window = TimeInterval {
+        start: window_start,
+        end: window_end,
+    };
+    let mut days: Vec<(NaiveDate, TimeInterval)> = Vec::new();
+    if window_start < window_end {
+        let mut date = window_start.with_timezone(&tz).date_naive();
+        let last = window_end.with_timezone(&tz).date_naive();
+        while date <= last {
+            let hours = calendar
+                .is_business_day(date)
+                .then(|| working_window(date, &tz, &calendar.working_hours))
+                .flatten();
+            if let Some((start, end)) = hours {
+                if let Some(clipped) = (TimeInterval { start, end }).clamp_to(&window) {
+                    days.push((date, clipped));
+                }
+            }
+            date = match date.succ_opt() {
+                Some(d) => d,
+                None => break,
+            };
+        }
+    }
+
+    let mut order: Vec<&Task> = tasks.iter().collect();
+    order.sort_by(|a, b| {
+        b.priority
+            .cmp(&a.priority)
+            .then_with(|| match (a.deadline, b.deadline) {
+                (Some(x), Some(y)) => x.cmp(&y),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            })
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let buffer = Duration::minutes(options.buffer_minutes as i64);
+    // Everything a block must keep clear of, unpadded, with its location.
+    let mut occupied: Vec<(TimeInterval, Option<&str>)> = busy
+        .iter()
+        .map(|e| (TimeInterval::from(&e.event), e.location.as_deref()))
+        .collect();
+    let mut used: HashMap<NaiveDate, i64> = HashMap::new();
+    let mut blocks = Vec::new();
+    let mut unplaced = Vec::new();
+    for task in order {
+        let length = Duration::minutes(task.duration_minutes as i64);
+        let placer = Placer {
+            days: &days,
+            blocked: merge(occupied.iter().map(|(span, location)| {
+                let travel = |from: &str, to: &str, departure| {
+                    provider
+                        .and_then(|p| p.travel_time(from, to, departure))
+                        .unwrap_or_else(Duration::zero)
+                };
+                let (before, after) = match (task.location.as_deref(), location) {
+                    (Some(here), Some(there)) => (
+                        travel(here, there, span.start),
+                        travel(there, here, span.end),
+                    ),
+                    _ => (Duration::zero(), Duration::zero()),
+                };
+                TimeInterval {
+                    start: span.start - before.max(buffer),
+                    end: span.end + after.max(buffer),
+                }
+            })),
+            used: &used,
+            cap: options.max_minutes_per_day.map(i64::from),
+            length,
+        };
+        match placer.earliest(task.deadline) {
+            Some((date, start)) => {
+                *used.entry(date).or_default() += length.num_minutes();
+                occupied.push((
+                    TimeInterval {
+                        start,
+                        end: start + length,
+                    },
+                    task.location.as_deref(),
+                ));
+                blocks.push(PlannedBlock {
+                    task_id: task.id.clone(),
+                    start,
+                    end: start + length,
+                });
+            }
+            None => {
+                let reason = if task.deadline.is_some() && placer.earliest(None).is_some() {
+                    UnplacedReason::DeadlineTooSoon
+                } else {
+                    UnplacedReason::NoRoom
+                };
+                unplaced.push(UnplacedTask {
+                    task_id: task.id.clone(),
+                    reason,
+                });
+            }
+        }
+    }
+    blocks.sort_by(|a, b| (a.start, &a.task_id).cmp(&(b.start, &b.task_id)));
+    Ok(Plan { blocks, unplaced })
+}
+
+/// Finds the earliest start for one task.
+struct Placer<'a> {
+    days: &'a [(NaiveDate, TimeInterval)],
+    blocked: Vec<TimeInterval>,
+    used: &'a HashMap<NaiveDate, i64>,
+    cap: Option<i64>,
+    length: Duration,
+}
+
+impl Placer<'_> {
+    fn earliest(&self, deadline: Option<DateTime<Utc>>) -> Option<(NaiveDate, DateTime<Utc>)> {
+        for (date, working) in self.days {
+            let used = self.used.get(date).copied().unwrap_or(0);
+            if self
+                .cap
+                .is_some_and(|cap| used + self.length.num_minutes() > cap)
+            {
+                continue;
+            }
+            for gap in working.subtract_all(&self.blocked) {
+                let end = gap.start + self.length;
+                if end > gap.end {
+                    continue;
+                }
+                if deadline.is_some_and(|d| end > d) {
+                    return None;
+                }
+                return Some((*date, gap.start));
+            }
+        }
+        None
+    }
+}
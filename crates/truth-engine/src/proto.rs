@@ -0,0 +1,499 @@
+//! Protobuf mirrors of the result types (`protobuf` feature).
+//!
+//! These are the prost messages for `proto/truth_engine.proto`, written out
+//! so building the crate needs no `protoc`; `tests/proto_tests.rs` checks
+//! every message's field names, tags, and presence, and every enum's values,
+//! against the schema. Each engine result converts into
+//! its message with `From`; the calendar inputs a gRPC request carries
+//! ([`ExpandedEvent`] and [`EventStream`]) also convert back with `TryFrom`.
+//!
+//! ```
+//! use chrono::{TimeZone, Utc};
+//! use prost::Message;
+//! use truth_engine::{proto, ExpandedEvent};
+//!
+//! let event = ExpandedEvent::new(
+//!     Utc.with_ymd_and_hms(2026, 3, 2, 14, 0, 0).unwrap(),
+//!     Utc.with_ymd_and_hms(2026, 3, 2, 15, 0, 0).unwrap(),
+//! );
+//! let bytes = proto::ExpandedEvent::from(event.clone()).encode_to_vec();
+//! let decoded = proto::ExpandedEvent::decode(bytes.as_slice()).unwrap();
+//! assert_eq!(ExpandedEvent::try_from(decoded).unwrap(), event);
+//! ```
+
+use chrono::{DateTime, Utc};
+use prost_types::Timestamp;
+
+use crate::error::{Result, TruthError};
+use crate::{availability, conflict, expander, freebusy, temporal};
+
+// ── Calendar computation ────────────────────────────────────────────────────
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ExpandedEvent {
+    #[prost(message, optional, tag = "1")]
+    pub start: Option<Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub end: Option<Timestamp>,
+    #[prost(uint64, optional, tag = "3")]
+    pub occurrence_index: Option<u64>,
+    #[prost(bool, tag = "4")]
+    pub is_rdate: bool,
+    #[prost(bool, tag = "5")]
+    pub is_override: bool,
+    #[prost(message, optional, tag = "6")]
+    pub original_start: Option<Timestamp>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Conflict {
+    #[prost(message, optional, tag = "1")]
+    pub event_a: Option<ExpandedEvent>,
+    #[prost(message, optional, tag = "2")]
+    pub event_b: Option<ExpandedEvent>,
+    #[prost(int64, tag = "3")]
+    pub overlap_minutes: i64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FreeSlot {
+    #[prost(message, optional, tag = "1")]
+    pub start: Option<Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub end: Option<Timestamp>,
+    #[prost(int64, tag = "3")]
+    pub duration_minutes: i64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct EventStream {
+    #[prost(string, tag = "1")]
+    pub stream_id: String,
+    #[prost(message, repeated, tag = "2")]
+    pub events: Vec<ExpandedEvent>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BusyBlock {
+    #[prost(message, optional, tag = "1")]
+    pub start: Option<Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub end: Option<Timestamp>,
+    #[prost(uint64, tag = "3")]
+    pub source_count: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum PrivacyLevel {
+    Unspecified = 0,
+    Full = 1,
+    Opaque = 2,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UnifiedAvailability {
+    #[prost(message, repeated, tag = "1")]
+    pub busy: Vec<BusyBlock>,
+    #[prost(message, repeated, tag = "2")]
+    pub free: Vec<FreeSlot>,
+    #[prost(message, optional, tag = "3")]
+    pub window_start: Option<Timestamp>,
+    #[prost(message, optional, tag = "4")]
+    pub window_end: Option<Timestamp>,
+    #[prost(enumeration = "PrivacyLevel", tag = "5")]
+    pub privacy: i32,
+}
+
+// ── Temporal computation ────────────────────────────────────────────────────
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Renderings {
+    #[prost(string, optional, tag = "1")]
+    pub custom: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub rfc2822: Option<String>,
+    #[prost(int64, optional, tag = "3")]
+    pub epoch_seconds: Option<i64>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConvertedDatetime {
+    #[prost(string, tag = "1")]
+    pub utc: String,
+    #[prost(string, tag = "2")]
+    pub local: String,
+    #[prost(string, tag = "3")]
+    pub timezone: String,
+    #[prost(string, tag = "4")]
+    pub utc_offset: String,
+    #[prost(bool, tag = "5")]
+    pub dst_active: bool,
+    #[prost(message, optional, tag = "6")]
+    pub renderings: Option<Renderings>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DurationInfo {
+    #[prost(int64, tag = "1")]
+    pub total_seconds: i64,
+    #[prost(int64, tag = "2")]
+    pub days: i64,
+    #[prost(int64, tag = "3")]
+    pub hours: i64,
+    #[prost(int64, tag = "4")]
+    pub minutes: i64,
+    #[prost(int64, tag = "5")]
+    pub seconds: i64,
+    #[prost(string, tag = "6")]
+    pub human_readable: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AdjustedTimestamp {
+    #[prost(string, tag = "1")]
+    pub original: String,
+    #[prost(string, tag = "2")]
+    pub adjusted_utc: String,
+    #[prost(string, tag = "3")]
+    pub adjusted_local: String,
+    #[prost(string, tag = "4")]
+    pub adjustment_applied: String,
+    #[prost(string, tag = "5")]
+    pub timezone: String,
+    #[prost(message, optional, tag = "6")]
+    pub renderings: Option<Renderings>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TypoCorrection {
+    #[prost(string, tag = "1")]
+    pub original: String,
+    #[prost(string, tag = "2")]
+    pub corrected: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum DstWarningKind {
+    Unspecified = 0,
+    NearTransition = 1,
+    Ambiguous = 2,
+    Shifted = 3,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DstWarning {
+    #[prost(enumeration = "DstWarningKind", tag = "1")]
+    pub kind: i32,
+    #[prost(string, tag = "2")]
+    pub transition_utc: String,
+    #[prost(string, tag = "3")]
+    pub offset_before: String,
+    #[prost(string, tag = "4")]
+    pub offset_after: String,
+    #[prost(string, tag = "5")]
+    pub message: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum ResolutionGranularity {
+    Unspecified = 0,
+    Date = 1,
+    DateTime = 2,
+    Period = 3,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum WeekScheme {
+    Unspecified = 0,
+    Iso = 1,
+    Us = 2,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WeekNumber {
+    #[prost(int32, tag = "1")]
+    pub year: i32,
+    #[prost(uint32, tag = "2")]
+    pub week: u32,
+    #[prost(enumeration = "WeekScheme", tag = "3")]
+    pub scheme: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ResolvedDatetime {
+    #[prost(string, tag = "1")]
+    pub resolved_utc: String,
+    #[prost(string, tag = "2")]
+    pub resolved_local: String,
+    #[prost(string, tag = "3")]
+    pub timezone: String,
+    #[prost(string, tag = "4")]
+    pub interpretation: String,
+    #[prost(string, optional, tag = "5")]
+    pub expression_timezone: Option<String>,
+    #[prost(message, repeated, tag = "6")]
+    pub corrections: Vec<TypoCorrection>,
+    #[prost(message, repeated, tag = "7")]
+    pub warnings: Vec<DstWarning>,
+    #[prost(message, optional, tag = "8")]
+    pub renderings: Option<Renderings>,
+    #[prost(enumeration = "ResolutionGranularity", tag = "9")]
+    pub granularity: i32,
+    #[prost(message, optional, tag = "10")]
+    pub week: Option<WeekNumber>,
+}
+
+// ── Conversions ─────────────────────────────────────────────────────────────
+
+fn timestamp(dt: DateTime<Utc>) -> Option<Timestamp> {
+    Some(Timestamp {
+        seconds: dt.timestamp(),
+        nanos: dt.timestamp_subsec_nanos() as i32,
+    })
+}
+
+fn datetime(ts: Option<Timestamp>, field: &str) -> Result<DateTime<Utc>> {
+    let ts = ts.ok_or_else(|| TruthError::InvalidDatetime(format!("{} is missing", field)))?;
+    u32::try_from(ts.nanos)
+        .ok()
+        .and_then(|nanos| DateTime::from_timestamp(ts.seconds, nanos))
+        .ok_or_else(|| TruthError::InvalidDatetime(format!("{} is out of range", field)))
+}
+
+impl From<expander::ExpandedEvent> for ExpandedEvent {
+    fn from(e: expander::ExpandedEvent) -> Self {
+        Self {
+            start: timestamp(e.start),
+            end: timestamp(e.end),
+            occurrence_index: e.occurrence_index.map(|i| i as u64),
+            is_rdate: e.is_rdate,
+            is_override: e.is_override,
+            original_start: e.original_start.and_then(timestamp),
+        }
+    }
+}
+
+impl TryFrom<ExpandedEvent> for expander::ExpandedEvent {
+    type Error = TruthError;
+
+    /// Fails with [`TruthError::InvalidDatetime`] if either bound is missing
+    /// or any timestamp is outside chrono's range.
+    fn try_from(e: ExpandedEvent) -> Result<Self> {
+        Ok(Self {
+            start: datetime(e.start, "start")?,
+            end: datetime(e.end, "end")?,
+            occurrence_index: e.occurrence_index.map(|i| i as usize),
+            is_rdate: e.is_rdate,
+            is_override: e.is_override,
+            original_start: e
+                .original_start
+                .map(|ts| datetime(Some(ts), "original_start"))
+                .transpose()?,
+        })
+    }
+}
+
+impl From<conflict::Conflict> for Conflict {
+    fn from(c: conflict::Conflict) -> Self {
+        Self {
+            event_a: Some(c.event_a.into()),
+            event_b: Some(c.event_b.into()),
+            overlap_minutes: c.overlap_minutes,
+        }
+    }
+}
+
+impl From<freebusy::FreeSlot> for FreeSlot {
+    fn from(s: freebusy::FreeSlot) -> Self {
+        Self {
+            start: timestamp(s.start),
+            end: timestamp(s.end),
+            duration_minutes: s.duration_minutes,
+        }
+    }
+}
+
+impl From<availability::EventStream> for EventStream {
+    fn from(s: availability::EventStream) -> Self {
+        Self {
+            stream_id: s.stream_id,
+            events: s.events.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<EventStream> for availability::EventStream {
+    type Error = TruthError;
+
+    fn try_from(s: EventStream) -> Result<Self> {
+        Ok(Self {
+            stream_id: s.stream_id,
+            events: s
+                .events
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl From<availability::BusyBlock> for BusyBlock {
+    fn from(b: availability::BusyBlock) -> Self {
+        Self {
+            start: timestamp(b.start),
+            end: timestamp(b.end),
+            source_count: b.source_count as u64,
+        }
+    }
+}
+
+impl From<availability::PrivacyLevel> for PrivacyLevel {
+    fn from(p: availability::PrivacyLevel) -> Self {
+        match p {
+            availability::PrivacyLevel::Full => Self::Full,
+            availability::PrivacyLevel::Opaque => Self::Opaque,
+        }
+    }
+}
+
+impl From<availability::UnifiedAvailability> for UnifiedAvailability {
+    fn from(a: availability::UnifiedAvailability) -> Self {
+        Self {
+            busy: a.busy.into_iter().map(Into::into).collect(),
+            free: a.free.into_iter().map(Into::into).collect(),
+            window_start: timestamp(a.window_start),
+            window_end: timestamp(a.window_end),
+            privacy: PrivacyLevel::from(a.privacy) as i32,
+        }
+    }
+}
+
+impl From<temporal::Renderings> for Renderings {
+    fn from(r: temporal::Renderings) -> Self {
+        Self {
+            custom: r.custom,
+            rfc2822: r.rfc2822,
+            epoch_seconds: r.epoch_seconds,
+        }
+    }
+}
+
+impl From<temporal::ConvertedDatetime> for ConvertedDatetime {
+    fn from(c: temporal::ConvertedDatetime) -> Self {
+        Self {
+            utc: c.utc,
+            local: c.local,
+            timezone: c.timezone.into_owned(),
+            utc_offset: c.utc_offset,
+            dst_active: c.dst_active,
+            renderings: c.renderings.map(Into::into),
+        }
+    }
+}
+
+impl From<temporal::DurationInfo> for DurationInfo {
+    fn from(d: temporal::DurationInfo) -> Self {
+        Self {
+            total_seconds: d.total_seconds,
+            days: d.days,
+            hours: d.hours,
+            minutes: d.minutes,
+            seconds: d.seconds,
+            human_readable: d.human_readable,
+        }
+    }
+}
+
+impl From<temporal::AdjustedTimestamp> for AdjustedTimestamp {
+    fn from(a: temporal::AdjustedTimestamp) -> Self {
+        Self {
+            original: a.original,
+            adjusted_utc: a.adjusted_utc,
+            adjusted_local: a.adjusted_local,
+            adjustment_applied: a.adjustment_applied,
+            timezone: a.timezone.into_owned(),
+            renderings: a.renderings.map(Into::into),
+        }
+    }
+}
+
+impl From<temporal::TypoCorrection> for TypoCorrection {
+    fn from(t: temporal::TypoCorrection) -> Self {
+        Self {
+            original: t.original,
+            corrected: t.corrected,
+        }
+    }
+}
+
+impl From<temporal::DstWarningKind> for DstWarningKind {
+    fn from(k: temporal::DstWarningKind) -> Self {
+        match k {
+            temporal::DstWarningKind::NearTransition => Self::NearTransition,
+            temporal::DstWarningKind::Ambiguous => Self::Ambiguous,
+            temporal::DstWarningKind::Shifted => Self::Shifted,
+        }
+    }
+}
+
+impl From<temporal::DstWarning> for DstWarning {
+    fn from(w: temporal::DstWarning) -> Self {
+        Self {
+            kind: DstWarningKind::from(w.kind) as i32,
+            transition_utc: w.transition_utc,
+            offset_before: w.offset_before,
+            offset_after: w.offset_after,
+            message: w.message,
+        }
+    }
+}
+
+impl From<temporal::ResolutionGranularity> for ResolutionGranularity {
+    fn from(g: temporal::ResolutionGranularity) -> Self {
+        match g {
+            temporal::ResolutionGranularity::Date => Self::Date,
+            temporal::ResolutionGranularity::DateTime => Self::DateTime,
+            temporal::ResolutionGranularity::Period => Self::Period,
+        }
+    }
+}
+
+impl From<temporal::WeekScheme> for WeekScheme {
+    fn from(s: temporal::WeekScheme) -> Self {
+        match s {
+            temporal::WeekScheme::Iso => Self::Iso,
+            temporal::WeekScheme::Us => Self::Us,
+        }
+    }
+}
+
+impl From<temporal::WeekNumber> for WeekNumber {
+    fn from(w: temporal::WeekNumber) -> Self {
+        Self {
+            year: w.year,
+            week: w.week,
+            scheme: WeekScheme::from(w.scheme) as i32,
+        }
+    }
+}
+
+impl From<temporal::ResolvedDatetime> for ResolvedDatetime {
+    fn from(r: temporal::ResolvedDatetime) -> Self {
+        Self {
+            resolved_utc: r.resolved_utc,
+            resolved_local: r.resolved_local,
+            timezone: r.timezone.into_owned(),
+            interpretation: r.interpretation,
+            expression_timezone: r.expression_timezone,
+            corrections: r.corrections.into_iter().map(Into::into).collect(),
+            warnings: r.warnings.into_iter().map(Into::into).collect(),
+            renderings: r.renderings.map(Into::into),
+            granularity: ResolutionGranularity::from(r.granularity) as i32,
+            week: r.week.map(Into::into),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Provenance: which engine, timezone data, and options produced a result.
+//!
+//! Results are deterministic for a given engine version, IANA timezone
+//! database, and set of options, so those three identify a result for
+//! caching. [`Stamped`] wraps any serializable result with its
+//! [`Provenance`]; a cache compares the stored provenance with
+//! [`Provenance::is_current`] and recomputes when timezone rules or the
+//! engine have changed since.
+//!
+//! The block carries no timestamps or host details, so the same inputs
+//! always serialize to the same bytes.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use serde_json::Value;
+
+/// This crate's version.
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The IANA timezone database release compiled into the engine ("2025b").
+pub const TZDB_VERSION: &str = chrono_tz::IANA_TZDB_VERSION;
+
+/// What a result was computed with.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Provenance {
+    pub engine_version: String,
+    pub tzdb_version: String,
+    /// [`options_hash`] of the options (for a tool call, the arguments) the
+    /// result was computed with.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub options_hash: Option<String>,
+}
+
+impl Provenance {
+    /// The running engine and timezone database, with no options.
+    pub fn current() -> Self {
+        Self {
+            engine_version: ENGINE_VERSION.to_string(),
+            tzdb_version: TZDB_VERSION.to_string(),
+            options_hash: None,
+        }
+    }
+
+    /// The running engine and timezone database, with `options`. Requires
+    /// the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn for_options<O: Serialize + ?Sized>(options: &O) -> Self {
+        Self {
+            options_hash: Some(options_hash(options)),
+            ..Self::current()
+        }
+    }
+
+    /// Whether this engine, with its timezone database, would compute the
+    /// same result: the engine and tzdb versions both match the running
+    /// ones.
+    pub fn is_current(&self) -> bool {
+        self.engine_version == ENGINE_VERSION && self.tzdb_version == TZDB_VERSION
+    }
+}
+
+/// A result with its provenance, serialized as
+/// `{"result": ..., "provenance": {...}}`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "resolver", feature = "serde"))] {
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::provenance::{Provenance, Stamped};
+/// use truth_engine::temporal::{resolve_relative_with_options, ResolveOptions};
+///
+/// let options = ResolveOptions::default();
+/// let anchor = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+/// let resolved = resolve_relative_with_options(anchor, "tomorrow", "UTC", &options).unwrap();
+/// let stamped = Stamped::with_options(resolved, &options);
+///
+/// assert!(stamped.provenance.is_current());
+/// assert_eq!(stamped.provenance, Provenance::for_options(&ResolveOptions::default()));
+/// let json = serde_json::to_value(&stamped).unwrap();
+/// assert_eq!(json["provenance"]["engine_version"], env!("CARGO_PKG_VERSION"));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Stamped<T> {
+    pub result: T,
+    pub provenance: Provenance,
+}
+
+impl<T> Stamped<T> {
+    /// `result`, computed without options.
+    pub fn new(result: T) -> Self {
+        Self {
+            result,
+            provenance: Provenance::current(),
+        }
+    }
+
+    /// `result`, computed with `options`. Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn with_options<O: Serialize + ?Sized>(result: T, options: &O) -> Self {
+        Self {
+            result,
+            provenance: Provenance::for_options(options),
+        }
+    }
+}
+
+/// A stable hash of `options`: FNV-1a (64-bit) over their JSON with object
+/// keys sorted, as `"fnv1a64:<16 hex digits>"`. Equal options hash equally
+/// on every platform and run; options types gaining a field change the
+/// hash, as they may change results. Requires the `serde` feature.
+///
+/// # Panics
+///
+/// If `options` cannot be serialized to JSON (a map with non-string keys);
+/// the engine's option types always can.
+#[cfg(feature = "serde")]
+pub fn options_hash<O: Serialize + ?Sized>(options: &O) -> String {
+    let value = serde_json::to_value(options).expect("options serialize");
+    let mut canonical = String::new();
+    write_canonical(&value, &mut canonical);
+    let hash = canonical
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+    format!("fnv1a64:{hash:016x}")
+}
+
+/// Compact JSON with object keys sorted at every level.
+#[cfg(feature = "serde")]
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by_key(|(key, _)| *key);
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
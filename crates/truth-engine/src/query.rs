@@ -0,0 +1,857 @@
+//! One query over a schedule, with its options stated once.
+//!
+//! The free functions each take the options they need in their own shape:
+//! [`find_free_slots`](crate::find_free_slots) a window,
+//! [`merge_business_availability`](crate::availability::merge_business_availability)
+//! a window and a business calendar, [`find_conflicts`](crate::find_conflicts)
+//! two event lists. A [`ScheduleQuery`] collects the events (one list or
+//! many named streams), the window, working hours, a buffer around events,
+//! and the privacy level, then answers conflicts, free slots, the first
+//! common slot, merged availability, where a conflicted event could move
+//! to, or where a batch of new meetings fits together, from the same
+//! settings. A [`RestRule`] keeps gaps around the meetings it proposes.
+
+use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::availability::{self, EventStream, PrivacyLevel, UnifiedAvailability};
+use crate::business::BusinessCalendar;
+use crate::error::{Result, TruthError};
+use crate::expander::ExpandedEvent;
+use crate::freebusy::FreeSlot;
+use crate::interval_tree::IntervalTree;
+use crate::temporal::interval::{self, TimeInterval};
+
+/// Stream id of the events added with [`ScheduleQuery::events`].
+pub const DEFAULT_STREAM: &str = "events";
+
+/// Two events in a [`ScheduleQuery`] that overlap or, with a buffer, sit
+/// closer together than it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ScheduleConflict {
+    /// Stream of `event_a`.
+    pub stream_a: String,
+    /// Stream of `event_b`; the same as `stream_a` for a clash within one
+    /// calendar.
+    pub stream_b: String,
+    /// The event added first.
+    pub event_a: ExpandedEvent,
+    pub event_b: ExpandedEvent,
+    /// Minutes the events themselves overlap; 0 when only the buffer is
+    /// breached.
+    pub overlap_minutes: i64,
+}
+
+/// A place a conflicted event could move to, from
+/// [`ScheduleQuery::reschedule`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RescheduleSuggestion {
+    pub start: DateTime<Utc>,
+    /// `start` plus the event's duration.
+    pub end: DateTime<Utc>,
+    /// Minutes from the event's current start; negative when earlier.
+    pub displacement_minutes: i64,
+}
+
+/// A meeting to place with [`ScheduleQuery::place_batch`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MeetingRequest {
+    pub id: String,
+    pub duration_minutes: u32,
+    /// Streams that attend; empty means every stream.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub attendees: Vec<String>,
+    /// The meeting starts at or after this instant.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub not_before: Option<DateTime<Utc>>,
+    /// The meeting ends at or before this instant.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub deadline: Option<DateTime<Utc>>,
+}
+
+/// Where [`ScheduleQuery::place_batch`] put a meeting.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PlacedMeeting {
+    pub meeting_id: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// Why a meeting was left out of a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum UnplacedMeetingReason {
+    /// Its attendees have no free time long enough between `not_before`
+    /// and `deadline`, even with the rest of the batch left out, or none
+    /// that keeps the query's [`RestRule`].
+    NoCommonTime,
+    /// It fits on its own, but every start clashes with a placed meeting
+    /// that shares an attendee, or would leave that attendee short of the
+    /// daily gap beside it.
+    ConflictsWithBatch,
+}
+
+/// A meeting left out of a batch.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct UnplacedMeeting {
+    pub meeting_id: String,
+    pub reason: UnplacedMeetingReason,
+    /// Placed meetings that take up at least one of its possible starts,
+    /// for [`ConflictsWithBatch`](UnplacedMeetingReason::ConflictsWithBatch).
+    pub blocked_by: Vec<String>,
+}
+
+/// The result of [`ScheduleQuery::place_batch`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BatchPlacement {
+    /// Placed meetings, sorted by start.
+    pub placed: Vec<PlacedMeeting>,
+    /// Meetings that did not fit, in request order.
+    pub unplaced: Vec<UnplacedMeeting>,
+}
+
+impl BatchPlacement {
+    /// Whether every requested meeting was placed.
+    pub fn is_complete(&self) -> bool {
+        self.unplaced.is_empty()
+    }
+}
+
+/// Rest an attendee keeps around proposed meetings, for
+/// [`ScheduleQuery::rest_rule`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RestRule {
+    /// Least free time, in minutes, between a proposed meeting and any other
+    /// meeting of one of its attendees.
+    pub min_gap_minutes: u32,
+    /// Least length, in minutes, of the longest free stretch each attendee
+    /// keeps on a day with a proposed meeting: within working hours when
+    /// the query has them, otherwise over the UTC day. 0 for no daily gap.
+    pub daily_gap_minutes: u32,
+}
+
+/// Search steps [`ScheduleQuery::place_batch`] takes before settling for
+/// the best placement found so far.
+const PLACEMENT_BUDGET: usize = 100_000;
+
+/// Events plus the options every question about them shares.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::business::BusinessCalendar;
+/// use truth_engine::{ExpandedEvent, ScheduleQuery};
+///
+/// let at = |h, m| Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap();
+/// let event = |s, e| ExpandedEvent::new(s, e);
+/// let query = ScheduleQuery::new()
+///     .stream("alice", &[event(at(9, 0), at(10, 0)), event(at(13, 0), at(14, 0))])
+///     .stream("bob", &[event(at(9, 55), at(11, 0))])
+///     .window(at(0, 0), at(23, 59))
+///     .working_hours(BusinessCalendar::default())
+///     .buffer_minutes(10);
+///
+/// // Alice's and Bob's morning meetings overlap by five minutes.
+/// let conflicts = query.conflicts();
+/// assert_eq!(conflicts.len(), 1);
+/// assert_eq!(conflicts[0].overlap_minutes, 5);
+///
+/// // An hour together: after Bob's meeting and its buffer, before Alice's.
+/// let slot = query.first_common_slot(60).unwrap().unwrap();
+/// assert_eq!((slot.start, slot.end), (at(11, 10), at(12, 50)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleQuery {
+    streams: Vec<EventStream>,
+    window: Option<TimeInterval>,
+    calendar: Option<BusinessCalendar>,
+    buffer: Duration,
+    rest: RestRule,
+    privacy: PrivacyLevel,
+}
+
+impl ScheduleQuery {
+    /// A query with no events, no window, no working hours, no buffer, and
+    /// opaque privacy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `events` to the stream [`DEFAULT_STREAM`], for questions about a
+    /// single calendar.
+    pub fn events(self, events: &[ExpandedEvent]) -> Self {
+        self.stream(DEFAULT_STREAM, events)
+    }
+
+    /// Add `events` to the stream `stream_id`, creating it if needed.
+    pub fn stream(mut self, stream_id: &str, events: &[ExpandedEvent]) -> Self {
+        match self.streams.iter_mut().find(|s| s.stream_id == stream_id) {
+            Some(stream) => stream.events.extend_from_slice(events),
+            None => self.streams.push(EventStream {
+                stream_id: stream_id.to_string(),
+                events: events.to_vec(),
+            }),
+        }
+        self
+    }
+
+    /// Add each of `streams`, as [`stream`](Self::stream) does.
+    pub fn streams(self, streams: &[EventStream]) -> Self {
+        streams
+            .iter()
+            .fold(self, |query, s| query.stream(&s.stream_id, &s.events))
+    }
+
+    /// Consider only `[start, end)`. Required for free-time queries;
+    /// conflicts without a window consider every event.
+    pub fn window(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.window = Some(TimeInterval { start, end });
+        self
+    }
+
+    /// Limit free time to working hours on `calendar`'s business days.
+    /// Conflicts are found at any hour.
+    pub fn working_hours(mut self, calendar: BusinessCalendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// Keep `minutes` clear around every event: free time stops that far
+    /// short of each event, and events closer together conflict.
+    pub fn buffer_minutes(mut self, minutes: u32) -> Self {
+        self.buffer = Duration::minutes(i64::from(minutes));
+        self
+    }
+
+    /// Keep `rule`'s rest around the meetings the query proposes, in
+    /// [`reschedule`](Self::reschedule) and
+    /// [`place_batch`](Self::place_batch): starts that would leave an
+    /// attendee less than the minimum gap between meetings, or no long
+    /// enough free stretch that day, are not proposed. Free-time queries
+    /// are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use truth_engine::business::BusinessCalendar;
+    /// use truth_engine::query::RestRule;
+    /// use truth_engine::{ExpandedEvent, ScheduleQuery};
+    ///
+    /// let at = |h, m| Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap();
+    /// let event = |s, e| ExpandedEvent::new(s, e);
+    /// // Busy 9:00–10:00 and 13:00–17:00, with an hour at 11:00 to move.
+    /// let sync = event(at(11, 0), at(12, 0));
+    /// let query = ScheduleQuery::new()
+    ///     .events(&[event(at(9, 0), at(10, 0)), sync.clone(), event(at(13, 0), at(17, 0))])
+    ///     .window(at(0, 0), at(23, 59))
+    ///     .working_hours(BusinessCalendar::default())
+    ///     .rest_rule(RestRule { min_gap_minutes: 0, daily_gap_minutes: 90 });
+    ///
+    /// // At 11:00 the day has no free 90 minutes; half an hour earlier
+    /// // keeps 11:30–13:00 free.
+    /// let moves = query.reschedule(&sync, 1).unwrap();
+    /// assert_eq!(moves[0].start, at(10, 30));
+    /// ```
+    pub fn rest_rule(mut self, rule: RestRule) -> Self {
+        self.rest = rule;
+        self
+    }
+
+    /// Report busy blocks with per-block source counts or without, for
+    /// [`availability`](Self::availability).
+    pub fn privacy(mut self, privacy: PrivacyLevel) -> Self {
+        self.privacy = privacy;
+        self
+    }
+
+    /// Every pair of events, across streams or within one, that overlap or
+    /// are less than the buffer apart, ordered by the first event and then
+    /// the second in the order they were added. With a window, only events
+    /// overlapping it are considered. Adjacent events do not conflict
+    /// without a buffer.
+    pub fn conflicts(&self) -> Vec<ScheduleConflict> {
+        let events: Vec<(&str, &ExpandedEvent)> = self
+            .streams
+            .iter()
+            .flat_map(|s| s.events.iter().map(move |e| (s.stream_id.as_str(), e)))
+            .filter(|(_, e)| {
+                self.window
+                    .is_none_or(|window| TimeInterval::from(*e).clamp_to(&window).is_some())
+            })
+            .collect();
+        // Each event with the buffer added after it: two such spans overlap
+        // exactly when the events are less than the buffer apart.
+        let padded = |e: &ExpandedEvent| TimeInterval {
+            start: e.start,
+            end: e.end + self.buffer,
+        };
+        let index: IntervalTree<usize> = events
+            .iter()
+            .enumerate()
+            .map(|(i, (_, e))| (padded(e), i))
+            .collect();
+
+        let mut conflicts = Vec::new();
+        for (i, &(stream_a, a)) in events.iter().enumerate() {
+            let a_span = padded(a);
+            let mut hits: Vec<usize> = index
+                .overlapping(&a_span)
+                .into_iter()
+                .filter(|&(b_span, &j)| j > i && a_span.intersect(b_span).is_some())
+                .map(|(_, &j)| j)
+                .collect();
+            hits.sort_unstable();
+            for j in hits {
+                let (stream_b, b) = events[j];
+                let overlap = TimeInterval::from(a).intersect(&TimeInterval::from(b));
+                conflicts.push(ScheduleConflict {
+                    stream_a: stream_a.to_string(),
+                    stream_b: stream_b.to_string(),
+                    event_a: a.clone(),
+                    event_b: b.clone(),
+                    overlap_minutes: overlap.map_or(0, |o| o.duration().num_minutes()),
+                });
+            }
+        }
+        conflicts
+    }
+
+    /// Free time in the window when no stream is busy, at least the buffer
+    /// away from every event and within working hours if set. Without a
+    /// buffer or working hours, the same as
+    /// [`find_free_slots`](crate::find_free_slots) over every event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidArguments`] if no window is set, or
+    /// [`TruthError::InvalidTimezone`] for an unknown working-hours timezone.
+    pub fn free_slots(&self) -> Result<Vec<FreeSlot>> {
+        self.free_slots_without(None)
+    }
+
+    /// Where `event` could move to, nearest first: for each free slot long
+    /// enough to hold it, the start in that slot closest to its current
+    /// start. Free time is computed as for [`free_slots`](Self::free_slots)
+    /// with `event` itself left out of every stream, so the buffer and
+    /// working hours hold at the new time. A suggestion with a displacement
+    /// of 0 means the event already fits where it is; equally distant
+    /// suggestions put the earlier one first. At most `limit` are returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidArguments`] if `event` ends before it
+    /// starts, and otherwise as for [`free_slots`](Self::free_slots).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use truth_engine::{ExpandedEvent, ScheduleQuery};
+    ///
+    /// let at = |h, m| Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap();
+    /// let event = |s, e| ExpandedEvent::new(s, e);
+    /// let sync = event(at(10, 0), at(11, 0));
+    /// let query = ScheduleQuery::new()
+    ///     .stream("alice", &[event(at(9, 0), at(10, 30)), sync.clone()])
+    ///     .stream("bob", &[event(at(11, 30), at(12, 0)), sync.clone()])
+    ///     .window(at(8, 0), at(13, 0));
+    ///
+    /// let moves = query.reschedule(&sync, 2).unwrap();
+    /// // Half an hour later fits between Alice's and Bob's meetings; two
+    /// // hours earlier is the next best.
+    /// assert_eq!((moves[0].start, moves[0].displacement_minutes), (at(10, 30), 30));
+    /// assert_eq!((moves[1].start, moves[1].displacement_minutes), (at(8, 0), -120));
+    /// ```
+    pub fn reschedule(
+        &self,
+        event: &ExpandedEvent,
+        limit: usize,
+    ) -> Result<Vec<RescheduleSuggestion>> {
+        let duration = event.end - event.start;
+        if duration < Duration::zero() {
+            return Err(TruthError::InvalidArguments(format!(
+                "event ends ({}) before it starts ({})",
+                event.end, event.start
+            )));
+        }
+        let who: Vec<&str> = self.streams.iter().map(|s| s.stream_id.as_str()).collect();
+        let mut suggestions = Vec::new();
+        for slot in self.free_time(None, Some(event), self.proposal_pad())? {
+            if slot.end - slot.start < duration {
+                continue;
+            }
+            let latest = slot.end - duration;
+            // The starts that keep the daily gap form runs bounded by the
+            // slot and by a gap's length from the ends of an attendee's
+            // free stretches, so the nearest one is among those points.
+            let mut points = vec![event.start.clamp(slot.start, latest)];
+            let gap = Duration::minutes(i64::from(self.rest.daily_gap_minutes));
+            if gap > Duration::zero() {
+                let around = TimeInterval {
+                    start: slot.start,
+                    end: slot.end,
+                };
+                for day in self.rest_days(&around)? {
+                    for stream in &who {
+                        for free in day.subtract_all(&self.stream_busy(stream, Some(event))) {
+                            points.push((free.start + gap).clamp(slot.start, latest));
+                            points.push((free.end - duration - gap).clamp(slot.start, latest));
+                        }
+                    }
+                }
+            }
+            points.sort_by_key(|p| ((*p - event.start).num_seconds().abs(), *p));
+            for start in points {
+                let span = TimeInterval {
+                    start,
+                    end: start + duration,
+                };
+                let days = self.rest_days(&span)?;
+                if self.keeps_daily_gap(&who, &span, &days, &[], Some(event)) {
+                    suggestions.push(RescheduleSuggestion {
+                        start,
+                        end: span.end,
+                        displacement_minutes: (start - event.start).num_minutes(),
+                    });
+                    break;
+                }
+            }
+        }
+        suggestions.sort_by_key(|s| (s.displacement_minutes.abs(), s.start));
+        suggestions.truncate(limit);
+        Ok(suggestions)
+    }
+
+    /// Place every meeting in `meetings` in the window, clear of the
+    /// streams' events and of each other, or as many as can be placed
+    /// together with reasons for the rest.
+    ///
+    /// A meeting starts on a multiple of `step_minutes` (counted from the
+    /// Unix epoch, so on the hour for a step that divides it) inside free
+    /// time its attendees share, computed as for
+    /// [`free_slots`](Self::free_slots), and keeps the buffer from every
+    /// other meeting with an attendee in common. Meetings without a shared
+    /// attendee may overlap. When no placement holds every meeting, the
+    /// one holding the most is returned, preferring meetings with fewer
+    /// possible starts, then earlier in `meetings`, then earlier starts;
+    /// a bounded search keeps large batches fast, and every meeting left
+    /// out clashes with the placement at each of its possible starts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidDuration`] for a meeting with a zero
+    /// duration or a zero `step_minutes`,
+    /// [`TruthError::InvalidArguments`] for an attendee that is not a
+    /// stream, and otherwise as for [`free_slots`](Self::free_slots).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use truth_engine::{ExpandedEvent, MeetingRequest, ScheduleQuery};
+    ///
+    /// let at = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+    /// let meeting = |id: &str, hours: u32| MeetingRequest {
+    ///     id: id.to_string(),
+    ///     duration_minutes: hours * 60,
+    ///     attendees: vec![],
+    ///     not_before: None,
+    ///     deadline: None,
+    /// };
+    /// let query = ScheduleQuery::new()
+    ///     .stream("alice", &[ExpandedEvent::new(at(10), at(11))])
+    ///     .window(at(9), at(13));
+    ///
+    /// // Only 11:00–13:00 holds the two-hour review, so the one-hour sync
+    /// // takes 9:00.
+    /// let batch = query
+    ///     .place_batch(&[meeting("sync", 1), meeting("review", 2)], 30)
+    ///     .unwrap();
+    /// assert!(batch.is_complete());
+    /// assert_eq!((batch.placed[0].meeting_id.as_str(), batch.placed[0].start), ("sync", at(9)));
+    /// assert_eq!((batch.placed[1].meeting_id.as_str(), batch.placed[1].start), ("review", at(11)));
+    /// ```
+    pub fn place_batch(
+        &self,
+        meetings: &[MeetingRequest],
+        step_minutes: u32,
+    ) -> Result<BatchPlacement> {
+        if step_minutes == 0 {
+            return Err(TruthError::InvalidDuration(
+                "placement step must be at least one minute".to_string(),
+            ));
+        }
+        let step = i64::from(step_minutes) * 60;
+        let mut candidates = Vec::with_capacity(meetings.len());
+        let mut rest_days = Vec::with_capacity(meetings.len());
+        let mut attendees = Vec::with_capacity(meetings.len());
+        for meeting in meetings {
+            if meeting.duration_minutes == 0 {
+                return Err(TruthError::InvalidDuration(format!(
+                    "meeting '{}' has a zero duration",
+                    meeting.id
+                )));
+            }
+            if let Some(unknown) = meeting
+                .attendees
+                .iter()
+                .find(|a| !self.streams.iter().any(|s| &s.stream_id == *a))
+            {
+                return Err(TruthError::InvalidArguments(format!(
+                    "meeting '{}' has attendee '{unknown}', which is not a stream",
+                    meeting.id
+                )));
+            }
+            let who: Vec<&str> = if meeting.attendees.is_empty() {
+                self.streams.iter().map(|s| s.stream_id.as_str()).collect()
+            } else {
+                meeting.attendees.iter().map(String::as_str).collect()
+            };
+            let length = Duration::minutes(i64::from(meeting.duration_minutes));
+            let bounds = TimeInterval {
+                start: meeting.not_before.unwrap_or(DateTime::<Utc>::MIN_UTC),
+                end: meeting.deadline.unwrap_or(DateTime::<Utc>::MAX_UTC),
+            };
+            let mut starts = Vec::new();
+            let mut days = Vec::new();
+            for slot in self.free_time(Some(&who), None, self.proposal_pad())? {
+                let Some(slot) = slot.intersect(&bounds) else {
+                    continue;
+                };
+                let first = slot.start.timestamp().div_euclid(step) * step;
+                let mut start = DateTime::from_timestamp(first, 0).unwrap_or(slot.start);
+                if start < slot.start {
+                    start += Duration::seconds(step);
+                }
+                while start + length <= slot.end {
+                    let span = TimeInterval {
+                        start,
+                        end: start + length,
+                    };
+                    let span_days = self.rest_days(&span)?;
+                    if self.keeps_daily_gap(&who, &span, &span_days, &[], None) {
+                        starts.push(span);
+                        days.push(span_days);
+                    }
+                    start += Duration::seconds(step);
+                }
+            }
+            candidates.push(starts);
+            rest_days.push(days);
+            attendees.push(who);
+        }
+
+        let pad = self.proposal_pad();
+        let shared = |a: usize, b: usize| attendees[a].iter().any(|who| attendees[b].contains(who));
+        let clash = |a: usize, a_span: &TimeInterval, b: usize, b_span: &TimeInterval| {
+            shared(a, b) && a_span.start < b_span.end + pad && b_span.start < a_span.end + pad
+        };
+        // Whether meeting `i` can take candidate `c` beside the `chosen` ones.
+        let fits = |i: usize, c: usize, chosen: &[Option<usize>]| {
+            let span = &candidates[i][c];
+            let others: Vec<(&[&str], TimeInterval)> = chosen
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .filter_map(|(j, o)| Some((attendees[j].as_slice(), candidates[j][(*o)?])))
+                .collect();
+            chosen
+                .iter()
+                .enumerate()
+                .all(|(j, o)| j == i || o.is_none_or(|o| !clash(i, span, j, &candidates[j][o])))
+                && self.keeps_daily_gap(&attendees[i], span, &rest_days[i][c], &others, None)
+        };
+        let mut order: Vec<usize> = (0..meetings.len()).collect();
+        order.sort_by_key(|&i| (candidates[i].len(), i));
+        let mut search = BatchSearch {
+            order: &order,
+            candidates: &candidates,
+            fits: &fits,
+            chosen: vec![None; meetings.len()],
+            best: vec![None; meetings.len()],
+            best_count: 0,
+            budget: PLACEMENT_BUDGET,
+        };
+        search.run(0, 0);
+        let mut chosen = search.best;
+        // A cut-short search can leave room: fill it, in search order.
+        for &i in &order {
+            if chosen[i].is_some() {
+                continue;
+            }
+            chosen[i] = (0..candidates[i].len()).find(|&c| fits(i, c, &chosen));
+        }
+
+        let mut placed: Vec<PlacedMeeting> = chosen
+            .iter()
+            .enumerate()
+            .filter_map(|(i, c)| {
+                let span = candidates[i][(*c)?];
+                Some(PlacedMeeting {
+                    meeting_id: meetings[i].id.clone(),
+                    start: span.start,
+                    end: span.end,
+                })
+            })
+            .collect();
+        placed.sort_by(|a, b| (a.start, &a.meeting_id).cmp(&(b.start, &b.meeting_id)));
+        let unplaced = (0..meetings.len())
+            .filter(|&i| chosen[i].is_none())
+            .map(|i| {
+                let blocked_by: Vec<String> = (0..meetings.len())
+                    .filter(|&j| {
+                        chosen[j].is_some_and(|o| {
+                            let other = &candidates[j][o];
+                            candidates[i].iter().zip(&rest_days[i]).any(|(span, days)| {
+                                clash(i, span, j, other)
+                                    || (shared(i, j) && days.iter().any(|d| d.overlaps(other)))
+                            })
+                        })
+                    })
+                    .map(|j| meetings[j].id.clone())
+                    .collect();
+                UnplacedMeeting {
+                    meeting_id: meetings[i].id.clone(),
+                    reason: if candidates[i].is_empty() {
+                        UnplacedMeetingReason::NoCommonTime
+                    } else {
+                        UnplacedMeetingReason::ConflictsWithBatch
+                    },
+                    blocked_by,
+                }
+            })
+            .collect();
+        Ok(BatchPlacement { placed, unplaced })
+    }
+
+    /// [`free_slots`](Self::free_slots), ignoring every copy of `skip`.
+    fn free_slots_without(&self, skip: Option<&ExpandedEvent>) -> Result<Vec<FreeSlot>> {
+        Ok(self
+            .free_time(None, skip, self.buffer)?
+            .into_iter()
+            .map(|free| FreeSlot {
+                start: free.start,
+                end: free.end,
+                duration_minutes: free.duration().num_minutes(),
+            })
+            .collect())
+    }
+
+    /// Free time for the streams in `only` (every stream when `None`),
+    /// ignoring every copy of `skip`, at least `pad` from every event.
+    fn free_time(
+        &self,
+        only: Option<&[&str]>,
+        skip: Option<&ExpandedEvent>,
+        pad: Duration,
+    ) -> Result<Vec<TimeInterval>> {
+        let window = self.require_window()?;
+        let streams = self
+            .streams
+            .iter()
+            .filter(|s| only.is_none_or(|only| only.contains(&s.stream_id.as_str())));
+        let busy = interval::merge(streams.flat_map(|s| {
+            s.events
+                .iter()
+                .filter(|e| Some(*e) != skip)
+                .filter_map(move |e| {
+                    TimeInterval {
+                        start: e.start - pad,
+                        end: e.end + pad,
+                    }
+                    .clamp_to(&window)
+                })
+        }));
+        let Some(calendar) = &self.calendar else {
+            return Ok(window.subtract_all(&busy));
+        };
+        Ok(calendar
+            .working_intervals(window.start, window.end)?
+            .iter()
+            .flat_map(|working| working.subtract_all(&busy))
+            .collect())
+    }
+
+    /// The earliest [free slot](Self::free_slots) of at least
+    /// `duration_minutes`, when every stream can meet.
+    ///
+    /// # Errors
+    ///
+    /// As for [`free_slots`](Self::free_slots).
+    pub fn first_common_slot(&self, duration_minutes: i64) -> Result<Option<FreeSlot>> {
+        Ok(self
+            .free_slots()?
+            .into_iter()
+            .find(|slot| slot.duration_minutes >= duration_minutes))
+    }
+
+    /// [`merge_availability`](crate::merge_availability) over the streams
+    /// at the query's privacy level, with the query's
+    /// [free slots](Self::free_slots). Busy blocks are the events
+    /// themselves, without buffers and at any hour.
+    ///
+    /// # Errors
+    ///
+    /// As for [`free_slots`](Self::free_slots).
+    pub fn availability(&self) -> Result<UnifiedAvailability> {
+        let window = self.require_window()?;
+        let mut merged =
+            availability::merge_availability(&self.streams, window.start, window.end, self.privacy);
+        merged.free = self.free_slots()?;
+        Ok(merged)
+    }
+
+    /// The clearance kept around proposed meetings: the buffer or the rest
+    /// rule's minimum gap, whichever is longer.
+    fn proposal_pad(&self) -> Duration {
+        self.buffer
+            .max(Duration::minutes(i64::from(self.rest.min_gap_minutes)))
+    }
+
+    /// The events of stream `stream_id`, less every copy of `skip`.
+    fn stream_busy(&self, stream_id: &str, skip: Option<&ExpandedEvent>) -> Vec<TimeInterval> {
+        self.streams
+            .iter()
+            .filter(|s| s.stream_id == stream_id)
+            .flat_map(|s| &s.events)
+            .filter(|e| Some(*e) != skip)
+            .map(TimeInterval::from)
+            .collect()
+    }
+
+    /// The days `span` touches, over which the rest rule's daily gap is
+    /// measured: working intervals with working hours, otherwise UTC days.
+    /// Empty without a daily gap.
+    fn rest_days(&self, span: &TimeInterval) -> Result<Vec<TimeInterval>> {
+        if self.rest.daily_gap_minutes == 0 {
+            return Ok(Vec::new());
+        }
+        let touches = |day: &TimeInterval| day.start < span.end && span.start < day.end;
+        if let Some(calendar) = &self.calendar {
+            let around = Duration::days(1);
+            return Ok(calendar
+                .working_intervals(span.start - around, span.end + around)?
+                .into_iter()
+                .filter(touches)
+                .collect());
+        }
+        let mut days = Vec::new();
+        let mut date = span.start.date_naive();
+        loop {
+            let start = date.and_time(chrono::NaiveTime::MIN).and_utc();
+            let Some(next) = date.succ_opt() else { break };
+            let day = TimeInterval {
+                start,
+                end: next.and_time(chrono::NaiveTime::MIN).and_utc(),
+            };
+            if day.start >= span.end && !days.is_empty() {
+                break;
+            }
+            days.push(day);
+            date = next;
+        }
+        Ok(days)
+    }
+
+    /// Whether every stream in `who` keeps the rest rule's daily gap on each
+    /// of `days` with `span` and the `others` it attends (attendees and
+    /// spans of other proposed meetings) added to its events, less `skip`.
+    fn keeps_daily_gap(
+        &self,
+        who: &[&str],
+        span: &TimeInterval,
+        days: &[TimeInterval],
+        others: &[(&[&str], TimeInterval)],
+        skip: Option<&ExpandedEvent>,
+    ) -> bool {
+        let gap = Duration::minutes(i64::from(self.rest.daily_gap_minutes));
+        if gap.is_zero() {
+            return true;
+        }
+        who.iter().all(|stream| {
+            let mut busy = self.stream_busy(stream, skip);
+            busy.push(*span);
+            busy.extend(
+                others
+                    .iter()
+                    .filter(|(attendees, _)| attendees.contains(stream))
+                    .map(|(_, other)| *other),
+            );
+            days.iter().all(|day| {
+                day.subtract_all(&busy)
+                    .iter()
+                    .any(|free| free.duration() >= gap)
+            })
+        })
+    }
+
+    fn require_window(&self) -> Result<TimeInterval> {
+        self.window.ok_or_else(|| {
+            TruthError::InvalidArguments("the query needs a window for free time".to_string())
+        })
+    }
+}
+
+/// Branch-and-bound over one start per meeting, or none, for the placement
+/// that holds the most meetings.
+struct BatchSearch<'a, F> {
+    /// Meetings in the order they are decided.
+    order: &'a [usize],
+    candidates: &'a [Vec<TimeInterval>],
+    /// Whether a meeting can take a candidate beside the chosen ones.
+    fits: &'a F,
+    /// The candidate index chosen for each meeting on the current path.
+    chosen: Vec<Option<usize>>,
+    best: Vec<Option<usize>>,
+    best_count: usize,
+    budget: usize,
+}
+
+impl<F> BatchSearch<'_, F>
+where
+    F: Fn(usize, usize, &[Option<usize>]) -> bool,
+{
+    /// Decide `order[depth..]` with `placed` meetings placed so far.
+    fn run(&mut self, depth: usize, placed: usize) {
+        if self.budget == 0 || placed + (self.order.len() - depth) <= self.best_count {
+            return;
+        }
+        self.budget -= 1;
+        let Some(&i) = self.order.get(depth) else {
+            self.best.clone_from(&self.chosen);
+            self.best_count = placed;
+            return;
+        };
+        for c in 0..self.candidates[i].len() {
+            if !(self.fits)(i, c, &self.chosen) {
+                continue;
+            }
+            self.chosen[i] = Some(c);
+            self.run(depth + 1, placed + 1);
+            self.chosen[i] = None;
+            if self.best_count == self.order.len() || self.budget == 0 {
+                return;
+            }
+        }
+        self.run(depth + 1, placed);
+    }
+}
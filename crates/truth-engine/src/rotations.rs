@@ -0,0 +1,343 @@
+//! On-call rotations and shift schedules.
+//!
+//! A [`Rotation`] is a repeating [`RotationPattern`] — N people taking turns
+//! for a fixed number of days, or regional teams handing off each day
+//! follow-the-sun — plus [`Override`]s for swaps and cover. It answers "who
+//! is on call at T?" ([`Rotation::on_call_at`]) and "what are the next
+//! eight weeks of shifts?" ([`Rotation::shifts`]).
+//!
+//! Handoffs are local wall-clock times in each pattern's timezone, so a
+//! 09:00 handoff stays at 09:00 across DST changes and the shift spanning
+//! the change is an hour shorter or longer.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, TruthError};
+use crate::temporal::interval::TimeInterval;
+use crate::temporal::{local_to_utc, parse_timezone};
+
+/// How the base schedule repeats.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum RotationPattern {
+    /// Participants take turns in order, each for `length_days` days,
+    /// handing off at `handoff` local time. The first shift starts on
+    /// `start_date`.
+    Cycle {
+        participants: Vec<String>,
+        length_days: u32,
+        start_date: NaiveDate,
+        handoff: NaiveTime,
+        timezone: String,
+    },
+    /// Every day each region takes over at its local `start` and covers
+    /// until the next region's start.
+    FollowTheSun { regions: Vec<Region> },
+}
+
+/// One team in a follow-the-sun rotation.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Region {
+    pub participant: String,
+    pub timezone: String,
+    /// Local time the region takes over each day.
+    pub start: NaiveTime,
+}
+
+/// Someone covering `[start, end)` instead of whoever the pattern names.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Override {
+    pub participant: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// A stretch of time one participant is on call.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Shift {
+    pub participant: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Whether this stretch comes from an [`Override`] rather than the pattern.
+    pub is_override: bool,
+}
+
+/// A rotation pattern with its overrides.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+/// use truth_engine::rotations::{Rotation, RotationPattern};
+///
+/// let rotation = Rotation::new(RotationPattern::Cycle {
+///     participants: vec!["ana".into(), "ben".into(), "cy".into()],
+///     length_days: 7,
+///     start_date: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+///     handoff: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+///     timezone: "America/New_York".into(),
+/// })
+/// .unwrap();
+///
+/// // Week two is Ben's; the Monday 09:00 handoff moved to 13:00 UTC with DST.
+/// let t = Utc.with_ymd_and_hms(2026, 3, 12, 0, 0, 0).unwrap();
+/// assert_eq!(rotation.on_call_at(t).unwrap().as_deref(), Some("ben"));
+///
+/// let weeks = rotation
+///     .shifts(t, t + chrono::Duration::weeks(8))
+///     .unwrap();
+/// assert_eq!(weeks.len(), 9);
+/// assert_eq!(weeks[1].start, Utc.with_ymd_and_hms(2026, 3, 16, 13, 0, 0).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Rotation {
+    pub pattern: RotationPattern,
+    /// Applied in order; a later override wins where two overlap.
+    pub overrides: Vec<Override>,
+}
+
+impl Rotation {
+    /// A rotation with no overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidRotation`] for a pattern with no
+    /// participants or regions or a zero `length_days`, and
+    /// [`TruthError::InvalidTimezone`] for an unknown timezone.
+    pub fn new(pattern: RotationPattern) -> Result<Self> {
+        match &pattern {
+            RotationPattern::Cycle {
+                participants,
+                length_days,
+                timezone,
+                ..
+            } => {
+                if participants.is_empty() {
+                    return Err(invalid("a cycle needs at least one participant"));
+                }
+                if *length_days == 0 {
+                    return Err(invalid("shifts must last at least one day"));
+                }
+                parse_timezone(timezone)?;
+            }
+            RotationPattern::FollowTheSun { regions } => {
+                if regions.is_empty() {
+                    return Err(invalid("follow-the-sun needs at least one region"));
+                }
+                for region in regions {
+                    parse_timezone(&region.timezone)?;
+                }
+            }
+        }
+        Ok(Self {
+            pattern,
+            overrides: Vec::new(),
+        })
+    }
+
+    /// Add an override.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidRotation`] if it does not end after it starts.
+    pub fn add_override(&mut self, over: Override) -> Result<()> {
+        if over.end <= over.start {
+            return Err(invalid("an override must end after it starts"));
+        }
+        self.overrides.push(over);
+        Ok(())
+    }
+
+    /// Swap the shifts in effect at `a` and `b`: each person covers the
+    /// other's shift, recorded as two overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidRotation`] if no one is on call at `a` or `b`.
+    pub fn swap(&mut self, a: DateTime<Utc>, b: DateTime<Utc>) -> Result<()> {
+        let first = self.shift_at(a)?;
+        let second = self.shift_at(b)?;
+        self.overrides.push(Override {
+            participant: second.participant.clone(),
+            start: first.start,
+            end: first.end,
+        });
+        self.overrides.push(Override {
+            participant: first.participant,
+            start: second.start,
+            end: second.end,
+        });
+        Ok(())
+    }
+
+    /// Who is on call at `at`, or `None` before the rotation starts.
+    ///
+    /// # Errors
+    ///
+    /// As for [`Rotation::new`], if the pattern was changed since.
+    pub fn on_call_at(&self, at: DateTime<Utc>) -> Result<Option<String>> {
+        Ok(self
+            .shifts(at, at + Duration::seconds(1))?
+            .into_iter()
+            .next()
+            .map(|s| s.participant))
+    }
+
+    /// Every shift overlapping `[window_start, window_end)`, sorted by
+    /// start, with overrides applied. Shifts are not clipped to the window;
+    /// an override splits the shift it falls in.
+    ///
+    /// # Errors
+    ///
+    /// As for [`Rotation::new`], if the pattern was changed since.
+    pub fn shifts(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> Result<Vec<Shift>> {
+        if window_start >= window_end {
+            return Ok(Vec::new());
+        }
+        let window = TimeInterval {
+            start: window_start,
+            end: window_end,
+        };
+        // Overrides can reach into the window from base shifts outside it,
+        // so widen the base schedule to cover every relevant override.
+        let (mut from, mut to) = (window_start, window_end);
+        for over in &self.overrides {
+            if over.start < window_end && over.end > window_start {
+                from = from.min(over.start);
+                to = to.max(over.end);
+            }
+        }
+        let mut shifts = self.base_shifts(from, to)?;
+        for over in &self.overrides {
+            let span = TimeInterval {
+                start: over.start,
+                end: over.end,
+            };
+            let mut next = Vec::with_capacity(shifts.len() + 2);
+            for shift in shifts {
+                let own = TimeInterval {
+                    start: shift.start,
+                    end: shift.end,
+                };
+                for piece in own.subtract(&span) {
+                    next.push(Shift {
+                        start: piece.start,
+                        end: piece.end,
+                        ..shift.clone()
+                    });
+                }
+            }
+            next.push(Shift {
+                participant: over.participant.clone(),
+                start: over.start,
+                end: over.end,
+                is_override: true,
+            });
+            shifts = next;
+        }
+        shifts.retain(|s| {
+            TimeInterval {
+                start: s.start,
+                end: s.end,
+            }
+            .overlaps(&window)
+        });
+        shifts.sort_by_key(|s| s.start);
+        Ok(shifts)
+    }
+
+    fn shift_at(&self, at: DateTime<Utc>) -> Result<Shift> {
+        self.shifts(at, at + Duration::seconds(1))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| invalid(&format!("no one is on call at {}", at.to_rfc3339())))
+    }
+
+    /// Pattern shifts overlapping `[from, to)`.
+    fn base_shifts(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<Shift>> {
+        let starts = match &self.pattern {
+            RotationPattern::Cycle {
+                participants,
+                length_days,
+                start_date,
+                handoff,
+                timezone,
+            } => {
+                let tz = parse_timezone(timezone)?;
+                let length = *length_days as i64;
+                let handoff_at = |k: i64| {
+                    local_to_utc(
+                        &tz,
+                        (*start_date + Duration::days(k * length)).and_time(*handoff),
+                    )
+                };
+                // The shift containing `from` begins at most one cycle before
+                // the one counted from its local date.
+                let days = (from.with_timezone(&tz).date_naive() - *start_date).num_days();
+                let mut k = (days.div_euclid(length) - 1).max(0);
+                let mut starts = Vec::new();
+                while let Some(start) = handoff_at(k) {
+                    let person = &participants[(k as usize) % participants.len()];
+                    starts.push((start, person.clone()));
+                    if start >= to {
+                        break;
+                    }
+                    k += 1;
+                }
+                starts
+            }
+            RotationPattern::FollowTheSun { regions } => {
+                let zones = regions
+                    .iter()
+                    .map(|r| parse_timezone(&r.timezone))
+                    .collect::<Result<Vec<Tz>>>()?;
+                let mut starts = Vec::new();
+                let mut date = from.date_naive() - Duration::days(2);
+                while date <= to.date_naive() + Duration::days(1) {
+                    for (region, tz) in regions.iter().zip(&zones) {
+                        if let Some(start) = local_to_utc(tz, date.and_time(region.start)) {
+                            starts.push((start, region.participant.clone()));
+                        }
+                    }
+                    date += Duration::days(1);
+                }
+                starts.sort_by_key(|(start, _)| *start);
+                // Two regions starting at the same instant: the later one listed wins.
+                starts.reverse();
+                starts.dedup_by_key(|(start, _)| *start);
+                starts.reverse();
+                starts
+            }
+        };
+        Ok(starts
+            .windows(2)
+            .filter(|w| w[0].0 < to && w[1].0 > from)
+            .map(|w| Shift {
+                participant: w[0].1.clone(),
+                start: w[0].0,
+                end: w[1].0,
+                is_override: false,
+            })
+            .collect())
+    }
+}
+
+fn invalid(message: &str) -> TruthError {
+    TruthError::InvalidRotation(message.to_string())
+}
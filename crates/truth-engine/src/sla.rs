@@ -0,0 +1,146 @@
+//! Service-level deadlines measured in working time.
+//!
+//! An SLA such as "respond within 8 business hours" only runs while the
+//! [`BusinessCalendar`] is open, and stops while the ticket waits on the
+//! customer. [`compute_deadline`] finds the instant the allowance runs out;
+//! [`remaining`] says how much of it is left at a given moment (negative
+//! once breached). Both skip nights, weekends, holidays, closures, and
+//! [`Pause`]s.
+
+use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::business::BusinessCalendar;
+use crate::error::{Result, TruthError};
+use crate::temporal::interval::TimeInterval;
+
+/// How far ahead [`compute_deadline`] looks before giving up.
+const HORIZON_DAYS: i64 = 3660;
+
+/// A stretch during which the SLA clock is stopped, such as waiting on the
+/// customer.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Pause {
+    pub start: DateTime<Utc>,
+    /// When the clock resumed; `None` if it is still stopped.
+    pub end: Option<DateTime<Utc>>,
+}
+
+/// The instant `sla` of working time after `start` runs out, not counting
+/// time inside `pauses`.
+///
+/// A deadline that lands exactly at the end of a working day is that end,
+/// not the next morning. Returns `None` while an open pause stops the clock
+/// before the allowance is used, or if the calendar has no working time in
+/// the next ten years.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDuration`] for a negative `sla`, or
+/// [`TruthError::InvalidTimezone`] for an unknown calendar timezone.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use truth_engine::business::BusinessCalendar;
+/// use truth_engine::sla::{compute_deadline, Pause};
+///
+/// let at = |d, h| Utc.with_ymd_and_hms(2026, 3, d, h, 0, 0).unwrap();
+/// let calendar = BusinessCalendar::default(); // 09:00–17:00 UTC, Monday to Friday
+///
+/// // Opened Friday 15:00 with 4 business hours: 2 on Friday, 2 on Monday.
+/// let deadline = compute_deadline(at(6, 15), Duration::hours(4), &calendar, &[]).unwrap();
+/// assert_eq!(deadline, Some(at(9, 11)));
+///
+/// // Waiting on the customer Monday 09:00–10:00 pushes it back an hour.
+/// let pause = Pause { start: at(9, 9), end: Some(at(9, 10)) };
+/// let deadline = compute_deadline(at(6, 15), Duration::hours(4), &calendar, &[pause]).unwrap();
+/// assert_eq!(deadline, Some(at(9, 12)));
+/// ```
+pub fn compute_deadline(
+    start: DateTime<Utc>,
+    sla: Duration,
+    calendar: &BusinessCalendar,
+    pauses: &[Pause],
+) -> Result<Option<DateTime<Utc>>> {
+    if sla < Duration::zero() {
+        return Err(TruthError::InvalidDuration(
+            "an SLA cannot be negative".to_string(),
+        ));
+    }
+    if sla == Duration::zero() {
+        return Ok(Some(start));
+    }
+    // Nothing counts after an open pause begins.
+    let stopped = pauses
+        .iter()
+        .filter(|p| p.end.is_none())
+        .map(|p| p.start.max(start))
+        .min();
+    let horizon = start + Duration::days(HORIZON_DAYS);
+    let limit = stopped.map_or(horizon, |s| s.min(horizon));
+
+    let mut left = sla;
+    let mut from = start;
+    while from < limit {
+        let to = (from + Duration::days(31)).min(limit);
+        for interval in counted(calendar, pauses, from, to)? {
+            if left <= interval.duration() {
+                return Ok(Some(interval.start + left));
+            }
+            left -= interval.duration();
+        }
+        from = to;
+    }
+    Ok(None)
+}
+
+/// How much of `sla` is left at `now` for work that started at `start`:
+/// the allowance minus the working time in `[start, now)` outside `pauses`.
+/// Negative once the deadline has passed.
+///
+/// # Errors
+///
+/// As for [`compute_deadline`].
+pub fn remaining(
+    now: DateTime<Utc>,
+    start: DateTime<Utc>,
+    sla: Duration,
+    calendar: &BusinessCalendar,
+    pauses: &[Pause],
+) -> Result<Duration> {
+    if sla < Duration::zero() {
+        return Err(TruthError::InvalidDuration(
+            "an SLA cannot be negative".to_string(),
+        ));
+    }
+    let elapsed = counted(calendar, pauses, start, now)?
+        .iter()
+        .fold(Duration::zero(), |sum, i| sum + i.duration());
+    Ok(sla - elapsed)
+}
+
+/// Working time in `[from, to)` outside every pause, sorted by start.
+fn counted(
+    calendar: &BusinessCalendar,
+    pauses: &[Pause],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<TimeInterval>> {
+    let stopped: Vec<TimeInterval> = pauses
+        .iter()
+        .map(|p| TimeInterval {
+            start: p.start,
+            end: p.end.unwrap_or(to.max(p.start)),
+        })
+        .collect();
+    Ok(calendar
+        .working_intervals(from, to)?
+        .iter()
+        .flat_map(|working| working.subtract_all(&stopped))
+        .collect())
+}
@@ -0,0 +1,348 @@
+//! An in-memory, indexed collection of events and recurring series.
+//!
+//! [`EventStore`] keeps one-off events in a start-time index and recurring
+//! series as rules, expanding only the part of a series that a query window
+//! touches. Range queries feed straight into the conflict, free/busy, and
+//! availability functions, so callers stop rebuilding `Vec<ExpandedEvent>`
+//! inputs by hand on every call.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::availability::{merge_availability, EventStream, PrivacyLevel, UnifiedAvailability};
+use crate::error::{Result, TruthError};
+use crate::expander::{expand_rrule_between, ExpandedEvent};
+use crate::freebusy::{find_free_slots, FreeSlot};
+
+/// When a stored event happens.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Schedule {
+    /// A single occurrence.
+    Once {
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+    /// A recurring series, as given to
+    /// [`expand_rrule_with_exdates`](crate::expand_rrule_with_exdates).
+    Recurring {
+        rrule: String,
+        /// Local start of the first occurrence in `timezone`.
+        dtstart: NaiveDateTime,
+        duration_minutes: u32,
+        timezone: String,
+        /// Local starts of skipped occurrences.
+        exdates: Vec<NaiveDateTime>,
+    },
+}
+
+/// An event in the store.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StoredEvent {
+    /// The calendar the event belongs to, used to group query results into
+    /// [`EventStream`]s.
+    pub stream_id: String,
+    pub schedule: Schedule,
+}
+
+/// One occurrence returned by a range query.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StoredOccurrence {
+    /// The id the event was inserted under.
+    pub id: String,
+    pub stream_id: String,
+    pub occurrence: ExpandedEvent,
+}
+
+/// Events and series indexed for range queries.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use truth_engine::store::{EventStore, Schedule, StoredEvent};
+///
+/// let mut store = EventStore::new();
+/// store
+///     .insert(
+///         "standup",
+///         StoredEvent {
+///             stream_id: "work".to_string(),
+///             schedule: Schedule::Recurring {
+///                 rrule: "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR".to_string(),
+///                 dtstart: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(9, 0, 0).unwrap(),
+///                 duration_minutes: 15,
+///                 timezone: "America/New_York".to_string(),
+///                 exdates: vec![],
+///             },
+///         },
+///     )
+///     .unwrap();
+///
+/// // The week of March 2, 2026: five standups, two years into the series.
+/// let week = store
+///     .occurrences(
+///         Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap(),
+///     )
+///     .unwrap();
+/// assert_eq!(week.len(), 5);
+/// assert_eq!(week[0].occurrence.start, Utc.with_ymd_and_hms(2026, 3, 2, 14, 0, 0).unwrap());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EventStore {
+    events: BTreeMap<String, StoredEvent>,
+    /// One-off events by (start, id).
+    by_start: BTreeSet<(DateTime<Utc>, String)>,
+    /// Ids of recurring series.
+    series: BTreeSet<String>,
+    /// Upper bound on one-off event length, so a window query knows how far
+    /// back in `by_start` an overlapping event can begin. Never shrinks.
+    longest: Duration,
+}
+
+impl EventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert `event` under `id`, replacing and returning any event already
+    /// stored there.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidDatetime`] if a one-off event ends before
+    /// it starts, or the errors of
+    /// [`expand_rrule_with_exdates`](crate::expand_rrule_with_exdates) for an
+    /// invalid series. The store is unchanged on error.
+    pub fn insert(&mut self, id: &str, event: StoredEvent) -> Result<Option<StoredEvent>> {
+        match &event.schedule {
+            Schedule::Once { start, end } if end < start => {
+                return Err(TruthError::InvalidDatetime(format!(
+                    "event '{}' ends before it starts",
+                    id
+                )));
+            }
+            Schedule::Once { .. } => {}
+            recurring @ Schedule::Recurring { dtstart, .. } => {
+                // Expanding the first occurrence checks the rule and timezone.
+                let first = dtstart.and_utc();
+                expand(
+                    recurring,
+                    first - Duration::days(2),
+                    first + Duration::days(2),
+                )?;
+            }
+        }
+        let previous = self.remove(id);
+        match &event.schedule {
+            Schedule::Once { start, end } => {
+                self.by_start.insert((*start, id.to_string()));
+                self.longest = self.longest.max(*end - *start);
+            }
+            Schedule::Recurring { .. } => {
+                self.series.insert(id.to_string());
+            }
+        }
+        self.events.insert(id.to_string(), event);
+        Ok(previous)
+    }
+
+    /// Remove and return the event stored under `id`.
+    pub fn remove(&mut self, id: &str) -> Option<StoredEvent> {
+        let event = self.events.remove(id)?;
+        match &event.schedule {
+            Schedule::Once { start, .. } => {
+                self.by_start.remove(&(*start, id.to_string()));
+            }
+            Schedule::Recurring { .. } => {
+                self.series.remove(id);
+            }
+        }
+        Some(event)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&StoredEvent> {
+        self.events.get(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Every occurrence overlapping `[window_start, window_end)`, sorted by
+    /// start, then end, then id.
+    ///
+    /// # Errors
+    ///
+    /// As for [`insert`](Self::insert); stored series were validated there,
+    /// so errors are not expected in practice.
+    pub fn occurrences(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> Result<Vec<StoredOccurrence>> {
+        if window_start >= window_end {
+            return Ok(Vec::new());
+        }
+        let mut out = Vec::new();
+        let earliest = (window_start - self.longest, String::new());
+        for (start, id) in self.by_start.range(earliest..) {
+            if *start >= window_end {
+                break;
+            }
+            let event = &self.events[id];
+            if let Schedule::Once { start, end } = event.schedule {
+                // Zero-length events count when they sit inside the window.
+                if end > window_start || (start == end && start >= window_start) {
+                    out.push(occurrence(id, event, ExpandedEvent::new(start, end)));
+                }
+            }
+        }
+        for id in &self.series {
+            let event = &self.events[id];
+            for o in expand(&event.schedule, window_start, window_end)? {
+                out.push(occurrence(id, event, o));
+            }
+        }
+        out.sort_by(|a, b| {
+            (a.occurrence.start, a.occurrence.end, &a.id).cmp(&(
+                b.occurrence.start,
+                b.occurrence.end,
+                &b.id,
+            ))
+        });
+        Ok(out)
+    }
+
+    /// The window's occurrences grouped into one [`EventStream`] per
+    /// `stream_id`, in id order, for
+    /// [`merge_availability`](crate::merge_availability).
+    ///
+    /// # Errors
+    ///
+    /// As for [`occurrences`](Self::occurrences).
+    pub fn streams(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> Result<Vec<EventStream>> {
+        let mut streams: BTreeMap<&str, Vec<ExpandedEvent>> = BTreeMap::new();
+        let occurrences = self.occurrences(window_start, window_end)?;
+        for o in &occurrences {
+            streams
+                .entry(o.stream_id.as_str())
+                .or_default()
+                .push(o.occurrence.clone());
+        }
+        Ok(streams
+            .into_iter()
+            .map(|(stream_id, events)| EventStream {
+                stream_id: stream_id.to_string(),
+                events,
+            })
+            .collect())
+    }
+
+    /// Occurrences that overlap `candidate`, e.g. to check a proposed
+    /// meeting before booking it. Adjacent occurrences do not conflict.
+    ///
+    /// # Errors
+    ///
+    /// As for [`occurrences`](Self::occurrences).
+    pub fn conflicts_with(&self, candidate: &ExpandedEvent) -> Result<Vec<StoredOccurrence>> {
+        Ok(self
+            .occurrences(candidate.start, candidate.end)?
+            .into_iter()
+            .filter(|o| o.occurrence.start < candidate.end && o.occurrence.end > candidate.start)
+            .collect())
+    }
+
+    /// Free slots in the window across every stored event.
+    ///
+    /// # Errors
+    ///
+    /// As for [`occurrences`](Self::occurrences).
+    pub fn free_slots(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> Result<Vec<FreeSlot>> {
+        let events: Vec<ExpandedEvent> = self
+            .occurrences(window_start, window_end)?
+            .into_iter()
+            .map(|o| o.occurrence)
+            .collect();
+        Ok(find_free_slots(&events, window_start, window_end))
+    }
+
+    /// [`merge_availability`](crate::merge_availability) over the window's streams.
+    ///
+    /// # Errors
+    ///
+    /// As for [`occurrences`](Self::occurrences).
+    pub fn availability(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+        privacy: PrivacyLevel,
+    ) -> Result<UnifiedAvailability> {
+        let streams = self.streams(window_start, window_end)?;
+        Ok(merge_availability(
+            &streams,
+            window_start,
+            window_end,
+            privacy,
+        ))
+    }
+}
+
+fn occurrence(id: &str, event: &StoredEvent, occurrence: ExpandedEvent) -> StoredOccurrence {
+    StoredOccurrence {
+        id: id.to_string(),
+        stream_id: event.stream_id.clone(),
+        occurrence,
+    }
+}
+
+/// A series' occurrences overlapping the window.
+fn expand(
+    schedule: &Schedule,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Result<Vec<ExpandedEvent>> {
+    let Schedule::Recurring {
+        rrule,
+        dtstart,
+        duration_minutes,
+        timezone,
+        exdates,
+    } = schedule
+    else {
+        return Ok(Vec::new());
+    };
+    let format = |t: &NaiveDateTime| t.format("%Y-%m-%dT%H:%M:%S").to_string();
+    let exdates: Vec<String> = exdates.iter().map(format).collect();
+    let exdate_refs: Vec<&str> = exdates.iter().map(String::as_str).collect();
+    expand_rrule_between(
+        rrule,
+        &format(dtstart),
+        *duration_minutes,
+        timezone,
+        &exdate_refs,
+        window_start,
+        window_end,
+    )
+}
@@ -15,9 +15,34 @@
 //! # Functions
 //!
 //! - [`convert_timezone`] — Convert a datetime between timezone representations
+//! - [`convert_timezone_batch`] / [`convert_timezone_multi`] — Many datetimes to one zone (with per-datetime errors), or one datetime to many zones
 //! - [`compute_duration`] — Calculate the duration between two timestamps
+//! - [`time_until`] — Countdown to a target, optionally counting business time only
+//! - [`time_until_next`] — Countdown to the next "Friday 5pm" in a given zone
 //! - [`adjust_timestamp`] — Add or subtract a duration from a timestamp
+//! - [`round_timestamp`] — Snap a timestamp to a 5/15/30/60-minute or day boundary
+//! - [`adjust_to_next`] — Move to the next "09:00", "top of the hour", or "Monday 09:00"
+//! - [`next_matching`] — Next occurrence of a simple pattern ("every Monday 09:00", "the 15th at 17:00") without an RRULE
 //! - [`resolve_relative`] — Resolve a relative time expression to an absolute datetime
+//! - [`resolve_relative_batch`] — Resolve many expressions in one call, with per-expression errors
+//! - [`lookup_timezone_abbreviation`] — Map "PST", "CST", etc. to candidate IANA zones
+//! - [`find_timezone`] / [`find_timezones_by_country`] — Map a city, country, or country code to IANA zones
+//! - [`world_clock`] — One instant across many zones, with working-hours and day-offset markers
+//! - [`overlap_window`] — When several zones are all within working hours on a date
+//! - [`dst_advisories`] — Clock changes that move a proposed weekly meeting for some attendees
+//! - [`day_of_year`] / [`julian_day_number`] — Number a date by day of year or Julian day (and back)
+//! - [`interval`] — Half-open [`TimeInterval`](interval::TimeInterval) arithmetic: shift, intersect, union, subtract, clamp
+//! - [`parse_iso_week_date`] — Turn an ISO 8601 week date ("2026-W08-3") into a calendar date
+//! - [`week_number`] — ISO 8601 or US week number of a date, with the year the week belongs to
+//!
+//! The string-accepting functions require RFC 3339 input. Each has a
+//! `_with_mode` variant taking an [`InputMode`]; [`InputMode::Lenient`] also
+//! accepts near-misses such as `"2026-03-15 14:00"` (see [`parse_datetime`]).
+//!
+//! Rust callers can skip string round-trips with the typed `_dt` variants
+//! ([`convert_timezone_dt`], [`compute_duration_dt`], [`adjust_timestamp_dt`],
+//! [`round_timestamp_dt`], [`resolve_relative_dt`]), which take and return
+//! `chrono` datetimes and [`Duration`].
 //!
 //! # Datetime Accuracy
 //!
@@ -25,18 +50,71 @@
 //! which reads the OS kernel clock (NTP-synchronized on modern systems, typically
 //! <50ms accuracy). No online time service is used.
 
-use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Offset, TimeZone, Utc, Weekday};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, LazyLock};
+
+use chrono::{
+    DateTime, Datelike, LocalResult, Month, NaiveDate, NaiveTime, Offset, TimeZone, Timelike, Utc,
+    Weekday,
+};
 use chrono_tz::Tz;
-use serde::Serialize;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
+use crate::business::BusinessCalendar;
 use crate::error::TruthError;
+use crate::explain::TraceStep;
+use crate::fiscal::FiscalCalendar;
+use crate::terms::TermCalendar;
+
+mod advisories;
+mod anchors;
+mod format;
+mod input;
+pub mod interval;
+mod locale;
+mod ordinal;
+mod parsers;
+mod pattern;
+#[cfg(feature = "resolver")]
+mod resolve;
+mod solar;
+mod weeks;
+mod workweek;
+mod zones;
+
+pub use advisories::{dst_advisories, DstAdvisory, DstAdvisoryKind};
+pub use anchors::{AnchorRule, RecurringAnchor};
+pub use format::{FormatOptions, Renderings};
+pub use input::{parse_datetime, InputMode};
+pub(crate) use locale::{format_short_in, format_time_in};
+pub use locale::{ClockFormat, Locale};
+pub use ordinal::{date_from_day_of_year, date_from_julian_day, day_of_year, julian_day_number};
+pub use parsers::{ExpressionParser, ParserRegistry};
+pub use pattern::{next_matching, PatternDays, SimplePattern};
+#[cfg(feature = "resolver")]
+pub(crate) use resolve::resolve_in_timezone;
+#[cfg(feature = "resolver")]
+pub use resolve::{
+    resolve_relative, resolve_relative_batch, resolve_relative_dt, resolve_relative_with_options,
+};
+#[cfg(feature = "solar")]
+pub use solar::SunriseEquation;
+pub use solar::{GeoLocation, SolarCalculator, SolarEvent};
+pub use weeks::{parse_iso_week_date, week_number, WeekNumber, WeekScheme};
+pub use zones::{
+    find_timezone, find_timezones_by_country, lookup_timezone_abbreviation, TimezoneAbbreviation,
+};
 
 // ── Configurable week start ─────────────────────────────────────────────────
 
 /// Which day begins a week for period computations ("start of week", "next week", etc.).
 ///
 /// Does **not** affect named-weekday expressions like "next Monday" or "last Friday".
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum WeekStartDay {
     /// ISO 8601 standard (Monday = day 0 of the week).
     #[default]
@@ -47,34 +125,208 @@ pub enum WeekStartDay {
 
 /// Options for [`resolve_relative_with_options`].
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ResolveOptions {
     /// Which day starts the week for period computations.
     pub week_start: WeekStartDay,
+    /// Working days and hours for business-day expressions ("end of next business day").
+    /// The working days also define the work week and weekend ("end of the work
+    /// week", "this weekend"); see [`WorkingHours::work_week_start`].
+    pub working_hours: WorkingHours,
+    /// Holidays and closures for business-day expressions. When set, its work
+    /// week and working hours are used instead of [`working_hours`](Self::working_hours)
+    /// for those expressions, and its holidays and closures are skipped.
+    pub business_calendar: Option<BusinessCalendar>,
+    /// Application-defined parsers consulted before the built-in grammar.
+    /// Code, not data: skipped when (de)serializing.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub parsers: ParserRegistry,
+    /// Named recurring dates ("payday", "sprint boundary") usable in expressions
+    /// like "next payday" or "two days before next payday".
+    pub anchors: Vec<RecurringAnchor>,
+    /// Caller-supplied named instants ("meeting start") usable in expressions
+    /// like "two hours after the meeting start". Names match case-insensitively,
+    /// ignoring a leading "the".
+    pub references: BTreeMap<String, DateTime<Utc>>,
+    /// Where solar anchors ("sunrise", "tomorrow at dusk", "an hour before
+    /// sunset") are observed. Without one, those words do not resolve.
+    pub location: Option<GeoLocation>,
+    /// Computes solar anchors at [`location`](Self::location). `None` uses
+    /// `SunriseEquation` with the `solar` feature; without the feature,
+    /// solar anchors need a calculator here. Code, not data: skipped when
+    /// (de)serializing.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub solar_calculator: Option<Arc<dyn SolarCalculator>>,
+    /// First month of the fiscal year for quarter expressions ("Q3", "end of next
+    /// quarter"). `None` means calendar quarters starting in January. With a
+    /// fiscal start, a year in "Q1 2027" names the fiscal year by the calendar
+    /// year it ends in (FY2027 starting October 2026 → Q1 is Oct–Dec 2026).
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub fiscal_year_start: Option<Month>,
+    /// Week-based fiscal calendar for period expressions ("P7", "end of P7 FY2027",
+    /// "start of next period"). Without one, those expressions do not resolve.
+    pub fiscal_calendar: Option<FiscalCalendar>,
+    /// Academic terms and breaks usable by name ("first Monday of spring term",
+    /// "end of reading week"). Without one, those names do not resolve.
+    pub term_calendar: Option<TermCalendar>,
+    /// Correct obvious typos ("tommorow", "wensday") when an expression does not
+    /// parse as written. Corrections are reported in [`ResolvedDatetime::corrections`].
+    pub lenient: bool,
+    /// Record how the expression was read in [`ResolvedDatetime::trace`].
+    pub explain: bool,
+    /// Where "mid-month" and "mid-week" land.
+    pub midpoints: MidpointConvention,
+    /// Which occurrence a bare day of the month ("the 15th") refers to.
+    pub day_of_month: DayOfMonthPolicy,
+    /// How an hour without am/pm ("tomorrow at 7") is read.
+    pub bare_hour: BareHourPolicy,
+    /// Language of [`ResolvedDatetime::interpretation`].
+    pub locale: Locale,
+    /// 12- or 24-hour times in [`ResolvedDatetime::interpretation`] and warning
+    /// messages. `None` uses the locale's convention ([`Locale::default_clock`]).
+    pub clock: Option<ClockFormat>,
+}
+
+impl ResolveOptions {
+    /// The calendar business days and working time are counted on ("next
+    /// business day", "in 4 working hours", "+6bh"):
+    /// [`business_calendar`](Self::business_calendar) if set, otherwise
+    /// [`working_hours`](Self::working_hours) read in `tz`.
+    pub(crate) fn working_time(&self, tz: &Tz) -> Cow<'_, BusinessCalendar> {
+        match &self.business_calendar {
+            Some(calendar) => Cow::Borrowed(calendar),
+            None => Cow::Owned(BusinessCalendar {
+                working_hours: self.working_hours.clone(),
+                timezone: tz.name().to_string(),
+                ..BusinessCalendar::default()
+            }),
+        }
+    }
+}
+
+/// How to read an hour-only time without am/pm, such as "at 7".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum BareHourPolicy {
+    /// Reject the expression as ambiguous.
+    #[default]
+    Error,
+    /// The earliest reading at or after the anchor (the later one if both are past).
+    /// A bare weekday ("friday at 7") is the next such day with a reading still to come.
+    PreferFuture,
+    /// The reading inside [`ResolveOptions::working_hours`]; if neither or both are,
+    /// the one closer to the working-hours window (the earlier on a tie).
+    PreferBusinessHours,
+}
+
+/// How a bare day-of-month ordinal ("the 15th") picks a month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DayOfMonthPolicy {
+    /// The next occurrence on or after today (today's date counts).
+    #[default]
+    NextFuture,
+    /// Whichever occurrence, past or upcoming, is fewer days away (upcoming on a tie).
+    Nearest,
+}
+
+/// Where midpoint expressions ("mid-month", "middle of next week") resolve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MidpointConvention {
+    /// Day of the month for "mid-month", clamped to the month's last day.
+    pub day_of_month: u32,
+    /// Day of the week for "mid-week".
+    pub weekday: Weekday,
+    /// Local time of day for both.
+    pub time: NaiveTime,
+}
+
+impl Default for MidpointConvention {
+    /// The 15th of the month and Wednesday, both at 12:00.
+    fn default() -> Self {
+        Self {
+            day_of_month: 15,
+            weekday: Weekday::Wed,
+            time: NaiveTime::from_hms_opt(12, 0, 0).expect("valid time"),
+        }
+    }
+}
+
+// ── Working hours ───────────────────────────────────────────────────────────
+
+/// A daily working-hours window, interpreted in each timezone's local wall-clock time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WorkingHours {
+    /// Local time the working day starts (inclusive).
+    pub start: NaiveTime,
+    /// Local time the working day ends (exclusive).
+    pub end: NaiveTime,
+    /// Days of the week that are working days.
+    pub days: Vec<Weekday>,
+}
+
+impl Default for WorkingHours {
+    /// 09:00–17:00, Monday through Friday.
+    fn default() -> Self {
+        Self {
+            start: NaiveTime::from_hms_opt(9, 0, 0).expect("valid time"),
+            end: NaiveTime::from_hms_opt(17, 0, 0).expect("valid time"),
+            days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+        }
+    }
 }
 
-/// How many days `weekday` is from the week-start day.
-fn days_from_week_start(weekday: Weekday, week_start: WeekStartDay) -> i64 {
-    match week_start {
-        WeekStartDay::Monday => weekday.num_days_from_monday() as i64,
-        WeekStartDay::Sunday => weekday.num_days_from_sunday() as i64,
+impl WorkingHours {
+    /// Whether a local datetime falls on a working day within working hours.
+    pub fn contains<T: TimeZone>(&self, local: &DateTime<T>) -> bool {
+        let time = local.time();
+        self.days.contains(&local.weekday()) && time >= self.start && time < self.end
     }
 }
 
 // ── convert_timezone ────────────────────────────────────────────────────────
 
 /// The result of converting a datetime to a target timezone.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ConvertedDatetime {
-    /// The instant in UTC (RFC 3339).
+    /// The instant in UTC (RFC 3339). Empty, and omitted when serialized,
+    /// under [`OutputFields::Minimal`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "String::is_empty")
+    )]
     pub utc: String,
     /// The instant in the target timezone (RFC 3339 with offset).
     pub local: String,
     /// The IANA timezone name used.
-    pub timezone: String,
-    /// The UTC offset at this instant (e.g., "-05:00").
+    pub timezone: Cow<'static, str>,
+    /// The UTC offset at this instant (e.g., "-05:00"). Empty, and omitted
+    /// when serialized, under [`OutputFields::Minimal`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "String::is_empty")
+    )]
     pub utc_offset: String,
     /// Whether Daylight Saving Time is active at this instant.
     pub dst_active: bool,
+    /// Extra renderings of `local`, filled by [`ConvertedDatetime::with_renderings`].
+    pub renderings: Option<Renderings>,
 }
 
 /// Convert a datetime string to a different timezone representation.
@@ -82,7 +334,8 @@ pub struct ConvertedDatetime {
 /// # Arguments
 ///
 /// * `datetime` — An RFC 3339 datetime string (e.g., `"2026-03-15T14:00:00Z"`)
-/// * `target_timezone` — An IANA timezone name (e.g., `"America/New_York"`)
+/// * `target_timezone` — An IANA timezone name (e.g., `"America/New_York"`) or an
+///   unambiguous abbreviation (e.g., `"PST"`)
 ///
 /// # Returns
 ///
@@ -92,7 +345,8 @@ pub struct ConvertedDatetime {
 /// # Errors
 ///
 /// Returns [`TruthError::InvalidDatetime`] if the datetime string cannot be parsed,
-/// or [`TruthError::InvalidTimezone`] if the timezone name is not a valid IANA timezone.
+/// [`TruthError::InvalidTimezone`] if the timezone name is not a valid IANA timezone,
+/// or [`TruthError::AmbiguousTimezone`] if it is an abbreviation shared by several zones.
 ///
 /// # Examples
 ///
@@ -108,30 +362,424 @@ pub fn convert_timezone(
     datetime: &str,
     target_timezone: &str,
 ) -> Result<ConvertedDatetime, TruthError> {
-    let dt = parse_rfc3339(datetime)?;
+    convert_timezone_with_mode(datetime, target_timezone, InputMode::Strict)
+}
+
+/// [`convert_timezone`] with a choice of [`InputMode`] for the datetime input.
+pub fn convert_timezone_with_mode(
+    datetime: &str,
+    target_timezone: &str,
+    input: InputMode,
+) -> Result<ConvertedDatetime, TruthError> {
+    let dt = parse_datetime(datetime, input)?;
+    let tz = parse_timezone(target_timezone)?;
+
+    Ok(convert_parsed(dt, &tz))
+}
+
+/// Convert many datetimes to a single target timezone.
+///
+/// The timezone is parsed once and reused for every datetime, so converting a
+/// schedule of hundreds of occurrences costs one timezone lookup instead of one
+/// per occurrence. Each datetime then succeeds or fails on its own, so one
+/// malformed datetime does not discard the others. Results are returned in
+/// input order.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] (or [`TruthError::AmbiguousTimezone`]) if the
+/// target timezone is invalid. Per-datetime failures
+/// ([`TruthError::InvalidDatetime`]) are returned in place.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::convert_timezone_batch;
+///
+/// let results = convert_timezone_batch(
+///     &["2026-03-15T14:00:00Z", "yesterday", "2026-03-16T14:00:00Z"],
+///     "Asia/Tokyo",
+/// )
+/// .unwrap();
+/// assert_eq!(results.len(), 3);
+/// assert!(results[1].is_err());
+/// assert!(results[2].as_ref().unwrap().local.starts_with("2026-03-16T23:00:00"));
+/// ```
+pub fn convert_timezone_batch(
+    datetimes: &[&str],
+    target_timezone: &str,
+) -> Result<Vec<Result<ConvertedDatetime, TruthError>>, TruthError> {
+    convert_timezone_batch_with_mode(datetimes, target_timezone, InputMode::Strict)
+}
+
+/// [`convert_timezone_batch`] with a choice of [`InputMode`] for the datetime input.
+pub fn convert_timezone_batch_with_mode(
+    datetimes: &[&str],
+    target_timezone: &str,
+    input: InputMode,
+) -> Result<Vec<Result<ConvertedDatetime, TruthError>>, TruthError> {
+    let tz = parse_timezone(target_timezone)?;
+    Ok(datetimes
+        .iter()
+        .map(|datetime| parse_datetime(datetime, input).map(|dt| convert_parsed(dt, &tz)))
+        .collect())
+}
+
+/// Which string fields a conversion fills in.
+///
+/// Every field of a [`ConvertedDatetime`] is formatted text, and in bulk the
+/// formatting dominates. `local` carries both the instant and its offset, so
+/// pipelines that only need it can skip the rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum OutputFields {
+    /// Every field.
+    #[default]
+    Full,
+    /// Leave [`ConvertedDatetime::utc`] and [`ConvertedDatetime::utc_offset`]
+    /// empty, as both can be read from `local`.
+    Minimal,
+}
+
+/// Typed batch variant of [`convert_timezone_batch`]: convert instants that
+/// are already parsed, such as expanded occurrences, to one timezone.
+///
+/// Skips parsing each datetime, and with [`OutputFields::Minimal`] formats
+/// only `local`. Zone names are borrowed from the timezone database rather
+/// than copied into every result. Results are returned in input order.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] (or [`TruthError::AmbiguousTimezone`])
+/// if the target timezone is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::temporal::{convert_instants, OutputFields};
+///
+/// let instants = [Utc.with_ymd_and_hms(2026, 3, 15, 14, 0, 0).unwrap()];
+/// let results = convert_instants(&instants, "Asia/Tokyo", OutputFields::Minimal).unwrap();
+/// assert_eq!(results[0].local, "2026-03-15T23:00:00+09:00");
+/// assert!(results[0].utc.is_empty());
+/// ```
+pub fn convert_instants(
+    instants: &[DateTime<Utc>],
+    target_timezone: &str,
+    fields: OutputFields,
+) -> Result<Vec<ConvertedDatetime>, TruthError> {
     let tz = parse_timezone(target_timezone)?;
+    Ok(instants
+        .iter()
+        .map(|&dt| convert_with_fields(dt, &tz, fields))
+        .collect())
+}
+
+/// Convert one datetime to several target timezones (e.g., a world-clock answer).
+///
+/// The datetime is parsed once. Results are returned in the order of `target_timezones`.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the datetime cannot be parsed, or
+/// [`TruthError::InvalidTimezone`] (or [`TruthError::AmbiguousTimezone`]) for the first
+/// timezone that cannot be resolved.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::convert_timezone_multi;
+///
+/// let results = convert_timezone_multi(
+///     "2026-03-15T14:00:00Z",
+///     &["America/New_York", "Europe/London", "Asia/Tokyo"],
+/// )
+/// .unwrap();
+/// assert_eq!(results[0].utc_offset, "-04:00");
+/// assert_eq!(results[2].utc_offset, "+09:00");
+/// ```
+pub fn convert_timezone_multi(
+    datetime: &str,
+    target_timezones: &[&str],
+) -> Result<Vec<ConvertedDatetime>, TruthError> {
+    convert_timezone_multi_with_mode(datetime, target_timezones, InputMode::Strict)
+}
 
-    let local = dt.with_timezone(&tz);
+/// [`convert_timezone_multi`] with a choice of [`InputMode`] for the datetime input.
+pub fn convert_timezone_multi_with_mode(
+    datetime: &str,
+    target_timezones: &[&str],
+    input: InputMode,
+) -> Result<Vec<ConvertedDatetime>, TruthError> {
+    let dt = parse_datetime(datetime, input)?;
+    target_timezones
+        .iter()
+        .map(|name| parse_timezone(name).map(|tz| convert_parsed(dt, &tz)))
+        .collect()
+}
+
+/// Typed variant of [`convert_timezone`]: express an instant in a timezone.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Asia::Tokyo;
+/// use truth_engine::temporal::convert_timezone_dt;
+///
+/// let utc = Utc.with_ymd_and_hms(2026, 3, 15, 14, 0, 0).unwrap();
+/// let tokyo = convert_timezone_dt(utc, Tokyo);
+/// assert_eq!(tokyo.to_rfc3339(), "2026-03-15T23:00:00+09:00");
+/// ```
+pub fn convert_timezone_dt<T: TimeZone>(datetime: DateTime<T>, timezone: Tz) -> DateTime<Tz> {
+    datetime.with_timezone(&timezone)
+}
+
+/// Convert an already-parsed instant into a [`ConvertedDatetime`].
+fn convert_parsed(dt: DateTime<Utc>, tz: &Tz) -> ConvertedDatetime {
+    convert_with_fields(dt, tz, OutputFields::Full)
+}
+
+/// [`convert_parsed`], formatting only the requested fields.
+pub(crate) fn convert_with_fields(
+    dt: DateTime<Utc>,
+    tz: &Tz,
+    fields: OutputFields,
+) -> ConvertedDatetime {
+    let local = dt.with_timezone(tz);
 
     // Determine DST: compare the timezone's standard offset with the current offset.
     // If they differ, DST is active.
-    let dst_active = is_dst_active(&local, &tz);
+    let dst_active = is_dst_active(&local, tz);
 
-    let utc_offset = format_utc_offset(&local);
+    let (utc, utc_offset) = match fields {
+        OutputFields::Full => (dt.to_rfc3339(), format_utc_offset(&local)),
+        OutputFields::Minimal => (String::new(), String::new()),
+    };
 
-    Ok(ConvertedDatetime {
-        utc: dt.to_rfc3339(),
+    ConvertedDatetime {
+        utc,
         local: local.to_rfc3339(),
-        timezone: target_timezone.to_string(),
+        timezone: tz.name().into(),
         utc_offset,
         dst_active,
-    })
+        renderings: None,
+    }
+}
+
+// ── world_clock ─────────────────────────────────────────────────────────────
+
+/// One row of a world-clock comparison.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WorldClockEntry {
+    /// The IANA timezone name.
+    pub timezone: Cow<'static, str>,
+    /// The instant in this timezone (RFC 3339 with offset).
+    pub local: String,
+    /// The UTC offset at this instant (e.g., "+09:00").
+    pub utc_offset: String,
+    /// Whether Daylight Saving Time is active at this instant.
+    pub dst_active: bool,
+    /// Calendar-day difference from the input's own local date (`+1` = next day).
+    pub day_offset: i64,
+    /// Whether the local time falls within the given working hours.
+    pub is_business_hours: bool,
+    /// Human-readable local time (e.g., "Monday, March 16, 2026 at 10:00 PM JST").
+    pub interpretation: String,
+}
+
+/// Show one instant across several timezones, with working-hours and day-offset markers.
+///
+/// Answers questions like "what time is 9am ET in Tokyo and Sydney, and is that
+/// during their workday?" in a single call.
+///
+/// # Arguments
+///
+/// * `datetime` — An RFC 3339 datetime string. Its own offset defines the reference
+///   date for `day_offset` (so `"…T09:00:00-04:00"` is compared against Eastern dates).
+/// * `timezones` — IANA timezone names (or unambiguous abbreviations), in display order
+/// * `working_hours` — The working-hours window applied in each zone's local time
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the datetime cannot be parsed, or
+/// [`TruthError::InvalidTimezone`] (or [`TruthError::AmbiguousTimezone`]) for the first
+/// timezone that cannot be resolved.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::{world_clock, WorkingHours};
+///
+/// // Monday 9am Eastern
+/// let rows = world_clock(
+///     "2026-03-16T09:00:00-04:00",
+///     &["Europe/London", "Australia/Sydney"],
+///     &WorkingHours::default(),
+/// )
+/// .unwrap();
+/// assert!(rows[0].is_business_hours); // 13:00 in London
+/// assert_eq!(rows[1].day_offset, 1); // already Tuesday in Sydney
+/// ```
+pub fn world_clock(
+    datetime: &str,
+    timezones: &[&str],
+    working_hours: &WorkingHours,
+) -> Result<Vec<WorldClockEntry>, TruthError> {
+    let input = DateTime::parse_from_rfc3339(datetime)
+        .map_err(|e| TruthError::InvalidDatetime(format!("'{}': {}", datetime, e)))?;
+    let reference_date = input.date_naive();
+    let dt = input.with_timezone(&Utc);
+
+    timezones
+        .iter()
+        .map(|name| {
+            let tz = parse_timezone(name)?;
+            let local = dt.with_timezone(&tz);
+            Ok(WorldClockEntry {
+                timezone: tz.name().into(),
+                local: local.to_rfc3339(),
+                utc_offset: format_utc_offset(&local),
+                dst_active: is_dst_active(&local, &tz),
+                day_offset: (local.date_naive() - reference_date).num_days(),
+                is_business_hours: working_hours.contains(&local),
+                interpretation: format_interpretation(&local),
+            })
+        })
+        .collect()
+}
+
+// ── overlap_window ──────────────────────────────────────────────────────────
+
+/// A span of time during which every requested timezone is within working hours.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct OverlapWindow {
+    /// Start of the overlap (RFC 3339, UTC).
+    pub start: String,
+    /// End of the overlap (RFC 3339, UTC).
+    pub end: String,
+    /// Length of the overlap in minutes.
+    pub duration_minutes: i64,
+    /// The same span in each zone's local time, in input order.
+    pub local: Vec<LocalWindow>,
+}
+
+/// An overlap span expressed in one timezone's local time.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LocalWindow {
+    /// The IANA timezone name.
+    pub timezone: Cow<'static, str>,
+    /// Local start (RFC 3339 with offset).
+    pub start: String,
+    /// Local end (RFC 3339 with offset).
+    pub end: String,
+}
+
+/// Find the intervals on a date when all timezones are simultaneously within working hours.
+///
+/// Powers questions like "find an hour that works for London and San Francisco"
+/// without touching any calendars. The date is read in the first timezone's local
+/// calendar; the other zones may be on a different local date during the overlap
+/// (Tokyo's Tuesday morning overlaps San Francisco's Monday afternoon).
+///
+/// # Arguments
+///
+/// * `timezones` — IANA timezone names (or unambiguous abbreviations); the first is the reference zone
+/// * `working_hours` — The working-hours window applied in each zone's local time
+/// * `date` — The reference date as `YYYY-MM-DD`
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the date cannot be parsed, or
+/// [`TruthError::InvalidTimezone`] (or [`TruthError::AmbiguousTimezone`]) for the first
+/// timezone that cannot be resolved.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::{overlap_window, WorkingHours};
+///
+/// let windows = overlap_window(
+///     &["Europe/London", "America/Los_Angeles"],
+///     &WorkingHours::default(),
+///     "2026-03-18",
+/// )
+/// .unwrap();
+/// // London works 09:00–17:00 GMT; Los Angeles opens at 09:00 PDT (16:00 UTC).
+/// assert_eq!(windows.len(), 1);
+/// assert_eq!(windows[0].start, "2026-03-18T16:00:00+00:00");
+/// assert_eq!(windows[0].duration_minutes, 60);
+/// ```
+pub fn overlap_window(
+    timezones: &[&str],
+    working_hours: &WorkingHours,
+    date: &str,
+) -> Result<Vec<OverlapWindow>, TruthError> {
+    let date = parse_date(date)?;
+    let zones = timezones
+        .iter()
+        .map(|name| parse_timezone(name))
+        .collect::<Result<Vec<Tz>, _>>()?;
+
+    let Some(reference) = zones.first() else {
+        return Ok(Vec::new());
+    };
+    let mut spans: Vec<(DateTime<Utc>, DateTime<Utc>)> =
+        working_window(date, reference, working_hours)
+            .into_iter()
+            .collect();
+
+    for tz in &zones[1..] {
+        // Neighbouring local dates can overlap the reference day.
+        let windows: Vec<_> = [date.pred_opt(), Some(date), date.succ_opt()]
+            .into_iter()
+            .flatten()
+            .filter_map(|d| working_window(d, tz, working_hours))
+            .collect();
+        spans = spans
+            .iter()
+            .flat_map(|&(s, e)| {
+                windows.iter().filter_map(move |&(ws, we)| {
+                    let start = s.max(ws);
+                    let end = e.min(we);
+                    (start < end).then_some((start, end))
+                })
+            })
+            .collect();
+    }
+
+    Ok(spans
+        .into_iter()
+        .map(|(start, end)| OverlapWindow {
+            start: start.to_rfc3339(),
+            end: end.to_rfc3339(),
+            duration_minutes: (end - start).num_minutes(),
+            local: zones
+                .iter()
+                .map(|tz| LocalWindow {
+                    timezone: tz.name().into(),
+                    start: start.with_timezone(tz).to_rfc3339(),
+                    end: end.with_timezone(tz).to_rfc3339(),
+                })
+                .collect(),
+        })
+        .collect())
 }
 
 // ── compute_duration ────────────────────────────────────────────────────────
 
 /// Duration information between two timestamps.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DurationInfo {
     /// Total duration in seconds (negative if end is before start).
     pub total_seconds: i64,
@@ -164,9 +812,31 @@ pub struct DurationInfo {
 ///
 /// Returns [`TruthError::InvalidDatetime`] if either datetime string cannot be parsed.
 pub fn compute_duration(start: &str, end: &str) -> Result<DurationInfo, TruthError> {
-    let start_dt = parse_rfc3339(start)?;
-    let end_dt = parse_rfc3339(end)?;
+    compute_duration_with_mode(start, end, InputMode::Strict)
+}
 
+/// [`compute_duration`] with a choice of [`InputMode`] for the datetime input.
+pub fn compute_duration_with_mode(
+    start: &str,
+    end: &str,
+    input: InputMode,
+) -> Result<DurationInfo, TruthError> {
+    let start_dt = parse_datetime(start, input)?;
+    let end_dt = parse_datetime(end, input)?;
+
+    Ok(duration_info_between(start_dt, end_dt))
+}
+
+/// Typed variant of [`compute_duration`]: the duration between two instants.
+pub fn compute_duration_dt<A: TimeZone, B: TimeZone>(
+    start: DateTime<A>,
+    end: DateTime<B>,
+) -> DurationInfo {
+    duration_info_between(start.with_timezone(&Utc), end.with_timezone(&Utc))
+}
+
+/// Build a [`DurationInfo`] for two already-parsed instants.
+fn duration_info_between(start_dt: DateTime<Utc>, end_dt: DateTime<Utc>) -> DurationInfo {
     let total_seconds = (end_dt - start_dt).num_seconds();
     let abs_seconds = total_seconds.unsigned_abs();
 
@@ -179,1828 +849,4839 @@ pub fn compute_duration(start: &str, end: &str) -> Result<DurationInfo, TruthErr
 
     let human_readable = format_human_duration(days, hours, minutes, seconds);
 
-    Ok(DurationInfo {
+    DurationInfo {
         total_seconds,
         days,
         hours,
         minutes,
         seconds,
         human_readable,
-    })
+    }
 }
 
-// ── adjust_timestamp ────────────────────────────────────────────────────────
+// ── time_until ──────────────────────────────────────────────────────────────
 
-/// The result of adjusting a timestamp by a duration.
-#[derive(Debug, Clone, Serialize)]
-pub struct AdjustedTimestamp {
-    /// The original datetime (echoed back).
-    pub original: String,
-    /// The adjusted datetime in UTC (RFC 3339).
-    pub adjusted_utc: String,
-    /// The adjusted datetime in the source timezone (RFC 3339 with offset).
-    pub adjusted_local: String,
-    /// The normalized adjustment applied (e.g., "+2h30m").
-    pub adjustment_applied: String,
+/// Options for [`time_until`].
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TimeUntilOptions {
+    /// When set, also count the remaining time that is working time on this
+    /// calendar, skipping its holidays and closures.
+    pub business_calendar: Option<BusinessCalendar>,
+    /// Options used to resolve the target expression.
+    pub resolve: ResolveOptions,
 }
 
-/// Parsed duration components from an adjustment string.
-#[derive(Debug, Clone, Default)]
-struct ParsedDuration {
-    sign: i64, // +1 or -1
-    weeks: i64,
-    days: i64,
-    hours: i64,
-    minutes: i64,
-    seconds: i64,
+/// Remaining time counted only within working time.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BusinessDuration {
+    /// Total working seconds (negative if the target is in the past).
+    pub total_seconds: i64,
+    /// Whole business days, where one business day is the length of the working-hours window.
+    pub business_days: i64,
+    /// Hours component of the remainder.
+    pub hours: i64,
+    /// Minutes component of the remainder.
+    pub minutes: i64,
+    /// Seconds component of the remainder.
+    pub seconds: i64,
+    /// Human-readable representation (e.g., "3 business days, 4 hours").
+    pub human_readable: String,
 }
 
-/// Adjust a timestamp by adding or subtracting a duration.
+/// A countdown from an anchor to a target.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TimeUntil {
+    /// The resolved target in UTC (RFC 3339).
+    pub target_utc: String,
+    /// The resolved target in the given timezone (RFC 3339 with offset).
+    pub target_local: String,
+    /// Whether the target is before the anchor.
+    pub is_past: bool,
+    /// Wall-clock time remaining.
+    pub calendar: DurationInfo,
+    /// Working time remaining, when [`TimeUntilOptions::business_calendar`] is set.
+    pub business: Option<BusinessDuration>,
+    /// Business-time string if requested, otherwise the calendar string.
+    pub human_readable: String,
+}
+
+/// Compute how long remains from `anchor` until `target`, optionally in business time.
 ///
-/// # Arguments
+/// `target` is anything [`resolve_relative_with_options`] accepts — an RFC 3339
+/// datetime or an expression such as `"end of next week"` — so deadline tracking
+/// is one call instead of resolve + duration + business-hours arithmetic.
 ///
-/// * `datetime` — An RFC 3339 datetime string
-/// * `adjustment` — A duration string (e.g., `"+2h"`, `"-30m"`, `"+1d2h30m"`, `"+1w"`)
-/// * `timezone` — An IANA timezone name for interpreting day-level adjustments
+/// Business time is [`BusinessCalendar::business_seconds_between`], read in the
+/// calendar's own timezone, so nights, weekends, holidays, and closures are
+/// skipped and DST transitions are handled in local time.
 ///
-/// # Duration Format
+/// # Errors
 ///
-/// Must start with `+` or `-`, followed by one or more components:
-/// - `Nw` — weeks
-/// - `Nd` — days (timezone-aware: same wall-clock time, not +24h across DST)
-/// - `Nh` — hours
-/// - `Nm` — minutes
-/// - `Ns` — seconds
+/// Returns [`TruthError::InvalidTimezone`] if the timezone (or the business
+/// calendar's) is invalid, or [`TruthError::InvalidExpression`] if the target
+/// cannot be resolved.
 ///
-/// Components can be combined: `+1d2h30m`, `-2w3d`.
+/// # Examples
 ///
-/// # Errors
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::business::BusinessCalendar;
+/// use truth_engine::temporal::{time_until, TimeUntilOptions};
 ///
-/// Returns [`TruthError::InvalidDatetime`] if the datetime cannot be parsed,
-/// [`TruthError::InvalidTimezone`] if the timezone is invalid, or
-/// [`TruthError::InvalidDuration`] if the adjustment string cannot be parsed.
-pub fn adjust_timestamp(
-    datetime: &str,
-    adjustment: &str,
+/// // Friday 15:00 → Monday 11:00: two working hours each side of the weekend
+/// let anchor = Utc.with_ymd_and_hms(2026, 3, 13, 15, 0, 0).unwrap();
+/// let options = TimeUntilOptions {
+///     business_calendar: Some(BusinessCalendar::default()),
+///     ..Default::default()
+/// };
+/// let result = time_until(anchor, "2026-03-16T11:00:00Z", "UTC", &options).unwrap();
+/// assert_eq!(result.calendar.days, 2);
+/// assert_eq!(result.human_readable, "4 hours");
+/// ```
+#[cfg(feature = "resolver")]
+pub fn time_until(
+    anchor: DateTime<Utc>,
+    target: &str,
     timezone: &str,
-) -> Result<AdjustedTimestamp, TruthError> {
-    let dt = parse_rfc3339(datetime)?;
+    options: &TimeUntilOptions,
+) -> Result<TimeUntil, TruthError> {
     let tz = parse_timezone(timezone)?;
-    let parsed = parse_duration_string(adjustment)?;
+    let resolved = resolve_relative_with_options(anchor, target, timezone, &options.resolve)?;
+    let target_utc = parse_datetime(&resolved.resolved_utc, InputMode::Strict)?;
 
-    // For day/week adjustments, we work in local time to preserve wall-clock time
-    // across DST transitions. For sub-day adjustments, we work in UTC.
-    let local = dt.with_timezone(&tz);
+    time_until_instant(anchor, target_utc, &tz, options.business_calendar.as_ref())
+}
 
-    let adjusted_local = if parsed.weeks != 0 || parsed.days != 0 {
-        // Day-level: adjust date in local time, then add sub-day components in UTC
-        let total_days = parsed.sign * (parsed.weeks * 7 + parsed.days);
-        let new_date = local.date_naive() + chrono::Duration::days(total_days);
-        let new_local_naive = new_date.and_time(local.time());
+/// How long until the next occurrence of a weekday and/or time of day, such as
+/// "Friday 5pm" in Berlin.
+///
+/// `pattern` is anything [`adjust_to_next`] accepts (`"Friday 17:00"`,
+/// `"friday at 5pm"`, `"09:00"`, `"Monday"`, `"top of the hour"`), interpreted
+/// in `timezone`. The target is always strictly after `anchor`, so asking on
+/// Friday at 17:00 gives next Friday. With a `business_calendar`, working time
+/// is counted as in [`time_until`].
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] if the timezone (or the business
+/// calendar's) is invalid, or [`TruthError::InvalidExpression`] if the pattern
+/// is not recognized.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::temporal::time_until_next;
+///
+/// // Wednesday 14:30 UTC = 15:30 in Berlin
+/// let anchor = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+/// let result = time_until_next(anchor, "Friday 5pm", "Europe/Berlin", None).unwrap();
+/// assert_eq!(result.target_local, "2026-02-20T17:00:00+01:00");
+/// assert_eq!(result.human_readable, "2 days, 1 hour, 30 minutes");
+/// ```
+pub fn time_until_next(
+    anchor: DateTime<Utc>,
+    pattern: &str,
+    timezone: &str,
+    business_calendar: Option<&BusinessCalendar>,
+) -> Result<TimeUntil, TruthError> {
+    let tz = parse_timezone(timezone)?;
+    let target = next_boundary(anchor, pattern, &tz)?;
+    time_until_instant(anchor, target, &tz, business_calendar)
+}
 
-        let adjusted_local_dt = tz
-            .from_local_datetime(&new_local_naive)
-            .single()
-            .ok_or_else(|| {
-                TruthError::InvalidDatetime(
-                    "ambiguous or nonexistent local time after day adjustment".to_string(),
-                )
-            })?;
+/// Build a [`TimeUntil`] for an already-resolved target.
+fn time_until_instant(
+    anchor: DateTime<Utc>,
+    target_utc: DateTime<Utc>,
+    tz: &Tz,
+    business_calendar: Option<&BusinessCalendar>,
+) -> Result<TimeUntil, TruthError> {
+    let calendar = duration_info_between(anchor, target_utc);
+
+    let business = business_calendar
+        .map(|business_calendar| {
+            let total_seconds = business_calendar.business_seconds_between(anchor, target_utc)?;
+            let wh = &business_calendar.working_hours;
+            let secs = total_seconds.abs();
+            let day_len = (wh.end - wh.start).num_seconds().max(1);
+            let business_days = secs / day_len;
+            let rem = secs % day_len;
+            let (hours, minutes, seconds) = (rem / 3600, (rem % 3600) / 60, rem % 60);
+            Ok::<_, TruthError>(BusinessDuration {
+                total_seconds,
+                business_days,
+                hours,
+                minutes,
+                seconds,
+                human_readable: format_business_duration(business_days, hours, minutes, seconds),
+            })
+        })
+        .transpose()?;
 
-        // Add sub-day components in UTC
-        let sub_day_seconds =
-            parsed.sign * (parsed.hours * 3600 + parsed.minutes * 60 + parsed.seconds);
-        adjusted_local_dt + chrono::Duration::seconds(sub_day_seconds)
-    } else {
-        // Sub-day only: simple UTC arithmetic
-        let total_seconds =
-            parsed.sign * (parsed.hours * 3600 + parsed.minutes * 60 + parsed.seconds);
-        local + chrono::Duration::seconds(total_seconds)
+    let human_readable = match &business {
+        Some(b) => b.human_readable.clone(),
+        None => calendar.human_readable.clone(),
     };
 
-    let adjusted_utc = adjusted_local.with_timezone(&Utc);
-    let normalized = normalize_duration_string(&parsed);
-
-    Ok(AdjustedTimestamp {
-        original: datetime.to_string(),
-        adjusted_utc: adjusted_utc.to_rfc3339(),
-        adjusted_local: adjusted_local.to_rfc3339(),
-        adjustment_applied: normalized,
+    Ok(TimeUntil {
+        target_utc: target_utc.to_rfc3339(),
+        target_local: target_utc.with_timezone(tz).to_rfc3339(),
+        is_past: target_utc < anchor,
+        calendar,
+        business,
+        human_readable,
     })
 }
 
-// ── resolve_relative ────────────────────────────────────────────────────────
+/// The UTC bounds of the working-hours window on a local date, or `None` on a
+/// non-working day.
+pub(crate) fn working_window(
+    date: NaiveDate,
+    tz: &Tz,
+    wh: &WorkingHours,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    if !wh.days.contains(&date.weekday()) {
+        return None;
+    }
+    let start = local_to_utc(tz, date.and_time(wh.start))?;
+    let end = local_to_utc(tz, date.and_time(wh.end))?;
+    Some((start, end))
+}
 
-/// The result of resolving a relative time expression.
-#[derive(Debug, Clone, Serialize)]
-pub struct ResolvedDatetime {
-    /// The resolved datetime in UTC (RFC 3339).
-    pub resolved_utc: String,
-    /// The resolved datetime in the given timezone (RFC 3339 with offset).
-    pub resolved_local: String,
-    /// The IANA timezone used for resolution.
-    pub timezone: String,
-    /// Human-readable interpretation (e.g., "Tuesday, February 24, 2026 at 2:00 PM EST").
-    pub interpretation: String,
+/// Resolve a local wall-clock time to UTC, taking the earlier instant for
+/// ambiguous (fall-back) times and shifting forward past spring-forward gaps.
+pub(crate) fn local_to_utc(tz: &Tz, naive: chrono::NaiveDateTime) -> Option<DateTime<Utc>> {
+    if let Some(dt) = tz.from_local_datetime(&naive).earliest() {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // Nonexistent local time (DST gap): gaps are at most a few hours.
+    (1..=3).find_map(|h| {
+        tz.from_local_datetime(&(naive + chrono::Duration::hours(h)))
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc))
+    })
 }
 
-/// Resolve a relative time expression to an absolute datetime.
-///
-/// Uses ISO 8601 week start (Monday). For configurable week start, use
-/// [`resolve_relative_with_options`].
-///
-/// # Arguments
-///
-/// * `anchor` — The reference "now" instant (typically `Utc::now()`)
-/// * `expression` — A time expression (see [`resolve_relative_with_options`] for grammar)
-/// * `timezone` — An IANA timezone name for interpreting local-time expressions
-///
-/// # Errors
-///
-/// Returns [`TruthError::InvalidExpression`] if the expression cannot be parsed
-/// deterministically.
-pub fn resolve_relative(
-    anchor: DateTime<Utc>,
-    expression: &str,
-    timezone: &str,
-) -> Result<ResolvedDatetime, TruthError> {
-    resolve_relative_with_options(anchor, expression, timezone, &ResolveOptions::default())
+/// Every instant a local wall-clock time reads as: both readings of an
+/// ambiguous (fall-back) time, earlier first, or the time shifted forward past
+/// a spring-forward gap.
+pub(crate) fn local_readings(tz: &Tz, naive: chrono::NaiveDateTime) -> Vec<DateTime<Utc>> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => vec![dt.with_timezone(&Utc)],
+        LocalResult::Ambiguous(earlier, later) => {
+            vec![earlier.with_timezone(&Utc), later.with_timezone(&Utc)]
+        }
+        LocalResult::None => local_to_utc(tz, naive).into_iter().collect(),
+    }
 }
 
-/// Resolve a relative time expression to an absolute datetime with options.
-///
-/// # Arguments
-///
-/// * `anchor` — The reference "now" instant (typically `Utc::now()`)
-/// * `expression` — A time expression (see grammar below)
-/// * `timezone` — An IANA timezone name for interpreting local-time expressions
-/// * `options` — Resolution options (week start day, etc.)
-///
-/// # Supported Expressions
-///
-/// **Anchored**: `"now"`, `"today"`, `"tomorrow"`, `"yesterday"`
-///
-/// **Weekday-relative**: `"next Monday"`, `"this Friday"`, `"last Wednesday"`
-///
-/// **Time-of-day**: `"morning"` (09:00), `"noon"` (12:00), `"afternoon"` (13:00),
-/// `"evening"` (18:00), `"night"` (21:00), `"midnight"` (00:00),
-/// `"end of day"` / `"eob"` (17:00), `"start of business"` / `"sob"` (09:00), `"lunch"` (12:00)
-///
-/// **Explicit time**: `"2pm"`, `"2:30pm"`, `"14:00"`, `"14:30"`
-///
-/// **Offset durations**: `"+2h"`, `"-30m"`, `"in 2 hours"`, `"30 minutes ago"`,
-/// `"a week from now"`
-///
-/// **Combined**: `"next Tuesday at 2pm"`, `"tomorrow morning"`,
-/// `"next Friday at 10:30am"`
-///
-/// **Period boundaries**: `"start of week"`, `"end of month"`, `"start of quarter"`,
-/// `"next week"`, `"last month"`, `"next year"`
-///
-/// **Compound periods**: `"start of last week"`, `"end of next month"`,
-/// `"start of next quarter"`, `"end of last year"`
+// ── adjust_timestamp ────────────────────────────────────────────────────────
+
+/// The result of adjusting a timestamp by a duration.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AdjustedTimestamp {
+    /// The original datetime (echoed back).
+    pub original: String,
+    /// The adjusted datetime in UTC (RFC 3339).
+    pub adjusted_utc: String,
+    /// The adjusted datetime in the source timezone (RFC 3339 with offset).
+    pub adjusted_local: String,
+    /// The normalized adjustment applied (e.g., "+2h30m").
+    pub adjustment_applied: String,
+    /// The IANA timezone of `adjusted_local`.
+    pub timezone: Cow<'static, str>,
+    /// Extra renderings of `adjusted_local`, filled by [`AdjustedTimestamp::with_renderings`].
+    pub renderings: Option<Renderings>,
+}
+
+/// A signed calendar-aware duration: weeks and days move the local calendar date
+/// (keeping wall-clock time across DST), while hours, minutes, and seconds are
+/// exact elapsed time.
 ///
-/// **Ordinal dates**: `"first Monday of March"`, `"last Friday of the month"`,
-/// `"third Tuesday of March 2026"`
+/// This is the typed form of adjustment strings like `"+1d2h30m"`. It parses
+/// from compact (`"90m"`, `"+1d2h30m"`) and ISO 8601 (`"PT1H30M"`) text via
+/// [`FromStr`](std::str::FromStr). That is more lenient than
+/// [`adjust_timestamp`], which requires a sign and the compact form; to
+/// validate an adjustment up front, use [`Duration::parse_adjustment`],
+/// which accepts exactly what [`adjust_timestamp`] does.
 ///
-/// **Passthrough**: Any valid RFC 3339 or ISO 8601 date string
+/// # Examples
 ///
-/// # Errors
+/// ```
+/// use truth_engine::temporal::{Duration, DurationFormat};
 ///
-/// Returns [`TruthError::InvalidExpression`] if the expression cannot be parsed
-/// deterministically. This function **never guesses** — it returns an error for
-/// any ambiguous input.
-pub fn resolve_relative_with_options(
-    anchor: DateTime<Utc>,
-    expression: &str,
-    timezone: &str,
-    options: &ResolveOptions,
-) -> Result<ResolvedDatetime, TruthError> {
-    let tz = parse_timezone(timezone)?;
-    let local_anchor = anchor.with_timezone(&tz);
-    let ws = options.week_start;
-
-    // Normalize: trim, lowercase, strip articles
-    let normalized = normalize_expression(expression);
-
-    // Try each parser in order of specificity
-    let resolved_local = try_passthrough_rfc3339(&normalized)
-        .map(|dt| dt.with_timezone(&tz))
-        .or_else(|| try_passthrough_iso_date(&normalized, &tz))
-        .or_else(|| try_anchored(&normalized, &local_anchor, &tz))
-        .or_else(|| try_combined_weekday_time(&normalized, &local_anchor, &tz))
-        .or_else(|| try_combined_anchor_time(&normalized, &local_anchor, &tz))
-        .or_else(|| try_weekday_relative(&normalized, &local_anchor, &tz))
-        .or_else(|| try_compound_period(&normalized, &local_anchor, &tz, ws))
-        .or_else(|| try_period_boundary(&normalized, &local_anchor, &tz, ws))
-        .or_else(|| try_period_relative(&normalized, &local_anchor, &tz, ws))
-        .or_else(|| try_ordinal_date(&normalized, &local_anchor, &tz))
-        .or_else(|| try_natural_offset(&normalized, &anchor))
-        .or_else(|| try_duration_offset(&normalized, &anchor))
-        .or_else(|| try_time_of_day_named(&normalized, &local_anchor, &tz))
-        .or_else(|| try_explicit_time(&normalized, &local_anchor, &tz))
-        .ok_or_else(|| {
-            TruthError::InvalidExpression(format!(
-                "cannot parse expression: '{}'",
-                expression.trim()
-            ))
-        })?;
-
-    let resolved_utc = resolved_local.with_timezone(&Utc);
-    let interpretation = format_interpretation(&resolved_local);
-
-    Ok(ResolvedDatetime {
-        resolved_utc: resolved_utc.to_rfc3339(),
-        resolved_local: resolved_local.to_rfc3339(),
-        timezone: timezone.to_string(),
-        interpretation,
-    })
-}
-
-// ── Internal helpers ────────────────────────────────────────────────────────
-
-/// Parse an RFC 3339 datetime string into `DateTime<Utc>`.
-fn parse_rfc3339(s: &str) -> Result<DateTime<Utc>, TruthError> {
-    DateTime::parse_from_rfc3339(s)
-        .map(|dt| dt.with_timezone(&Utc))
-        .map_err(|e| TruthError::InvalidDatetime(format!("'{}': {}", s, e)))
-}
-
-/// Parse an IANA timezone string into `Tz`.
-fn parse_timezone(s: &str) -> Result<Tz, TruthError> {
-    s.parse::<Tz>()
-        .map_err(|_| TruthError::InvalidTimezone(format!("'{}'", s)))
-}
-
-/// Determine if DST is active for a datetime in a timezone.
-fn is_dst_active<T: TimeZone>(dt: &DateTime<T>, tz: &Tz) -> bool {
-    // Compare January 1 offset (winter / standard) with the current offset.
-    // If they differ, DST is active.
-    let utc = dt.with_timezone(&Utc);
-    let year = utc.year();
-
-    let jan1 = Utc
-        .with_ymd_and_hms(year, 1, 1, 12, 0, 0)
-        .single()
-        .unwrap_or(utc);
-    let jan1_local = jan1.with_timezone(tz);
-
-    let current_offset = dt.offset().fix().local_minus_utc();
-    let jan_offset = jan1_local.offset().fix().local_minus_utc();
-
-    current_offset != jan_offset
-}
-
-/// Format the UTC offset as a string (e.g., "-05:00", "+09:00").
-fn format_utc_offset<T: TimeZone>(dt: &DateTime<T>) -> String {
-    let offset_secs = dt.offset().fix().local_minus_utc();
-    let sign = if offset_secs >= 0 { "+" } else { "-" };
-    let abs_secs = offset_secs.unsigned_abs();
-    let hours = abs_secs / 3600;
-    let minutes = (abs_secs % 3600) / 60;
-    format!("{sign}{hours:02}:{minutes:02}")
+/// let d: Duration = "90m".parse().unwrap();
+/// assert_eq!(d.normalized().to_string(), "+1h30m");
+/// assert_eq!(d.format(DurationFormat::Iso8601), "PT90M");
+/// assert_eq!("-P1DT2H".parse::<Duration>().unwrap().to_string(), "-1d2h");
+/// // Adjustments need a sign:
+/// assert!(Duration::parse_adjustment("90m").is_err());
+/// assert!(Duration::parse_adjustment("+90m").is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Duration {
+    /// Whether the duration moves backward in time.
+    pub negative: bool,
+    /// Weeks (7 calendar days each).
+    pub weeks: i64,
+    /// Calendar days.
+    pub days: i64,
+    /// Hours.
+    pub hours: i64,
+    /// Minutes.
+    pub minutes: i64,
+    /// Seconds.
+    pub seconds: i64,
+    /// Working time, from `bh` and `bm` components, in minutes. It is
+    /// counted only while the business calendar is open, after the other
+    /// components are applied.
+    pub business_minutes: i64,
 }
 
-/// Format a human-readable duration string.
-fn format_human_duration(days: i64, hours: i64, minutes: i64, seconds: i64) -> String {
-    let mut parts = Vec::new();
-    if days > 0 {
-        parts.push(format!("{} day{}", days, if days == 1 { "" } else { "s" }));
-    }
-    if hours > 0 {
-        parts.push(format!(
-            "{} hour{}",
-            hours,
-            if hours == 1 { "" } else { "s" }
-        ));
+impl Duration {
+    /// An exact duration of `total_seconds`, split into hours, minutes, and seconds.
+    pub fn from_seconds(total_seconds: i64) -> Self {
+        let abs = total_seconds.unsigned_abs() as i64;
+        Self {
+            negative: total_seconds < 0,
+            hours: abs / 3600,
+            minutes: (abs % 3600) / 60,
+            seconds: abs % 60,
+            ..Default::default()
+        }
     }
-    if minutes > 0 {
-        parts.push(format!(
-            "{} minute{}",
-            minutes,
-            if minutes == 1 { "" } else { "s" }
-        ));
+
+    /// Signed number of calendar days (weeks included).
+    pub fn calendar_days(&self) -> i64 {
+        self.sign() * (self.weeks * 7 + self.days)
     }
-    if seconds > 0 || parts.is_empty() {
-        parts.push(format!(
-            "{} second{}",
-            seconds,
-            if seconds == 1 { "" } else { "s" }
-        ));
+
+    /// Signed number of exact seconds from the hour/minute/second components.
+    pub fn clock_seconds(&self) -> i64 {
+        self.sign() * (self.hours * 3600 + self.minutes * 60 + self.seconds)
     }
-    parts.join(", ")
-}
 
-/// Parse a duration adjustment string (e.g., "+2h", "-1d30m", "+1w2d").
-fn parse_duration_string(s: &str) -> Result<ParsedDuration, TruthError> {
-    let s = s.trim();
-    if s.is_empty() {
-        return Err(TruthError::InvalidDuration("empty duration".to_string()));
+    /// Signed number of working seconds from the business components.
+    pub fn business_seconds(&self) -> i64 {
+        self.sign() * self.business_minutes * 60
     }
 
-    let (sign, rest) = match s.as_bytes().first() {
-        Some(b'+') => (1i64, &s[1..]),
-        Some(b'-') => (-1i64, &s[1..]),
-        _ => {
+    /// Parse an adjustment the way [`adjust_timestamp`] does: a required `+`
+    /// or `-`, then compact components (`"+1d2h30m"`, `"-1.5h"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidDuration`] if the sign is missing, the
+    /// duration is in ISO 8601 form, or the components do not parse. When the
+    /// input is a valid [`Duration`] in another form (`"90m"`, `"PT1H30M"`),
+    /// the message names the adjustment to write instead.
+    pub fn parse_adjustment(s: &str) -> Result<Self, TruthError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(TruthError::InvalidDuration("empty duration".to_string()));
+        }
+        let suggestion = || match s.parse::<Duration>() {
+            Ok(d) => format!(" (write '{d}')"),
+            Err(_) => String::new(),
+        };
+        let (negative, rest) = match s.as_bytes()[0] {
+            b'+' => (false, &s[1..]),
+            b'-' => (true, &s[1..]),
+            _ => {
+                return Err(TruthError::InvalidDuration(format!(
+                    "duration must start with '+' or '-': '{s}'{}",
+                    suggestion()
+                )))
+            }
+        };
+        if rest.starts_with(['P', 'p']) {
             return Err(TruthError::InvalidDuration(format!(
-                "duration must start with '+' or '-': '{s}'"
+                "ISO 8601 durations are not accepted as adjustments: '{s}'{}",
+                suggestion()
             )));
         }
-    };
+        Self::parse_compact(s, rest, negative)
+    }
+
+    /// The same duration with seconds carried into minutes and minutes into
+    /// hours (`"+90m"` → `"+1h30m"`). Hours are not carried into days, which
+    /// move the calendar date rather than add elapsed time.
+    pub fn normalized(&self) -> Self {
+        let clock = self.hours * 3600 + self.minutes * 60 + self.seconds;
+        Self {
+            weeks: self.weeks,
+            days: self.days,
+            business_minutes: self.business_minutes,
+            ..Self::from_seconds(if self.negative { -clock } else { clock })
+        }
+    }
 
-    if rest.is_empty() {
-        return Err(TruthError::InvalidDuration(format!(
-            "duration has no components: '{s}'"
-        )));
+    /// Whether every component is zero.
+    pub fn is_zero(&self) -> bool {
+        self.calendar_days() == 0 && self.clock_seconds() == 0 && self.business_minutes == 0
+    }
+
+    /// Write the duration in `format`. Components are written as they are;
+    /// call [`normalized`](Self::normalized) first to carry overflow.
+    pub fn format(&self, format: DurationFormat) -> String {
+        match format {
+            DurationFormat::Compact => {
+                let sign = if self.negative { "-" } else { "+" };
+                let mut out = String::from(sign);
+                for (n, unit) in [
+                    (self.weeks, 'w'),
+                    (self.days, 'd'),
+                    (self.hours, 'h'),
+                    (self.minutes, 'm'),
+                    (self.seconds, 's'),
+                ] {
+                    if n != 0 {
+                        out.push_str(&format!("{n}{unit}"));
+                    }
+                }
+                for (n, unit) in [
+                    (self.business_minutes / 60, "bh"),
+                    (self.business_minutes % 60, "bm"),
+                ] {
+                    if n != 0 {
+                        out.push_str(&format!("{n}{unit}"));
+                    }
+                }
+                if out.len() == 1 {
+                    out.push_str("0s");
+                }
+                out
+            }
+            DurationFormat::Iso8601 => {
+                let mut out = String::from(if self.negative { "-P" } else { "P" });
+                let days = self.weeks * 7 + self.days;
+                if self.days == 0 && self.weeks != 0 {
+                    out.push_str(&format!("{}W", self.weeks));
+                } else if days != 0 {
+                    out.push_str(&format!("{days}D"));
+                }
+                if self.hours != 0 || self.minutes != 0 || self.seconds != 0 || days == 0 {
+                    out.push('T');
+                    for (n, unit) in [(self.hours, 'H'), (self.minutes, 'M'), (self.seconds, 'S')] {
+                        if n != 0 {
+                            out.push_str(&format!("{n}{unit}"));
+                        }
+                    }
+                    if out.ends_with('T') {
+                        out.push_str("0S");
+                    }
+                }
+                out
+            }
+            DurationFormat::Human => {
+                let mut parts = Vec::new();
+                if self.weeks > 0 {
+                    let plural = if self.weeks == 1 { "" } else { "s" };
+                    parts.push(format!("{} week{plural}", self.weeks));
+                }
+                if self.days > 0
+                    || self.hours > 0
+                    || self.minutes > 0
+                    || self.seconds > 0
+                    || (parts.is_empty() && self.business_minutes == 0)
+                {
+                    let rest =
+                        format_human_duration(self.days, self.hours, self.minutes, self.seconds);
+                    parts.push(rest);
+                }
+                for (n, unit) in [
+                    (self.business_minutes / 60, "business hour"),
+                    (self.business_minutes % 60, "business minute"),
+                ] {
+                    if n > 0 {
+                        let plural = if n == 1 { "" } else { "s" };
+                        parts.push(format!("{n} {unit}{plural}"));
+                    }
+                }
+                let text = parts.join(", ");
+                if self.negative && !self.is_zero() {
+                    format!("minus {text}")
+                } else {
+                    text
+                }
+            }
+        }
     }
 
-    let mut parsed = ParsedDuration {
-        sign,
-        ..Default::default()
-    };
+    fn sign(&self) -> i64 {
+        if self.negative {
+            -1
+        } else {
+            1
+        }
+    }
 
-    let mut num_buf = String::new();
-    let mut found_any = false;
+    /// Compact components ("1d2h30m") after the sign; `s` is the whole input,
+    /// for messages.
+    fn parse_compact(s: &str, rest: &str, negative: bool) -> Result<Self, TruthError> {
+        if rest.is_empty() {
+            return Err(TruthError::InvalidDuration(format!(
+                "duration has no components: '{s}'"
+            )));
+        }
 
-    for ch in rest.chars() {
-        if ch.is_ascii_digit() {
-            num_buf.push(ch);
-        } else {
+        let mut parsed = Duration {
+            negative,
+            ..Default::default()
+        };
+        let mut num_buf = String::new();
+        let mut chars = rest.chars();
+        while let Some(ch) = chars.next() {
+            if ch.is_ascii_digit() || ch == '.' {
+                num_buf.push(ch);
+                continue;
+            }
             if num_buf.is_empty() {
                 return Err(TruthError::InvalidDuration(format!(
                     "expected number before '{ch}' in '{s}'"
                 )));
             }
-            let n: i64 = num_buf
-                .parse()
-                .map_err(|_| TruthError::InvalidDuration(format!("invalid number in '{s}'")))?;
+            let amount = parse_decimal(&num_buf)
+                .ok_or_else(|| TruthError::InvalidDuration(format!("invalid number in '{s}'")))?;
             num_buf.clear();
-            found_any = true;
+            if ch.eq_ignore_ascii_case(&'b') {
+                // Working time: "bh" or "bm", rounded to the minute.
+                let (whole, fraction) = amount;
+                let per_unit = match chars.next().map(|c| c.to_ascii_lowercase()) {
+                    Some('h') => 60,
+                    Some('m') => 1,
+                    _ => {
+                        return Err(TruthError::InvalidDuration(format!(
+                            "expected 'bh' or 'bm' in '{s}'"
+                        )))
+                    }
+                };
+                parsed.business_minutes += whole * per_unit + fraction.of(per_unit);
+                continue;
+            }
+            if !parsed.add(amount, ch.to_ascii_lowercase()) {
+                return Err(TruthError::InvalidDuration(format!(
+                    "unknown unit '{ch}' in '{s}'"
+                )));
+            }
+        }
 
-            match ch {
-                'w' | 'W' => parsed.weeks += n,
-                'd' | 'D' => parsed.days += n,
-                'h' | 'H' => parsed.hours += n,
-                'm' | 'M' => parsed.minutes += n,
-                's' | 'S' => parsed.seconds += n,
-                _ => {
-                    return Err(TruthError::InvalidDuration(format!(
-                        "unknown unit '{ch}' in '{s}'"
-                    )));
-                }
+        // Trailing number without unit
+        if !num_buf.is_empty() {
+            return Err(TruthError::InvalidDuration(format!(
+                "number without unit at end of '{s}'"
+            )));
+        }
+        Ok(parsed)
+    }
+
+    /// ISO 8601 components ("P1DT2H", "PT1.5H", "P2W") after the sign.
+    /// Years and months have no fixed length and are rejected.
+    fn parse_iso(s: &str, rest: &str, negative: bool) -> Result<Self, TruthError> {
+        let bad = |why: &str| TruthError::InvalidDuration(format!("{why} in '{s}'"));
+        let body = rest
+            .strip_prefix(['P', 'p'])
+            .ok_or_else(|| bad("expected 'P'"))?;
+        let mut parsed = Duration {
+            negative,
+            ..Default::default()
+        };
+        let mut num_buf = String::new();
+        let mut in_time = false;
+        let mut found_any = false;
+        let mut found_time = false;
+        for ch in body.chars() {
+            let unit = ch.to_ascii_uppercase();
+            if ch.is_ascii_digit() || ch == '.' || ch == ',' {
+                num_buf.push(if ch == ',' { '.' } else { ch });
+                continue;
+            }
+            if unit == 'T' && num_buf.is_empty() && !in_time {
+                in_time = true;
+                continue;
             }
+            if num_buf.is_empty() {
+                return Err(bad(&format!("expected number before '{ch}'")));
+            }
+            let amount = parse_decimal(&num_buf).ok_or_else(|| bad("invalid number"))?;
+            num_buf.clear();
+            let unit = match (unit, in_time) {
+                ('W', false) => 'w',
+                ('D', false) => 'd',
+                ('H', true) => 'h',
+                ('M', true) => 'm',
+                ('S', true) => 's',
+                ('Y' | 'M', false) => {
+                    return Err(bad("years and months have no fixed length"));
+                }
+                _ => return Err(bad(&format!("unexpected '{ch}'"))),
+            };
+            parsed.add(amount, unit);
+            found_any = true;
+            found_time |= in_time;
+        }
+        if !num_buf.is_empty() {
+            return Err(bad("number without unit at end"));
+        }
+        if !found_any || (in_time && !found_time) {
+            return Err(bad("no components"));
         }
+        Ok(parsed)
     }
 
-    // Trailing number without unit
-    if !num_buf.is_empty() {
-        return Err(TruthError::InvalidDuration(format!(
-            "number without unit at end of '{s}'"
-        )));
+    /// Add `amount` of `unit` ('w', 'd', 'h', 'm', or 's'), carrying its
+    /// fractional part into smaller units (1.5d → 1d12h). Returns `false` for
+    /// an unknown unit.
+    fn add(&mut self, (whole, fraction): (i64, Fraction), unit: char) -> bool {
+        let unit_seconds = match unit {
+            'w' => {
+                self.weeks += whole;
+                604_800
+            }
+            'd' => {
+                self.days += whole;
+                86_400
+            }
+            'h' => {
+                self.hours += whole;
+                3_600
+            }
+            'm' => {
+                self.minutes += whole;
+                60
+            }
+            's' => {
+                self.seconds += whole;
+                1
+            }
+            _ => return false,
+        };
+        let mut carry = fraction.of(unit_seconds);
+        self.days += carry / 86_400;
+        carry %= 86_400;
+        self.hours += carry / 3_600;
+        carry %= 3_600;
+        self.minutes += carry / 60;
+        self.seconds += carry % 60;
+        true
     }
+}
 
-    if !found_any {
-        return Err(TruthError::InvalidDuration(format!(
-            "no valid components in '{s}'"
-        )));
+impl std::str::FromStr for Duration {
+    type Err = TruthError;
+
+    /// Compact components with an optional sign (`"90m"`, `"+1d2h30m"`,
+    /// `"-1.5h"`), or ISO 8601 (`"PT1H30M"`, `"P1DT12H"`, `"-P2W"`).
+    ///
+    /// Not every duration that parses is an [`adjust_timestamp`] argument:
+    /// check those with [`Duration::parse_adjustment`].
+    fn from_str(s: &str) -> Result<Self, TruthError> {
+        let s = s.trim();
+        let (negative, rest) = match s.as_bytes().first() {
+            None => return Err(TruthError::InvalidDuration("empty duration".to_string())),
+            Some(b'+') => (false, &s[1..]),
+            Some(b'-') => (true, &s[1..]),
+            Some(_) => (false, s),
+        };
+        if rest.starts_with(['P', 'p']) {
+            Self::parse_iso(s, rest, negative)
+        } else {
+            Self::parse_compact(s, rest, negative)
+        }
     }
-
-    Ok(parsed)
 }
 
-/// Normalize a parsed duration back to a string like "+1d2h30m".
-fn normalize_duration_string(d: &ParsedDuration) -> String {
-    let sign = if d.sign >= 0 { "+" } else { "-" };
-    let mut parts = String::from(sign);
-    if d.weeks != 0 {
-        parts.push_str(&format!("{}w", d.weeks));
-    }
-    if d.days != 0 {
-        parts.push_str(&format!("{}d", d.days));
-    }
-    if d.hours != 0 {
-        parts.push_str(&format!("{}h", d.hours));
-    }
-    if d.minutes != 0 {
-        parts.push_str(&format!("{}m", d.minutes));
-    }
-    if d.seconds != 0 {
-        parts.push_str(&format!("{}s", d.seconds));
-    }
-    if parts.len() == 1 {
-        // Only sign, no components (shouldn't happen after parsing, but defensive)
-        parts.push_str("0s");
+impl std::fmt::Display for Duration {
+    /// The compact form, `"+1d2h30m"`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.format(DurationFormat::Compact))
     }
-    parts
 }
 
-// ── resolve_relative expression parsers ─────────────────────────────────────
+/// How [`Duration::format`] writes a duration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DurationFormat {
+    /// `"+1d2h30m"`, as [`adjust_timestamp`] reports it.
+    #[default]
+    Compact,
+    /// ISO 8601: `"P1DT2H30M"`, `"P2W"`, `"-PT45S"`. Working time has no
+    /// ISO 8601 form and is left out.
+    Iso8601,
+    /// `"1 day, 2 hours, 30 minutes"`; negative durations start with "minus".
+    Human,
+}
 
-/// Normalize expression: trim, lowercase, strip common articles (but not "a"/"an" at start
-/// since those are meaningful for patterns like "a week from now").
-fn normalize_expression(s: &str) -> String {
-    let s = s.trim().to_lowercase();
-    // Strip articles in the middle: "the", "a", "an"
-    let s = s
-        .replace(" the ", " ")
-        .replace(" a ", " ")
-        .replace(" an ", " ");
-    // Strip leading "the " only (not "a "/"an " — they matter for "a week from now")
-    let s = s.strip_prefix("the ").unwrap_or(&s).to_string();
-    // Collapse multiple spaces
-    let mut result = String::new();
-    let mut prev_space = false;
-    for ch in s.chars() {
-        if ch == ' ' {
-            if !prev_space {
-                result.push(' ');
-            }
-            prev_space = true;
-        } else {
-            result.push(ch);
-            prev_space = false;
-        }
-    }
-    result.trim().to_string()
+/// Adjust a timestamp by adding or subtracting a duration.
+///
+/// # Arguments
+///
+/// * `datetime` — An RFC 3339 datetime string
+/// * `adjustment` — A duration string (e.g., `"+2h"`, `"-30m"`, `"+1d2h30m"`, `"+1w"`)
+/// * `timezone` — An IANA timezone name for interpreting day-level adjustments
+///
+/// # Duration Format
+///
+/// Must start with `+` or `-`, followed by one or more components:
+/// - `Nw` — weeks
+/// - `Nd` — days (timezone-aware: same wall-clock time, not +24h across DST)
+/// - `Nh` — hours
+/// - `Nm` — minutes
+/// - `Ns` — seconds
+/// - `Nbh`, `Nbm` — working hours and minutes, counted only during working
+///   hours (09:00–17:00, Monday to Friday, in `timezone`), after the other
+///   components; [`adjust_timestamp_with_calendar`] counts them on a
+///   business calendar with holidays and closures
+///
+/// Components can be combined: `+1d2h30m`, `-2w3d`. A component may be
+/// fractional (`+1.5h`, `+2.5d`); the fraction carries into smaller units
+/// (`+1h30m`, `+2d12h`) and is rounded to the nearest second.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the datetime cannot be parsed,
+/// [`TruthError::InvalidTimezone`] if the timezone is invalid, or
+/// [`TruthError::InvalidDuration`] if the adjustment string cannot be parsed
+/// or its working time finds no working hours within ten years.
+pub fn adjust_timestamp(
+    datetime: &str,
+    adjustment: &str,
+    timezone: &str,
+) -> Result<AdjustedTimestamp, TruthError> {
+    adjust_timestamp_with_mode(datetime, adjustment, timezone, InputMode::Strict)
 }
 
-/// Try to parse as an RFC 3339 passthrough.
-fn try_passthrough_rfc3339(s: &str) -> Option<DateTime<Utc>> {
-    DateTime::parse_from_rfc3339(s)
-        .map(|dt| dt.with_timezone(&Utc))
-        .ok()
+/// [`adjust_timestamp`] with a choice of [`InputMode`] for the datetime input.
+pub fn adjust_timestamp_with_mode(
+    datetime: &str,
+    adjustment: &str,
+    timezone: &str,
+    input: InputMode,
+) -> Result<AdjustedTimestamp, TruthError> {
+    let dt = parse_datetime(datetime, input)?;
+    let tz = parse_timezone(timezone)?;
+    adjust_parsed(datetime, dt, adjustment, &tz, None)
 }
 
-/// Try to parse as an ISO 8601 date (YYYY-MM-DD) → start of day in timezone.
-fn try_passthrough_iso_date(s: &str, tz: &Tz) -> Option<DateTime<Tz>> {
-    NaiveDate::parse_from_str(s, "%Y-%m-%d")
-        .ok()
-        .and_then(|date| {
-            let naive = date.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        })
+/// [`adjust_timestamp_with_mode`] with working time (`bh`, `bm`) counted on
+/// `calendar`, skipping its holidays and closures. The result is in the
+/// calendar's timezone.
+///
+/// # Errors
+///
+/// As for [`adjust_timestamp`].
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::business::BusinessCalendar;
+/// use truth_engine::temporal::{adjust_timestamp_with_calendar, InputMode};
+///
+/// let calendar = BusinessCalendar {
+///     timezone: "Europe/Berlin".to_string(),
+///     holidays: [chrono::NaiveDate::from_ymd_opt(2026, 4, 6).unwrap()].into(),
+///     ..BusinessCalendar::default()
+/// };
+/// // Thursday 15:00 + 6 working hours: 2 on Thursday, 4 on Friday.
+/// let adjusted = adjust_timestamp_with_calendar(
+///     "2026-04-02T13:00:00Z",
+///     "+6bh",
+///     &calendar,
+///     InputMode::Strict,
+/// )
+/// .unwrap();
+/// assert_eq!(adjusted.adjusted_local, "2026-04-03T13:00:00+02:00");
+/// let after_easter = adjust_timestamp_with_calendar(
+///     "2026-04-03T13:00:00Z",
+///     "+6bh",
+///     &calendar,
+///     InputMode::Strict,
+/// )
+/// .unwrap();
+/// // Friday 15:00 + 6 working hours skips the weekend and the Monday holiday.
+/// assert_eq!(after_easter.adjusted_local, "2026-04-07T13:00:00+02:00");
+/// ```
+pub fn adjust_timestamp_with_calendar(
+    datetime: &str,
+    adjustment: &str,
+    calendar: &BusinessCalendar,
+    input: InputMode,
+) -> Result<AdjustedTimestamp, TruthError> {
+    let dt = parse_datetime(datetime, input)?;
+    let tz = parse_timezone(&calendar.timezone)?;
+    adjust_parsed(datetime, dt, adjustment, &tz, Some(calendar))
 }
 
-/// Try anchored references: "now", "today", "tomorrow", "yesterday".
-fn try_anchored(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
-    match s {
-        "now" => Some(*local),
-        "today" => make_local_start_of_day(local, tz),
-        "tomorrow" => {
-            let next = local.date_naive().succ_opt()?;
-            let naive = next.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "yesterday" => {
-            let prev = local.date_naive().pred_opt()?;
-            let naive = prev.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        _ => None,
+/// [`adjust_timestamp_with_mode`] once `datetime` has been parsed as `dt`,
+/// with working time counted on `calendar` (default working hours in `tz`
+/// when `None`).
+pub(crate) fn adjust_parsed(
+    datetime: &str,
+    dt: DateTime<Utc>,
+    adjustment: &str,
+    tz: &Tz,
+    calendar: Option<&BusinessCalendar>,
+) -> Result<AdjustedTimestamp, TruthError> {
+    let parsed = Duration::parse_adjustment(adjustment)?;
+
+    let adjusted_local = adjust_in(dt.with_timezone(tz), &parsed, calendar)?;
+    let adjusted_utc = adjusted_local.with_timezone(&Utc);
+    let normalized = parsed.to_string();
+
+    Ok(AdjustedTimestamp {
+        original: datetime.to_string(),
+        adjusted_utc: adjusted_utc.to_rfc3339(),
+        adjusted_local: adjusted_local.to_rfc3339(),
+        adjustment_applied: normalized,
+        timezone: tz.name().into(),
+        renderings: None,
+    })
+}
+
+/// Typed variant of [`adjust_timestamp`]: add a [`Duration`] to a zoned datetime.
+///
+/// Weeks and days move the local date at the same wall-clock time; hours,
+/// minutes, and seconds are added as exact elapsed time; then working time
+/// is counted through 09:00–17:00, Monday to Friday, in the datetime's zone.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the day adjustment lands on a
+/// local time that is ambiguous or does not exist, or
+/// [`TruthError::InvalidDuration`] if working time finds no working hours
+/// within ten years.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeZone;
+/// use chrono_tz::America::New_York;
+/// use truth_engine::temporal::{adjust_timestamp_dt, Duration};
+///
+/// // Across spring-forward: still 09:00 local the next day.
+/// let start = New_York.with_ymd_and_hms(2026, 3, 7, 9, 0, 0).unwrap();
+/// let next = adjust_timestamp_dt(start, &Duration { days: 1, ..Default::default() }).unwrap();
+/// assert_eq!(next, New_York.with_ymd_and_hms(2026, 3, 8, 9, 0, 0).unwrap());
+/// ```
+pub fn adjust_timestamp_dt(
+    datetime: DateTime<Tz>,
+    adjustment: &Duration,
+) -> Result<DateTime<Tz>, TruthError> {
+    adjust_in(datetime, adjustment, None)
+}
+
+/// [`adjust_timestamp_dt`] with working time counted on `calendar`, or on
+/// default working hours in the datetime's zone when `None`.
+fn adjust_in(
+    datetime: DateTime<Tz>,
+    adjustment: &Duration,
+    calendar: Option<&BusinessCalendar>,
+) -> Result<DateTime<Tz>, TruthError> {
+    let tz = datetime.timezone();
+
+    // For day/week adjustments, we work in local time to preserve wall-clock time
+    // across DST transitions. For sub-day adjustments, we work in UTC.
+    let day_adjusted = if adjustment.calendar_days() != 0 {
+        let new_date = datetime.date_naive() + chrono::Duration::days(adjustment.calendar_days());
+        tz.from_local_datetime(&new_date.and_time(datetime.time()))
+            .single()
+            .ok_or_else(|| {
+                TruthError::InvalidDatetime(
+                    "ambiguous or nonexistent local time after day adjustment".to_string(),
+                )
+            })?
+    } else {
+        datetime
+    };
+    let clock_adjusted = day_adjusted + chrono::Duration::seconds(adjustment.clock_seconds());
+    if adjustment.business_minutes == 0 {
+        return Ok(clock_adjusted);
+    }
+    let default_calendar;
+    let calendar = match calendar {
+        Some(calendar) => calendar,
+        None => {
+            default_calendar = BusinessCalendar {
+                timezone: tz.name().to_string(),
+                ..BusinessCalendar::default()
+            };
+            &default_calendar
+        }
+    };
+    let working = chrono::Duration::seconds(adjustment.business_seconds());
+    let adjusted = calendar
+        .add_business_time(clock_adjusted.with_timezone(&Utc), working)?
+        .ok_or_else(|| {
+            TruthError::InvalidDuration(format!(
+                "no working hours within ten years for '{adjustment}'"
+            ))
+        })?;
+    Ok(adjusted.with_timezone(&tz))
+}
+
+// ── round_timestamp ─────────────────────────────────────────────────────────
+
+/// The interval a timestamp is rounded to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum RoundingGranularity {
+    /// A number of minutes that evenly divides a day (e.g., 5, 15, 30, 60).
+    Minutes(u32),
+    /// Local midnight.
+    Day,
+}
+
+/// Which direction to round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum RoundingMode {
+    /// Round to the closer boundary; exact halfway rounds up.
+    #[default]
+    Nearest,
+    /// Round forward to the next boundary (unchanged if already on one).
+    Up,
+    /// Round back to the previous boundary (unchanged if already on one).
+    Down,
+}
+
+/// Round (snap) a timestamp to an interval boundary in a timezone.
+///
+/// Boundaries are computed on the local wall clock, so rounding to 60 minutes
+/// in `Asia/Kolkata` (UTC+5:30) snaps to local `:00`, and [`RoundingGranularity::Day`]
+/// snaps to local midnight. Inside a repeated (fall-back) hour, boundaries are
+/// the nearest instants reading that wall-clock time, so rounding never jumps
+/// to the other fold. The `adjustment_applied` field reports the signed shift
+/// (e.g., `"+3m"`, `"-7m30s"`).
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the datetime cannot be parsed,
+/// [`TruthError::InvalidTimezone`] if the timezone is invalid, or
+/// [`TruthError::InvalidDuration`] if a minute granularity is zero or does not
+/// evenly divide a day.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::{round_timestamp, RoundingGranularity, RoundingMode};
+///
+/// let result = round_timestamp(
+///     "2026-03-16T10:07:00Z",
+///     RoundingGranularity::Minutes(15),
+///     RoundingMode::Up,
+///     "UTC",
+/// )
+/// .unwrap();
+/// assert!(result.adjusted_utc.contains("10:15:00"));
+/// assert_eq!(result.adjustment_applied, "+8m");
+/// ```
+pub fn round_timestamp(
+    datetime: &str,
+    granularity: RoundingGranularity,
+    mode: RoundingMode,
+    timezone: &str,
+) -> Result<AdjustedTimestamp, TruthError> {
+    round_timestamp_with_mode(datetime, granularity, mode, timezone, InputMode::Strict)
+}
+
+/// [`round_timestamp`] with a choice of [`InputMode`] for the datetime input.
+pub fn round_timestamp_with_mode(
+    datetime: &str,
+    granularity: RoundingGranularity,
+    mode: RoundingMode,
+    timezone: &str,
+    input: InputMode,
+) -> Result<AdjustedTimestamp, TruthError> {
+    let dt = parse_datetime(datetime, input)?;
+    let tz = parse_timezone(timezone)?;
+    let rounded = round_timestamp_dt(dt.with_timezone(&tz), granularity, mode)?;
+
+    Ok(AdjustedTimestamp {
+        original: datetime.to_string(),
+        adjusted_utc: rounded.with_timezone(&Utc).to_rfc3339(),
+        adjusted_local: rounded.to_rfc3339(),
+        adjustment_applied: format_signed_seconds((rounded.with_timezone(&Utc) - dt).num_seconds()),
+        timezone: tz.name().into(),
+        renderings: None,
+    })
+}
+
+/// Typed variant of [`round_timestamp`]: snap a zoned datetime to a local boundary.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDuration`] if a minute granularity does not evenly
+/// divide a day.
+pub fn round_timestamp_dt(
+    datetime: DateTime<Tz>,
+    granularity: RoundingGranularity,
+    mode: RoundingMode,
+) -> Result<DateTime<Tz>, TruthError> {
+    let tz = datetime.timezone();
+    let dt = datetime.with_timezone(&Utc);
+    let local = datetime;
+
+    let step_secs: i64 = match granularity {
+        RoundingGranularity::Minutes(m) if m > 0 && 1440 % m == 0 => m as i64 * 60,
+        RoundingGranularity::Minutes(m) => {
+            return Err(TruthError::InvalidDuration(format!(
+                "rounding granularity must evenly divide a day, got {m} minutes"
+            )));
+        }
+        RoundingGranularity::Day => 86400,
+    };
+
+    // Work on the local wall clock: seconds since local midnight.
+    let date = local.date_naive();
+    let secs_of_day = local.time().num_seconds_from_midnight() as i64;
+    let floor_secs = secs_of_day - secs_of_day % step_secs;
+    let on_boundary = floor_secs == secs_of_day && local.nanosecond() == 0;
+
+    // Both boundaries at every reading, so inside a repeated (fall-back) hour
+    // they are taken from the fold `dt` is in rather than always the first.
+    let midnight = date.and_hms_opt(0, 0, 0).expect("midnight is valid");
+    let boundaries: Vec<DateTime<Utc>> = [floor_secs, floor_secs + step_secs]
+        .into_iter()
+        .flat_map(|secs| local_readings(&tz, midnight + chrono::Duration::seconds(secs)))
+        .collect();
+    let unresolved =
+        || TruthError::InvalidDatetime("cannot resolve rounded local time".to_string());
+
+    let rounded = if on_boundary {
+        dt
+    } else {
+        let down = boundaries
+            .iter()
+            .filter(|b| **b <= dt)
+            .max()
+            .copied()
+            .ok_or_else(unresolved)?;
+        let up = boundaries
+            .iter()
+            .filter(|b| **b > dt)
+            .min()
+            .copied()
+            .ok_or_else(unresolved)?;
+        match mode {
+            RoundingMode::Down => down,
+            RoundingMode::Up => up,
+            RoundingMode::Nearest => {
+                if dt - down < up - dt {
+                    down
+                } else {
+                    up
+                }
+            }
+        }
+    };
+
+    Ok(rounded.with_timezone(&tz))
+}
+
+// ── adjust_to_next ──────────────────────────────────────────────────────────
+
+/// Move a timestamp forward to the next occurrence of a wall-clock boundary.
+///
+/// Unlike [`resolve_relative`], the anchor is an arbitrary instant rather than
+/// "now": this answers "the next time it is 9am in Berlin after this instant".
+/// The result is always strictly after `datetime`.
+///
+/// # Supported Targets
+///
+/// - A time of day: `"09:00"`, `"9am"`, `"2:30pm"`, or a named time (`"noon"`, `"eob"`)
+/// - `"top of the hour"`
+/// - A weekday, optionally with a time: `"Monday"`, `"Monday 09:00"`, `"Friday at 5pm"`
+///   (a bare weekday means that day at 00:00)
+///
+/// Local times that do not exist on a given day (DST gaps) are shifted forward
+/// past the gap, and times that occur twice (fall-back) match at both
+/// readings.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the datetime cannot be parsed,
+/// [`TruthError::InvalidTimezone`] if the timezone is invalid, or
+/// [`TruthError::InvalidExpression`] if the target is not recognized.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::adjust_to_next;
+///
+/// // 10:00 UTC on Monday March 16 = 11:00 in Berlin → next 09:00 Berlin is Tuesday
+/// let result = adjust_to_next("2026-03-16T10:00:00Z", "09:00", "Europe/Berlin").unwrap();
+/// assert_eq!(result.adjusted_local, "2026-03-17T09:00:00+01:00");
+/// ```
+pub fn adjust_to_next(
+    datetime: &str,
+    target: &str,
+    timezone: &str,
+) -> Result<AdjustedTimestamp, TruthError> {
+    adjust_to_next_with_mode(datetime, target, timezone, InputMode::Strict)
+}
+
+/// [`adjust_to_next`] with a choice of [`InputMode`] for the datetime input.
+pub fn adjust_to_next_with_mode(
+    datetime: &str,
+    target: &str,
+    timezone: &str,
+    input: InputMode,
+) -> Result<AdjustedTimestamp, TruthError> {
+    let dt = parse_datetime(datetime, input)?;
+    let tz = parse_timezone(timezone)?;
+    let next = next_boundary(dt, target, &tz)?;
+
+    Ok(AdjustedTimestamp {
+        original: datetime.to_string(),
+        adjusted_utc: next.to_rfc3339(),
+        adjusted_local: next.with_timezone(&tz).to_rfc3339(),
+        adjustment_applied: format_signed_seconds((next - dt).num_seconds()),
+        timezone: tz.name().into(),
+        renderings: None,
+    })
+}
+
+/// The first instant strictly after `dt` matching an [`adjust_to_next`] target.
+fn next_boundary(dt: DateTime<Utc>, target: &str, tz: &Tz) -> Result<DateTime<Utc>, TruthError> {
+    let local = dt.with_timezone(tz);
+    let normalized = normalize_expression(target);
+
+    let invalid = || {
+        TruthError::InvalidExpression(format!("cannot parse target: '{}'", target.trim()).into())
+    };
+
+    // Every reading of a repeated (fall-back) hour is a candidate, so the
+    // second 01:00 follows 01:30 in the first.
+    let next = if matches!(normalized.as_str(), "top of hour" | "top of the hour") {
+        let hour_start = local
+            .date_naive()
+            .and_hms_opt(local.hour(), 0, 0)
+            .ok_or_else(invalid)?;
+        (0..=3)
+            .flat_map(|h| local_readings(tz, hour_start + chrono::Duration::hours(h)))
+            .filter(|candidate| *candidate > dt)
+            .min()
+            .ok_or_else(invalid)?
+    } else {
+        let (weekday, time) = parse_boundary_target(&normalized).ok_or_else(invalid)?;
+        (0..=8)
+            .map(|offset| local.date_naive() + chrono::Duration::days(offset))
+            .filter(|date| weekday.is_none_or(|wd| wd == date.weekday()))
+            .flat_map(|date| local_readings(tz, date.and_time(time)))
+            .find(|candidate| *candidate > dt)
+            .ok_or_else(invalid)?
+    };
+    Ok(next)
+}
+
+/// Parse an [`adjust_to_next`] target into an optional weekday and a time of day.
+fn parse_boundary_target(s: &str) -> Option<(Option<Weekday>, NaiveTime)> {
+    let time_of = |t: &str| {
+        let t = t.strip_prefix("at ").unwrap_or(t);
+        named_time_to_naive(t).or_else(|| parse_time_string(t))
+    };
+
+    if let Some(time) = time_of(s) {
+        return Some((None, time));
+    }
+
+    let (first, rest) = match s.split_once(' ') {
+        Some((first, rest)) => (first, Some(rest)),
+        None => (s, None),
+    };
+    let weekday = parse_weekday(first)?;
+    let time = match rest {
+        Some(rest) => time_of(rest)?,
+        None => NaiveTime::from_hms_opt(0, 0, 0)?,
+    };
+    Some((Some(weekday), time))
+}
+
+// ── resolve_relative ────────────────────────────────────────────────────────
+
+/// A word that lenient mode replaced before resolving an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TypoCorrection {
+    /// The word as it appeared (lowercased).
+    pub original: String,
+    /// The vocabulary word it was replaced with.
+    pub corrected: String,
+}
+
+/// The result of resolving a relative time expression.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ResolvedDatetime {
+    /// The resolved datetime in UTC (RFC 3339).
+    pub resolved_utc: String,
+    /// The resolved datetime in the given timezone (RFC 3339 with offset).
+    pub resolved_local: String,
+    /// The IANA timezone used for resolution.
+    pub timezone: Cow<'static, str>,
+    /// Human-readable interpretation (e.g., "Tuesday, February 24, 2026 at 2:00 PM EST"),
+    /// in [`ResolveOptions::locale`].
+    pub interpretation: String,
+    /// The timezone named inside the expression ("3pm Tokyo time" → "Asia/Tokyo"),
+    /// or the UTC offset written on its time ("14:00 +02:00" → "+02:00"), which the
+    /// expression was interpreted in before converting to `timezone`.
+    pub expression_timezone: Option<String>,
+    /// Typos corrected in lenient mode (empty when the expression parsed as written).
+    pub corrections: Vec<TypoCorrection>,
+    /// DST caveats in the output timezone (empty when no transition is near).
+    pub warnings: Vec<DstWarning>,
+    /// Extra renderings of `resolved_local`, filled by [`ResolvedDatetime::with_renderings`].
+    pub renderings: Option<Renderings>,
+    /// Whether the expression named a day, a moment, or a longer period.
+    pub granularity: ResolutionGranularity,
+    /// The week the result falls in, for week-based expressions ("next week",
+    /// "mid-week", "2026-W08"). Numbered per [`WeekScheme::Us`] when
+    /// [`ResolveOptions::week_start`] is Sunday, otherwise ISO 8601.
+    pub week: Option<WeekNumber>,
+    /// How the expression was read — normalization, typo corrections, and
+    /// each parser that matched, innermost first — when
+    /// [`ResolveOptions::explain`] is set.
+    pub trace: Option<Vec<TraceStep>>,
+}
+
+/// How precisely an expression pinned down a moment.
+///
+/// Only [`DateTime`](Self::DateTime) results carry a time the user asked for;
+/// the others resolve to a conventional instant (usually midnight) that
+/// callers should treat as all-day or as the start of a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ResolutionGranularity {
+    /// A calendar day without a time ("tomorrow", "march 3rd", "next business day").
+    Date,
+    /// A specific moment ("tomorrow at 3pm", "in 2 hours", "now").
+    DateTime,
+    /// A span longer than a day ("next week", "q3"); the result is its start.
+    Period,
+}
+
+/// What kind of DST caveat applies to a resolved datetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DstWarningKind {
+    /// A transition happens within 24 hours of the resolved instant.
+    NearTransition,
+    /// The local time occurs twice (clocks fell back); the earlier instant was used.
+    Ambiguous,
+    /// The requested local time may not have existed (clocks sprang forward) and
+    /// was moved forward past the gap.
+    Shifted,
+}
+
+/// A caveat about a DST transition near a resolved datetime, for the agent to relay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DstWarning {
+    /// The kind of caveat.
+    pub kind: DstWarningKind,
+    /// When the transition happens (RFC 3339, UTC).
+    pub transition_utc: String,
+    /// The UTC offset before the transition (e.g., "-05:00").
+    pub offset_before: String,
+    /// The UTC offset after the transition (e.g., "-04:00").
+    pub offset_after: String,
+    /// Human-readable explanation.
+    pub message: String,
+}
+
+// ── Internal helpers ────────────────────────────────────────────────────────
+
+/// Parse an IANA timezone string (or a known abbreviation) into `Tz`.
+///
+/// Known abbreviations are tried first, so every one follows its region's
+/// daylight saving time (`"PST"` in July is UTC-7). Ambiguous abbreviations
+/// return [`TruthError::AmbiguousTimezone`] listing every candidate zone:
+/// `"CST"` for the regions that share it, and `"EST"` and `"MST"` for the
+/// region against the tzdb zone fixed at standard time. Anything else is
+/// an IANA name, matched exactly and then case-insensitively
+/// (`"europe/berlin"`).
+pub(crate) fn parse_timezone(s: &str) -> Result<Tz, TruthError> {
+    match lookup_timezone_abbreviation(s) {
+        Some(abbr) if abbr.ambiguous => Err(TruthError::AmbiguousTimezone {
+            abbreviation: abbr.abbreviation,
+            candidates: abbr.candidates,
+        }),
+        Some(abbr) => abbr.candidates[0]
+            .parse::<Tz>()
+            .map_err(|_| TruthError::InvalidTimezone(format!("'{}'", s))),
+        None => s
+            .parse::<Tz>()
+            .ok()
+            .or_else(|| {
+                TZ_BY_LOWERCASE_NAME
+                    .get(s.trim().to_ascii_lowercase().as_str())
+                    .copied()
+            })
+            .ok_or_else(|| TruthError::InvalidTimezone(format!("'{}'", s))),
+    }
+}
+
+/// Every IANA zone keyed by its lowercased name, for case-insensitive lookup.
+static TZ_BY_LOWERCASE_NAME: LazyLock<HashMap<String, Tz>> = LazyLock::new(|| {
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .map(|tz| (tz.name().to_ascii_lowercase(), *tz))
+        .collect()
+});
+
+/// Determine if DST is active for a datetime in a timezone.
+fn is_dst_active<T: TimeZone>(dt: &DateTime<T>, tz: &Tz) -> bool {
+    // Compare January 1 offset (winter / standard) with the current offset.
+    // If they differ, DST is active.
+    let utc = dt.with_timezone(&Utc);
+    let year = utc.year();
+
+    let jan1 = Utc
+        .with_ymd_and_hms(year, 1, 1, 12, 0, 0)
+        .single()
+        .unwrap_or(utc);
+    let jan1_local = jan1.with_timezone(tz);
+
+    let current_offset = dt.offset().fix().local_minus_utc();
+    let jan_offset = jan1_local.offset().fix().local_minus_utc();
+
+    current_offset != jan_offset
+}
+
+/// Format the UTC offset as a string (e.g., "-05:00", "+09:00").
+fn format_utc_offset<T: TimeZone>(dt: &DateTime<T>) -> String {
+    let offset_secs = dt.offset().fix().local_minus_utc();
+    let sign = if offset_secs >= 0 { "+" } else { "-" };
+    let abs_secs = offset_secs.unsigned_abs();
+    let hours = abs_secs / 3600;
+    let minutes = (abs_secs % 3600) / 60;
+    format!("{sign}{hours:02}:{minutes:02}")
+}
+
+/// Format a human-readable duration string.
+fn format_human_duration(days: i64, hours: i64, minutes: i64, seconds: i64) -> String {
+    let mut parts = Vec::new();
+    if days > 0 {
+        parts.push(format!("{} day{}", days, if days == 1 { "" } else { "s" }));
+    }
+    if hours > 0 {
+        parts.push(format!(
+            "{} hour{}",
+            hours,
+            if hours == 1 { "" } else { "s" }
+        ));
+    }
+    if minutes > 0 {
+        parts.push(format!(
+            "{} minute{}",
+            minutes,
+            if minutes == 1 { "" } else { "s" }
+        ));
+    }
+    if seconds > 0 || parts.is_empty() {
+        parts.push(format!(
+            "{} second{}",
+            seconds,
+            if seconds == 1 { "" } else { "s" }
+        ));
+    }
+    parts.join(", ")
+}
+
+/// Format a business-time duration (e.g., "3 business days, 4 hours").
+fn format_business_duration(business_days: i64, hours: i64, minutes: i64, seconds: i64) -> String {
+    if business_days == 0 {
+        return format_human_duration(0, hours, minutes, seconds);
+    }
+    let days = format!(
+        "{} business day{}",
+        business_days,
+        if business_days == 1 { "" } else { "s" }
+    );
+    if hours == 0 && minutes == 0 && seconds == 0 {
+        days
+    } else {
+        format!(
+            "{}, {}",
+            days,
+            format_human_duration(0, hours, minutes, seconds)
+        )
+    }
+}
+
+/// Format a signed number of seconds as a duration string (e.g., "+2h30m", "-45s").
+fn format_signed_seconds(total_seconds: i64) -> String {
+    Duration::from_seconds(total_seconds).to_string()
+}
+
+// ── Parsing helpers ─────────────────────────────────────────────────────────
+
+/// Format a human-readable interpretation string.
+fn format_interpretation<T: TimeZone>(dt: &DateTime<T>) -> String
+where
+    T::Offset: std::fmt::Display,
+{
+    dt.format("%A, %B %-d, %Y at %-I:%M %p %Z").to_string()
+}
+
+/// Normalize expression: trim, lowercase, strip common articles (but not "a"/"an" at start
+/// since those are meaningful for patterns like "a week from now").
+fn normalize_expression(s: &str) -> String {
+    let s = s.trim().to_lowercase();
+    // Strip articles in the middle: "the", "a", "an"
+    let s = s
+        .replace(" the ", " ")
+        .replace(" a ", " ")
+        .replace(" an ", " ");
+    // Strip leading "the " only (not "a "/"an " — they matter for "a week from now")
+    let s = s.strip_prefix("the ").unwrap_or(&s).to_string();
+    // Collapse multiple spaces
+    let mut result = String::new();
+    let mut prev_space = false;
+    for ch in s.chars() {
+        if ch == ' ' {
+            if !prev_space {
+                result.push(' ');
+            }
+            prev_space = true;
+        } else {
+            result.push(ch);
+            prev_space = false;
+        }
+    }
+    result.trim().to_string()
+}
+
+/// Parse a `YYYY-MM-DD` date argument.
+fn parse_date(date: &str) -> Result<NaiveDate, TruthError> {
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .map_err(|e| TruthError::InvalidDatetime(format!("'{}': {}", date, e)))
+}
+
+/// Map named time to NaiveTime.
+fn named_time_to_naive(s: &str) -> Option<NaiveTime> {
+    match s {
+        "morning" | "start of business" | "sob" => NaiveTime::from_hms_opt(9, 0, 0),
+        "noon" | "lunch" => NaiveTime::from_hms_opt(12, 0, 0),
+        "afternoon" => NaiveTime::from_hms_opt(13, 0, 0),
+        "end of day" | "end of business" | "eob" => NaiveTime::from_hms_opt(17, 0, 0),
+        "evening" => NaiveTime::from_hms_opt(18, 0, 0),
+        "night" => NaiveTime::from_hms_opt(21, 0, 0),
+        "midnight" => NaiveTime::from_hms_opt(0, 0, 0),
+        _ => None,
+    }
+}
+
+/// Parse a time string: "2pm", "2:30pm", "14:00", "14:30:00".
+fn parse_time_string(s: &str) -> Option<NaiveTime> {
+    let s = s.trim();
+
+    // 24-hour format: "14:00", "14:30", "14:30:00"
+    if let Ok(t) = NaiveTime::parse_from_str(s, "%H:%M:%S") {
+        return Some(t);
+    }
+    if let Ok(t) = NaiveTime::parse_from_str(s, "%H:%M") {
+        return Some(t);
+    }
+
+    // 12-hour format: "2pm", "2:30pm", "2:30:00pm", "2 pm"
+    let s_no_space = s.replace(' ', "");
+    let (time_part, is_pm) = if s_no_space.ends_with("pm") {
+        (s_no_space.strip_suffix("pm")?, true)
+    } else if s_no_space.ends_with("am") {
+        (s_no_space.strip_suffix("am")?, false)
+    } else {
+        return None;
+    };
+
+    let parts: Vec<&str> = time_part.split(':').collect();
+    let hour: u32 = parts.first()?.parse().ok()?;
+    let minute: u32 = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(0);
+    let second: u32 = parts.get(2).and_then(|s| s.parse().ok()).unwrap_or(0);
+
+    let hour24 = match (hour, is_pm) {
+        (12, true) => 12,
+        (12, false) => 0,
+        (h, true) => h + 12,
+        (h, false) => h,
+    };
+
+    NaiveTime::from_hms_opt(hour24, minute, second)
+}
+
+/// Parse a weekday name (case-insensitive, supports full and abbreviated).
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    match s {
+        "monday" | "mon" => Some(Weekday::Mon),
+        "tuesday" | "tue" | "tues" => Some(Weekday::Tue),
+        "wednesday" | "wed" => Some(Weekday::Wed),
+        "thursday" | "thu" | "thurs" => Some(Weekday::Thu),
+        "friday" | "fri" => Some(Weekday::Fri),
+        "saturday" | "sat" => Some(Weekday::Sat),
+        "sunday" | "sun" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Parse a non-negative decimal ("2", "1.5", ".25", "two") into whole and
+/// fractional parts.
+///
+/// At most six fractional digits are accepted.
+fn parse_decimal(s: &str) -> Option<(i64, Fraction)> {
+    if let Some(n) = parse_number_word(s) {
+        return Some((n, Fraction::ZERO));
+    }
+    let (whole, frac) = s.split_once('.').unwrap_or((s, ""));
+    if whole.is_empty() && frac.is_empty() {
+        return None;
+    }
+    if !whole.chars().all(|c| c.is_ascii_digit())
+        || !frac.chars().all(|c| c.is_ascii_digit())
+        || frac.len() > 6
+        || (s.contains('.') && frac.is_empty())
+    {
+        return None;
+    }
+    let whole: i64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().ok()?
+    };
+    let fraction = if frac.is_empty() {
+        Fraction::ZERO
+    } else {
+        Fraction {
+            numerator: frac.parse().ok()?,
+            denominator: 10i64.pow(frac.len() as u32),
+        }
+    };
+    Some((whole, fraction))
+}
+
+/// The fractional part of a decimal quantity, as `numerator / denominator`.
+#[derive(Debug, Clone, Copy)]
+struct Fraction {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Fraction {
+    const ZERO: Fraction = Fraction {
+        numerator: 0,
+        denominator: 1,
+    };
+    #[cfg(feature = "resolver")]
+    const HALF: Fraction = Fraction {
+        numerator: 1,
+        denominator: 2,
+    };
+
+    /// This fraction of `unit_seconds`, rounded to the nearest second.
+    fn of(self, unit_seconds: i64) -> i64 {
+        (self.numerator * unit_seconds * 2 + self.denominator) / (self.denominator * 2)
+    }
+}
+
+/// Parse a number word from "one" to "twelve".
+fn parse_number_word(s: &str) -> Option<i64> {
+    const WORDS: [&str; 12] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+        "twelve",
+    ];
+    WORDS.iter().position(|w| *w == s).map(|i| i as i64 + 1)
+}
+
+// ── Tests ───────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    // ── convert_timezone tests ──────────────────────────────────────────
+
+    #[test]
+    fn test_convert_utc_to_eastern() {
+        let result = convert_timezone("2026-03-15T14:00:00Z", "America/New_York").unwrap();
+        assert_eq!(result.timezone, "America/New_York");
+        // March 15 2026 is EDT (UTC-4), so 14:00 UTC = 10:00 local
+        assert!(result.local.contains("10:00:00"));
+        assert_eq!(result.utc, "2026-03-15T14:00:00+00:00");
+    }
+
+    #[test]
+    fn test_convert_eastern_to_pacific() {
+        // Input is in UTC-5 (EST), convert to Pacific
+        let result = convert_timezone("2026-01-15T14:00:00-05:00", "America/Los_Angeles").unwrap();
+        assert_eq!(result.timezone, "America/Los_Angeles");
+        // Jan 15 is PST (UTC-8). The input is 14:00 EST = 19:00 UTC = 11:00 PST
+        assert!(result.local.contains("11:00:00"));
+    }
+
+    #[test]
+    fn test_convert_across_dst_spring_forward() {
+        // March 8, 2026: US spring forward (2:00 AM → 3:00 AM)
+        // Before DST: Jan 15, 2026 — EST (UTC-5)
+        let winter = convert_timezone("2026-01-15T12:00:00Z", "America/New_York").unwrap();
+        assert_eq!(winter.utc_offset, "-05:00");
+        assert!(!winter.dst_active);
+
+        // After DST: March 15, 2026 — EDT (UTC-4)
+        let summer = convert_timezone("2026-03-15T12:00:00Z", "America/New_York").unwrap();
+        assert_eq!(summer.utc_offset, "-04:00");
+        assert!(summer.dst_active);
+    }
+
+    #[test]
+    fn test_convert_across_dst_fall_back() {
+        // November 1, 2026: US fall back (2:00 AM → 1:00 AM)
+        // After fall back: Nov 2 — EST (UTC-5)
+        let result = convert_timezone("2026-11-02T12:00:00Z", "America/New_York").unwrap();
+        assert_eq!(result.utc_offset, "-05:00");
+        assert!(!result.dst_active);
+    }
+
+    #[test]
+    fn test_convert_utc_offset_correct() {
+        let result = convert_timezone("2026-06-15T12:00:00Z", "Asia/Tokyo").unwrap();
+        assert_eq!(result.utc_offset, "+09:00");
+        assert!(!result.dst_active); // Japan does not observe DST
+    }
+
+    #[test]
+    fn test_convert_dst_active_flag() {
+        // Summer in New York — DST active
+        let summer = convert_timezone("2026-07-15T12:00:00Z", "America/New_York").unwrap();
+        assert!(summer.dst_active);
+
+        // Winter in New York — DST not active
+        let winter = convert_timezone("2026-12-15T12:00:00Z", "America/New_York").unwrap();
+        assert!(!winter.dst_active);
+    }
+
+    #[test]
+    fn test_convert_invalid_timezone_returns_error() {
+        let result = convert_timezone("2026-03-15T14:00:00Z", "Invalid/Zone");
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Invalid timezone"), "got: {err}");
+    }
+
+    #[test]
+    fn test_convert_invalid_datetime_returns_error() {
+        let result = convert_timezone("not-a-datetime", "America/New_York");
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Invalid datetime"), "got: {err}");
+    }
+
+    // ── Batch conversion tests ──────────────────────────────────────────
+
+    #[test]
+    fn test_convert_batch_preserves_order() {
+        let results = convert_timezone_batch(
+            &[
+                "2026-01-15T12:00:00Z",
+                "2026-07-15T12:00:00Z",
+                "2026-03-15T14:00:00Z",
+            ],
+            "America/New_York",
+        )
+        .unwrap()
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].utc_offset, "-05:00");
+        assert_eq!(results[1].utc_offset, "-04:00");
+        assert!(results[2].local.contains("10:00:00"));
+    }
+
+    #[test]
+    fn test_convert_batch_empty_input() {
+        let results = convert_timezone_batch(&[], "UTC").unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_convert_batch_invalid_datetime_fails_alone() {
+        let results = convert_timezone_batch(
+            &["2026-01-15T12:00:00Z", "bogus", "2026-01-16T12:00:00Z"],
+            "UTC",
+        )
+        .unwrap();
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(TruthError::InvalidDatetime(_))));
+        assert!(results[2].is_ok());
+
+        let result = convert_timezone_batch(&["2026-01-15T12:00:00Z"], "Mars/Olympus");
+        assert!(matches!(result, Err(TruthError::InvalidTimezone(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_convert_instants_matches_string_batch() {
+        let inputs = ["2026-01-15T12:00:00Z", "2026-07-15T12:00:00Z"];
+        let instants: Vec<DateTime<Utc>> = inputs
+            .iter()
+            .map(|s| DateTime::parse_from_rfc3339(s).unwrap().to_utc())
+            .collect();
+        let full = convert_instants(&instants, "Europe/Paris", OutputFields::Full).unwrap();
+        let batch = convert_timezone_batch(&inputs, "Europe/Paris").unwrap();
+        assert_eq!(
+            full,
+            batch.into_iter().collect::<Result<Vec<_>, _>>().unwrap()
+        );
+        // Zone names are borrowed from the timezone database, not copied.
+        assert!(matches!(full[0].timezone, Cow::Borrowed("Europe/Paris")));
+
+        let minimal = convert_instants(&instants, "Europe/Paris", OutputFields::Minimal).unwrap();
+        for (minimal, full) in minimal.iter().zip(&full) {
+            assert_eq!(minimal.local, full.local);
+            assert_eq!(minimal.dst_active, full.dst_active);
+            assert!(minimal.utc.is_empty() && minimal.utc_offset.is_empty());
+        }
+        let json = serde_json::to_value(&minimal[1]).unwrap();
+        assert!(json.get("utc").is_none() && json.get("utc_offset").is_none());
+        let back: ConvertedDatetime = serde_json::from_value(json).unwrap();
+        assert_eq!(back, minimal[1]);
+    }
+
+    #[test]
+    fn test_convert_multi_matches_single_conversions() {
+        let zones = ["America/Los_Angeles", "Europe/Berlin", "Australia/Sydney"];
+        let results = convert_timezone_multi("2026-03-15T14:00:00Z", &zones).unwrap();
+        for (zone, result) in zones.iter().zip(&results) {
+            let single = convert_timezone("2026-03-15T14:00:00Z", zone).unwrap();
+            assert_eq!(result.local, single.local);
+            assert_eq!(result.timezone, *zone);
+        }
+    }
+
+    #[test]
+    fn test_convert_multi_invalid_timezone_fails() {
+        let result = convert_timezone_multi("2026-03-15T14:00:00Z", &["UTC", "Nowhere/Land"]);
+        assert!(matches!(result, Err(TruthError::InvalidTimezone(_))));
+    }
+
+    // ── world_clock tests ───────────────────────────────────────────────
+
+    #[test]
+    fn test_world_clock_nine_am_eastern() {
+        // Monday March 16, 2026, 09:00 EDT = 13:00 UTC
+        let rows = world_clock(
+            "2026-03-16T09:00:00-04:00",
+            &["Asia/Tokyo", "Australia/Sydney", "Europe/London"],
+            &WorkingHours::default(),
+        )
+        .unwrap();
+
+        // Tokyo: 22:00 Monday — same day, after hours
+        assert!(rows[0].local.contains("2026-03-16T22:00:00"));
+        assert_eq!(rows[0].day_offset, 0);
+        assert!(!rows[0].is_business_hours);
+
+        // Sydney (AEDT, +11): 00:00 Tuesday — next day, after hours
+        assert!(rows[1].local.contains("2026-03-17T00:00:00"));
+        assert_eq!(rows[1].day_offset, 1);
+        assert!(!rows[1].is_business_hours);
+
+        // London (GMT): 13:00 Monday — working hours
+        assert_eq!(rows[2].utc_offset, "+00:00");
+        assert!(rows[2].is_business_hours);
+    }
+
+    #[test]
+    fn test_world_clock_previous_day() {
+        // Tuesday 07:00 in Tokyo is still Monday in Los Angeles
+        let rows = world_clock(
+            "2026-03-17T07:00:00+09:00",
+            &["America/Los_Angeles"],
+            &WorkingHours::default(),
+        )
+        .unwrap();
+        assert_eq!(rows[0].day_offset, -1);
+        assert!(rows[0].is_business_hours); // 15:00 Monday PDT
+    }
+
+    #[test]
+    fn test_world_clock_weekend_is_not_business_hours() {
+        // Saturday 11:00 UTC
+        let rows = world_clock("2026-03-14T11:00:00Z", &["UTC"], &WorkingHours::default()).unwrap();
+        assert!(!rows[0].is_business_hours);
+    }
+
+    #[test]
+    fn test_world_clock_invalid_zone() {
+        let result = world_clock(
+            "2026-03-14T11:00:00Z",
+            &["Nowhere/Land"],
+            &WorkingHours::default(),
+        );
+        assert!(result.is_err());
+    }
+
+    // ── overlap_window tests ────────────────────────────────────────────
+
+    #[test]
+    fn test_overlap_window_london_and_new_york() {
+        // Wed Feb 18, 2026: London 09–17 UTC, New York 09–17 EST = 14–22 UTC
+        let windows = overlap_window(
+            &["Europe/London", "America/New_York"],
+            &WorkingHours::default(),
+            "2026-02-18",
+        )
+        .unwrap();
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].start, "2026-02-18T14:00:00+00:00");
+        assert_eq!(windows[0].end, "2026-02-18T17:00:00+00:00");
+        assert_eq!(windows[0].duration_minutes, 180);
+        assert_eq!(windows[0].local[1].timezone, "America/New_York");
+        assert_eq!(windows[0].local[1].start, "2026-02-18T09:00:00-05:00");
+    }
+
+    #[test]
+    fn test_overlap_window_crosses_local_dates() {
+        // Tue Feb 17 in Tokyo, 09–10 JST = Mon Feb 16, 16–17 PST in Los Angeles
+        let wh = WorkingHours {
+            end: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+            ..Default::default()
+        };
+        let windows =
+            overlap_window(&["Asia/Tokyo", "America/Los_Angeles"], &wh, "2026-02-17").unwrap();
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].start, "2026-02-17T00:00:00+00:00");
+        assert_eq!(windows[0].end, "2026-02-17T02:00:00+00:00");
+        assert_eq!(windows[0].local[1].start, "2026-02-16T16:00:00-08:00");
+    }
+
+    #[test]
+    fn test_overlap_window_no_overlap() {
+        let windows = overlap_window(
+            &["Asia/Tokyo", "America/New_York"],
+            &WorkingHours::default(),
+            "2026-02-18",
+        )
+        .unwrap();
+        assert!(windows.is_empty());
+    }
+
+    #[test]
+    fn test_overlap_window_weekend_reference_day() {
+        let windows = overlap_window(
+            &["Europe/London", "Europe/Paris"],
+            &WorkingHours::default(),
+            "2026-02-21",
+        )
+        .unwrap();
+        assert!(windows.is_empty());
+    }
+
+    #[test]
+    fn test_overlap_window_invalid_inputs() {
+        let wh = WorkingHours::default();
+        assert!(matches!(
+            overlap_window(&["Europe/London"], &wh, "next week"),
+            Err(TruthError::InvalidDatetime(_))
+        ));
+        assert!(matches!(
+            overlap_window(&["Europe/London", "Mars/Olympus"], &wh, "2026-02-18"),
+            Err(TruthError::InvalidTimezone(_))
+        ));
+        assert!(overlap_window(&[], &wh, "2026-02-18").unwrap().is_empty());
+    }
+
+    // ── dst_advisories tests ────────────────────────────────────────────
+
+    #[test]
+    fn test_dst_advisories_when_the_meetings_zone_changes_first() {
+        // Kept at 10:00 in Berlin; New York springs forward on March 8.
+        let advisories = dst_advisories(
+            "2026-03-06T10:00:00+01:00",
+            "Europe/Berlin",
+            &["America/New_York"],
+        )
+        .unwrap();
+        assert_eq!(advisories.len(), 2);
+
+        let near = &advisories[0];
+        assert_eq!(near.kind, DstAdvisoryKind::NearTransition);
+        assert_eq!(near.timezone, "America/New_York");
+        assert_eq!(near.transition_utc, "2026-03-08T07:00:00+00:00");
+        assert_eq!(
+            (near.offset_before.as_str(), near.offset_after.as_str()),
+            ("-05:00", "-04:00")
+        );
+        assert_eq!(
+            near.message,
+            "America/New_York changes clocks on Sunday, March 8 at 03:00 EDT (-05:00 → -04:00), 2 days after this meeting"
+        );
+
+        let shift = &advisories[1];
+        assert_eq!(shift.kind, DstAdvisoryKind::ShiftsNextWeek);
+        assert_eq!(shift.transition_timezone, "America/New_York");
+        assert_eq!(shift.local, "2026-03-06T04:00:00-05:00");
+        assert_eq!(shift.shift_minutes, 60);
+        assert_eq!(
+            shift.message,
+            "From Friday, March 13 this meeting is at 05:00 EDT in America/New_York, 1 hour later than this week (04:00 EST), because America/New_York changes clocks on Sunday, March 8"
+        );
+    }
+
+    #[test]
+    fn test_dst_advisories_after_an_attendees_zone_changed() {
+        // Kept at 10:00 in New York; Berlin sprang forward on March 29.
+        let advisories = dst_advisories(
+            "2026-04-03T10:00:00-04:00",
+            "America/New_York",
+            &["Europe/Berlin", "America/New_York"],
+        )
+        .unwrap();
+        assert_eq!(advisories.len(), 1);
+        let shift = &advisories[0];
+        assert_eq!(shift.kind, DstAdvisoryKind::ShiftedSinceLastWeek);
+        assert_eq!(shift.timezone, "Europe/Berlin");
+        assert_eq!(shift.transition_timezone, "Europe/Berlin");
+        assert_eq!(shift.shift_minutes, 60);
+        assert!(shift.message.starts_with(
+            "This week the meeting is at 16:00 CEST in Europe/Berlin, 1 hour later than on Friday, March 27 (15:00 CET)"
+        ));
+    }
+
+    #[test]
+    fn test_dst_advisories_for_zones_that_change_together() {
+        // London and Berlin both change on March 29: no one's meeting moves.
+        let advisories = dst_advisories(
+            "2026-03-27T09:00:00+00:00",
+            "Europe/London",
+            &["Europe/Berlin"],
+        )
+        .unwrap();
+        let kinds: Vec<(&str, DstAdvisoryKind)> = advisories
+            .iter()
+            .map(|a| (a.timezone.as_ref(), a.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("Europe/London", DstAdvisoryKind::NearTransition),
+                ("Europe/Berlin", DstAdvisoryKind::NearTransition),
+            ]
+        );
+
+        // Far from any change there is nothing to say.
+        assert!(dst_advisories(
+            "2026-06-12T09:00:00+00:00",
+            "Europe/London",
+            &["Asia/Tokyo"]
+        )
+        .unwrap()
+        .is_empty());
+        assert!(matches!(
+            dst_advisories("next friday", "Europe/London", &[]),
+            Err(TruthError::InvalidDatetime(_))
+        ));
+        assert!(matches!(
+            dst_advisories("2026-03-27T09:00:00Z", "Europe/London", &["Mars/Olympus"]),
+            Err(TruthError::InvalidTimezone(_))
+        ));
+    }
+
+    // ── Rendering tests ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_renderings_absent_by_default() {
+        let result = convert_timezone("2026-03-15T14:00:00Z", "UTC").unwrap();
+        assert!(result.renderings.is_none());
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolved_renderings() {
+        let r = resolve_relative(anchor(), "next tuesday at 2pm", "America/New_York")
+            .unwrap()
+            .with_renderings(&FormatOptions {
+                pattern: Some("%A %-d %B, %-I:%M %p %Z".to_string()),
+                rfc2822: true,
+                epoch_seconds: true,
+            })
+            .unwrap();
+        let renderings = r.renderings.unwrap();
+        assert_eq!(
+            renderings.custom.as_deref(),
+            Some("Tuesday 24 February, 2:00 PM EST")
+        );
+        assert_eq!(
+            renderings.rfc2822.as_deref(),
+            Some("Tue, 24 Feb 2026 14:00:00 -0500")
+        );
+        assert_eq!(renderings.epoch_seconds, Some(1_771_959_600));
+    }
+
+    #[test]
+    fn test_adjusted_renderings_use_source_timezone() {
+        let adjusted = adjust_timestamp("2026-07-01T12:00:00Z", "+1d", "Europe/Paris")
+            .unwrap()
+            .with_renderings(&FormatOptions {
+                pattern: Some("%Y-%m-%d %H:%M %Z".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(adjusted.timezone, "Europe/Paris");
+        let renderings = adjusted.renderings.unwrap();
+        assert_eq!(renderings.custom.as_deref(), Some("2026-07-02 14:00 CEST"));
+        assert_eq!(renderings.rfc2822, None);
+    }
+
+    #[test]
+    fn test_renderings_reject_invalid_pattern() {
+        let result = convert_timezone("2026-03-15T14:00:00Z", "UTC")
+            .unwrap()
+            .with_renderings(&FormatOptions {
+                pattern: Some("%Q".to_string()),
+                ..Default::default()
+            });
+        assert!(matches!(result, Err(TruthError::InvalidFormat(_))));
+    }
+
+    // ── Lenient input tests ─────────────────────────────────────────────
+
+    #[test]
+    fn test_strict_input_is_default() {
+        assert!(convert_timezone("2026-03-15 14:00", "UTC").is_err());
+        assert!(compute_duration("2026-03-15", "2026-03-16").is_err());
+    }
+
+    #[test]
+    fn test_lenient_input_forms() {
+        let expected = "2026-03-15T14:00:00+00:00";
+        for input in [
+            "2026-03-15 14:00",
+            "2026-03-15T14:00Z",
+            "2026-03-15 14:00:00 Z",
+            "2026-03-15t14:00:00z",
+            " 2026-03-15 15:00+01:00 ",
+            "2026-03-15T15:00+0100",
+            "2026-03-15T09:00-05",
+        ] {
+            let dt = parse_datetime(input, InputMode::Lenient).unwrap();
+            assert_eq!(dt.to_rfc3339(), expected, "{input}");
+        }
+        let date_only = parse_datetime("2026-03-15", InputMode::Lenient).unwrap();
+        assert_eq!(date_only.to_rfc3339(), "2026-03-15T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_lenient_input_rejects_garbage() {
+        for input in [
+            "next tuesday",
+            "2026-13-01",
+            "2026-03-15 25:00",
+            "2026-03-15T14:00+1",
+        ] {
+            let result = parse_datetime(input, InputMode::Lenient);
+            assert!(
+                matches!(result, Err(TruthError::InvalidDatetime(_))),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_mode_variants_accept_lenient_input() {
+        let converted =
+            convert_timezone_with_mode("2026-03-15 14:00", "Asia/Tokyo", InputMode::Lenient)
+                .unwrap();
+        assert_eq!(converted.local, "2026-03-15T23:00:00+09:00");
+
+        let duration =
+            compute_duration_with_mode("2026-03-15", "2026-03-16 12:00", InputMode::Lenient)
+                .unwrap();
+        assert_eq!(duration.total_seconds, 36 * 3600);
+
+        let adjusted =
+            adjust_timestamp_with_mode("2026-03-15 14:00", "+1h", "UTC", InputMode::Lenient)
+                .unwrap();
+        assert_eq!(adjusted.adjusted_utc, "2026-03-15T15:00:00+00:00");
+        assert_eq!(adjusted.original, "2026-03-15 14:00");
+    }
+
+    // ── time_until_next tests ───────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_next_weekday_time() {
+        let r = time_until_next(anchor(), "friday at 5pm", "Europe/Berlin", None).unwrap();
+        assert_eq!(r.target_utc, "2026-02-20T16:00:00+00:00");
+        assert_eq!(r.calendar.total_seconds, (2 * 24 + 1) * 3600 + 30 * 60);
+        assert!(!r.is_past);
+        assert!(r.business.is_none());
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_next_is_strictly_future() {
+        // Anchor is 14:30 UTC; asking for 14:30 gives tomorrow.
+        let r = time_until_next(anchor(), "14:30", "UTC", None).unwrap();
+        assert_eq!(r.target_utc, "2026-02-19T14:30:00+00:00");
+        assert_eq!(r.human_readable, "1 day");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_next_business_hours() {
+        let calendar = BusinessCalendar::default();
+        let r = time_until_next(anchor(), "monday 09:00", "UTC", Some(&calendar)).unwrap();
+        assert_eq!(r.target_utc, "2026-02-23T09:00:00+00:00");
+        // Wed 14:30–17:00, Thu and Fri in full: 2.5h + 16h
+        assert_eq!(r.business.unwrap().total_seconds, (2 * 8 * 3600) + 9000);
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_next_rejects_unknown_pattern() {
+        let result = time_until_next(anchor(), "someday", "UTC", None);
+        assert!(matches!(result, Err(TruthError::InvalidExpression(_))));
+        let result = time_until_next(anchor(), "friday", "Mars/Olympus", None);
+        assert!(matches!(result, Err(TruthError::InvalidTimezone(_))));
+    }
+
+    // ── next_matching tests ─────────────────────────────────────────────
+
+    #[test]
+    fn test_next_matching_is_strictly_after_the_anchor() {
+        // Wednesday, February 18, 2026 at 14:30 UTC.
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        let daily = SimplePattern::daily(at(14, 30));
+        assert_eq!(
+            next_matching(anchor, &daily, chrono_tz::UTC).unwrap(),
+            chrono_tz::UTC
+                .with_ymd_and_hms(2026, 2, 19, 14, 30, 0)
+                .unwrap()
+        );
+
+        let twice_weekly = SimplePattern {
+            days: PatternDays::Weekdays(vec![Weekday::Mon, Weekday::Thu]),
+            time: at(9, 0),
+        };
+        let berlin = chrono_tz::Europe::Berlin;
+        assert_eq!(
+            next_matching(anchor, &twice_weekly, berlin).unwrap(),
+            berlin.with_ymd_and_hms(2026, 2, 19, 9, 0, 0).unwrap()
+        );
+
+        // The 31st falls on the last day of shorter months.
+        let april = Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap();
+        let month_end = SimplePattern::monthly(31, at(17, 0));
+        assert_eq!(
+            next_matching(april, &month_end, chrono_tz::UTC).unwrap(),
+            chrono_tz::UTC
+                .with_ymd_and_hms(2026, 4, 30, 17, 0, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_next_matching_across_dst_and_invalid_patterns() {
+        // 02:30 does not exist in New York on March 8, 2026.
+        let new_york = chrono_tz::America::New_York;
+        let midnight = new_york.with_ymd_and_hms(2026, 3, 8, 0, 0, 0).unwrap();
+        let early = SimplePattern::daily(NaiveTime::from_hms_opt(2, 30, 0).unwrap());
+        let next = next_matching(midnight.with_timezone(&Utc), &early, new_york).unwrap();
+        assert_eq!(next.to_rfc3339(), "2026-03-08T03:30:00-04:00");
+
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+        let none = SimplePattern {
+            days: PatternDays::Weekdays(vec![]),
+            time: NaiveTime::MIN,
+        };
+        assert!(matches!(
+            next_matching(anchor, &none, new_york),
+            Err(TruthError::InvalidArguments(_))
+        ));
+        assert!(matches!(
+            next_matching(anchor, &SimplePattern::monthly(0, NaiveTime::MIN), new_york),
+            Err(TruthError::InvalidArguments(_))
+        ));
+    }
+
+    // ── Relative-to-expression tests ────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_offset_from_explicit_date() {
+        let r = resolve_relative(anchor(), "3 days after March 10", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-03-13T00:00:00+00:00");
+        assert_eq!(r.granularity, ResolutionGranularity::Date);
+        let r = resolve_relative(anchor(), "two weeks before march 10 at 3pm", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-24T15:00:00+00:00");
+        assert_eq!(r.granularity, ResolutionGranularity::DateTime);
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_offset_from_period_boundary() {
+        let r = resolve_relative(anchor(), "a week before the end of the quarter", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-03-24T23:59:59+00:00");
+        let r = resolve_relative(anchor(), "a month after march 31", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-04-30T00:00:00+00:00");
+        let r = resolve_relative(anchor(), "1 year before next month", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2025-03-01T00:00:00+00:00");
+        assert_eq!(r.granularity, ResolutionGranularity::Date);
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_offset_days_keep_wall_clock_across_dst() {
+        // US clocks spring forward on 2026-03-08.
+        let r =
+            resolve_relative(anchor(), "3 days after march 6 at 9am", "America/New_York").unwrap();
+        assert_eq!(r.resolved_local, "2026-03-09T09:00:00-04:00");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_offset_from_caller_reference() {
+        let mut references = BTreeMap::new();
+        references.insert(
+            "Meeting start".to_string(),
+            Utc.with_ymd_and_hms(2026, 2, 20, 15, 0, 0).unwrap(),
+        );
+        let options = ResolveOptions {
+            references,
+            ..Default::default()
+        };
+        let resolve = |expr| {
+            resolve_relative_with_options(anchor(), expr, "UTC", &options)
+                .unwrap()
+                .resolved_utc
+        };
+        assert_eq!(
+            resolve("two hours after the meeting start"),
+            "2026-02-20T17:00:00+00:00"
+        );
+        assert_eq!(
+            resolve("30 minutes before meeting start"),
+            "2026-02-20T14:30:00+00:00"
+        );
+        assert_eq!(resolve("the meeting start"), "2026-02-20T15:00:00+00:00");
+        assert_eq!(
+            resolve("1 business day after meeting start"),
+            "2026-02-23T15:00:00+00:00"
+        );
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_offset_day_after_tomorrow_and_failures() {
+        let r = resolve_relative(anchor(), "the day after tomorrow", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-20T00:00:00+00:00");
+        assert!(resolve_relative(anchor(), "3 days after the meeting", "UTC").is_err());
+        assert!(resolve_relative(anchor(), "soon after march 10", "UTC").is_err());
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_ordinal_days_of_any_period() {
+        let resolve = |expr| resolve_relative(anchor(), expr, "UTC").unwrap();
+        let r = resolve("the last Friday of the quarter");
+        assert_eq!(r.resolved_utc, "2026-03-27T00:00:00+00:00");
+        assert_eq!(r.granularity, ResolutionGranularity::Date);
+        assert_eq!(
+            resolve("first monday of next year").resolved_utc,
+            "2027-01-04T00:00:00+00:00"
+        );
+        assert_eq!(
+            resolve("the 2nd business day of Q3 2027").resolved_utc,
+            "2027-07-02T09:00:00+00:00"
+        );
+        assert_eq!(
+            resolve("10th day of next month").resolved_utc,
+            "2026-03-10T00:00:00+00:00"
+        );
+        // Past the end of the period.
+        assert!(resolve_relative(anchor(), "fifth friday of next month", "UTC").is_err());
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_offsets_and_times_compose() {
+        let resolve = |expr| {
+            resolve_relative(anchor(), expr, "UTC")
+                .unwrap()
+                .resolved_utc
+        };
+        // Friday March 27 is the quarter's last; three business days earlier.
+        assert_eq!(
+            resolve("3 business days before the last Friday of the quarter"),
+            "2026-03-24T00:00:00+00:00"
+        );
+        assert_eq!(
+            resolve("2 weeks after the end of the month at 3pm"),
+            "2026-03-14T15:00:00+00:00"
+        );
+        assert_eq!(
+            resolve("last friday of the quarter at noon"),
+            "2026-03-27T12:00:00+00:00"
+        );
+        assert_eq!(
+            resolve("a week after the last business day of next quarter"),
+            "2026-07-07T09:00:00+00:00"
+        );
+        // A bare hour still follows the bare-hour policy.
+        assert!(resolve_relative(anchor(), "end of the month at 7", "UTC").is_err());
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_number_words_in_quantities() {
+        let r = resolve_relative(anchor(), "in two hours", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-18T16:30:00+00:00");
+    }
+
+    // ── Doubled relative period tests ───────────────────────────────────
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_doubled_relative_periods() {
+        // Anchor: Wednesday 2026-02-18
+        let cases = [
+            ("the week after next", "2026-03-02T00:00:00+00:00"),
+            ("week before last", "2026-02-02T00:00:00+00:00"),
+            ("the month after next", "2026-04-01T00:00:00+00:00"),
+            ("the month before last", "2025-12-01T00:00:00+00:00"),
+            ("the year after next", "2028-01-01T00:00:00+00:00"),
+            ("the weekend after next", "2026-03-07T00:00:00+00:00"),
+            (
+                "end of the work week after next",
+                "2026-03-06T17:00:00+00:00",
+            ),
+            ("the weekend before last", "2026-02-07T00:00:00+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative(anchor(), expr, "UTC").unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
+        let r = resolve_relative(anchor(), "the week after next", "UTC").unwrap();
+        assert_eq!(r.granularity, ResolutionGranularity::Period);
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_week_after_next_respects_week_start() {
+        let options = ResolveOptions {
+            week_start: WeekStartDay::Sunday,
+            ..Default::default()
+        };
+        let r =
+            resolve_relative_with_options(anchor(), "week after next", "UTC", &options).unwrap();
+        assert_eq!(r.resolved_utc, "2026-03-01T00:00:00+00:00");
+    }
+
+    // ── Work week tests ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_work_week_start_and_weekend() {
+        let wh = WorkingHours::default();
+        assert_eq!(wh.work_week_start(), Some(Weekday::Mon));
+        assert_eq!(wh.weekend(), vec![Weekday::Sat, Weekday::Sun]);
+
+        let wh = WorkingHours::sunday_to_thursday();
+        assert_eq!(wh.work_week_start(), Some(Weekday::Sun));
+        assert_eq!(wh.weekend(), vec![Weekday::Fri, Weekday::Sat]);
+
+        let every_day = WorkingHours {
+            days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ],
+            ..Default::default()
+        };
+        assert_eq!(every_day.work_week_start(), None);
+        assert!(every_day.weekend().is_empty());
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_work_week_expressions_default() {
+        // Anchor: Wednesday 2026-02-18
+        let r = resolve_relative(anchor(), "end of the work week", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-20T17:00:00+00:00");
+        let r = resolve_relative(anchor(), "start of next work week", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-23T09:00:00+00:00");
+        let r = resolve_relative(anchor(), "this weekend", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-21T00:00:00+00:00");
+        assert_eq!(r.granularity, ResolutionGranularity::Period);
+        let r = resolve_relative(anchor(), "end of last weekend", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-15T23:59:59+00:00");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_work_week_expressions_sunday_to_thursday() {
+        let options = ResolveOptions {
+            working_hours: WorkingHours::sunday_to_thursday(),
+            ..Default::default()
+        };
+        let resolve = |expr| {
+            resolve_relative_with_options(anchor(), expr, "Asia/Dubai", &options)
+                .unwrap()
+                .resolved_local
+        };
+        assert_eq!(resolve("end of work week"), "2026-02-19T17:00:00+04:00");
+        assert_eq!(resolve("weekend"), "2026-02-20T00:00:00+04:00");
+        assert_eq!(resolve("next weekend"), "2026-02-27T00:00:00+04:00");
+        assert_eq!(
+            resolve("start of next work week"),
+            "2026-02-22T09:00:00+04:00"
+        );
+        // Business-day arithmetic already follows the working days.
+        assert_eq!(
+            resolve("end of next business day"),
+            "2026-02-19T17:00:00+04:00"
+        );
+    }
+
+    // ── Clock format tests ──────────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_clock_format_defaults_follow_locale() {
+        assert_eq!(Locale::English.default_clock(), ClockFormat::TwelveHour);
+        assert_eq!(Locale::German.default_clock(), ClockFormat::TwentyFourHour);
+        let r = resolve_relative(anchor(), "tomorrow at 2pm", "America/New_York").unwrap();
+        assert_eq!(
+            r.interpretation,
+            "Thursday, February 19, 2026 at 2:00 PM EST"
+        );
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_clock_format_override() {
+        let options = ResolveOptions {
+            clock: Some(ClockFormat::TwentyFourHour),
+            ..Default::default()
+        };
+        let r = resolve_relative_with_options(
+            anchor(),
+            "tomorrow at 2pm",
+            "America/New_York",
+            &options,
+        )
+        .unwrap();
+        assert_eq!(r.interpretation, "Thursday, February 19, 2026 at 14:00 EST");
+
+        let options = ResolveOptions {
+            locale: Locale::French,
+            clock: Some(ClockFormat::TwelveHour),
+            ..Default::default()
+        };
+        let r =
+            resolve_relative_with_options(anchor(), "tomorrow at 2pm", "Europe/Paris", &options)
+                .unwrap();
+        assert_eq!(r.interpretation, "jeudi 19 février 2026 à 2:00 PM CET");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_clock_format_applies_to_dst_warnings() {
+        let options = ResolveOptions {
+            clock: Some(ClockFormat::TwentyFourHour),
+            ..Default::default()
+        };
+        let anchor = Utc.with_ymd_and_hms(2026, 3, 7, 12, 0, 0).unwrap();
+        let r = resolve_relative_with_options(
+            anchor,
+            "tomorrow at 2:30am",
+            "America/New_York",
+            &options,
+        )
+        .unwrap();
+        let shifted = r
+            .warnings
+            .iter()
+            .find(|w| w.kind == DstWarningKind::Shifted)
+            .unwrap();
+        assert!(
+            shifted.message.contains("between 02:00 and 03:00"),
+            "{}",
+            shifted.message
+        );
+    }
+
+    // ── Week number tests ───────────────────────────────────────────────
+
+    #[test]
+    fn test_week_number_iso_year_boundaries() {
+        let w = week_number("2026-01-01", WeekScheme::Iso).unwrap();
+        assert_eq!((w.year, w.week), (2026, 1));
+        let w = week_number("2027-01-01", WeekScheme::Iso).unwrap();
+        assert_eq!((w.year, w.week), (2026, 53));
+        let w = week_number("2024-12-30", WeekScheme::Iso).unwrap();
+        assert_eq!((w.year, w.week), (2025, 1));
+    }
+
+    #[test]
+    fn test_week_number_us_scheme() {
+        // Jan 1 2026 is a Thursday; its Sunday-start week is week 1.
+        let w = week_number("2026-01-03", WeekScheme::Us).unwrap();
+        assert_eq!((w.year, w.week), (2026, 1));
+        let w = week_number("2026-01-04", WeekScheme::Us).unwrap();
+        assert_eq!((w.year, w.week), (2026, 2));
+        let w = week_number("2026-02-18", WeekScheme::Us).unwrap();
+        assert_eq!((w.year, w.week, w.scheme), (2026, 8, WeekScheme::Us));
+        // The week containing Jan 1 2027 (a Friday) starts Sunday Dec 27.
+        let w = week_number("2026-12-27", WeekScheme::Us).unwrap();
+        assert_eq!((w.year, w.week), (2027, 1));
+        let w = week_number("2026-12-26", WeekScheme::Us).unwrap();
+        assert_eq!((w.year, w.week), (2026, 52));
+        assert!(week_number("not a date", WeekScheme::Us).is_err());
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolved_week_for_week_expressions() {
+        let r = resolve_relative(anchor(), "next week", "UTC").unwrap();
+        let week = r.week.unwrap();
+        assert_eq!(
+            (week.year, week.week, week.scheme),
+            (2026, 9, WeekScheme::Iso)
+        );
+
+        let options = ResolveOptions {
+            week_start: WeekStartDay::Sunday,
+            ..Default::default()
+        };
+        let r =
+            resolve_relative_with_options(anchor(), "start of next week", "UTC", &options).unwrap();
+        let week = r.week.unwrap();
+        assert_eq!(
+            (week.year, week.week, week.scheme),
+            (2026, 9, WeekScheme::Us)
+        );
+
+        let r = resolve_relative(anchor(), "2026-W30", "UTC").unwrap();
+        assert_eq!(r.week.unwrap().week, 30);
+
+        assert!(resolve_relative(anchor(), "tomorrow", "UTC")
+            .unwrap()
+            .week
+            .is_none());
+    }
+
+    // ── ISO week-date tests ─────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_iso_week_date_forms() {
+        assert_eq!(parse_iso_week_date("2026-W08-3").unwrap(), "2026-02-18");
+        assert_eq!(parse_iso_week_date("2026W083").unwrap(), "2026-02-18");
+        assert_eq!(parse_iso_week_date("2026-w08").unwrap(), "2026-02-16");
+        // Week-numbering year differs from the calendar year at the boundaries.
+        assert_eq!(parse_iso_week_date("2026-W01-1").unwrap(), "2025-12-29");
+        assert_eq!(parse_iso_week_date("2020-W53-7").unwrap(), "2021-01-03");
+    }
+
+    #[test]
+    fn test_parse_iso_week_date_rejects_invalid() {
+        for bad in [
+            "2025-W53-1",
+            "2026-W08-8",
+            "2026-W00",
+            "2026-W8-3",
+            "26-W08-3",
+            "",
+        ] {
+            assert!(parse_iso_week_date(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_iso_week_date_passthrough() {
+        let r = resolve_relative(anchor(), "2026-W10-5", "America/New_York").unwrap();
+        assert_eq!(r.resolved_local, "2026-03-06T00:00:00-05:00");
+        assert_eq!(r.granularity, ResolutionGranularity::Date);
+        let r = resolve_relative(anchor(), "2026-W10", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-03-02T00:00:00+00:00");
+        assert_eq!(r.granularity, ResolutionGranularity::Period);
+    }
+
+    // ── Day-of-year and Julian day tests ────────────────────────────────
+
+    #[test]
+    fn test_day_of_year_round_trip() {
+        assert_eq!(day_of_year("2026-01-01").unwrap(), 1);
+        assert_eq!(day_of_year("2024-12-31").unwrap(), 366);
+        assert_eq!(date_from_day_of_year(2024, 366).unwrap(), "2024-12-31");
+        assert_eq!(date_from_day_of_year(2026, 59).unwrap(), "2026-02-28");
+        assert!(date_from_day_of_year(2026, 0).is_err());
+        assert!(day_of_year("2026-02-30").is_err());
+    }
+
+    #[test]
+    fn test_julian_day_round_trip() {
+        assert_eq!(julian_day_number("1858-11-17").unwrap(), 2_400_001);
+        assert_eq!(julian_day_number("2026-02-18").unwrap(), 2_461_090);
+        assert_eq!(date_from_julian_day(2_451_545).unwrap(), "2000-01-01");
+        assert!(date_from_julian_day(i64::MAX).is_err());
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_day_of_year_expression() {
+        let r = resolve_relative(anchor(), "day 200 of 2026", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-07-19T00:00:00+00:00");
+        assert_eq!(r.granularity, ResolutionGranularity::Date);
+        let r = resolve_relative(anchor(), "day 1 of next year", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2027-01-01T00:00:00+00:00");
+        let r = resolve_relative(anchor(), "day 32", "UTC").unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-01T00:00:00+00:00");
+        assert!(resolve_relative(anchor(), "day 366 of 2026", "UTC").is_err());
+    }
+
+    // ── Recurring anchor tests ──────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
+    fn anchor_options() -> ResolveOptions {
+        ResolveOptions {
+            anchors: vec![
+                RecurringAnchor::new("payday", AnchorRule::NthBusinessDayOfMonth(-1)),
+                RecurringAnchor::new(
+                    "sprint boundary",
+                    AnchorRule::EveryNWeeks {
+                        start: NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+                        weeks: 2,
+                    },
+                ),
+                RecurringAnchor::new("rent day", AnchorRule::DayOfMonth(31)),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "resolver")]
+    fn resolve_anchor(expr: &str) -> String {
+        resolve_relative_with_options(anchor(), expr, "UTC", &anchor_options())
+            .unwrap()
+            .resolved_utc
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_recurring_anchor_payday() {
+        // Anchor: Wed 2026-02-18. Last business day of Feb is Fri the 27th.
+        assert_eq!(resolve_anchor("next payday"), "2026-02-27T00:00:00+00:00");
+        assert_eq!(resolve_anchor("payday"), "2026-02-27T00:00:00+00:00");
+        assert_eq!(resolve_anchor("last payday"), "2026-01-30T00:00:00+00:00");
+        assert_eq!(
+            resolve_anchor("two days before next payday"),
+            "2026-02-25T00:00:00+00:00"
+        );
+        assert_eq!(
+            resolve_anchor("3 business days after last payday"),
+            "2026-02-04T00:00:00+00:00"
+        );
+        assert_eq!(resolve_anchor("Payday at 9am"), "2026-02-27T09:00:00+00:00");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_recurring_anchor_every_other_week() {
+        // Boundaries every other Monday from 2026-01-05: Jan 19, Feb 2, Feb 16, Mar 2.
+        assert_eq!(
+            resolve_anchor("next sprint boundary"),
+            "2026-03-02T00:00:00+00:00"
+        );
+        assert_eq!(
+            resolve_anchor("last sprint boundary"),
+            "2026-02-16T00:00:00+00:00"
+        );
+        assert_eq!(
+            resolve_anchor("a week after last sprint boundary"),
+            "2026-02-23T00:00:00+00:00"
+        );
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_recurring_anchor_day_of_month_clamps() {
+        // February has no 31st.
+        assert_eq!(resolve_anchor("rent day"), "2026-02-28T00:00:00+00:00");
+        assert_eq!(resolve_anchor("last rent day"), "2026-01-31T00:00:00+00:00");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_recurring_anchor_unknown_name_errors() {
+        let result =
+            resolve_relative_with_options(anchor(), "next bonus day", "UTC", &anchor_options());
+        assert!(result.is_err());
+        // Without anchors configured the grammar is unchanged.
+        assert!(resolve_relative(anchor(), "next payday", "UTC").is_err());
+    }
+
+    // ── Resolution granularity tests ────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_granularity_of_common_expressions() {
+        use ResolutionGranularity::*;
+        let cases = [
+            ("tomorrow", Date),
+            ("tomorrow at 3pm", DateTime),
+            ("3pm tomorrow", DateTime),
+            ("next friday", Date),
+            ("next friday at 2pm", DateTime),
+            ("march 3rd", Date),
+            ("march 3rd at noon", DateTime),
+            ("2026-04-01", Date),
+            ("2026-04-01T09:00:00Z", DateTime),
+            ("now", DateTime),
+            ("in 2 hours", DateTime),
+            ("next week", Period),
+            ("next month", Period),
+            ("q3", Period),
+            ("start of q3", Date),
+            ("end of next month", Date),
+            ("next business day", Date),
+            ("end of next business day", DateTime),
+            ("start of first business day of next month", DateTime),
+            ("15th", Date),
+            ("15th at 9am", DateTime),
+            ("first monday of march", Date),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative(anchor(), expr, "UTC").unwrap();
+            assert_eq!(r.granularity, expected, "{expr}");
+        }
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_granularity_from_custom_parser_is_datetime() {
+        let mut parsers = ParserRegistry::new();
+        parsers.register("payday", |expr: &str, anchor: &DateTime<Tz>| {
+            (expr == "payday").then_some(*anchor)
+        });
+        let options = ResolveOptions {
+            parsers,
+            ..Default::default()
+        };
+        let r = resolve_relative_with_options(anchor(), "payday", "UTC", &options).unwrap();
+        assert_eq!(r.granularity, ResolutionGranularity::DateTime);
+    }
+
+    // ── Typed API tests ─────────────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_typed_variants_match_string_api() {
+        let ny: Tz = "America/New_York".parse().unwrap();
+        let dt = Utc.with_ymd_and_hms(2026, 3, 7, 14, 7, 0).unwrap();
+
+        let converted = convert_timezone_dt(dt, ny);
+        let string = convert_timezone(&dt.to_rfc3339(), "America/New_York").unwrap();
+        assert_eq!(converted.to_rfc3339(), string.local);
+
+        let adjustment = Duration {
+            days: 1,
+            hours: 2,
+            ..Default::default()
+        };
+        let adjusted = adjust_timestamp_dt(converted, &adjustment).unwrap();
+        let string = adjust_timestamp(&dt.to_rfc3339(), "+1d2h", "America/New_York").unwrap();
+        assert_eq!(adjusted.to_rfc3339(), string.adjusted_local);
+
+        let rounded = round_timestamp_dt(
+            converted,
+            RoundingGranularity::Minutes(15),
+            RoundingMode::Up,
+        )
+        .unwrap();
+        assert_eq!(rounded.to_rfc3339(), "2026-03-07T09:15:00-05:00");
+
+        let resolved = resolve_relative_dt(
+            anchor(),
+            "next friday at 2pm",
+            ny,
+            &ResolveOptions::default(),
+        )
+        .unwrap();
+        let string = resolve_relative(anchor(), "next friday at 2pm", "America/New_York").unwrap();
+        assert_eq!(resolved.to_rfc3339(), string.resolved_local);
+
+        let info = compute_duration_dt(dt, rounded);
+        assert_eq!(info.total_seconds, 8 * 60);
+    }
+
+    #[test]
+    fn test_typed_duration_negative_and_from_seconds() {
+        let d = Duration::from_seconds(-5400);
+        assert!(d.negative);
+        assert_eq!((d.hours, d.minutes), (1, 30));
+        assert_eq!(d.clock_seconds(), -5400);
+
+        let back = Duration {
+            negative: true,
+            weeks: 1,
+            ..Default::default()
+        };
+        assert_eq!(back.calendar_days(), -7);
+        let dt = "Europe/Paris"
+            .parse::<Tz>()
+            .unwrap()
+            .with_ymd_and_hms(2026, 4, 1, 9, 0, 0)
+            .unwrap();
+        let adjusted = adjust_timestamp_dt(dt, &back).unwrap();
+        assert_eq!(adjusted.to_rfc3339(), "2026-03-25T09:00:00+01:00");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_typed_resolve_errors() {
+        let result = resolve_relative_dt(anchor(), "whenever", Tz::UTC, &ResolveOptions::default());
+        assert!(matches!(result, Err(TruthError::InvalidExpression(_))));
+    }
+
+    // ── compute_duration tests ──────────────────────────────────────────
+
+    #[test]
+    fn test_duration_same_day() {
+        let result = compute_duration("2026-03-16T09:00:00Z", "2026-03-16T17:00:00Z").unwrap();
+        assert_eq!(result.total_seconds, 28800); // 8 hours
+        assert_eq!(result.hours, 8);
+        assert_eq!(result.days, 0);
+        assert_eq!(result.minutes, 0);
+    }
+
+    #[test]
+    fn test_duration_across_days() {
+        let result = compute_duration(
+            "2026-03-13T17:00:00Z", // Friday 5pm
+            "2026-03-16T09:00:00Z", // Monday 9am
+        )
+        .unwrap();
+        assert_eq!(result.total_seconds, 230400); // 2d + 16h = 2*86400 + 16*3600
+        assert_eq!(result.days, 2);
+        assert_eq!(result.hours, 16);
+    }
+
+    #[test]
+    fn test_duration_negative_direction() {
+        let result = compute_duration("2026-03-16T17:00:00Z", "2026-03-16T09:00:00Z").unwrap();
+        assert_eq!(result.total_seconds, -28800);
+        // Decomposition is always positive
+        assert_eq!(result.hours, 8);
+    }
+
+    #[test]
+    fn test_duration_exact_days() {
+        let result = compute_duration("2026-03-16T00:00:00Z", "2026-03-19T00:00:00Z").unwrap();
+        assert_eq!(result.days, 3);
+        assert_eq!(result.hours, 0);
+        assert_eq!(result.minutes, 0);
+        assert_eq!(result.seconds, 0);
+    }
+
+    #[test]
+    fn test_duration_sub_minute() {
+        let result = compute_duration("2026-03-16T10:00:00Z", "2026-03-16T10:00:45Z").unwrap();
+        assert_eq!(result.total_seconds, 45);
+        assert_eq!(result.seconds, 45);
+        assert_eq!(result.minutes, 0);
+    }
+
+    #[test]
+    fn test_duration_human_readable_format() {
+        let result = compute_duration("2026-03-16T00:00:00Z", "2026-03-18T03:15:00Z").unwrap();
+        assert_eq!(result.human_readable, "2 days, 3 hours, 15 minutes");
+    }
+
+    #[test]
+    fn test_duration_invalid_input() {
+        let result = compute_duration("not-a-datetime", "2026-03-16T10:00:00Z");
+        assert!(result.is_err());
+    }
+
+    // ── time_until tests ────────────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
+    fn business_options() -> TimeUntilOptions {
+        TimeUntilOptions {
+            business_calendar: Some(BusinessCalendar::default()),
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_calendar_only() {
+        let result = time_until(
+            anchor(),
+            "2026-02-20T17:00:00Z",
+            "UTC",
+            &TimeUntilOptions::default(),
+        )
+        .unwrap();
+        assert!(!result.is_past);
+        assert!(result.business.is_none());
+        assert_eq!(result.human_readable, "2 days, 2 hours, 30 minutes");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_business_skips_weekend() {
+        // Friday Feb 20 15:00 → Monday Feb 23 11:00 = 2h Friday + 2h Monday
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 20, 15, 0, 0).unwrap();
+        let result =
+            time_until(anchor, "2026-02-23T11:00:00Z", "UTC", &business_options()).unwrap();
+        let business = result.business.unwrap();
+        assert_eq!(business.total_seconds, 4 * 3600);
+        assert_eq!(business.business_days, 0);
+        assert_eq!(result.human_readable, "4 hours");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_business_days_and_hours() {
+        // Wed Feb 18 14:30 → Tue Feb 24 at 11:00: Wed 2.5h + Thu 8h + Fri 8h + Mon 8h + Tue 2h
+        let result =
+            time_until(anchor(), "2026-02-24T11:00:00Z", "UTC", &business_options()).unwrap();
+        let business = result.business.unwrap();
+        assert_eq!(business.business_days, 3);
+        assert_eq!(business.hours, 4);
+        assert_eq!(business.minutes, 30);
+        assert_eq!(
+            result.human_readable,
+            "3 business days, 4 hours, 30 minutes"
+        );
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_resolves_expressions() {
+        // Anchor Wed Feb 18 14:30 → end of week is Sun Feb 22 23:59:59
+        let result = time_until(anchor(), "end of week", "UTC", &business_options()).unwrap();
+        assert!(result.target_utc.contains("2026-02-22T23:59:59"));
+        // Remaining business time: Wed 2.5h + Thu 8h + Fri 8h
+        assert_eq!(result.business.unwrap().total_seconds, 18 * 3600 + 1800);
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_past_target_is_negative() {
+        let result =
+            time_until(anchor(), "2026-02-18T10:30:00Z", "UTC", &business_options()).unwrap();
+        assert!(result.is_past);
+        assert_eq!(result.calendar.total_seconds, -4 * 3600);
+        assert_eq!(result.business.unwrap().total_seconds, -4 * 3600);
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_business_hours_in_calendar_zone() {
+        // 9am–5pm New York time; anchor 14:30 UTC = 09:30 EST
+        let options = TimeUntilOptions {
+            business_calendar: Some(BusinessCalendar {
+                timezone: "America/New_York".to_string(),
+                ..BusinessCalendar::default()
+            }),
+            ..Default::default()
+        };
+        let result = time_until(anchor(), "2026-02-18T22:00:00Z", "UTC", &options).unwrap();
+        // 09:30–17:00 EST
+        assert_eq!(result.business.unwrap().total_seconds, 7 * 3600 + 1800);
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_time_until_skips_holidays_and_closures() {
+        let date = |d| NaiveDate::from_ymd_opt(2026, 2, d).unwrap();
+        let calendar = BusinessCalendar {
+            holidays: [date(19)].into(),
+            closures: vec![crate::business::Closure {
+                name: "Offsite".to_string(),
+                start: date(23),
+                end: date(23),
+            }],
+            ..BusinessCalendar::default()
+        };
+        let options = TimeUntilOptions {
+            business_calendar: Some(calendar.clone()),
+            ..Default::default()
+        };
+        // Wed 2.5h + Fri 8h + Tue 2h; Thursday and Monday are off
+        let result = time_until(anchor(), "2026-02-24T11:00:00Z", "UTC", &options).unwrap();
+        assert_eq!(result.business.unwrap().total_seconds, 12 * 3600 + 1800);
+        let result = time_until_next(anchor(), "tuesday 11:00", "UTC", Some(&calendar)).unwrap();
+        assert_eq!(result.business.unwrap().total_seconds, 12 * 3600 + 1800);
+    }
+
+    // ── adjust_timestamp tests ──────────────────────────────────────────
+
+    #[test]
+    fn test_adjust_add_hours() {
+        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+2h", "UTC").unwrap();
+        assert!(result.adjusted_utc.contains("12:00:00"));
+    }
+
+    #[test]
+    fn test_adjust_subtract_days() {
+        let result = adjust_timestamp("2026-03-05T10:00:00Z", "-3d", "UTC").unwrap();
+        assert!(result.adjusted_utc.contains("2026-03-02"));
+    }
+
+    #[test]
+    fn test_adjust_add_minutes() {
+        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+90m", "UTC").unwrap();
+        assert!(result.adjusted_utc.contains("11:30:00"));
+    }
+
+    #[test]
+    fn test_adjust_add_weeks() {
+        let result = adjust_timestamp("2026-03-02T10:00:00Z", "+2w", "UTC").unwrap();
+        assert!(result.adjusted_utc.contains("2026-03-16"));
+    }
+
+    #[test]
+    fn test_adjust_compound_duration() {
+        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+1d2h30m", "UTC").unwrap();
+        // March 16 10:00 + 1d2h30m = March 17 12:30
+        assert!(result.adjusted_utc.contains("2026-03-17"));
+        assert!(result.adjusted_utc.contains("12:30:00"));
+    }
+
+    #[test]
+    fn test_adjust_day_across_dst() {
+        // March 8 2026: US spring forward. +1d should preserve wall-clock time.
+        let result = adjust_timestamp(
+            "2026-03-07T22:00:00-05:00", // 10pm EST (= 03:00 UTC on March 8)
+            "+1d",
+            "America/New_York",
+        )
+        .unwrap();
+        // March 8, 10pm EDT (now in EDT = -04:00)
+        assert!(result.adjusted_local.contains("22:00:00"));
+    }
+
+    #[test]
+    fn test_adjust_negative_compound() {
+        let result = adjust_timestamp("2026-03-16T10:00:00Z", "-1d12h", "UTC").unwrap();
+        // March 16 10:00 - 1d12h = March 14 22:00
+        assert!(result.adjusted_utc.contains("2026-03-14"));
+        assert!(result.adjusted_utc.contains("22:00:00"));
+    }
+
+    #[test]
+    fn test_adjust_add_seconds() {
+        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+3600s", "UTC").unwrap();
+        assert!(result.adjusted_utc.contains("11:00:00"));
+    }
+
+    #[test]
+    fn test_adjust_invalid_format() {
+        let result = adjust_timestamp("2026-03-16T10:00:00Z", "2h", "UTC");
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("must start with '+' or '-'"), "got: {err}");
     }
-}
 
-/// Try weekday-relative: "next Monday", "this Friday", "last Wednesday".
-fn try_weekday_relative(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
-    let parts: Vec<&str> = s.splitn(2, ' ').collect();
-    if parts.len() != 2 {
-        return None;
+    #[test]
+    fn test_adjust_fractional_components() {
+        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+1.5h", "UTC").unwrap();
+        assert_eq!(result.adjusted_utc, "2026-03-16T11:30:00+00:00");
+        assert_eq!(result.adjustment_applied, "+1h30m");
+
+        let result = adjust_timestamp("2026-03-16T10:00:00Z", "-0.25d", "UTC").unwrap();
+        assert_eq!(result.adjusted_utc, "2026-03-16T04:00:00+00:00");
     }
 
-    let modifier = parts[0];
-    let weekday = parse_weekday(parts[1])?;
-    let current = local.weekday();
+    #[test]
+    fn test_adjust_fractional_day_keeps_wall_clock_days() {
+        // 2.5 days across spring-forward: 2 calendar days, then 12 hours
+        let result =
+            adjust_timestamp("2026-03-07T09:00:00-05:00", "+2.5d", "America/New_York").unwrap();
+        assert_eq!(result.adjusted_local, "2026-03-09T21:00:00-04:00");
+    }
 
-    let target_date = match modifier {
-        "next" => {
-            // Always future: if today is the same weekday, go to next week
-            let days_ahead =
-                (weekday.num_days_from_monday() as i64 - current.num_days_from_monday() as i64 + 7)
-                    % 7;
-            let days_ahead = if days_ahead == 0 { 7 } else { days_ahead };
-            local.date_naive() + chrono::Duration::days(days_ahead)
-        }
-        "this" => {
-            // Same week: may be past or future
-            let diff =
-                weekday.num_days_from_monday() as i64 - current.num_days_from_monday() as i64;
-            local.date_naive() + chrono::Duration::days(diff)
-        }
-        "last" => {
-            // Always past: if today is the same weekday, go to last week
-            let days_back =
-                (current.num_days_from_monday() as i64 - weekday.num_days_from_monday() as i64 + 7)
-                    % 7;
-            let days_back = if days_back == 0 { 7 } else { days_back };
-            local.date_naive() - chrono::Duration::days(days_back)
+    #[test]
+    fn test_adjust_rejects_malformed_decimals() {
+        for bad in ["+1.h", "+1..5h", "+.h", "+1.2345678h"] {
+            let result = adjust_timestamp("2026-03-16T10:00:00Z", bad, "UTC");
+            assert!(
+                matches!(result, Err(TruthError::InvalidDuration(_))),
+                "{bad} should fail"
+            );
         }
-        _ => return None,
-    };
-
-    let naive = target_date.and_hms_opt(0, 0, 0)?;
-    tz.from_local_datetime(&naive).single()
-}
+    }
 
-/// Try combined weekday + time: "next Tuesday at 2pm", "next Friday at 10:30am".
-fn try_combined_weekday_time(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
-    // Pattern: "(next|this|last) <weekday> at <time>"
-    // or: "(next|this|last) <weekday> <named_time>"
-    let parts: Vec<&str> = s.splitn(3, ' ').collect();
-    if parts.len() < 2 {
-        return None;
+    #[test]
+    fn test_adjust_working_hours_skip_nights_and_weekends() {
+        // Friday 15:00 + 6 working hours: 2 on Friday, 4 on Monday
+        let result = adjust_timestamp("2026-02-20T15:00:00Z", "+6bh", "UTC").unwrap();
+        assert_eq!(result.adjusted_utc, "2026-02-23T13:00:00+00:00");
+        assert_eq!(result.adjustment_applied, "+6bh");
+        // Outside working hours, counting starts at the next opening.
+        let result = adjust_timestamp("2026-02-18T20:00:00Z", "+30bm", "UTC").unwrap();
+        assert_eq!(result.adjusted_utc, "2026-02-19T09:30:00+00:00");
+        // Days apply first, then working time.
+        let result = adjust_timestamp("2026-02-19T16:00:00Z", "+1d2bh", "UTC").unwrap();
+        assert_eq!(result.adjusted_utc, "2026-02-23T10:00:00+00:00");
+        let result = adjust_timestamp("2026-02-23T10:00:00Z", "-2bh", "UTC").unwrap();
+        assert_eq!(result.adjusted_utc, "2026-02-20T16:00:00+00:00");
+        // Working hours are read in the target zone: 09:00–17:00 Tokyo.
+        let result = adjust_timestamp("2026-02-20T07:00:00Z", "+2bh", "Asia/Tokyo").unwrap();
+        assert_eq!(result.adjusted_local, "2026-02-23T10:00:00+09:00");
     }
 
-    let modifier = parts[0];
-    if !matches!(modifier, "next" | "this" | "last") {
-        return None;
+    #[test]
+    fn test_adjust_zero_duration() {
+        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+0h", "UTC").unwrap();
+        assert!(result.adjusted_utc.contains("10:00:00"));
     }
 
-    // Check for weekday in parts[1]
-    let weekday_str = parts[1];
-    let _weekday = parse_weekday(weekday_str)?;
+    // ── Parse-error span tests ──────────────────────────────────────────
 
-    // Get the base date from weekday-relative
-    let weekday_expr = format!("{} {}", modifier, weekday_str);
-    let base = try_weekday_relative(&weekday_expr, local, tz)?;
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_unresolvable_expressions_are_located() {
+        let span = |expr: &str| {
+            let err = resolve_relative(anchor(), expr, "UTC").unwrap_err();
+            assert!(matches!(err, TruthError::InvalidExpression(_)));
+            err.span().map(|s| (s.char_offset, s.token.clone()))
+        };
+        // A misspelling, with its correction in the message.
+        assert_eq!(
+            span("the day after tomorow"),
+            Some((14, "tomorow".to_string()))
+        );
+        let err = resolve_relative(anchor(), "tomorow at 3pm", "UTC").unwrap_err();
+        assert!(err.to_string().contains("did you mean 'tomorrow'"), "{err}");
+        // The token after the longest prefix that resolves, with its connective.
+        assert_eq!(span("tomorrow at 25pm"), Some((9, "at 25pm".to_string())));
+        assert_eq!(span("next friday please"), Some((12, "please".to_string())));
+        // A word the grammar does not know; offsets count characters.
+        assert_eq!(span("in 3 fortnights"), Some((5, "fortnights".to_string())));
+        assert_eq!(span("— wibble"), Some((2, "wibble".to_string())));
+        let err = resolve_relative(anchor(), "— wibble", "UTC").unwrap_err();
+        assert_eq!(err.span().unwrap().offset, 4);
+        // Nothing to point at.
+        assert_eq!(span("3"), None);
+    }
+
+    // ── Duration parse/format tests ─────────────────────────────────────
 
-    if parts.len() == 2 {
-        return Some(base);
+    #[test]
+    fn test_duration_parses_compact_and_iso() {
+        let parse = |s: &str| s.parse::<Duration>().unwrap();
+        assert_eq!(
+            parse("90m"),
+            Duration {
+                minutes: 90,
+                ..Default::default()
+            }
+        );
+        assert_eq!(parse("+1d2h30m"), parse("P1DT2H30M"));
+        assert_eq!(parse("-1.5h"), parse("-PT1H30M"));
+        assert_eq!(parse("PT0,5H"), parse("30m"));
+        assert_eq!(
+            parse("p2w"),
+            Duration {
+                weeks: 2,
+                ..Default::default()
+            }
+        );
+        assert_eq!(parse("P1.5D").to_string(), "+1d12h");
+        assert_eq!(
+            parse("+1.5bh"),
+            Duration {
+                business_minutes: 90,
+                ..Default::default()
+            }
+        );
+        assert_eq!(parse("-2h30bm").business_seconds(), -1800);
+
+        for bad in [
+            "", "P", "PT", "P1M", "P1Y", "PT1D", "P1H", "1x", "h", "P1DT", "1b", "1bd", "bh",
+        ] {
+            assert!(
+                matches!(bad.parse::<Duration>(), Err(TruthError::InvalidDuration(_))),
+                "{bad:?} should fail"
+            );
+        }
     }
 
-    let time_part = parts[2];
-
-    // Handle "at <time>" pattern
-    if let Some(at_time) = time_part.strip_prefix("at ") {
-        let time = parse_time_string(at_time)?;
-        let naive = base.date_naive().and_time(time);
-        return tz.from_local_datetime(&naive).single();
+    #[test]
+    fn test_duration_parse_adjustment_matches_adjust_timestamp() {
+        for input in [
+            "+2h", "-1d30m", "+1.5h", "2h", "+", "+1x", "PT1H", "90m", "PT1H30M", "+PT1H30M",
+            "-P1D",
+        ] {
+            assert_eq!(
+                Duration::parse_adjustment(input).is_ok(),
+                adjust_timestamp("2026-03-16T10:00:00Z", input, "UTC").is_ok(),
+                "{input}"
+            );
+        }
     }
 
-    // Handle named time: "morning", "afternoon", etc.
-    if let Some(time) = named_time_to_naive(time_part) {
-        let naive = base.date_naive().and_time(time);
-        return tz.from_local_datetime(&naive).single();
+    #[test]
+    fn test_duration_parse_adjustment_suggests_signed_form() {
+        // Valid durations that are not adjustments say what to write instead,
+        // and adjust_timestamp reports the same error.
+        for (input, suggestion) in [
+            ("90m", "'+90m'"),
+            ("PT1H30M", "'+1h30m'"),
+            ("-P1D", "'-1d'"),
+        ] {
+            assert!(input.parse::<Duration>().is_ok(), "{input}");
+            let err = Duration::parse_adjustment(input).unwrap_err().to_string();
+            assert!(err.contains(suggestion), "{input}: {err}");
+            let adjust_err = adjust_timestamp("2026-03-16T10:00:00Z", input, "UTC")
+                .unwrap_err()
+                .to_string();
+            assert_eq!(adjust_err, err);
+        }
     }
 
-    None
-}
+    #[test]
+    fn test_duration_formats() {
+        let d: Duration = "-1w2d3h4m5s".parse().unwrap();
+        assert_eq!(d.to_string(), "-1w2d3h4m5s");
+        assert_eq!(d.format(DurationFormat::Iso8601), "-P9DT3H4M5S");
+        assert_eq!(
+            d.format(DurationFormat::Human),
+            "minus 1 week, 2 days, 3 hours, 4 minutes, 5 seconds"
+        );
+
+        let zero = Duration::default();
+        assert!(zero.is_zero());
+        assert_eq!(zero.to_string(), "+0s");
+        assert_eq!(zero.format(DurationFormat::Iso8601), "PT0S");
+        assert_eq!(zero.format(DurationFormat::Human), "0 seconds");
+
+        let working: Duration = "+1d1.5bh".parse().unwrap();
+        assert_eq!(working.to_string(), "+1d1bh30bm");
+        assert_eq!(
+            working.format(DurationFormat::Human),
+            "1 day, 1 business hour, 30 business minutes"
+        );
+        assert_eq!(working.format(DurationFormat::Iso8601), "P1D");
+
+        let weeks: Duration = "+2w".parse().unwrap();
+        assert_eq!(weeks.format(DurationFormat::Iso8601), "P2W");
+        assert_eq!(weeks.format(DurationFormat::Human), "2 weeks");
+
+        let long: Duration = "3725s".parse().unwrap();
+        assert_eq!(long.to_string(), "+3725s");
+        assert_eq!(long.normalized().to_string(), "+1h2m5s");
+        assert_eq!(
+            "-1d90m"
+                .parse::<Duration>()
+                .unwrap()
+                .normalized()
+                .to_string(),
+            "-1d1h30m"
+        );
+    }
+
+    // ── round_timestamp tests ───────────────────────────────────────────
 
-/// Try combined anchor + time: "tomorrow at 2pm", "today at noon", "tomorrow morning".
-fn try_combined_anchor_time(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
-    let parts: Vec<&str> = s.splitn(2, ' ').collect();
-    if parts.len() != 2 {
-        return None;
+    #[test]
+    fn test_round_nearest_15_minutes() {
+        let result = round_timestamp(
+            "2026-03-16T10:07:00Z",
+            RoundingGranularity::Minutes(15),
+            RoundingMode::Nearest,
+            "UTC",
+        )
+        .unwrap();
+        assert!(result.adjusted_utc.contains("10:00:00"));
+        assert_eq!(result.adjustment_applied, "-7m");
     }
 
-    let anchor_str = parts[0];
-    if !matches!(anchor_str, "today" | "tomorrow" | "yesterday") {
-        return None;
+    #[test]
+    fn test_round_nearest_halfway_rounds_up() {
+        let result = round_timestamp(
+            "2026-03-16T10:02:30Z",
+            RoundingGranularity::Minutes(5),
+            RoundingMode::Nearest,
+            "UTC",
+        )
+        .unwrap();
+        assert!(result.adjusted_utc.contains("10:05:00"));
     }
 
-    let base = try_anchored(anchor_str, local, tz)?;
-    let time_part = parts[1];
+    #[test]
+    fn test_round_down_30_minutes() {
+        let result = round_timestamp(
+            "2026-03-16T10:59:59Z",
+            RoundingGranularity::Minutes(30),
+            RoundingMode::Down,
+            "UTC",
+        )
+        .unwrap();
+        assert!(result.adjusted_utc.contains("10:30:00"));
+    }
 
-    // "at <time>" — try named time first (e.g., "at noon"), then explicit time (e.g., "at 2pm")
-    if let Some(at_time) = time_part.strip_prefix("at ") {
-        if let Some(time) = named_time_to_naive(at_time) {
-            let naive = base.date_naive().and_time(time);
-            return tz.from_local_datetime(&naive).single();
-        }
-        let time = parse_time_string(at_time)?;
-        let naive = base.date_naive().and_time(time);
-        return tz.from_local_datetime(&naive).single();
+    #[test]
+    fn test_round_on_boundary_is_unchanged() {
+        let result = round_timestamp(
+            "2026-03-16T10:30:00Z",
+            RoundingGranularity::Minutes(30),
+            RoundingMode::Up,
+            "UTC",
+        )
+        .unwrap();
+        assert!(result.adjusted_utc.contains("10:30:00"));
+        assert_eq!(result.adjustment_applied, "+0s");
     }
 
-    // Named time
-    if let Some(time) = named_time_to_naive(time_part) {
-        let naive = base.date_naive().and_time(time);
-        return tz.from_local_datetime(&naive).single();
+    #[test]
+    fn test_round_hour_uses_local_clock() {
+        // 10:10 UTC = 15:40 IST (+05:30) → up to 16:00 IST = 10:30 UTC
+        let result = round_timestamp(
+            "2026-03-16T10:10:00Z",
+            RoundingGranularity::Minutes(60),
+            RoundingMode::Up,
+            "Asia/Kolkata",
+        )
+        .unwrap();
+        assert!(result.adjusted_local.contains("16:00:00+05:30"));
+        assert!(result.adjusted_utc.contains("10:30:00"));
     }
 
-    None
-}
+    #[test]
+    fn test_round_day_up_is_local_midnight() {
+        // 2026-03-16 18:00 EDT → next local midnight
+        let result = round_timestamp(
+            "2026-03-16T22:00:00Z",
+            RoundingGranularity::Day,
+            RoundingMode::Up,
+            "America/New_York",
+        )
+        .unwrap();
+        assert_eq!(result.adjusted_local, "2026-03-17T00:00:00-04:00");
+    }
 
-/// Try time-of-day named anchors: "morning", "noon", "afternoon", etc.
-fn try_time_of_day_named(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
-    let time = named_time_to_naive(s)?;
-    let naive = local.date_naive().and_time(time);
-    tz.from_local_datetime(&naive).single()
-}
+    #[test]
+    fn test_round_in_repeated_hour() {
+        let round = |datetime, mode| {
+            round_timestamp(
+                datetime,
+                RoundingGranularity::Minutes(60),
+                mode,
+                "America/New_York",
+            )
+            .unwrap()
+            .adjusted_utc
+        };
+        // 06:40 UTC = 01:40 EST, the second 01:00-02:00 on November 1, 2026.
+        let est = "2026-11-01T06:40:00Z";
+        assert_eq!(round(est, RoundingMode::Down), "2026-11-01T06:00:00+00:00");
+        assert_eq!(round(est, RoundingMode::Up), "2026-11-01T07:00:00+00:00");
+        assert_eq!(
+            round(est, RoundingMode::Nearest),
+            "2026-11-01T07:00:00+00:00"
+        );
+        // 05:10 UTC = 01:10 EDT, the first; the next boundary is 01:00 EST.
+        let edt = "2026-11-01T05:10:00Z";
+        assert_eq!(round(edt, RoundingMode::Down), "2026-11-01T05:00:00+00:00");
+        assert_eq!(round(edt, RoundingMode::Up), "2026-11-01T06:00:00+00:00");
+        assert_eq!(
+            round(edt, RoundingMode::Nearest),
+            "2026-11-01T05:00:00+00:00"
+        );
+    }
 
-/// Try explicit time: "2pm", "2:30pm", "14:00".
-fn try_explicit_time(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
-    let time = parse_time_string(s)?;
-    let naive = local.date_naive().and_time(time);
-    tz.from_local_datetime(&naive).single()
-}
+    #[test]
+    fn test_round_invalid_granularity() {
+        let result = round_timestamp(
+            "2026-03-16T10:07:00Z",
+            RoundingGranularity::Minutes(7),
+            RoundingMode::Nearest,
+            "UTC",
+        );
+        assert!(matches!(result, Err(TruthError::InvalidDuration(_))));
+    }
 
-/// Try natural offset: "in 2 hours", "30 minutes ago", "a week from now".
-fn try_natural_offset(s: &str, anchor: &DateTime<Utc>) -> Option<DateTime<Tz>> {
-    // "in N unit(s)"
-    if let Some(rest) = s.strip_prefix("in ") {
-        let (n, unit) = parse_natural_number_and_unit(rest)?;
-        let seconds = unit_to_seconds(n, &unit)?;
-        let result = *anchor + chrono::Duration::seconds(seconds);
-        // Return as UTC (which is a valid Tz via chrono_tz)
-        let utc_tz: Tz = "UTC".parse().ok()?;
-        return Some(result.with_timezone(&utc_tz));
+    // ── adjust_to_next tests ────────────────────────────────────────────
+
+    #[test]
+    fn test_adjust_to_next_time_later_today() {
+        let result = adjust_to_next("2026-03-16T07:15:00Z", "9am", "UTC").unwrap();
+        assert_eq!(result.adjusted_utc, "2026-03-16T09:00:00+00:00");
+        assert_eq!(result.adjustment_applied, "+1h45m");
     }
 
-    // "N unit(s) ago"
-    if s.ends_with(" ago") {
-        let rest = s.strip_suffix(" ago")?;
-        let (n, unit) = parse_natural_number_and_unit(rest)?;
-        let seconds = unit_to_seconds(n, &unit)?;
-        let result = *anchor - chrono::Duration::seconds(seconds);
-        let utc_tz: Tz = "UTC".parse().ok()?;
-        return Some(result.with_timezone(&utc_tz));
+    #[test]
+    fn test_adjust_to_next_is_strictly_after() {
+        let result = adjust_to_next("2026-03-16T09:00:00Z", "09:00", "UTC").unwrap();
+        assert_eq!(result.adjusted_utc, "2026-03-17T09:00:00+00:00");
     }
 
-    // "a/an <unit> from now"
-    if s.ends_with(" from now") {
-        let rest = s.strip_suffix(" from now")?;
-        let (n, unit) = parse_natural_number_and_unit_with_article(rest)?;
-        let seconds = unit_to_seconds(n, &unit)?;
-        let result = *anchor + chrono::Duration::seconds(seconds);
-        let utc_tz: Tz = "UTC".parse().ok()?;
-        return Some(result.with_timezone(&utc_tz));
+    #[test]
+    fn test_adjust_to_next_top_of_hour() {
+        let result =
+            adjust_to_next("2026-03-16T10:20:00Z", "top of the hour", "Asia/Kolkata").unwrap();
+        // 10:20 UTC = 15:50 IST → 16:00 IST
+        assert_eq!(result.adjusted_local, "2026-03-16T16:00:00+05:30");
     }
 
-    None
-}
+    #[test]
+    fn test_adjust_to_next_weekday_time() {
+        // Monday March 16 10:00 UTC → next Monday 09:00 Berlin is March 23
+        let result =
+            adjust_to_next("2026-03-16T10:00:00Z", "Monday 09:00", "Europe/Berlin").unwrap();
+        assert_eq!(result.adjusted_local, "2026-03-23T09:00:00+01:00");
+    }
 
-/// Try duration offset: "+2h", "-30m", "+1d2h30m".
-fn try_duration_offset(s: &str, anchor: &DateTime<Utc>) -> Option<DateTime<Tz>> {
-    if !s.starts_with('+') && !s.starts_with('-') {
-        return None;
+    #[test]
+    fn test_adjust_to_next_weekday_at_named_time() {
+        // Wednesday Feb 18 → Friday Feb 20 at 17:00
+        let result = adjust_to_next("2026-02-18T14:30:00Z", "Friday at eob", "UTC").unwrap();
+        assert_eq!(result.adjusted_utc, "2026-02-20T17:00:00+00:00");
     }
-    let parsed = parse_duration_string(s).ok()?;
-    let total_seconds = parsed.sign
-        * (parsed.weeks * 7 * 86400
-            + parsed.days * 86400
-            + parsed.hours * 3600
-            + parsed.minutes * 60
-            + parsed.seconds);
-    let result = *anchor + chrono::Duration::seconds(total_seconds);
-    let utc_tz: Tz = "UTC".parse().ok()?;
-    Some(result.with_timezone(&utc_tz))
-}
-
-/// Try period boundary: "start of week", "end of month", etc.
-fn try_period_boundary(
-    s: &str,
-    local: &DateTime<Tz>,
-    tz: &Tz,
-    ws: WeekStartDay,
-) -> Option<DateTime<Tz>> {
-    match s {
-        "start of today" => make_local_start_of_day(local, tz),
-        "end of today" => {
-            let naive = local.date_naive().and_hms_opt(23, 59, 59)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "start of week" => {
-            let days_since_start = days_from_week_start(local.weekday(), ws);
-            let start = local.date_naive() - chrono::Duration::days(days_since_start);
-            let naive = start.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "end of week" => {
-            let days_until_end = 6 - days_from_week_start(local.weekday(), ws);
-            let end = local.date_naive() + chrono::Duration::days(days_until_end);
-            let naive = end.and_hms_opt(23, 59, 59)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "start of month" => {
-            let date = NaiveDate::from_ymd_opt(local.year(), local.month(), 1)?;
-            let naive = date.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "end of month" => {
-            let (y, m) = if local.month() == 12 {
-                (local.year() + 1, 1)
-            } else {
-                (local.year(), local.month() + 1)
-            };
-            let first_next = NaiveDate::from_ymd_opt(y, m, 1)?;
-            let last_day = first_next.pred_opt()?;
-            let naive = last_day.and_hms_opt(23, 59, 59)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "start of year" => {
-            let date = NaiveDate::from_ymd_opt(local.year(), 1, 1)?;
-            let naive = date.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "end of year" => {
-            let date = NaiveDate::from_ymd_opt(local.year(), 12, 31)?;
-            let naive = date.and_hms_opt(23, 59, 59)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "start of quarter" => {
-            let q_start_month = ((local.month() - 1) / 3) * 3 + 1;
-            let date = NaiveDate::from_ymd_opt(local.year(), q_start_month, 1)?;
-            let naive = date.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "end of quarter" => {
-            let q_end_month = ((local.month() - 1) / 3 + 1) * 3;
-            let (y, m) = if q_end_month == 12 {
-                (local.year() + 1, 1)
-            } else {
-                (local.year(), q_end_month + 1)
-            };
-            let first_next = NaiveDate::from_ymd_opt(y, m, 1)?;
-            let last_day = first_next.pred_opt()?;
-            let naive = last_day.and_hms_opt(23, 59, 59)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        _ => None,
+
+    #[test]
+    fn test_adjust_to_next_across_spring_forward() {
+        // 02:30 does not exist on March 8, 2026 in New York → shifted to 03:30 EDT
+        let result = adjust_to_next("2026-03-08T05:00:00Z", "2:30am", "America/New_York").unwrap();
+        assert_eq!(result.adjusted_local, "2026-03-08T03:30:00-04:00");
     }
-}
 
-/// Try period relative: "next week", "last month", "next year", etc.
-fn try_period_relative(
-    s: &str,
-    local: &DateTime<Tz>,
-    tz: &Tz,
-    ws: WeekStartDay,
-) -> Option<DateTime<Tz>> {
-    match s {
-        "next week" => {
-            let days_until_next_start = 7 - days_from_week_start(local.weekday(), ws);
-            let start = local.date_naive() + chrono::Duration::days(days_until_next_start);
-            let naive = start.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "last week" => {
-            let days_since_start = days_from_week_start(local.weekday(), ws);
-            let this_start = local.date_naive() - chrono::Duration::days(days_since_start);
-            let last_start = this_start - chrono::Duration::days(7);
-            let naive = last_start.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "next month" => {
-            let (y, m) = if local.month() == 12 {
-                (local.year() + 1, 1)
-            } else {
-                (local.year(), local.month() + 1)
-            };
-            let date = NaiveDate::from_ymd_opt(y, m, 1)?;
-            let naive = date.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "last month" => {
-            let (y, m) = if local.month() == 1 {
-                (local.year() - 1, 12)
-            } else {
-                (local.year(), local.month() - 1)
-            };
-            let date = NaiveDate::from_ymd_opt(y, m, 1)?;
-            let naive = date.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "next year" => {
-            let date = NaiveDate::from_ymd_opt(local.year() + 1, 1, 1)?;
-            let naive = date.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        "last year" => {
-            let date = NaiveDate::from_ymd_opt(local.year() - 1, 1, 1)?;
-            let naive = date.and_hms_opt(0, 0, 0)?;
-            tz.from_local_datetime(&naive).single()
-        }
-        _ => None,
+    #[test]
+    fn test_adjust_to_next_top_of_hour_in_repeated_hour() {
+        // 05:30 UTC = 01:30 EDT on November 1, 2026; 01:00 repeats as EST next.
+        let result = adjust_to_next(
+            "2026-11-01T05:30:00Z",
+            "top of the hour",
+            "America/New_York",
+        )
+        .unwrap();
+        assert_eq!(result.adjusted_utc, "2026-11-01T06:00:00+00:00");
+        assert_eq!(result.adjusted_local, "2026-11-01T01:00:00-05:00");
+        assert_eq!(result.adjustment_applied, "+30m");
+
+        // From the repeated hour, the next is 02:00 EST.
+        let result = adjust_to_next(
+            "2026-11-01T06:30:00Z",
+            "top of the hour",
+            "America/New_York",
+        )
+        .unwrap();
+        assert_eq!(result.adjusted_local, "2026-11-01T02:00:00-05:00");
     }
-}
 
-/// Try compound period: "start of last week", "end of next month", etc.
-///
-/// Combines a boundary (start/end) with a period relative (last/next week/month/year/quarter).
-fn try_compound_period(
-    s: &str,
-    local: &DateTime<Tz>,
-    tz: &Tz,
-    ws: WeekStartDay,
-) -> Option<DateTime<Tz>> {
-    let (is_start, rest) = if let Some(r) = s.strip_prefix("start of ") {
-        (true, r)
-    } else if let Some(r) = s.strip_prefix("end of ") {
-        (false, r)
-    } else {
-        return None;
-    };
+    #[test]
+    fn test_adjust_to_next_time_in_repeated_hour() {
+        // 01:10 EST, after the first 01:30 has passed: the second 01:30 is next.
+        let result = adjust_to_next("2026-11-01T06:10:00Z", "1:30am", "America/New_York").unwrap();
+        assert_eq!(result.adjusted_local, "2026-11-01T01:30:00-05:00");
+    }
 
-    match rest {
-        "last week" => {
-            let days_since_start = days_from_week_start(local.weekday(), ws);
-            let this_start = local.date_naive() - chrono::Duration::days(days_since_start);
-            let last_start = this_start - chrono::Duration::days(7);
-            if is_start {
-                let naive = last_start.and_hms_opt(0, 0, 0)?;
-                tz.from_local_datetime(&naive).single()
-            } else {
-                let last_end = last_start + chrono::Duration::days(6);
-                let naive = last_end.and_hms_opt(23, 59, 59)?;
-                tz.from_local_datetime(&naive).single()
-            }
-        }
-        "next week" => {
-            let days_until_next_start = 7 - days_from_week_start(local.weekday(), ws);
-            let next_start = local.date_naive() + chrono::Duration::days(days_until_next_start);
-            if is_start {
-                let naive = next_start.and_hms_opt(0, 0, 0)?;
-                tz.from_local_datetime(&naive).single()
-            } else {
-                let next_end = next_start + chrono::Duration::days(6);
-                let naive = next_end.and_hms_opt(23, 59, 59)?;
-                tz.from_local_datetime(&naive).single()
-            }
-        }
-        "last month" => {
-            let (y, m) = if local.month() == 1 {
-                (local.year() - 1, 12)
-            } else {
-                (local.year(), local.month() - 1)
-            };
-            if is_start {
-                let date = NaiveDate::from_ymd_opt(y, m, 1)?;
-                let naive = date.and_hms_opt(0, 0, 0)?;
-                tz.from_local_datetime(&naive).single()
-            } else {
-                // Last day of prev month = day before 1st of current month
-                let first_current = NaiveDate::from_ymd_opt(local.year(), local.month(), 1)?;
-                let last_day = first_current.pred_opt()?;
-                let naive = last_day.and_hms_opt(23, 59, 59)?;
-                tz.from_local_datetime(&naive).single()
-            }
-        }
-        "next month" => {
-            let (y, m) = if local.month() == 12 {
-                (local.year() + 1, 1)
-            } else {
-                (local.year(), local.month() + 1)
-            };
-            if is_start {
-                let date = NaiveDate::from_ymd_opt(y, m, 1)?;
-                let naive = date.and_hms_opt(0, 0, 0)?;
-                tz.from_local_datetime(&naive).single()
-            } else {
-                // Last day of next month
-                let (ny, nm) = if m == 12 { (y + 1, 1) } else { (y, m + 1) };
-                let first_after = NaiveDate::from_ymd_opt(ny, nm, 1)?;
-                let last_day = first_after.pred_opt()?;
-                let naive = last_day.and_hms_opt(23, 59, 59)?;
-                tz.from_local_datetime(&naive).single()
-            }
-        }
-        "last year" => {
-            let y = local.year() - 1;
-            if is_start {
-                let date = NaiveDate::from_ymd_opt(y, 1, 1)?;
-                let naive = date.and_hms_opt(0, 0, 0)?;
-                tz.from_local_datetime(&naive).single()
-            } else {
-                let date = NaiveDate::from_ymd_opt(y, 12, 31)?;
-                let naive = date.and_hms_opt(23, 59, 59)?;
-                tz.from_local_datetime(&naive).single()
-            }
-        }
-        "next year" => {
-            let y = local.year() + 1;
-            if is_start {
-                let date = NaiveDate::from_ymd_opt(y, 1, 1)?;
-                let naive = date.and_hms_opt(0, 0, 0)?;
-                tz.from_local_datetime(&naive).single()
-            } else {
-                let date = NaiveDate::from_ymd_opt(y, 12, 31)?;
-                let naive = date.and_hms_opt(23, 59, 59)?;
-                tz.from_local_datetime(&naive).single()
-            }
-        }
-        "last quarter" => {
-            let current_q = (local.month() - 1) / 3; // 0-based: Q1=0, Q2=1, Q3=2, Q4=3
-            let (prev_y, prev_q) = if current_q == 0 {
-                (local.year() - 1, 3)
-            } else {
-                (local.year(), current_q - 1)
-            };
-            let q_first_month = prev_q * 3 + 1;
-            if is_start {
-                let date = NaiveDate::from_ymd_opt(prev_y, q_first_month, 1)?;
-                let naive = date.and_hms_opt(0, 0, 0)?;
-                tz.from_local_datetime(&naive).single()
-            } else {
-                let q_last_month = prev_q * 3 + 3;
-                let (ny, nm) = if q_last_month == 12 {
-                    (prev_y + 1, 1)
-                } else {
-                    (prev_y, q_last_month + 1)
-                };
-                let first_after = NaiveDate::from_ymd_opt(ny, nm, 1)?;
-                let last_day = first_after.pred_opt()?;
-                let naive = last_day.and_hms_opt(23, 59, 59)?;
-                tz.from_local_datetime(&naive).single()
-            }
-        }
-        "next quarter" => {
-            let current_q = (local.month() - 1) / 3;
-            let (next_y, next_q) = if current_q == 3 {
-                (local.year() + 1, 0)
-            } else {
-                (local.year(), current_q + 1)
-            };
-            let q_first_month = next_q * 3 + 1;
-            if is_start {
-                let date = NaiveDate::from_ymd_opt(next_y, q_first_month, 1)?;
-                let naive = date.and_hms_opt(0, 0, 0)?;
-                tz.from_local_datetime(&naive).single()
-            } else {
-                let q_last_month = next_q * 3 + 3;
-                let (ny, nm) = if q_last_month == 12 {
-                    (next_y + 1, 1)
-                } else {
-                    (next_y, q_last_month + 1)
-                };
-                let first_after = NaiveDate::from_ymd_opt(ny, nm, 1)?;
-                let last_day = first_after.pred_opt()?;
-                let naive = last_day.and_hms_opt(23, 59, 59)?;
-                tz.from_local_datetime(&naive).single()
-            }
-        }
-        _ => None,
+    #[test]
+    fn test_adjust_to_next_invalid_target() {
+        let result = adjust_to_next("2026-03-16T10:00:00Z", "whenever", "UTC");
+        assert!(matches!(result, Err(TruthError::InvalidExpression(_))));
     }
-}
 
-/// Try ordinal date: "first Monday of March", "last Friday of the month",
-/// "third Tuesday of March 2026".
-fn try_ordinal_date(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
-    // Pattern: "<ordinal> <weekday> of <month> [year]"
-    // or: "last <weekday> of <month>" / "last day of <month>"
-    let parts: Vec<&str> = s.split_whitespace().collect();
+    // ── resolve_relative tests ──────────────────────────────────────────
 
-    if parts.len() < 4 || parts.iter().position(|&p| p == "of")? < 2 {
-        return None;
+    #[cfg(feature = "resolver")]
+    fn anchor() -> DateTime<Utc> {
+        // Wednesday, February 18, 2026, 14:30:00 UTC
+        Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap()
     }
 
-    let of_idx = parts.iter().position(|&p| p == "of")?;
-    if of_idx < 2 {
-        return None;
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_now() {
+        let result = resolve_relative(anchor(), "now", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("14:30:00"));
     }
 
-    let ordinal_str = parts[0];
-    let target_str = parts[1];
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_today() {
+        let result = resolve_relative(anchor(), "today", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-18"));
+        assert!(result.resolved_utc.contains("00:00:00"));
+    }
 
-    // Parse "last day of <month>"
-    if ordinal_str == "last" && target_str == "day" {
-        let month_str = parts.get(of_idx + 1)?;
-        let month = parse_month(month_str)?;
-        let year = if let Some(y_str) = parts.get(of_idx + 2) {
-            y_str.parse::<i32>().ok()?
-        } else {
-            local.year()
-        };
-        let (ny, nm) = if month == 12 {
-            (year + 1, 1)
-        } else {
-            (year, month + 1)
-        };
-        let first_next = NaiveDate::from_ymd_opt(ny, nm, 1)?;
-        let last_day = first_next.pred_opt()?;
-        let naive = last_day.and_hms_opt(0, 0, 0)?;
-        return tz.from_local_datetime(&naive).single();
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_tomorrow() {
+        let result = resolve_relative(anchor(), "tomorrow", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-19"));
+        assert!(result.resolved_utc.contains("00:00:00"));
     }
 
-    let weekday = parse_weekday(target_str)?;
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_yesterday() {
+        let result = resolve_relative(anchor(), "yesterday", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-17"));
+    }
 
-    let month_part = parts.get(of_idx + 1)?;
-    // "the month" → current month, otherwise parse month name
-    let (month, year) = if *month_part == "month" {
-        (local.month(), local.year())
-    } else if let Some(month_num) = parse_month(month_part) {
-        let year = if let Some(y_str) = parts.get(of_idx + 2) {
-            y_str.parse::<i32>().unwrap_or(local.year())
-        } else {
-            local.year()
-        };
-        (month_num, year)
-    } else if *month_part == "next" && parts.get(of_idx + 2) == Some(&"month") {
-        let (y, m) = if local.month() == 12 {
-            (local.year() + 1, 1)
-        } else {
-            (local.year(), local.month() + 1)
-        };
-        (m, y)
-    } else {
-        return None;
-    };
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_next_monday_from_wednesday() {
+        // Anchor is Wednesday Feb 18 → next Monday is Feb 23
+        let result = resolve_relative(anchor(), "next Monday", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-23"));
+    }
 
-    let ordinal = parse_ordinal(ordinal_str)?;
-
-    let date = find_nth_weekday_in_month(year, month, weekday, ordinal)?;
-    let naive = date.and_hms_opt(0, 0, 0)?;
-    tz.from_local_datetime(&naive).single()
-}
-
-/// Find the Nth weekday in a month. ordinal < 0 means "last" (-1), "second to last" (-2), etc.
-fn find_nth_weekday_in_month(
-    year: i32,
-    month: u32,
-    weekday: Weekday,
-    ordinal: i32,
-) -> Option<NaiveDate> {
-    if ordinal > 0 {
-        // Forward from the first of the month
-        let first = NaiveDate::from_ymd_opt(year, month, 1)?;
-        let first_wd = first.weekday();
-        let diff = (weekday.num_days_from_monday() as i32 - first_wd.num_days_from_monday() as i32
-            + 7)
-            % 7;
-        let first_occurrence = first + chrono::Duration::days(diff as i64);
-        let target = first_occurrence + chrono::Duration::weeks((ordinal - 1) as i64);
-        // Verify still in the same month
-        if target.month() == month {
-            Some(target)
-        } else {
-            None
-        }
-    } else {
-        // Backward from the last of the month (ordinal = -1 means "last")
-        let (ny, nm) = if month == 12 {
-            (year + 1, 1)
-        } else {
-            (year, month + 1)
-        };
-        let first_next = NaiveDate::from_ymd_opt(ny, nm, 1)?;
-        let last = first_next.pred_opt()?;
-        let last_wd = last.weekday();
-        let diff =
-            (last_wd.num_days_from_monday() as i32 - weekday.num_days_from_monday() as i32 + 7) % 7;
-        let last_occurrence = last - chrono::Duration::days(diff as i64);
-        let target = last_occurrence - chrono::Duration::weeks((-ordinal - 1) as i64);
-        // Verify still in the same month
-        if target.month() == month {
-            Some(target)
-        } else {
-            None
-        }
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_next_friday_from_friday() {
+        // If anchor is Friday Feb 20 → next Friday should be Feb 27 (not same day)
+        let fri_anchor = Utc.with_ymd_and_hms(2026, 2, 20, 10, 0, 0).unwrap();
+        let result = resolve_relative(fri_anchor, "next Friday", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-27"));
     }
-}
 
-// ── Parsing helpers ─────────────────────────────────────────────────────────
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_this_wednesday_from_monday() {
+        let mon_anchor = Utc.with_ymd_and_hms(2026, 2, 16, 10, 0, 0).unwrap();
+        let result = resolve_relative(mon_anchor, "this Wednesday", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-18"));
+    }
 
-/// Parse a weekday name (case-insensitive, supports full and abbreviated).
-fn parse_weekday(s: &str) -> Option<Weekday> {
-    match s {
-        "monday" | "mon" => Some(Weekday::Mon),
-        "tuesday" | "tue" | "tues" => Some(Weekday::Tue),
-        "wednesday" | "wed" => Some(Weekday::Wed),
-        "thursday" | "thu" | "thurs" => Some(Weekday::Thu),
-        "friday" | "fri" => Some(Weekday::Fri),
-        "saturday" | "sat" => Some(Weekday::Sat),
-        "sunday" | "sun" => Some(Weekday::Sun),
-        _ => None,
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_last_tuesday_from_thursday() {
+        let thu_anchor = Utc.with_ymd_and_hms(2026, 2, 19, 10, 0, 0).unwrap();
+        let result = resolve_relative(thu_anchor, "last Tuesday", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-17"));
     }
-}
 
-/// Parse a month name to number (1-12).
-fn parse_month(s: &str) -> Option<u32> {
-    match s {
-        "january" | "jan" => Some(1),
-        "february" | "feb" => Some(2),
-        "march" | "mar" => Some(3),
-        "april" | "apr" => Some(4),
-        "may" => Some(5),
-        "june" | "jun" => Some(6),
-        "july" | "jul" => Some(7),
-        "august" | "aug" => Some(8),
-        "september" | "sep" | "sept" => Some(9),
-        "october" | "oct" => Some(10),
-        "november" | "nov" => Some(11),
-        "december" | "dec" => Some(12),
-        _ => None,
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_morning() {
+        let result = resolve_relative(anchor(), "morning", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("09:00:00"));
     }
-}
 
-/// Parse an ordinal: "first"→1, "second"→2, ..., "last"→-1.
-fn parse_ordinal(s: &str) -> Option<i32> {
-    match s {
-        "first" | "1st" => Some(1),
-        "second" | "2nd" => Some(2),
-        "third" | "3rd" => Some(3),
-        "fourth" | "4th" => Some(4),
-        "fifth" | "5th" => Some(5),
-        "last" => Some(-1),
-        _ => None,
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_noon() {
+        let result = resolve_relative(anchor(), "noon", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("12:00:00"));
     }
-}
 
-/// Map named time to NaiveTime.
-fn named_time_to_naive(s: &str) -> Option<NaiveTime> {
-    match s {
-        "morning" | "start of business" | "sob" => NaiveTime::from_hms_opt(9, 0, 0),
-        "noon" | "lunch" => NaiveTime::from_hms_opt(12, 0, 0),
-        "afternoon" => NaiveTime::from_hms_opt(13, 0, 0),
-        "end of day" | "end of business" | "eob" => NaiveTime::from_hms_opt(17, 0, 0),
-        "evening" => NaiveTime::from_hms_opt(18, 0, 0),
-        "night" => NaiveTime::from_hms_opt(21, 0, 0),
-        "midnight" => NaiveTime::from_hms_opt(0, 0, 0),
-        _ => None,
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_afternoon() {
+        let result = resolve_relative(anchor(), "afternoon", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("13:00:00"));
     }
-}
 
-/// Parse a time string: "2pm", "2:30pm", "14:00", "14:30:00".
-fn parse_time_string(s: &str) -> Option<NaiveTime> {
-    let s = s.trim();
-
-    // 24-hour format: "14:00", "14:30", "14:30:00"
-    if let Ok(t) = NaiveTime::parse_from_str(s, "%H:%M:%S") {
-        return Some(t);
-    }
-    if let Ok(t) = NaiveTime::parse_from_str(s, "%H:%M") {
-        return Some(t);
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_evening() {
+        let result = resolve_relative(anchor(), "evening", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("18:00:00"));
     }
 
-    // 12-hour format: "2pm", "2:30pm", "2:30:00pm", "2 pm"
-    let s_no_space = s.replace(' ', "");
-    let (time_part, is_pm) = if s_no_space.ends_with("pm") {
-        (s_no_space.strip_suffix("pm")?, true)
-    } else if s_no_space.ends_with("am") {
-        (s_no_space.strip_suffix("am")?, false)
-    } else {
-        return None;
-    };
-
-    let parts: Vec<&str> = time_part.split(':').collect();
-    let hour: u32 = parts.first()?.parse().ok()?;
-    let minute: u32 = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(0);
-    let second: u32 = parts.get(2).and_then(|s| s.parse().ok()).unwrap_or(0);
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_eob() {
+        let result = resolve_relative(anchor(), "eob", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("17:00:00"));
+    }
 
-    let hour24 = match (hour, is_pm) {
-        (12, true) => 12,
-        (12, false) => 0,
-        (h, true) => h + 12,
-        (h, false) => h,
-    };
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_midnight() {
+        let result = resolve_relative(anchor(), "midnight", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("00:00:00"));
+    }
 
-    NaiveTime::from_hms_opt(hour24, minute, second)
-}
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_2pm() {
+        let result = resolve_relative(anchor(), "2pm", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("14:00:00"));
+    }
 
-/// Parse "N unit(s)" from natural language (e.g., "2 hours", "30 minutes").
-fn parse_natural_number_and_unit(s: &str) -> Option<(i64, String)> {
-    let parts: Vec<&str> = s.split_whitespace().collect();
-    if parts.len() < 2 {
-        return None;
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_2_30pm() {
+        let result = resolve_relative(anchor(), "2:30pm", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("14:30:00"));
     }
-    let n: i64 = parts[0].parse().ok()?;
-    let unit = normalize_time_unit(parts[1])?;
-    Some((n, unit))
-}
 
-/// Parse "a/an unit from now" or "N unit(s) from now" prefix.
-fn parse_natural_number_and_unit_with_article(s: &str) -> Option<(i64, String)> {
-    let parts: Vec<&str> = s.split_whitespace().collect();
-    if parts.is_empty() {
-        return None;
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_14_00() {
+        let result = resolve_relative(anchor(), "14:00", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("14:00:00"));
     }
 
-    // "a week", "an hour"
-    if parts[0] == "a" || parts[0] == "an" {
-        if parts.len() < 2 {
-            return None;
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_fractional_offsets() {
+        let cases = [
+            ("in 1.5 hours", "2026-02-18T16:00:00+00:00"),
+            ("in 2.5 days", "2026-02-21T02:30:00+00:00"),
+            ("1.5h ago", "2026-02-18T13:00:00+00:00"),
+            ("1.5h", "2026-02-18T16:00:00+00:00"),
+            ("in 1.5h", "2026-02-18T16:00:00+00:00"),
+            ("90m from now", "2026-02-18T16:00:00+00:00"),
+            ("in 1.5x", ""),
+            ("in an hour and a half", "2026-02-18T16:00:00+00:00"),
+            ("an hour and a half from now", "2026-02-18T16:00:00+00:00"),
+            ("an hour and a half", "2026-02-18T16:00:00+00:00"),
+            ("2 and a half hours", "2026-02-18T17:00:00+00:00"),
+            ("half an hour", "2026-02-18T15:00:00+00:00"),
+            ("hour", ""),
+            ("in 2 and a half hours", "2026-02-18T17:00:00+00:00"),
+            ("half an hour ago", "2026-02-18T14:00:00+00:00"),
+            ("0.5 days ago", "2026-02-18T02:30:00+00:00"),
+            ("+1.5h", "2026-02-18T16:00:00+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let result = resolve_relative(anchor(), expr, "UTC");
+            if expected.is_empty() {
+                assert!(result.is_err(), "{expr} should fail");
+            } else {
+                assert_eq!(result.unwrap().resolved_utc, expected, "{expr}");
+            }
         }
-        let unit = normalize_time_unit(parts[1])?;
-        return Some((1, unit));
     }
 
-    // "2 hours", "30 minutes"
-    parse_natural_number_and_unit(s)
-}
-
-/// Normalize a time unit name to a standard form.
-fn normalize_time_unit(s: &str) -> Option<String> {
-    match s {
-        "second" | "seconds" | "sec" | "secs" => Some("seconds".to_string()),
-        "minute" | "minutes" | "min" | "mins" => Some("minutes".to_string()),
-        "hour" | "hours" | "hr" | "hrs" => Some("hours".to_string()),
-        "day" | "days" => Some("days".to_string()),
-        "week" | "weeks" | "wk" | "wks" => Some("weeks".to_string()),
-        _ => None,
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_rejects_half_of_fraction() {
+        let result = resolve_relative(anchor(), "in 1.5 and a half hours", "UTC");
+        assert!(matches!(result, Err(TruthError::InvalidExpression(_))));
     }
-}
 
-/// Convert a number and unit to total seconds.
-fn unit_to_seconds(n: i64, unit: &str) -> Option<i64> {
-    let multiplier = match unit {
-        "seconds" => 1,
-        "minutes" => 60,
-        "hours" => 3600,
-        "days" => 86400,
-        "weeks" => 604800,
-        _ => return None,
-    };
-    Some(n * multiplier)
-}
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_in_2_hours() {
+        let result = resolve_relative(anchor(), "in 2 hours", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("16:30:00"));
+    }
 
-/// Create a DateTime at the start of the day (00:00) in the given timezone.
-fn make_local_start_of_day(local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
-    let naive = local.date_naive().and_hms_opt(0, 0, 0)?;
-    tz.from_local_datetime(&naive).single()
-}
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_30_minutes_ago() {
+        let result = resolve_relative(anchor(), "30 minutes ago", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("14:00:00"));
+    }
 
-/// Format a human-readable interpretation string.
-fn format_interpretation<T: TimeZone>(dt: &DateTime<T>) -> String
-where
-    T::Offset: std::fmt::Display,
-{
-    dt.format("%A, %B %-d, %Y at %-I:%M %p %Z").to_string()
-}
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_in_3_days() {
+        let result = resolve_relative(anchor(), "in 3 days", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-21"));
+    }
 
-// ── Tests ───────────────────────────────────────────────────────────────────
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_a_week_from_now() {
+        let result = resolve_relative(anchor(), "a week from now", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-25"));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use chrono::TimeZone;
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_next_tuesday_at_2pm() {
+        // Anchor is Wed Feb 18 → next Tuesday is Feb 24, at 2pm
+        let result = resolve_relative(anchor(), "next Tuesday at 2pm", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-24"));
+        assert!(result.resolved_utc.contains("14:00:00"));
+    }
 
-    // ── convert_timezone tests ──────────────────────────────────────────
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_tomorrow_at_10_30am() {
+        let result = resolve_relative(anchor(), "tomorrow at 10:30am", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-19"));
+        assert!(result.resolved_utc.contains("10:30:00"));
+    }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_convert_utc_to_eastern() {
-        let result = convert_timezone("2026-03-15T14:00:00Z", "America/New_York").unwrap();
-        assert_eq!(result.timezone, "America/New_York");
-        // March 15 2026 is EDT (UTC-4), so 14:00 UTC = 10:00 local
-        assert!(result.local.contains("10:00:00"));
-        assert_eq!(result.utc, "2026-03-15T14:00:00+00:00");
+    fn test_resolve_tomorrow_morning() {
+        let result = resolve_relative(anchor(), "tomorrow morning", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-19"));
+        assert!(result.resolved_utc.contains("09:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_convert_eastern_to_pacific() {
-        // Input is in UTC-5 (EST), convert to Pacific
-        let result = convert_timezone("2026-01-15T14:00:00-05:00", "America/Los_Angeles").unwrap();
-        assert_eq!(result.timezone, "America/Los_Angeles");
-        // Jan 15 is PST (UTC-8). The input is 14:00 EST = 19:00 UTC = 11:00 PST
-        assert!(result.local.contains("11:00:00"));
+    fn test_resolve_next_friday_evening() {
+        // Anchor is Wed Feb 18 → next Friday is Feb 20, evening = 18:00
+        let result = resolve_relative(anchor(), "next Friday evening", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-20"));
+        assert!(result.resolved_utc.contains("18:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_convert_across_dst_spring_forward() {
-        // March 8, 2026: US spring forward (2:00 AM → 3:00 AM)
-        // Before DST: Jan 15, 2026 — EST (UTC-5)
-        let winter = convert_timezone("2026-01-15T12:00:00Z", "America/New_York").unwrap();
-        assert_eq!(winter.utc_offset, "-05:00");
-        assert!(!winter.dst_active);
+    fn test_resolve_today_at_noon() {
+        let result = resolve_relative(anchor(), "today at noon", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-18"));
+        assert!(result.resolved_utc.contains("12:00:00"));
+    }
 
-        // After DST: March 15, 2026 — EDT (UTC-4)
-        let summer = convert_timezone("2026-03-15T12:00:00Z", "America/New_York").unwrap();
-        assert_eq!(summer.utc_offset, "-04:00");
-        assert!(summer.dst_active);
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_start_of_week() {
+        // Anchor is Wed Feb 18 → start of ISO week is Mon Feb 16
+        let result = resolve_relative(anchor(), "start of week", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-16"));
+        assert!(result.resolved_utc.contains("00:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_convert_across_dst_fall_back() {
-        // November 1, 2026: US fall back (2:00 AM → 1:00 AM)
-        // After fall back: Nov 2 — EST (UTC-5)
-        let result = convert_timezone("2026-11-02T12:00:00Z", "America/New_York").unwrap();
-        assert_eq!(result.utc_offset, "-05:00");
-        assert!(!result.dst_active);
+    fn test_resolve_end_of_month() {
+        let result = resolve_relative(anchor(), "end of month", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-28"));
+        assert!(result.resolved_utc.contains("23:59:59"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_convert_utc_offset_correct() {
-        let result = convert_timezone("2026-06-15T12:00:00Z", "Asia/Tokyo").unwrap();
-        assert_eq!(result.utc_offset, "+09:00");
-        assert!(!result.dst_active); // Japan does not observe DST
+    fn test_resolve_start_of_quarter() {
+        // Feb is Q1, so start of quarter is Jan 1
+        let result = resolve_relative(anchor(), "start of quarter", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-01-01"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_convert_dst_active_flag() {
-        // Summer in New York — DST active
-        let summer = convert_timezone("2026-07-15T12:00:00Z", "America/New_York").unwrap();
-        assert!(summer.dst_active);
+    fn test_resolve_next_week() {
+        // Anchor is Wed Feb 18 → next Monday is Feb 23
+        let result = resolve_relative(anchor(), "next week", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-23"));
+    }
 
-        // Winter in New York — DST not active
-        let winter = convert_timezone("2026-12-15T12:00:00Z", "America/New_York").unwrap();
-        assert!(!winter.dst_active);
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_next_month() {
+        let result = resolve_relative(anchor(), "next month", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-03-01"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_convert_invalid_timezone_returns_error() {
-        let result = convert_timezone("2026-03-15T14:00:00Z", "Invalid/Zone");
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("Invalid timezone"), "got: {err}");
+    fn test_resolve_first_monday_of_march() {
+        let result = resolve_relative(anchor(), "first Monday of March", "UTC").unwrap();
+        // March 2026: first Monday is March 2
+        assert!(result.resolved_utc.contains("2026-03-02"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_convert_invalid_datetime_returns_error() {
-        let result = convert_timezone("not-a-datetime", "America/New_York");
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("Invalid datetime"), "got: {err}");
+    fn test_resolve_last_friday_of_month() {
+        let result = resolve_relative(anchor(), "last Friday of the month", "UTC").unwrap();
+        // February 2026: last Friday is Feb 27
+        assert!(result.resolved_utc.contains("2026-02-27"));
     }
 
-    // ── compute_duration tests ──────────────────────────────────────────
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_third_tuesday_of_march_2026() {
+        let result = resolve_relative(anchor(), "third Tuesday of March 2026", "UTC").unwrap();
+        // March 2026: 1st Tue=3, 2nd=10, 3rd=17
+        assert!(result.resolved_utc.contains("2026-03-17"));
+    }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_duration_same_day() {
-        let result = compute_duration("2026-03-16T09:00:00Z", "2026-03-16T17:00:00Z").unwrap();
-        assert_eq!(result.total_seconds, 28800); // 8 hours
-        assert_eq!(result.hours, 8);
-        assert_eq!(result.days, 0);
-        assert_eq!(result.minutes, 0);
+    fn test_resolve_passthrough_rfc3339() {
+        let input = "2026-06-15T10:00:00-04:00";
+        let result = resolve_relative(anchor(), input, "UTC").unwrap();
+        // Should preserve the instant (convert to UTC)
+        assert!(result.resolved_utc.contains("2026-06-15"));
+        assert!(result.resolved_utc.contains("14:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_duration_across_days() {
-        let result = compute_duration(
-            "2026-03-13T17:00:00Z", // Friday 5pm
-            "2026-03-16T09:00:00Z", // Monday 9am
-        )
-        .unwrap();
-        assert_eq!(result.total_seconds, 230400); // 2d + 16h = 2*86400 + 16*3600
-        assert_eq!(result.days, 2);
-        assert_eq!(result.hours, 16);
+    fn test_resolve_passthrough_iso_date() {
+        let result = resolve_relative(anchor(), "2026-03-15", "America/New_York").unwrap();
+        // Should be start of day March 15 in Eastern time
+        assert!(result.resolved_local.contains("2026-03-15"));
+        assert!(result.resolved_local.contains("00:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_duration_negative_direction() {
-        let result = compute_duration("2026-03-16T17:00:00Z", "2026-03-16T09:00:00Z").unwrap();
-        assert_eq!(result.total_seconds, -28800);
-        // Decomposition is always positive
-        assert_eq!(result.hours, 8);
+    fn test_resolve_case_insensitive() {
+        let result = resolve_relative(anchor(), "Next TUESDAY at 2PM", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-24"));
+        assert!(result.resolved_utc.contains("14:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_duration_exact_days() {
-        let result = compute_duration("2026-03-16T00:00:00Z", "2026-03-19T00:00:00Z").unwrap();
-        assert_eq!(result.days, 3);
-        assert_eq!(result.hours, 0);
-        assert_eq!(result.minutes, 0);
-        assert_eq!(result.seconds, 0);
+    fn test_resolve_articles_ignored() {
+        let result = resolve_relative(anchor(), "a week from now", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-25"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_duration_sub_minute() {
-        let result = compute_duration("2026-03-16T10:00:00Z", "2026-03-16T10:00:45Z").unwrap();
-        assert_eq!(result.total_seconds, 45);
-        assert_eq!(result.seconds, 45);
-        assert_eq!(result.minutes, 0);
+    fn test_resolve_unparseable_returns_error() {
+        let result = resolve_relative(anchor(), "gobbledygook", "UTC");
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("cannot parse expression"), "got: {err}");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_duration_human_readable_format() {
-        let result = compute_duration("2026-03-16T00:00:00Z", "2026-03-18T03:15:00Z").unwrap();
-        assert_eq!(result.human_readable, "2 days, 3 hours, 15 minutes");
+    fn test_resolve_interpretation_format() {
+        let result = resolve_relative(anchor(), "next Tuesday at 2pm", "UTC").unwrap();
+        // Should contain day of week and date
+        assert!(result.interpretation.contains("Tuesday"));
+        assert!(result.interpretation.contains("February 24"));
+        assert!(result.interpretation.contains("2026"));
     }
 
+    // ── Compound period expression tests ────────────────────────────────
+
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_duration_invalid_input() {
-        let result = compute_duration("not-a-datetime", "2026-03-16T10:00:00Z");
-        assert!(result.is_err());
+    fn test_resolve_start_of_last_week() {
+        // Anchor is Wed Feb 18 → last week started Mon Feb 9
+        let result = resolve_relative(anchor(), "start of last week", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-09"));
+        assert!(result.resolved_utc.contains("00:00:00"));
     }
 
-    // ── adjust_timestamp tests ──────────────────────────────────────────
-
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_adjust_add_hours() {
-        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+2h", "UTC").unwrap();
-        assert!(result.adjusted_utc.contains("12:00:00"));
+    fn test_resolve_end_of_last_week() {
+        // Anchor is Wed Feb 18 → last week ended Sun Feb 15
+        let result = resolve_relative(anchor(), "end of last week", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-15"));
+        assert!(result.resolved_utc.contains("23:59:59"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_adjust_subtract_days() {
-        let result = adjust_timestamp("2026-03-05T10:00:00Z", "-3d", "UTC").unwrap();
-        assert!(result.adjusted_utc.contains("2026-03-02"));
+    fn test_resolve_start_of_next_week() {
+        // Anchor is Wed Feb 18 → next week starts Mon Feb 23
+        let result = resolve_relative(anchor(), "start of next week", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-02-23"));
+        assert!(result.resolved_utc.contains("00:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_adjust_add_minutes() {
-        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+90m", "UTC").unwrap();
-        assert!(result.adjusted_utc.contains("11:30:00"));
+    fn test_resolve_end_of_next_week() {
+        // Anchor is Wed Feb 18 → next week ends Sun Mar 1
+        let result = resolve_relative(anchor(), "end of next week", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-03-01"));
+        assert!(result.resolved_utc.contains("23:59:59"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_adjust_add_weeks() {
-        let result = adjust_timestamp("2026-03-02T10:00:00Z", "+2w", "UTC").unwrap();
-        assert!(result.adjusted_utc.contains("2026-03-16"));
+    fn test_resolve_start_of_last_month() {
+        let result = resolve_relative(anchor(), "start of last month", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-01-01"));
+        assert!(result.resolved_utc.contains("00:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_adjust_compound_duration() {
-        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+1d2h30m", "UTC").unwrap();
-        // March 16 10:00 + 1d2h30m = March 17 12:30
-        assert!(result.adjusted_utc.contains("2026-03-17"));
-        assert!(result.adjusted_utc.contains("12:30:00"));
+    fn test_resolve_end_of_last_month() {
+        // Jan has 31 days
+        let result = resolve_relative(anchor(), "end of last month", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-01-31"));
+        assert!(result.resolved_utc.contains("23:59:59"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_adjust_day_across_dst() {
-        // March 8 2026: US spring forward. +1d should preserve wall-clock time.
-        let result = adjust_timestamp(
-            "2026-03-07T22:00:00-05:00", // 10pm EST (= 03:00 UTC on March 8)
-            "+1d",
-            "America/New_York",
-        )
-        .unwrap();
-        // March 8, 10pm EDT (now in EDT = -04:00)
-        assert!(result.adjusted_local.contains("22:00:00"));
+    fn test_resolve_start_of_next_month() {
+        let result = resolve_relative(anchor(), "start of next month", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-03-01"));
+        assert!(result.resolved_utc.contains("00:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_adjust_negative_compound() {
-        let result = adjust_timestamp("2026-03-16T10:00:00Z", "-1d12h", "UTC").unwrap();
-        // March 16 10:00 - 1d12h = March 14 22:00
-        assert!(result.adjusted_utc.contains("2026-03-14"));
-        assert!(result.adjusted_utc.contains("22:00:00"));
+    fn test_resolve_end_of_next_month() {
+        // March has 31 days
+        let result = resolve_relative(anchor(), "end of next month", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2026-03-31"));
+        assert!(result.resolved_utc.contains("23:59:59"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_adjust_add_seconds() {
-        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+3600s", "UTC").unwrap();
-        assert!(result.adjusted_utc.contains("11:00:00"));
+    fn test_resolve_start_of_next_year() {
+        let result = resolve_relative(anchor(), "start of next year", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2027-01-01"));
+        assert!(result.resolved_utc.contains("00:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_adjust_invalid_format() {
-        let result = adjust_timestamp("2026-03-16T10:00:00Z", "2h", "UTC");
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("must start with '+' or '-'"), "got: {err}");
+    fn test_resolve_end_of_last_quarter() {
+        // Anchor is Feb 2026 (Q1) → last quarter is Q4 2025 → ends Dec 31, 2025
+        let result = resolve_relative(anchor(), "end of last quarter", "UTC").unwrap();
+        assert!(result.resolved_utc.contains("2025-12-31"));
+        assert!(result.resolved_utc.contains("23:59:59"));
     }
 
+    // ── Inline timezone tests ───────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_adjust_zero_duration() {
-        let result = adjust_timestamp("2026-03-16T10:00:00Z", "+0h", "UTC").unwrap();
-        assert!(result.adjusted_utc.contains("10:00:00"));
+    fn test_resolve_place_time_suffix() {
+        // 3pm in Tokyo on Feb 18 = 06:00 UTC, reported in New York (01:00 EST)
+        let result = resolve_relative(anchor(), "3pm Tokyo time", "America/New_York").unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-18T06:00:00+00:00");
+        assert_eq!(result.resolved_local, "2026-02-18T01:00:00-05:00");
+        assert_eq!(result.timezone, "America/New_York");
+        assert_eq!(result.expression_timezone.as_deref(), Some("Asia/Tokyo"));
     }
 
-    // ── resolve_relative tests ──────────────────────────────────────────
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_abbreviation_before_day() {
+        // "9am ET tomorrow" → Feb 19 09:00 EST = 14:00 UTC
+        let result = resolve_relative(anchor(), "9am ET tomorrow", "Europe/London").unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-19T14:00:00+00:00");
+        assert_eq!(
+            result.expression_timezone.as_deref(),
+            Some("America/New_York")
+        );
+    }
 
-    fn anchor() -> DateTime<Utc> {
-        // Wednesday, February 18, 2026, 14:30:00 UTC
-        Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap()
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_trailing_iana_abbreviation() {
+        // Next Monday (Feb 23) 14:00 CET = 13:00 UTC
+        let result = resolve_relative(anchor(), "next Monday 14:00 CET", "UTC").unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-23T13:00:00+00:00");
+        assert_eq!(result.expression_timezone.as_deref(), Some("Europe/Berlin"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_now() {
-        let result = resolve_relative(anchor(), "now", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("14:30:00"));
+    fn test_resolve_trailing_iana_name() {
+        let result = resolve_relative(anchor(), "tomorrow at noon Asia/Kolkata", "UTC").unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-19T06:30:00+00:00");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_today() {
-        let result = resolve_relative(anchor(), "today", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-18"));
-        assert!(result.resolved_utc.contains("00:00:00"));
+    fn test_resolve_utc_offset_suffixes() {
+        let resolve = |expr, tz| {
+            let r = resolve_relative(anchor(), expr, tz).unwrap();
+            (r.resolved_utc, r.expression_timezone.unwrap())
+        };
+        let expect = |utc: &str, frame: &str| (utc.to_string(), frame.to_string());
+        // The frame wins over the timezone argument, which only reports.
+        assert_eq!(
+            resolve("tomorrow 3pm UTC", "America/New_York"),
+            expect("2026-02-19T15:00:00+00:00", "UTC")
+        );
+        assert_eq!(
+            resolve("next Monday 14:00 +02:00", "UTC"),
+            expect("2026-02-23T12:00:00+00:00", "+02:00")
+        );
+        assert_eq!(
+            resolve("tomorrow at 9am-0500", "Asia/Tokyo"),
+            expect("2026-02-19T14:00:00+00:00", "-05:00")
+        );
+        assert_eq!(
+            resolve("9am GMT+1 tomorrow", "UTC"),
+            expect("2026-02-19T08:00:00+00:00", "+01:00")
+        );
+        // "Tomorrow" is read in the frame too: it is already February 19 in
+        // Kiritimati, but still the 18th in UTC.
+        assert_eq!(
+            resolve("tomorrow 14:00Z", "Pacific/Kiritimati"),
+            expect("2026-02-19T14:00:00+00:00", "UTC")
+        );
+
+        let err = resolve_relative(anchor(), "tomorrow 9am +15:00", "UTC").unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{err}");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_fractional_utc_offsets() {
+        let resolve = |expr| {
+            let r = resolve_relative(anchor(), expr, "UTC").unwrap();
+            (r.resolved_utc, r.expression_timezone.unwrap())
+        };
+        let expect = |utc: &str, frame: &str| (utc.to_string(), frame.to_string());
+        // India
+        assert_eq!(
+            resolve("tomorrow 9am +05:30"),
+            expect("2026-02-19T03:30:00+00:00", "+05:30")
+        );
+        assert_eq!(
+            resolve("14:00 +05:30"),
+            expect("2026-02-18T08:30:00+00:00", "+05:30")
+        );
+        // Nepal
+        assert_eq!(
+            resolve("tomorrow 14:00 +05:45"),
+            expect("2026-02-19T08:15:00+00:00", "+05:45")
+        );
+        // Newfoundland standard time, written onto the time
+        assert_eq!(
+            resolve("tomorrow at 9am-0330"),
+            expect("2026-02-19T12:30:00+00:00", "-03:30")
+        );
+        // "Today" is read at the offset: 14:30 UTC is already 04:15 on the
+        // 19th at +13:45 (Chatham daylight time).
+        assert_eq!(
+            resolve("today 23:00 +13:45"),
+            expect("2026-02-19T09:15:00+00:00", "+13:45")
+        );
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_without_inline_zone_has_none() {
+        let result = resolve_relative(anchor(), "tomorrow at 9am", "UTC").unwrap();
+        assert!(result.expression_timezone.is_none());
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_tomorrow() {
-        let result = resolve_relative(anchor(), "tomorrow", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-19"));
-        assert!(result.resolved_utc.contains("00:00:00"));
+    fn test_resolve_ambiguous_inline_abbreviation_errors() {
+        let result = resolve_relative(anchor(), "3pm CST", "UTC");
+        assert!(matches!(result, Err(TruthError::AmbiguousTimezone { .. })));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_yesterday() {
-        let result = resolve_relative(anchor(), "yesterday", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-17"));
+    fn test_resolve_time_before_day_without_zone() {
+        let result = resolve_relative(anchor(), "2:30 pm next friday", "UTC").unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-20T14:30:00+00:00");
     }
 
+    // ── Business day tests ──────────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_next_monday_from_wednesday() {
-        // Anchor is Wednesday Feb 18 → next Monday is Feb 23
-        let result = resolve_relative(anchor(), "next Monday", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-23"));
+    fn test_resolve_next_business_day_starts_at_working_hours() {
+        // Anchor Wed Feb 18 → Thu Feb 19 at 09:00
+        let result = resolve_relative(anchor(), "next business day", "UTC").unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-19T09:00:00+00:00");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_next_friday_from_friday() {
-        // If anchor is Friday Feb 20 → next Friday should be Feb 27 (not same day)
+    fn test_resolve_end_of_next_business_day_skips_weekend() {
+        // Friday Feb 20 → Monday Feb 23 at 17:00
         let fri_anchor = Utc.with_ymd_and_hms(2026, 2, 20, 10, 0, 0).unwrap();
-        let result = resolve_relative(fri_anchor, "next Friday", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-27"));
+        let result = resolve_relative(fri_anchor, "end of next business day", "UTC").unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-23T17:00:00+00:00");
+        // 17:00 is a moment, not the whole day
+        assert_eq!(result.granularity, ResolutionGranularity::DateTime);
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_this_wednesday_from_monday() {
+    fn test_resolve_previous_business_day() {
+        // Monday Feb 16 → Friday Feb 13
         let mon_anchor = Utc.with_ymd_and_hms(2026, 2, 16, 10, 0, 0).unwrap();
-        let result = resolve_relative(mon_anchor, "this Wednesday", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-18"));
+        let result = resolve_relative(mon_anchor, "start of previous business day", "UTC").unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-13T09:00:00+00:00");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_last_tuesday_from_thursday() {
-        let thu_anchor = Utc.with_ymd_and_hms(2026, 2, 19, 10, 0, 0).unwrap();
-        let result = resolve_relative(thu_anchor, "last Tuesday", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-17"));
+    fn test_resolve_last_business_day_of_month() {
+        // Feb 2026 ends Saturday 28 → last business day is Friday Feb 27
+        let result = resolve_relative(
+            anchor(),
+            "start of the last business day of the month",
+            "UTC",
+        )
+        .unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-27T09:00:00+00:00");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_morning() {
-        let result = resolve_relative(anchor(), "morning", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("09:00:00"));
+    fn test_resolve_first_business_day_of_next_month() {
+        // March 1, 2026 is a Sunday → first business day is Monday March 2
+        let result =
+            resolve_relative(anchor(), "end of first business day of next month", "UTC").unwrap();
+        assert_eq!(result.resolved_utc, "2026-03-02T17:00:00+00:00");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_noon() {
-        let result = resolve_relative(anchor(), "noon", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("12:00:00"));
+    fn test_resolve_business_day_custom_working_hours() {
+        // Sunday–Thursday work week, 08:00–16:00
+        let options = ResolveOptions {
+            working_hours: WorkingHours {
+                start: NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+                end: NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+                days: vec![
+                    Weekday::Sun,
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                ],
+            },
+            ..Default::default()
+        };
+        // Thursday Feb 19 → next business day is Sunday Feb 22
+        let thu_anchor = Utc.with_ymd_and_hms(2026, 2, 19, 10, 0, 0).unwrap();
+        let result =
+            resolve_relative_with_options(thu_anchor, "end of next business day", "UTC", &options)
+                .unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-22T16:00:00+00:00");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_afternoon() {
-        let result = resolve_relative(anchor(), "afternoon", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("13:00:00"));
+    fn test_resolve_working_hour_offsets_pause_outside_working_hours() {
+        // Friday Feb 20 15:00: 2 hours on Friday, 2 more on Monday
+        let fri_anchor = Utc.with_ymd_and_hms(2026, 2, 20, 15, 0, 0).unwrap();
+        let resolve = |expr| {
+            resolve_relative(fri_anchor, expr, "UTC")
+                .unwrap()
+                .resolved_utc
+        };
+        assert_eq!(resolve("in 4 working hours"), "2026-02-23T11:00:00+00:00");
+        assert_eq!(
+            resolve("4 business hours from now"),
+            "2026-02-23T11:00:00+00:00"
+        );
+        assert_eq!(resolve("+6bh"), "2026-02-23T13:00:00+00:00");
+        assert_eq!(resolve("in 1.5 working hours"), "2026-02-20T16:30:00+00:00");
+        // Backward from Monday 09:30 crosses the weekend.
+        let mon_anchor = Utc.with_ymd_and_hms(2026, 2, 23, 9, 30, 0).unwrap();
+        let result = resolve_relative(mon_anchor, "90 working minutes ago", "UTC").unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-20T16:00:00+00:00");
+        // As an offset from another expression: Thursday 16:00 + 2 hours.
+        let result =
+            resolve_relative(anchor(), "2 business hours after tomorrow at 4pm", "UTC").unwrap();
+        assert_eq!(result.resolved_utc, "2026-02-20T10:00:00+00:00");
+        // Working hours are read in the expression's timezone.
+        let result = resolve_relative(fri_anchor, "in 3 working hours", "Europe/Berlin").unwrap();
+        assert_eq!(result.resolved_local, "2026-02-23T11:00:00+01:00");
+    }
+
+    // ── Custom parser tests ─────────────────────────────────────────────
+
+    /// Sprints are two weeks long; sprint 1 starts Monday 2026-01-05 at 09:00.
+    #[cfg(feature = "resolver")]
+    fn sprint_options() -> ResolveOptions {
+        let mut parsers = ParserRegistry::new();
+        parsers.register("sprint", |expr: &str, anchor: &DateTime<Tz>| {
+            let n: i64 = expr.strip_prefix("sprint ")?.parse().ok()?;
+            let start = NaiveDate::from_ymd_opt(2026, 1, 5)? + chrono::Duration::weeks(2 * (n - 1));
+            anchor
+                .timezone()
+                .from_local_datetime(&start.and_hms_opt(9, 0, 0)?)
+                .earliest()
+        });
+        ResolveOptions {
+            parsers,
+            ..Default::default()
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_evening() {
-        let result = resolve_relative(anchor(), "evening", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("18:00:00"));
+    fn test_custom_parser_resolves_domain_vocabulary() {
+        let r = resolve_relative_with_options(
+            anchor(),
+            "Sprint 5",
+            "America/New_York",
+            &sprint_options(),
+        )
+        .unwrap();
+        // Sprint 5 starts 8 weeks after Jan 5 → Monday March 2
+        assert_eq!(r.resolved_local, "2026-03-02T09:00:00-05:00");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_eob() {
-        let result = resolve_relative(anchor(), "eob", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("17:00:00"));
+    fn test_custom_parser_falls_back_to_builtins() {
+        let r =
+            resolve_relative_with_options(anchor(), "tomorrow", "UTC", &sprint_options()).unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-19T00:00:00+00:00");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_midnight() {
-        let result = resolve_relative(anchor(), "midnight", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("00:00:00"));
+    fn test_custom_parser_runs_before_builtins() {
+        let mut options = ResolveOptions::default();
+        options
+            .parsers
+            .register("team tomorrow", |expr: &str, anchor: &DateTime<Tz>| {
+                (expr == "tomorrow").then(|| *anchor + chrono::Duration::hours(24))
+            });
+        let r = resolve_relative_with_options(anchor(), "tomorrow", "UTC", &options).unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-19T14:30:00+00:00");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_custom_parsers_run_in_registration_order() {
+        let mut parsers = ParserRegistry::new();
+        parsers
+            .register("first", |_: &str, anchor: &DateTime<Tz>| Some(*anchor))
+            .register("second", |_: &str, _: &DateTime<Tz>| None);
+        assert_eq!(parsers.names(), vec!["first", "second"]);
+        assert_eq!(format!("{:?}", parsers), r#"["first", "second"]"#);
+        let options = ResolveOptions {
+            parsers,
+            ..Default::default()
+        };
+        let r = resolve_relative_with_options(anchor(), "anything", "UTC", &options).unwrap();
+        assert_eq!(r.resolved_utc, anchor().to_rfc3339());
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_2pm() {
-        let result = resolve_relative(anchor(), "2pm", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("14:00:00"));
+    fn test_custom_parser_sees_inline_timezone_frame() {
+        let r = resolve_relative_with_options(
+            anchor(),
+            "sprint 5 tokyo time",
+            "UTC",
+            &sprint_options(),
+        )
+        .unwrap();
+        assert_eq!(r.resolved_utc, "2026-03-02T00:00:00+00:00");
+        assert_eq!(r.expression_timezone.as_deref(), Some("Asia/Tokyo"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_2_30pm() {
-        let result = resolve_relative(anchor(), "2:30pm", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("14:30:00"));
+    fn test_custom_parser_unmatched_still_errors() {
+        let result = resolve_relative_with_options(anchor(), "sprint x", "UTC", &sprint_options());
+        assert!(matches!(result, Err(TruthError::InvalidExpression(_))));
     }
 
-    #[test]
-    fn test_resolve_14_00() {
-        let result = resolve_relative(anchor(), "14:00", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("14:00:00"));
+    // ── Lenient mode tests ──────────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
+    fn lenient() -> ResolveOptions {
+        ResolveOptions {
+            lenient: true,
+            ..Default::default()
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_in_2_hours() {
-        let result = resolve_relative(anchor(), "in 2 hours", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("16:30:00"));
+    fn test_lenient_corrects_typos() {
+        let r =
+            resolve_relative_with_options(anchor(), "tommorow morning", "UTC", &lenient()).unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-19T09:00:00+00:00");
+        assert_eq!(
+            r.corrections,
+            vec![TypoCorrection {
+                original: "tommorow".to_string(),
+                corrected: "tomorrow".to_string(),
+            }]
+        );
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_lenient_corrects_weekday_and_transposition() {
+        let r = resolve_relative_with_options(anchor(), "next wensday at 2pm", "UTC", &lenient())
+            .unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-25T14:00:00+00:00");
+        assert_eq!(r.corrections[0].corrected, "wednesday");
+
+        let r = resolve_relative_with_options(anchor(), "nxet fridya", "UTC", &lenient()).unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-20T00:00:00+00:00");
+        assert_eq!(r.corrections.len(), 2);
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_30_minutes_ago() {
-        let result = resolve_relative(anchor(), "30 minutes ago", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("14:00:00"));
+    fn test_lenient_leaves_valid_expressions_alone() {
+        let r = resolve_relative_with_options(anchor(), "next friday", "UTC", &lenient()).unwrap();
+        assert!(r.corrections.is_empty());
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_in_3_days() {
-        let result = resolve_relative(anchor(), "in 3 days", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-21"));
+    fn test_strict_mode_rejects_typos() {
+        let result = resolve_relative(anchor(), "tommorow", "UTC");
+        assert!(matches!(result, Err(TruthError::InvalidExpression(_))));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_a_week_from_now() {
-        let result = resolve_relative(anchor(), "a week from now", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-25"));
+    fn test_lenient_does_not_guess_between_ties() {
+        // "jule" is one edit from both "june" and "july"
+        let result =
+            resolve_relative_with_options(anchor(), "first monday of jule", "UTC", &lenient());
+        assert!(matches!(result, Err(TruthError::InvalidExpression(_))));
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_lenient_rejects_unrecognizable_words() {
+        let result = resolve_relative_with_options(anchor(), "purple elephant", "UTC", &lenient());
+        assert!(matches!(result, Err(TruthError::InvalidExpression(_))));
     }
 
+    // ── DST warning tests ───────────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_next_tuesday_at_2pm() {
-        // Anchor is Wed Feb 18 → next Tuesday is Feb 24, at 2pm
-        let result = resolve_relative(anchor(), "next Tuesday at 2pm", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-24"));
-        assert!(result.resolved_utc.contains("14:00:00"));
+    fn test_no_dst_warnings_far_from_transition() {
+        let r = resolve_relative(anchor(), "tomorrow at 2pm", "America/New_York").unwrap();
+        assert!(r.warnings.is_empty());
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_tomorrow_at_10_30am() {
-        let result = resolve_relative(anchor(), "tomorrow at 10:30am", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-19"));
-        assert!(result.resolved_utc.contains("10:30:00"));
+    fn test_dst_warning_near_spring_forward() {
+        // US clocks spring forward Sunday March 8, 2026 at 02:00 EST (07:00 UTC)
+        let anchor = Utc.with_ymd_and_hms(2026, 3, 6, 15, 0, 0).unwrap();
+        let r = resolve_relative(anchor, "tomorrow at 5pm", "America/New_York").unwrap();
+        assert_eq!(r.warnings.len(), 1);
+        let w = &r.warnings[0];
+        assert_eq!(w.kind, DstWarningKind::NearTransition);
+        assert_eq!(w.transition_utc, "2026-03-08T07:00:00+00:00");
+        assert_eq!(w.offset_before, "-05:00");
+        assert_eq!(w.offset_after, "-04:00");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_dst_warning_shifted_out_of_gap() {
+        let anchor = Utc.with_ymd_and_hms(2026, 3, 7, 15, 0, 0).unwrap();
+        let r = resolve_relative(anchor, "tomorrow at 2:30am", "America/New_York").unwrap();
+        assert_eq!(r.resolved_local, "2026-03-08T03:30:00-04:00");
+        let kinds: Vec<_> = r.warnings.iter().map(|w| w.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![DstWarningKind::NearTransition, DstWarningKind::Shifted]
+        );
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_dst_warning_ambiguous_fall_back() {
+        // US clocks fall back Sunday November 1, 2026 at 02:00 EDT
+        let anchor = Utc.with_ymd_and_hms(2026, 10, 31, 15, 0, 0).unwrap();
+        let r = resolve_relative(anchor, "tomorrow at 1:30am", "America/New_York").unwrap();
+        assert_eq!(r.resolved_local, "2026-11-01T01:30:00-04:00");
+        let kinds: Vec<_> = r.warnings.iter().map(|w| w.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![DstWarningKind::NearTransition, DstWarningKind::Ambiguous]
+        );
+        assert!(r.warnings[1].message.contains("occurs twice"));
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_no_dst_warnings_in_zone_without_dst() {
+        let anchor = Utc.with_ymd_and_hms(2026, 3, 7, 15, 0, 0).unwrap();
+        let r = resolve_relative(anchor, "tomorrow", "Asia/Tokyo").unwrap();
+        assert!(r.warnings.is_empty());
     }
 
+    // ── Batch resolve tests ─────────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_tomorrow_morning() {
-        let result = resolve_relative(anchor(), "tomorrow morning", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-19"));
-        assert!(result.resolved_utc.contains("09:00:00"));
+    fn test_resolve_batch_mixed_results() {
+        let results = resolve_relative_batch(
+            anchor(),
+            &["tomorrow at 2pm", "gibberish", "next monday"],
+            "America/New_York",
+            &ResolveOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap().resolved_local,
+            "2026-02-19T14:00:00-05:00"
+        );
+        assert!(matches!(results[1], Err(TruthError::InvalidExpression(_))));
+        assert_eq!(
+            results[2].as_ref().unwrap().resolved_local,
+            "2026-02-23T00:00:00-05:00"
+        );
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_batch_matches_single_calls() {
+        let expressions = [
+            "eob",
+            "in 90 minutes",
+            "start of next month",
+            "3pm tokyo time",
+        ];
+        let batch = resolve_relative_batch(
+            anchor(),
+            &expressions,
+            "Europe/London",
+            &ResolveOptions::default(),
+        )
+        .unwrap();
+        for (expr, result) in expressions.iter().zip(batch) {
+            let single = resolve_relative(anchor(), expr, "Europe/London").unwrap();
+            assert_eq!(result.unwrap().resolved_utc, single.resolved_utc, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_next_friday_evening() {
-        // Anchor is Wed Feb 18 → next Friday is Feb 20, evening = 18:00
-        let result = resolve_relative(anchor(), "next Friday evening", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-20"));
-        assert!(result.resolved_utc.contains("18:00:00"));
+    fn test_resolve_batch_invalid_timezone_fails_whole_call() {
+        let result = resolve_relative_batch(
+            anchor(),
+            &["today"],
+            "Mars/Olympus",
+            &ResolveOptions::default(),
+        );
+        assert!(matches!(result, Err(TruthError::InvalidTimezone(_))));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_today_at_noon() {
-        let result = resolve_relative(anchor(), "today at noon", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-18"));
-        assert!(result.resolved_utc.contains("12:00:00"));
+    fn test_resolve_batch_empty() {
+        let results =
+            resolve_relative_batch(anchor(), &[], "UTC", &ResolveOptions::default()).unwrap();
+        assert!(results.is_empty());
     }
 
+    // ── Explicit date + time tests ──────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_start_of_week() {
-        // Anchor is Wed Feb 18 → start of ISO week is Mon Feb 16
-        let result = resolve_relative(anchor(), "start of week", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-16"));
-        assert!(result.resolved_utc.contains("00:00:00"));
+    fn test_resolve_explicit_date_forms() {
+        let cases = [
+            ("March 3rd", "2026-03-03T00:00:00-05:00"),
+            ("march 10 2027", "2027-03-10T00:00:00-05:00"),
+            ("3 March", "2026-03-03T00:00:00-05:00"),
+            ("the 3rd of March", "2026-03-03T00:00:00-05:00"),
+            ("21st of june 2026", "2026-06-21T00:00:00-04:00"),
+            ("March 3rd, 2026", "2026-03-03T00:00:00-05:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative(anchor(), expr, "America/New_York").unwrap();
+            assert_eq!(r.resolved_local, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_end_of_month() {
-        let result = resolve_relative(anchor(), "end of month", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-28"));
-        assert!(result.resolved_utc.contains("23:59:59"));
+    fn test_resolve_explicit_date_with_time() {
+        let cases = [
+            ("morning of March 3rd", "2026-03-03T09:00:00-05:00"),
+            ("March 10 at 2pm", "2026-03-10T14:00:00-04:00"),
+            ("2026-04-01 eob", "2026-04-01T17:00:00-04:00"),
+            ("2026-04-01 at 9:30am", "2026-04-01T09:30:00-04:00"),
+            ("2pm on 10 march 2027", "2027-03-10T14:00:00-05:00"),
+            ("noon on the 1st of april", "2026-04-01T12:00:00-04:00"),
+            ("9am march 3", "2026-03-03T09:00:00-05:00"),
+            ("march 3 end of day", "2026-03-03T17:00:00-05:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative(anchor(), expr, "America/New_York").unwrap();
+            assert_eq!(r.resolved_local, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_start_of_quarter() {
-        // Feb is Q1, so start of quarter is Jan 1
-        let result = resolve_relative(anchor(), "start of quarter", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-01-01"));
+    fn test_resolve_explicit_date_rejects_invalid_days() {
+        for expr in [
+            "february 30",
+            "march 32",
+            "2026-02-30 at noon",
+            "march 3 at 25:00",
+        ] {
+            let result = resolve_relative(anchor(), expr, "UTC");
+            assert!(
+                matches!(result, Err(TruthError::InvalidExpression(_))),
+                "{expr} should fail"
+            );
+        }
     }
 
+    // ── Quarter literal tests ───────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_next_week() {
-        // Anchor is Wed Feb 18 → next Monday is Feb 23
-        let result = resolve_relative(anchor(), "next week", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-23"));
+    fn test_resolve_quarter_literals() {
+        let cases = [
+            ("Q3", "2026-07-01T00:00:00+00:00"),
+            ("q2 2027", "2027-04-01T00:00:00+00:00"),
+            ("start of Q3 2026", "2026-07-01T00:00:00+00:00"),
+            ("end of Q1", "2026-03-31T23:59:59+00:00"),
+            ("end of Q4 2025", "2025-12-31T23:59:59+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative(anchor(), expr, "UTC").unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_next_month() {
-        let result = resolve_relative(anchor(), "next month", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-03-01"));
+    fn test_resolve_quarter_literal_rejects_q5() {
+        let result = resolve_relative(anchor(), "Q5 2026", "UTC");
+        assert!(matches!(result, Err(TruthError::InvalidExpression(_))));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_first_monday_of_march() {
-        let result = resolve_relative(anchor(), "first Monday of March", "UTC").unwrap();
-        // March 2026: first Monday is March 2
-        assert!(result.resolved_utc.contains("2026-03-02"));
+    fn test_resolve_fiscal_quarters() {
+        // Fiscal year starts in October: FY2026 = Oct 2025 – Sep 2026.
+        let options = ResolveOptions {
+            fiscal_year_start: Some(Month::October),
+            ..Default::default()
+        };
+        let cases = [
+            // Anchor (Feb 2026) is in FY2026 Q2 (Jan–Mar 2026)
+            ("start of quarter", "2026-01-01T00:00:00+00:00"),
+            ("end of next quarter", "2026-06-30T23:59:59+00:00"),
+            ("start of last quarter", "2025-10-01T00:00:00+00:00"),
+            ("Q1", "2025-10-01T00:00:00+00:00"),
+            ("Q1 FY2027", "2026-10-01T00:00:00+00:00"),
+            ("end of Q4 2026", "2026-09-30T23:59:59+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative_with_options(anchor(), expr, "UTC", &options).unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_last_friday_of_month() {
-        let result = resolve_relative(anchor(), "last Friday of the month", "UTC").unwrap();
-        // February 2026: last Friday is Feb 27
-        assert!(result.resolved_utc.contains("2026-02-27"));
+    fn test_resolve_fiscal_quarter_crossing_year() {
+        // Fiscal year starts in April; anchor Feb 2026 is FY2026 Q4 (Jan–Mar 2026).
+        let options = ResolveOptions {
+            fiscal_year_start: Some(Month::April),
+            ..Default::default()
+        };
+        let r = resolve_relative_with_options(anchor(), "start of next quarter", "UTC", &options)
+            .unwrap();
+        assert_eq!(r.resolved_utc, "2026-04-01T00:00:00+00:00");
+        let r = resolve_relative_with_options(anchor(), "Q4", "UTC", &options).unwrap();
+        assert_eq!(r.resolved_utc, "2026-01-01T00:00:00+00:00");
     }
 
+    // ── Midpoint tests ──────────────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_third_tuesday_of_march_2026() {
-        let result = resolve_relative(anchor(), "third Tuesday of March 2026", "UTC").unwrap();
-        // March 2026: 1st Tue=3, 2nd=10, 3rd=17
-        assert!(result.resolved_utc.contains("2026-03-17"));
+    fn test_resolve_midpoints_default_convention() {
+        let cases = [
+            ("mid-month", "2026-02-15T12:00:00+00:00"),
+            ("middle of the month", "2026-02-15T12:00:00+00:00"),
+            ("middle of next month", "2026-03-15T12:00:00+00:00"),
+            ("mid last month", "2026-01-15T12:00:00+00:00"),
+            ("mid March 2027", "2027-03-15T12:00:00+00:00"),
+            // Anchor is Wednesday Feb 18
+            ("mid-week", "2026-02-18T12:00:00+00:00"),
+            ("middle of next week", "2026-02-25T12:00:00+00:00"),
+            ("mid last week", "2026-02-11T12:00:00+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative(anchor(), expr, "UTC").unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_passthrough_rfc3339() {
-        let input = "2026-06-15T10:00:00-04:00";
-        let result = resolve_relative(anchor(), input, "UTC").unwrap();
-        // Should preserve the instant (convert to UTC)
-        assert!(result.resolved_utc.contains("2026-06-15"));
-        assert!(result.resolved_utc.contains("14:00:00"));
+    fn test_resolve_midpoints_custom_convention() {
+        let options = ResolveOptions {
+            midpoints: MidpointConvention {
+                day_of_month: 31,
+                weekday: Weekday::Thu,
+                time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            },
+            ..Default::default()
+        };
+        // Day 31 clamps to February's last day
+        let r = resolve_relative_with_options(anchor(), "mid-month", "UTC", &options).unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-28T09:00:00+00:00");
+        let r = resolve_relative_with_options(anchor(), "mid-week", "UTC", &options).unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-19T09:00:00+00:00");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_passthrough_iso_date() {
-        let result = resolve_relative(anchor(), "2026-03-15", "America/New_York").unwrap();
-        // Should be start of day March 15 in Eastern time
-        assert!(result.resolved_local.contains("2026-03-15"));
-        assert!(result.resolved_local.contains("00:00:00"));
+    fn test_resolve_mid_week_with_sunday_start() {
+        let options = ResolveOptions {
+            week_start: WeekStartDay::Sunday,
+            ..Default::default()
+        };
+        let r = resolve_relative_with_options(anchor(), "middle of next week", "UTC", &options)
+            .unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-25T12:00:00+00:00");
     }
 
+    // ── Day-of-month tests ──────────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_case_insensitive() {
-        let result = resolve_relative(anchor(), "Next TUESDAY at 2PM", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-24"));
-        assert!(result.resolved_utc.contains("14:00:00"));
+    fn test_resolve_bare_day_of_month_next_future() {
+        // Anchor is Feb 18
+        let cases = [
+            ("the 20th", "2026-02-20T00:00:00+00:00"),
+            ("the 18th", "2026-02-18T00:00:00+00:00"),
+            ("the 15th", "2026-03-15T00:00:00+00:00"),
+            // February has no 30th
+            ("the 30th", "2026-03-30T00:00:00+00:00"),
+            ("the 1st at 9am", "2026-03-01T09:00:00+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative(anchor(), expr, "UTC").unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_articles_ignored() {
-        let result = resolve_relative(anchor(), "a week from now", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-25"));
+    fn test_resolve_bare_day_of_month_nearest() {
+        let options = ResolveOptions {
+            day_of_month: DayOfMonthPolicy::Nearest,
+            ..Default::default()
+        };
+        let cases = [
+            ("the 15th", "2026-02-15T00:00:00+00:00"),
+            ("the 20th", "2026-02-20T00:00:00+00:00"),
+            ("the 3rd", "2026-03-03T00:00:00+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative_with_options(anchor(), expr, "UTC", &options).unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_unparseable_returns_error() {
-        let result = resolve_relative(anchor(), "gobbledygook", "UTC");
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("cannot parse expression"), "got: {err}");
+    fn test_resolve_compound_day_of_month() {
+        let cases = [
+            ("the 3rd of next month", "2026-03-03T00:00:00+00:00"),
+            ("the 1st of last month", "2026-01-01T00:00:00+00:00"),
+            ("15th of this month at 2pm", "2026-02-15T14:00:00+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative(anchor(), expr, "UTC").unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_interpretation_format() {
-        let result = resolve_relative(anchor(), "next Tuesday at 2pm", "UTC").unwrap();
-        // Should contain day of week and date
-        assert!(result.interpretation.contains("Tuesday"));
-        assert!(result.interpretation.contains("February 24"));
-        assert!(result.interpretation.contains("2026"));
+    fn test_resolve_day_of_month_rejects_invalid() {
+        for expr in ["the 32nd", "the 30th of this month", "15"] {
+            let result = resolve_relative(anchor(), expr, "UTC");
+            assert!(result.is_err(), "{expr} should fail");
+        }
     }
 
-    // ── Compound period expression tests ────────────────────────────────
+    // ── Bare hour tests ─────────────────────────────────────────────────
 
-    #[test]
-    fn test_resolve_start_of_last_week() {
-        // Anchor is Wed Feb 18 → last week started Mon Feb 9
-        let result = resolve_relative(anchor(), "start of last week", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-09"));
-        assert!(result.resolved_utc.contains("00:00:00"));
+    #[cfg(feature = "resolver")]
+    fn bare_hour(policy: BareHourPolicy) -> ResolveOptions {
+        ResolveOptions {
+            bare_hour: policy,
+            ..Default::default()
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_end_of_last_week() {
-        // Anchor is Wed Feb 18 → last week ended Sun Feb 15
-        let result = resolve_relative(anchor(), "end of last week", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-15"));
-        assert!(result.resolved_utc.contains("23:59:59"));
+    fn test_bare_hour_errors_by_default() {
+        let result = resolve_relative(anchor(), "tomorrow at 7", "UTC");
+        assert!(matches!(result, Err(TruthError::InvalidExpression(_))));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_start_of_next_week() {
-        // Anchor is Wed Feb 18 → next week starts Mon Feb 23
-        let result = resolve_relative(anchor(), "start of next week", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-02-23"));
-        assert!(result.resolved_utc.contains("00:00:00"));
+    fn test_bare_hour_prefer_future() {
+        let options = bare_hour(BareHourPolicy::PreferFuture);
+        // Anchor is 14:30: today's 7am has passed, 7pm has not
+        let cases = [
+            ("at 7", "2026-02-18T19:00:00+00:00"),
+            ("tomorrow at 7", "2026-02-19T07:00:00+00:00"),
+            ("next friday at 3", "2026-02-20T03:00:00+00:00"),
+            // Both noon and midnight today have passed → the later one
+            ("at 12", "2026-02-18T12:00:00+00:00"),
+            ("today at 16", "2026-02-18T16:00:00+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative_with_options(anchor(), expr, "UTC", &options).unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_end_of_next_week() {
-        // Anchor is Wed Feb 18 → next week ends Sun Mar 1
-        let result = resolve_relative(anchor(), "end of next week", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-03-01"));
-        assert!(result.resolved_utc.contains("23:59:59"));
+    fn test_bare_hour_prefer_future_after_weekday() {
+        let options = bare_hour(BareHourPolicy::PreferFuture);
+        // Anchor is Wednesday 14:30
+        let cases = [
+            ("friday at 7", "2026-02-20T07:00:00+00:00"),
+            ("monday at 5", "2026-02-23T05:00:00+00:00"),
+            // Today is Wednesday and 7pm is still to come
+            ("wednesday at 7", "2026-02-18T19:00:00+00:00"),
+            // Both of today's readings of 2 have passed → next Wednesday
+            ("wednesday at 2", "2026-02-25T02:00:00+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative_with_options(anchor(), expr, "UTC", &options).unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_start_of_last_month() {
-        let result = resolve_relative(anchor(), "start of last month", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-01-01"));
-        assert!(result.resolved_utc.contains("00:00:00"));
+    fn test_bare_hour_with_am_pm() {
+        let options = bare_hour(BareHourPolicy::PreferFuture);
+        let cases = [
+            ("at 7pm", "2026-02-18T19:00:00+00:00"),
+            ("at 7 pm", "2026-02-18T19:00:00+00:00"),
+            ("at 9am", "2026-02-18T09:00:00+00:00"),
+            ("friday at 7pm", "2026-02-20T19:00:00+00:00"),
+            ("friday at 7am", "2026-02-20T07:00:00+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative_with_options(anchor(), expr, "UTC", &options).unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+            // An am/pm time is not ambiguous, so it needs no policy
+            let r = resolve_relative(anchor(), expr, "UTC").unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_end_of_last_month() {
-        // Jan has 31 days
-        let result = resolve_relative(anchor(), "end of last month", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-01-31"));
-        assert!(result.resolved_utc.contains("23:59:59"));
+    fn test_bare_hour_prefer_future_both_past() {
+        let options = bare_hour(BareHourPolicy::PreferFuture);
+        let r = resolve_relative_with_options(anchor(), "yesterday at 7", "UTC", &options).unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-17T19:00:00+00:00");
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_start_of_next_month() {
-        let result = resolve_relative(anchor(), "start of next month", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-03-01"));
-        assert!(result.resolved_utc.contains("00:00:00"));
+    fn test_bare_hour_prefer_business_hours() {
+        let options = bare_hour(BareHourPolicy::PreferBusinessHours);
+        let cases = [
+            ("tomorrow at 3", "2026-02-19T15:00:00+00:00"),
+            ("tomorrow at 10", "2026-02-19T10:00:00+00:00"),
+            ("tomorrow at 12", "2026-02-19T12:00:00+00:00"),
+            // Neither 7am nor 7pm is in 9–17; both are 2h away → earlier
+            ("tomorrow at 7", "2026-02-19T07:00:00+00:00"),
+            ("tomorrow at 6", "2026-02-19T18:00:00+00:00"),
+        ];
+        for (expr, expected) in cases {
+            let r = resolve_relative_with_options(anchor(), expr, "UTC", &options).unwrap();
+            assert_eq!(r.resolved_utc, expected, "{expr}");
+        }
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_end_of_next_month() {
-        // March has 31 days
-        let result = resolve_relative(anchor(), "end of next month", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2026-03-31"));
-        assert!(result.resolved_utc.contains("23:59:59"));
+    fn test_bare_hour_policy_leaves_explicit_times_alone() {
+        let options = bare_hour(BareHourPolicy::PreferBusinessHours);
+        let r =
+            resolve_relative_with_options(anchor(), "tomorrow at 7am", "UTC", &options).unwrap();
+        assert_eq!(r.resolved_utc, "2026-02-19T07:00:00+00:00");
+        let result = resolve_relative_with_options(anchor(), "tomorrow at 25", "UTC", &options);
+        assert!(result.is_err());
     }
 
+    // ── Locale tests ────────────────────────────────────────────────────
+
+    #[cfg(feature = "resolver")]
     #[test]
-    fn test_resolve_start_of_next_year() {
-        let result = resolve_relative(anchor(), "start of next year", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2027-01-01"));
-        assert!(result.resolved_utc.contains("00:00:00"));
+    fn test_interpretation_locales() {
+        let cases = [
+            (Locale::English, "Tuesday, February 24, 2026 at 2:00 PM EST"),
+            (Locale::Spanish, "martes, 24 de febrero de 2026, 14:00 EST"),
+            (Locale::French, "mardi 24 février 2026 à 14:00 EST"),
+            (Locale::German, "Dienstag, 24. Februar 2026 um 14:00 EST"),
+            (
+                Locale::Portuguese,
+                "terça-feira, 24 de fevereiro de 2026 às 14:00 EST",
+            ),
+            (Locale::Italian, "martedì 24 febbraio 2026 alle 14:00 EST"),
+            (Locale::Dutch, "dinsdag 24 februari 2026 om 14:00 EST"),
+        ];
+        for (locale, expected) in cases {
+            let options = ResolveOptions {
+                locale,
+                ..Default::default()
+            };
+            let r = resolve_relative_with_options(
+                anchor(),
+                "next tuesday at 2pm",
+                "America/New_York",
+                &options,
+            )
+            .unwrap();
+            assert_eq!(r.interpretation, expected, "{locale:?}");
+        }
     }
 
     #[test]
-    fn test_resolve_end_of_last_quarter() {
-        // Anchor is Feb 2026 (Q1) → last quarter is Q4 2025 → ends Dec 31, 2025
-        let result = resolve_relative(anchor(), "end of last quarter", "UTC").unwrap();
-        assert!(result.resolved_utc.contains("2025-12-31"));
-        assert!(result.resolved_utc.contains("23:59:59"));
+    fn test_locale_from_tag() {
+        assert_eq!(Locale::from_tag("en"), Some(Locale::English));
+        assert_eq!(Locale::from_tag("pt_BR"), Some(Locale::Portuguese));
+        assert_eq!(Locale::from_tag("FR-ca"), Some(Locale::French));
+        assert_eq!(Locale::from_tag(""), None);
+        assert_eq!(Locale::from_tag("ja"), None);
     }
 
     // ── Sunday week start tests ─────────────────────────────────────────
 
+    #[cfg(feature = "resolver")]
     #[test]
     fn test_resolve_start_of_week_sunday() {
         // Anchor is Wed Feb 18 → with Sunday start, week started Sun Feb 15
         let options = ResolveOptions {
             week_start: WeekStartDay::Sunday,
+            ..Default::default()
         };
         let result =
             resolve_relative_with_options(anchor(), "start of week", "UTC", &options).unwrap();
@@ -2008,11 +5689,13 @@ fn test_resolve_start_of_week_sunday() {
         assert!(result.resolved_utc.contains("00:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
     fn test_resolve_end_of_week_sunday() {
         // Anchor is Wed Feb 18 → with Sunday start, week ends Sat Feb 21
         let options = ResolveOptions {
             week_start: WeekStartDay::Sunday,
+            ..Default::default()
         };
         let result =
             resolve_relative_with_options(anchor(), "end of week", "UTC", &options).unwrap();
@@ -2020,11 +5703,13 @@ fn test_resolve_end_of_week_sunday() {
         assert!(result.resolved_utc.contains("23:59:59"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
     fn test_resolve_start_of_last_week_sunday() {
         // Anchor is Wed Feb 18 → with Sunday start, last week started Sun Feb 8
         let options = ResolveOptions {
             week_start: WeekStartDay::Sunday,
+            ..Default::default()
         };
         let result =
             resolve_relative_with_options(anchor(), "start of last week", "UTC", &options).unwrap();
@@ -2032,14 +5717,278 @@ fn test_resolve_start_of_last_week_sunday() {
         assert!(result.resolved_utc.contains("00:00:00"));
     }
 
+    #[cfg(feature = "resolver")]
     #[test]
     fn test_resolve_next_week_sunday() {
         // Anchor is Wed Feb 18 → with Sunday start, next week starts Sun Feb 22
         let options = ResolveOptions {
             week_start: WeekStartDay::Sunday,
+            ..Default::default()
         };
         let result = resolve_relative_with_options(anchor(), "next week", "UTC", &options).unwrap();
         assert!(result.resolved_utc.contains("2026-02-22"));
         assert!(result.resolved_utc.contains("00:00:00"));
     }
+
+    // ── Solar anchor tests ──────────────────────────────────────────────
+
+    /// Sunrise at 06:00 UTC and sunset at 18:00 UTC, each with half an hour
+    /// of twilight, every day.
+    #[cfg(feature = "resolver")]
+    #[derive(Debug)]
+    struct EvenDays;
+
+    #[cfg(feature = "resolver")]
+    impl SolarCalculator for EvenDays {
+        fn solar_time(
+            &self,
+            event: SolarEvent,
+            date: NaiveDate,
+            _location: &GeoLocation,
+        ) -> Option<DateTime<Utc>> {
+            let (h, m) = match event {
+                SolarEvent::Dawn => (5, 30),
+                SolarEvent::Sunrise => (6, 0),
+                SolarEvent::Sunset => (18, 0),
+                SolarEvent::Dusk => (18, 30),
+            };
+            Some(date.and_hms_opt(h, m, 0)?.and_utc())
+        }
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_solar_anchors_on_days_and_as_offset_bases() {
+        let options = ResolveOptions {
+            location: Some(GeoLocation {
+                latitude: 0.0,
+                longitude: 0.0,
+            }),
+            solar_calculator: Some(Arc::new(EvenDays)),
+            ..Default::default()
+        };
+        let resolve = |expr| {
+            resolve_relative_with_options(anchor(), expr, "UTC", &options).map(|r| r.resolved_utc)
+        };
+        assert_eq!(resolve("sunset").unwrap(), "2026-02-18T18:00:00+00:00");
+        assert_eq!(
+            resolve("tomorrow at dawn").unwrap(),
+            "2026-02-19T05:30:00+00:00"
+        );
+        assert_eq!(
+            resolve("Sunrise next Friday").unwrap(),
+            "2026-02-20T06:00:00+00:00"
+        );
+        assert_eq!(
+            resolve("dusk next monday").unwrap(),
+            "2026-02-23T18:30:00+00:00"
+        );
+        assert_eq!(
+            resolve("30 minutes before sunset tomorrow").unwrap(),
+            "2026-02-19T17:30:00+00:00"
+        );
+        assert!(resolve("sunset next week").is_err());
+
+        // Without a location the words mean nothing.
+        assert!(resolve_relative(anchor(), "sunset", "UTC").is_err());
+    }
+
+    #[cfg(all(feature = "resolver", feature = "solar"))]
+    #[test]
+    fn test_solar_anchors_use_the_sunrise_equation_by_default() {
+        let at = |latitude, longitude| ResolveOptions {
+            location: Some(GeoLocation {
+                latitude,
+                longitude,
+            }),
+            ..Default::default()
+        };
+        // London on the summer solstice: almanacs give sunrise at 04:43 and
+        // sunset at 21:21 BST.
+        let london = at(51.5074, -0.1278);
+        let eve = Utc.with_ymd_and_hms(2026, 6, 20, 12, 0, 0).unwrap();
+        let resolve = |expr| {
+            let r = resolve_relative_with_options(eve, expr, "Europe/London", &london).unwrap();
+            DateTime::parse_from_rfc3339(&r.resolved_utc)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        let near = |expr, h, m| {
+            let expected = Utc.with_ymd_and_hms(2026, 6, 21, h, m, 0).unwrap();
+            assert!(
+                (resolve(expr) - expected).num_seconds().abs() <= 120,
+                "{expr}: {}",
+                resolve(expr)
+            );
+        };
+        near("tomorrow at sunrise", 3, 43);
+        near("sunset tomorrow", 20, 21);
+        assert!(resolve("tomorrow at dawn") < resolve("tomorrow at sunrise"));
+        assert!(resolve("tomorrow at dusk") > resolve("tomorrow at sunset"));
+
+        // The sun does not rise in Tromsø in mid-December.
+        let tromso = at(69.6492, 18.9553);
+        let december = Utc.with_ymd_and_hms(2026, 12, 15, 12, 0, 0).unwrap();
+        assert!(
+            resolve_relative_with_options(december, "sunrise", "Europe/Oslo", &tromso).is_err()
+        );
+    }
+
+    // ── Timezone abbreviation tests ─────────────────────────────────────
+
+    #[test]
+    fn test_convert_unambiguous_abbreviation() {
+        let result = convert_timezone("2026-07-15T12:00:00Z", "PST").unwrap();
+        assert_eq!(result.timezone, "America/Los_Angeles");
+        // Abbreviation maps to the zone, so summer is PDT
+        assert_eq!(result.utc_offset, "-07:00");
+    }
+
+    #[test]
+    fn test_abbreviation_is_case_insensitive() {
+        let result = convert_timezone("2026-01-15T12:00:00Z", "jst").unwrap();
+        assert_eq!(result.timezone, "Asia/Tokyo");
+    }
+
+    #[test]
+    fn test_tzdb_named_abbreviations_follow_their_region() {
+        // "CET" is Central European time, so summer is CEST
+        let result = convert_timezone("2026-07-15T12:00:00Z", "CET").unwrap();
+        assert_eq!(result.timezone, "Europe/Berlin");
+        assert_eq!(result.utc_offset, "+02:00");
+        let result = convert_timezone("2026-07-15T12:00:00Z", "HST").unwrap();
+        assert_eq!(result.timezone, "Pacific/Honolulu");
+        assert_eq!(result.utc_offset, "-10:00");
+    }
+
+    #[test]
+    fn test_fixed_offset_abbreviations_are_ambiguous() {
+        // New York observes DST; the tzdb "EST" zone does not.
+        for (name, candidates) in [
+            ("EST", ["America/New_York", "America/Panama"]),
+            ("mst", ["America/Denver", "America/Phoenix"]),
+        ] {
+            match convert_timezone("2026-07-15T12:00:00Z", name).unwrap_err() {
+                TruthError::AmbiguousTimezone {
+                    candidates: found, ..
+                } => assert_eq!(found, candidates),
+                other => panic!("expected AmbiguousTimezone, got {other:?}"),
+            }
+        }
+        let est = lookup_timezone_abbreviation("est").unwrap();
+        assert!(est.ambiguous && est.fixed_offset_reading);
+        let cst = lookup_timezone_abbreviation("CST").unwrap();
+        assert!(cst.ambiguous && !cst.fixed_offset_reading);
+    }
+
+    #[test]
+    fn test_timezone_names_are_case_insensitive() {
+        let result = convert_timezone("2026-07-15T12:00:00Z", "america/new_york").unwrap();
+        assert_eq!(result.timezone, "America/New_York");
+        let result = convert_timezone("2026-07-15T12:00:00Z", "europe/berlin").unwrap();
+        assert_eq!(result.timezone, "Europe/Berlin");
+        assert_eq!(result.utc_offset, "+02:00");
+    }
+
+    #[test]
+    fn test_ambiguous_abbreviation_lists_candidates() {
+        let err = convert_timezone("2026-03-15T14:00:00Z", "CST").unwrap_err();
+        match &err {
+            TruthError::AmbiguousTimezone {
+                abbreviation,
+                candidates,
+            } => {
+                assert_eq!(abbreviation, "CST");
+                assert_eq!(candidates[0], "America/Chicago");
+                assert!(candidates.contains(&"Asia/Shanghai".to_string()));
+            }
+            other => panic!("expected AmbiguousTimezone, got {other:?}"),
+        }
+        assert!(err.to_string().contains("Asia/Shanghai"), "got: {err}");
+    }
+
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn test_resolve_with_abbreviation_reports_iana_zone() {
+        let result = resolve_relative(anchor(), "tomorrow at 9am", "AEST").unwrap();
+        assert_eq!(result.timezone, "Australia/Sydney");
+        assert!(result.resolved_local.contains("09:00:00+11:00"));
+    }
+
+    #[test]
+    fn test_lookup_unknown_abbreviation() {
+        assert!(lookup_timezone_abbreviation("XYZ").is_none());
+    }
+
+    // ── Timezone search tests ───────────────────────────────────────────
+
+    #[test]
+    fn test_find_timezone_by_zone_city() {
+        assert_eq!(find_timezone("Paris"), vec!["Europe/Paris"]);
+        assert_eq!(find_timezone("new york"), vec!["America/New_York"]);
+    }
+
+    #[test]
+    fn test_find_timezone_ignores_accents_and_separators() {
+        assert_eq!(find_timezone("São Paulo"), vec!["America/Sao_Paulo"]);
+        assert_eq!(
+            find_timezone("Port-au-Prince"),
+            vec!["America/Port-au-Prince"]
+        );
+    }
+
+    #[test]
+    fn test_find_timezone_city_alias() {
+        assert_eq!(find_timezone("Mumbai"), vec!["Asia/Kolkata"]);
+        assert_eq!(find_timezone("Portland").len(), 2);
+    }
+
+    #[test]
+    fn test_find_timezone_by_country_name() {
+        assert_eq!(find_timezone("Japan"), vec!["Asia/Tokyo"]);
+    }
+
+    #[test]
+    fn test_find_timezone_no_match() {
+        assert!(find_timezone("Gotham").is_empty());
+        assert!(find_timezone("   ").is_empty());
+    }
+
+    #[test]
+    fn test_find_timezones_by_country_code() {
+        let zones = find_timezones_by_country("br");
+        assert_eq!(zones[0], "America/Sao_Paulo");
+        assert!(zones.contains(&"America/Manaus".to_string()));
+        assert!(find_timezones_by_country("ZZ").is_empty());
+    }
+
+    #[test]
+    fn test_country_table_zones_are_valid() {
+        for code in ["AR", "AU", "BR", "CA", "RU", "US", "UA"] {
+            for zone in find_timezones_by_country(code) {
+                assert!(zone.parse::<Tz>().is_ok(), "invalid zone {zone}");
+            }
+        }
+    }
+
+    // ── Serde round-trip tests ──────────────────────────────────────────
+
+    #[cfg(all(feature = "resolver", feature = "serde"))]
+    fn round_trip<T>(value: &T)
+    where
+        T: Serialize + for<'de> Deserialize<'de> + PartialEq + std::fmt::Debug,
+    {
+        let json = serde_json::to_string(value).unwrap();
+        assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), value, "{json}");
+    }
+
+    #[cfg(all(feature = "resolver", feature = "serde"))]
+    #[test]
+    fn test_results_round_trip_through_json() {
+        round_trip(&convert_timezone("2026-03-08T07:30:00Z", "America/New_York").unwrap());
+        round_trip(&compute_duration("2026-02-18T09:00:00Z", "2026-02-20T17:30:00Z").unwrap());
+        round_trip(&adjust_timestamp("2026-02-18T14:30:00Z", "+1d2h", "Europe/London").unwrap());
+        round_trip(&resolve_relative(anchor(), "next Tuesday at 2pm", "UTC").unwrap());
+        round_trip(&WorkingHours::default());
+    }
 }
@@ -0,0 +1,268 @@
+//! DST advisories for a proposed meeting time, for [`dst_advisories`].
+//!
+//! A meeting keeps the wall-clock time of one zone, the organizer's. When
+//! another attendee's zone changes clocks on a different date (Europe moves
+//! three weeks after the United States in spring, a week earlier in the
+//! autumn) the meeting moves by an hour on that attendee's clock, and a
+//! clock change the weekend of the meeting is easy to miss. The advisories
+//! name the zone that changes, when, and how far the meeting moves, with a
+//! sentence an agent can pass on as it is.
+
+use std::borrow::Cow;
+
+use chrono::{DateTime, Duration, Offset, Utc};
+use chrono_tz::Tz;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{format_utc_offset, local_to_utc, parse_timezone};
+use crate::error::TruthError;
+
+/// How close to the meeting a clock change counts as
+/// [`NearTransition`](DstAdvisoryKind::NearTransition): the weekend around it.
+const NEAR_DAYS: i64 = 3;
+
+/// What a [`DstAdvisory`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DstAdvisoryKind {
+    /// The zone changes clocks within three days of the meeting.
+    NearTransition,
+    /// In this zone the meeting was at another local time a week earlier.
+    ShiftedSinceLastWeek,
+    /// In this zone the meeting is at another local time a week later.
+    ShiftsNextWeek,
+}
+
+/// A clock change that moves a proposed meeting or falls near it, for one
+/// attendee zone.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DstAdvisory {
+    pub kind: DstAdvisoryKind,
+    /// The attendee zone the advisory is for.
+    pub timezone: Cow<'static, str>,
+    /// The zone that changes clocks: `timezone` itself, or the meeting's
+    /// zone when its change moves the meeting for everyone else.
+    pub transition_timezone: Cow<'static, str>,
+    /// When the clocks change (RFC 3339, UTC).
+    pub transition_utc: String,
+    /// The UTC offset of `transition_timezone` before the change.
+    pub offset_before: String,
+    /// The UTC offset of `transition_timezone` after the change.
+    pub offset_after: String,
+    /// The proposed meeting in `timezone` (RFC 3339 with offset).
+    pub local: String,
+    /// How far the meeting moves on `timezone`'s clock from the earlier week
+    /// to the later, in minutes (positive = later); 0 for
+    /// [`NearTransition`](DstAdvisoryKind::NearTransition).
+    pub shift_minutes: i64,
+    /// Human-readable explanation.
+    pub message: String,
+}
+
+/// Advisories about clock changes around a proposed meeting, for attendees
+/// in several zones.
+///
+/// The meeting recurs weekly at the same wall-clock time in
+/// `meeting_timezone`. For each zone (the meeting's first, then
+/// `timezones` in order, each once) this reports a clock change within
+/// three days of the meeting, and whether the meeting is at another local
+/// time a week before or a week after, because that zone or the meeting's
+/// changes clocks in between. Zones that change together see no shift.
+///
+/// # Arguments
+///
+/// * `datetime` — The proposed meeting, as an RFC 3339 datetime string
+/// * `meeting_timezone` — The zone whose wall-clock time the meeting keeps
+/// * `timezones` — The attendees' zones (IANA names or unambiguous abbreviations)
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the datetime cannot be parsed, or
+/// [`TruthError::InvalidTimezone`] (or [`TruthError::AmbiguousTimezone`]) for the
+/// first timezone that cannot be resolved.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::{dst_advisories, DstAdvisoryKind};
+///
+/// // Friday 10:00 in New York, two days before Europe changes clocks.
+/// let advisories = dst_advisories(
+///     "2026-03-27T10:00:00-04:00",
+///     "America/New_York",
+///     &["Europe/Berlin", "Asia/Tokyo"],
+/// )
+/// .unwrap();
+/// let kinds: Vec<_> = advisories.iter().map(|a| (a.timezone.as_ref(), a.kind)).collect();
+/// assert_eq!(
+///     kinds,
+///     vec![
+///         ("Europe/Berlin", DstAdvisoryKind::NearTransition),
+///         ("Europe/Berlin", DstAdvisoryKind::ShiftsNextWeek),
+///     ]
+/// );
+/// // 15:00 in Berlin this week, 16:00 from the next.
+/// assert_eq!(advisories[1].shift_minutes, 60);
+/// ```
+pub fn dst_advisories(
+    datetime: &str,
+    meeting_timezone: &str,
+    timezones: &[&str],
+) -> Result<Vec<DstAdvisory>, TruthError> {
+    let at = DateTime::parse_from_rfc3339(datetime)
+        .map_err(|e| TruthError::InvalidDatetime(format!("'{}': {}", datetime, e)))?
+        .with_timezone(&Utc);
+    let meeting_tz = parse_timezone(meeting_timezone)?;
+    let mut zones = vec![meeting_tz];
+    for name in timezones {
+        let tz = parse_timezone(name)?;
+        if !zones.contains(&tz) {
+            zones.push(tz);
+        }
+    }
+
+    // The same wall-clock time in the meeting's zone a week either side.
+    let week = |weeks: i64| {
+        let wall = at.with_timezone(&meeting_tz).naive_local() + Duration::weeks(weeks);
+        local_to_utc(&meeting_tz, wall)
+    };
+    let (last_week, next_week) = (week(-1), week(1));
+
+    let mut advisories = Vec::new();
+    for tz in &zones {
+        let local = at.with_timezone(tz);
+        let advisory =
+            |kind, changing: &Tz, transition: DateTime<Utc>, shift, message| DstAdvisory {
+                kind,
+                timezone: tz.name().into(),
+                transition_timezone: changing.name().into(),
+                transition_utc: transition.to_rfc3339(),
+                offset_before: format_utc_offset(
+                    &(transition - Duration::seconds(1)).with_timezone(changing),
+                ),
+                offset_after: format_utc_offset(&transition.with_timezone(changing)),
+                local: local.to_rfc3339(),
+                shift_minutes: shift,
+                message,
+            };
+
+        let near = Duration::days(NEAR_DAYS);
+        if let Some(transition) = transition_between(tz, at - near, at + near) {
+            let changed = transition.with_timezone(tz);
+            let days = (changed.date_naive() - local.date_naive()).num_days();
+            let when = match days {
+                0 => "the day of this meeting".to_string(),
+                1 => "the day after this meeting".to_string(),
+                -1 => "the day before this meeting".to_string(),
+                d if d > 0 => format!("{d} days after this meeting"),
+                d => format!("{} days before this meeting", -d),
+            };
+            let message = format!(
+                "{} changes clocks on {} ({} → {}), {}",
+                tz.name(),
+                changed.format("%A, %B %-d at %H:%M %Z"),
+                format_utc_offset(&(transition - Duration::seconds(1)).with_timezone(tz)),
+                format_utc_offset(&changed),
+                when
+            );
+            advisories.push(advisory(
+                DstAdvisoryKind::NearTransition,
+                tz,
+                transition,
+                0,
+                message,
+            ));
+        }
+
+        for (kind, other) in [
+            (DstAdvisoryKind::ShiftedSinceLastWeek, last_week),
+            (DstAdvisoryKind::ShiftsNextWeek, next_week),
+        ] {
+            let Some(other) = other else { continue };
+            let (early, late) = (at.min(other), at.max(other));
+            // Minutes the meeting moves on this zone's clock, from the
+            // earlier week to the later one.
+            let moved = (late.with_timezone(tz).naive_local()
+                - early.with_timezone(tz).naive_local()
+                - (late.with_timezone(&meeting_tz).naive_local()
+                    - early.with_timezone(&meeting_tz).naive_local()))
+            .num_minutes();
+            if moved == 0 {
+                continue;
+            }
+            // The attendee's own change when it has one, else the meeting's.
+            let Some((changing, transition)) = transition_between(tz, early, late)
+                .map(|t| (tz, t))
+                .or_else(|| transition_between(&meeting_tz, early, late).map(|t| (&meeting_tz, t)))
+            else {
+                continue;
+            };
+            let other_local = other.with_timezone(tz);
+            let because = format!(
+                "because {} changes clocks on {}",
+                changing.name(),
+                transition.with_timezone(changing).format("%A, %B %-d")
+            );
+            let message = if kind == DstAdvisoryKind::ShiftsNextWeek {
+                format!(
+                    "From {} this meeting is at {} in {}, {} than this week ({}), {}",
+                    other_local.format("%A, %B %-d"),
+                    other_local.format("%H:%M %Z"),
+                    tz.name(),
+                    describe_shift(moved),
+                    local.format("%H:%M %Z"),
+                    because
+                )
+            } else {
+                format!(
+                    "This week the meeting is at {} in {}, {} than on {} ({}), {}",
+                    local.format("%H:%M %Z"),
+                    tz.name(),
+                    describe_shift(moved),
+                    other_local.format("%A, %B %-d"),
+                    other_local.format("%H:%M %Z"),
+                    because
+                )
+            };
+            advisories.push(advisory(kind, changing, transition, moved, message));
+        }
+    }
+    Ok(advisories)
+}
+
+/// The instant `tz` changes its UTC offset within `(from, to]`, to the
+/// second, if it changes once.
+fn transition_between(tz: &Tz, from: DateTime<Utc>, to: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let offset_at = |t: DateTime<Utc>| t.with_timezone(tz).offset().fix().local_minus_utc();
+    let before = offset_at(from);
+    if before == offset_at(to) {
+        return None;
+    }
+    let (mut lo, mut hi) = (from, to);
+    while hi - lo > Duration::seconds(1) {
+        let mid = lo + Duration::seconds((hi - lo).num_seconds() / 2);
+        if offset_at(mid) == before {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(hi)
+}
+
+/// "1 hour later", "30 minutes earlier", "1 hour 30 minutes later".
+fn describe_shift(minutes: i64) -> String {
+    let direction = if minutes > 0 { "later" } else { "earlier" };
+    let minutes = minutes.abs();
+    let plural = |n: i64, unit: &str| format!("{n} {unit}{}", if n == 1 { "" } else { "s" });
+    let amount = match (minutes / 60, minutes % 60) {
+        (0, m) => plural(m, "minute"),
+        (h, 0) => plural(h, "hour"),
+        (h, m) => format!("{} {}", plural(h, "hour"), plural(m, "minute")),
+    };
+    format!("{amount} {direction}")
+}
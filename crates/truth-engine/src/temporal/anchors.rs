@@ -0,0 +1,83 @@
+//! Named recurring anchors ("payday", "sprint boundary") for
+//! [`resolve_relative_with_options`].
+//!
+//! An anchor is a name plus a calendar rule. Once registered in
+//! [`ResolveOptions::anchors`], the name works like a weekday: "next payday",
+//! "last sprint boundary", "two days before next payday", "payday at 9am".
+//! Rules are evaluated against the anchor date in the expression's timezone,
+//! so results are as deterministic as the rest of the grammar.
+//!
+//! [`resolve_relative_with_options`]: super::resolve_relative_with_options
+//! [`ResolveOptions::anchors`]: super::ResolveOptions::anchors
+
+use chrono::NaiveDate;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// When a named anchor recurs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AnchorRule {
+    /// The Nth business day of each month, per [`ResolveOptions::working_hours`]
+    /// or [`ResolveOptions::business_calendar`]. Negative values count from the
+    /// end: `-1` is the last business day.
+    ///
+    /// [`ResolveOptions::working_hours`]: super::ResolveOptions::working_hours
+    /// [`ResolveOptions::business_calendar`]: super::ResolveOptions::business_calendar
+    NthBusinessDayOfMonth(i32),
+    /// A fixed day of each month, clamped to shorter months (31 → April 30).
+    DayOfMonth(u32),
+    /// Every `weeks` weeks counting from `start`, on `start`'s weekday.
+    EveryNWeeks {
+        /// Any one occurrence; earlier and later occurrences are derived from it.
+        start: NaiveDate,
+        /// Weeks between occurrences (1 = weekly, 2 = every other week).
+        weeks: u32,
+    },
+}
+
+/// A named recurring date that expressions can refer to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RecurringAnchor {
+    /// The name used in expressions, matched case-insensitively ("payday").
+    pub name: String,
+    /// When it recurs.
+    pub rule: AnchorRule,
+}
+
+impl RecurringAnchor {
+    /// Create an anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "resolver")] {
+    /// use chrono::{TimeZone, Utc};
+    /// use truth_engine::temporal::{
+    ///     resolve_relative_with_options, AnchorRule, RecurringAnchor, ResolveOptions,
+    /// };
+    ///
+    /// let options = ResolveOptions {
+    ///     anchors: vec![RecurringAnchor::new(
+    ///         "payday",
+    ///         AnchorRule::NthBusinessDayOfMonth(-1),
+    ///     )],
+    ///     ..Default::default()
+    /// };
+    /// let anchor = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+    /// let r = resolve_relative_with_options(anchor, "two days before next payday", "UTC", &options)
+    ///     .unwrap();
+    /// // Last business day of February 2026 is Friday the 27th.
+    /// assert_eq!(r.resolved_utc, "2026-02-25T00:00:00+00:00");
+    /// # }
+    /// ```
+    pub fn new(name: impl Into<String>, rule: AnchorRule) -> Self {
+        Self {
+            name: name.into(),
+            rule,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Additional renderings of result datetimes.
+//!
+//! Every temporal result carries RFC 3339 strings. Clients that display times
+//! would otherwise re-parse those strings just to show "Tue, 24 Feb 2026
+//! 14:00:00 -0500" or an epoch value, so results can carry the extra
+//! renderings themselves.
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::DateTime;
+use chrono_tz::Tz;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{AdjustedTimestamp, ConvertedDatetime, ResolvedDatetime};
+use crate::error::TruthError;
+
+/// Which extra renderings to produce.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FormatOptions {
+    /// A `strftime` pattern (e.g., `"%a %d %b %Y, %H:%M %Z"`), rendered in the
+    /// result's local timezone.
+    pub pattern: Option<String>,
+    /// Produce an RFC 2822 rendering (e.g., `"Tue, 24 Feb 2026 14:00:00 -0500"`).
+    pub rfc2822: bool,
+    /// Produce Unix epoch seconds.
+    pub epoch_seconds: bool,
+}
+
+/// Extra renderings of a result datetime. Fields not requested are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Renderings {
+    /// The datetime rendered with [`FormatOptions::pattern`].
+    pub custom: Option<String>,
+    /// The datetime in RFC 2822 format.
+    pub rfc2822: Option<String>,
+    /// Seconds since the Unix epoch.
+    pub epoch_seconds: Option<i64>,
+}
+
+impl ConvertedDatetime {
+    /// Attach the requested renderings of [`local`](Self::local).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidFormat`] if the pattern is not valid `strftime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use truth_engine::temporal::{convert_timezone, FormatOptions};
+    ///
+    /// let converted = convert_timezone("2026-03-15T14:00:00Z", "Asia/Tokyo")
+    ///     .unwrap()
+    ///     .with_renderings(&FormatOptions {
+    ///         pattern: Some("%H:%M %Z".to_string()),
+    ///         epoch_seconds: true,
+    ///         ..Default::default()
+    ///     })
+    ///     .unwrap();
+    /// let renderings = converted.renderings.unwrap();
+    /// assert_eq!(renderings.custom.as_deref(), Some("23:00 JST"));
+    /// assert_eq!(renderings.epoch_seconds, Some(1_773_583_200));
+    /// ```
+    pub fn with_renderings(mut self, options: &FormatOptions) -> Result<Self, TruthError> {
+        self.renderings = Some(render_string(&self.local, &self.timezone, options)?);
+        Ok(self)
+    }
+}
+
+impl ResolvedDatetime {
+    /// Attach the requested renderings of [`resolved_local`](Self::resolved_local).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidFormat`] if the pattern is not valid `strftime`.
+    pub fn with_renderings(mut self, options: &FormatOptions) -> Result<Self, TruthError> {
+        self.renderings = Some(render_string(
+            &self.resolved_local,
+            &self.timezone,
+            options,
+        )?);
+        Ok(self)
+    }
+}
+
+impl AdjustedTimestamp {
+    /// Attach the requested renderings of [`adjusted_local`](Self::adjusted_local).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidFormat`] if the pattern is not valid `strftime`.
+    pub fn with_renderings(mut self, options: &FormatOptions) -> Result<Self, TruthError> {
+        self.renderings = Some(render_string(
+            &self.adjusted_local,
+            &self.timezone,
+            options,
+        )?);
+        Ok(self)
+    }
+}
+
+/// Render an RFC 3339 result string in its IANA timezone.
+fn render_string(
+    local: &str,
+    timezone: &str,
+    options: &FormatOptions,
+) -> Result<Renderings, TruthError> {
+    let tz = super::parse_timezone(timezone)?;
+    let dt = DateTime::parse_from_rfc3339(local)
+        .map_err(|e| TruthError::InvalidDatetime(format!("'{}': {}", local, e)))?
+        .with_timezone(&tz);
+    render(&dt, options)
+}
+
+/// Produce the requested renderings of a datetime.
+fn render(dt: &DateTime<Tz>, options: &FormatOptions) -> Result<Renderings, TruthError> {
+    let custom = match &options.pattern {
+        Some(pattern) => {
+            // `format` panics on invalid items when displayed, so validate first.
+            let items: Vec<Item<'_>> = StrftimeItems::new(pattern).collect();
+            if items.iter().any(|item| matches!(item, Item::Error)) {
+                return Err(TruthError::InvalidFormat(format!(
+                    "'{}' is not a valid strftime pattern",
+                    pattern
+                )));
+            }
+            Some(dt.format_with_items(items.into_iter()).to_string())
+        }
+        None => None,
+    };
+    Ok(Renderings {
+        custom,
+        rfc2822: options.rfc2822.then(|| dt.to_rfc2822()),
+        epoch_seconds: options.epoch_seconds.then(|| dt.timestamp()),
+    })
+}
@@ -0,0 +1,116 @@
+//! Parsing of datetime strings passed to the string-accepting APIs.
+//!
+//! By default those APIs require strict RFC 3339. Callers forwarding
+//! user- or model-written input can opt into [`InputMode::Lenient`], which
+//! also accepts the near-misses that show up in practice: a space instead of
+//! `T`, missing seconds, a detached or lowercase `Z`, and bare dates.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::TruthError;
+
+/// How datetime strings are parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum InputMode {
+    /// RFC 3339 only (`2026-03-15T14:00:00Z`).
+    #[default]
+    Strict,
+    /// RFC 3339 plus common near-misses:
+    /// - `2026-03-15 14:00:00Z` — space separator
+    /// - `2026-03-15T14:00Z` — missing seconds
+    /// - `2026-03-15 14:00 Z`, `2026-03-15t14:00z` — detached or lowercase `Z`
+    /// - `2026-03-15T14:00+0100`, `2026-03-15T14:00+01` — compact offsets
+    /// - `2026-03-15` — a bare date, read as `T00:00`
+    ///
+    /// Input without an offset is read as UTC.
+    Lenient,
+}
+
+/// Parse a datetime string into `DateTime<Utc>`.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the string is not a datetime in
+/// the given mode.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::{parse_datetime, InputMode};
+///
+/// assert!(parse_datetime("2026-03-15 14:00", InputMode::Strict).is_err());
+/// let dt = parse_datetime("2026-03-15 14:00", InputMode::Lenient).unwrap();
+/// assert_eq!(dt.to_rfc3339(), "2026-03-15T14:00:00+00:00");
+/// ```
+pub fn parse_datetime(s: &str, mode: InputMode) -> Result<DateTime<Utc>, TruthError> {
+    let strict = DateTime::parse_from_rfc3339(s).map(|dt| dt.with_timezone(&Utc));
+    match (strict, mode) {
+        (Ok(dt), _) => Ok(dt),
+        (Err(_), InputMode::Lenient) => parse_lenient(s).ok_or_else(|| {
+            TruthError::InvalidDatetime(format!("'{}': not a recognized datetime", s))
+        }),
+        (Err(e), InputMode::Strict) => Err(TruthError::InvalidDatetime(format!("'{}': {}", s, e))),
+    }
+}
+
+fn parse_lenient(s: &str) -> Option<DateTime<Utc>> {
+    let s = s.trim();
+    let (date, rest) = s.split_at_checked(10)?;
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    let rest = rest
+        .strip_prefix(['T', 't', ' '])
+        .unwrap_or(rest)
+        .trim_start();
+    if rest.is_empty() {
+        return Some(Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN)));
+    }
+
+    let (time, offset) = split_offset(rest)?;
+    let time = NaiveTime::parse_from_str(time.trim_end(), "%H:%M:%S%.f")
+        .or_else(|_| NaiveTime::parse_from_str(time.trim_end(), "%H:%M"))
+        .ok()?;
+    let naive = NaiveDateTime::new(date, time);
+    Some(
+        offset
+            .from_local_datetime(&naive)
+            .single()?
+            .with_timezone(&Utc),
+    )
+}
+
+/// Split a time-of-day from its trailing offset (`Z`, `+01:00`, `+0100`, `+01`);
+/// no offset means UTC.
+fn split_offset(s: &str) -> Option<(&str, FixedOffset)> {
+    let utc = FixedOffset::east_opt(0)?;
+    if let Some(time) = s.strip_suffix(['Z', 'z']) {
+        return Some((time, utc));
+    }
+    let Some(at) = s.rfind(['+', '-']) else {
+        return Some((s, utc));
+    };
+    let (time, offset) = s.split_at(at);
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let digits: String = offset[1..].chars().filter(|c| *c != ':').collect();
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (
+            digits[..2].parse::<i32>().ok()?,
+            digits[2..].parse::<i32>().ok()?,
+        ),
+        _ => return None,
+    };
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some((
+        time,
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))?,
+    ))
+}
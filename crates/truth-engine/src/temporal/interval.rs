@@ -0,0 +1,242 @@
+//! Half-open time intervals and the arithmetic on them.
+//!
+//! Conflict detection, free/busy, and availability merging all reduce to the
+//! same few operations on `[start, end)` ranges. [`TimeInterval`] provides
+//! them once, with the conventions those modules rely on: an interval
+//! contains its start but not its end, so back-to-back intervals touch without
+//! overlapping.
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::TruthError;
+use crate::expander::ExpandedEvent;
+
+/// A half-open span of time `[start, end)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TimeInterval {
+    /// First instant in the interval.
+    pub start: DateTime<Utc>,
+    /// First instant after the interval.
+    pub end: DateTime<Utc>,
+}
+
+impl TimeInterval {
+    /// Create an interval. `start == end` is an empty interval.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidDatetime`] if `end` is before `start`.
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Self, TruthError> {
+        if end < start {
+            return Err(TruthError::InvalidDatetime(format!(
+                "interval end {} is before start {}",
+                end.to_rfc3339(),
+                start.to_rfc3339()
+            )));
+        }
+        Ok(Self { start, end })
+    }
+
+    /// The interval's length.
+    pub fn duration(&self) -> chrono::Duration {
+        self.end - self.start
+    }
+
+    /// Whether the interval has zero length.
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// The same interval moved by `by` (negative moves it earlier).
+    pub fn shift(&self, by: chrono::Duration) -> Self {
+        Self {
+            start: self.start + by,
+            end: self.end + by,
+        }
+    }
+
+    /// Whether `instant` is inside the interval (start inclusive, end exclusive).
+    pub fn contains(&self, instant: DateTime<Utc>) -> bool {
+        self.start <= instant && instant < self.end
+    }
+
+    /// Whether `other` lies entirely within this interval.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether the intervals share any time. Intervals that merely touch
+    /// (one ends when the other starts) do not overlap.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// The time both intervals share, or `None` if they don't overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use truth_engine::temporal::interval::TimeInterval;
+    ///
+    /// let at = |h| Utc.with_ymd_and_hms(2026, 2, 18, h, 0, 0).unwrap();
+    /// let a = TimeInterval::new(at(9), at(12)).unwrap();
+    /// let b = TimeInterval::new(at(11), at(14)).unwrap();
+    /// assert_eq!(a.intersect(&b), Some(TimeInterval::new(at(11), at(12)).unwrap()));
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        self.overlaps(other).then(|| Self {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    /// The smallest interval covering both, if they overlap or touch; `None`
+    /// if there is a gap between them.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        (self.start <= other.end && other.start <= self.end).then(|| Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// The parts of this interval not covered by `other`: zero, one, or two
+    /// intervals, in order.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        if !self.overlaps(other) {
+            return if self.is_empty() {
+                Vec::new()
+            } else {
+                vec![*self]
+            };
+        }
+        let mut parts = Vec::with_capacity(2);
+        if self.start < other.start {
+            parts.push(Self {
+                start: self.start,
+                end: other.start,
+            });
+        }
+        if other.end < self.end {
+            parts.push(Self {
+                start: other.end,
+                end: self.end,
+            });
+        }
+        parts
+    }
+
+    /// The parts of this interval not covered by any of `others`, in order.
+    pub fn subtract_all(&self, others: &[Self]) -> Vec<Self> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        let mut free = Vec::new();
+        let mut cursor = self.start;
+        for busy in merge(others.iter().filter_map(|o| o.clamp_to(self))) {
+            if cursor < busy.start {
+                free.push(Self {
+                    start: cursor,
+                    end: busy.start,
+                });
+            }
+            cursor = cursor.max(busy.end);
+        }
+        if cursor < self.end {
+            free.push(Self {
+                start: cursor,
+                end: self.end,
+            });
+        }
+        free
+    }
+
+    /// This interval cut down to `window`, or `None` if it falls outside it.
+    /// Equivalent to [`intersect`](Self::intersect), named for the common case
+    /// of restricting events to a query range.
+    pub fn clamp_to(&self, window: &Self) -> Option<Self> {
+        self.intersect(window)
+    }
+}
+
+impl From<&ExpandedEvent> for TimeInterval {
+    fn from(event: &ExpandedEvent) -> Self {
+        Self {
+            start: event.start,
+            end: event.end,
+        }
+    }
+}
+
+/// Merge overlapping or touching intervals into a sorted, disjoint list.
+/// Empty intervals are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::temporal::interval::{merge, TimeInterval};
+///
+/// let at = |h| Utc.with_ymd_and_hms(2026, 2, 18, h, 0, 0).unwrap();
+/// let merged = merge([
+///     TimeInterval::new(at(13), at(14)).unwrap(),
+///     TimeInterval::new(at(9), at(10)).unwrap(),
+///     TimeInterval::new(at(10), at(11)).unwrap(),
+/// ]);
+/// assert_eq!(merged.len(), 2);
+/// assert_eq!((merged[0].start, merged[0].end), (at(9), at(11)));
+/// ```
+pub fn merge(intervals: impl IntoIterator<Item = TimeInterval>) -> Vec<TimeInterval> {
+    merge_within(intervals, chrono::Duration::zero())
+}
+
+/// Like [`merge`], but also merges intervals separated by a gap of at most
+/// `tolerance`, which becomes part of the merged interval. A negative
+/// tolerance is treated as zero.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use truth_engine::temporal::interval::{merge_within, TimeInterval};
+///
+/// let at = |h, m| Utc.with_ymd_and_hms(2026, 2, 18, h, m, 0).unwrap();
+/// let merged = merge_within(
+///     [
+///         TimeInterval::new(at(9, 0), at(10, 0)).unwrap(),
+///         TimeInterval::new(at(10, 5), at(11, 0)).unwrap(),
+///         TimeInterval::new(at(11, 30), at(12, 0)).unwrap(),
+///     ],
+///     Duration::minutes(5),
+/// );
+/// assert_eq!(merged.len(), 2);
+/// assert_eq!((merged[0].start, merged[0].end), (at(9, 0), at(11, 0)));
+/// ```
+pub fn merge_within(
+    intervals: impl IntoIterator<Item = TimeInterval>,
+    tolerance: chrono::Duration,
+) -> Vec<TimeInterval> {
+    let tolerance = tolerance.max(chrono::Duration::zero());
+    let mut sorted: Vec<TimeInterval> = intervals.into_iter().filter(|i| !i.is_empty()).collect();
+    #[cfg(feature = "rayon")]
+    sorted.par_sort_unstable();
+    #[cfg(not(feature = "rayon"))]
+    sorted.sort_unstable();
+
+    let mut merged: Vec<TimeInterval> = Vec::with_capacity(sorted.len());
+    for interval in sorted {
+        match merged.last_mut() {
+            Some(last) if interval.start - last.end <= tolerance => {
+                last.end = last.end.max(interval.end)
+            }
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
@@ -0,0 +1,372 @@
+//! Localized interpretation strings.
+//!
+//! Weekday and month names are kept in small tables here rather than pulling
+//! in a locale database: the interpretation string only needs names, a date
+//! order, and a 12- or 24-hour clock.
+
+use chrono::{DateTime, Datelike, TimeZone};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Language for human-readable interpretation strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Locale {
+    /// "Tuesday, February 24, 2026 at 2:00 PM EST"
+    #[default]
+    English,
+    /// "martes, 24 de febrero de 2026, 14:00 EST"
+    Spanish,
+    /// "mardi 24 février 2026 à 14:00 EST"
+    French,
+    /// "Dienstag, 24. Februar 2026 um 14:00 EST"
+    German,
+    /// "terça-feira, 24 de fevereiro de 2026 às 14:00 EST"
+    Portuguese,
+    /// "martedì 24 febbraio 2026 alle 14:00 EST"
+    Italian,
+    /// "dinsdag 24 februari 2026 om 14:00 EST"
+    Dutch,
+}
+
+/// 12- or 24-hour clock for times in human-readable strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ClockFormat {
+    /// "2:00 PM"
+    TwelveHour,
+    /// "14:00"
+    TwentyFourHour,
+}
+
+impl ClockFormat {
+    /// The `strftime` pattern for an hour and minute on this clock.
+    pub(super) fn pattern(self) -> &'static str {
+        match self {
+            Self::TwelveHour => "%-I:%M %p",
+            Self::TwentyFourHour => "%H:%M",
+        }
+    }
+}
+
+impl Locale {
+    /// The clock this locale conventionally uses: 12-hour for English, 24-hour
+    /// for the others.
+    pub fn default_clock(self) -> ClockFormat {
+        match self {
+            Self::English => ClockFormat::TwelveHour,
+            _ => ClockFormat::TwentyFourHour,
+        }
+    }
+
+    /// Look up a locale from a BCP 47 tag such as `"es"`, `"pt-BR"`, or `"de_AT"`.
+    /// Only the language subtag is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use truth_engine::temporal::Locale;
+    ///
+    /// assert_eq!(Locale::from_tag("es-MX"), Some(Locale::Spanish));
+    /// assert_eq!(Locale::from_tag("xx"), None);
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_']).next()?.to_ascii_lowercase();
+        match language.as_str() {
+            "en" => Some(Self::English),
+            "es" => Some(Self::Spanish),
+            "fr" => Some(Self::French),
+            "de" => Some(Self::German),
+            "pt" => Some(Self::Portuguese),
+            "it" => Some(Self::Italian),
+            "nl" => Some(Self::Dutch),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "resolver")]
+    fn weekdays(self) -> [&'static str; 7] {
+        match self {
+            Self::English => [
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Saturday",
+                "Sunday",
+            ],
+            Self::Spanish => [
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+                "domingo",
+            ],
+            Self::French => [
+                "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+            ],
+            Self::German => [
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+                "Sonntag",
+            ],
+            Self::Portuguese => [
+                "segunda-feira",
+                "terça-feira",
+                "quarta-feira",
+                "quinta-feira",
+                "sexta-feira",
+                "sábado",
+                "domingo",
+            ],
+            Self::Italian => [
+                "lunedì",
+                "martedì",
+                "mercoledì",
+                "giovedì",
+                "venerdì",
+                "sabato",
+                "domenica",
+            ],
+            Self::Dutch => [
+                "maandag",
+                "dinsdag",
+                "woensdag",
+                "donderdag",
+                "vrijdag",
+                "zaterdag",
+                "zondag",
+            ],
+        }
+    }
+
+    fn short_weekdays(self) -> [&'static str; 7] {
+        match self {
+            Self::English => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+            Self::Spanish => ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+            Self::French => ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+            Self::German => ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+            Self::Portuguese => ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+            Self::Italian => ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+            Self::Dutch => ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        }
+    }
+
+    fn short_months(self) -> [&'static str; 12] {
+        match self {
+            Self::English => [
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+            Self::Spanish => [
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ],
+            Self::French => [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+            Self::German => [
+                "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+                "Nov.", "Dez.",
+            ],
+            Self::Portuguese => [
+                "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.",
+                "nov.", "dez.",
+            ],
+            Self::Italian => [
+                "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+            ],
+            Self::Dutch => [
+                "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+            ],
+        }
+    }
+
+    #[cfg(feature = "resolver")]
+    fn months(self) -> [&'static str; 12] {
+        match self {
+            Self::English => [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            Self::Spanish => [
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+            Self::French => [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            Self::German => [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            Self::Portuguese => [
+                "janeiro",
+                "fevereiro",
+                "março",
+                "abril",
+                "maio",
+                "junho",
+                "julho",
+                "agosto",
+                "setembro",
+                "outubro",
+                "novembro",
+                "dezembro",
+            ],
+            Self::Italian => [
+                "gennaio",
+                "febbraio",
+                "marzo",
+                "aprile",
+                "maggio",
+                "giugno",
+                "luglio",
+                "agosto",
+                "settembre",
+                "ottobre",
+                "novembre",
+                "dicembre",
+            ],
+            Self::Dutch => [
+                "januari",
+                "februari",
+                "maart",
+                "april",
+                "mei",
+                "juni",
+                "juli",
+                "augustus",
+                "september",
+                "oktober",
+                "november",
+                "december",
+            ],
+        }
+    }
+}
+
+/// Format a datetime as a human-readable interpretation in `locale`, with
+/// times on `clock`.
+#[cfg(feature = "resolver")]
+pub(super) fn format_interpretation_in<T: TimeZone>(
+    dt: &DateTime<T>,
+    locale: Locale,
+    clock: ClockFormat,
+) -> String
+where
+    T::Offset: std::fmt::Display,
+{
+    let weekday = locale.weekdays()[dt.weekday().num_days_from_monday() as usize];
+    let month = locale.months()[dt.month0() as usize];
+    let (day, year) = (dt.day(), dt.year());
+    let time = dt.format(&format!("{} %Z", clock.pattern())).to_string();
+    match locale {
+        Locale::English => format!("{}, {} at {time}", dt.format("%A, %B %-d"), year),
+        Locale::Spanish => format!("{weekday}, {day} de {month} de {year}, {time}"),
+        Locale::French => format!("{weekday} {day} {month} {year} à {time}"),
+        Locale::German => format!("{weekday}, {day}. {month} {year} um {time}"),
+        Locale::Portuguese => format!("{weekday}, {day} de {month} de {year} às {time}"),
+        Locale::Italian => format!("{weekday} {day} {month} {year} alle {time}"),
+        Locale::Dutch => format!("{weekday} {day} {month} {year} om {time}"),
+    }
+}
+
+/// Format the time of day on `clock` with the zone abbreviation ("9:00 AM EST").
+pub(crate) fn format_time_in<T: TimeZone>(dt: &DateTime<T>, clock: ClockFormat) -> String
+where
+    T::Offset: std::fmt::Display,
+{
+    dt.format(&format!("{} %Z", clock.pattern())).to_string()
+}
+
+/// Format a datetime compactly in `locale`, with abbreviated names and times
+/// on `clock` ("Tue, Mar 3 at 9:00 AM EST"); the year is included only when
+/// `with_year` is set ("Wed, Mar 3, 2027 at 9:00 AM EST").
+pub(crate) fn format_short_in<T: TimeZone>(
+    dt: &DateTime<T>,
+    locale: Locale,
+    clock: ClockFormat,
+    with_year: bool,
+) -> String
+where
+    T::Offset: std::fmt::Display,
+{
+    let weekday = locale.short_weekdays()[dt.weekday().num_days_from_monday() as usize];
+    let month = locale.short_months()[dt.month0() as usize];
+    let day = dt.day();
+    let year = if with_year {
+        format!(" {}", dt.year())
+    } else {
+        String::new()
+    };
+    let time = format_time_in(dt, clock);
+    match locale {
+        Locale::English => {
+            let year = if with_year { format!(",{year}") } else { year };
+            format!("{weekday}, {month} {day}{year} at {time}")
+        }
+        Locale::Spanish => format!("{weekday}, {day} {month}{year}, {time}"),
+        Locale::French => format!("{weekday} {day} {month}{year} à {time}"),
+        Locale::German => format!("{weekday}, {day}. {month}{year} um {time}"),
+        Locale::Portuguese => {
+            let year = if with_year {
+                format!(" de{year}")
+            } else {
+                year
+            };
+            format!("{weekday}, {day} de {month}{year} às {time}")
+        }
+        Locale::Italian => format!("{weekday} {day} {month}{year} alle {time}"),
+        Locale::Dutch => format!("{weekday} {day} {month}{year} om {time}"),
+    }
+}
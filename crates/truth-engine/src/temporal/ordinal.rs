@@ -0,0 +1,91 @@
+//! Day-of-year and Julian day numbers.
+//!
+//! Logistics and scientific users number days rather than naming them:
+//! "day 200 of 2026" or Julian day 2461240. These helpers convert between
+//! those numbers and `YYYY-MM-DD` dates in the proleptic Gregorian calendar.
+
+use chrono::{Datelike, NaiveDate};
+
+use super::parse_date;
+use crate::error::TruthError;
+
+/// Julian day number of 0000-12-31 (the day before `num_days_from_ce` day 1).
+const JDN_OFFSET: i64 = 1_721_425;
+
+/// The ordinal day of the year (1–366) for a `YYYY-MM-DD` date.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the date cannot be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::day_of_year;
+///
+/// assert_eq!(day_of_year("2026-07-19").unwrap(), 200);
+/// ```
+pub fn day_of_year(date: &str) -> Result<u32, TruthError> {
+    Ok(parse_date(date)?.ordinal())
+}
+
+/// The `YYYY-MM-DD` date of ordinal day `day` (1–366) in `year`.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if `year` has no such day (e.g., day
+/// 366 of a common year).
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::date_from_day_of_year;
+///
+/// assert_eq!(date_from_day_of_year(2026, 200).unwrap(), "2026-07-19");
+/// assert!(date_from_day_of_year(2026, 366).is_err());
+/// ```
+pub fn date_from_day_of_year(year: i32, day: u32) -> Result<String, TruthError> {
+    NaiveDate::from_yo_opt(year, day)
+        .map(|d| d.to_string())
+        .ok_or_else(|| TruthError::InvalidDatetime(format!("{} has no day {}", year, day)))
+}
+
+/// The Julian day number of a `YYYY-MM-DD` date (the integer day count used
+/// in astronomy; 2000-01-01 is 2451545).
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the date cannot be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::julian_day_number;
+///
+/// assert_eq!(julian_day_number("2000-01-01").unwrap(), 2_451_545);
+/// ```
+pub fn julian_day_number(date: &str) -> Result<i64, TruthError> {
+    Ok(i64::from(parse_date(date)?.num_days_from_ce()) + JDN_OFFSET)
+}
+
+/// The `YYYY-MM-DD` date of a Julian day number.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the number is outside the
+/// supported date range.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::date_from_julian_day;
+///
+/// assert_eq!(date_from_julian_day(2_461_090).unwrap(), "2026-02-18");
+/// ```
+pub fn date_from_julian_day(jdn: i64) -> Result<String, TruthError> {
+    i32::try_from(jdn - JDN_OFFSET)
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .map(|d| d.to_string())
+        .ok_or_else(|| TruthError::InvalidDatetime(format!("Julian day {} is out of range", jdn)))
+}
@@ -0,0 +1,108 @@
+//! Application-defined expression parsers for [`resolve_relative_with_options`].
+//!
+//! The built-in grammar is deliberately closed. Applications with their own
+//! vocabulary ("sprint 42", "payday", "the offsite") register parsers here;
+//! they run before the built-ins, in registration order, and the first one
+//! that returns a datetime wins. Parsers receive the anchor explicitly, so
+//! resolution stays deterministic.
+//!
+//! [`resolve_relative_with_options`]: super::resolve_relative_with_options
+
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono_tz::Tz;
+
+/// A custom expression parser.
+///
+/// Implemented for any `Fn(&str, &DateTime<Tz>) -> Option<DateTime<Tz>>` closure.
+pub trait ExpressionParser: Send + Sync {
+    /// Resolve `expression` relative to `anchor`, or return `None` to defer to the
+    /// next parser.
+    ///
+    /// `expression` is normalized (trimmed, lowercased, single-spaced, leading
+    /// article removed). `anchor` is "now" in the timezone the expression is
+    /// interpreted in; its `timezone()` is that zone.
+    fn parse(&self, expression: &str, anchor: &DateTime<Tz>) -> Option<DateTime<Tz>>;
+}
+
+impl<F> ExpressionParser for F
+where
+    F: Fn(&str, &DateTime<Tz>) -> Option<DateTime<Tz>> + Send + Sync,
+{
+    fn parse(&self, expression: &str, anchor: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        self(expression, anchor)
+    }
+}
+
+/// An ordered set of named custom parsers, consulted before the built-in grammar.
+///
+/// Cloning is cheap: parsers are shared, not copied.
+#[derive(Clone, Default)]
+pub struct ParserRegistry {
+    parsers: Vec<(String, Arc<dyn ExpressionParser>)>,
+}
+
+impl ParserRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a parser under `name`. Parsers run in registration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "resolver")] {
+    /// use chrono::{DateTime, TimeZone, Utc};
+    /// use chrono_tz::Tz;
+    /// use truth_engine::temporal::{resolve_relative_with_options, ParserRegistry, ResolveOptions};
+    ///
+    /// let mut parsers = ParserRegistry::new();
+    /// parsers.register("payday", |expr: &str, anchor: &DateTime<Tz>| {
+    ///     (expr == "payday").then(|| {
+    ///         anchor.timezone().with_ymd_and_hms(2026, 2, 27, 9, 0, 0).unwrap()
+    ///     })
+    /// });
+    /// let options = ResolveOptions { parsers, ..Default::default() };
+    ///
+    /// let anchor = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+    /// let r = resolve_relative_with_options(anchor, "Payday", "UTC", &options).unwrap();
+    /// assert_eq!(r.resolved_utc, "2026-02-27T09:00:00+00:00");
+    /// # }
+    /// ```
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        parser: impl ExpressionParser + 'static,
+    ) -> &mut Self {
+        self.parsers.push((name.into(), Arc::new(parser)));
+        self
+    }
+
+    /// The registered parser names, in the order they run.
+    pub fn names(&self) -> Vec<&str> {
+        self.parsers.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Whether no parsers are registered.
+    pub fn is_empty(&self) -> bool {
+        self.parsers.is_empty()
+    }
+
+    /// Run the parsers in order and return the first match.
+    #[cfg(feature = "resolver")]
+    pub(crate) fn parse(&self, expression: &str, anchor: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        self.parsers
+            .iter()
+            .find_map(|(_, parser)| parser.parse(expression, anchor))
+    }
+}
+
+impl fmt::Debug for ParserRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
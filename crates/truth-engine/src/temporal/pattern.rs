@@ -0,0 +1,137 @@
+//! Simple recurring times ("every Monday at 09:00", "the 15th of every month
+//! at 17:00") for [`next_matching`], without building an RRULE.
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::local_to_utc;
+use crate::error::TruthError;
+
+/// Which days a [`SimplePattern`] falls on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PatternDays {
+    /// Every day.
+    EveryDay,
+    /// Each of these weekdays.
+    Weekdays(Vec<Weekday>),
+    /// A fixed day of each month, clamped to shorter months (31 → April 30).
+    DayOfMonth(u32),
+}
+
+/// A time of day on the days [`PatternDays`] picks, in the timezone given
+/// to [`next_matching`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SimplePattern {
+    pub days: PatternDays,
+    /// Local time of day.
+    pub time: NaiveTime,
+}
+
+impl SimplePattern {
+    /// Every day at `time`.
+    pub fn daily(time: NaiveTime) -> Self {
+        Self {
+            days: PatternDays::EveryDay,
+            time,
+        }
+    }
+
+    /// Every `weekday` at `time`.
+    pub fn weekly(weekday: Weekday, time: NaiveTime) -> Self {
+        Self {
+            days: PatternDays::Weekdays(vec![weekday]),
+            time,
+        }
+    }
+
+    /// The `day`th of every month at `time`, clamped to shorter months.
+    pub fn monthly(day: u32, time: NaiveTime) -> Self {
+        Self {
+            days: PatternDays::DayOfMonth(day),
+            time,
+        }
+    }
+
+    /// Whether the pattern falls on `date`.
+    fn matches(&self, date: NaiveDate) -> bool {
+        match &self.days {
+            PatternDays::EveryDay => true,
+            PatternDays::Weekdays(days) => days.contains(&date.weekday()),
+            PatternDays::DayOfMonth(day) => date.day() == clamp_day(date, *day),
+        }
+    }
+}
+
+/// `day` in `date`'s month, clamped to the month's last day.
+fn clamp_day(date: NaiveDate, day: u32) -> u32 {
+    let last = date
+        .with_day(1)
+        .and_then(|first| first.checked_add_months(Months::new(1)))
+        .and_then(|next| next.pred_opt())
+        .map_or(28, |last| last.day());
+    day.min(last)
+}
+
+/// The first occurrence of `pattern` in `timezone` strictly after `anchor`.
+///
+/// Local times that do not exist on a day (DST gaps) are shifted forward
+/// past the gap; times that occur twice take the earlier instant.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidArguments`] if the pattern names no days (an
+/// empty weekday list, or a day of the month outside 1–31).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveTime, TimeZone, Utc, Weekday};
+/// use chrono_tz::Europe::Berlin;
+/// use truth_engine::temporal::{next_matching, SimplePattern};
+///
+/// // Wednesday, February 18, 2026 at 15:30 in Berlin.
+/// let anchor = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+/// let at = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+///
+/// let monday = next_matching(anchor, &SimplePattern::weekly(Weekday::Mon, at(9)), Berlin).unwrap();
+/// assert_eq!(monday, Berlin.with_ymd_and_hms(2026, 2, 23, 9, 0, 0).unwrap());
+///
+/// let fifteenth = next_matching(anchor, &SimplePattern::monthly(15, at(17)), Berlin).unwrap();
+/// assert_eq!(fifteenth, Berlin.with_ymd_and_hms(2026, 3, 15, 17, 0, 0).unwrap());
+/// ```
+pub fn next_matching(
+    anchor: DateTime<Utc>,
+    pattern: &SimplePattern,
+    timezone: Tz,
+) -> Result<DateTime<Tz>, TruthError> {
+    match &pattern.days {
+        PatternDays::Weekdays(days) if days.is_empty() => {
+            return Err(TruthError::InvalidArguments(
+                "pattern has no weekdays".to_string(),
+            ));
+        }
+        PatternDays::DayOfMonth(day) if !(1..=31).contains(day) => {
+            return Err(TruthError::InvalidArguments(format!(
+                "day of month {} is not between 1 and 31",
+                day
+            )));
+        }
+        _ => {}
+    }
+    // A valid pattern matches at least once a month.
+    let start = anchor.with_timezone(&timezone).date_naive();
+    start
+        .iter_days()
+        .take(62)
+        .filter(|date| pattern.matches(*date))
+        .filter_map(|date| local_to_utc(&timezone, date.and_time(pattern.time)))
+        .find(|at| *at > anchor)
+        .map(|at| at.with_timezone(&timezone))
+        .ok_or_else(|| TruthError::InvalidArguments("pattern has no next occurrence".to_string()))
+}
@@ -0,0 +1,2492 @@
+//! Natural-language resolution of relative time expressions.
+//!
+//! [`resolve_relative`] and its variants turn expressions like "next Friday
+//! at 3pm", "end of Q3", or "two days before next payday" into absolute
+//! datetimes. The grammar, its vocabulary, and the typo tables behind
+//! lenient mode make up most of the crate's parsing code, so they are
+//! compiled only with the `resolver` feature; the option and result types
+//! ([`ResolveOptions`], [`ResolvedDatetime`]) are always available.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc,
+    Weekday,
+};
+use chrono_tz::Tz;
+
+mod anchors;
+mod compose;
+mod diagnose;
+mod solar;
+mod typos;
+mod workweek;
+
+use super::{
+    find_timezone, format_utc_offset, local_to_utc, locale, named_time_to_naive,
+    normalize_expression, parse_decimal, parse_number_word, parse_time_string, parse_timezone,
+    parse_weekday,
+    weeks::{self, parse_week_date},
+    BareHourPolicy, ClockFormat, DayOfMonthPolicy, DstWarning, DstWarningKind, Duration, Fraction,
+    MidpointConvention, ResolutionGranularity, ResolveOptions, ResolvedDatetime, TypoCorrection,
+    WeekNumber, WeekScheme, WeekStartDay,
+};
+use crate::business::BusinessCalendar;
+use crate::error::TruthError;
+use crate::explain::TraceStep;
+use crate::fiscal::FiscalCalendar;
+use crate::terms::TermCalendar;
+
+/// Resolve a relative time expression to an absolute datetime.
+///
+/// Uses ISO 8601 week start (Monday). For configurable week start, use
+/// [`resolve_relative_with_options`].
+///
+/// # Arguments
+///
+/// * `anchor` — The reference "now" instant (typically `Utc::now()`)
+/// * `expression` — A time expression (see [`resolve_relative_with_options`] for grammar)
+/// * `timezone` — An IANA timezone name for interpreting local-time expressions
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidExpression`] if the expression cannot be parsed
+/// deterministically.
+pub fn resolve_relative(
+    anchor: DateTime<Utc>,
+    expression: &str,
+    timezone: &str,
+) -> Result<ResolvedDatetime, TruthError> {
+    resolve_relative_with_options(anchor, expression, timezone, &ResolveOptions::default())
+}
+
+/// Resolve a relative time expression to an absolute datetime with options.
+///
+/// # Arguments
+///
+/// * `anchor` — The reference "now" instant (typically `Utc::now()`)
+/// * `expression` — A time expression (see grammar below)
+/// * `timezone` — An IANA timezone name for interpreting local-time expressions
+/// * `options` — Resolution options (week start day, etc.)
+///
+/// # Supported Expressions
+///
+/// **Anchored**: `"now"`, `"today"`, `"tomorrow"`, `"yesterday"`
+///
+/// **Weekday-relative**: `"next Monday"`, `"this Friday"`, `"last Wednesday"`
+///
+/// **Time-of-day**: `"morning"` (09:00), `"noon"` (12:00), `"afternoon"` (13:00),
+/// `"evening"` (18:00), `"night"` (21:00), `"midnight"` (00:00),
+/// `"end of day"` / `"eob"` (17:00), `"start of business"` / `"sob"` (09:00), `"lunch"` (12:00)
+///
+/// **Explicit time**: `"2pm"`, `"2:30pm"`, `"14:00"`, `"14:30"`
+///
+/// **Offset durations**: `"+2h"`, `"-30m"`, `"in 2 hours"`, `"30 minutes ago"`,
+/// `"a week from now"`. Quantities may be fractional: `"+1.5h"`, `"in 2.5 days"`,
+/// `"in an hour and a half"`, `"half an hour ago"`
+///
+/// **Combined**: `"next Tuesday at 2pm"`, `"tomorrow morning"`,
+/// `"next Friday at 10:30am"`
+///
+/// **Period boundaries**: `"start of week"`, `"end of month"`, `"start of quarter"`,
+/// `"next week"`, `"last month"`, `"next year"`
+///
+/// **Compound periods**: `"start of last week"`, `"end of next month"`,
+/// `"start of next quarter"`, `"end of last year"`
+///
+/// **Midpoints**: `"mid-month"`, `"middle of next month"`, `"mid March"`, `"mid-week"`,
+/// `"middle of next week"` — the 15th and Wednesday at 12:00 by default, configurable
+/// via [`ResolveOptions::midpoints`].
+///
+/// **Bare hours**: `"tomorrow at 7"` is rejected unless [`ResolveOptions::bare_hour`]
+/// picks a reading ([`BareHourPolicy::PreferFuture`] or
+/// [`BareHourPolicy::PreferBusinessHours`]).
+///
+/// **Days of the month**: `"the 15th"` (next occurrence on or after today by default;
+/// see [`ResolveOptions::day_of_month`]), `"the 3rd of next month"`, `"the 1st of last month"`,
+/// optionally `"at <time>"`.
+///
+/// **Quarters**: `"Q3"` (this year), `"Q2 2027"`, `"start of Q3 2026"`, `"end of Q1"`.
+/// Quarters follow [`ResolveOptions::fiscal_year_start`] when set.
+///
+/// **Fiscal periods** (with [`ResolveOptions::fiscal_calendar`]): `"P7"`, `"end of P7"`,
+/// `"start of P1 FY2027"`, `"this period"`, `"end of next period"`.
+///
+/// **Academic terms** (with [`ResolveOptions::term_calendar`]): `"spring term"`,
+/// `"end of spring term 2027"`, `"first Monday of spring term"`, `"start of reading week"`.
+/// Term and break boundaries are midnights in the term calendar's timezone.
+///
+/// **Hebrew, Islamic, and Chinese dates**: `"15 Nisan 5787"`, `"1 Ramadan 1448 AH"`,
+/// `"1 Ramadan"` (the next one on or after today), `"Chinese New Year 2027"`. These resolve
+/// to local midnight of the civil day; see [`crate::calendars`].
+///
+/// **Explicit dates**: `"March 3rd"`, `"3 March 2027"`, `"the 1st of April"`, alone or
+/// with a time: `"March 10 at 2pm"`, `"2026-04-01 eob"`, `"morning of March 3rd"`,
+/// `"2pm on 10 March"`. A missing year means the anchor's year.
+///
+/// **Ordinal dates**: `"first Monday of March"`, `"last Friday of the month"`,
+/// `"third Tuesday of March 2026"`, and within any period with a start and an
+/// end: `"last Friday of the quarter"`, `"2nd business day of Q3"`,
+/// `"10th day of next month"`
+///
+/// **Composition**: offsets apply to any other expression, including each
+/// other (`"3 business days before the last Friday of the quarter"`), and
+/// `"at <time>"` sets the time on any day (`"2 weeks after the end of the month at 3pm"`)
+///
+/// **Business days** (working days from [`ResolveOptions::working_hours`], or from
+/// [`ResolveOptions::business_calendar`] with its holidays and closures skipped):
+/// `"next business day"`, `"end of next business day"`, `"start of previous business day"`,
+/// `"last business day of the month"`, `"start of the first business day of next month"`.
+/// `start of` (and the bare form) resolves to the working-hours start; `end of` to the
+/// working-hours end.
+///
+/// **Passthrough**: Any valid RFC 3339 or ISO 8601 date string
+///
+/// **Lenient mode**: with [`ResolveOptions::lenient`], an expression that fails to
+/// parse is retried with obvious typos corrected (`"tommorow"`, `"wensday"`); the
+/// corrections are listed in [`ResolvedDatetime::corrections`].
+///
+/// **DST caveats**: a result within 24 hours of a DST transition in `timezone`
+/// carries [`ResolvedDatetime::warnings`]. Local times that occur twice resolve to
+/// the earlier instant, and times skipped by spring-forward move past the gap; both
+/// are flagged.
+///
+/// **Explanations**: with [`ResolveOptions::explain`], [`ResolvedDatetime::trace`] lists
+/// the normalized expression, typo corrections, an inline timezone, and each parser that
+/// matched (`"parser:business_day"`), inner expressions first.
+///
+/// **Custom vocabulary**: parsers registered in [`ResolveOptions::parsers`] run
+/// before all of the above ("sprint 42", "payday").
+///
+/// **Inline timezones**: `"3pm Tokyo time"`, `"9am ET tomorrow"`, `"next Monday 14:00 CET"`,
+/// and UTC offsets on the time: `"tomorrow 3pm UTC"`, `"next Monday 14:00 +02:00"`,
+/// `"14:00-0500"`, `"9am GMT+1"`. The expression is interpreted in the mentioned zone or
+/// offset and reported in `timezone`; the mentioned zone, or the offset as `±HH:MM`, is
+/// echoed in [`ResolvedDatetime::expression_timezone`]. Offsets need not be whole hours
+/// (`"14:00 +05:30"`, `"9am +05:45"`).
+/// Day and time may appear in either order (`"9am tomorrow"`, `"next Monday 14:00"`).
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidExpression`] if the expression cannot be parsed
+/// deterministically. This function **never guesses** — it returns an error for
+/// any ambiguous input.
+pub fn resolve_relative_with_options(
+    anchor: DateTime<Utc>,
+    expression: &str,
+    timezone: &str,
+    options: &ResolveOptions,
+) -> Result<ResolvedDatetime, TruthError> {
+    let tz = parse_timezone(timezone)?;
+    resolve_in_timezone(anchor, expression, &tz, options)
+}
+
+/// Resolve many expressions against one anchor and timezone in a single call.
+///
+/// The timezone is parsed once; each expression then succeeds or fails on its
+/// own, so one unparseable expression does not discard the others.
+///
+/// # Arguments
+///
+/// * `anchor` — The reference "now" instant shared by every expression
+/// * `expressions` — Time expressions (see [`resolve_relative_with_options`] for grammar)
+/// * `timezone` — An IANA timezone name for interpreting local-time expressions
+/// * `options` — Resolution options applied to every expression
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] (or [`TruthError::AmbiguousTimezone`]) if
+/// the timezone cannot be resolved. Per-expression failures are returned in place.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::temporal::{resolve_relative_batch, ResolveOptions};
+///
+/// let anchor = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+/// let results = resolve_relative_batch(
+///     anchor,
+///     &["tomorrow", "next flurbday", "in 2 hours"],
+///     "UTC",
+///     &ResolveOptions::default(),
+/// )
+/// .unwrap();
+/// assert_eq!(results[0].as_ref().unwrap().resolved_utc, "2026-02-19T00:00:00+00:00");
+/// assert!(results[1].is_err());
+/// assert_eq!(results[2].as_ref().unwrap().resolved_utc, "2026-02-18T16:30:00+00:00");
+/// ```
+pub fn resolve_relative_batch(
+    anchor: DateTime<Utc>,
+    expressions: &[&str],
+    timezone: &str,
+    options: &ResolveOptions,
+) -> Result<Vec<Result<ResolvedDatetime, TruthError>>, TruthError> {
+    let tz = parse_timezone(timezone)?;
+    Ok(expressions
+        .iter()
+        .map(|expression| resolve_in_timezone(anchor, expression, &tz, options))
+        .collect())
+}
+
+/// Resolve one expression in an already-parsed output timezone.
+pub(crate) fn resolve_in_timezone(
+    anchor: DateTime<Utc>,
+    expression: &str,
+    tz: &Tz,
+    options: &ResolveOptions,
+) -> Result<ResolvedDatetime, TruthError> {
+    let resolution = resolve_core(anchor, expression, tz, options)?;
+    let resolved_local = resolution.datetime;
+    let resolved_utc = resolved_local.with_timezone(&Utc);
+    let clock = options
+        .clock
+        .unwrap_or_else(|| options.locale.default_clock());
+    let interpretation = locale::format_interpretation_in(&resolved_local, options.locale, clock);
+
+    Ok(ResolvedDatetime {
+        resolved_utc: resolved_utc.to_rfc3339(),
+        resolved_local: resolved_local.to_rfc3339(),
+        timezone: tz.name().into(),
+        interpretation,
+        expression_timezone: resolution.frame,
+        corrections: resolution.corrections,
+        warnings: dst_warnings(&resolved_local, tz, clock),
+        renderings: None,
+        granularity: resolution.granularity,
+        week: resolution.week,
+        trace: resolution.trace,
+    })
+}
+
+/// Resolve an expression to a datetime in `tz`.
+fn resolve_core(
+    anchor: DateTime<Utc>,
+    expression: &str,
+    tz: &Tz,
+    options: &ResolveOptions,
+) -> Result<Resolution, TruthError> {
+    // Normalize: trim, lowercase, strip articles
+    let normalized = normalize_expression(expression);
+    let trace = Tracer::new(options.explain);
+    if normalized != expression.trim() {
+        trace.record(
+            "normalize",
+            format!("'{}' → '{normalized}'", expression.trim()),
+        );
+    }
+
+    let mark = trace.mark();
+    let mut resolved = resolve_in_frame(&normalized, anchor, tz, options, &trace)?;
+    if resolved.is_none() && options.lenient {
+        trace.rewind(mark);
+        if let Some((corrected, applied)) = typos::correct_typos(&normalized) {
+            for c in &applied {
+                trace.record("typo", format!("'{}' → '{}'", c.original, c.corrected));
+            }
+            resolved =
+                resolve_in_frame(&corrected, anchor, tz, options, &trace)?.map(|r| Resolution {
+                    corrections: applied,
+                    ..r
+                });
+        }
+    }
+    let Some(resolution) = resolved else {
+        let message = format!("cannot parse expression: '{}'", expression.trim());
+        let untraced = Tracer::new(false);
+        return Err(TruthError::InvalidExpression(diagnose::locate(
+            expression,
+            message,
+            |prefix| {
+                resolve_in_frame(
+                    &normalize_expression(prefix),
+                    anchor,
+                    tz,
+                    options,
+                    &untraced,
+                )
+                .is_ok_and(|r| r.is_some())
+            },
+        )));
+    };
+    Ok(Resolution {
+        datetime: resolution.datetime.with_timezone(tz),
+        trace: trace.into_steps(),
+        ..resolution
+    })
+}
+
+/// Typed variant of [`resolve_relative_with_options`]: resolve an expression to a
+/// zoned datetime without building result strings.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidExpression`] if the expression cannot be parsed
+/// deterministically.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Europe::London;
+/// use truth_engine::temporal::{resolve_relative_dt, ResolveOptions};
+///
+/// let anchor = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+/// let dt = resolve_relative_dt(anchor, "tomorrow at 9am", London, &ResolveOptions::default())
+///     .unwrap();
+/// assert_eq!(dt, London.with_ymd_and_hms(2026, 2, 19, 9, 0, 0).unwrap());
+/// ```
+pub fn resolve_relative_dt(
+    anchor: DateTime<Utc>,
+    expression: &str,
+    timezone: Tz,
+    options: &ResolveOptions,
+) -> Result<DateTime<Tz>, TruthError> {
+    resolve_core(anchor, expression, &timezone, options).map(|r| r.datetime)
+}
+
+/// DST caveats for a resolved local datetime.
+fn dst_warnings(dt: &DateTime<Tz>, tz: &Tz, clock: ClockFormat) -> Vec<DstWarning> {
+    let utc = dt.with_timezone(&Utc);
+    let offset_at = |t: DateTime<Utc>| t.with_timezone(tz).offset().fix().local_minus_utc();
+
+    // Binary-search the (single) offset change within ±24h, to the second.
+    let mut lo = utc - chrono::Duration::hours(24);
+    let mut hi = utc + chrono::Duration::hours(24);
+    let before = offset_at(lo);
+    let after = offset_at(hi);
+    if before == after {
+        return Vec::new();
+    }
+    while hi - lo > chrono::Duration::seconds(1) {
+        let mid = lo + (hi - lo) / 2;
+        if offset_at(mid) == before {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let transition = hi;
+    let transition_local = transition.with_timezone(tz);
+    let hm = clock.pattern();
+    let offset_before = format_utc_offset(&lo.with_timezone(tz));
+    let offset_after = format_utc_offset(&transition_local);
+    let shift_minutes = (after - before) / 60;
+    let warning = |kind, message| DstWarning {
+        kind,
+        transition_utc: transition.to_rfc3339(),
+        offset_before: offset_before.clone(),
+        offset_after: offset_after.clone(),
+        message,
+    };
+
+    let mut warnings = vec![warning(
+        DstWarningKind::NearTransition,
+        format!(
+            "{} changes clocks at {} ({} → {}), within 24 hours of this time",
+            tz.name(),
+            transition_local.format(&format!("%A, %B %-d at {hm} %Z")),
+            offset_before,
+            offset_after
+        ),
+    )];
+
+    let wall = dt.naive_local();
+    if matches!(
+        tz.from_local_datetime(&wall),
+        chrono::LocalResult::Ambiguous(_, _)
+    ) {
+        warnings.push(warning(
+            DstWarningKind::Ambiguous,
+            format!(
+                "{} occurs twice in {}; the earlier ({}) instance was used",
+                wall.format(hm),
+                tz.name(),
+                offset_before
+            ),
+        ));
+    }
+
+    let gap = chrono::Duration::minutes(shift_minutes as i64);
+    if shift_minutes > 0 && utc >= transition && utc < transition + gap {
+        warnings.push(warning(
+            DstWarningKind::Shifted,
+            format!(
+                "local times between {} and {} do not exist in {}; a time requested in that gap was moved forward",
+                (transition_local.naive_local() - gap).format(hm),
+                transition_local.format(hm),
+                tz.name()
+            ),
+        ));
+    }
+
+    warnings
+}
+
+/// A resolved expression before it is rendered into a [`ResolvedDatetime`].
+struct Resolution {
+    /// The timezone or UTC offset named inside the expression, if any, as
+    /// reported in [`ResolvedDatetime::expression_timezone`].
+    frame: Option<String>,
+    datetime: DateTime<Tz>,
+    granularity: ResolutionGranularity,
+    /// Set for week-based expressions, numbered in the frame's calendar.
+    week: Option<WeekNumber>,
+    corrections: Vec<TypoCorrection>,
+    trace: Option<Vec<TraceStep>>,
+}
+
+/// Collects [`TraceStep`]s while parsing when [`ResolveOptions::explain`] is set.
+struct Tracer(Option<RefCell<Vec<TraceStep>>>);
+
+impl Tracer {
+    fn new(enabled: bool) -> Self {
+        Self(enabled.then(|| RefCell::new(Vec::new())))
+    }
+
+    fn record(&self, source: &str, detail: String) {
+        if let Some(steps) = &self.0 {
+            steps.borrow_mut().push(TraceStep::new(source, detail));
+        }
+    }
+
+    fn mark(&self) -> usize {
+        self.0.as_ref().map_or(0, |steps| steps.borrow().len())
+    }
+
+    /// Drop steps recorded since `mark`, left by an attempt that failed.
+    fn rewind(&self, mark: usize) {
+        if let Some(steps) = &self.0 {
+            steps.borrow_mut().truncate(mark);
+        }
+    }
+
+    /// Run one parser, recording it if it matches `s`.
+    fn attempt(
+        &self,
+        parser: &str,
+        s: &str,
+        parse: impl FnOnce() -> Option<(DateTime<Tz>, ResolutionGranularity)>,
+    ) -> Option<(DateTime<Tz>, ResolutionGranularity)> {
+        let mark = self.mark();
+        let result = parse();
+        match &result {
+            Some((dt, _)) => self.record(
+                &format!("parser:{parser}"),
+                format!("'{s}' → {}", dt.to_rfc3339()),
+            ),
+            None => self.rewind(mark),
+        }
+        result
+    }
+
+    fn into_steps(self) -> Option<Vec<TraceStep>> {
+        self.0.map(RefCell::into_inner)
+    }
+}
+
+/// Resolve a normalized expression, honoring a timezone mentioned inside it.
+///
+/// Returns the result in the mentioned zone's frame (if any), or `None` if no
+/// parser accepts the expression.
+fn resolve_in_frame(
+    normalized: &str,
+    anchor: DateTime<Utc>,
+    tz: &Tz,
+    options: &ResolveOptions,
+    trace: &Tracer,
+) -> Result<Option<Resolution>, TruthError> {
+    // A zone mentioned inside the expression ("3pm Tokyo time") is the frame the
+    // expression is interpreted in; the result is still reported in `timezone`.
+    let (frame, stripped) = match extract_inline_timezone(normalized)? {
+        Some((frame, rest)) => (Some(frame), rest),
+        None => (None, normalized.to_string()),
+    };
+    let scheme = match options.week_start {
+        WeekStartDay::Monday => WeekScheme::Iso,
+        WeekStartDay::Sunday => WeekScheme::Us,
+    };
+    let is_week = is_week_expression(&stripped);
+    if let Some(frame) = &frame {
+        let named = if frame.is_offset() { "offset" } else { "zone" };
+        trace.record(
+            "timezone",
+            format!(
+                "read in {}, the {named} the expression names",
+                frame.label()
+            ),
+        );
+    }
+    let label = frame.as_ref().map(InlineFrame::label);
+    let resolved = match frame {
+        Some(InlineFrame::Offset(offset)) => {
+            resolve_at_offset(&stripped, anchor, offset, options, trace)
+        }
+        Some(InlineFrame::Zone(zone)) => {
+            resolve_expression(&stripped, anchor, &zone, options, true, trace)
+                .map(|(dt, g)| (dt.naive_local(), dt, g))
+        }
+        None => resolve_expression(&stripped, anchor, tz, options, true, trace)
+            .map(|(dt, g)| (dt.naive_local(), dt, g)),
+    };
+    Ok(resolved.map(|(local, datetime, granularity)| Resolution {
+        frame: label,
+        datetime,
+        granularity,
+        week: is_week.then(|| weeks::week_of(local.date(), scheme)),
+        corrections: Vec::new(),
+        trace: None,
+    }))
+}
+
+/// Resolve an expression in the frame of a fixed UTC offset.
+///
+/// A fixed offset's wall clock is UTC's shifted by the offset, with no
+/// transitions, so the parsers run in UTC against a shifted anchor and the
+/// resulting wall time is then read at `offset`. Returns the frame-local
+/// wall time alongside the instant.
+fn resolve_at_offset(
+    s: &str,
+    anchor: DateTime<Utc>,
+    offset: FixedOffset,
+    options: &ResolveOptions,
+    trace: &Tracer,
+) -> Option<(NaiveDateTime, DateTime<Tz>, ResolutionGranularity)> {
+    let shift = chrono::Duration::seconds(offset.local_minus_utc().into());
+    let (dt, granularity) = resolve_expression(s, anchor + shift, &Tz::UTC, options, true, trace)?;
+    let local = dt.naive_local();
+    let instant = offset.from_local_datetime(&local).single()?;
+    Some((local, instant.with_timezone(&Tz::UTC), granularity))
+}
+
+/// Run the parser chain on a normalized expression in the given timezone.
+///
+/// `allow_reorder` permits one rewrite of "9am tomorrow" / "next monday 14:00"
+/// into the "<day> at <time>" form the combined parsers expect.
+fn resolve_expression(
+    s: &str,
+    anchor: DateTime<Utc>,
+    tz: &Tz,
+    options: &ResolveOptions,
+    allow_reorder: bool,
+    trace: &Tracer,
+) -> Option<(DateTime<Tz>, ResolutionGranularity)> {
+    use ResolutionGranularity as G;
+
+    let local_anchor = anchor.with_timezone(tz);
+    let ws = options.week_start;
+    let fy = options
+        .fiscal_year_start
+        .map_or(1, |m| m.number_from_month());
+    let with = |g: G| move |dt: DateTime<Tz>| (dt, g);
+    // Boundaries ("start of q3") name a day; a bare quarter names the whole span.
+    let quarter = if s.starts_with('q') {
+        G::Period
+    } else {
+        G::Date
+    };
+    let day_of_month = if s.contains(" at ") {
+        G::DateTime
+    } else {
+        G::Date
+    };
+    let anchored = if s == "now" { G::DateTime } else { G::Date };
+    // "next friday" alone also matches the combined weekday parser.
+    let weekday = if s.split(' ').count() > 2 {
+        G::DateTime
+    } else {
+        G::Date
+    };
+
+    // Custom parsers first, then each built-in parser in order of specificity.
+    // Custom parsers can't say what they matched, so their results count as moments.
+    let local = &local_anchor;
+    trace
+        .attempt("custom", s, || {
+            options.parsers.parse(s, local).map(with(G::DateTime))
+        })
+        .or_else(|| {
+            trace.attempt("recurring_anchor", s, || {
+                let calendar = &options.working_time(tz);
+                anchors::try_recurring_anchor(s, local, tz, &options.anchors, calendar)
+            })
+        })
+        .or_else(|| {
+            trace.attempt("reference", s, || {
+                try_reference(s, tz, &options.references).map(with(G::DateTime))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("rfc3339", s, || {
+                try_passthrough_rfc3339(s).map(|dt| (dt.with_timezone(tz), G::DateTime))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("iso_date", s, || {
+                try_passthrough_iso_date(s, tz).map(with(G::Date))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("iso_week", s, || {
+                try_iso_week_date(s, tz)
+                    .map(|(dt, has_day)| (dt, if has_day { G::Date } else { G::Period }))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("anchored", s, || {
+                try_anchored(s, local, tz).map(with(anchored))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("weekday_time", s, || {
+                try_combined_weekday_time(s, local, tz).map(with(weekday))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("anchor_time", s, || {
+                try_combined_anchor_time(s, local, tz).map(with(G::DateTime))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("explicit_date_time", s, || {
+                try_explicit_date_time(s, local, tz)
+            })
+        })
+        .or_else(|| {
+            trace.attempt("weekday_relative", s, || {
+                try_weekday_relative(s, local, tz).map(with(G::Date))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("business_day", s, || {
+                try_business_day(s, local, tz, &options.working_time(tz))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("work_week", s, || {
+                workweek::try_work_week(s, local, tz, &options.working_hours)
+            })
+        })
+        .or_else(|| {
+            trace.attempt("quarter", s, || {
+                try_quarter_literal(s, local, tz, fy).map(with(quarter))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("fiscal_period", s, || {
+                let calendar = options.fiscal_calendar.as_ref()?;
+                try_fiscal_period(s, local, tz, calendar)
+            })
+        })
+        .or_else(|| {
+            trace.attempt("term", s, || {
+                let calendar = options.term_calendar.as_ref()?;
+                try_term(s, local, tz, calendar)
+            })
+        })
+        .or_else(|| {
+            trace.attempt("calendar_date", s, || {
+                try_calendar_date(s, local, tz).map(with(G::Date))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("midpoint", s, || {
+                try_midpoint(s, local, tz, ws, &options.midpoints).map(with(G::Date))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("day_of_year", s, || {
+                try_day_of_year(s, local, tz).map(with(G::Date))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("day_of_month", s, || {
+                try_day_of_month(s, local, tz, options.day_of_month).map(with(day_of_month))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("compound_period", s, || {
+                try_compound_period(s, local, tz, ws, fy).map(with(G::Date))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("period_boundary", s, || {
+                try_period_boundary(s, local, tz, ws, fy).map(with(G::Date))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("period_relative", s, || {
+                try_period_relative(s, local, tz, ws).map(with(G::Period))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("ordinal_date", s, || {
+                try_ordinal_date(s, local, tz).map(with(G::Date))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("ordinal_of_period", s, || {
+                compose::try_ordinal_of_period(s, anchor, tz, options, allow_reorder, trace)
+                    .map(with(G::Date))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("business_offset", s, || {
+                try_business_offset(s, anchor, tz, options).map(with(G::DateTime))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("natural_offset", s, || {
+                try_natural_offset(s, &anchor).map(with(G::DateTime))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("duration_offset", s, || {
+                try_duration_offset(s, anchor, tz, options).map(with(G::DateTime))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("time_of_day", s, || {
+                try_time_of_day_named(s, local, tz).map(with(G::DateTime))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("explicit_time", s, || {
+                try_explicit_time(s, local, tz).map(with(G::DateTime))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("solar", s, || {
+                solar::try_solar(s, anchor, tz, options, allow_reorder, trace)
+                    .map(with(G::DateTime))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("relative_to", s, || {
+                try_relative_to(s, anchor, tz, options, allow_reorder, trace)
+            })
+        })
+        .or_else(|| {
+            trace.attempt("day_at_time", s, || {
+                compose::try_day_at_time(s, anchor, tz, options, allow_reorder, trace)
+                    .map(with(G::DateTime))
+            })
+        })
+        .or_else(|| {
+            trace.attempt("bare_hour", s, || {
+                try_bare_hour(s, anchor, tz, options, allow_reorder).map(with(G::DateTime))
+            })
+        })
+        .or_else(|| {
+            if !allow_reorder {
+                return None;
+            }
+            let reordered = reorder_day_and_time(s)?;
+            trace.attempt("reorder", s, || {
+                resolve_expression(&reordered, anchor, tz, options, false, trace)
+            })
+        })
+}
+
+/// Try a caller-supplied reference instant ("meeting start").
+fn try_reference(
+    s: &str,
+    tz: &Tz,
+    references: &BTreeMap<String, DateTime<Utc>>,
+) -> Option<DateTime<Tz>> {
+    references
+        .iter()
+        .find(|(name, _)| normalize_expression(name) == s)
+        .map(|(_, instant)| instant.with_timezone(tz))
+}
+
+/// Try an offset from another expression: "3 days after march 10", "a week
+/// before end of quarter", "two hours after meeting start", "2 business days
+/// before next payday". The referenced expression goes through the full chain.
+///
+/// Whole days, weeks, months, and years move the calendar date and keep the
+/// wall-clock time; smaller units are exact elapsed time.
+fn try_relative_to(
+    s: &str,
+    anchor: DateTime<Utc>,
+    tz: &Tz,
+    options: &ResolveOptions,
+    allow_reorder: bool,
+    trace: &Tracer,
+) -> Option<(DateTime<Tz>, ResolutionGranularity)> {
+    let (quantity, sign, rest) = match (s.find(" after "), s.find(" before ")) {
+        (Some(a), Some(b)) if b < a => (&s[..b], -1, &s[b + 8..]),
+        (Some(a), _) => (&s[..a], 1, &s[a + 7..]),
+        (None, Some(b)) => (&s[..b], -1, &s[b + 8..]),
+        (None, None) => return None,
+    };
+    let (base, base_granularity) =
+        resolve_expression(rest, anchor, tz, options, allow_reorder, trace)?;
+    // An offset of whole days from a period lands on a day, not a period.
+    let day_granularity = match base_granularity {
+        ResolutionGranularity::Period => ResolutionGranularity::Date,
+        g => g,
+    };
+    let shift_date =
+        |date: NaiveDate| localize(tz, &date.and_time(base.time())).map(|dt| (dt, day_granularity));
+
+    let tokens: Vec<&str> = quantity.split(' ').collect();
+    match tokens.as_slice() {
+        ["business" | "working", "day"] => {
+            let date = options
+                .working_time(tz)
+                .add_business_days(base.date_naive(), sign)?;
+            return shift_date(date);
+        }
+        [n, "business" | "working", "day" | "days"] => {
+            let n = sign * parse_count(n)?;
+            return shift_date(
+                options
+                    .working_time(tz)
+                    .add_business_days(base.date_naive(), n)?,
+            );
+        }
+        [unit @ ("month" | "year")] | [_, unit @ ("month" | "months" | "year" | "years")] => {
+            let count = match tokens.as_slice() {
+                [n, _] => parse_count(n)?,
+                _ => 1,
+            };
+            let per_unit = if unit.starts_with("year") { 12 } else { 1 };
+            let months = chrono::Months::new(u32::try_from(count * per_unit).ok()?);
+            let date = if sign > 0 {
+                base.date_naive().checked_add_months(months)?
+            } else {
+                base.date_naive().checked_sub_months(months)?
+            };
+            return shift_date(date);
+        }
+        _ => {}
+    }
+
+    if let Some(seconds) = parse_working_quantity(quantity) {
+        let dt = options
+            .working_time(tz)
+            .add_business_time(
+                base.with_timezone(&Utc),
+                chrono::Duration::seconds(sign * seconds),
+            )
+            .ok()??;
+        return Some((dt.with_timezone(tz), ResolutionGranularity::DateTime));
+    }
+
+    let seconds = parse_natural_quantity(quantity)?;
+    if seconds % 86_400 == 0 {
+        let date = base
+            .date_naive()
+            .checked_add_signed(chrono::Duration::days(sign * seconds / 86_400))?;
+        shift_date(date)
+    } else {
+        let dt = base.checked_add_signed(chrono::Duration::seconds(sign * seconds))?;
+        Some((dt, ResolutionGranularity::DateTime))
+    }
+}
+
+/// Resolve "at <hour>" without am/pm ("tomorrow at 7") per [`BareHourPolicy`].
+///
+/// Each reading is resolved through the full parser chain, then one is chosen.
+/// An am/pm suffix ("at 7pm", "at 7 pm") leaves a single reading, which
+/// resolves under every policy. A bare
+/// weekday before it ("friday at 7") means the next such day on which a
+/// reading is still to come, counting today.
+fn try_bare_hour(
+    s: &str,
+    anchor: DateTime<Utc>,
+    tz: &Tz,
+    options: &ResolveOptions,
+    allow_reorder: bool,
+) -> Option<DateTime<Tz>> {
+    let mut tokens: Vec<String> = s.split(' ').map(str::to_string).collect();
+    let i = (1..tokens.len())
+        .find(|&i| tokens[i - 1] == "at" && tokens[i].starts_with(|c: char| c.is_ascii_digit()))?;
+    if matches!(tokens.get(i + 1).map(String::as_str), Some("am" | "pm")) {
+        let suffix = tokens.remove(i + 1);
+        tokens[i].push_str(&suffix);
+    }
+    let readings: Vec<NaiveTime> = if tokens[i].ends_with("am") || tokens[i].ends_with("pm") {
+        vec![parse_time_string(&tokens[i])?]
+    } else {
+        if options.bare_hour == BareHourPolicy::Error {
+            return None;
+        }
+        if tokens[i].len() > 2 || !tokens[i].chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let hour: u32 = tokens[i].parse().ok()?;
+        let hours = match hour {
+            1..=11 => vec![hour, hour + 12],
+            12 => vec![12, 0],
+            13..=23 | 0 => vec![hour],
+            _ => return None,
+        };
+        hours
+            .into_iter()
+            .filter_map(|h| NaiveTime::from_hms_opt(h, 0, 0))
+            .collect()
+    };
+
+    // The day part before "at": as written, or for a bare weekday today
+    // (when it is that day) and then the next one.
+    let day = tokens[..i - 1].join(" ");
+    let days = match parse_weekday(&day) {
+        Some(weekday) => {
+            let mut days = Vec::new();
+            if anchor.with_timezone(tz).weekday() == weekday {
+                days.push("today".to_string());
+            }
+            days.push(format!("next {day}"));
+            days
+        }
+        None => vec![day],
+    };
+    let rest = tokens[i + 1..].join(" ");
+
+    let mut candidates: Vec<(NaiveTime, DateTime<Tz>)> = Vec::new();
+    for day in &days {
+        candidates = readings
+            .iter()
+            .filter_map(|&time| {
+                let time_text = time.format("%H:%M").to_string();
+                // A leading "at" is not part of the bare-time grammar: "at 7" → "19:00".
+                let expr = [
+                    day.as_str(),
+                    if day.is_empty() { "" } else { "at" },
+                    &time_text,
+                    &rest,
+                ]
+                .into_iter()
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+                // Only the chosen reading belongs in a trace, so the candidates
+                // are resolved untraced.
+                let untraced = Tracer::new(false);
+                let (resolved, _) =
+                    resolve_expression(&expr, anchor, tz, options, allow_reorder, &untraced)?;
+                Some((time, resolved))
+            })
+            .collect();
+        if candidates.iter().any(|(_, dt)| *dt >= anchor) {
+            break;
+        }
+    }
+
+    match options.bare_hour {
+        // Only an am/pm reading gets here, and it is the only one.
+        BareHourPolicy::Error => candidates.first().map(|(_, dt)| *dt),
+        BareHourPolicy::PreferFuture => candidates
+            .iter()
+            .filter(|(_, dt)| *dt >= anchor)
+            .min_by_key(|(_, dt)| *dt)
+            .or_else(|| candidates.iter().max_by_key(|(_, dt)| *dt))
+            .map(|(_, dt)| *dt),
+        BareHourPolicy::PreferBusinessHours => {
+            let wh = &options.working_hours;
+            let distance = |t: NaiveTime| {
+                if t >= wh.start && t < wh.end {
+                    0
+                } else {
+                    (t - wh.start)
+                        .num_seconds()
+                        .abs()
+                        .min((t - wh.end).num_seconds().abs())
+                }
+            };
+            candidates
+                .iter()
+                .min_by_key(|(t, _)| (distance(*t), *t))
+                .map(|(_, dt)| *dt)
+        }
+    }
+}
+
+/// Rewrite "<time> <day>" and "<day> <time>" as "<day> at <time>".
+fn reorder_day_and_time(s: &str) -> Option<String> {
+    let is_time = |t: &str| parse_time_string(t).is_some() || named_time_to_naive(t).is_some();
+    let tokens: Vec<&str> = s.split(' ').collect();
+    if tokens.len() < 2 {
+        return None;
+    }
+
+    // Leading time: "9am tomorrow", "2:30 pm next friday"
+    for n in [2, 1] {
+        if tokens.len() > n && is_time(&tokens[..n].join(" ")) {
+            return Some(format!(
+                "{} at {}",
+                tokens[n..].join(" "),
+                tokens[..n].join(" ")
+            ));
+        }
+    }
+
+    // Trailing time without "at": "next monday 14:00"
+    for n in [2, 1] {
+        if tokens.len() > n && is_time(&tokens[tokens.len() - n..].join(" ")) {
+            let day = &tokens[..tokens.len() - n];
+            if day.last() == Some(&"at") {
+                return None;
+            }
+            return Some(format!(
+                "{} at {}",
+                day.join(" "),
+                tokens[tokens.len() - n..].join(" ")
+            ));
+        }
+    }
+
+    None
+}
+
+/// Find a timezone mentioned inside an expression and return it with the
+/// expression minus the mention.
+///
+/// Recognizes "<place> time" at the end ("3pm tokyo time"), a UTC offset
+/// written onto a time ("14:00+02:00"), and a zone name, abbreviation, or
+/// UTC offset that ends the expression or directly follows a time ("next
+/// monday 14:00 cet", "9am et tomorrow", "tomorrow 3pm utc+2").
+fn extract_inline_timezone(s: &str) -> Result<Option<(InlineFrame, String)>, TruthError> {
+    let tokens: Vec<&str> = s.split(' ').collect();
+
+    // An offset written onto the time, as in RFC 3339: "14:00+02:00", "14:00z".
+    for (i, token) in tokens.iter().enumerate() {
+        let (time, offset) = match token.strip_suffix('z') {
+            Some(time) => (time, None),
+            None => match token.rfind(['+', '-']).filter(|&at| at > 0) {
+                Some(at) => {
+                    let (time, offset) = token.split_at(at);
+                    (time, Some(offset))
+                }
+                None => continue,
+            },
+        };
+        if parse_time_string(time).is_none() {
+            continue;
+        }
+        let frame = match offset {
+            Some(offset) => offset_from_token(offset)?,
+            None => Some(InlineFrame::zone(Tz::UTC)),
+        };
+        if let Some(frame) = frame {
+            let mut rest = tokens.clone();
+            rest[i] = time;
+            return Ok(Some((frame, rest.join(" "))));
+        }
+    }
+
+    if tokens.len() < 2 {
+        return Ok(None);
+    }
+
+    // "<place> time"
+    if tokens.last() == Some(&"time") {
+        let n = tokens.len() - 1;
+        for k in (1..=3.min(n - 1)).rev() {
+            let place = tokens[n - k..n].join(" ");
+            let zones = find_timezone(&place);
+            match zones.len() {
+                0 => continue,
+                1 => {
+                    let zone = InlineFrame::zone(parse_timezone(&zones[0])?);
+                    return Ok(Some((zone, tokens[..n - k].join(" "))));
+                }
+                _ => {
+                    return Err(TruthError::InvalidExpression(
+                        format!("ambiguous place '{}': could be {}", place, zones.join(", "))
+                            .into(),
+                    ));
+                }
+            }
+        }
+    }
+
+    for (i, token) in tokens.iter().enumerate() {
+        let is_last = i == tokens.len() - 1;
+        let follows_time = i > 0 && parse_time_string(tokens[i - 1]).is_some();
+        if i == 0 || !(is_last || follows_time) {
+            continue;
+        }
+        let frame = match offset_from_token(token)? {
+            Some(frame) => Some(frame),
+            None => zone_from_token(token)?.map(InlineFrame::zone),
+        };
+        if let Some(zone) = frame {
+            let rest: Vec<&str> = tokens
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, t)| *t)
+                .collect();
+            return Ok(Some((zone, rest.join(" "))));
+        }
+    }
+
+    Ok(None)
+}
+
+/// A timezone or fixed UTC offset named inside an expression.
+enum InlineFrame {
+    Zone(Tz),
+    Offset(FixedOffset),
+}
+
+impl InlineFrame {
+    fn zone(zone: Tz) -> Self {
+        Self::Zone(zone)
+    }
+
+    fn is_offset(&self) -> bool {
+        matches!(self, Self::Offset(_))
+    }
+
+    /// The zone's name, or the offset as `±HH:MM`.
+    fn label(&self) -> String {
+        match self {
+            Self::Zone(zone) => zone.name().to_string(),
+            Self::Offset(offset) => offset.to_string(),
+        }
+    }
+}
+
+/// Interpret a single lowercase token as a UTC offset: `+02:00`, `-0500`,
+/// `+05:30`, `+05`, or `utc`/`gmt` followed by hours (`utc+2`, `gmt-05:00`).
+fn offset_from_token(token: &str) -> Result<Option<InlineFrame>, TruthError> {
+    let (prefixed, rest) = match token
+        .strip_prefix("utc")
+        .or_else(|| token.strip_prefix("gmt"))
+    {
+        Some(rest) => (true, rest),
+        None => (false, token),
+    };
+    let sign = match rest.chars().next() {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => return Ok(None),
+    };
+    let body = &rest[1..];
+    let (hours, minutes) = match body.split_once(':') {
+        Some((h, m)) if m.len() == 2 => (h, m),
+        Some(_) => return Ok(None),
+        None if body.len() == 4 => body.split_at(2),
+        None => (body, "00"),
+    };
+    let digits = |d: &str| !d.is_empty() && d.bytes().all(|b| b.is_ascii_digit());
+    // Without a utc/gmt prefix, a bare "+2" is too easily something else.
+    let hour_digits = if prefixed { 1..=2 } else { 2..=2 };
+    if !digits(hours) || !digits(minutes) || !hour_digits.contains(&hours.len()) {
+        return Ok(None);
+    }
+    let hours: i32 = hours.parse().expect("two digits");
+    let minutes: i32 = minutes.parse().expect("two digits");
+    if minutes > 59 {
+        return Ok(None);
+    }
+    if hours * 60 + minutes > 14 * 60 {
+        return Err(TruthError::InvalidExpression(
+            format!("UTC offset '{token}' is out of range").into(),
+        ));
+    }
+    let offset =
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).expect("offset within ±14:00");
+    Ok(Some(InlineFrame::Offset(offset)))
+}
+
+/// Interpret a single lowercase token as an IANA zone name or abbreviation.
+fn zone_from_token(token: &str) -> Result<Option<Tz>, TruthError> {
+    if token.chars().any(|c| c.is_ascii_digit()) {
+        return Ok(None);
+    }
+    match parse_timezone(token) {
+        Ok(tz) => Ok(Some(tz)),
+        Err(TruthError::InvalidTimezone(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// ── resolve_relative expression parsers ─────────────────────────────────────
+
+/// Try to parse as an RFC 3339 passthrough.
+fn try_passthrough_rfc3339(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+}
+
+/// Try to parse as an ISO 8601 date (YYYY-MM-DD) → start of day in timezone.
+fn try_passthrough_iso_date(s: &str, tz: &Tz) -> Option<DateTime<Tz>> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| {
+            let naive = date.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        })
+}
+
+/// Try anchored references: "now", "today", "tomorrow", "yesterday".
+fn try_anchored(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
+    match s {
+        "now" => Some(*local),
+        "today" => make_local_start_of_day(local, tz),
+        "tomorrow" => {
+            let next = local.date_naive().succ_opt()?;
+            let naive = next.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "yesterday" => {
+            let prev = local.date_naive().pred_opt()?;
+            let naive = prev.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        _ => None,
+    }
+}
+
+/// Try weekday-relative: "next Monday", "this Friday", "last Wednesday".
+fn try_weekday_relative(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
+    let parts: Vec<&str> = s.splitn(2, ' ').collect();
+    if parts.len() != 2 {
+        return None;
+    }
+
+    let modifier = parts[0];
+    let weekday = parse_weekday(parts[1])?;
+    let current = local.weekday();
+
+    let target_date = match modifier {
+        "next" => {
+            // Always future: if today is the same weekday, go to next week
+            let days_ahead =
+                (weekday.num_days_from_monday() as i64 - current.num_days_from_monday() as i64 + 7)
+                    % 7;
+            let days_ahead = if days_ahead == 0 { 7 } else { days_ahead };
+            local.date_naive() + chrono::Duration::days(days_ahead)
+        }
+        "this" => {
+            // Same week: may be past or future
+            let diff =
+                weekday.num_days_from_monday() as i64 - current.num_days_from_monday() as i64;
+            local.date_naive() + chrono::Duration::days(diff)
+        }
+        "last" => {
+            // Always past: if today is the same weekday, go to last week
+            let days_back =
+                (current.num_days_from_monday() as i64 - weekday.num_days_from_monday() as i64 + 7)
+                    % 7;
+            let days_back = if days_back == 0 { 7 } else { days_back };
+            local.date_naive() - chrono::Duration::days(days_back)
+        }
+        _ => return None,
+    };
+
+    let naive = target_date.and_hms_opt(0, 0, 0)?;
+    localize(tz, &naive)
+}
+
+/// Try combined weekday + time: "next Tuesday at 2pm", "next Friday at 10:30am".
+fn try_combined_weekday_time(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
+    // Pattern: "(next|this|last) <weekday> at <time>"
+    // or: "(next|this|last) <weekday> <named_time>"
+    let parts: Vec<&str> = s.splitn(3, ' ').collect();
+    if parts.len() < 2 {
+        return None;
+    }
+
+    let modifier = parts[0];
+    if !matches!(modifier, "next" | "this" | "last") {
+        return None;
+    }
+
+    // Check for weekday in parts[1]
+    let weekday_str = parts[1];
+    let _weekday = parse_weekday(weekday_str)?;
+
+    // Get the base date from weekday-relative
+    let weekday_expr = format!("{} {}", modifier, weekday_str);
+    let base = try_weekday_relative(&weekday_expr, local, tz)?;
+
+    if parts.len() == 2 {
+        return Some(base);
+    }
+
+    let time_part = parts[2];
+
+    // Handle "at <time>" pattern
+    if let Some(at_time) = time_part.strip_prefix("at ") {
+        let time = parse_time_string(at_time)?;
+        let naive = base.date_naive().and_time(time);
+        return localize(tz, &naive);
+    }
+
+    // Handle named time: "morning", "afternoon", etc.
+    if let Some(time) = named_time_to_naive(time_part) {
+        let naive = base.date_naive().and_time(time);
+        return localize(tz, &naive);
+    }
+
+    None
+}
+
+/// Try combined anchor + time: "tomorrow at 2pm", "today at noon", "tomorrow morning".
+fn try_combined_anchor_time(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
+    let parts: Vec<&str> = s.splitn(2, ' ').collect();
+    if parts.len() != 2 {
+        return None;
+    }
+
+    let anchor_str = parts[0];
+    if !matches!(anchor_str, "today" | "tomorrow" | "yesterday") {
+        return None;
+    }
+
+    let base = try_anchored(anchor_str, local, tz)?;
+    let time_part = parts[1];
+
+    // "at <time>" — try named time first (e.g., "at noon"), then explicit time (e.g., "at 2pm")
+    if let Some(at_time) = time_part.strip_prefix("at ") {
+        if let Some(time) = named_time_to_naive(at_time) {
+            let naive = base.date_naive().and_time(time);
+            return localize(tz, &naive);
+        }
+        let time = parse_time_string(at_time)?;
+        let naive = base.date_naive().and_time(time);
+        return localize(tz, &naive);
+    }
+
+    // Named time
+    if let Some(time) = named_time_to_naive(time_part) {
+        let naive = base.date_naive().and_time(time);
+        return localize(tz, &naive);
+    }
+
+    None
+}
+
+/// Try time-of-day named anchors: "morning", "noon", "afternoon", etc.
+fn try_time_of_day_named(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
+    let time = named_time_to_naive(s)?;
+    let naive = local.date_naive().and_time(time);
+    localize(tz, &naive)
+}
+
+/// Try explicit time: "2pm", "2:30pm", "14:00".
+fn try_explicit_time(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
+    let time = parse_time_string(s)?;
+    let naive = local.date_naive().and_time(time);
+    localize(tz, &naive)
+}
+
+/// Try natural offset: "in 2 hours", "30 minutes ago", "a week from now", "in 1.5 hours",
+/// and compact quantities: "in 1.5h", "90m ago". A bare quantity means from
+/// now: "1.5h", "an hour and a half", "2 hours" (but not a lone unit word).
+fn try_natural_offset(s: &str, anchor: &DateTime<Utc>) -> Option<DateTime<Tz>> {
+    let seconds = if let Some(rest) = s.strip_prefix("in ") {
+        // "in N unit(s)", "in 1.5 hours", "in an hour and a half"
+        parse_natural_quantity(rest)?
+    } else if let Some(rest) = s.strip_suffix(" ago") {
+        // "N unit(s) ago"
+        -parse_natural_quantity(rest)?
+    } else if let Some(rest) = s.strip_suffix(" from now") {
+        // "a/an <unit> from now", "2.5 days from now"
+        parse_natural_quantity(rest)?
+    } else if s.contains(' ') {
+        // "an hour and a half", "2 hours"
+        parse_natural_quantity(s)?
+    } else {
+        // "1.5h"
+        parse_compact_quantity(s)?
+    };
+    let result = *anchor + chrono::Duration::seconds(seconds);
+    // Return as UTC (which is a valid Tz via chrono_tz)
+    Some(result.with_timezone(&Tz::UTC))
+}
+
+/// Try working-time offset: "in 4 working hours", "90 business minutes ago",
+/// "2 working hours from now". Time passes only while the business calendar
+/// is open.
+fn try_business_offset(
+    s: &str,
+    anchor: DateTime<Utc>,
+    tz: &Tz,
+    options: &ResolveOptions,
+) -> Option<DateTime<Tz>> {
+    let seconds = if let Some(rest) = s.strip_prefix("in ") {
+        parse_working_quantity(rest)?
+    } else if let Some(rest) = s.strip_suffix(" ago") {
+        -parse_working_quantity(rest)?
+    } else {
+        parse_working_quantity(s.strip_suffix(" from now")?)?
+    };
+    let result = options
+        .working_time(tz)
+        .add_business_time(anchor, chrono::Duration::seconds(seconds))
+        .ok()??;
+    Some(result.with_timezone(tz))
+}
+
+/// Try duration offset: "+2h", "-30m", "+1d2h30m", "+6bh".
+fn try_duration_offset(
+    s: &str,
+    anchor: DateTime<Utc>,
+    tz: &Tz,
+    options: &ResolveOptions,
+) -> Option<DateTime<Tz>> {
+    if !s.starts_with('+') && !s.starts_with('-') {
+        return None;
+    }
+    let parsed = Duration::parse_adjustment(s).ok()?;
+    let total_seconds = parsed.calendar_days() * 86400 + parsed.clock_seconds();
+    let mut result = anchor + chrono::Duration::seconds(total_seconds);
+    if parsed.business_minutes != 0 {
+        result = options
+            .working_time(tz)
+            .add_business_time(result, chrono::Duration::seconds(parsed.business_seconds()))
+            .ok()??;
+    }
+    let utc_tz: Tz = "UTC".parse().ok()?;
+    Some(result.with_timezone(&utc_tz))
+}
+
+/// Try period boundary: "start of week", "end of month", etc.
+fn try_period_boundary(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    ws: WeekStartDay,
+    fy_start: u32,
+) -> Option<DateTime<Tz>> {
+    match s {
+        "start of today" => make_local_start_of_day(local, tz),
+        "end of today" => {
+            let naive = local.date_naive().and_hms_opt(23, 59, 59)?;
+            localize(tz, &naive)
+        }
+        "start of week" => {
+            let days_since_start = days_from_week_start(local.weekday(), ws);
+            let start = local.date_naive() - chrono::Duration::days(days_since_start);
+            let naive = start.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "end of week" => {
+            let days_until_end = 6 - days_from_week_start(local.weekday(), ws);
+            let end = local.date_naive() + chrono::Duration::days(days_until_end);
+            let naive = end.and_hms_opt(23, 59, 59)?;
+            localize(tz, &naive)
+        }
+        "start of month" => {
+            let date = NaiveDate::from_ymd_opt(local.year(), local.month(), 1)?;
+            let naive = date.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "end of month" => {
+            let (y, m) = if local.month() == 12 {
+                (local.year() + 1, 1)
+            } else {
+                (local.year(), local.month() + 1)
+            };
+            let first_next = NaiveDate::from_ymd_opt(y, m, 1)?;
+            let last_day = first_next.pred_opt()?;
+            let naive = last_day.and_hms_opt(23, 59, 59)?;
+            localize(tz, &naive)
+        }
+        "start of year" => {
+            let date = NaiveDate::from_ymd_opt(local.year(), 1, 1)?;
+            let naive = date.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "end of year" => {
+            let date = NaiveDate::from_ymd_opt(local.year(), 12, 31)?;
+            let naive = date.and_hms_opt(23, 59, 59)?;
+            localize(tz, &naive)
+        }
+        "start of quarter" => {
+            let (fy, q) = fiscal_quarter_of(local.date_naive(), fy_start);
+            let naive = quarter_start(fy, q, fy_start)?.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "end of quarter" => {
+            let (fy, q) = fiscal_quarter_of(local.date_naive(), fy_start);
+            let naive = quarter_end(fy, q, fy_start)?.and_hms_opt(23, 59, 59)?;
+            localize(tz, &naive)
+        }
+        _ => None,
+    }
+}
+
+/// Try period relative: "next week", "last month", "next year", "the week after
+/// next", "the month before last", etc.
+fn try_period_relative(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    ws: WeekStartDay,
+) -> Option<DateTime<Tz>> {
+    match s {
+        "next week" => {
+            let days_until_next_start = 7 - days_from_week_start(local.weekday(), ws);
+            let start = local.date_naive() + chrono::Duration::days(days_until_next_start);
+            let naive = start.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "last week" => {
+            let days_since_start = days_from_week_start(local.weekday(), ws);
+            let this_start = local.date_naive() - chrono::Duration::days(days_since_start);
+            let last_start = this_start - chrono::Duration::days(7);
+            let naive = last_start.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "next month" => {
+            let (y, m) = if local.month() == 12 {
+                (local.year() + 1, 1)
+            } else {
+                (local.year(), local.month() + 1)
+            };
+            let date = NaiveDate::from_ymd_opt(y, m, 1)?;
+            let naive = date.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "last month" => {
+            let (y, m) = if local.month() == 1 {
+                (local.year() - 1, 12)
+            } else {
+                (local.year(), local.month() - 1)
+            };
+            let date = NaiveDate::from_ymd_opt(y, m, 1)?;
+            let naive = date.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "next year" => {
+            let date = NaiveDate::from_ymd_opt(local.year() + 1, 1, 1)?;
+            let naive = date.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "last year" => {
+            let date = NaiveDate::from_ymd_opt(local.year() - 1, 1, 1)?;
+            let naive = date.and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        // Doubled relatives: "the week after next", "the month before last"
+        "week after next" | "week before last" => {
+            let days_since_start = days_from_week_start(local.weekday(), ws);
+            let this_start = local.date_naive() - chrono::Duration::days(days_since_start);
+            let weeks = if s == "week after next" { 2 } else { -2 };
+            let naive = (this_start + chrono::Duration::weeks(weeks)).and_hms_opt(0, 0, 0)?;
+            localize(tz, &naive)
+        }
+        "month after next" | "month before last" => {
+            let this_month = NaiveDate::from_ymd_opt(local.year(), local.month(), 1)?;
+            let date = if s == "month after next" {
+                this_month.checked_add_months(chrono::Months::new(2))?
+            } else {
+                this_month.checked_sub_months(chrono::Months::new(2))?
+            };
+            localize(tz, &date.and_hms_opt(0, 0, 0)?)
+        }
+        "year after next" | "year before last" => {
+            let years = if s == "year after next" { 2 } else { -2 };
+            let date = NaiveDate::from_ymd_opt(local.year() + years, 1, 1)?;
+            localize(tz, &date.and_hms_opt(0, 0, 0)?)
+        }
+        _ => None,
+    }
+}
+
+/// Try compound period: "start of last week", "end of next month", etc.
+///
+/// Combines a boundary (start/end) with a period relative (last/next week/month/year/quarter).
+fn try_compound_period(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    ws: WeekStartDay,
+    fy_start: u32,
+) -> Option<DateTime<Tz>> {
+    let (is_start, rest) = if let Some(r) = s.strip_prefix("start of ") {
+        (true, r)
+    } else {
+        (false, s.strip_prefix("end of ")?)
+    };
+
+    match rest {
+        "last week" => {
+            let days_since_start = days_from_week_start(local.weekday(), ws);
+            let this_start = local.date_naive() - chrono::Duration::days(days_since_start);
+            let last_start = this_start - chrono::Duration::days(7);
+            if is_start {
+                let naive = last_start.and_hms_opt(0, 0, 0)?;
+                localize(tz, &naive)
+            } else {
+                let last_end = last_start + chrono::Duration::days(6);
+                let naive = last_end.and_hms_opt(23, 59, 59)?;
+                localize(tz, &naive)
+            }
+        }
+        "next week" => {
+            let days_until_next_start = 7 - days_from_week_start(local.weekday(), ws);
+            let next_start = local.date_naive() + chrono::Duration::days(days_until_next_start);
+            if is_start {
+                let naive = next_start.and_hms_opt(0, 0, 0)?;
+                localize(tz, &naive)
+            } else {
+                let next_end = next_start + chrono::Duration::days(6);
+                let naive = next_end.and_hms_opt(23, 59, 59)?;
+                localize(tz, &naive)
+            }
+        }
+        "last month" => {
+            let (y, m) = if local.month() == 1 {
+                (local.year() - 1, 12)
+            } else {
+                (local.year(), local.month() - 1)
+            };
+            if is_start {
+                let date = NaiveDate::from_ymd_opt(y, m, 1)?;
+                let naive = date.and_hms_opt(0, 0, 0)?;
+                localize(tz, &naive)
+            } else {
+                // Last day of prev month = day before 1st of current month
+                let first_current = NaiveDate::from_ymd_opt(local.year(), local.month(), 1)?;
+                let last_day = first_current.pred_opt()?;
+                let naive = last_day.and_hms_opt(23, 59, 59)?;
+                localize(tz, &naive)
+            }
+        }
+        "next month" => {
+            let (y, m) = if local.month() == 12 {
+                (local.year() + 1, 1)
+            } else {
+                (local.year(), local.month() + 1)
+            };
+            if is_start {
+                let date = NaiveDate::from_ymd_opt(y, m, 1)?;
+                let naive = date.and_hms_opt(0, 0, 0)?;
+                localize(tz, &naive)
+            } else {
+                // Last day of next month
+                let (ny, nm) = if m == 12 { (y + 1, 1) } else { (y, m + 1) };
+                let first_after = NaiveDate::from_ymd_opt(ny, nm, 1)?;
+                let last_day = first_after.pred_opt()?;
+                let naive = last_day.and_hms_opt(23, 59, 59)?;
+                localize(tz, &naive)
+            }
+        }
+        "last year" => {
+            let y = local.year() - 1;
+            if is_start {
+                let date = NaiveDate::from_ymd_opt(y, 1, 1)?;
+                let naive = date.and_hms_opt(0, 0, 0)?;
+                localize(tz, &naive)
+            } else {
+                let date = NaiveDate::from_ymd_opt(y, 12, 31)?;
+                let naive = date.and_hms_opt(23, 59, 59)?;
+                localize(tz, &naive)
+            }
+        }
+        "next year" => {
+            let y = local.year() + 1;
+            if is_start {
+                let date = NaiveDate::from_ymd_opt(y, 1, 1)?;
+                let naive = date.and_hms_opt(0, 0, 0)?;
+                localize(tz, &naive)
+            } else {
+                let date = NaiveDate::from_ymd_opt(y, 12, 31)?;
+                let naive = date.and_hms_opt(23, 59, 59)?;
+                localize(tz, &naive)
+            }
+        }
+        "last quarter" | "next quarter" => {
+            let (fy, q) = fiscal_quarter_of(local.date_naive(), fy_start);
+            let (fy, q) = shift_quarter(fy, q, if rest == "last quarter" { -1 } else { 1 });
+            if is_start {
+                let naive = quarter_start(fy, q, fy_start)?.and_hms_opt(0, 0, 0)?;
+                localize(tz, &naive)
+            } else {
+                let naive = quarter_end(fy, q, fy_start)?.and_hms_opt(23, 59, 59)?;
+                localize(tz, &naive)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Try an explicit quarter: "q3" (this year), "q2 2027", "start of q3 2026", "end of q1".
+///
+/// A bare quarter resolves to its start. Years name fiscal years when a fiscal
+/// start is configured (see [`ResolveOptions::fiscal_year_start`]).
+fn try_quarter_literal(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    fy_start: u32,
+) -> Option<DateTime<Tz>> {
+    let (is_end, rest) = if let Some(r) = s.strip_prefix("start of ") {
+        (false, r)
+    } else if let Some(r) = s.strip_prefix("end of ") {
+        (true, r)
+    } else {
+        (false, s)
+    };
+
+    let mut parts = rest.split(' ');
+    let q: u32 = parts.next()?.strip_prefix('q')?.parse().ok()?;
+    if !(1..=4).contains(&q) {
+        return None;
+    }
+    let fy = match parts.next() {
+        Some(y) => parse_year(y.strip_prefix("fy").unwrap_or(y))?,
+        None => fiscal_quarter_of(local.date_naive(), fy_start).0,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let naive = if is_end {
+        quarter_end(fy, q, fy_start)?.and_hms_opt(23, 59, 59)?
+    } else {
+        quarter_start(fy, q, fy_start)?.and_hms_opt(0, 0, 0)?
+    };
+    localize(tz, &naive)
+}
+
+/// Try a fiscal-period expression: "p7", "p7 fy2027", "end of p7", "this period",
+/// "start of next fiscal period". A bare period names the whole span.
+fn try_fiscal_period(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    calendar: &FiscalCalendar,
+) -> Option<(DateTime<Tz>, ResolutionGranularity)> {
+    let (boundary, rest) = if let Some(r) = s.strip_prefix("start of ") {
+        (Some(false), r)
+    } else if let Some(r) = s.strip_prefix("end of ") {
+        (Some(true), r)
+    } else {
+        (None, s)
+    };
+
+    let today = local.date_naive();
+    let relative = rest
+        .strip_suffix(" fiscal period")
+        .or_else(|| rest.strip_suffix(" period"));
+    let period = match relative {
+        Some("this" | "current") => calendar.period_of(today)?,
+        Some("next") => calendar.shift_period(today, 1)?,
+        Some("last" | "previous") => calendar.shift_period(today, -1)?,
+        Some(_) => return None,
+        None => {
+            let mut parts = rest.split(' ');
+            let number: u32 = parts.next()?.strip_prefix('p')?.parse().ok()?;
+            let year = match parts.next() {
+                Some(y) => parse_year(y.strip_prefix("fy").unwrap_or(y))?,
+                None => calendar.year_of(today)?,
+            };
+            if parts.next().is_some() {
+                return None;
+            }
+            calendar.period(year, number)?
+        }
+    };
+
+    let (naive, granularity) = match boundary {
+        Some(true) => (
+            period.end.and_hms_opt(23, 59, 59)?,
+            ResolutionGranularity::Date,
+        ),
+        Some(false) => (
+            period.start.and_hms_opt(0, 0, 0)?,
+            ResolutionGranularity::Date,
+        ),
+        None => (
+            period.start.and_hms_opt(0, 0, 0)?,
+            ResolutionGranularity::Period,
+        ),
+    };
+    localize(tz, &naive).map(|dt| (dt, granularity))
+}
+
+/// Try an academic-term expression: "spring term", "end of spring term",
+/// "first monday of spring term", "start of reading week". A bare name
+/// names the whole span.
+fn try_term(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    calendar: &TermCalendar,
+) -> Option<(DateTime<Tz>, ResolutionGranularity)> {
+    let school = parse_timezone(&calendar.timezone).ok()?;
+    let today = local.with_timezone(&school).date_naive();
+    let span = |name: &str| {
+        calendar
+            .find_term(name, today)
+            .map(|t| (t.start, t.end))
+            .or_else(|| calendar.find_break(name, today).map(|b| (b.start, b.end)))
+    };
+    let at_midnight = |date: NaiveDate| {
+        localize(&school, &date.and_hms_opt(0, 0, 0)?).map(|dt| dt.with_timezone(tz))
+    };
+
+    if let Some(rest) = s.strip_prefix("start of ") {
+        let (start, _) = span(rest)?;
+        return at_midnight(start).map(|dt| (dt, ResolutionGranularity::Date));
+    }
+    if let Some(rest) = s.strip_prefix("end of ") {
+        let (_, end) = span(rest)?;
+        let naive = end.and_hms_opt(23, 59, 59)?;
+        let dt = localize(&school, &naive)?.with_timezone(tz);
+        return Some((dt, ResolutionGranularity::Date));
+    }
+    let mut words = s.splitn(4, ' ');
+    if let (Some(ordinal), Some(weekday), Some("of"), Some(name)) =
+        (words.next(), words.next(), words.next(), words.next())
+    {
+        if let (Some(ordinal), Some(weekday)) = (parse_ordinal(ordinal), parse_weekday(weekday)) {
+            let (start, end) = span(name)?;
+            let date = crate::terms::nth_weekday_between(start, end, weekday, ordinal)?;
+            return at_midnight(date).map(|dt| (dt, ResolutionGranularity::Date));
+        }
+    }
+    let (start, _) = span(s)?;
+    at_midnight(start).map(|dt| (dt, ResolutionGranularity::Period))
+}
+
+/// Try a Hebrew, Islamic, or Chinese date: "15 nisan 5787", "1 ramadan",
+/// "chinese new year 2027".
+#[cfg(feature = "calendars")]
+fn try_calendar_date(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
+    let date = crate::calendars::resolve_named_date(s, local.date_naive())?;
+    localize(tz, &date.and_hms_opt(0, 0, 0)?)
+}
+
+/// Without the `calendars` feature, no expression names a Hebrew, Islamic,
+/// or Chinese date.
+#[cfg(not(feature = "calendars"))]
+fn try_calendar_date(_: &str, _: &DateTime<Tz>, _: &Tz) -> Option<DateTime<Tz>> {
+    None
+}
+
+/// The fiscal year and quarter (1–4) containing `date`, for a fiscal year that
+/// starts in month `fy_start`. Fiscal years are named by the calendar year they end in.
+fn fiscal_quarter_of(date: NaiveDate, fy_start: u32) -> (i32, u32) {
+    let months_in = (date.month() + 12 - fy_start) % 12;
+    let fy = if fy_start == 1 || date.month() < fy_start {
+        date.year()
+    } else {
+        date.year() + 1
+    };
+    (fy, months_in / 3 + 1)
+}
+
+/// Step a (fiscal year, quarter) pair by `delta` quarters.
+fn shift_quarter(fy: i32, q: u32, delta: i32) -> (i32, u32) {
+    let index = fy * 4 + q as i32 - 1 + delta;
+    (index.div_euclid(4), index.rem_euclid(4) as u32 + 1)
+}
+
+/// First day of quarter `q` of fiscal year `fy`.
+fn quarter_start(fy: i32, q: u32, fy_start: u32) -> Option<NaiveDate> {
+    let base_year = if fy_start == 1 { fy } else { fy - 1 };
+    let months = fy_start - 1 + (q - 1) * 3;
+    NaiveDate::from_ymd_opt(base_year + (months / 12) as i32, months % 12 + 1, 1)
+}
+
+/// Last day of quarter `q` of fiscal year `fy`.
+fn quarter_end(fy: i32, q: u32, fy_start: u32) -> Option<NaiveDate> {
+    let (next_fy, next_q) = shift_quarter(fy, q, 1);
+    quarter_start(next_fy, next_q, fy_start)?.pred_opt()
+}
+
+/// Try a period midpoint: "mid-month", "middle of next month", "mid march",
+/// "mid-week", "middle of last week".
+fn try_midpoint(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    ws: WeekStartDay,
+    convention: &MidpointConvention,
+) -> Option<DateTime<Tz>> {
+    let rest = s
+        .strip_prefix("middle of ")
+        .or_else(|| s.strip_prefix("mid-"))
+        .or_else(|| s.strip_prefix("mid "))?;
+
+    let week_offset = match rest {
+        "week" | "this week" => Some(0),
+        "next week" => Some(1),
+        "last week" => Some(-1),
+        _ => None,
+    };
+    let date = match week_offset {
+        Some(offset) => {
+            let today = local.date_naive();
+            let week_start =
+                today - chrono::Duration::days(days_from_week_start(today.weekday(), ws));
+            week_start
+                + chrono::Duration::days(7 * offset + days_from_week_start(convention.weekday, ws))
+        }
+        None => {
+            let (year, month) = parse_month_spec(rest, local)?;
+            let last_day = NaiveDate::from_ymd_opt(year, month, 1)?
+                .checked_add_months(chrono::Months::new(1))?
+                .pred_opt()?;
+            let day = convention.day_of_month.clamp(1, last_day.day());
+            NaiveDate::from_ymd_opt(year, month, day)?
+        }
+    };
+    localize(tz, &date.and_time(convention.time))
+}
+
+/// Try a day-of-month ordinal: "the 15th", "the 3rd of next month",
+/// "21st of this month at 2pm".
+///
+/// Bare ordinals pick a month by `policy`; months too short for the day are skipped.
+fn try_day_of_month(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    policy: DayOfMonthPolicy,
+) -> Option<DateTime<Tz>> {
+    let (date_part, time) = match s.split_once(" at ") {
+        Some((d, t)) => (d, parse_any_time(t)?),
+        None => (s, NaiveTime::MIN),
+    };
+    let (day_str, month_spec) = match date_part.split_once(" of ") {
+        Some((d, m)) => (d, Some(m)),
+        None => (date_part, None),
+    };
+    // Bare ordinals need a suffix: "15" alone could be an hour.
+    if !day_str.ends_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let day = parse_day_of_month(day_str)?;
+
+    let date = match month_spec {
+        Some(spec) => {
+            let (year, month) = parse_month_spec(spec, local)?;
+            NaiveDate::from_ymd_opt(year, month, day)?
+        }
+        None => {
+            let today = local.date_naive();
+            let this_month = NaiveDate::from_ymd_opt(today.year(), today.month(), 1)?;
+            // The first month (from this one) that contains `day` on or after today.
+            let next = (0..=12).find_map(|i| {
+                this_month
+                    .checked_add_months(chrono::Months::new(i))?
+                    .with_day(day)
+                    .filter(|d| *d >= today)
+            })?;
+            match policy {
+                DayOfMonthPolicy::NextFuture => next,
+                DayOfMonthPolicy::Nearest => {
+                    let previous = (0..=12).find_map(|i| {
+                        this_month
+                            .checked_sub_months(chrono::Months::new(i))?
+                            .with_day(day)
+                            .filter(|d| *d < today)
+                    });
+                    match previous {
+                        Some(prev) if today - prev < next - today => prev,
+                        _ => next,
+                    }
+                }
+            }
+        }
+    };
+    localize(tz, &date.and_time(time))
+}
+
+/// Try an explicit calendar date, alone or combined with a time:
+/// "march 3rd", "march 10 at 2pm", "2026-04-01 eob", "morning of march 3rd",
+/// "2pm on 10 march 2026".
+fn try_explicit_date_time(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+) -> Option<(DateTime<Tz>, ResolutionGranularity)> {
+    let tokens: Vec<&str> = s.split(' ').collect();
+    if let Some(date) = parse_explicit_date(&tokens, local) {
+        let dt = localize(tz, &date.and_hms_opt(0, 0, 0)?)?;
+        return Some((dt, ResolutionGranularity::Date));
+    }
+    let timed = |date: NaiveDate, time| {
+        localize(tz, &date.and_time(time)).map(|dt| (dt, ResolutionGranularity::DateTime))
+    };
+
+    for i in 1..tokens.len() {
+        let (left, right) = tokens.split_at(i);
+        // "<date> [at] <time>"
+        if let Some(date) = parse_explicit_date(left, local) {
+            let time_tokens = match right {
+                ["at", rest @ ..] => rest,
+                _ => right,
+            };
+            if let Some(time) = parse_any_time(&time_tokens.join(" ")) {
+                return timed(date, time);
+            }
+        }
+        // "<time> of|on <date>"
+        if let ["of" | "on", date_tokens @ ..] = right {
+            if let (Some(time), Some(date)) = (
+                parse_any_time(&left.join(" ")),
+                parse_explicit_date(date_tokens, local),
+            ) {
+                return timed(date, time);
+            }
+        }
+    }
+
+    None
+}
+
+/// Parse an explicit date: "2026-04-01", "march 3rd", "march 3 2026",
+/// "3 march", "3rd of march 2026". A missing year means the anchor's year.
+fn parse_explicit_date(tokens: &[&str], local: &DateTime<Tz>) -> Option<NaiveDate> {
+    let tokens: Vec<&str> = tokens.iter().map(|t| t.trim_end_matches(',')).collect();
+    // Month and day in either order: "march 3rd" / "3rd march"
+    let month_day = |a: &str, b: &str| {
+        parse_month(a)
+            .zip(parse_day_of_month(b))
+            .or_else(|| parse_month(b).zip(parse_day_of_month(a)))
+    };
+    let ((month, day), year) = match tokens.as_slice() {
+        [iso] => return NaiveDate::parse_from_str(iso, "%Y-%m-%d").ok(),
+        [a, b] => (month_day(a, b)?, local.year()),
+        [a, b, y] if *b != "of" => (month_day(a, b)?, parse_year(y)?),
+        [d, "of", m] => (month_day(m, d)?, local.year()),
+        [d, "of", m, y] => (month_day(m, d)?, parse_year(y)?),
+        _ => return None,
+    };
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Parse a day of the month with an optional ordinal suffix: "3", "3rd", "21st".
+fn parse_day_of_month(s: &str) -> Option<u32> {
+    let digits = s
+        .strip_suffix("st")
+        .or_else(|| s.strip_suffix("nd"))
+        .or_else(|| s.strip_suffix("rd"))
+        .or_else(|| s.strip_suffix("th"))
+        .unwrap_or(s);
+    if digits.is_empty() || digits.len() > 2 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let day: u32 = digits.parse().ok()?;
+    (1..=31).contains(&day).then_some(day)
+}
+
+/// Parse an explicit ("2pm", "14:00") or named ("noon", "eob") time of day.
+pub(super) fn parse_any_time(s: &str) -> Option<NaiveTime> {
+    named_time_to_naive(s).or_else(|| parse_time_string(s))
+}
+
+/// Try ordinal date: "first Monday of March", "last Friday of the month",
+/// "third Tuesday of March 2026".
+fn try_ordinal_date(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
+    // Pattern: "<ordinal> <weekday> of <month> [year]"
+    // or: "last <weekday> of <month>" / "last day of <month>"
+    let parts: Vec<&str> = s.split_whitespace().collect();
+
+    if parts.len() < 4 || parts.iter().position(|&p| p == "of")? < 2 {
+        return None;
+    }
+
+    let of_idx = parts.iter().position(|&p| p == "of")?;
+    if of_idx < 2 {
+        return None;
+    }
+
+    let ordinal_str = parts[0];
+    let target_str = parts[1];
+
+    // Parse "last day of <month>"
+    if ordinal_str == "last" && target_str == "day" {
+        let month_str = parts.get(of_idx + 1)?;
+        let month = parse_month(month_str)?;
+        let year = if let Some(y_str) = parts.get(of_idx + 2) {
+            y_str.parse::<i32>().ok()?
+        } else {
+            local.year()
+        };
+        let (ny, nm) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        let first_next = NaiveDate::from_ymd_opt(ny, nm, 1)?;
+        let last_day = first_next.pred_opt()?;
+        let naive = last_day.and_hms_opt(0, 0, 0)?;
+        return localize(tz, &naive);
+    }
+
+    let weekday = parse_weekday(target_str)?;
+
+    let month_part = parts.get(of_idx + 1)?;
+    // "the month" → current month, otherwise parse month name
+    let (month, year) = if *month_part == "month" {
+        (local.month(), local.year())
+    } else if let Some(month_num) = parse_month(month_part) {
+        let year = if let Some(y_str) = parts.get(of_idx + 2) {
+            y_str.parse::<i32>().unwrap_or(local.year())
+        } else {
+            local.year()
+        };
+        (month_num, year)
+    } else if *month_part == "next" && parts.get(of_idx + 2) == Some(&"month") {
+        let (y, m) = if local.month() == 12 {
+            (local.year() + 1, 1)
+        } else {
+            (local.year(), local.month() + 1)
+        };
+        (m, y)
+    } else {
+        return None;
+    };
+
+    let ordinal = parse_ordinal(ordinal_str)?;
+
+    let date = find_nth_weekday_in_month(year, month, weekday, ordinal)?;
+    let naive = date.and_hms_opt(0, 0, 0)?;
+    localize(tz, &naive)
+}
+
+/// Try business-day expressions: "next business day", "end of next business day",
+/// "last business day of the month", "start of first business day of March".
+///
+/// A bare business day is a [`Date`](ResolutionGranularity::Date); its start
+/// or end is the working-hours boundary, a
+/// [`DateTime`](ResolutionGranularity::DateTime).
+fn try_business_day(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    calendar: &BusinessCalendar,
+) -> Option<(DateTime<Tz>, ResolutionGranularity)> {
+    let (is_end, rest, granularity) = if let Some(r) = s.strip_prefix("start of ") {
+        (false, r, ResolutionGranularity::DateTime)
+    } else if let Some(r) = s.strip_prefix("end of ") {
+        (true, r, ResolutionGranularity::DateTime)
+    } else {
+        (false, s, ResolutionGranularity::Date)
+    };
+
+    let today = local.date_naive();
+    let date = match rest {
+        "next business day" | "next working day" => calendar.add_business_days(today, 1)?,
+        "previous business day"
+        | "previous working day"
+        | "last business day"
+        | "last working day" => calendar.add_business_days(today, -1)?,
+        _ => {
+            let (ordinal_str, after) = rest.split_once(' ')?;
+            let spec = after
+                .strip_prefix("business day of ")
+                .or_else(|| after.strip_prefix("working day of "))?;
+            let ordinal = parse_ordinal(ordinal_str)?;
+            let (year, month) = parse_month_spec(spec, local)?;
+            nth_business_day_of_month(year, month, ordinal, calendar)?
+        }
+    };
+
+    let wh = &calendar.working_hours;
+    let time = if is_end { wh.end } else { wh.start };
+    Some((localize(tz, &date.and_time(time))?, granularity))
+}
+
+/// Parse a month reference: "month", "this month", "next month", "last month",
+/// or a month name with an optional year ("march", "march 2027").
+fn parse_month_spec(s: &str, local: &DateTime<Tz>) -> Option<(i32, u32)> {
+    let (y, m) = (local.year(), local.month());
+    match s {
+        "month" | "this month" => Some((y, m)),
+        "next month" => Some(if m == 12 { (y + 1, 1) } else { (y, m + 1) }),
+        "last month" => Some(if m == 1 { (y - 1, 12) } else { (y, m - 1) }),
+        _ => {
+            let mut parts = s.split_whitespace();
+            let month = parse_month(parts.next()?)?;
+            let year = match parts.next() {
+                Some(y_str) => y_str.parse::<i32>().ok()?,
+                None => y,
+            };
+            if parts.next().is_some() {
+                return None;
+            }
+            Some((year, month))
+        }
+    }
+}
+
+/// Find the Nth business day of a month (ordinal < 0 counts from the end).
+pub(super) fn nth_business_day_of_month(
+    year: i32,
+    month: u32,
+    ordinal: i32,
+    calendar: &BusinessCalendar,
+) -> Option<NaiveDate> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let days: Vec<NaiveDate> = first
+        .iter_days()
+        .take_while(|d| d.month() == month)
+        .filter(|d| calendar.is_business_day(*d))
+        .collect();
+    if ordinal > 0 {
+        days.get(ordinal as usize - 1).copied()
+    } else {
+        days.len()
+            .checked_sub(ordinal.unsigned_abs() as usize)
+            .map(|i| days[i])
+    }
+}
+
+/// Find the Nth weekday in a month. ordinal < 0 means "last" (-1), "second to last" (-2), etc.
+fn find_nth_weekday_in_month(
+    year: i32,
+    month: u32,
+    weekday: Weekday,
+    ordinal: i32,
+) -> Option<NaiveDate> {
+    if ordinal > 0 {
+        // Forward from the first of the month
+        let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let first_wd = first.weekday();
+        let diff = (weekday.num_days_from_monday() as i32 - first_wd.num_days_from_monday() as i32
+            + 7)
+            % 7;
+        let first_occurrence = first + chrono::Duration::days(diff as i64);
+        let target = first_occurrence + chrono::Duration::weeks((ordinal - 1) as i64);
+        // Verify still in the same month
+        if target.month() == month {
+            Some(target)
+        } else {
+            None
+        }
+    } else {
+        // Backward from the last of the month (ordinal = -1 means "last")
+        let (ny, nm) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        let first_next = NaiveDate::from_ymd_opt(ny, nm, 1)?;
+        let last = first_next.pred_opt()?;
+        let last_wd = last.weekday();
+        let diff =
+            (last_wd.num_days_from_monday() as i32 - weekday.num_days_from_monday() as i32 + 7) % 7;
+        let last_occurrence = last - chrono::Duration::days(diff as i64);
+        let target = last_occurrence - chrono::Duration::weeks((-ordinal - 1) as i64);
+        // Verify still in the same month
+        if target.month() == month {
+            Some(target)
+        } else {
+            None
+        }
+    }
+}
+
+/// Try a day-of-year expression: "day 200", "day 200 of 2026", "day 45 of next year".
+pub(super) fn try_day_of_year(s: &str, local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
+    let rest = s.strip_prefix("day ")?;
+    let (day, year) = match rest.split_once(" of ") {
+        Some((day, year)) => (day, Some(year)),
+        None => (rest, None),
+    };
+    let day: u32 = day.parse().ok()?;
+    let year = match year {
+        None | Some("this year") | Some("year") => local.year(),
+        Some("next year") => local.year() + 1,
+        Some("last year") => local.year() - 1,
+        Some(y) => parse_year(y)?,
+    };
+    localize(
+        tz,
+        &NaiveDate::from_yo_opt(year, day)?.and_hms_opt(0, 0, 0)?,
+    )
+}
+
+/// Try an ISO week-date passthrough. Returns the date and whether a weekday was
+/// given (a bare week names the whole week).
+pub(super) fn try_iso_week_date(s: &str, tz: &Tz) -> Option<(DateTime<Tz>, bool)> {
+    let (date, has_day) = parse_week_date(s)?;
+    Some((localize(tz, &date.and_hms_opt(0, 0, 0)?)?, has_day))
+}
+
+/// Whether an expression names a week ("next week", "mid-week", "2026-W08")
+/// rather than a day that happens to fall in one.
+pub(super) fn is_week_expression(s: &str) -> bool {
+    parse_week_date(s).is_some()
+        || s.split(' ')
+            .any(|w| matches!(w, "week" | "mid-week" | "midweek"))
+}
+
+/// Create a DateTime at the start of the day (00:00) in the given timezone.
+/// Place a local wall-clock time in a timezone: the earlier instant when it is
+/// ambiguous, and shifted forward past the gap when it does not exist.
+/// [`dst_warnings`] reports both cases on the resolved result.
+fn localize(tz: &Tz, naive: &chrono::NaiveDateTime) -> Option<DateTime<Tz>> {
+    local_to_utc(tz, *naive).map(|dt| dt.with_timezone(tz))
+}
+
+/// Parse a four-digit year.
+fn parse_year(s: &str) -> Option<i32> {
+    (s.len() == 4 && s.chars().all(|c| c.is_ascii_digit()))
+        .then(|| s.parse().ok())
+        .flatten()
+}
+
+/// How many days `weekday` is from the week-start day.
+fn days_from_week_start(weekday: Weekday, week_start: WeekStartDay) -> i64 {
+    match week_start {
+        WeekStartDay::Monday => weekday.num_days_from_monday() as i64,
+        WeekStartDay::Sunday => weekday.num_days_from_sunday() as i64,
+    }
+}
+
+// ── Parsing helpers ─────────────────────────────────────────────────────────
+
+/// Parse a month name to number (1-12).
+pub(super) fn parse_month(s: &str) -> Option<u32> {
+    match s {
+        "january" | "jan" => Some(1),
+        "february" | "feb" => Some(2),
+        "march" | "mar" => Some(3),
+        "april" | "apr" => Some(4),
+        "may" => Some(5),
+        "june" | "jun" => Some(6),
+        "july" | "jul" => Some(7),
+        "august" | "aug" => Some(8),
+        "september" | "sep" | "sept" => Some(9),
+        "october" | "oct" => Some(10),
+        "november" | "nov" => Some(11),
+        "december" | "dec" => Some(12),
+        _ => None,
+    }
+}
+
+/// Parse an ordinal: "first"→1, "second"→2, ..., "last"→-1.
+fn parse_ordinal(s: &str) -> Option<i32> {
+    match s {
+        "first" | "1st" => Some(1),
+        "second" | "2nd" => Some(2),
+        "third" | "3rd" => Some(3),
+        "fourth" | "4th" => Some(4),
+        "fifth" | "5th" => Some(5),
+        "last" => Some(-1),
+        _ => None,
+    }
+}
+
+/// Parse a natural-language quantity into seconds: "2 hours", "1.5 hours",
+/// "an hour", "an hour and a half", "2 and a half days", "half an hour".
+fn parse_natural_quantity(s: &str) -> Option<i64> {
+    // Articles inside the expression are already stripped by normalization, so
+    // "an hour and a half" arrives as "an hour and half" or "hour and half".
+    let mut parts: Vec<&str> = s.split_whitespace().collect();
+    if matches!(parts.first(), Some(&"a") | Some(&"an")) {
+        parts.remove(0);
+    }
+    let (amount, unit) = match parts.as_slice() {
+        // "half hour"
+        ["half", unit] => ((0, Fraction::HALF), *unit),
+        // "1.5 hours", "2 hours and half"
+        [n, unit] => (parse_decimal(n)?, *unit),
+        [n, unit, "and", "half"] => (plus_half(parse_decimal(n)?)?, *unit),
+        // "2 and half hours"
+        [n, "and", "half", unit] => (plus_half(parse_decimal(n)?)?, *unit),
+        // "1.5h", "90m"
+        [compact] if compact.starts_with(|c: char| c.is_ascii_digit()) => {
+            return parse_compact_quantity(compact)
+        }
+        // "hour", "hour and half"
+        [unit] => ((1, Fraction::ZERO), *unit),
+        [unit, "and", "half"] => ((1, Fraction::HALF), *unit),
+        _ => return None,
+    };
+    let unit_seconds = unit_to_seconds(1, &normalize_time_unit(unit)?)?;
+    let (whole, fraction) = amount;
+    whole
+        .checked_mul(unit_seconds)?
+        .checked_add(fraction.of(unit_seconds))
+}
+
+/// Parse a compact quantity into seconds: a number, possibly fractional,
+/// directly followed by one of the unit letters `s`, `m`, `h`, `d`, or `w`
+/// ("1.5h", "90m", "2d").
+fn parse_compact_quantity(s: &str) -> Option<i64> {
+    let (number, unit) = s.split_at(s.find(|c: char| c.is_ascii_alphabetic())?);
+    let unit_seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        "w" => 604800,
+        _ => return None,
+    };
+    let (whole, fraction) = parse_decimal(number)?;
+    whole
+        .checked_mul(unit_seconds)?
+        .checked_add(fraction.of(unit_seconds))
+}
+
+/// Parse a working-time quantity into seconds: "4 working hours",
+/// "90 business minutes", "a business hour and a half". Only hours and
+/// minutes count as working time.
+fn parse_working_quantity(s: &str) -> Option<i64> {
+    let tokens: Vec<&str> = s.split_whitespace().collect();
+    let i = tokens
+        .iter()
+        .position(|t| matches!(*t, "business" | "working"))?;
+    let unit = normalize_time_unit(tokens.get(i + 1)?)?;
+    if unit != "hours" && unit != "minutes" {
+        return None;
+    }
+    let rest: Vec<&str> = tokens[..i]
+        .iter()
+        .chain(&tokens[i + 1..])
+        .copied()
+        .collect();
+    parse_natural_quantity(&rest.join(" "))
+}
+
+/// Add "and a half" to a parsed decimal; only whole numbers take it.
+fn plus_half((whole, fraction): (i64, Fraction)) -> Option<(i64, Fraction)> {
+    (fraction.numerator == 0).then_some((whole, Fraction::HALF))
+}
+
+/// Parse a whole count: digits, a number word, or "a"/"an" for one.
+fn parse_count(s: &str) -> Option<i64> {
+    match s {
+        "a" | "an" => Some(1),
+        _ => parse_number_word(s).or_else(|| s.parse().ok().filter(|n: &i64| *n >= 0)),
+    }
+}
+
+/// Normalize a time unit name to a standard form.
+fn normalize_time_unit(s: &str) -> Option<String> {
+    match s {
+        "second" | "seconds" | "sec" | "secs" => Some("seconds".to_string()),
+        "minute" | "minutes" | "min" | "mins" => Some("minutes".to_string()),
+        "hour" | "hours" | "hr" | "hrs" => Some("hours".to_string()),
+        "day" | "days" => Some("days".to_string()),
+        "week" | "weeks" | "wk" | "wks" => Some("weeks".to_string()),
+        _ => None,
+    }
+}
+
+/// Convert a number and unit to total seconds.
+fn unit_to_seconds(n: i64, unit: &str) -> Option<i64> {
+    let multiplier = match unit {
+        "seconds" => 1,
+        "minutes" => 60,
+        "hours" => 3600,
+        "days" => 86400,
+        "weeks" => 604800,
+        _ => return None,
+    };
+    Some(n * multiplier)
+}
+
+fn make_local_start_of_day(local: &DateTime<Tz>, tz: &Tz) -> Option<DateTime<Tz>> {
+    let naive = local.date_naive().and_hms_opt(0, 0, 0)?;
+    localize(tz, &naive)
+}
@@ -0,0 +1,115 @@
+//! Resolving named recurring anchors ("next payday", "last sprint boundary")
+//! registered in [`ResolveOptions::anchors`](crate::temporal::ResolveOptions::anchors).
+
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, NaiveTime};
+use chrono_tz::Tz;
+
+use super::localize;
+use super::{nth_business_day_of_month, parse_any_time};
+use crate::business::BusinessCalendar;
+use crate::temporal::{AnchorRule, RecurringAnchor, ResolutionGranularity};
+
+impl AnchorRule {
+    /// The first occurrence on or after `date`.
+    fn on_or_after(&self, date: NaiveDate, calendar: &BusinessCalendar) -> Option<NaiveDate> {
+        match *self {
+            Self::EveryNWeeks { start, weeks } => {
+                let period = i64::from(weeks.max(1)) * 7;
+                let offset = (date - start).num_days();
+                let steps = offset.div_euclid(period) + i64::from(offset.rem_euclid(period) > 0);
+                Some(start + chrono::Duration::days(steps * period))
+            }
+            _ => {
+                let month = date.with_day(1)?;
+                (0..=12).find_map(|i| {
+                    self.in_month(month.checked_add_months(Months::new(i))?, calendar)
+                        .filter(|d| *d >= date)
+                })
+            }
+        }
+    }
+
+    /// The last occurrence strictly before `date`.
+    fn before(&self, date: NaiveDate, calendar: &BusinessCalendar) -> Option<NaiveDate> {
+        match *self {
+            Self::EveryNWeeks { weeks, .. } => {
+                let period = i64::from(weeks.max(1)) * 7;
+                Some(self.on_or_after(date, calendar)? - chrono::Duration::days(period))
+            }
+            _ => {
+                let month = date.with_day(1)?;
+                (0..=12).find_map(|i| {
+                    self.in_month(month.checked_sub_months(Months::new(i))?, calendar)
+                        .filter(|d| *d < date)
+                })
+            }
+        }
+    }
+
+    /// The occurrence in the month starting at `first` (monthly rules only).
+    fn in_month(&self, first: NaiveDate, calendar: &BusinessCalendar) -> Option<NaiveDate> {
+        match *self {
+            Self::NthBusinessDayOfMonth(n) => {
+                nth_business_day_of_month(first.year(), first.month(), n, calendar)
+            }
+            Self::DayOfMonth(day) => {
+                let last = first.checked_add_months(Months::new(1))?.pred_opt()?;
+                first.with_day(day.clamp(1, last.day()))
+            }
+            Self::EveryNWeeks { .. } => None,
+        }
+    }
+}
+
+/// Try a named-anchor expression: "[next|last|this] <name> [at <time>]".
+/// Offsets ("two days before next payday") compose via the general
+/// "<quantity> before|after <expression>" form.
+pub(super) fn try_recurring_anchor(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    anchors: &[RecurringAnchor],
+    calendar: &BusinessCalendar,
+) -> Option<(DateTime<Tz>, ResolutionGranularity)> {
+    if anchors.is_empty() {
+        return None;
+    }
+    let (phrase, time) = match s.split_once(" at ") {
+        Some((phrase, t)) => (phrase, Some(parse_any_time(t)?)),
+        None => (s, None),
+    };
+
+    // Which occurrence: after today, before today, or on or after today.
+    let (name, direction) = if let Some(name) = phrase.strip_prefix("next ") {
+        (name, Ordering::Greater)
+    } else if let Some(name) = phrase
+        .strip_prefix("last ")
+        .or_else(|| phrase.strip_prefix("previous "))
+    {
+        (name, Ordering::Less)
+    } else {
+        (
+            phrase.strip_prefix("this ").unwrap_or(phrase),
+            Ordering::Equal,
+        )
+    };
+    let rule = &anchors
+        .iter()
+        .find(|a| a.name.trim().eq_ignore_ascii_case(name))?
+        .rule;
+    let today = local.date_naive();
+    let date = match direction {
+        Ordering::Greater => rule.on_or_after(today.succ_opt()?, calendar)?,
+        Ordering::Less => rule.before(today, calendar)?,
+        Ordering::Equal => rule.on_or_after(today, calendar)?,
+    };
+
+    let granularity = match time {
+        Some(_) => ResolutionGranularity::DateTime,
+        None => ResolutionGranularity::Date,
+    };
+    let dt = localize(tz, &date.and_time(time.unwrap_or(NaiveTime::MIN)))?;
+    Some((dt, granularity))
+}
@@ -0,0 +1,128 @@
+//! Composing expressions out of smaller ones: an ordinal day inside any period
+//! ("the last Friday of the quarter", "the 2nd business day of next month")
+//! and a time on any day ("3 days after the end of the month at 3pm").
+//!
+//! Each part is resolved through the full chain, so the pieces nest with
+//! offsets ("3 business days before the last Friday of the quarter") and with
+//! each other.
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+use super::{
+    localize, parse_any_time, parse_day_of_month, parse_month_spec, parse_ordinal,
+    resolve_expression, Tracer,
+};
+use crate::temporal::{parse_weekday, ResolutionGranularity, ResolveOptions};
+
+/// What an ordinal counts inside a period.
+enum Item {
+    Day,
+    BusinessDay,
+    Weekday(chrono::Weekday),
+}
+
+/// Try an ordinal day of a period: "last friday of the quarter", "first
+/// monday of next year", "3rd business day of q3", "10th day of next month".
+///
+/// The period is read as its start and end ("start of next quarter", "end of
+/// next quarter"), both resolved through the full chain, or as a month
+/// ("march 2027"). Business days begin at the start of working hours; other
+/// days at midnight.
+pub(super) fn try_ordinal_of_period(
+    s: &str,
+    anchor: DateTime<Utc>,
+    tz: &Tz,
+    options: &ResolveOptions,
+    allow_reorder: bool,
+    trace: &Tracer,
+) -> Option<DateTime<Tz>> {
+    let (head, period) = s.split_once(" of ")?;
+    // "... of the quarter at noon" is a time on the day; see `try_day_at_time`.
+    if period.contains(" at ") {
+        return None;
+    }
+    let (ordinal, item) = head.split_once(' ')?;
+    let ordinal = parse_ordinal(ordinal)
+        .or_else(|| parse_day_of_month(ordinal).and_then(|n| i32::try_from(n).ok()))?;
+    let item = match item {
+        "day" => Item::Day,
+        "business day" | "working day" => Item::BusinessDay,
+        _ => Item::Weekday(parse_weekday(item)?),
+    };
+    let (first, last) = period_span(period, anchor, tz, options, allow_reorder, trace)?;
+
+    let calendar = options.working_time(tz);
+    let days: Vec<NaiveDate> = first
+        .iter_days()
+        .take_while(|d| *d <= last)
+        .filter(|d| match item {
+            Item::Day => true,
+            Item::BusinessDay => calendar.is_business_day(*d),
+            Item::Weekday(weekday) => d.weekday() == weekday,
+        })
+        .collect();
+    // "last" is -1: counted from the end.
+    let index = if ordinal > 0 {
+        ordinal - 1
+    } else {
+        i32::try_from(days.len()).ok()? + ordinal
+    };
+    let date = *days.get(usize::try_from(index).ok()?)?;
+    let time = match item {
+        Item::BusinessDay => calendar.working_hours.start,
+        _ => chrono::NaiveTime::MIN,
+    };
+    localize(tz, &date.and_time(time))
+}
+
+/// The first and last days of a period expression.
+fn period_span(
+    period: &str,
+    anchor: DateTime<Utc>,
+    tz: &Tz,
+    options: &ResolveOptions,
+    allow_reorder: bool,
+    trace: &Tracer,
+) -> Option<(NaiveDate, NaiveDate)> {
+    // "this quarter" is the quarter.
+    let period = period.strip_prefix("this ").unwrap_or(period);
+    if let Some((year, month)) = parse_month_spec(period, &anchor.with_timezone(tz)) {
+        let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let last = first
+            .checked_add_months(chrono::Months::new(1))?
+            .pred_opt()?;
+        return Some((first, last));
+    }
+    let boundary = |edge: &str| {
+        let expression = format!("{edge} of {period}");
+        resolve_expression(&expression, anchor, tz, options, allow_reorder, trace)
+            .map(|(dt, _)| dt.date_naive())
+    };
+    let (first, last) = (boundary("start")?, boundary("end")?);
+    (first <= last).then_some((first, last))
+}
+
+/// Try a time on a day named by any other expression: "2 weeks after the end
+/// of the month at 3pm", "last friday of the quarter at noon". The day goes
+/// through the full chain and must name a single day.
+pub(super) fn try_day_at_time(
+    s: &str,
+    anchor: DateTime<Utc>,
+    tz: &Tz,
+    options: &ResolveOptions,
+    allow_reorder: bool,
+    trace: &Tracer,
+) -> Option<DateTime<Tz>> {
+    let (day, time) = s.rsplit_once(" at ")?;
+    // A bare hour ("at 7") is left to the bare-hour policy.
+    if time.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let time = parse_any_time(time)?;
+    let (base, granularity) = resolve_expression(day, anchor, tz, options, allow_reorder, trace)?;
+    if granularity != ResolutionGranularity::Date {
+        return None;
+    }
+    localize(tz, &base.date_naive().and_time(time))
+}
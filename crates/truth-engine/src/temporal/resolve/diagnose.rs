@@ -0,0 +1,109 @@
+//! Locating where an unresolvable expression goes wrong.
+//!
+//! When no parser accepts an expression, the error points at one token of it,
+//! so UIs can highlight it and agents can correct just that part:
+//!
+//! 1. A misspelled word with a unique correction ("tomorow").
+//! 2. Otherwise the token after the longest prefix that resolves ("25pm" in
+//!    "tomorrow at 25pm", reported with the connective before it).
+//! 3. Otherwise the first word the grammar does not know.
+//!
+//! Failing that, the error has no span.
+
+use super::typos::{correct_word, is_known_word};
+use crate::error::ParseError;
+use crate::temporal::find_timezone;
+
+/// Words that join phrases, reported together with the token after them.
+const CONNECTIVES: &[&str] = &[
+    "at", "on", "in", "by", "from", "to", "of", "and", "after", "before",
+];
+
+/// Grammar words outside the typo vocabulary, which rule 3 must not report.
+const FUNCTION_WORDS: &[&str] = &[
+    "a", "an", "past", "half", "am", "pm", "every", "until", "weekend", "weekday",
+];
+
+/// A [`ParseError`] for `expression` with `message`, located where possible;
+/// `resolves` reports whether a prefix of the expression resolves.
+pub(super) fn locate(
+    expression: &str,
+    message: String,
+    resolves: impl Fn(&str) -> bool,
+) -> ParseError {
+    let tokens = tokens(expression);
+    let word = |token: &str| {
+        token
+            .trim_matches(|c: char| c.is_ascii_punctuation())
+            .to_lowercase()
+    };
+
+    for &(offset, token) in &tokens {
+        if let Some(fix) = correct_word(&word(token)) {
+            return ParseError::at(
+                format!("{message}; did you mean '{fix}' for '{token}'?"),
+                expression,
+                offset,
+                token,
+            );
+        }
+    }
+
+    for k in (1..tokens.len()).rev() {
+        let (offset, token) = tokens[k];
+        let prefix = expression[..offset].trim_end();
+        if !resolves(prefix) {
+            continue;
+        }
+        let end = match tokens.get(k + 1) {
+            Some(&(next, after)) if CONNECTIVES.contains(&word(token).as_str()) => {
+                next + after.len()
+            }
+            _ => offset + token.len(),
+        };
+        return ParseError::at(
+            format!("{message}; '{prefix}' resolves, but not what follows"),
+            expression,
+            offset,
+            &expression[offset..end],
+        );
+    }
+
+    for &(offset, token) in &tokens {
+        let w = word(token);
+        let known = !w.chars().all(|c| c.is_alphabetic())
+            || is_known_word(&w)
+            || CONNECTIVES.contains(&w.as_str())
+            || FUNCTION_WORDS.contains(&w.as_str())
+            || !find_timezone(&w).is_empty();
+        if !known {
+            return ParseError::at(
+                format!("{message}; unknown word '{token}'"),
+                expression,
+                offset,
+                token,
+            );
+        }
+    }
+    message.into()
+}
+
+/// Whitespace-separated tokens with their byte offsets.
+fn tokens(s: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in s.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(st)) => {
+                tokens.push((st, &s[st..i]));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(st) = start {
+        tokens.push((st, &s[st..]));
+    }
+    tokens
+}
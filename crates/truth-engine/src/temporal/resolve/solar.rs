@@ -0,0 +1,59 @@
+//! Resolving solar anchors ("sunset", "tomorrow at dawn", "dusk on friday")
+//! at [`ResolveOptions::location`](crate::temporal::ResolveOptions::location).
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+use super::{resolve_expression, Tracer};
+use crate::temporal::{ResolutionGranularity, ResolveOptions, SolarCalculator, SolarEvent};
+
+/// Try a solar event alone ("sunset", today's) or with a day before or after
+/// it ("tomorrow at dawn", "sunrise next friday"). The day goes through the
+/// full chain; only its date is used.
+pub(super) fn try_solar(
+    s: &str,
+    anchor: DateTime<Utc>,
+    tz: &Tz,
+    options: &ResolveOptions,
+    allow_reorder: bool,
+    trace: &Tracer,
+) -> Option<DateTime<Tz>> {
+    let location = options.location.as_ref()?;
+    let words: Vec<&str> = s.split(' ').collect();
+    let (event, day) = match words.as_slice() {
+        [first, rest @ ..] if SolarEvent::from_name(first).is_some() => (
+            SolarEvent::from_name(first)?,
+            rest.strip_prefix(&["on"]).unwrap_or(rest),
+        ),
+        [rest @ .., last] if SolarEvent::from_name(last).is_some() => (
+            SolarEvent::from_name(last)?,
+            rest.strip_suffix(&["at"]).unwrap_or(rest),
+        ),
+        _ => return None,
+    };
+    let date = if day.is_empty() {
+        anchor.with_timezone(tz).date_naive()
+    } else {
+        let (base, granularity) =
+            resolve_expression(&day.join(" "), anchor, tz, options, allow_reorder, trace)?;
+        // "Sunset next week" names no day.
+        if granularity == ResolutionGranularity::Period {
+            return None;
+        }
+        base.date_naive()
+    };
+    calculator(options)?
+        .solar_time(event, date, location)
+        .map(|instant| instant.with_timezone(tz))
+}
+
+/// The configured calculator, else the built-in one with the `solar` feature.
+fn calculator(options: &ResolveOptions) -> Option<&dyn SolarCalculator> {
+    match &options.solar_calculator {
+        Some(calculator) => Some(calculator.as_ref()),
+        #[cfg(feature = "solar")]
+        None => Some(&crate::temporal::SunriseEquation),
+        #[cfg(not(feature = "solar"))]
+        None => None,
+    }
+}
@@ -0,0 +1,179 @@
+//! Typo correction for lenient expression resolution.
+//!
+//! Voice transcripts and hurried typing produce "tommorow" and "wensday". In
+//! lenient mode, each unknown word is compared against the grammar's
+//! vocabulary by edit distance (with adjacent transpositions) and replaced
+//! when exactly one vocabulary word is closest. Ties are left alone — we would
+//! rather fail than guess between "june" and "july".
+
+use super::parse_month;
+use crate::temporal::{
+    find_timezone, named_time_to_naive, parse_number_word, parse_time_string, parse_weekday,
+    TypoCorrection,
+};
+
+/// Every full word the built-in grammar understands.
+const VOCABULARY: &[&str] = &[
+    "today",
+    "tomorrow",
+    "yesterday",
+    "now",
+    "next",
+    "last",
+    "this",
+    "previous",
+    "ago",
+    "from",
+    "after",
+    "before",
+    "start",
+    "end",
+    "business",
+    "morning",
+    "afternoon",
+    "evening",
+    "night",
+    "midnight",
+    "noon",
+    "lunch",
+    "dawn",
+    "sunrise",
+    "sunset",
+    "dusk",
+    "second",
+    "seconds",
+    "minute",
+    "minutes",
+    "hour",
+    "hours",
+    "day",
+    "days",
+    "week",
+    "weeks",
+    "month",
+    "months",
+    "quarter",
+    "year",
+    "years",
+    "first",
+    "third",
+    "fourth",
+    "fifth",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "january",
+    "february",
+    "march",
+    "april",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Replace misspelled words in a normalized expression.
+///
+/// Returns `None` when nothing was corrected.
+pub(super) fn correct_typos(s: &str) -> Option<(String, Vec<TypoCorrection>)> {
+    let mut corrections = Vec::new();
+    let words: Vec<String> = s
+        .split(' ')
+        .map(|word| match correct_word(word) {
+            Some(fixed) => {
+                corrections.push(TypoCorrection {
+                    original: word.to_string(),
+                    corrected: fixed.to_string(),
+                });
+                fixed.to_string()
+            }
+            None => word.to_string(),
+        })
+        .collect();
+    if corrections.is_empty() {
+        None
+    } else {
+        Some((words.join(" "), corrections))
+    }
+}
+
+/// The unique closest vocabulary word for an unknown word, if close enough.
+pub(super) fn correct_word(word: &str) -> Option<&'static str> {
+    let len = word.chars().count();
+    if len < 3
+        || !word.chars().all(|c| c.is_ascii_alphabetic())
+        || is_known_word(word)
+        || !find_timezone(word).is_empty()
+    {
+        return None;
+    }
+    let max_distance = if len >= 6 { 2 } else { 1 };
+
+    let mut best: Option<(&'static str, usize)> = None;
+    let mut tied = false;
+    for &candidate in VOCABULARY {
+        let d = edit_distance(word, candidate);
+        if d > max_distance {
+            continue;
+        }
+        match best {
+            Some((_, best_d)) if d > best_d => {}
+            Some((_, best_d)) if d == best_d => tied = true,
+            _ => {
+                best = Some((candidate, d));
+                tied = false;
+            }
+        }
+    }
+    if tied {
+        return None;
+    }
+    best.map(|(candidate, _)| candidate)
+}
+
+/// Whether a word is already meaningful to the grammar (including short forms).
+pub(super) fn is_known_word(word: &str) -> bool {
+    VOCABULARY.contains(&word)
+        || matches!(
+            word,
+            "the" | "and" | "for" | "of" | "on" | "by" | "may" | "time" | "sob" | "eob"
+        )
+        || parse_weekday(word).is_some()
+        || parse_month(word).is_some()
+        || parse_number_word(word).is_some()
+        || parse_time_string(word).is_some()
+        || named_time_to_naive(word).is_some()
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions, and
+/// adjacent transpositions each cost 1.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
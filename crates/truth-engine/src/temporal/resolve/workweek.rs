@@ -0,0 +1,70 @@
+//! Resolving work-week and weekend expressions from the working days in
+//! [`WorkingHours::days`].
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Weekday};
+use chrono_tz::Tz;
+
+use super::localize;
+use crate::temporal::{ResolutionGranularity, WorkingHours};
+
+/// The first day of the work-week cycle containing `date`, shifted by `weeks`.
+fn cycle_start(date: NaiveDate, start: Weekday, weeks: i64) -> NaiveDate {
+    date.week(start).first_day() + chrono::Duration::weeks(weeks)
+}
+
+/// Try work-week and weekend expressions: "end of work week", "start of next
+/// work week", "this weekend", "next weekend", "end of last weekend", "the
+/// weekend after next".
+pub(super) fn try_work_week(
+    s: &str,
+    local: &DateTime<Tz>,
+    tz: &Tz,
+    wh: &WorkingHours,
+) -> Option<(DateTime<Tz>, ResolutionGranularity)> {
+    let (boundary, rest) = if let Some(r) = s.strip_prefix("start of ") {
+        (Some(false), r)
+    } else if let Some(r) = s.strip_prefix("end of ") {
+        (Some(true), r)
+    } else {
+        (None, s)
+    };
+    let (weeks, period) = if let Some(p) = rest.strip_suffix(" after next") {
+        (2, p)
+    } else if let Some(p) = rest.strip_suffix(" before last") {
+        (-2, p)
+    } else {
+        match rest.split_once(' ') {
+            Some(("this", p)) => (0, p),
+            Some(("next", p)) => (1, p),
+            Some(("last" | "previous", p)) => (-1, p),
+            _ => (0, rest),
+        }
+    };
+    let start_day = wh.work_week_start()?;
+    let cycle = cycle_start(local.date_naive(), start_day, weeks);
+    let work_days: Vec<NaiveDate> = (0..7)
+        .map(|i| cycle + chrono::Duration::days(i))
+        .filter(|d| wh.days.contains(&d.weekday()))
+        .collect();
+
+    let (first, last, start_time, end_time) = match period {
+        "work week" | "workweek" | "working week" => {
+            (*work_days.first()?, *work_days.last()?, wh.start, wh.end)
+        }
+        "weekend" => {
+            let last_work = *work_days.last()?;
+            let weekend = wh.weekend();
+            let first = last_work + chrono::Duration::days(1);
+            let last = first + chrono::Duration::days(weekend.len() as i64 - 1);
+            let end_of_day = NaiveTime::from_hms_opt(23, 59, 59)?;
+            (first, last, NaiveTime::MIN, end_of_day)
+        }
+        _ => return None,
+    };
+    let (date, time, granularity) = match boundary {
+        None => (first, NaiveTime::MIN, ResolutionGranularity::Period),
+        Some(false) => (first, start_time, ResolutionGranularity::Date),
+        Some(true) => (last, end_time, ResolutionGranularity::Date),
+    };
+    Some((localize(tz, &date.and_time(time))?, granularity))
+}
@@ -0,0 +1,153 @@
+//! Solar anchors ("sunrise", "an hour before dusk") for
+//! [`resolve_relative_with_options`].
+//!
+//! Field work is scheduled around daylight, which moves every day and with
+//! latitude. Set [`ResolveOptions::location`] and the words "dawn",
+//! "sunrise", "sunset", and "dusk" resolve on their own ("sunset"), on a day
+//! ("tomorrow at dawn", "sunset on friday"), or as the base of an offset
+//! ("30 minutes before sunset"). The times come from
+//! [`ResolveOptions::solar_calculator`], or from `SunriseEquation` with the
+//! `solar` feature.
+//!
+//! [`resolve_relative_with_options`]: super::resolve_relative_with_options
+//! [`ResolveOptions::location`]: super::ResolveOptions::location
+//! [`ResolveOptions::solar_calculator`]: super::ResolveOptions::solar_calculator
+
+use std::fmt;
+
+#[cfg(feature = "solar")]
+use chrono::TimeZone;
+use chrono::{DateTime, NaiveDate, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A point on the Earth, in decimal degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GeoLocation {
+    /// Degrees north of the equator; negative in the southern hemisphere.
+    pub latitude: f64,
+    /// Degrees east of Greenwich; negative in the western hemisphere.
+    pub longitude: f64,
+}
+
+/// A daily solar event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum SolarEvent {
+    /// Start of morning civil twilight: the sun 6° below the horizon.
+    Dawn,
+    /// The sun's upper edge clears the horizon.
+    Sunrise,
+    /// The sun's upper edge drops below the horizon.
+    Sunset,
+    /// End of evening civil twilight: the sun 6° below the horizon.
+    Dusk,
+}
+
+impl SolarEvent {
+    /// The event named by an expression word.
+    #[cfg_attr(not(feature = "resolver"), allow(dead_code))]
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "dawn" => Some(Self::Dawn),
+            "sunrise" => Some(Self::Sunrise),
+            "sunset" => Some(Self::Sunset),
+            "dusk" => Some(Self::Dusk),
+            _ => None,
+        }
+    }
+}
+
+/// Computes the instant of a solar event.
+///
+/// Set one in [`ResolveOptions::solar_calculator`] to use an ephemeris of
+/// your own, or a table of observed times.
+///
+/// [`ResolveOptions::solar_calculator`]: super::ResolveOptions::solar_calculator
+pub trait SolarCalculator: fmt::Debug + Send + Sync {
+    /// When `event` happens at `location` on `date`, the local date of the
+    /// expression; `None` if it does not happen that day, as in polar day
+    /// or night.
+    fn solar_time(
+        &self,
+        event: SolarEvent,
+        date: NaiveDate,
+        location: &GeoLocation,
+    ) -> Option<DateTime<Utc>>;
+}
+
+/// The sunrise equation: solar times from the sun's mean anomaly and
+/// declination, corrected for refraction and the sun's radius at sunrise and
+/// sunset. Within about a minute of almanac times away from the poles.
+///
+/// `date` is read as the day around solar noon at the location, which is the
+/// local date wherever the timezone roughly follows longitude.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use truth_engine::temporal::{GeoLocation, SolarCalculator, SolarEvent, SunriseEquation};
+///
+/// let london = GeoLocation { latitude: 51.5074, longitude: -0.1278 };
+/// let solstice = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+/// let sunrise = SunriseEquation.solar_time(SolarEvent::Sunrise, solstice, &london).unwrap();
+/// // 04:43 BST in almanacs.
+/// let almanac = Utc.with_ymd_and_hms(2026, 6, 21, 3, 43, 0).unwrap();
+/// assert!((sunrise - almanac).num_seconds().abs() <= 90);
+/// ```
+#[cfg(feature = "solar")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SunriseEquation;
+
+#[cfg(feature = "solar")]
+impl SolarCalculator for SunriseEquation {
+    fn solar_time(
+        &self,
+        event: SolarEvent,
+        date: NaiveDate,
+        location: &GeoLocation,
+    ) -> Option<DateTime<Utc>> {
+        // Julian date 2451545.0 is noon UTC on 2000-01-01.
+        const J2000: f64 = 2_451_545.0;
+        const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+        let j2000 = NaiveDate::from_ymd_opt(2000, 1, 1).expect("valid date");
+        let days = (date - j2000).num_days() as f64;
+
+        // Mean solar noon at the location, in days since J2000.
+        let noon = days + 0.0008 - location.longitude / 360.0;
+        let anomaly = (357.5291 + 0.985_600_28 * noon)
+            .rem_euclid(360.0)
+            .to_radians();
+        let center = 1.9148 * anomaly.sin()
+            + 0.0200 * (2.0 * anomaly).sin()
+            + 0.0003 * (3.0 * anomaly).sin();
+        let ecliptic_longitude = (anomaly.to_degrees() + center + 180.0 + 102.9372)
+            .rem_euclid(360.0)
+            .to_radians();
+        let transit =
+            J2000 + noon + 0.0053 * anomaly.sin() - 0.0069 * (2.0 * ecliptic_longitude).sin();
+        let declination = (ecliptic_longitude.sin() * 23.4397_f64.to_radians().sin()).asin();
+
+        let altitude: f64 = match event {
+            SolarEvent::Sunrise | SolarEvent::Sunset => -0.833,
+            SolarEvent::Dawn | SolarEvent::Dusk => -6.0,
+        };
+        let latitude = location.latitude.to_radians();
+        let cos_hour_angle = (altitude.to_radians().sin() - latitude.sin() * declination.sin())
+            / (latitude.cos() * declination.cos());
+        if !(-1.0..=1.0).contains(&cos_hour_angle) {
+            return None;
+        }
+        let half_day = cos_hour_angle.acos().to_degrees() / 360.0;
+        let julian = match event {
+            SolarEvent::Dawn | SolarEvent::Sunrise => transit - half_day,
+            SolarEvent::Sunset | SolarEvent::Dusk => transit + half_day,
+        };
+        let seconds = ((julian - UNIX_EPOCH_JD) * 86_400.0).round() as i64;
+        Utc.timestamp_opt(seconds, 0).single()
+    }
+}
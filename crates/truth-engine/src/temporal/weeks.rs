@@ -0,0 +1,128 @@
+//! Week numbers and ISO 8601 week dates.
+//!
+//! A week date names a day by ISO week-numbering year, week, and weekday:
+//! `2026-W08-3` is the Wednesday of week 8 of 2026. The week-numbering year
+//! can differ from the calendar year near January 1 (`2026-W01-1` is
+//! 2025-12-29).
+
+use chrono::{Datelike, NaiveDate, Weekday};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::parse_date;
+use crate::error::TruthError;
+
+/// How weeks are numbered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum WeekScheme {
+    /// ISO 8601: weeks start Monday; week 1 contains the year's first Thursday.
+    #[default]
+    Iso,
+    /// US: weeks start Sunday; week 1 contains January 1.
+    Us,
+}
+
+/// A week number and the year it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WeekNumber {
+    /// The week-numbering year, which can differ from the calendar year for
+    /// days near January 1.
+    pub year: i32,
+    /// The week within `year` (1–53).
+    pub week: u32,
+    /// The scheme used.
+    pub scheme: WeekScheme,
+}
+
+/// The week number of a `YYYY-MM-DD` date.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the date cannot be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::{week_number, WeekScheme};
+///
+/// // Sunday, December 28, 2025
+/// let iso = week_number("2025-12-28", WeekScheme::Iso).unwrap();
+/// assert_eq!((iso.year, iso.week), (2025, 52));
+/// let us = week_number("2025-12-28", WeekScheme::Us).unwrap();
+/// assert_eq!((us.year, us.week), (2026, 1));
+/// ```
+pub fn week_number(date: &str, scheme: WeekScheme) -> Result<WeekNumber, TruthError> {
+    Ok(week_of(parse_date(date)?, scheme))
+}
+
+/// The week number of a date.
+pub(super) fn week_of(date: NaiveDate, scheme: WeekScheme) -> WeekNumber {
+    let (year, week) = match scheme {
+        WeekScheme::Iso => (date.iso_week().year(), date.iso_week().week()),
+        WeekScheme::Us => {
+            // The week belongs to the year its Saturday falls in, so the week
+            // containing January 1 is week 1 of the new year.
+            let sunday = date.week(Weekday::Sun).first_day();
+            let year = sunday.week(Weekday::Sun).last_day().year();
+            let week1 = NaiveDate::from_ymd_opt(year, 1, 1)
+                .map_or(sunday, |jan1| jan1.week(Weekday::Sun).first_day());
+            (year, ((sunday - week1).num_days() / 7) as u32 + 1)
+        }
+    };
+    WeekNumber { year, week, scheme }
+}
+
+/// Parse an ISO 8601 week date into a `YYYY-MM-DD` calendar date.
+///
+/// Accepts the extended (`2026-W08-3`) and basic (`2026W083`) forms. Without a
+/// weekday (`2026-W08`), the week's Monday is returned.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidDatetime`] if the string is not a week date or
+/// names a week the year doesn't have (e.g., `2025-W53`).
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::parse_iso_week_date;
+///
+/// assert_eq!(parse_iso_week_date("2026-W08-3").unwrap(), "2026-02-18");
+/// assert_eq!(parse_iso_week_date("2026W011").unwrap(), "2025-12-29");
+/// ```
+pub fn parse_iso_week_date(s: &str) -> Result<String, TruthError> {
+    parse_week_date(s)
+        .map(|(date, _)| date.to_string())
+        .ok_or_else(|| TruthError::InvalidDatetime(format!("'{}' is not an ISO week date", s)))
+}
+
+/// Parse `YYYY-Www[-D]` or `YYYYWww[D]`, case-insensitively.
+pub(super) fn parse_week_date(s: &str) -> Option<(NaiveDate, bool)> {
+    let s = s.trim();
+    let (year, rest) = s.split_at_checked(4)?;
+    let rest = rest.strip_prefix('-').unwrap_or(rest);
+    let rest = rest.strip_prefix(['W', 'w'])?;
+    let (week, day) = match rest.len() {
+        2 => (rest, None),
+        3 => (&rest[..2], Some(&rest[2..])),
+        4 if rest.as_bytes()[2] == b'-' => (&rest[..2], Some(&rest[3..])),
+        _ => return None,
+    };
+    let all_digits = |t: &str| !t.is_empty() && t.bytes().all(|b| b.is_ascii_digit());
+    if !all_digits(year) || !all_digits(week) || !day.is_none_or(all_digits) {
+        return None;
+    }
+    let weekday = match day {
+        Some(d) => match d.parse::<u8>().ok()? {
+            n @ 1..=7 => Weekday::try_from(n - 1).ok()?,
+            _ => return None,
+        },
+        None => Weekday::Mon,
+    };
+    let date = NaiveDate::from_isoywd_opt(year.parse().ok()?, week.parse().ok()?, weekday)?;
+    Some((date, day.is_some()))
+}
@@ -0,0 +1,77 @@
+//! Work weeks and weekends derived from [`WorkingHours::days`].
+//!
+//! [`WeekStartDay`](super::WeekStartDay) only says where a calendar week
+//! begins. Deployments where the weekend is Friday–Saturday also need "end of
+//! the work week" to mean Thursday and "this weekend" to mean Friday. Both
+//! follow from the working days: the work week starts on the day after the
+//! weekend, and the weekend is the run of non-working days that ends it.
+
+use chrono::Weekday;
+
+use super::WorkingHours;
+
+impl WorkingHours {
+    /// 09:00–17:00, Sunday through Thursday (a Friday–Saturday weekend).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use truth_engine::temporal::WorkingHours;
+    ///
+    /// let wh = WorkingHours::sunday_to_thursday();
+    /// assert_eq!(wh.work_week_start(), Some(Weekday::Sun));
+    /// assert_eq!(wh.weekend(), vec![Weekday::Fri, Weekday::Sat]);
+    /// ```
+    pub fn sunday_to_thursday() -> Self {
+        Self {
+            days: vec![
+                Weekday::Sun,
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+            ],
+            ..Self::default()
+        }
+    }
+
+    /// The first day of the work week: the day after the longest run of
+    /// non-working days. `None` if every day (or no day) is a working day.
+    pub fn work_week_start(&self) -> Option<Weekday> {
+        if self.days.is_empty() || self.days.len() >= 7 {
+            return None;
+        }
+        // Walk the week twice so runs that wrap past Sunday are measured whole.
+        let mut best: Option<(usize, Weekday)> = None;
+        let mut run = 0;
+        let mut day = Weekday::Mon;
+        for _ in 0..14 {
+            if self.days.contains(&day) {
+                if run > 0 && best.is_none_or(|(longest, _)| run > longest) {
+                    best = Some((run, day));
+                }
+                run = 0;
+            } else {
+                run += 1;
+            }
+            day = day.succ();
+        }
+        best.map(|(_, start)| start)
+    }
+
+    /// The non-working days that close the work week, in order (e.g.,
+    /// `[Sat, Sun]`). Empty if there is no work week.
+    pub fn weekend(&self) -> Vec<Weekday> {
+        let Some(start) = self.work_week_start() else {
+            return Vec::new();
+        };
+        let mut days = Vec::new();
+        let mut day = start.pred();
+        while !self.days.contains(&day) {
+            days.insert(0, day);
+            day = day.pred();
+        }
+        days
+    }
+}
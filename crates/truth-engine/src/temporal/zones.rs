@@ -0,0 +1,451 @@
+//! Static timezone lookup tables: abbreviations, cities, and countries → IANA zones.
+//!
+//! Abbreviations are not standardized — "CST" is used for US Central, China,
+//! and Cuba. We map an abbreviation to a single zone only when its common
+//! usage is unambiguous; otherwise we return every candidate and let the
+//! caller (or the user) choose.
+//!
+//! City search combines the city component of every IANA zone name
+//! ("America/Sao_Paulo" → "sao paulo") with a small alias table for major
+//! cities that are not zone names themselves ("San Francisco", "Mumbai").
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The result of looking up a timezone abbreviation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TimezoneAbbreviation {
+    /// The abbreviation as looked up (uppercased, e.g., "CST").
+    pub abbreviation: String,
+    /// Candidate IANA zones, most commonly intended first.
+    pub candidates: Vec<String>,
+    /// Whether more than one zone uses this abbreviation.
+    pub ambiguous: bool,
+    /// Whether the abbreviation is also a tzdb zone fixed at standard time
+    /// all year, so it may mean the region's clock, which observes daylight
+    /// saving time, or a fixed offset (`"EST"`: America/New_York or
+    /// America/Panama). Always `ambiguous` too.
+    pub fixed_offset_reading: bool,
+}
+
+/// Abbreviation table. The first candidate is the most common reading.
+///
+/// Every abbreviation names a region's clock and follows its daylight saving
+/// time, as `PST` does. `EST`, `MST`, `HST`, `CET`, `MET`, `EET`, and `WET`
+/// are also tzdb zones; they are listed here too, under the same rule. Where
+/// the region observes DST but the tzdb zone does not (`EST`, `MST`), the
+/// zone tzdb links the name to follows as a second, fixed-offset candidate,
+/// so the abbreviation is ambiguous rather than silently one or the other.
+const ABBREVIATIONS: &[(&str, &[&str])] = &[
+    // North America
+    ("ET", &["America/New_York"]),
+    ("EST", &["America/New_York", "America/Panama"]),
+    ("EDT", &["America/New_York"]),
+    ("CT", &["America/Chicago"]),
+    ("CDT", &["America/Chicago"]),
+    (
+        "CST",
+        &["America/Chicago", "Asia/Shanghai", "America/Havana"],
+    ),
+    ("MT", &["America/Denver"]),
+    ("MST", &["America/Denver", "America/Phoenix"]),
+    ("MDT", &["America/Denver"]),
+    ("PT", &["America/Los_Angeles"]),
+    ("PST", &["America/Los_Angeles"]),
+    ("PDT", &["America/Los_Angeles"]),
+    ("AKST", &["America/Anchorage"]),
+    ("AKDT", &["America/Anchorage"]),
+    ("HST", &["Pacific/Honolulu"]),
+    ("AST", &["America/Halifax", "Asia/Riyadh"]),
+    ("ADT", &["America/Halifax"]),
+    ("NST", &["America/St_Johns"]),
+    ("NDT", &["America/St_Johns"]),
+    // South America
+    ("BRT", &["America/Sao_Paulo"]),
+    ("ART", &["America/Argentina/Buenos_Aires"]),
+    // Europe
+    ("BST", &["Europe/London", "Asia/Dhaka"]),
+    ("IST", &["Asia/Kolkata", "Europe/Dublin", "Asia/Jerusalem"]),
+    ("CET", &["Europe/Berlin"]),
+    ("CEST", &["Europe/Berlin"]),
+    ("MET", &["Europe/Berlin"]),
+    ("EET", &["Europe/Athens"]),
+    ("EEST", &["Europe/Athens"]),
+    ("WET", &["Europe/Lisbon"]),
+    ("WEST", &["Europe/Lisbon"]),
+    ("MSK", &["Europe/Moscow"]),
+    // Africa
+    ("WAT", &["Africa/Lagos"]),
+    ("CAT", &["Africa/Maputo"]),
+    ("EAT", &["Africa/Nairobi"]),
+    ("SAST", &["Africa/Johannesburg"]),
+    // Asia
+    ("GST", &["Asia/Dubai", "Atlantic/South_Georgia"]),
+    ("PKT", &["Asia/Karachi"]),
+    ("ICT", &["Asia/Bangkok"]),
+    ("WIB", &["Asia/Jakarta"]),
+    ("SGT", &["Asia/Singapore"]),
+    ("HKT", &["Asia/Hong_Kong"]),
+    ("PHT", &["Asia/Manila"]),
+    ("JST", &["Asia/Tokyo"]),
+    ("KST", &["Asia/Seoul"]),
+    // Oceania
+    ("AWST", &["Australia/Perth"]),
+    ("ACST", &["Australia/Adelaide"]),
+    ("ACDT", &["Australia/Adelaide"]),
+    ("AEST", &["Australia/Sydney"]),
+    ("AEDT", &["Australia/Sydney"]),
+    ("NZST", &["Pacific/Auckland"]),
+    ("NZDT", &["Pacific/Auckland"]),
+];
+
+/// Look up a timezone abbreviation (case-insensitive).
+///
+/// Returns `None` if the abbreviation is unknown. Abbreviations name a
+/// region's clock and follow its daylight saving time; `"EST"` and `"MST"`,
+/// which are also fixed-offset tzdb zones, are ambiguous between the two
+/// readings and set [`TimezoneAbbreviation::fixed_offset_reading`].
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::lookup_timezone_abbreviation;
+///
+/// let pst = lookup_timezone_abbreviation("pst").unwrap();
+/// assert_eq!(pst.candidates, vec!["America/Los_Angeles"]);
+/// assert!(!pst.ambiguous);
+///
+/// let cst = lookup_timezone_abbreviation("CST").unwrap();
+/// assert!(cst.ambiguous);
+/// assert!(cst.candidates.contains(&"Asia/Shanghai".to_string()));
+///
+/// let est = lookup_timezone_abbreviation("EST").unwrap();
+/// assert_eq!(est.candidates, vec!["America/New_York", "America/Panama"]);
+/// assert!(est.fixed_offset_reading);
+/// ```
+pub fn lookup_timezone_abbreviation(abbreviation: &str) -> Option<TimezoneAbbreviation> {
+    let upper = abbreviation.trim().to_uppercase();
+    ABBREVIATIONS
+        .iter()
+        .find(|(abbr, _)| *abbr == upper)
+        .map(|(abbr, zones)| TimezoneAbbreviation {
+            abbreviation: abbr.to_string(),
+            candidates: zones.iter().map(|z| z.to_string()).collect(),
+            ambiguous: zones.len() > 1,
+            fixed_offset_reading: zones.len() > 1 && abbr.parse::<chrono_tz::Tz>().is_ok(),
+        })
+}
+
+// ── City and country search ─────────────────────────────────────────────────
+
+/// Major cities that are not the city component of an IANA zone name.
+const CITY_ALIASES: &[(&str, &[&str])] = &[
+    ("abu dhabi", &["Asia/Dubai"]),
+    ("atlanta", &["America/New_York"]),
+    ("austin", &["America/Chicago"]),
+    ("bangalore", &["Asia/Kolkata"]),
+    ("barcelona", &["Europe/Madrid"]),
+    ("beijing", &["Asia/Shanghai"]),
+    ("bengaluru", &["Asia/Kolkata"]),
+    ("boston", &["America/New_York"]),
+    ("cape town", &["Africa/Johannesburg"]),
+    ("chennai", &["Asia/Kolkata"]),
+    ("dallas", &["America/Chicago"]),
+    ("delhi", &["Asia/Kolkata"]),
+    ("edinburgh", &["Europe/London"]),
+    ("frankfurt", &["Europe/Berlin"]),
+    ("geneva", &["Europe/Zurich"]),
+    ("guangzhou", &["Asia/Shanghai"]),
+    ("hamburg", &["Europe/Berlin"]),
+    ("hanoi", &["Asia/Ho_Chi_Minh"]),
+    ("houston", &["America/Chicago"]),
+    ("hyderabad", &["Asia/Kolkata"]),
+    ("krakow", &["Europe/Warsaw"]),
+    ("manchester", &["Europe/London"]),
+    ("miami", &["America/New_York"]),
+    ("milan", &["Europe/Rome"]),
+    ("montreal", &["America/Toronto"]),
+    ("mumbai", &["Asia/Kolkata"]),
+    ("munich", &["Europe/Berlin"]),
+    ("new delhi", &["Asia/Kolkata"]),
+    ("osaka", &["Asia/Tokyo"]),
+    ("ottawa", &["America/Toronto"]),
+    ("philadelphia", &["America/New_York"]),
+    ("portland", &["America/Los_Angeles", "America/New_York"]),
+    ("rio de janeiro", &["America/Sao_Paulo"]),
+    ("saint petersburg", &["Europe/Moscow"]),
+    ("san diego", &["America/Los_Angeles"]),
+    ("san francisco", &["America/Los_Angeles"]),
+    ("seattle", &["America/Los_Angeles"]),
+    ("shenzhen", &["Asia/Shanghai"]),
+    ("st petersburg", &["Europe/Moscow"]),
+    ("tel aviv", &["Asia/Jerusalem"]),
+    ("washington", &["America/New_York"]),
+    ("wellington", &["Pacific/Auckland"]),
+];
+
+/// ISO 3166-1 alpha-2 code, English country name, and IANA zones (primary first).
+const COUNTRIES: &[(&str, &str, &[&str])] = &[
+    ("AE", "united arab emirates", &["Asia/Dubai"]),
+    (
+        "AR",
+        "argentina",
+        &[
+            "America/Argentina/Buenos_Aires",
+            "America/Argentina/Cordoba",
+        ],
+    ),
+    ("AT", "austria", &["Europe/Vienna"]),
+    (
+        "AU",
+        "australia",
+        &[
+            "Australia/Sydney",
+            "Australia/Melbourne",
+            "Australia/Brisbane",
+            "Australia/Adelaide",
+            "Australia/Darwin",
+            "Australia/Perth",
+            "Australia/Hobart",
+        ],
+    ),
+    ("BE", "belgium", &["Europe/Brussels"]),
+    (
+        "BR",
+        "brazil",
+        &[
+            "America/Sao_Paulo",
+            "America/Manaus",
+            "America/Fortaleza",
+            "America/Recife",
+            "America/Belem",
+            "America/Cuiaba",
+            "America/Rio_Branco",
+            "America/Noronha",
+        ],
+    ),
+    (
+        "CA",
+        "canada",
+        &[
+            "America/Toronto",
+            "America/Vancouver",
+            "America/Edmonton",
+            "America/Winnipeg",
+            "America/Halifax",
+            "America/St_Johns",
+            "America/Regina",
+        ],
+    ),
+    ("CH", "switzerland", &["Europe/Zurich"]),
+    (
+        "CL",
+        "chile",
+        &["America/Santiago", "America/Punta_Arenas", "Pacific/Easter"],
+    ),
+    ("CN", "china", &["Asia/Shanghai", "Asia/Urumqi"]),
+    ("CO", "colombia", &["America/Bogota"]),
+    ("CZ", "czechia", &["Europe/Prague"]),
+    ("DE", "germany", &["Europe/Berlin"]),
+    ("DK", "denmark", &["Europe/Copenhagen"]),
+    ("EG", "egypt", &["Africa/Cairo"]),
+    ("ES", "spain", &["Europe/Madrid", "Atlantic/Canary"]),
+    ("FI", "finland", &["Europe/Helsinki"]),
+    ("FR", "france", &["Europe/Paris"]),
+    ("GB", "united kingdom", &["Europe/London"]),
+    ("GR", "greece", &["Europe/Athens"]),
+    ("HK", "hong kong", &["Asia/Hong_Kong"]),
+    (
+        "ID",
+        "indonesia",
+        &["Asia/Jakarta", "Asia/Makassar", "Asia/Jayapura"],
+    ),
+    ("IE", "ireland", &["Europe/Dublin"]),
+    ("IL", "israel", &["Asia/Jerusalem"]),
+    ("IN", "india", &["Asia/Kolkata"]),
+    ("IT", "italy", &["Europe/Rome"]),
+    ("JP", "japan", &["Asia/Tokyo"]),
+    ("KE", "kenya", &["Africa/Nairobi"]),
+    ("KR", "south korea", &["Asia/Seoul"]),
+    (
+        "MX",
+        "mexico",
+        &[
+            "America/Mexico_City",
+            "America/Cancun",
+            "America/Monterrey",
+            "America/Chihuahua",
+            "America/Tijuana",
+        ],
+    ),
+    ("MY", "malaysia", &["Asia/Kuala_Lumpur"]),
+    ("NG", "nigeria", &["Africa/Lagos"]),
+    ("NL", "netherlands", &["Europe/Amsterdam"]),
+    ("NO", "norway", &["Europe/Oslo"]),
+    (
+        "NZ",
+        "new zealand",
+        &["Pacific/Auckland", "Pacific/Chatham"],
+    ),
+    ("PE", "peru", &["America/Lima"]),
+    ("PH", "philippines", &["Asia/Manila"]),
+    ("PK", "pakistan", &["Asia/Karachi"]),
+    ("PL", "poland", &["Europe/Warsaw"]),
+    ("PT", "portugal", &["Europe/Lisbon", "Atlantic/Azores"]),
+    (
+        "RU",
+        "russia",
+        &[
+            "Europe/Moscow",
+            "Europe/Kaliningrad",
+            "Europe/Samara",
+            "Asia/Yekaterinburg",
+            "Asia/Novosibirsk",
+            "Asia/Krasnoyarsk",
+            "Asia/Irkutsk",
+            "Asia/Yakutsk",
+            "Asia/Vladivostok",
+            "Asia/Magadan",
+            "Asia/Kamchatka",
+        ],
+    ),
+    ("SA", "saudi arabia", &["Asia/Riyadh"]),
+    ("SE", "sweden", &["Europe/Stockholm"]),
+    ("SG", "singapore", &["Asia/Singapore"]),
+    ("TH", "thailand", &["Asia/Bangkok"]),
+    ("TR", "turkey", &["Europe/Istanbul"]),
+    ("TW", "taiwan", &["Asia/Taipei"]),
+    ("UA", "ukraine", &["Europe/Kyiv"]),
+    (
+        "US",
+        "united states",
+        &[
+            "America/New_York",
+            "America/Chicago",
+            "America/Denver",
+            "America/Phoenix",
+            "America/Los_Angeles",
+            "America/Anchorage",
+            "Pacific/Honolulu",
+        ],
+    ),
+    ("VN", "vietnam", &["Asia/Ho_Chi_Minh"]),
+    ("ZA", "south africa", &["Africa/Johannesburg"]),
+];
+
+/// IANA regions whose zone names end in a city (excludes `Etc/`, `US/`, etc.).
+const CITY_REGIONS: &[&str] = &[
+    "Africa",
+    "America",
+    "Antarctica",
+    "Asia",
+    "Atlantic",
+    "Australia",
+    "Europe",
+    "Indian",
+    "Pacific",
+];
+
+/// Find candidate IANA zones for a city or country name (e.g., `"Paris"`,
+/// `"São Paulo"`, `"San Francisco"`, `"Japan"`).
+///
+/// Matching is exact after normalization (case, accents, and `_`/`-` separators
+/// are ignored) — no fuzzy matching, so the same query always yields the same
+/// zones. City matches come before country matches. Returns an empty list when
+/// nothing matches.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::find_timezone;
+///
+/// assert_eq!(find_timezone("São Paulo"), vec!["America/Sao_Paulo"]);
+/// assert_eq!(find_timezone("san francisco"), vec!["America/Los_Angeles"]);
+/// assert!(find_timezone("Atlantis").is_empty());
+/// ```
+pub fn find_timezone(query: &str) -> Vec<String> {
+    let needle = normalize_place(query);
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<String> = Vec::new();
+    let mut push = |zone: &str| {
+        if !matches.iter().any(|m| m == zone) {
+            matches.push(zone.to_string());
+        }
+    };
+
+    for tz in chrono_tz::TZ_VARIANTS.iter() {
+        let name = tz.name();
+        let Some((region, city)) = name.split_once('/') else {
+            continue;
+        };
+        if !CITY_REGIONS.contains(&region) {
+            continue;
+        }
+        let city = city.rsplit('/').next().unwrap_or(city);
+        if normalize_place(city) == needle {
+            push(name);
+        }
+    }
+
+    for (city, zones) in CITY_ALIASES {
+        if *city == needle {
+            zones.iter().for_each(|z| push(z));
+        }
+    }
+
+    for (_, country, zones) in COUNTRIES {
+        if *country == needle {
+            zones.iter().for_each(|z| push(z));
+        }
+    }
+
+    matches
+}
+
+/// List the IANA zones for an ISO 3166-1 alpha-2 country code (case-insensitive),
+/// primary zone first. Returns an empty list for unknown codes.
+///
+/// # Examples
+///
+/// ```
+/// use truth_engine::temporal::find_timezones_by_country;
+///
+/// assert_eq!(find_timezones_by_country("fr"), vec!["Europe/Paris"]);
+/// assert_eq!(find_timezones_by_country("US")[0], "America/New_York");
+/// ```
+pub fn find_timezones_by_country(country_code: &str) -> Vec<String> {
+    let code = country_code.trim().to_uppercase();
+    COUNTRIES
+        .iter()
+        .find(|(c, _, _)| *c == code)
+        .map(|(_, _, zones)| zones.iter().map(|z| z.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Normalize a place name: lowercase, strip common Latin diacritics, treat `_` and
+/// `-` as spaces, drop `.` and `'`, and collapse whitespace.
+fn normalize_place(s: &str) -> String {
+    let folded: String = s
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| !matches!(c, '.' | '\''))
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            '_' | '-' => ' ',
+            other => other,
+        })
+        .collect();
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,337 @@
+//! Academic term calendars: named terms with breaks and reading weeks.
+//!
+//! A [`TermCalendar`] lists a school's terms, each with the breaks inside
+//! it. It answers which term or break a date falls in, resolves term names
+//! in expressions through
+//! [`ResolveOptions::term_calendar`](crate::temporal::ResolveOptions::term_calendar)
+//! ("first Monday of spring term", "end of reading week"), and turns the
+//! non-teaching time into an [`EventStream`] of blackouts for availability.
+//!
+//! Dates are whole local days in the calendar's timezone; term and break
+//! ends are inclusive.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::availability::{merge_availability, EventStream, PrivacyLevel, UnifiedAvailability};
+use crate::error::{Result, TruthError};
+use crate::expander::ExpandedEvent;
+use crate::temporal::interval::{merge, TimeInterval};
+use crate::temporal::{local_to_utc, parse_timezone};
+
+/// Stream id of the blackouts from [`TermCalendar::blackouts`].
+pub const BLACKOUTS_STREAM: &str = "term-blackouts";
+
+/// What a break inside a term is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum BreakKind {
+    /// A holiday: no teaching and no other term activity.
+    #[default]
+    Break,
+    /// No teaching, but students and staff are around.
+    ReadingWeek,
+}
+
+/// A break inside a term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TermBreak {
+    pub name: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub kind: BreakKind,
+    pub start: NaiveDate,
+    /// Last day of the break (inclusive).
+    pub end: NaiveDate,
+}
+
+/// A named term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Term {
+    /// The name used in expressions, matched case-insensitively ("Spring term").
+    pub name: String,
+    pub start: NaiveDate,
+    /// Last day of the term (inclusive).
+    pub end: NaiveDate,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub breaks: Vec<TermBreak>,
+}
+
+impl Term {
+    /// The `ordinal`-th `weekday` of the term (`-1` is the last), or `None`
+    /// if the term is too short.
+    pub fn nth_weekday(&self, weekday: Weekday, ordinal: i32) -> Option<NaiveDate> {
+        nth_weekday_between(self.start, self.end, weekday, ordinal)
+    }
+}
+
+/// Which days [`TermCalendar::blackouts`] blocks out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Blackout {
+    /// Breaks of kind [`BreakKind::Break`] only.
+    Breaks,
+    /// Every break, including reading weeks.
+    AllBreaks,
+    /// Everything but teaching days: every break plus the time between and
+    /// around terms.
+    NonTeaching,
+}
+
+/// A school's terms, in one timezone.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use truth_engine::terms::{BreakKind, Term, TermBreak, TermCalendar};
+///
+/// let date = |m, d| NaiveDate::from_ymd_opt(2027, m, d).unwrap();
+/// let calendar = TermCalendar::new(
+///     "Europe/London",
+///     vec![Term {
+///         name: "Spring term".into(),
+///         start: date(1, 6),
+///         end: date(3, 26),
+///         breaks: vec![TermBreak {
+///             name: "Reading week".into(),
+///             kind: BreakKind::ReadingWeek,
+///             start: date(2, 15),
+///             end: date(2, 19),
+///         }],
+///     }],
+/// )
+/// .unwrap();
+///
+/// let spring = calendar.find_term("spring term", date(1, 1)).unwrap();
+/// assert_eq!(spring.nth_weekday(Weekday::Mon, 1), Some(date(1, 11)));
+/// assert!(calendar.is_teaching_day(date(2, 12)));
+/// assert!(!calendar.is_teaching_day(date(2, 16)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TermCalendar {
+    /// IANA timezone the dates are read in.
+    pub timezone: String,
+    /// Terms sorted by start, each with its breaks sorted by start.
+    pub terms: Vec<Term>,
+}
+
+impl TermCalendar {
+    /// A calendar with `terms`, sorted by start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidTimezone`] for an unknown `timezone`, and
+    /// [`TruthError::InvalidTermCalendar`] if a term or break ends before it
+    /// starts, a break lies outside its term, or two terms overlap.
+    pub fn new(timezone: &str, mut terms: Vec<Term>) -> Result<Self> {
+        parse_timezone(timezone)?;
+        terms.sort_by_key(|t| t.start);
+        for term in &mut terms {
+            term.breaks.sort_by_key(|b| b.start);
+        }
+        for term in &terms {
+            if term.end < term.start {
+                return Err(invalid(format!(
+                    "term '{}' ends before it starts",
+                    term.name
+                )));
+            }
+            for b in &term.breaks {
+                if b.end < b.start {
+                    return Err(invalid(format!("break '{}' ends before it starts", b.name)));
+                }
+                if b.start < term.start || b.end > term.end {
+                    return Err(invalid(format!(
+                        "break '{}' is outside term '{}'",
+                        b.name, term.name
+                    )));
+                }
+            }
+        }
+        if let Some(pair) = terms.windows(2).find(|w| w[1].start <= w[0].end) {
+            return Err(invalid(format!(
+                "terms '{}' and '{}' overlap",
+                pair[0].name, pair[1].name
+            )));
+        }
+        Ok(Self {
+            timezone: timezone.to_string(),
+            terms,
+        })
+    }
+
+    /// The term containing `date`.
+    pub fn term_of(&self, date: NaiveDate) -> Option<&Term> {
+        self.terms.iter().find(|t| t.start <= date && date <= t.end)
+    }
+
+    /// The break containing `date`.
+    pub fn break_of(&self, date: NaiveDate) -> Option<&TermBreak> {
+        self.term_of(date)?
+            .breaks
+            .iter()
+            .find(|b| b.start <= date && date <= b.end)
+    }
+
+    /// Whether `date` is in a term and not in one of its breaks.
+    pub fn is_teaching_day(&self, date: NaiveDate) -> bool {
+        self.term_of(date).is_some() && self.break_of(date).is_none()
+    }
+
+    /// The term called `name` (case-insensitive) that is current or next
+    /// as of `today`, or the most recent one if all have ended. A trailing
+    /// year ("spring term 2027") picks the term starting in that year.
+    pub fn find_term(&self, name: &str, today: NaiveDate) -> Option<&Term> {
+        pick(
+            named(&self.terms, name, |t| (&t.name, t.start)),
+            today,
+            |t| t.end,
+        )
+    }
+
+    /// Like [`find_term`](Self::find_term), for breaks in any term.
+    pub fn find_break(&self, name: &str, today: NaiveDate) -> Option<&TermBreak> {
+        let breaks = self.terms.iter().flat_map(|t| &t.breaks);
+        pick(named(breaks, name, |b| (&b.name, b.start)), today, |b| {
+            b.end
+        })
+    }
+
+    /// Blacked-out time in `[window_start, window_end)` as an
+    /// [`EventStream`] named [`BLACKOUTS_STREAM`], one event per run of
+    /// consecutive blacked-out days.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidTimezone`] for an unknown `timezone`.
+    pub fn blackouts(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+        which: Blackout,
+    ) -> Result<EventStream> {
+        let tz = parse_timezone(&self.timezone)?;
+        let window = TimeInterval {
+            start: window_start,
+            end: window_end,
+        };
+        // Whole local days [first, last] as a UTC interval.
+        let days = |first: NaiveDate, last: NaiveDate| {
+            let start = local_to_utc(&tz, first.and_hms_opt(0, 0, 0)?)?;
+            let end = local_to_utc(&tz, last.succ_opt()?.and_hms_opt(0, 0, 0)?)?;
+            Some(TimeInterval { start, end })
+        };
+        let breaks = self
+            .terms
+            .iter()
+            .flat_map(|t| &t.breaks)
+            .filter(|b| which != Blackout::Breaks || b.kind == BreakKind::Break);
+        let mut spans: Vec<TimeInterval> = breaks.filter_map(|b| days(b.start, b.end)).collect();
+        if which == Blackout::NonTeaching {
+            let terms: Vec<TimeInterval> = self
+                .terms
+                .iter()
+                .filter_map(|t| days(t.start, t.end))
+                .collect();
+            spans.extend(window.subtract_all(&terms));
+        }
+        Ok(EventStream {
+            stream_id: BLACKOUTS_STREAM.to_string(),
+            events: merge(spans.iter().filter_map(|s| s.clamp_to(&window)))
+                .into_iter()
+                .map(|s| ExpandedEvent::new(s.start, s.end))
+                .collect(),
+        })
+    }
+
+    /// [`merge_availability`] over `streams` plus the blackouts.
+    ///
+    /// # Errors
+    ///
+    /// As for [`blackouts`](Self::blackouts).
+    pub fn availability(
+        &self,
+        streams: &[EventStream],
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+        privacy: PrivacyLevel,
+        which: Blackout,
+    ) -> Result<UnifiedAvailability> {
+        let mut all = streams.to_vec();
+        all.push(self.blackouts(window_start, window_end, which)?);
+        Ok(merge_availability(&all, window_start, window_end, privacy))
+    }
+}
+
+/// The `ordinal`-th `weekday` in `[first, last]` (`-1` is the last).
+pub(crate) fn nth_weekday_between(
+    first: NaiveDate,
+    last: NaiveDate,
+    weekday: Weekday,
+    ordinal: i32,
+) -> Option<NaiveDate> {
+    let date = if ordinal > 0 {
+        let ahead =
+            (weekday.num_days_from_monday() + 7 - first.weekday().num_days_from_monday()) % 7;
+        first + Duration::days(ahead as i64 + 7 * (ordinal as i64 - 1))
+    } else if ordinal < 0 {
+        let back = (last.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+        last - Duration::days(back as i64 + 7 * (-ordinal as i64 - 1))
+    } else {
+        return None;
+    };
+    (first <= date && date <= last).then_some(date)
+}
+
+/// Items whose name matches `query`, or whose name plus start year does.
+fn named<'a, T: 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    query: &str,
+    key: impl Fn(&T) -> (&String, NaiveDate),
+) -> Vec<&'a T> {
+    let query = query.trim().to_lowercase();
+    let items: Vec<&T> = items.into_iter().collect();
+    let exact: Vec<&T> = items
+        .iter()
+        .copied()
+        .filter(|i| key(i).0.to_lowercase() == query)
+        .collect();
+    if !exact.is_empty() {
+        return exact;
+    }
+    let year = query
+        .rsplit_once(' ')
+        .and_then(|(name, year)| Some((name, year.parse::<i32>().ok()?)));
+    let Some((name, year)) = year else {
+        return Vec::new();
+    };
+    items
+        .into_iter()
+        .filter(|i| {
+            let (n, start) = key(i);
+            n.to_lowercase() == name && start.year() == year
+        })
+        .collect()
+}
+
+/// The first candidate not yet over on `today`, else the last one.
+fn pick<T>(candidates: Vec<&T>, today: NaiveDate, end: impl Fn(&T) -> NaiveDate) -> Option<&T> {
+    candidates
+        .iter()
+        .find(|c| end(c) >= today)
+        .or(candidates.last())
+        .copied()
+}
+
+fn invalid(message: String) -> TruthError {
+    TruthError::InvalidTermCalendar(message)
+}
@@ -0,0 +1,268 @@
+//! Proptest strategies and `Arbitrary` implementations for engine types.
+//!
+//! Everything here generates *valid* input: RRULEs that expand, events
+//! whose end is after their start, windows inside the span events are drawn
+//! from, and expressions that [`resolve_relative`](crate::resolve_relative)
+//! accepts. Downstream crates can property-test their integration without
+//! writing generators of their own:
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use truth_engine::find_free_slots;
+//! use truth_engine::testing::{events, window};
+//!
+//! proptest!(|(events in events(0..20), (start, end) in window())| {
+//!     for slot in find_free_slots(&events, start, end) {
+//!         for event in &events {
+//!             prop_assert!(slot.end <= event.start || event.end <= slot.start);
+//!         }
+//!     }
+//! });
+//! ```
+//!
+//! Events and windows fall within [`SPAN_START`]..[`SPAN_END`] (January and
+//! February 2026), so random events overlap each other and random windows
+//! often enough to exercise merging. Requires the `test-support` feature.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use proptest::prelude::*;
+use proptest::sample::SizeRange;
+
+use crate::availability::{EventStream, PrivacyLevel};
+use crate::error::TruthError;
+use crate::expander::{expand_rrule, ExpandedEvent};
+use crate::temporal::interval::TimeInterval;
+
+/// Start of the span generated events and windows fall within (inclusive).
+pub const SPAN_START: DateTime<Utc> = NaiveDate::from_ymd_opt(2026, 1, 1)
+    .expect("valid date")
+    .and_hms_opt(0, 0, 0)
+    .expect("valid time")
+    .and_utc();
+
+/// End of the span generated events and windows fall within (exclusive).
+pub const SPAN_END: DateTime<Utc> = NaiveDate::from_ymd_opt(2026, 3, 1)
+    .expect("valid date")
+    .and_hms_opt(0, 0, 0)
+    .expect("valid time")
+    .and_utc();
+
+/// Longest generated event, in minutes.
+const MAX_EVENT_MINUTES: i64 = 8 * 60;
+
+/// A recurring series: the arguments [`expand_rrule`] takes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceSpec {
+    /// RRULE with a COUNT, so every series is finite.
+    pub rrule: String,
+    /// Local datetime, "YYYY-MM-DDTHH:MM:SS".
+    pub dtstart: String,
+    pub duration_minutes: u32,
+    pub timezone: String,
+}
+
+impl RecurrenceSpec {
+    /// Expand the series.
+    pub fn expand(&self) -> Result<Vec<ExpandedEvent>, TruthError> {
+        expand_rrule(
+            &self.rrule,
+            &self.dtstart,
+            self.duration_minutes,
+            &self.timezone,
+            None,
+            None,
+        )
+    }
+}
+
+/// IANA timezones, including ones with DST in either hemisphere and
+/// non-hour offsets.
+pub fn timezone() -> impl Strategy<Value = String> {
+    prop::sample::select(vec![
+        "UTC",
+        "America/New_York",
+        "America/Los_Angeles",
+        "America/Sao_Paulo",
+        "Europe/London",
+        "Europe/Berlin",
+        "Asia/Kolkata",
+        "Asia/Tokyo",
+        "Australia/Adelaide",
+        "Pacific/Auckland",
+    ])
+    .prop_map(str::to_string)
+}
+
+/// Instants in [`SPAN_START`]..[`SPAN_END`], on whole minutes.
+pub fn datetime() -> impl Strategy<Value = DateTime<Utc>> {
+    datetime_in(SPAN_START, SPAN_END)
+}
+
+/// Instants in `start..end`, on whole minutes.
+///
+/// # Panics
+///
+/// If `start..end` contains no whole minute.
+pub fn datetime_in(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> impl Strategy<Value = DateTime<Utc>> {
+    let minutes = (end - start).num_minutes();
+    assert!(minutes > 0, "empty datetime range");
+    (0..minutes).prop_map(move |m| start + Duration::minutes(m))
+}
+
+/// RRULEs that expand: a FREQ with an INTERVAL and COUNT, and for weekly
+/// and monthly rules sometimes a BYDAY or BYMONTHDAY.
+pub fn rrule() -> impl Strategy<Value = String> {
+    let byday = prop::sample::subsequence(vec!["MO", "TU", "WE", "TH", "FR", "SA", "SU"], 1..=3)
+        .prop_map(|days| format!(";BYDAY={}", days.join(",")));
+    let bymonthday = (1u32..=28).prop_map(|d| format!(";BYMONTHDAY={d}"));
+    let daily = Just(("DAILY", String::new()));
+    let weekly = prop_oneof![Just(String::new()), byday].prop_map(|by| ("WEEKLY", by));
+    let monthly = prop_oneof![Just(String::new()), bymonthday].prop_map(|by| ("MONTHLY", by));
+    let yearly = Just(("YEARLY", String::new()));
+    (
+        prop_oneof![daily, weekly, monthly, yearly],
+        1u32..=4,
+        1u32..=30,
+    )
+        .prop_map(|((freq, by), interval, count)| {
+            format!("FREQ={freq};INTERVAL={interval}{by};COUNT={count}")
+        })
+}
+
+/// Recurring series in [`timezone`]s, starting in 2025–2027 on days 1–28.
+pub fn recurrence() -> impl Strategy<Value = RecurrenceSpec> {
+    (
+        rrule(),
+        (2025i32..=2027, 1u32..=12, 1u32..=28, 0u32..=23, 0u32..=59),
+        15u32..=120,
+        timezone(),
+    )
+        .prop_map(
+            |(rrule, (y, m, d, h, min), duration_minutes, timezone)| RecurrenceSpec {
+                rrule,
+                dtstart: format!("{y:04}-{m:02}-{d:02}T{h:02}:{min:02}:00"),
+                duration_minutes,
+                timezone,
+            },
+        )
+}
+
+/// Events of 1 minute to 8 hours starting in [`SPAN_START`]..[`SPAN_END`].
+pub fn event() -> impl Strategy<Value = ExpandedEvent> {
+    (datetime(), 1..=MAX_EVENT_MINUTES)
+        .prop_map(|(start, minutes)| ExpandedEvent::new(start, start + Duration::minutes(minutes)))
+}
+
+/// Unsorted, possibly overlapping [`event`]s.
+pub fn events(size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<ExpandedEvent>> {
+    prop::collection::vec(event(), size)
+}
+
+/// Streams of up to 10 [`event`]s, with ids "stream-0" through "stream-99".
+pub fn event_stream() -> impl Strategy<Value = EventStream> {
+    (0u32..100, events(0..=10)).prop_map(|(n, events)| EventStream {
+        stream_id: format!("stream-{n}"),
+        events,
+    })
+}
+
+/// `(start, end)` windows of 1 hour to 14 days inside
+/// [`SPAN_START`]..[`SPAN_END`].
+pub fn window() -> impl Strategy<Value = (DateTime<Utc>, DateTime<Utc>)> {
+    let longest = Duration::days(14);
+    (
+        datetime_in(SPAN_START, SPAN_END - longest),
+        60..=longest.num_minutes(),
+    )
+        .prop_map(|(start, minutes)| (start, start + Duration::minutes(minutes)))
+}
+
+/// Expressions [`resolve_relative`](crate::resolve_relative) resolves from
+/// any anchor in any timezone.
+pub fn expression() -> impl Strategy<Value = String> {
+    let weekday = prop::sample::select(vec![
+        "monday",
+        "tuesday",
+        "wednesday",
+        "thursday",
+        "friday",
+        "saturday",
+        "sunday",
+    ]);
+    let unit = prop::sample::select(vec!["minutes", "hours", "days", "weeks"]);
+    let month = prop::sample::select(vec![
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ]);
+    let hour = (1u32..=12, prop::sample::select(vec!["am", "pm"]))
+        .prop_map(|(h, meridiem)| format!("{h}{meridiem}"));
+    prop_oneof![
+        prop::sample::select(vec![
+            "now",
+            "today",
+            "tomorrow",
+            "yesterday",
+            "next week",
+            "start of next week",
+            "end of month",
+            "next business day",
+        ])
+        .prop_map(str::to_string),
+        weekday.clone().prop_map(|d| format!("next {d}")),
+        (weekday, hour.clone()).prop_map(|(d, h)| format!("next {d} at {h}")),
+        hour.prop_map(|h| format!("tomorrow at {h}")),
+        (1u32..=48, unit.clone()).prop_map(|(n, u)| format!("in {n} {u}")),
+        (1u32..=48, unit).prop_map(|(n, u)| format!("{n} {u} ago")),
+        (month, 1u32..=28, 2025i32..=2027).prop_map(|(m, d, y)| format!("{m} {d}, {y}")),
+    ]
+}
+
+impl Arbitrary for ExpandedEvent {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        event().boxed()
+    }
+}
+
+impl Arbitrary for TimeInterval {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Non-empty intervals, like [`event`]s.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        event().prop_map(|e| TimeInterval::from(&e)).boxed()
+    }
+}
+
+impl Arbitrary for EventStream {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        event_stream().boxed()
+    }
+}
+
+impl Arbitrary for PrivacyLevel {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![Just(PrivacyLevel::Full), Just(PrivacyLevel::Opaque)].boxed()
+    }
+}
@@ -0,0 +1,232 @@
+//! Chart-ready timelines of event streams.
+//!
+//! [`build_timeline`] lays the events of several streams out for a
+//! Gantt-style view of a window: each stream gets as many lanes as it needs
+//! so its own overlapping events never share a row, intervals are clipped to
+//! the window with flags saying which ends were cut, and overlaps (within or
+//! across streams) and free gaps are listed alongside. Offsets are in minutes
+//! from the window start, so a frontend can position bars without parsing
+//! datetimes.
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::availability::EventStream;
+use crate::freebusy::find_free_slots;
+use crate::temporal::interval::TimeInterval;
+
+/// A laid-out window of event streams.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Timeline {
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    /// Window length in minutes.
+    pub total_minutes: i64,
+    /// Rows, grouped by stream in input order.
+    pub lanes: Vec<TimelineLane>,
+    /// Every event intersecting the window, sorted by start then lane.
+    pub items: Vec<TimelineItem>,
+    /// Every pair of overlapping items.
+    pub conflicts: Vec<ConflictMarker>,
+    /// Gaps in the window where no stream is busy.
+    pub free: Vec<TimelineSpan>,
+}
+
+/// One row of the chart.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TimelineLane {
+    pub stream_id: String,
+    /// Position among this stream's lanes, from 0.
+    pub sub_lane: usize,
+}
+
+/// One event bar.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TimelineItem {
+    /// Index into [`Timeline::lanes`].
+    pub lane: usize,
+    pub stream_id: String,
+    /// Index of the event in its stream's `events`.
+    pub event_index: usize,
+    /// The bar, clipped to the window.
+    pub span: TimelineSpan,
+    /// Whether the event starts before the window.
+    pub clipped_start: bool,
+    /// Whether the event ends after the window.
+    pub clipped_end: bool,
+}
+
+/// An interval positioned within the window.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TimelineSpan {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Minutes from the window start to `start`.
+    pub offset_minutes: i64,
+    pub duration_minutes: i64,
+}
+
+/// An overlap between two items.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ConflictMarker {
+    /// Indexes into [`Timeline::items`], `item_a < item_b`.
+    pub item_a: usize,
+    pub item_b: usize,
+    /// The overlapping part.
+    pub span: TimelineSpan,
+}
+
+/// Lay out `streams` over `[window_start, window_end)`.
+///
+/// Events outside the window and zero-length events are left out. An empty
+/// or inverted window gives an empty timeline.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::timeline::build_timeline;
+/// use truth_engine::{EventStream, ExpandedEvent};
+///
+/// let at = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+/// let work = EventStream {
+///     stream_id: "work".to_string(),
+///     events: vec![
+///         ExpandedEvent::new(at(9), at(11)),
+///         ExpandedEvent::new(at(10), at(12)),
+///     ],
+/// };
+/// let timeline = build_timeline(&[work], at(8), at(18));
+/// assert_eq!(timeline.lanes.len(), 2);
+/// assert_eq!(timeline.items[1].lane, 1);
+/// assert_eq!(timeline.items[1].span.offset_minutes, 120);
+/// assert_eq!(timeline.conflicts[0].span.duration_minutes, 60);
+/// assert_eq!(timeline.free.len(), 2);
+/// ```
+pub fn build_timeline(
+    streams: &[EventStream],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Timeline {
+    let window = TimeInterval {
+        start: window_start,
+        end: window_end,
+    };
+    let mut timeline = Timeline {
+        window_start,
+        window_end,
+        total_minutes: (window_end - window_start).num_minutes().max(0),
+        lanes: Vec::new(),
+        items: Vec::new(),
+        conflicts: Vec::new(),
+        free: Vec::new(),
+    };
+    if window_start >= window_end {
+        return timeline;
+    }
+
+    for stream in streams {
+        // Greedy interval partitioning: each event takes the first lane that
+        // is free by its start, so a stream uses as many lanes as its
+        // deepest overlap.
+        let mut visible: Vec<(usize, TimeInterval)> = stream
+            .events
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| {
+                let bar = TimeInterval::from(e).clamp_to(&window)?;
+                (!bar.is_empty()).then_some((i, bar))
+            })
+            .collect();
+        visible.sort_by_key(|(i, bar)| (bar.start, bar.end, *i));
+
+        let first_lane = timeline.lanes.len();
+        let mut lane_ends: Vec<DateTime<Utc>> = Vec::new();
+        for (event_index, bar) in visible {
+            let sub_lane = match lane_ends.iter().position(|&end| end <= bar.start) {
+                Some(lane) => lane,
+                None => {
+                    lane_ends.push(bar.end);
+                    timeline.lanes.push(TimelineLane {
+                        stream_id: stream.stream_id.clone(),
+                        sub_lane: lane_ends.len() - 1,
+                    });
+                    lane_ends.len() - 1
+                }
+            };
+            lane_ends[sub_lane] = bar.end;
+            let event = &stream.events[event_index];
+            timeline.items.push(TimelineItem {
+                lane: first_lane + sub_lane,
+                stream_id: stream.stream_id.clone(),
+                event_index,
+                span: span(window_start, &bar),
+                clipped_start: event.start < window_start,
+                clipped_end: event.end > window_end,
+            });
+        }
+    }
+    timeline
+        .items
+        .sort_by_key(|item| (item.span.start, item.lane, item.span.end));
+
+    // Items are sorted by start, so each one can only overlap later items
+    // that start before it ends.
+    for (a, item) in timeline.items.iter().enumerate() {
+        let bar_a = interval_of(&item.span);
+        for (b, other) in timeline.items.iter().enumerate().skip(a + 1) {
+            if other.span.start >= item.span.end {
+                break;
+            }
+            if let Some(overlap) = bar_a.intersect(&interval_of(&other.span)) {
+                timeline.conflicts.push(ConflictMarker {
+                    item_a: a,
+                    item_b: b,
+                    span: span(window_start, &overlap),
+                });
+            }
+        }
+    }
+
+    let events: Vec<_> = streams.iter().flat_map(|s| s.events.clone()).collect();
+    timeline.free = find_free_slots(&events, window_start, window_end)
+        .iter()
+        .map(|slot| {
+            span(
+                window_start,
+                &TimeInterval {
+                    start: slot.start,
+                    end: slot.end,
+                },
+            )
+        })
+        .collect();
+    timeline
+}
+
+fn span(window_start: DateTime<Utc>, bar: &TimeInterval) -> TimelineSpan {
+    TimelineSpan {
+        start: bar.start,
+        end: bar.end,
+        offset_minutes: (bar.start - window_start).num_minutes(),
+        duration_minutes: bar.duration().num_minutes(),
+    }
+}
+
+fn interval_of(span: &TimelineSpan) -> TimeInterval {
+    TimeInterval {
+        start: span.start,
+        end: span.end,
+    }
+}
@@ -0,0 +1,565 @@
+//! Tool descriptors for RPC layers (e.g., an MCP server).
+//!
+//! Each [`Tool`] names one engine capability, describes it, generates the
+//! JSON Schema of its arguments from the same type the arguments are
+//! deserialized into, and dispatches a JSON call to the engine function.
+//! Servers list [`TOOLS`] and forward calls to [`dispatch`], so new engine
+//! features reach them by adding an entry here rather than a hand-written
+//! mapping elsewhere.
+//!
+//! Requires the `schemars` and `resolver` features.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use schemars::{schema_for, JsonSchema};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::availability::{find_first_free_across, merge_availability, EventStream, PrivacyLevel};
+use crate::business::BusinessCalendar;
+use crate::conflict::{conflict_matrix, find_conflicts, find_series_conflicts};
+use crate::error::{Result, TruthError};
+use crate::expander::{expand_rrule_with_exdates, ExpandedEvent, RecurringSeries};
+use crate::freebusy::{
+    find_first_free_slot, find_free_slots, is_slot_free, label_slots, FreeSlot, SlotLabelOptions,
+};
+use crate::provenance::{Provenance, Stamped};
+use crate::temporal::{
+    adjust_timestamp_with_mode, adjust_to_next_with_mode, compute_duration_with_mode,
+    convert_timezone_with_mode, dst_advisories, find_timezone, overlap_window,
+    resolve_relative_with_options, round_timestamp_with_mode, time_until, time_until_next,
+    week_number, world_clock, InputMode, ResolveOptions, RoundingGranularity, RoundingMode,
+    TimeUntilOptions, WeekScheme, WorkingHours,
+};
+
+/// One engine capability exposed as a tool.
+#[derive(Debug, Clone, Copy)]
+pub struct Tool {
+    /// Tool name; matches the engine function it calls.
+    pub name: &'static str,
+    /// One-line description for tool listings.
+    pub description: &'static str,
+    schema: fn() -> Value,
+    call: fn(Value) -> Result<Value>,
+}
+
+impl Tool {
+    /// JSON Schema of the tool's arguments object.
+    pub fn input_schema(&self) -> Value {
+        (self.schema)()
+    }
+
+    /// Run the tool on a JSON arguments object, returning the serialized result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TruthError::InvalidArguments`] if the arguments do not match
+    /// [`input_schema`](Self::input_schema), or whatever error the engine
+    /// function returns.
+    pub fn call(&self, arguments: Value) -> Result<Value> {
+        (self.call)(arguments)
+    }
+}
+
+/// Look up a tool by name.
+pub fn find_tool(name: &str) -> Option<&'static Tool> {
+    TOOLS.iter().find(|tool| tool.name == name)
+}
+
+/// Run the named tool on a JSON arguments object.
+///
+/// # Errors
+///
+/// Returns [`TruthError::UnknownTool`] for a name not in [`TOOLS`], otherwise
+/// as [`Tool::call`].
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use truth_engine::tools::dispatch;
+///
+/// let result = dispatch(
+///     "convert_timezone",
+///     json!({"datetime": "2026-03-15T14:00:00Z", "target_timezone": "Asia/Tokyo"}),
+/// )
+/// .unwrap();
+/// assert_eq!(result["local"], "2026-03-15T23:00:00+09:00");
+/// ```
+pub fn dispatch(name: &str, arguments: Value) -> Result<Value> {
+    find_tool(name)
+        .ok_or_else(|| TruthError::UnknownTool(name.to_string()))?
+        .call(arguments)
+}
+
+/// Run the named tool like [`dispatch`], returning
+/// `{"result": ..., "provenance": {...}}` with the arguments' hash as the
+/// options hash, for callers that cache tool results.
+///
+/// # Errors
+///
+/// As for [`dispatch`].
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use truth_engine::provenance::{options_hash, ENGINE_VERSION};
+/// use truth_engine::tools::dispatch_with_provenance;
+///
+/// let arguments = json!({"datetime": "2026-03-15T14:00:00Z", "target_timezone": "Asia/Tokyo"});
+/// let stamped = dispatch_with_provenance("convert_timezone", arguments.clone()).unwrap();
+/// assert_eq!(stamped["result"]["local"], "2026-03-15T23:00:00+09:00");
+/// assert_eq!(stamped["provenance"]["engine_version"], ENGINE_VERSION);
+/// assert_eq!(stamped["provenance"]["options_hash"], options_hash(&arguments));
+/// ```
+pub fn dispatch_with_provenance(name: &str, arguments: Value) -> Result<Value> {
+    let provenance = Provenance::for_options(&arguments);
+    let result = dispatch(name, arguments)?;
+    Ok(serde_json::to_value(Stamped { result, provenance }).expect("results serialize"))
+}
+
+fn schema<A: JsonSchema>() -> Value {
+    serde_json::to_value(schema_for!(A)).expect("schemas serialize")
+}
+
+/// Deserialize `arguments`, run `f`, and serialize its result.
+fn run<A: DeserializeOwned, R: Serialize>(
+    arguments: Value,
+    f: impl FnOnce(A) -> Result<R>,
+) -> Result<Value> {
+    let args = serde_json::from_value(arguments)
+        .map_err(|e| TruthError::InvalidArguments(e.to_string()))?;
+    Ok(serde_json::to_value(f(args)?).expect("results serialize"))
+}
+
+macro_rules! tool {
+    ($name:literal, $description:literal, $args:ty, $f:expr) => {
+        Tool {
+            name: $name,
+            description: $description,
+            schema: schema::<$args>,
+            call: |arguments| run::<$args, _>(arguments, $f),
+        }
+    };
+}
+
+/// Every tool, in listing order.
+pub static TOOLS: &[Tool] = &[
+    tool!(
+        "resolve_relative",
+        "Resolve a natural-language time expression (\"next Tuesday at 2pm\") to an instant",
+        ResolveArgs,
+        |a: ResolveArgs| resolve_relative_with_options(
+            a.anchor,
+            &a.expression,
+            &a.timezone,
+            &a.options
+        )
+    ),
+    tool!(
+        "convert_timezone",
+        "Convert a datetime to another timezone, with offset and DST status",
+        ConvertArgs,
+        |a: ConvertArgs| convert_timezone_with_mode(&a.datetime, &a.target_timezone, a.input_mode)
+    ),
+    tool!(
+        "compute_duration",
+        "Compute the duration between two datetimes",
+        DurationArgs,
+        |a: DurationArgs| compute_duration_with_mode(&a.start, &a.end, a.input_mode)
+    ),
+    tool!(
+        "adjust_timestamp",
+        "Shift a datetime by a compound duration (\"+1d2h30m\"), DST-aware",
+        AdjustArgs,
+        |a: AdjustArgs| adjust_timestamp_with_mode(
+            &a.datetime,
+            &a.adjustment,
+            &a.timezone,
+            a.input_mode
+        )
+    ),
+    tool!(
+        "round_timestamp",
+        "Round a datetime to a granularity in a timezone",
+        RoundArgs,
+        |a: RoundArgs| round_timestamp_with_mode(
+            &a.datetime,
+            a.granularity,
+            a.mode,
+            &a.timezone,
+            a.input_mode
+        )
+    ),
+    tool!(
+        "adjust_to_next",
+        "Move a datetime to the next matching boundary (\"monday\", \"09:00\")",
+        AdjustToNextArgs,
+        |a: AdjustToNextArgs| adjust_to_next_with_mode(
+            &a.datetime,
+            &a.target,
+            &a.timezone,
+            a.input_mode
+        )
+    ),
+    tool!(
+        "time_until",
+        "Time remaining from an anchor until a datetime or expression",
+        TimeUntilArgs,
+        |a: TimeUntilArgs| time_until(a.anchor, &a.target, &a.timezone, &a.options)
+    ),
+    tool!(
+        "time_until_next",
+        "Time remaining until the next occurrence of a recurring pattern",
+        TimeUntilNextArgs,
+        |a: TimeUntilNextArgs| time_until_next(
+            a.anchor,
+            &a.pattern,
+            &a.timezone,
+            a.business_calendar.as_ref()
+        )
+    ),
+    tool!(
+        "world_clock",
+        "Show one instant in several timezones, with working-hours status",
+        WorldClockArgs,
+        |a: WorldClockArgs| world_clock(
+            &a.datetime,
+            &a.timezones.iter().map(String::as_str).collect::<Vec<_>>(),
+            &a.working_hours
+        )
+    ),
+    tool!(
+        "overlap_window",
+        "Find the working hours shared by several timezones on a date",
+        OverlapArgs,
+        |a: OverlapArgs| overlap_window(
+            &a.timezones.iter().map(String::as_str).collect::<Vec<_>>(),
+            &a.working_hours,
+            &a.date.to_string()
+        )
+    ),
+    tool!(
+        "dst_advisories",
+        "Warn about clock changes that fall near a proposed weekly meeting or move it for some attendees",
+        DstAdvisoryArgs,
+        |a: DstAdvisoryArgs| dst_advisories(
+            &a.datetime,
+            &a.meeting_timezone,
+            &a.timezones.iter().map(String::as_str).collect::<Vec<_>>()
+        )
+    ),
+    tool!(
+        "expand_rrule",
+        "Expand an RFC 5545 recurrence rule into concrete event instances",
+        ExpandArgs,
+        |a: ExpandArgs| expand_rrule_with_exdates(
+            &a.rrule,
+            &a.dtstart,
+            a.duration_minutes,
+            &a.timezone,
+            a.until.as_deref(),
+            a.count,
+            &a.exdates.iter().map(String::as_str).collect::<Vec<_>>()
+        )
+    ),
+    tool!(
+        "find_conflicts",
+        "Find overlapping events between two schedules",
+        ConflictArgs,
+        |a: ConflictArgs| Ok(find_conflicts(&a.events_a, &a.events_b))
+    ),
+    tool!(
+        "find_series_conflicts",
+        "Find overlapping occurrences between two sets of recurring series, with moved and cancelled occurrences applied",
+        SeriesConflictArgs,
+        |a: SeriesConflictArgs| find_series_conflicts(
+            &a.series_a,
+            &a.series_b,
+            a.window_start,
+            a.window_end
+        )
+    ),
+    tool!(
+        "conflict_matrix",
+        "Count the overlapping minutes between every pair of calendars in a window",
+        ConflictMatrixArgs,
+        |a: ConflictMatrixArgs| Ok(conflict_matrix(&a.streams, a.window_start, a.window_end))
+    ),
+    tool!(
+        "find_free_slots",
+        "Compute the free slots between events in a window",
+        FreeSlotArgs,
+        |a: FreeSlotArgs| Ok(find_free_slots(&a.events, a.window_start, a.window_end))
+    ),
+    tool!(
+        "is_slot_free",
+        "Check whether a proposed time slot is free, and which events block it",
+        SlotFreeArgs,
+        |a: SlotFreeArgs| is_slot_free(a.start, a.end, &a.events)
+    ),
+    tool!(
+        "label_slots",
+        "Render free slots in several display timezones",
+        LabelSlotsArgs,
+        |a: LabelSlotsArgs| label_slots(&a.slots, &a.options)
+    ),
+    tool!(
+        "find_first_free_slot",
+        "Find the earliest free slot of at least a given length",
+        FirstFreeSlotArgs,
+        |a: FirstFreeSlotArgs| Ok(find_first_free_slot(
+            &a.events,
+            a.window_start,
+            a.window_end,
+            a.min_duration_minutes
+        ))
+    ),
+    tool!(
+        "merge_availability",
+        "Merge several calendars into unified busy and free time",
+        MergeArgs,
+        |a: MergeArgs| Ok(merge_availability(
+            &a.streams,
+            a.window_start,
+            a.window_end,
+            a.privacy
+        ))
+    ),
+    tool!(
+        "find_first_free_across",
+        "Find the earliest slot free in every calendar",
+        FirstFreeAcrossArgs,
+        |a: FirstFreeAcrossArgs| Ok(find_first_free_across(
+            &a.streams,
+            a.window_start,
+            a.window_end,
+            a.min_duration_minutes
+        ))
+    ),
+    tool!(
+        "find_timezone",
+        "Find IANA timezones matching a city, country, or zone name",
+        FindTimezoneArgs,
+        |a: FindTimezoneArgs| Ok(find_timezone(&a.query))
+    ),
+    tool!(
+        "week_number",
+        "Compute the week number of a date",
+        WeekNumberArgs,
+        |a: WeekNumberArgs| week_number(&a.date.to_string(), a.scheme)
+    ),
+];
+
+// ── Arguments ───────────────────────────────────────────────────────────────
+
+#[derive(Deserialize, JsonSchema)]
+struct ResolveArgs {
+    /// The instant "now" refers to.
+    anchor: DateTime<Utc>,
+    /// The expression, e.g. "next Tuesday at 2pm".
+    expression: String,
+    /// IANA timezone the expression is read in.
+    timezone: String,
+    #[serde(default)]
+    options: ResolveOptions,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ConvertArgs {
+    /// RFC 3339 datetime (or near-miss, with `input_mode: "Lenient"`).
+    datetime: String,
+    /// IANA timezone or unambiguous abbreviation.
+    target_timezone: String,
+    #[serde(default)]
+    input_mode: InputMode,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct DurationArgs {
+    start: String,
+    end: String,
+    #[serde(default)]
+    input_mode: InputMode,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct AdjustArgs {
+    datetime: String,
+    /// Compound duration, e.g. "+1d2h30m" or "-1w"; "+6bh" and "+30bm"
+    /// count working hours and minutes.
+    adjustment: String,
+    timezone: String,
+    #[serde(default)]
+    input_mode: InputMode,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct RoundArgs {
+    datetime: String,
+    granularity: RoundingGranularity,
+    #[serde(default)]
+    mode: RoundingMode,
+    timezone: String,
+    #[serde(default)]
+    input_mode: InputMode,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct AdjustToNextArgs {
+    datetime: String,
+    /// A weekday ("monday"), time of day ("09:00"), or both.
+    target: String,
+    timezone: String,
+    #[serde(default)]
+    input_mode: InputMode,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct TimeUntilArgs {
+    anchor: DateTime<Utc>,
+    /// A datetime or time expression.
+    target: String,
+    timezone: String,
+    #[serde(default)]
+    options: TimeUntilOptions,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct TimeUntilNextArgs {
+    anchor: DateTime<Utc>,
+    /// A recurring pattern, e.g. "every Monday at 9am".
+    pattern: String,
+    timezone: String,
+    #[serde(default)]
+    business_calendar: Option<BusinessCalendar>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct WorldClockArgs {
+    datetime: String,
+    timezones: Vec<String>,
+    #[serde(default)]
+    working_hours: WorkingHours,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct OverlapArgs {
+    timezones: Vec<String>,
+    date: NaiveDate,
+    #[serde(default)]
+    working_hours: WorkingHours,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct DstAdvisoryArgs {
+    /// The proposed meeting, RFC 3339.
+    datetime: String,
+    /// The zone whose wall-clock time the meeting keeps.
+    meeting_timezone: String,
+    /// The attendees' zones.
+    timezones: Vec<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ExpandArgs {
+    /// RFC 5545 RRULE, e.g. "FREQ=WEEKLY;BYDAY=TU,TH".
+    rrule: String,
+    /// Local start datetime, e.g. "2026-02-17T14:00:00".
+    dtstart: String,
+    duration_minutes: u32,
+    timezone: String,
+    /// Local datetime to expand until.
+    #[serde(default)]
+    until: Option<String>,
+    #[serde(default)]
+    count: Option<u32>,
+    /// Local datetimes of instances to exclude.
+    #[serde(default)]
+    exdates: Vec<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ConflictArgs {
+    events_a: Vec<ExpandedEvent>,
+    events_b: Vec<ExpandedEvent>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct SeriesConflictArgs {
+    series_a: Vec<RecurringSeries>,
+    series_b: Vec<RecurringSeries>,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ConflictMatrixArgs {
+    /// One stream per attendee or calendar.
+    streams: Vec<EventStream>,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct FreeSlotArgs {
+    events: Vec<ExpandedEvent>,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct SlotFreeArgs {
+    /// Start of the proposed slot.
+    start: DateTime<Utc>,
+    /// End of the proposed slot.
+    end: DateTime<Utc>,
+    events: Vec<ExpandedEvent>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct LabelSlotsArgs {
+    slots: Vec<FreeSlot>,
+    /// Display timezones, locale, and clock.
+    #[serde(flatten)]
+    options: SlotLabelOptions,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct FirstFreeSlotArgs {
+    events: Vec<ExpandedEvent>,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    min_duration_minutes: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct MergeArgs {
+    streams: Vec<EventStream>,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    #[serde(default)]
+    privacy: PrivacyLevel,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct FirstFreeAcrossArgs {
+    streams: Vec<EventStream>,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    min_duration_minutes: i64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct FindTimezoneArgs {
+    /// City, country, or zone name.
+    query: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct WeekNumberArgs {
+    date: NaiveDate,
+    #[serde(default)]
+    scheme: WeekScheme,
+}
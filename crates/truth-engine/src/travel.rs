@@ -0,0 +1,212 @@
+//! Travel time between event locations.
+//!
+//! Two back-to-back meetings across town conflict even though their times
+//! do not overlap. A [`TravelTimeProvider`] answers "how long from here to
+//! there, leaving then?"; [`find_travel_conflicts`] and [`travel_buffers`]
+//! consult it for events that carry a location, and
+//! [`plan_tasks_with_travel`](crate::planner::plan_tasks_with_travel) keeps
+//! room for the trip around each block it places.
+//!
+//! [`StaticTravelMatrix`] is a fixed table of travel times. Applications
+//! backed by a maps API implement the trait themselves (or pass a closure);
+//! the engine stays deterministic as long as the provider is.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::expander::ExpandedEvent;
+
+/// Travel time between two locations.
+///
+/// Implemented for any `Fn(&str, &str, DateTime<Utc>) -> Option<Duration>` closure.
+pub trait TravelTimeProvider: Send + Sync {
+    /// How long it takes to get from `from` to `to`, leaving at `departure`,
+    /// or `None` if the route is unknown. Callers treat unknown routes as
+    /// needing no travel time.
+    fn travel_time(&self, from: &str, to: &str, departure: DateTime<Utc>) -> Option<Duration>;
+}
+
+impl<F> TravelTimeProvider for F
+where
+    F: Fn(&str, &str, DateTime<Utc>) -> Option<Duration> + Send + Sync,
+{
+    fn travel_time(&self, from: &str, to: &str, departure: DateTime<Utc>) -> Option<Duration> {
+        self(from, to, departure)
+    }
+}
+
+/// A fixed table of travel times in minutes, independent of departure time.
+///
+/// Staying at the same location always takes zero minutes.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, Utc};
+/// use truth_engine::travel::{StaticTravelMatrix, TravelTimeProvider};
+///
+/// let mut matrix = StaticTravelMatrix::new();
+/// matrix.insert_both("HQ", "Airport", 40).insert("HQ", "Cafe", 10);
+/// assert_eq!(matrix.travel_time("Airport", "HQ", Utc::now()), Some(Duration::minutes(40)));
+/// assert_eq!(matrix.travel_time("Cafe", "HQ", Utc::now()), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StaticTravelMatrix {
+    /// Minutes by origin, then destination.
+    minutes: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+impl StaticTravelMatrix {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the travel time from `from` to `to`.
+    pub fn insert(&mut self, from: &str, to: &str, minutes: u32) -> &mut Self {
+        self.minutes
+            .entry(from.to_string())
+            .or_default()
+            .insert(to.to_string(), minutes);
+        self
+    }
+
+    /// Set the travel time in both directions.
+    pub fn insert_both(&mut self, a: &str, b: &str, minutes: u32) -> &mut Self {
+        self.insert(a, b, minutes).insert(b, a, minutes)
+    }
+}
+
+impl TravelTimeProvider for StaticTravelMatrix {
+    fn travel_time(&self, from: &str, to: &str, _departure: DateTime<Utc>) -> Option<Duration> {
+        if from == to {
+            return Some(Duration::zero());
+        }
+        self.minutes
+            .get(from)?
+            .get(to)
+            .map(|m| Duration::minutes(*m as i64))
+    }
+}
+
+/// An event and where it happens.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LocatedEvent {
+    pub event: ExpandedEvent,
+    /// `None` for events with no place (calls, focus time); they never need travel.
+    pub location: Option<String>,
+}
+
+/// Consecutive located events without enough time between them to travel.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TravelConflict {
+    /// Index into the input of the event travelled from.
+    pub from_index: usize,
+    /// Index into the input of the event travelled to.
+    pub to_index: usize,
+    /// Minutes between the first event's end and the second's start
+    /// (negative when they overlap).
+    pub gap_minutes: i64,
+    pub travel_minutes: i64,
+}
+
+/// Pairs of consecutive located events (by start) whose gap is shorter than
+/// the travel time between their locations, leaving when the first ends.
+///
+/// Events without a location are skipped: travel is measured between the
+/// located events on either side of them. Unknown routes never conflict.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::travel::{find_travel_conflicts, LocatedEvent, StaticTravelMatrix};
+/// use truth_engine::ExpandedEvent;
+///
+/// let at = |h, m| Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap();
+/// let meeting = |start, end, place: &str| LocatedEvent {
+///     event: ExpandedEvent::new(start, end),
+///     location: Some(place.to_string()),
+/// };
+/// let events = [meeting(at(9, 0), at(10, 0), "HQ"), meeting(at(10, 15), at(11, 0), "Client")];
+/// let mut matrix = StaticTravelMatrix::new();
+/// matrix.insert_both("HQ", "Client", 30);
+///
+/// let conflicts = find_travel_conflicts(&events, &matrix);
+/// assert_eq!((conflicts[0].gap_minutes, conflicts[0].travel_minutes), (15, 30));
+/// ```
+pub fn find_travel_conflicts(
+    events: &[LocatedEvent],
+    provider: &dyn TravelTimeProvider,
+) -> Vec<TravelConflict> {
+    legs(events, provider)
+        .filter(|leg| leg.gap < leg.travel)
+        .map(|leg| TravelConflict {
+            from_index: leg.from,
+            to_index: leg.to,
+            gap_minutes: leg.gap.num_minutes(),
+            travel_minutes: leg.travel.num_minutes(),
+        })
+        .collect()
+}
+
+/// Travel between consecutive located events, as busy time ending when each
+/// trip's destination event starts.
+///
+/// Add these to the busy events given to
+/// [`find_free_slots`](crate::find_free_slots) or
+/// [`merge_availability`](crate::merge_availability) so nothing is booked
+/// into the trip. Zero-length and unknown trips are omitted.
+pub fn travel_buffers(
+    events: &[LocatedEvent],
+    provider: &dyn TravelTimeProvider,
+) -> Vec<ExpandedEvent> {
+    legs(events, provider)
+        .filter(|leg| leg.travel > Duration::zero())
+        .map(|leg| {
+            let start = events[leg.to].event.start;
+            ExpandedEvent::new(start - leg.travel, start)
+        })
+        .collect()
+}
+
+/// One trip between consecutive located events.
+struct Leg {
+    from: usize,
+    to: usize,
+    gap: Duration,
+    travel: Duration,
+}
+
+fn legs<'a>(
+    events: &'a [LocatedEvent],
+    provider: &'a dyn TravelTimeProvider,
+) -> impl Iterator<Item = Leg> + 'a {
+    let mut located: Vec<(usize, &str)> = events
+        .iter()
+        .enumerate()
+        .filter_map(|(i, e)| e.location.as_deref().map(|l| (i, l)))
+        .collect();
+    located.sort_by_key(|(i, _)| (events[*i].event.start, events[*i].event.end, *i));
+    let pairs: Vec<_> = located.windows(2).map(|w| (w[0], w[1])).collect();
+    pairs
+        .into_iter()
+        .filter_map(move |((from, here), (to, there))| {
+            let departure = events[from].event.end;
+            let travel = provider.travel_time(here, there, departure)?;
+            Some(Leg {
+                from,
+                to,
+                gap: events[to].event.start - departure,
+                travel,
+            })
+        })
+}
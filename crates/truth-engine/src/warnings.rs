@@ -0,0 +1,71 @@
+//! Non-fatal issues reported alongside a result.
+//!
+//! Some inputs have an answer, just not exactly the one asked for: an
+//! expansion cut off at its instance limit, an occurrence moved by a DST
+//! transition, an EXDATE that removed nothing or was listed twice. `_with_warnings` variants
+//! return their result in a [`Warned`] listing these, rather than dropping
+//! them silently or failing outright.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// What kind of non-fatal issue a [`Warning`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum WarningKind {
+    /// The result stopped at a size limit; there is more beyond it.
+    Truncated,
+    /// A local time did not exist (clocks sprang forward) and was moved
+    /// past the gap.
+    DstShifted,
+    /// A local time occurred twice (clocks fell back); the earlier instant
+    /// was used.
+    DstAmbiguous,
+    /// An EXDATE matched no occurrence, so it excluded nothing.
+    UnmatchedExdate,
+    /// A date in an EXDATE or RDATE list could not be parsed and was dropped.
+    InvalidDate,
+    /// A date was listed twice, or an RDATE repeats an occurrence the rule
+    /// already produces; the copy was dropped.
+    DuplicateDate,
+    /// An EXDATE or RDATE list mixed value types (dates, local times, UTC
+    /// times), or used dates for a series of date-times.
+    MixedValueTypes,
+}
+
+/// A non-fatal issue with a result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Warning {
+    pub kind: WarningKind,
+    /// Human-readable explanation.
+    pub message: String,
+}
+
+impl Warning {
+    pub(crate) fn new(kind: WarningKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+/// A result with the non-fatal issues found computing it, serialized as
+/// `{"result": ..., "warnings": [...]}`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Warned<T> {
+    pub result: T,
+    pub warnings: Vec<Warning>,
+}
+
+impl<T> Warned<T> {
+    /// Whether any warning is of `kind`.
+    pub fn has(&self, kind: WarningKind) -> bool {
+        self.warnings.iter().any(|w| w.kind == kind)
+    }
+}
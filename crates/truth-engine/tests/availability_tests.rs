@@ -2,19 +2,21 @@
 //!
 //! Follows TDD: tests were written first (RED), then the implementation (GREEN).
 
-use chrono::{TimeZone, Utc};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use proptest::prelude::*;
+#[cfg(feature = "serde")]
+use truth_engine::availability::UnifiedAvailability;
 use truth_engine::availability::{
-    find_first_free_across, merge_availability, EventStream, PrivacyLevel,
+    core_hours, find_first_free_across, merge_availability, merge_availability_chunked,
+    ChunkedAvailability, CoreHoursBlock, CoreHoursOptions, EventStream, Participant, PrivacyLevel,
 };
+use truth_engine::business::BusinessCalendar;
 use truth_engine::expander::ExpandedEvent;
 
 // ── Helpers ─────────────────────────────────────────────────────────────────
 
 fn event(start: &str, end: &str) -> ExpandedEvent {
-    ExpandedEvent {
-        start: start.parse().unwrap(),
-        end: end.parse().unwrap(),
-    }
+    ExpandedEvent::new(start.parse().unwrap(), end.parse().unwrap())
 }
 
 fn stream(id: &str, events: Vec<ExpandedEvent>) -> EventStream {
@@ -391,3 +393,340 @@ fn find_first_free_across_no_qualifying_slot() {
     let slot = find_first_free_across(&[stream_a], window_start, window_end, 30);
     assert!(slot.is_none());
 }
+
+// ── Test 13: Results round-trip through JSON ────────────────────────────────
+
+#[cfg(feature = "serde")]
+#[test]
+fn availability_round_trips_through_json() {
+    let stream_a = stream(
+        "work",
+        vec![event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z")],
+    );
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+
+    let result = merge_availability(
+        std::slice::from_ref(&stream_a),
+        window_start,
+        window_end,
+        PrivacyLevel::Full,
+    );
+    let json = serde_json::to_string(&result).unwrap();
+    assert_eq!(
+        serde_json::from_str::<UnifiedAvailability>(&json).unwrap(),
+        result
+    );
+
+    let json = serde_json::to_string(&stream_a).unwrap();
+    assert_eq!(
+        serde_json::from_str::<EventStream>(&json).unwrap(),
+        stream_a
+    );
+}
+
+// ── Test 14: Chunked merging matches the whole-window merge ─────────────────
+
+/// `streams` cut down to the events overlapping `from..to`, as a loader
+/// reading one chunk from storage would return them.
+fn chunk_of(streams: &[EventStream], from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<EventStream> {
+    streams
+        .iter()
+        .map(|s| EventStream {
+            stream_id: s.stream_id.clone(),
+            events: s
+                .events
+                .iter()
+                .filter(|e| e.start < to && from < e.end)
+                .cloned()
+                .collect(),
+        })
+        .filter(|s| !s.events.is_empty())
+        .collect()
+}
+
+#[test]
+fn chunked_merge_joins_blocks_across_chunk_boundaries() {
+    let streams = vec![
+        stream(
+            "work",
+            vec![event("2026-03-16T22:00:00Z", "2026-03-17T02:00:00Z")],
+        ),
+        stream(
+            "home",
+            vec![event("2026-03-17T01:00:00Z", "2026-03-17T03:00:00Z")],
+        ),
+    ];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 19, 0, 0, 0).unwrap();
+
+    let mut chunked = ChunkedAvailability::new(
+        window_start,
+        window_end,
+        PrivacyLevel::Full,
+        Duration::days(1),
+    )
+    .unwrap();
+    // The first day's block reaches midnight, so it stays open.
+    let (from, to) = chunked.next_chunk().unwrap();
+    assert_eq!((from, to), (window_start, window_start + Duration::days(1)));
+    let first = chunked.push(&chunk_of(&streams, from, to));
+    assert!(first.busy.is_empty());
+    assert_eq!(first.free.len(), 1);
+
+    let (from, to) = chunked.next_chunk().unwrap();
+    let second = chunked.push(&chunk_of(&streams, from, to));
+    assert_eq!(second.busy.len(), 1);
+    assert_eq!(
+        second.busy[0].start,
+        "2026-03-16T22:00:00Z".parse::<DateTime<Utc>>().unwrap()
+    );
+    assert_eq!(second.busy[0].source_count, 2);
+
+    let (from, to) = chunked.next_chunk().unwrap();
+    assert!(chunked.push(&chunk_of(&streams, from, to)).busy.is_empty());
+    assert_eq!(chunked.next_chunk(), None);
+    let rest = chunked.finish();
+    assert_eq!(rest.free.len(), 1);
+    assert_eq!(rest.free[0].end, window_end);
+
+    assert!(matches!(
+        ChunkedAvailability::new(
+            window_start,
+            window_end,
+            PrivacyLevel::Full,
+            Duration::zero()
+        ),
+        Err(truth_engine::TruthError::InvalidArguments(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn chunked_merge_matches_merge_availability(
+        raw in prop::collection::vec((0usize..4, 0i64..3000, 1i64..400), 0..40),
+        (w_start, w_len) in (-100i64..2000, 0i64..3000),
+        chunk_minutes in 1i64..900,
+        full in any::<bool>(),
+    ) {
+        let base = Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap();
+        let at = |minute: i64| base + Duration::minutes(minute);
+        let mut streams: Vec<EventStream> =
+            (0..4).map(|n| stream(&format!("s{n}"), Vec::new())).collect();
+        for (n, start, len) in raw {
+            streams[n].events.push(ExpandedEvent::new(at(start), at(start + len)));
+        }
+        let (window_start, window_end) = (at(w_start), at(w_start + w_len));
+        let privacy = if full { PrivacyLevel::Full } else { PrivacyLevel::Opaque };
+
+        let chunked = merge_availability_chunked(
+            window_start,
+            window_end,
+            privacy,
+            Duration::minutes(chunk_minutes),
+            |from, to| Ok(chunk_of(&streams, from, to)),
+        )
+        .unwrap();
+        prop_assert_eq!(chunked, merge_availability(&streams, window_start, window_end, privacy));
+    }
+}
+
+// ── Test 15: Org-wide merge agrees with free/busy over every event ──────────
+//
+// Large enough that, with the `rayon` feature, clipping, sorting, and source
+// attribution all run in parallel; the results must not depend on it.
+
+#[test]
+fn org_wide_merge_matches_free_busy_over_all_events() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+    let window_end = window_start + Duration::days(14);
+    let streams: Vec<EventStream> = (0..300i64)
+        .map(|p| EventStream {
+            stream_id: format!("person-{p}"),
+            events: (0..14i64)
+                .flat_map(|d| {
+                    (0..3i64).map(move |m| {
+                        let start = window_start
+                            + Duration::days(d)
+                            + Duration::minutes(6 * 60 + (p * 13 + m * 211) % (14 * 60));
+                        ExpandedEvent::new(start, start + Duration::minutes(15 + (p + m) % 3 * 15))
+                    })
+                })
+                .collect(),
+        })
+        .collect();
+    let all: Vec<ExpandedEvent> = streams.iter().flat_map(|s| s.events.clone()).collect();
+
+    let result = merge_availability(&streams, window_start, window_end, PrivacyLevel::Full);
+    assert_eq!(
+        result.free,
+        truth_engine::find_free_slots(&all, window_start, window_end)
+    );
+
+    for block in &result.busy {
+        let sources = streams
+            .iter()
+            .filter(|s| {
+                s.events
+                    .iter()
+                    .any(|e| e.start < block.end && block.start < e.end)
+            })
+            .count();
+        assert_eq!(block.source_count, sources);
+    }
+}
+
+// ── Test 16: Proposed slots checked against merged busy blocks ──────────────
+
+#[test]
+fn slot_checks_use_the_merged_busy_blocks() {
+    let streams = vec![
+        stream(
+            "work",
+            vec![
+                event("2026-03-02T09:00:00Z", "2026-03-02T10:00:00Z"),
+                event("2026-03-02T14:00:00Z", "2026-03-02T15:00:00Z"),
+            ],
+        ),
+        stream(
+            "personal",
+            vec![event("2026-03-02T09:30:00Z", "2026-03-02T11:00:00Z")],
+        ),
+    ];
+    let at = |h, m| Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap();
+    let availability = merge_availability(&streams, at(8, 0), at(18, 0), PrivacyLevel::Full);
+
+    let check = availability.is_slot_free(at(10, 30), at(14, 30)).unwrap();
+    assert!(!check.free);
+    let blocking: Vec<_> = check
+        .blocking
+        .iter()
+        .map(|b| (b.start, b.end, b.source_count))
+        .collect();
+    assert_eq!(
+        blocking,
+        vec![(at(9, 0), at(11, 0), 2), (at(14, 0), at(15, 0), 1)]
+    );
+    assert!(
+        availability
+            .is_slot_free(at(11, 0), at(14, 0))
+            .unwrap()
+            .free
+    );
+    assert!(availability.is_slot_free(at(8, 0), at(9, 0)).unwrap().free);
+
+    // Outside the window availability is unknown.
+    for (start, end) in [(at(7, 0), at(8, 30)), (at(17, 0), at(19, 0))] {
+        assert!(matches!(
+            availability.is_slot_free(start, end),
+            Err(truth_engine::TruthError::InvalidArguments(_))
+        ));
+    }
+}
+
+// ── Test 17: Group core hours from two weeks of history ─────────────────────
+
+#[test]
+fn core_hours_need_a_quorum_free_in_most_weeks() {
+    let participant = |id: &str, timezone: &str, events| Participant {
+        stream: stream(id, events),
+        calendar: BusinessCalendar {
+            timezone: timezone.to_string(),
+            ..BusinessCalendar::default()
+        },
+    };
+    // February 2026: London works 09:00–17:00 UTC, New York 14:00–22:00 UTC.
+    let group = [
+        participant(
+            "alice",
+            "Europe/London",
+            vec![
+                event("2026-02-03T15:00:00Z", "2026-02-03T16:00:00Z"),
+                event("2026-02-10T15:00:00Z", "2026-02-10T16:00:00Z"),
+            ],
+        ),
+        participant(
+            "bob",
+            "America/New_York",
+            vec![event("2026-02-04T14:00:00Z", "2026-02-04T15:00:00Z")],
+        ),
+    ];
+    let start = Utc.with_ymd_and_hms(2026, 2, 2, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2026, 2, 16, 0, 0, 0).unwrap();
+    let spans = |options: &CoreHoursOptions| -> Vec<(chrono::Weekday, String, i64, usize)> {
+        core_hours(&group, start, end, options)
+            .unwrap()
+            .into_iter()
+            .map(|b: CoreHoursBlock| {
+                (
+                    b.weekday,
+                    b.start.format("%H:%M").to_string(),
+                    b.duration_minutes,
+                    b.participants.len(),
+                )
+            })
+            .collect()
+    };
+    use chrono::Weekday::*;
+    let block = |day, start: &str, minutes, people| (day, start.to_string(), minutes, people);
+
+    // Alice's weekly Tuesday meeting splits the day; Bob's one Wednesday
+    // meeting makes him free in only half the weeks.
+    assert_eq!(
+        spans(&CoreHoursOptions::default()),
+        vec![
+            block(Mon, "14:00", 180, 2),
+            block(Tue, "14:00", 60, 2),
+            block(Tue, "16:00", 60, 2),
+            block(Wed, "15:00", 120, 2),
+            block(Thu, "14:00", 180, 2),
+            block(Fri, "14:00", 180, 2),
+        ]
+    );
+    let lenient = CoreHoursOptions {
+        min_free_ratio: 0.5,
+        ..CoreHoursOptions::default()
+    };
+    assert_eq!(spans(&lenient)[3], block(Wed, "14:00", 180, 2));
+
+    // With a quorum of one, each participant's own hours count too.
+    let anyone = CoreHoursOptions {
+        quorum: Some(1),
+        ..CoreHoursOptions::default()
+    };
+    assert_eq!(
+        spans(&anyone)[..3],
+        [
+            block(Mon, "09:00", 300, 1),
+            block(Mon, "14:00", 180, 2),
+            block(Mon, "17:00", 300, 1),
+        ]
+    );
+
+    // The same hours read in New York.
+    let new_york = CoreHoursOptions {
+        timezone: "America/New_York".to_string(),
+        ..CoreHoursOptions::default()
+    };
+    assert_eq!(spans(&new_york)[0], block(Mon, "09:00", 180, 2));
+
+    for options in [
+        CoreHoursOptions {
+            quorum: Some(3),
+            ..CoreHoursOptions::default()
+        },
+        CoreHoursOptions {
+            slot_minutes: 7,
+            ..CoreHoursOptions::default()
+        },
+        CoreHoursOptions {
+            min_free_ratio: 1.5,
+            ..CoreHoursOptions::default()
+        },
+    ] {
+        assert!(matches!(
+            core_hours(&group, start, end, &options),
+            Err(truth_engine::TruthError::InvalidArguments(_))
+        ));
+    }
+}
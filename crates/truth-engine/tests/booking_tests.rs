@@ -0,0 +1,218 @@
+//! Tests for the booking ledger.
+
+use chrono::{DateTime, TimeZone, Utc};
+use truth_engine::booking::{BookingLedger, BookingState, BOOKINGS_STREAM};
+use truth_engine::{EventStream, ExpandedEvent, PrivacyLevel, TruthError};
+
+fn utc(h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap()
+}
+
+fn slot(start: DateTime<Utc>, end: DateTime<Utc>) -> ExpandedEvent {
+    ExpandedEvent::new(start, end)
+}
+
+#[test]
+fn hold_then_confirm() {
+    let mut ledger = BookingLedger::new();
+    let booking = ledger
+        .hold("a", slot(utc(14, 0), utc(15, 0)), utc(9, 0), utc(8, 0), &[])
+        .unwrap();
+    assert_eq!(
+        booking.state,
+        BookingState::Held {
+            expires_at: utc(9, 0)
+        }
+    );
+    assert_eq!(
+        ledger.confirm("a", utc(8, 30)).unwrap().state,
+        BookingState::Confirmed
+    );
+    // Confirmed bookings never expire, and confirming again is harmless.
+    assert!(ledger.expire(utc(23, 0)).is_empty());
+    assert_eq!(
+        ledger.confirm("a", utc(23, 0)).unwrap().state,
+        BookingState::Confirmed
+    );
+    assert_eq!(ledger.active(utc(23, 0)).len(), 1);
+}
+
+#[test]
+fn lapsed_holds_cannot_be_confirmed() {
+    let mut ledger = BookingLedger::new();
+    ledger
+        .hold("a", slot(utc(14, 0), utc(15, 0)), utc(9, 0), utc(8, 0), &[])
+        .unwrap();
+    assert!(matches!(
+        ledger.confirm("a", utc(9, 0)),
+        Err(TruthError::Booking(_))
+    ));
+    assert_eq!(ledger.get("a").unwrap().state, BookingState::Expired);
+    assert!(matches!(
+        ledger.release("a", utc(9, 0)),
+        Err(TruthError::Booking(_))
+    ));
+}
+
+#[test]
+fn lapsed_holds_expire_instead_of_releasing() {
+    let mut ledger = BookingLedger::new();
+    ledger
+        .hold("a", slot(utc(14, 0), utc(15, 0)), utc(9, 0), utc(8, 0), &[])
+        .unwrap();
+    // The deadline passed, but `expire` has not recorded it yet.
+    assert!(matches!(
+        ledger.release("a", utc(9, 30)),
+        Err(TruthError::Booking(_))
+    ));
+    assert_eq!(ledger.get("a").unwrap().state, BookingState::Expired);
+
+    // Confirmed bookings release whenever asked.
+    ledger
+        .hold("b", slot(utc(16, 0), utc(17, 0)), utc(9, 0), utc(8, 0), &[])
+        .unwrap();
+    ledger.confirm("b", utc(8, 30)).unwrap();
+    assert_eq!(
+        ledger.release("b", utc(23, 0)).unwrap().state,
+        BookingState::Released
+    );
+}
+
+#[test]
+fn overlapping_holds_are_rejected_until_released_or_expired() {
+    let mut ledger = BookingLedger::new();
+    ledger
+        .hold("a", slot(utc(14, 0), utc(15, 0)), utc(9, 0), utc(8, 0), &[])
+        .unwrap();
+    let err = ledger
+        .hold(
+            "b",
+            slot(utc(14, 30), utc(15, 30)),
+            utc(9, 0),
+            utc(8, 0),
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("'a'"));
+    // Adjacent slots are fine.
+    ledger
+        .hold("c", slot(utc(15, 0), utc(16, 0)), utc(9, 0), utc(8, 0), &[])
+        .unwrap();
+
+    // An expired hold no longer blocks, even before `expire` records it.
+    ledger
+        .hold(
+            "b",
+            slot(utc(14, 30), utc(15, 0)),
+            utc(10, 0),
+            utc(9, 0),
+            &[],
+        )
+        .unwrap();
+    ledger.release("b", utc(9, 0)).unwrap();
+    assert_eq!(ledger.get("b").unwrap().state, BookingState::Released);
+    assert!(matches!(
+        ledger.hold(
+            "b",
+            slot(utc(17, 0), utc(18, 0)),
+            utc(10, 0),
+            utc(9, 0),
+            &[]
+        ),
+        Err(TruthError::Booking(_))
+    ));
+    assert_eq!(
+        ledger.expire(utc(9, 0)),
+        vec!["a".to_string(), "c".to_string()]
+    );
+}
+
+#[test]
+fn holds_respect_calendar_busy_time() {
+    let mut ledger = BookingLedger::new();
+    let busy = [EventStream {
+        stream_id: "work".to_string(),
+        events: vec![slot(utc(13, 0), utc(14, 15))],
+    }];
+    let err = ledger
+        .hold(
+            "a",
+            slot(utc(14, 0), utc(15, 0)),
+            utc(9, 0),
+            utc(8, 0),
+            &busy,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("'work'"));
+    assert!(ledger.get("a").is_none());
+}
+
+#[test]
+fn invalid_holds_are_rejected() {
+    let mut ledger = BookingLedger::new();
+    assert!(matches!(
+        ledger.hold("a", slot(utc(15, 0), utc(14, 0)), utc(9, 0), utc(8, 0), &[]),
+        Err(TruthError::InvalidArguments(_))
+    ));
+    assert!(matches!(
+        ledger.hold("a", slot(utc(14, 0), utc(15, 0)), utc(8, 0), utc(8, 0), &[]),
+        Err(TruthError::InvalidArguments(_))
+    ));
+    assert!(matches!(
+        ledger.confirm("missing", utc(8, 0)),
+        Err(TruthError::Booking(_))
+    ));
+}
+
+#[test]
+fn active_bookings_block_availability() {
+    let mut ledger = BookingLedger::new();
+    ledger
+        .hold(
+            "held",
+            slot(utc(10, 0), utc(11, 0)),
+            utc(9, 0),
+            utc(8, 0),
+            &[],
+        )
+        .unwrap();
+    ledger
+        .hold(
+            "kept",
+            slot(utc(13, 0), utc(14, 0)),
+            utc(9, 0),
+            utc(8, 0),
+            &[],
+        )
+        .unwrap();
+    ledger.confirm("kept", utc(8, 0)).unwrap();
+    let work = [EventStream {
+        stream_id: "work".to_string(),
+        events: vec![slot(utc(13, 30), utc(15, 0))],
+    }];
+
+    let before = ledger.availability(&work, utc(8, 0), utc(18, 0), PrivacyLevel::Full, utc(8, 0));
+    let busy: Vec<_> = before.busy.iter().map(|b| (b.start, b.end)).collect();
+    assert_eq!(
+        busy,
+        vec![(utc(10, 0), utc(11, 0)), (utc(13, 0), utc(15, 0))]
+    );
+    assert_eq!(before.busy[1].source_count, 2);
+
+    // After the hold's deadline only the confirmed booking blocks time.
+    let after = ledger.availability(&work, utc(8, 0), utc(18, 0), PrivacyLevel::Full, utc(9, 0));
+    assert_eq!(after.busy.len(), 1);
+    assert_eq!(ledger.stream(utc(9, 0)).stream_id, BOOKINGS_STREAM);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ledger_round_trips_through_json() {
+    let mut ledger = BookingLedger::new();
+    ledger
+        .hold("a", slot(utc(14, 0), utc(15, 0)), utc(9, 0), utc(8, 0), &[])
+        .unwrap();
+    let json = serde_json::to_string(&ledger).unwrap();
+    let restored: BookingLedger = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, ledger);
+}
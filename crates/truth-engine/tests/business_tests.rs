@@ -0,0 +1,180 @@
+//! Tests for business calendars and their consumers.
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use truth_engine::availability::{merge_business_availability, EventStream, PrivacyLevel};
+use truth_engine::business::{BusinessCalendar, Closure};
+use truth_engine::freebusy::find_business_free_slots;
+use truth_engine::planner::{plan_tasks, PlanOptions, Task};
+#[cfg(feature = "resolver")]
+use truth_engine::temporal::{resolve_relative_with_options, ResolveOptions};
+use truth_engine::{ExpandedEvent, TruthError};
+
+fn utc(mo: u32, d: u32, h: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, mo, d, h, 0, 0).unwrap()
+}
+
+fn date(mo: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, mo, d).unwrap()
+}
+
+/// New York hours, with Good Friday (April 3) off and a closure the week of
+/// April 6–10.
+fn new_york() -> BusinessCalendar {
+    BusinessCalendar {
+        timezone: "America/New_York".to_string(),
+        holidays: [date(4, 3)].into(),
+        closures: vec![Closure {
+            name: "Spring break".to_string(),
+            start: date(4, 6),
+            end: date(4, 10),
+        }],
+        ..BusinessCalendar::default()
+    }
+}
+
+#[test]
+fn business_days_skip_weekends_holidays_and_closures() {
+    let calendar = new_york();
+    assert!(calendar.is_business_day(date(4, 2)));
+    assert!(!calendar.is_business_day(date(4, 3)));
+    assert!(!calendar.is_business_day(date(4, 4)));
+    assert!(!calendar.is_business_day(date(4, 8)));
+
+    assert_eq!(calendar.add_business_days(date(4, 2), 1), Some(date(4, 13)));
+    assert_eq!(
+        calendar.add_business_days(date(4, 13), -1),
+        Some(date(4, 2))
+    );
+    assert_eq!(calendar.add_business_days(date(4, 2), 0), Some(date(4, 2)));
+    assert_eq!(calendar.business_days_between(date(3, 30), date(4, 14)), 5);
+    assert_eq!(calendar.business_days_between(date(4, 14), date(3, 30)), -5);
+
+    let never = BusinessCalendar {
+        working_hours: truth_engine::temporal::WorkingHours {
+            days: vec![],
+            ..Default::default()
+        },
+        ..BusinessCalendar::default()
+    };
+    assert_eq!(never.add_business_days(date(4, 2), 1), None);
+}
+
+#[test]
+fn working_intervals_follow_local_hours_across_dst() {
+    let calendar = new_york();
+    // Friday March 6 is EST, Monday March 9 is EDT.
+    let intervals = calendar
+        .working_intervals(utc(3, 6, 0), utc(3, 10, 0))
+        .unwrap();
+    let spans: Vec<_> = intervals.iter().map(|i| (i.start, i.end)).collect();
+    assert_eq!(
+        spans,
+        vec![
+            (utc(3, 6, 14), utc(3, 6, 22)),
+            (utc(3, 9, 13), utc(3, 9, 21))
+        ]
+    );
+    assert!(calendar.is_open(utc(3, 9, 13)).unwrap());
+    assert!(!calendar.is_open(utc(3, 9, 21)).unwrap());
+    assert!(!calendar.is_open(utc(4, 3, 15)).unwrap());
+    assert_eq!(
+        calendar
+            .business_seconds_between(utc(3, 6, 20), utc(3, 9, 15))
+            .unwrap(),
+        4 * 3600
+    );
+
+    let bad = BusinessCalendar {
+        timezone: "Mars/Olympus".to_string(),
+        ..BusinessCalendar::default()
+    };
+    assert!(matches!(
+        bad.is_open(utc(3, 9, 13)),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+}
+
+#[test]
+fn free_slots_and_availability_exclude_non_working_time() {
+    let calendar = new_york();
+    let events = vec![ExpandedEvent::new(utc(4, 2, 14), utc(4, 2, 18))];
+    // Thursday to the following Monday: only Thursday afternoon is free.
+    let free = find_business_free_slots(&events, utc(4, 2, 0), utc(4, 13, 0), &calendar).unwrap();
+    let spans: Vec<_> = free.iter().map(|s| (s.start, s.end)).collect();
+    assert_eq!(
+        spans,
+        vec![
+            (utc(4, 2, 13), utc(4, 2, 14)),
+            (utc(4, 2, 18), utc(4, 2, 21))
+        ]
+    );
+    assert_eq!(free[1].duration_minutes, 180);
+
+    let streams = [EventStream {
+        stream_id: "work".to_string(),
+        events,
+    }];
+    let availability = merge_business_availability(
+        &streams,
+        utc(4, 2, 0),
+        utc(4, 13, 0),
+        PrivacyLevel::Opaque,
+        &calendar,
+    )
+    .unwrap();
+    assert_eq!(availability.busy.len(), 1);
+    assert_eq!(availability.free, free);
+}
+
+#[cfg(feature = "resolver")]
+#[test]
+fn resolve_relative_uses_the_business_calendar() {
+    let options = ResolveOptions {
+        business_calendar: Some(new_york()),
+        ..Default::default()
+    };
+    // Thursday April 2: the next business day is after Good Friday and the closure.
+    let anchor = utc(4, 2, 15);
+    let r = resolve_relative_with_options(anchor, "next business day", "UTC", &options).unwrap();
+    assert_eq!(r.resolved_utc, "2026-04-13T09:00:00+00:00");
+    let r = resolve_relative_with_options(
+        anchor,
+        "2 business days before next business day",
+        "UTC",
+        &options,
+    )
+    .unwrap();
+    assert_eq!(r.resolved_utc, "2026-04-01T09:00:00+00:00");
+    let r =
+        resolve_relative_with_options(anchor, "first business day of next month", "UTC", &options);
+    assert_eq!(r.unwrap().resolved_utc, "2026-05-01T09:00:00+00:00");
+
+    // Without it, Friday is a business day.
+    let r = resolve_relative_with_options(
+        anchor,
+        "next business day",
+        "UTC",
+        &ResolveOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(r.resolved_utc, "2026-04-03T09:00:00+00:00");
+}
+
+#[test]
+fn planner_skips_holidays_and_closures() {
+    let options = PlanOptions {
+        calendar: new_york(),
+        ..PlanOptions::default()
+    };
+    let task = Task {
+        id: "report".to_string(),
+        duration_minutes: 60,
+        deadline: None,
+        priority: 0,
+        location: None,
+    };
+    // Thursday is fully booked; the next working day is Monday April 13.
+    let busy = [ExpandedEvent::new(utc(4, 2, 13), utc(4, 2, 21))];
+    let plan = plan_tasks(&[task], &busy, utc(4, 2, 0), utc(4, 14, 0), &options).unwrap();
+    assert_eq!(plan.blocks[0].start, utc(4, 13, 13));
+}
@@ -0,0 +1,93 @@
+//! Tests for CalDAV free-busy-query helpers.
+
+use chrono::{DateTime, TimeZone, Utc};
+use truth_engine::interop::caldav::{freebusy_query, freebusy_stream, parse_freebusy_report};
+use truth_engine::{merge_availability, EventStream, ExpandedEvent, PrivacyLevel, TruthError};
+
+fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+}
+
+/// Shaped like the RFC 4791 §7.10 example response.
+const RESPONSE: &str = "BEGIN:VCALENDAR\r\n\
+    VERSION:2.0\r\n\
+    PRODID:-//Example Corp.//CalDAV Server//EN\r\n\
+    BEGIN:VFREEBUSY\r\n\
+    DTSTAMP:20260218T120000Z\r\n\
+    DTSTART:20260302T000000Z\r\n\
+    DTEND:20260303T000000Z\r\n\
+    FREEBUSY;FBTYPE=BUSY:20260302T140000Z/PT1H\r\n\
+    FREEBUSY;FBTYPE=BUSY-TENTATIVE:20260302T143000Z/20260302T160000Z\r\n\
+    FREEBUSY;FBTYPE=BUSY-UNAVAILABLE:20260302T200000Z/PT30M\r\n\
+    FREEBUSY;FBTYPE=FREE:20260302T090000Z/PT4H\r\n\
+    END:VFREEBUSY\r\n\
+    END:VCALENDAR\r\n";
+
+#[test]
+fn query_body_matches_rfc_4791() {
+    assert_eq!(
+        freebusy_query(utc(2026, 3, 2, 0, 0), utc(2026, 3, 3, 0, 0)),
+        "<?xml version=\"1.0\" encoding=\"utf-8\" ?>\n\
+         <C:free-busy-query xmlns:C=\"urn:ietf:params:xml:ns:caldav\">\n  \
+         <C:time-range start=\"20260302T000000Z\" end=\"20260303T000000Z\"/>\n\
+         </C:free-busy-query>\n"
+    );
+}
+
+#[test]
+fn response_parses_into_merged_busy_blocks() {
+    let blocks = parse_freebusy_report(RESPONSE).unwrap();
+    let spans: Vec<_> = blocks
+        .iter()
+        .map(|b| (b.start, b.end, b.source_count))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            (utc(2026, 3, 2, 14, 0), utc(2026, 3, 2, 16, 0), 1),
+            (utc(2026, 3, 2, 20, 0), utc(2026, 3, 2, 20, 30), 1),
+        ]
+    );
+}
+
+#[test]
+fn stream_merges_with_other_calendars() {
+    let caldav = freebusy_stream("work-caldav", RESPONSE).unwrap();
+    assert_eq!(caldav.stream_id, "work-caldav");
+    let personal = EventStream {
+        stream_id: "personal".to_string(),
+        events: vec![ExpandedEvent::new(
+            utc(2026, 3, 2, 15, 30),
+            utc(2026, 3, 2, 17, 0),
+        )],
+    };
+    let availability = merge_availability(
+        &[caldav, personal],
+        utc(2026, 3, 2, 9, 0),
+        utc(2026, 3, 2, 18, 0),
+        PrivacyLevel::Full,
+    );
+    let busy: Vec<_> = availability
+        .busy
+        .iter()
+        .map(|b| (b.start, b.end, b.source_count))
+        .collect();
+    assert_eq!(
+        busy,
+        vec![(utc(2026, 3, 2, 14, 0), utc(2026, 3, 2, 17, 0), 2)]
+    );
+}
+
+#[test]
+fn empty_and_malformed_responses() {
+    let empty = "BEGIN:VCALENDAR\r\nBEGIN:VFREEBUSY\r\nEND:VFREEBUSY\r\nEND:VCALENDAR\r\n";
+    assert!(freebusy_stream("x", empty).unwrap().events.is_empty());
+
+    let local = "BEGIN:VCALENDAR\r\nBEGIN:VFREEBUSY\r\n\
+                 FREEBUSY:20260302T140000/PT1H\r\n\
+                 END:VFREEBUSY\r\nEND:VCALENDAR\r\n";
+    assert!(matches!(
+        parse_freebusy_report(local),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+}
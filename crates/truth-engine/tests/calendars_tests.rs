@@ -0,0 +1,149 @@
+//! Tests for Hebrew, Islamic civil, and Chinese calendar conversions (`calendars` feature).
+
+#![cfg(feature = "calendars")]
+
+use chrono::NaiveDate;
+#[cfg(feature = "resolver")]
+use chrono::{TimeZone, Utc};
+use truth_engine::calendars::{CalendarDate, CalendarSystem};
+#[cfg(feature = "resolver")]
+use truth_engine::temporal::{resolve_relative, ResolutionGranularity};
+use truth_engine::TruthError;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn calendar_date(calendar: CalendarSystem, year: i32, month: u8, day: u8) -> CalendarDate {
+    CalendarDate {
+        calendar,
+        year,
+        month,
+        leap_month: false,
+        day,
+    }
+}
+
+#[test]
+fn hebrew_dates() {
+    // Passover 5786 and Rosh Hashanah 5787.
+    let passover = calendar_date(CalendarSystem::Hebrew, 5786, 1, 15);
+    assert_eq!(passover.to_gregorian().unwrap(), date(2026, 4, 2));
+    let rosh_hashanah =
+        CalendarDate::from_gregorian(date(2026, 9, 12), CalendarSystem::Hebrew).unwrap();
+    assert_eq!(
+        rosh_hashanah,
+        calendar_date(CalendarSystem::Hebrew, 5787, 7, 1)
+    );
+    assert_eq!(rosh_hashanah.to_string(), "1 Tishrei 5787");
+
+    // 5787 is a leap year: a plain "Adar" is Adar II, where Purim falls.
+    let purim: CalendarDate = "14 Adar 5787".parse().unwrap();
+    assert_eq!(purim.month, 13);
+    assert_eq!(purim.to_gregorian().unwrap(), date(2027, 3, 23));
+    let purim_katan: CalendarDate = "14 Adar I 5787".parse().unwrap();
+    assert_eq!(purim_katan.to_gregorian().unwrap(), date(2027, 2, 21));
+    let purim_5786: CalendarDate = "14 Adar 5786".parse().unwrap();
+    assert_eq!(purim_5786.month, 12);
+    assert_eq!(purim_5786.to_gregorian().unwrap(), date(2026, 3, 3));
+}
+
+#[test]
+fn islamic_civil_dates() {
+    let ramadan: CalendarDate = "1 Ramadan 1447 AH".parse().unwrap();
+    assert_eq!(ramadan.to_gregorian().unwrap(), date(2026, 2, 18));
+    // 1447 is a tabular leap year, so Dhu al-Hijjah has 30 days.
+    let new_year =
+        CalendarDate::from_gregorian(date(2026, 6, 17), CalendarSystem::IslamicCivil).unwrap();
+    assert_eq!(
+        new_year,
+        calendar_date(CalendarSystem::IslamicCivil, 1448, 1, 1)
+    );
+    assert_eq!(new_year.month_name(), Some("Muharram"));
+    let eid: CalendarDate = "10 dhu al-hijjah 1447".parse().unwrap();
+    assert_eq!(eid.to_gregorian().unwrap(), date(2026, 5, 27));
+}
+
+#[test]
+fn chinese_dates() {
+    let new_year = calendar_date(CalendarSystem::Chinese, 2026, 1, 1);
+    assert_eq!(new_year.to_gregorian().unwrap(), date(2026, 2, 17));
+    assert_eq!(
+        calendar_date(CalendarSystem::Chinese, 2027, 1, 1)
+            .to_gregorian()
+            .unwrap(),
+        date(2027, 2, 6)
+    );
+    // Before the new year, the date belongs to the previous lunar year.
+    let before = CalendarDate::from_gregorian(date(2026, 2, 16), CalendarSystem::Chinese).unwrap();
+    assert_eq!((before.year, before.month, before.day), (2025, 12, 29));
+
+    // 2025 has a leap sixth month, from July 25 to August 22.
+    let leap = CalendarDate::from_gregorian(date(2025, 7, 25), CalendarSystem::Chinese).unwrap();
+    assert_eq!((leap.month, leap.leap_month, leap.day), (6, true, 1));
+    assert_eq!(leap.to_gregorian().unwrap(), date(2025, 7, 25));
+    let after = CalendarDate::from_gregorian(date(2025, 8, 23), CalendarSystem::Chinese).unwrap();
+    assert_eq!((after.month, after.leap_month, after.day), (7, false, 1));
+    assert_eq!(leap.to_string(), "2025 leap month 6 day 1");
+}
+
+#[test]
+fn round_trips() {
+    let systems = [
+        CalendarSystem::Hebrew,
+        CalendarSystem::IslamicCivil,
+        CalendarSystem::Chinese,
+    ];
+    let mut day = date(2024, 1, 1);
+    while day < date(2028, 1, 1) {
+        for system in systems {
+            let converted = CalendarDate::from_gregorian(day, system).unwrap();
+            assert_eq!(converted.to_gregorian().unwrap(), day, "{converted}");
+        }
+        day += chrono::Duration::days(3);
+    }
+}
+
+#[test]
+fn nonexistent_dates_are_rejected() {
+    let invalid = |d: CalendarDate| matches!(d.to_gregorian(), Err(TruthError::InvalidDatetime(_)));
+    // 5786 is not a leap year, so it has no Adar II.
+    assert!(invalid(calendar_date(CalendarSystem::Hebrew, 5786, 13, 1)));
+    // Iyyar always has 29 days.
+    assert!(invalid(calendar_date(CalendarSystem::Hebrew, 5786, 2, 30)));
+    assert!(invalid(calendar_date(
+        CalendarSystem::IslamicCivil,
+        1447,
+        13,
+        1
+    )));
+    assert!(invalid(CalendarDate {
+        leap_month: true,
+        ..calendar_date(CalendarSystem::Chinese, 2026, 6, 1)
+    }));
+    assert!(invalid(CalendarDate {
+        leap_month: true,
+        ..calendar_date(CalendarSystem::Hebrew, 5787, 1, 1)
+    }));
+    assert!("15 Nisan".parse::<CalendarDate>().is_err());
+    assert!("15 Smarch 5787".parse::<CalendarDate>().is_err());
+}
+
+#[cfg(feature = "resolver")]
+#[test]
+fn resolve_relative_understands_calendar_dates() {
+    let anchor = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+    let r = resolve_relative(anchor, "15 Nisan 5787", "America/New_York").unwrap();
+    assert_eq!(r.resolved_utc, "2027-04-22T04:00:00+00:00");
+    assert_eq!(r.granularity, ResolutionGranularity::Date);
+
+    // Without a year, the next occurrence.
+    let r = resolve_relative(anchor, "1 Ramadan", "UTC").unwrap();
+    assert_eq!(r.resolved_utc, "2027-02-08T00:00:00+00:00");
+    let r = resolve_relative(anchor, "Chinese New Year", "UTC").unwrap();
+    assert_eq!(r.resolved_utc, "2027-02-06T00:00:00+00:00");
+    let r = resolve_relative(anchor, "lunar new year 2026", "UTC").unwrap();
+    assert_eq!(r.resolved_utc, "2026-02-17T00:00:00+00:00");
+
+    assert!(resolve_relative(anchor, "30 Iyyar 5787", "UTC").is_err());
+}
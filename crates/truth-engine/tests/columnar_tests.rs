@@ -0,0 +1,111 @@
+//! Tests for Arrow export (`arrow` feature).
+
+#![cfg(feature = "arrow")]
+
+use arrow_array::{Array, Int64Array, StringArray, TimestampMicrosecondArray, UInt64Array};
+use arrow_ipc::reader::FileReader;
+use chrono::{DateTime, TimeZone, Utc};
+use std::io::Cursor;
+use truth_engine::columnar::{
+    availability_schema, availability_to_record_batch, streams_to_record_batch, write_ipc,
+};
+use truth_engine::{merge_availability, EventStream, ExpandedEvent, PrivacyLevel, TruthError};
+
+fn utc(h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap()
+}
+
+fn streams() -> Vec<EventStream> {
+    vec![
+        EventStream {
+            stream_id: "work".to_string(),
+            events: vec![
+                ExpandedEvent::new(utc(9, 0), utc(10, 0)),
+                ExpandedEvent::new(utc(9, 30), utc(11, 0)),
+            ],
+        },
+        EventStream {
+            stream_id: "personal".to_string(),
+            events: vec![ExpandedEvent::new(utc(13, 0), utc(13, 45))],
+        },
+    ]
+}
+
+#[test]
+fn events_become_rows() {
+    let batch = streams_to_record_batch(&streams()).unwrap();
+    assert_eq!(batch.num_rows(), 3);
+    let ids = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(ids.value(2), "personal");
+    let starts = batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<TimestampMicrosecondArray>()
+        .unwrap();
+    assert_eq!(starts.value(1), utc(9, 30).timestamp_micros());
+    assert_eq!(starts.timezone(), Some("UTC"));
+    let minutes = batch
+        .column(3)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(minutes.values().to_vec(), vec![60, 90, 45]);
+
+    assert_eq!(streams_to_record_batch(&[]).unwrap().num_rows(), 0);
+}
+
+#[test]
+fn availability_rows_mark_busy_and_free() {
+    let availability = merge_availability(&streams(), utc(8, 0), utc(14, 0), PrivacyLevel::Full);
+    let batch = availability_to_record_batch(&availability).unwrap();
+    assert_eq!(batch.schema(), availability_schema());
+    let kinds = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    let kinds: Vec<_> = (0..batch.num_rows()).map(|i| kinds.value(i)).collect();
+    assert_eq!(kinds, vec!["busy", "busy", "free", "free", "free"]);
+    let sources = batch
+        .column(4)
+        .as_any()
+        .downcast_ref::<UInt64Array>()
+        .unwrap();
+    assert_eq!(sources.value(0), 1);
+    assert!(sources.is_null(2));
+}
+
+#[test]
+fn ipc_files_read_back() {
+    let batch = streams_to_record_batch(&streams()).unwrap();
+    let mut bytes = Vec::new();
+    write_ipc(&mut bytes, &[batch.clone(), batch.clone()]).unwrap();
+
+    let reader = FileReader::try_new(Cursor::new(bytes), None).unwrap();
+    let read: Vec<_> = reader.map(Result::unwrap).collect();
+    assert_eq!(read, vec![batch.clone(), batch]);
+
+    let mut empty = Vec::new();
+    write_ipc(&mut empty, &[]).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn mixed_schemas_are_rejected() {
+    let events = streams_to_record_batch(&streams()).unwrap();
+    let availability = availability_to_record_batch(&merge_availability(
+        &streams(),
+        utc(8, 0),
+        utc(14, 0),
+        PrivacyLevel::Opaque,
+    ))
+    .unwrap();
+    assert!(matches!(
+        write_ipc(Vec::new(), &[events, availability]),
+        Err(TruthError::Export(_))
+    ));
+}
@@ -0,0 +1,136 @@
+//! Tests for compact calendars, against the free functions on event streams.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use proptest::prelude::*;
+use truth_engine::compact::{CompactCalendar, CompactEvent, StringTable};
+use truth_engine::expander::OccurrenceBuffer;
+use truth_engine::{
+    find_conflicts, find_free_slots, merge_availability, EventStream, ExpandedEvent, PrivacyLevel,
+};
+
+fn at(minute: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap() + Duration::minutes(minute)
+}
+
+fn event(start: i64, end: i64) -> ExpandedEvent {
+    ExpandedEvent::new(at(start), at(end))
+}
+
+#[test]
+fn strings_are_interned_once() {
+    let mut table = StringTable::new();
+    let work = table.intern("work");
+    let home = table.intern("home");
+    assert_eq!(table.intern("work"), work);
+    assert_ne!(work, home);
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.resolve(home), "home");
+    assert_eq!(table.get("gym"), None);
+    assert_eq!(
+        table.iter().map(|(_, s)| s).collect::<Vec<_>>(),
+        ["work", "home"]
+    );
+}
+
+#[test]
+fn calendars_round_trip_through_streams_and_buffers() {
+    let streams = vec![
+        EventStream {
+            stream_id: "work".to_string(),
+            events: vec![event(60, 120), event(0, 30)],
+        },
+        EventStream {
+            stream_id: "home".to_string(),
+            events: vec![event(90, 150)],
+        },
+    ];
+    let mut calendar = CompactCalendar::from_streams(&streams);
+    assert_eq!(calendar.len(), 3);
+    assert_eq!(calendar.to_streams(), streams);
+    assert_eq!(calendar.stream_events("work"), streams[0].events);
+    assert!(calendar.stream_events("gym").is_empty());
+
+    let first = calendar.events()[0];
+    assert_eq!(first.to_event(), event(60, 120));
+    assert_eq!(
+        CompactEvent::from_event(&event(60, 120), first.stream),
+        first
+    );
+
+    let mut buffer = OccurrenceBuffer::new();
+    buffer.starts.push(at(200).timestamp());
+    buffer.ends.push(at(260).timestamp());
+    calendar.extend_from_buffer("home", &buffer);
+    calendar.push("gym", &event(300, 360));
+    assert_eq!(
+        calendar.stream_events("home"),
+        [event(90, 150), event(200, 260)]
+    );
+    assert_eq!(calendar.strings().len(), 3);
+}
+
+#[test]
+fn conflicts_keep_input_order_and_skip_touching_events() {
+    let mut calendar = CompactCalendar::new();
+    for e in [event(120, 180), event(0, 60)] {
+        calendar.push("a", &e);
+    }
+    for e in [
+        event(150, 200),
+        event(60, 90),
+        event(30, 45),
+        event(130, 140),
+    ] {
+        calendar.push("b", &e);
+    }
+    let conflicts = calendar.conflicts("a", "b");
+    assert_eq!(
+        conflicts,
+        find_conflicts(&calendar.stream_events("a"), &calendar.stream_events("b"))
+    );
+    let pairs: Vec<_> = conflicts
+        .iter()
+        .map(|c| (c.event_a.start, c.event_b.start, c.overlap_minutes))
+        .collect();
+    assert_eq!(
+        pairs,
+        [
+            (at(120), at(150), 30),
+            (at(120), at(130), 10),
+            (at(0), at(30), 15)
+        ]
+    );
+    assert!(calendar.conflicts("a", "missing").is_empty());
+}
+
+proptest! {
+    #[test]
+    fn compact_results_match_the_free_functions(
+        raw in prop::collection::vec((0usize..3, 0i64..1000, 0i64..120), 0..60),
+        (w_start, w_len) in (-50i64..1100, 0i64..600),
+        full in any::<bool>(),
+    ) {
+        let mut calendar = CompactCalendar::new();
+        for &(stream, start, len) in &raw {
+            calendar.push(&format!("s{stream}"), &event(start, start + len));
+        }
+        let streams = calendar.to_streams();
+        let (start, end) = (at(w_start), at(w_start + w_len));
+        let privacy = if full { PrivacyLevel::Full } else { PrivacyLevel::Opaque };
+
+        let all: Vec<ExpandedEvent> = streams.iter().flat_map(|s| s.events.clone()).collect();
+        prop_assert_eq!(calendar.free_slots(start, end), find_free_slots(&all, start, end));
+        prop_assert_eq!(
+            calendar.availability(start, end, privacy),
+            merge_availability(&streams, start, end, privacy)
+        );
+        for a in &streams {
+            for b in &streams {
+                prop_assert_eq!(
+                    calendar.conflicts(&a.stream_id, &b.stream_id),
+                    find_conflicts(&a.events, &b.events)
+                );
+            }
+        }
+    }
+}
@@ -3,8 +3,9 @@
 //! All tests should compile but fail with `todo!()` panics until implementation.
 
 use chrono::{TimeZone, Utc};
-use truth_engine::expander::ExpandedEvent;
-use truth_engine::find_conflicts;
+use truth_engine::conflict::{conflict_matrix, find_series_conflicts};
+use truth_engine::expander::{expand_rrule, ExpandedEvent, OccurrenceOverride, RecurringSeries};
+use truth_engine::{find_conflicts, EventStream, TruthError};
 
 /// Helper to create an ExpandedEvent from hour ranges on a given day.
 fn event(
@@ -16,14 +17,12 @@ fn event(
     end_hour: u32,
     end_min: u32,
 ) -> ExpandedEvent {
-    ExpandedEvent {
-        start: Utc
-            .with_ymd_and_hms(year, month, day, start_hour, start_min, 0)
+    ExpandedEvent::new(
+        Utc.with_ymd_and_hms(year, month, day, start_hour, start_min, 0)
             .unwrap(),
-        end: Utc
-            .with_ymd_and_hms(year, month, day, end_hour, end_min, 0)
+        Utc.with_ymd_and_hms(year, month, day, end_hour, end_min, 0)
             .unwrap(),
-    }
+    )
 }
 
 #[test]
@@ -125,3 +124,132 @@ fn one_empty_list_no_conflicts() {
         "one empty list should produce no conflicts"
     );
 }
+
+#[test]
+fn conflict_matrix_counts_shared_busy_minutes_per_pair() {
+    let stream = |id: &str, events: Vec<ExpandedEvent>| EventStream {
+        stream_id: id.to_string(),
+        events,
+    };
+    let streams = [
+        // Overlapping events within one stream count once.
+        stream(
+            "alice",
+            vec![
+                event(2026, 3, 2, 9, 0, 11, 0),
+                event(2026, 3, 2, 10, 0, 10, 30),
+            ],
+        ),
+        stream(
+            "bob",
+            vec![
+                event(2026, 3, 2, 10, 30, 12, 0),
+                event(2026, 3, 2, 7, 0, 9, 15),
+            ],
+        ),
+        // Adjacent to Bob, partly outside the window.
+        stream("carol", vec![event(2026, 3, 2, 12, 0, 19, 0)]),
+    ];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 2, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 2, 18, 0, 0).unwrap();
+    let matrix = conflict_matrix(&streams, window_start, window_end);
+
+    assert_eq!(matrix.stream_ids, vec!["alice", "bob", "carol"]);
+    assert_eq!(
+        matrix.overlap_minutes,
+        vec![vec![120, 45, 0], vec![45, 165, 0], vec![0, 0, 360]]
+    );
+    assert_eq!(matrix.overlap("bob", "alice"), Some(45));
+    assert_eq!(matrix.overlap("alice", "dave"), None);
+    assert_eq!(
+        matrix.clashes(),
+        vec![("alice".to_string(), "bob".to_string(), 45)]
+    );
+    assert!(conflict_matrix(&[], window_start, window_end)
+        .overlap_minutes
+        .is_empty());
+}
+
+#[test]
+fn series_conflicts_use_effective_occurrences() {
+    let series = |rrule: &str, dtstart: &str, minutes| RecurringSeries {
+        rrule: rrule.to_string(),
+        dtstart: dtstart.to_string(),
+        duration_minutes: minutes,
+        timezone: "America/New_York".to_string(),
+        exdates: vec![],
+        rdates: vec![],
+        overrides: vec![],
+    };
+    let moved = |id: &str, event| OccurrenceOverride {
+        recurrence_id: id.to_string(),
+        event: Some(event),
+    };
+    // Monday to Wednesday in New York (UTC-5).
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 2, 5, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 5, 5, 0, 0).unwrap();
+    let mut team = series("FREQ=DAILY", "2026-03-02T09:00:00", 60);
+    let focus = series("FREQ=WEEKLY;BYDAY=MO,TU,WE", "2026-03-02T09:30:00", 30);
+
+    // The raw rules clash every day.
+    let raw = |s: &RecurringSeries| {
+        expand_rrule(
+            &s.rrule,
+            &s.dtstart,
+            s.duration_minutes,
+            &s.timezone,
+            None,
+            Some(3),
+        )
+        .unwrap()
+    };
+    assert_eq!(find_conflicts(&raw(&team), &raw(&focus)).len(), 3);
+
+    team.overrides = vec![
+        OccurrenceOverride {
+            recurrence_id: "2026-03-03T09:00:00".to_string(),
+            event: None,
+        },
+        moved("2026-03-04T09:00:00", event(2026, 3, 4, 14, 45, 15, 45)),
+        // Thursday's meeting pulled into Wednesday afternoon.
+        moved("2026-03-05T09:00:00", event(2026, 3, 4, 21, 0, 22, 0)),
+    ];
+    let occurrences = team.occurrences(window_start, window_end).unwrap();
+    let bounds: Vec<_> = occurrences
+        .iter()
+        .map(|o| ExpandedEvent::new(o.start, o.end))
+        .collect();
+    assert_eq!(
+        bounds,
+        vec![
+            event(2026, 3, 2, 14, 0, 15, 0),
+            event(2026, 3, 4, 14, 45, 15, 45),
+            event(2026, 3, 4, 21, 0, 22, 0),
+        ]
+    );
+
+    let conflicts = find_series_conflicts(
+        std::slice::from_ref(&team),
+        &[focus],
+        window_start,
+        window_end,
+    )
+    .unwrap();
+    let overlaps: Vec<_> = conflicts
+        .iter()
+        .map(|c| (c.event_a.start, c.overlap_minutes))
+        .collect();
+    assert_eq!(
+        overlaps,
+        vec![
+            (Utc.with_ymd_and_hms(2026, 3, 2, 14, 0, 0).unwrap(), 30),
+            (Utc.with_ymd_and_hms(2026, 3, 4, 14, 45, 0).unwrap(), 15),
+        ]
+    );
+
+    team.overrides[0].recurrence_id = "Tuesday".to_string();
+    assert!(matches!(
+        team.occurrences(window_start, window_end),
+        Err(TruthError::InvalidDatetime(_))
+    ));
+}
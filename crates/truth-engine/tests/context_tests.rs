@@ -0,0 +1,186 @@
+//! Tests for `TruthContext`: same results as the free functions, with caching.
+
+use std::sync::Arc;
+use std::thread;
+
+#[cfg(feature = "resolver")]
+use chrono::{NaiveDate, TimeZone, Utc};
+#[cfg(feature = "resolver")]
+use truth_engine::business::BusinessCalendar;
+#[cfg(feature = "resolver")]
+use truth_engine::temporal::InputMode;
+#[cfg(feature = "resolver")]
+use truth_engine::{
+    adjust_timestamp, convert_timezone, expand_rrule_with_exdates, resolve_relative_with_options,
+    ExpandedEvent, ResolveOptions,
+};
+use truth_engine::{expand_rrule, TruthContext, TruthError};
+
+#[cfg(feature = "resolver")]
+#[test]
+fn methods_match_the_free_functions() {
+    let ctx = TruthContext::new();
+    assert_eq!(
+        ctx.convert_timezone("2026-07-01T12:00:00Z", "PST").unwrap(),
+        convert_timezone("2026-07-01T12:00:00Z", "PST").unwrap()
+    );
+    assert_eq!(
+        ctx.adjust_timestamp("2026-03-07T12:00:00Z", "+1d", "America/New_York")
+            .unwrap(),
+        adjust_timestamp("2026-03-07T12:00:00Z", "+1d", "America/New_York").unwrap()
+    );
+    let anchor = Utc.with_ymd_and_hms(2026, 2, 17, 9, 0, 0).unwrap();
+    assert_eq!(
+        ctx.resolve_relative(anchor, "next friday at 10am", "Europe/London")
+            .unwrap(),
+        resolve_relative_with_options(
+            anchor,
+            "next friday at 10am",
+            "Europe/London",
+            &ResolveOptions::default()
+        )
+        .unwrap()
+    );
+    let exdates = ["2026-03-09T09:00:00"];
+    for _ in 0..2 {
+        assert_eq!(
+            ctx.expand_rrule_with_exdates(
+                "FREQ=DAILY",
+                "2026-03-07T09:00:00",
+                45,
+                "America/New_York",
+                None,
+                Some(5),
+                &exdates
+            )
+            .unwrap(),
+            expand_rrule_with_exdates(
+                "FREQ=DAILY",
+                "2026-03-07T09:00:00",
+                45,
+                "America/New_York",
+                None,
+                Some(5),
+                &exdates
+            )
+            .unwrap()
+        );
+    }
+    // A cached rule expands with whatever duration is asked for.
+    let long = ctx
+        .expand_rrule_with_exdates(
+            "FREQ=DAILY",
+            "2026-03-07T09:00:00",
+            90,
+            "America/New_York",
+            None,
+            Some(5),
+            &exdates,
+        )
+        .unwrap();
+    assert_eq!(long[0].end - long[0].start, chrono::Duration::minutes(90));
+}
+
+#[test]
+fn errors_are_not_cached() {
+    let ctx = TruthContext::new();
+    for _ in 0..2 {
+        assert!(matches!(
+            ctx.timezone("Mars/Olympus"),
+            Err(TruthError::InvalidTimezone(_))
+        ));
+        assert!(matches!(
+            ctx.expand_rrule(
+                "FREQ=SOMETIMES",
+                "2026-01-01T09:00:00",
+                30,
+                "UTC",
+                None,
+                None
+            ),
+            Err(TruthError::InvalidRule(_))
+        ));
+    }
+    assert!(matches!(
+        ctx.timezone("CST"),
+        Err(TruthError::AmbiguousTimezone { .. })
+    ));
+    assert_eq!(ctx.timezone("JST").unwrap(), chrono_tz::Asia::Tokyo);
+    ctx.clear_caches();
+    assert_eq!(ctx.timezone("JST").unwrap(), chrono_tz::Asia::Tokyo);
+}
+
+#[cfg(feature = "resolver")]
+#[test]
+fn configuration_applies_to_every_call() {
+    let calendar = BusinessCalendar {
+        timezone: "America/New_York".to_string(),
+        holidays: [NaiveDate::from_ymd_opt(2026, 7, 3).unwrap()].into(),
+        ..BusinessCalendar::default()
+    };
+    let ctx = TruthContext::with_options(ResolveOptions {
+        business_calendar: Some(calendar),
+        ..ResolveOptions::default()
+    })
+    .with_input_mode(InputMode::Lenient);
+
+    // Lenient input accepts a space for the 'T'.
+    assert!(ctx
+        .convert_timezone("2026-07-01 12:00", "America/New_York")
+        .is_ok());
+    // The holiday on Friday July 3 is skipped.
+    let anchor = Utc.with_ymd_and_hms(2026, 7, 2, 22, 0, 0).unwrap();
+    let resolved = ctx
+        .resolve_relative(anchor, "next business day", "America/New_York")
+        .unwrap();
+    assert!(resolved.resolved_local.starts_with("2026-07-06"));
+    // Working time counts on the same calendar.
+    let adjusted = ctx
+        .adjust_timestamp("2026-07-02T19:00:00Z", "+3bh", "America/New_York")
+        .unwrap();
+    assert_eq!(adjusted.adjusted_local, "2026-07-06T10:00:00-04:00");
+    let at = |d, h| Utc.with_ymd_and_hms(2026, 7, d, h, 0, 0).unwrap();
+    let busy = [ExpandedEvent::new(at(2, 14), at(2, 16))];
+    let free = ctx
+        .find_business_free_slots(&busy, at(2, 0), at(4, 0))
+        .unwrap();
+    assert!(free.iter().all(|slot| slot.start < at(3, 0)));
+
+    assert!(matches!(
+        TruthContext::new().find_business_free_slots(&busy, at(2, 0), at(4, 0)),
+        Err(TruthError::InvalidArguments(_))
+    ));
+}
+
+#[test]
+fn one_context_serves_many_threads() {
+    let ctx = Arc::new(TruthContext::new());
+    let expected = expand_rrule(
+        "FREQ=WEEKLY;BYDAY=MO,WE",
+        "2026-01-05T09:00:00",
+        30,
+        "Asia/Kolkata",
+        None,
+        Some(10),
+    )
+    .unwrap();
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let ctx = Arc::clone(&ctx);
+            thread::spawn(move || {
+                ctx.expand_rrule(
+                    "FREQ=WEEKLY;BYDAY=MO,WE",
+                    "2026-01-05T09:00:00",
+                    30,
+                    "Asia/Kolkata",
+                    None,
+                    Some(10),
+                )
+                .unwrap()
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), expected);
+    }
+}
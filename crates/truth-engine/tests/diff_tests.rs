@@ -0,0 +1,198 @@
+//! Tests for calendar diffing.
+
+use chrono::{DateTime, TimeZone, Utc};
+use truth_engine::interop::diff::{diff_calendars, Change, ChangeKind};
+use truth_engine::interop::ics::{parse_ics, Calendar};
+use truth_engine::{ExpandedEvent, TruthError};
+
+fn utc(d: u32, h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, d, h, m, 0).unwrap()
+}
+
+/// A calendar of the given VEVENT bodies.
+fn calendar(events: &[&str]) -> Calendar {
+    let mut text = "BEGIN:VCALENDAR\n".to_string();
+    for event in events {
+        text.push_str(&format!("BEGIN:VEVENT\n{}\nEND:VEVENT\n", event.trim()));
+    }
+    text.push_str("END:VCALENDAR\n");
+    parse_ics(&text).unwrap()
+}
+
+const STANDUP: &str = "UID:standup\nSUMMARY:Standup\nDTSTART:20260302T090000Z\nDURATION:PT15M\nRRULE:FREQ=DAILY;COUNT=5";
+
+fn kinds(old: &Calendar, new: &Calendar) -> Vec<ChangeKind> {
+    diff_calendars(old, new, utc(31, 0, 0), "UTC")
+        .unwrap()
+        .into_iter()
+        .map(|c| c.kind)
+        .collect()
+}
+
+#[test]
+fn identical_calendars_have_no_changes() {
+    let cal = calendar(&[STANDUP]);
+    assert!(kinds(&cal, &cal).is_empty());
+}
+
+#[test]
+fn events_are_added_and_removed_by_uid() {
+    let lunch = "UID:lunch\nDTSTART:20260302T120000Z\nDURATION:PT1H";
+    let changes = diff_calendars(
+        &calendar(&[STANDUP]),
+        &calendar(&[lunch]),
+        utc(31, 0, 0),
+        "UTC",
+    )
+    .unwrap();
+    assert_eq!(
+        changes,
+        vec![
+            Change {
+                uid: "lunch".to_string(),
+                kind: ChangeKind::Added
+            },
+            Change {
+                uid: "standup".to_string(),
+                kind: ChangeKind::Removed
+            },
+        ]
+    );
+}
+
+#[test]
+fn single_event_moves_and_detail_edits() {
+    let old = calendar(&["UID:lunch\nSUMMARY:Lunch\nDTSTART:20260302T120000Z\nDURATION:PT1H"]);
+    let new = calendar(&[
+        "UID:lunch\nSUMMARY:Team lunch\nLOCATION:Cafe\nDTSTART:20260302T123000Z\nDURATION:PT1H",
+    ]);
+    assert_eq!(
+        kinds(&old, &new),
+        vec![
+            ChangeKind::OccurrenceMoved {
+                original: utc(2, 12, 0),
+                from: ExpandedEvent::new(utc(2, 12, 0), utc(2, 13, 0)),
+                to: ExpandedEvent::new(utc(2, 12, 30), utc(2, 13, 30)),
+            },
+            ChangeKind::DetailsChanged {
+                original: None,
+                fields: vec!["SUMMARY", "LOCATION"],
+            },
+        ]
+    );
+}
+
+#[test]
+fn series_truncation_extension_and_rule_changes() {
+    let five = calendar(&[STANDUP]);
+    let until_fourth = calendar(&[&STANDUP.replace("COUNT=5", "UNTIL=20260305T000000Z")]);
+    assert_eq!(
+        kinds(&five, &until_fourth),
+        vec![ChangeKind::SeriesTruncated {
+            removed: vec![utc(5, 9, 0), utc(6, 9, 0)]
+        }]
+    );
+    assert_eq!(
+        kinds(&until_fourth, &five),
+        vec![ChangeKind::SeriesExtended {
+            added: vec![utc(5, 9, 0), utc(6, 9, 0)]
+        }]
+    );
+    let weekly = calendar(&[&STANDUP.replace("FREQ=DAILY;COUNT=5", "FREQ=WEEKLY;COUNT=2")]);
+    assert_eq!(
+        kinds(&five, &weekly),
+        vec![ChangeKind::RuleChanged {
+            added: vec![utc(9, 9, 0)],
+            removed: vec![utc(3, 9, 0), utc(4, 9, 0), utc(5, 9, 0), utc(6, 9, 0)],
+        }]
+    );
+}
+
+#[test]
+fn exdates_and_overrides_are_classified_per_occurrence() {
+    let old = calendar(&[STANDUP]);
+    let new = calendar(&[
+        &format!("{STANDUP}\nEXDATE:20260303T090000Z"),
+        // Moved.
+        "UID:standup\nRECURRENCE-ID:20260304T090000Z\nDTSTART:20260304T100000Z\nDURATION:PT15M",
+        // Same time, new summary.
+        "UID:standup\nSUMMARY:Demo\nRECURRENCE-ID:20260305T090000Z\nDTSTART:20260305T090000Z\nDURATION:PT15M",
+        // Cancelled.
+        "UID:standup\nSTATUS:CANCELLED\nRECURRENCE-ID:20260306T090000Z\nDTSTART:20260306T090000Z\nDURATION:PT15M",
+    ]);
+    assert_eq!(
+        kinds(&old, &new),
+        vec![
+            ChangeKind::OccurrenceCancelled {
+                original: utc(3, 9, 0)
+            },
+            ChangeKind::OccurrenceMoved {
+                original: utc(4, 9, 0),
+                from: ExpandedEvent::new(utc(4, 9, 0), utc(4, 9, 15)),
+                to: ExpandedEvent::new(utc(4, 10, 0), utc(4, 10, 15)),
+            },
+            ChangeKind::ExceptionAdded {
+                original: utc(5, 9, 0)
+            },
+            ChangeKind::OccurrenceCancelled {
+                original: utc(6, 9, 0)
+            },
+        ]
+    );
+    assert_eq!(
+        kinds(&new, &old),
+        vec![
+            ChangeKind::OccurrenceRestored {
+                original: utc(3, 9, 0)
+            },
+            ChangeKind::ExceptionRemoved {
+                original: utc(4, 9, 0)
+            },
+            ChangeKind::ExceptionRemoved {
+                original: utc(5, 9, 0)
+            },
+            ChangeKind::ExceptionRemoved {
+                original: utc(6, 9, 0)
+            },
+        ]
+    );
+}
+
+#[test]
+fn timezone_change_is_reported_once() {
+    let old = calendar(&[
+        "UID:sync\nDTSTART;TZID=America/New_York:20260302T090000\nDURATION:PT30M\nRRULE:FREQ=WEEKLY;COUNT=4",
+    ]);
+    let new = calendar(&[
+        "UID:sync\nDTSTART;TZID=Europe/London:20260302T090000\nDURATION:PT30M\nRRULE:FREQ=WEEKLY;COUNT=4",
+    ]);
+    assert_eq!(
+        kinds(&old, &new),
+        vec![ChangeKind::TimezoneChanged {
+            from: Some("America/New_York".to_string()),
+            to: Some("Europe/London".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn whole_series_cancellation() {
+    let cancelled = calendar(&[&format!("{STANDUP}\nSTATUS:CANCELLED")]);
+    assert_eq!(
+        kinds(&calendar(&[STANDUP]), &cancelled),
+        vec![ChangeKind::Cancelled]
+    );
+    assert_eq!(
+        kinds(&cancelled, &calendar(&[STANDUP])),
+        vec![ChangeKind::Restored]
+    );
+}
+
+#[test]
+fn unknown_default_timezone_is_rejected() {
+    let cal = calendar(&[STANDUP]);
+    assert!(matches!(
+        diff_calendars(&cal, &cal, utc(31, 0, 0), "Mars/Olympus"),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+}
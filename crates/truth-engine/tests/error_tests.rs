@@ -0,0 +1,109 @@
+//! Tests for error classification.
+
+use chrono::{TimeZone, Utc};
+#[cfg(feature = "serde")]
+use serde_json::json;
+use truth_engine::booking::BookingLedger;
+use truth_engine::markets::is_market_open;
+#[cfg(feature = "resolver")]
+use truth_engine::{convert_timezone, resolve_relative};
+use truth_engine::{ErrorClass, EventStream, ExpandedEvent, TruthError};
+
+#[cfg(feature = "resolver")]
+#[test]
+fn bad_arguments_are_input_errors() {
+    let anchor = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+    let errors = [
+        resolve_relative(anchor, "the day after wibble", "UTC").unwrap_err(),
+        convert_timezone("2026-02-18T14:30:00Z", "Mars/Olympus").unwrap_err(),
+    ];
+    for err in errors {
+        assert_eq!(err.class(), ErrorClass::Input, "{err}");
+        assert_eq!(err.class().http_status(), 400);
+        assert_eq!(err.class().json_rpc_code(), -32602);
+        assert!(!err.is_retryable(), "{err}");
+    }
+}
+
+#[test]
+fn invalid_definitions_are_configuration_errors() {
+    let at = Utc.with_ymd_and_hms(2026, 2, 18, 14, 30, 0).unwrap();
+    let err = is_market_open(at, "NOPE").unwrap_err();
+    assert_eq!(err.class(), ErrorClass::Configuration);
+    assert_eq!(err.class().http_status(), 500);
+    assert_eq!(err.class().json_rpc_code(), -32603);
+}
+
+#[test]
+fn internal_failures_are_internal_errors() {
+    let err = TruthError::Export("writer closed".to_string());
+    assert_eq!(err.class(), ErrorClass::Internal);
+    assert_eq!(err.class().http_status(), 500);
+}
+
+#[test]
+fn slot_conflicts_are_retryable_conflicts() {
+    let utc = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+    let slot = ExpandedEvent::new(utc(14), utc(15));
+    let busy = [EventStream {
+        stream_id: "work".to_string(),
+        events: vec![slot.clone()],
+    }];
+    let mut ledger = BookingLedger::new();
+    ledger.hold("a", slot.clone(), utc(9), utc(8), &[]).unwrap();
+    let errors = [
+        // Overlaps the hold on "a"
+        ledger
+            .hold("b", slot.clone(), utc(9), utc(8), &[])
+            .unwrap_err(),
+        // Busy in the caller's calendar
+        BookingLedger::new()
+            .hold("b", slot, utc(9), utc(8), &busy)
+            .unwrap_err(),
+    ];
+    for err in errors {
+        assert!(matches!(err, TruthError::SlotConflict(_)), "{err}");
+        assert_eq!(err.class(), ErrorClass::Conflict);
+        assert_eq!(err.class().http_status(), 409);
+        assert_eq!(err.class().json_rpc_code(), -32000);
+        assert!(err.is_retryable(), "{err}");
+    }
+}
+
+#[test]
+fn booking_state_errors_are_not_retryable() {
+    let utc = |h| Utc.with_ymd_and_hms(2026, 3, 2, h, 0, 0).unwrap();
+    let slot = |h| ExpandedEvent::new(utc(h), utc(h + 1));
+    let mut ledger = BookingLedger::new();
+    ledger.hold("a", slot(14), utc(9), utc(8), &[]).unwrap();
+    ledger.hold("b", slot(16), utc(9), utc(8), &[]).unwrap();
+    ledger.hold("c", slot(18), utc(9), utc(8), &[]).unwrap();
+    ledger.release("c", utc(8)).unwrap();
+
+    let errors = [
+        // "booking 'a' already exists"
+        ledger.hold("a", slot(20), utc(9), utc(8), &[]).unwrap_err(),
+        // "no booking 'missing'"
+        ledger.confirm("missing", utc(8)).unwrap_err(),
+        // "hold 'b' has expired"
+        ledger.confirm("b", utc(10)).unwrap_err(),
+        // "booking 'b' is no longer held"
+        ledger.confirm("b", utc(10)).unwrap_err(),
+        // "booking 'c' is not active"
+        ledger.release("c", utc(8)).unwrap_err(),
+    ];
+    for err in errors {
+        assert!(matches!(err, TruthError::Booking(_)), "{err}");
+        assert_eq!(err.class(), ErrorClass::Input, "{err}");
+        assert!(!err.is_retryable(), "{err}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn class_serializes_in_snake_case() {
+    assert_eq!(
+        serde_json::to_value(ErrorClass::Configuration).unwrap(),
+        json!("configuration")
+    );
+}
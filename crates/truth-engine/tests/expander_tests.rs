@@ -4,6 +4,15 @@
 
 use chrono::{TimeZone, Timelike, Utc};
 use truth_engine::expand_rrule;
+use truth_engine::expander::{
+    expand_rrule_with_exdates, expand_rrule_with_until, expand_rrule_with_warnings,
+    expand_series_batch, materialize_exception, normalize_exception_dates, preview, preview_in,
+    split_series, CompiledRule, OccurrenceBuffer, OccurrenceOverride, RecurringSeries, SeriesPart,
+    UntilDate, UntilOptions, UntilZone,
+};
+use truth_engine::temporal::{ClockFormat, Locale};
+use truth_engine::warnings::WarningKind;
+use truth_engine::ExpandedEvent;
 
 // ---------------------------------------------------------------------------
 // CTO's exact example: 3rd Tuesday of each month, America/Los_Angeles
@@ -307,3 +316,1100 @@ fn duration_applied_correctly() {
         Utc.with_ymd_and_hms(2026, 3, 1, 10, 30, 0).unwrap()
     );
 }
+
+#[test]
+fn timezone_abbreviation_resolves_to_iana_zone() {
+    // "PT" → America/Los_Angeles; 09:00 PST = 17:00 UTC
+    let result = expand_rrule(
+        "FREQ=DAILY;COUNT=1",
+        "2026-01-05T09:00:00",
+        30,
+        "PT",
+        None,
+        None,
+    )
+    .expect("abbreviation should be accepted");
+    assert_eq!(
+        result[0].start,
+        Utc.with_ymd_and_hms(2026, 1, 5, 17, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn ambiguous_timezone_abbreviation_returns_error() {
+    let result = expand_rrule(
+        "FREQ=DAILY;COUNT=1",
+        "2026-01-05T09:00:00",
+        30,
+        "IST",
+        None,
+        None,
+    );
+    assert!(matches!(
+        result,
+        Err(truth_engine::TruthError::AmbiguousTimezone { .. })
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Series editing: split and single-instance exceptions
+// ---------------------------------------------------------------------------
+
+/// Start and end of each event, since a split series numbers its
+/// occurrences afresh.
+fn bounds(
+    events: &[truth_engine::ExpandedEvent],
+) -> Vec<(chrono::DateTime<Utc>, chrono::DateTime<Utc>)> {
+    events.iter().map(|e| (e.start, e.end)).collect()
+}
+
+fn expand_part(part: &SeriesPart, timezone: &str) -> Vec<truth_engine::ExpandedEvent> {
+    let exdates: Vec<&str> = part.exdates.iter().map(String::as_str).collect();
+    expand_rrule_with_exdates(
+        &part.rrule,
+        &part.dtstart,
+        30,
+        timezone,
+        None,
+        None,
+        &exdates,
+    )
+    .unwrap()
+}
+
+#[test]
+fn split_with_count_divides_the_count() {
+    let exdates = ["2026-03-02T09:00:00", "2026-03-16T09:00:00"];
+    let original = expand_rrule_with_exdates(
+        "FREQ=WEEKLY;COUNT=8",
+        "2026-02-23T09:00:00",
+        30,
+        "America/New_York",
+        None,
+        None,
+        &exdates,
+    )
+    .unwrap();
+    // Split after US clocks go forward on March 8.
+    let split = split_series(
+        "FREQ=WEEKLY;COUNT=8",
+        "2026-02-23T09:00:00",
+        "America/New_York",
+        &exdates,
+        "2026-03-09T09:00:00",
+    )
+    .unwrap();
+
+    let before = split.original.unwrap();
+    assert_eq!(before.rrule, "FREQ=WEEKLY;COUNT=2");
+    assert_eq!(before.exdates, vec!["2026-03-02T09:00:00"]);
+    assert_eq!(split.following.rrule, "FREQ=WEEKLY;COUNT=6");
+    assert_eq!(split.following.dtstart, "2026-03-09T09:00:00");
+    assert_eq!(split.following.exdates, vec!["2026-03-16T09:00:00"]);
+
+    let mut rejoined = expand_part(&before, "America/New_York");
+    rejoined.extend(expand_part(&split.following, "America/New_York"));
+    assert_eq!(bounds(&rejoined), bounds(&original));
+}
+
+#[test]
+fn split_without_count_ends_the_original_at_the_previous_occurrence() {
+    let rule = "FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20260430T170000Z";
+    let original = expand_rrule(
+        rule,
+        "2026-03-02T10:00:00",
+        30,
+        "America/Los_Angeles",
+        None,
+        None,
+    )
+    .unwrap();
+    let split = split_series(
+        rule,
+        "2026-03-02T10:00:00",
+        "America/Los_Angeles",
+        &[],
+        "2026-03-18T10:00:00",
+    )
+    .unwrap();
+
+    let before = split.original.unwrap();
+    assert_eq!(
+        before.rrule,
+        "FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20260316T170000Z"
+    );
+    assert_eq!(split.following.rrule, rule);
+
+    let mut rejoined = expand_part(&before, "America/Los_Angeles");
+    rejoined.extend(expand_part(&split.following, "America/Los_Angeles"));
+    assert_eq!(bounds(&rejoined), bounds(&original));
+
+    // Open-ended series get an UNTIL too.
+    let split = split_series(
+        "FREQ=DAILY",
+        "2026-01-01T08:00:00",
+        "UTC",
+        &[],
+        "2026-01-05T08:00:00",
+    )
+    .unwrap();
+    assert_eq!(
+        split.original.unwrap().rrule,
+        "FREQ=DAILY;UNTIL=20260104T080000Z"
+    );
+    assert_eq!(split.following.rrule, "FREQ=DAILY");
+}
+
+#[test]
+fn split_at_the_first_occurrence_replaces_the_series() {
+    let split = split_series(
+        "FREQ=DAILY;COUNT=5",
+        "2026-01-01T08:00:00",
+        "UTC",
+        &[],
+        "2026-01-01T08:00:00",
+    )
+    .unwrap();
+    assert_eq!(split.original, None);
+    assert_eq!(split.following.rrule, "FREQ=DAILY;COUNT=5");
+}
+
+#[test]
+fn split_and_exceptions_need_a_live_occurrence() {
+    let invalid = |at| {
+        matches!(
+            split_series(
+                "FREQ=DAILY;COUNT=5",
+                "2026-01-01T08:00:00",
+                "UTC",
+                &["2026-01-03T08:00:00"],
+                at
+            ),
+            Err(truth_engine::TruthError::InvalidRule(_))
+        )
+    };
+    // Wrong time of day, excluded, and past the COUNT.
+    assert!(invalid("2026-01-02T09:00:00"));
+    assert!(invalid("2026-01-03T08:00:00"));
+    assert!(invalid("2026-01-06T08:00:00"));
+    assert!(matches!(
+        materialize_exception(
+            "FREQ=DAILY",
+            "2026-01-01T08:00:00",
+            30,
+            "UTC",
+            &[],
+            "tomorrow"
+        ),
+        Err(truth_engine::TruthError::InvalidDatetime(_))
+    ));
+}
+
+#[test]
+fn materialized_exception_leaves_the_rest_of_the_series() {
+    let exception = materialize_exception(
+        "FREQ=MONTHLY;BYDAY=TU;BYSETPOS=3",
+        "2026-02-17T14:00:00",
+        60,
+        "America/Los_Angeles",
+        &[],
+        "2026-03-17T14:00:00",
+    )
+    .unwrap();
+    assert_eq!(exception.recurrence_id, "2026-03-17T14:00:00");
+    // 14:00 PDT.
+    assert_eq!(
+        exception.event.start,
+        Utc.with_ymd_and_hms(2026, 3, 17, 21, 0, 0).unwrap()
+    );
+    assert_eq!(
+        exception.event.end,
+        Utc.with_ymd_and_hms(2026, 3, 17, 22, 0, 0).unwrap()
+    );
+    assert_eq!(exception.exdates, vec!["2026-03-17T14:00:00"]);
+
+    let exdates: Vec<&str> = exception.exdates.iter().map(String::as_str).collect();
+    let rest = expand_rrule_with_exdates(
+        "FREQ=MONTHLY;BYDAY=TU;BYSETPOS=3",
+        "2026-02-17T14:00:00",
+        60,
+        "America/Los_Angeles",
+        None,
+        Some(3),
+        &exdates,
+    )
+    .unwrap();
+    // COUNT counts the excluded occurrence.
+    assert_eq!(rest.len(), 2);
+    assert!(rest.iter().all(|e| e.start != exception.event.start));
+}
+
+// ---------------------------------------------------------------------------
+// Parse-error spans
+// ---------------------------------------------------------------------------
+
+#[test]
+fn malformed_rrule_parts_are_located() {
+    let span = |rrule: &str| {
+        let err = expand_rrule(rrule, "2026-01-01T09:00:00", 30, "UTC", None, Some(3)).unwrap_err();
+        assert!(matches!(err, truth_engine::TruthError::InvalidRule(_)));
+        let span = err.span().expect("located").clone();
+        (span.offset, span.token)
+    };
+    assert_eq!(span("FREQ=WEEKLY;BYDAY=MO,XX"), (21, "XX".to_string()));
+    assert_eq!(span("FREQ=FORTNIGHTLY"), (5, "FORTNIGHTLY".to_string()));
+    assert_eq!(span("FREQ=DAILY;COUNT=ten"), (17, "ten".to_string()));
+    assert_eq!(
+        span("FREQ=MONTHLY;BYMONTHDAY=1,-32"),
+        (26, "-32".to_string())
+    );
+    assert_eq!(span("FREQ=DAILY;FOO=1"), (11, "FOO".to_string()));
+    assert_eq!(span("FREQ=DAILY;COUNT"), (11, "COUNT".to_string()));
+    assert_eq!(
+        span("FREQ=DAILY;UNTIL=2026-01-01"),
+        (17, "2026-01-01".to_string())
+    );
+    assert_eq!(
+        span("FREQ=DAILY;freq=WEEKLY"),
+        (11, "freq=WEEKLY".to_string())
+    );
+
+    let err = expand_rrule(
+        "FREQ=MONTHLY;BYDAY=54MO",
+        "2026-01-01T09:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string().ends_with("(at character 19: '54MO')"),
+        "{err}"
+    );
+    // Well-formed parts still expand, whatever their case.
+    assert!(expand_rrule(
+        "freq=monthly;byday=-1fr",
+        "2026-01-01T09:00:00",
+        30,
+        "UTC",
+        None,
+        Some(2)
+    )
+    .is_ok());
+}
+
+// ---------------------------------------------------------------------------
+// Numbered BYDAY values
+// ---------------------------------------------------------------------------
+
+fn days_of(rrule: &str, dtstart: &str, count: u32) -> Vec<String> {
+    expand_rrule(rrule, dtstart, 30, "UTC", None, Some(count))
+        .unwrap()
+        .iter()
+        .map(|e| e.start.format("%Y-%m-%d").to_string())
+        .collect()
+}
+
+#[test]
+fn numbered_and_plain_byday_values_combine() {
+    // First Monday and every Friday of the month.
+    assert_eq!(
+        days_of("FREQ=MONTHLY;BYDAY=1MO,FR", "2026-01-01T09:00:00", 7),
+        [
+            "2026-01-02",
+            "2026-01-05",
+            "2026-01-09",
+            "2026-01-16",
+            "2026-01-23",
+            "2026-01-30",
+            "2026-02-02"
+        ]
+    );
+    // Last Monday and every Saturday of May.
+    assert_eq!(
+        days_of(
+            "FREQ=YEARLY;BYMONTH=5;BYDAY=-1MO,SA",
+            "2026-05-01T09:00:00",
+            6
+        ),
+        [
+            "2026-05-02",
+            "2026-05-09",
+            "2026-05-16",
+            "2026-05-23",
+            "2026-05-25",
+            "2026-05-30"
+        ]
+    );
+    // Fourth Thursday of November; without BYMONTH, the ordinal counts
+    // through the year.
+    assert_eq!(
+        days_of("FREQ=YEARLY;BYMONTH=11;BYDAY=4TH", "2026-01-01T09:00:00", 2),
+        ["2026-11-26", "2027-11-25"]
+    );
+    assert_eq!(
+        days_of("FREQ=YEARLY;BYDAY=20MO,-1FR", "2026-01-01T09:00:00", 2),
+        ["2026-05-18", "2026-12-25"]
+    );
+
+    // The bitmask path agrees with the general one.
+    let rule = CompiledRule::new(
+        "FREQ=MONTHLY;BYDAY=2TU,-1FR,SA",
+        "2026-01-01T09:00:00",
+        "America/New_York",
+        None,
+        Some(40),
+        &[],
+    )
+    .unwrap();
+    assert!(rule.is_accelerated());
+    assert_eq!(
+        rule.expand(30),
+        rule.clone().without_acceleration().expand(30)
+    );
+}
+
+#[test]
+fn numbered_byday_outside_monthly_and_yearly_is_rejected() {
+    for (rrule, offset) in [
+        ("FREQ=WEEKLY;BYDAY=MO,2TU", 21),
+        ("FREQ=DAILY;BYDAY=-1FR", 17),
+        ("FREQ=YEARLY;BYWEEKNO=20;BYDAY=1MO", 30),
+    ] {
+        let err = expand_rrule(rrule, "2026-01-01T09:00:00", 30, "UTC", None, Some(3)).unwrap_err();
+        assert!(
+            matches!(err, truth_engine::TruthError::InvalidRule(_)),
+            "{rrule}"
+        );
+        assert_eq!(err.span().expect("located").offset, offset, "{rrule}");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// UNTIL semantics
+// ---------------------------------------------------------------------------
+
+fn count_until(rrule: &str, options: UntilOptions) -> usize {
+    expand_rrule_with_until(
+        rrule,
+        "2026-03-01T09:00:00",
+        30,
+        "America/New_York",
+        None,
+        None,
+        &[],
+        &options,
+    )
+    .unwrap()
+    .events
+    .len()
+}
+
+#[test]
+fn until_bounds_follow_the_options() {
+    let exclusive = UntilOptions {
+        inclusive: false,
+        ..Default::default()
+    };
+    // March 5 at 09:00 in New York is 14:00 UTC.
+    let exact = "FREQ=DAILY;UNTIL=20260305T140000Z";
+    assert_eq!(count_until(exact, UntilOptions::default()), 5);
+    assert_eq!(count_until(exact, exclusive), 4);
+
+    let date = "FREQ=DAILY;UNTIL=20260305";
+    assert_eq!(count_until(date, UntilOptions::default()), 5);
+    let start_of_day = UntilOptions {
+        date_value: UntilDate::StartOfDay,
+        ..Default::default()
+    };
+    assert_eq!(count_until(date, start_of_day), 4);
+
+    let floating = "FREQ=DAILY;UNTIL=20260305T120000";
+    assert_eq!(count_until(floating, UntilOptions::default()), 5);
+    let utc = UntilOptions {
+        floating_zone: UntilZone::Utc,
+        ..Default::default()
+    };
+    assert_eq!(count_until(floating, utc), 4);
+}
+
+#[test]
+fn applied_until_is_echoed() {
+    let options = UntilOptions {
+        inclusive: false,
+        date_value: UntilDate::StartOfDay,
+        floating_zone: UntilZone::Dtstart,
+    };
+    let expanded = expand_rrule_with_until(
+        "FREQ=WEEKLY;UNTIL=20260401",
+        "2026-03-02T09:00:00",
+        30,
+        "Europe/Berlin",
+        None,
+        None,
+        &[],
+        &options,
+    )
+    .unwrap();
+    let until = expanded.until.unwrap();
+    assert_eq!(until.value, "20260401");
+    assert_eq!(
+        until.instant,
+        Utc.with_ymd_and_hms(2026, 3, 31, 22, 0, 0).unwrap()
+    );
+    assert_eq!(until.options, options);
+    assert_eq!(expanded.events.len(), 5);
+
+    let unbounded = expand_rrule_with_until(
+        "FREQ=DAILY",
+        "2026-03-02T09:00:00",
+        30,
+        "UTC",
+        None,
+        Some(3),
+        &[],
+        &options,
+    )
+    .unwrap();
+    assert_eq!(unbounded.events.len(), 3);
+    assert_eq!(unbounded.until, None);
+}
+
+#[test]
+fn until_argument_is_local_to_dtstart_zone() {
+    let events = expand_rrule(
+        "FREQ=DAILY",
+        "2026-03-01T09:00:00",
+        30,
+        "America/New_York",
+        Some("2026-03-05T09:00:00"),
+        None,
+    )
+    .unwrap();
+    assert_eq!(events.len(), 5);
+    assert_eq!(
+        events[4].start,
+        Utc.with_ymd_and_hms(2026, 3, 5, 14, 0, 0).unwrap()
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Warnings
+// ---------------------------------------------------------------------------
+
+fn warning_kinds(
+    rrule: &str,
+    dtstart: &str,
+    timezone: &str,
+    count: Option<u32>,
+    exdates: &[&str],
+) -> Vec<WarningKind> {
+    expand_rrule_with_warnings(rrule, dtstart, 30, timezone, None, count, exdates)
+        .unwrap()
+        .warnings
+        .iter()
+        .map(|w| w.kind)
+        .collect()
+}
+
+#[test]
+fn clean_expansion_has_no_warnings() {
+    let expanded = expand_rrule_with_warnings(
+        "FREQ=WEEKLY;BYDAY=MO",
+        "2026-01-05T09:00:00",
+        30,
+        "America/New_York",
+        None,
+        Some(20),
+        &["2026-01-12T09:00:00"],
+    )
+    .unwrap();
+    assert!(expanded.warnings.is_empty(), "{:?}", expanded.warnings);
+    assert_eq!(expanded.result.len(), 19);
+}
+
+#[test]
+fn unbounded_expansion_warns_of_truncation() {
+    let expanded = expand_rrule_with_warnings(
+        "FREQ=DAILY",
+        "2026-01-01T09:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+        &[],
+    )
+    .unwrap();
+    assert_eq!(expanded.result.len(), 500);
+    assert!(expanded.has(WarningKind::Truncated));
+
+    // A series that ends within the limit is not truncated.
+    let kinds = warning_kinds(
+        "FREQ=DAILY;COUNT=500",
+        "2026-01-01T09:00:00",
+        "UTC",
+        None,
+        &[],
+    );
+    assert!(kinds.is_empty(), "{kinds:?}");
+    let kinds = warning_kinds(
+        "FREQ=DAILY;UNTIL=20260201T000000Z",
+        "2026-01-01T09:00:00",
+        "UTC",
+        None,
+        &[],
+    );
+    assert!(kinds.is_empty(), "{kinds:?}");
+}
+
+#[test]
+fn dst_gap_and_fold_occurrences_warn() {
+    // 02:30 does not exist on 2026-03-08 in New York.
+    let expanded = expand_rrule_with_warnings(
+        "FREQ=DAILY;COUNT=3",
+        "2026-03-07T02:30:00",
+        30,
+        "America/New_York",
+        None,
+        None,
+        &[],
+    )
+    .unwrap();
+    assert_eq!(expanded.warnings.len(), 1);
+    assert_eq!(expanded.warnings[0].kind, WarningKind::DstShifted);
+    assert!(expanded.warnings[0].message.contains("2026-03-08"));
+
+    // 01:30 occurs twice on 2026-11-01.
+    let expanded = expand_rrule_with_warnings(
+        "FREQ=DAILY;COUNT=3",
+        "2026-10-31T01:30:00",
+        30,
+        "America/New_York",
+        None,
+        None,
+        &[],
+    )
+    .unwrap();
+    assert_eq!(expanded.warnings.len(), 1);
+    assert_eq!(expanded.warnings[0].kind, WarningKind::DstAmbiguous);
+    assert_eq!(
+        expanded.result[1].start,
+        Utc.with_ymd_and_hms(2026, 11, 1, 5, 30, 0).unwrap()
+    );
+    assert!(expanded.warnings[0].message.contains("earlier"));
+
+    // Hourly rules cross the gap by design.
+    let kinds = warning_kinds(
+        "FREQ=HOURLY;COUNT=6",
+        "2026-03-08T00:30:00",
+        "America/New_York",
+        None,
+        &[],
+    );
+    assert!(kinds.is_empty(), "{kinds:?}");
+}
+
+#[test]
+fn exdates_that_match_nothing_warn() {
+    let kinds = warning_kinds(
+        "FREQ=WEEKLY;BYDAY=MO",
+        "2026-01-05T09:00:00",
+        "UTC",
+        Some(4),
+        &[
+            "2026-01-12T09:00:00",
+            "2026-01-13T09:00:00",
+            "2026-01-12T10:00:00",
+        ],
+    );
+    assert_eq!(
+        kinds,
+        [WarningKind::UnmatchedExdate, WarningKind::UnmatchedExdate]
+    );
+
+    // Past the truncation limit, an EXDATE may match an instance that was
+    // never generated, so only truncation is reported.
+    let kinds = warning_kinds(
+        "FREQ=DAILY",
+        "2026-01-01T09:00:00",
+        "UTC",
+        None,
+        &["2030-01-01T09:00:00"],
+    );
+    assert_eq!(kinds, [WarningKind::Truncated]);
+}
+
+// ---------------------------------------------------------------------------
+// Compiled rules and occurrence buffers
+// ---------------------------------------------------------------------------
+
+/// rrule, dtstart, timezone, until, count, exdates
+type Series<'a> = (
+    &'a str,
+    &'a str,
+    &'a str,
+    Option<&'a str>,
+    Option<u32>,
+    &'a [&'a str],
+);
+
+#[test]
+fn buffered_expansion_matches_expand_rrule_with_exdates() {
+    let cases: [Series; 4] = [
+        (
+            "FREQ=DAILY",
+            "2026-03-06T02:30:00",
+            "America/New_York",
+            None,
+            Some(5),
+            &[],
+        ),
+        (
+            "FREQ=WEEKLY;BYDAY=TU,TH",
+            "2026-01-06T14:00:00",
+            "UTC",
+            Some("2026-02-28T00:00:00"),
+            None,
+            &["2026-01-08T14:00:00", "2026-01-20T14:00:00"],
+        ),
+        (
+            "FREQ=MONTHLY;BYMONTHDAY=31",
+            "2026-01-31T09:00:00",
+            "UTC",
+            None,
+            Some(4),
+            &["2026-03-31T09:00:00"],
+        ),
+        (
+            "FREQ=DAILY",
+            "2026-01-01T09:00:00",
+            "Asia/Kolkata",
+            None,
+            None,
+            &[],
+        ),
+    ];
+    let mut buffer = OccurrenceBuffer::new();
+    for (rrule, dtstart, tz, until, count, exdates) in cases {
+        let expected =
+            expand_rrule_with_exdates(rrule, dtstart, 50, tz, until, count, exdates).unwrap();
+        let rule = CompiledRule::new(rrule, dtstart, tz, until, count, exdates).unwrap();
+        assert_eq!(rule.expand(50), expected, "{rrule}");
+
+        // The buffer holds bounds only.
+        let plain = |e: &ExpandedEvent| ExpandedEvent::new(e.start, e.end);
+        let expected: Vec<_> = expected.iter().map(plain).collect();
+        buffer.clear();
+        assert_eq!(rule.expand_into(&mut buffer, 50), expected.len(), "{rrule}");
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), expected, "{rrule}");
+        assert_eq!(buffer.get(0).as_ref(), expected.first());
+        assert_eq!(buffer.get(expected.len()), None);
+    }
+}
+
+#[test]
+fn buffers_append_and_keep_capacity() {
+    let rule = CompiledRule::new(
+        "FREQ=DAILY",
+        "2026-01-01T09:00:00",
+        "UTC",
+        None,
+        Some(3),
+        &[],
+    )
+    .unwrap();
+    let mut buffer = OccurrenceBuffer::with_capacity(8);
+    rule.expand_into(&mut buffer, 30);
+    rule.expand_into(&mut buffer, 60);
+    assert_eq!(buffer.len(), 6);
+    assert_eq!(buffer.starts[0], buffer.starts[3]);
+    assert_eq!(buffer.ends[3] - buffer.starts[3], 3600);
+
+    let capacity = buffer.starts.capacity();
+    buffer.clear();
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.starts.capacity(), capacity);
+
+    assert!(matches!(
+        CompiledRule::new("", "2026-01-01T09:00:00", "UTC", None, None, &[]),
+        Err(truth_engine::TruthError::InvalidRule(_))
+    ));
+}
+
+#[test]
+fn simple_rules_take_the_bitmask_path_with_identical_results() {
+    let accelerated: [Series; 4] = [
+        (
+            "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR",
+            "2026-03-06T02:30:00",
+            "America/New_York",
+            None,
+            Some(10),
+            &["2026-03-10T02:30:00"],
+        ),
+        (
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=SU,TU;WKST=SU",
+            "2026-10-20T01:30:00",
+            "Europe/London",
+            None,
+            Some(6),
+            &[],
+        ),
+        (
+            "FREQ=MONTHLY;BYDAY=-1FR",
+            "2026-01-30T16:00:00",
+            "UTC",
+            Some("2026-12-31T00:00:00"),
+            None,
+            &["2026-07-31T16:00:00"],
+        ),
+        (
+            "FREQ=MONTHLY;BYMONTHDAY=1,-1;BYDAY=MO,TU,WE,TH,FR",
+            "2026-01-01T09:00:00",
+            "Australia/Lord_Howe",
+            None,
+            Some(12),
+            &[],
+        ),
+    ];
+    for (rrule, dtstart, tz, until, count, exdates) in accelerated {
+        let rule = CompiledRule::new(rrule, dtstart, tz, until, count, exdates).unwrap();
+        assert!(rule.is_accelerated(), "{rrule}");
+        let general = rule.clone().without_acceleration();
+        assert!(!general.is_accelerated());
+        assert_eq!(rule.expand(45), general.expand(45), "{rrule}");
+        assert_eq!(
+            rule.expand(45),
+            expand_rrule_with_exdates(rrule, dtstart, 45, tz, until, count, exdates).unwrap()
+        );
+    }
+
+    for rrule in [
+        "FREQ=YEARLY",
+        "FREQ=MONTHLY;BYDAY=TU;BYSETPOS=3",
+        "FREQ=MONTHLY;BYMONTH=1,7;BYMONTHDAY=1",
+        "FREQ=WEEKLY;BYDAY=MO;BYHOUR=9,15",
+        "FREQ=DAILY;BYMONTHDAY=-1",
+        "FREQ=HOURLY",
+    ] {
+        let rule =
+            CompiledRule::new(rrule, "2026-01-05T09:00:00", "UTC", None, Some(5), &[]).unwrap();
+        assert!(!rule.is_accelerated(), "{rrule}");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Previews
+// ---------------------------------------------------------------------------
+
+#[test]
+fn preview_labels_occurrences_and_adds_later_years() {
+    let next = preview("FREQ=MONTHLY;BYMONTHDAY=3", "2026-11-03T09:00:00", "PST", 3).unwrap();
+    let labels: Vec<_> = next.iter().map(|o| o.label.as_str()).collect();
+    assert_eq!(
+        labels,
+        [
+            "Tue, Nov 3 at 9:00 AM PST",
+            "Thu, Dec 3 at 9:00 AM PST",
+            "Sun, Jan 3, 2027 at 9:00 AM PST",
+        ]
+    );
+    assert_eq!(next[0].local, "2026-11-03T09:00:00-08:00");
+    assert_eq!(
+        next.iter().map(|o| o.start).collect::<Vec<_>>(),
+        expand_rrule(
+            "FREQ=MONTHLY;BYMONTHDAY=3",
+            "2026-11-03T09:00:00",
+            0,
+            "America/Los_Angeles",
+            None,
+            Some(3)
+        )
+        .unwrap()
+        .iter()
+        .map(|e| e.start)
+        .collect::<Vec<_>>()
+    );
+
+    // The rule's own COUNT still ends the series.
+    assert_eq!(
+        preview("FREQ=DAILY;COUNT=2", "2026-03-03T09:00:00", "UTC", 5)
+            .unwrap()
+            .len(),
+        2
+    );
+    assert!(preview("FREQ=DAILY", "2026-03-03T09:00:00", "UTC", 0)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn preview_labels_follow_locale_and_clock() {
+    let label = |locale, clock| {
+        preview_in(
+            "FREQ=WEEKLY",
+            "2026-03-03T14:30:00",
+            "Europe/Berlin",
+            1,
+            locale,
+            clock,
+        )
+        .unwrap()[0]
+            .label
+            .clone()
+    };
+    assert_eq!(label(Locale::German, None), "Di., 3. März um 14:30 CET");
+    assert_eq!(label(Locale::French, None), "mar. 3 mars à 14:30 CET");
+    assert_eq!(label(Locale::Spanish, None), "mar, 3 mar, 14:30 CET");
+    assert_eq!(
+        label(Locale::English, Some(ClockFormat::TwentyFourHour)),
+        "Tue, Mar 3 at 14:30 CET"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// EXDATE/RDATE normalization
+// ---------------------------------------------------------------------------
+
+#[test]
+fn exception_lists_normalize_to_sorted_local_times() {
+    // Daily at 09:00 New York; 14:00Z is 09:00 EST, 13:00Z 09:00 EDT.
+    let checked = normalize_exception_dates(
+        "FREQ=DAILY;COUNT=30",
+        "2026-03-02T09:00:00",
+        "America/New_York",
+        &[
+            "20260310T130000Z",
+            "2026-03-05T14:00:00+00:00",
+            "20260303T090000",
+            "2026-03-04T09:00:00",
+        ],
+        &["2026-04-15T12:00:00", "20260402T100000"],
+    )
+    .unwrap();
+    assert_eq!(
+        checked.result.exdates,
+        [
+            "2026-03-03T09:00:00",
+            "2026-03-04T09:00:00",
+            "2026-03-05T09:00:00",
+            "2026-03-10T09:00:00",
+        ]
+    );
+    assert_eq!(
+        checked.result.rdates,
+        ["2026-04-02T10:00:00", "2026-04-15T12:00:00"]
+    );
+    let kinds: Vec<_> = checked.warnings.iter().map(|w| w.kind).collect();
+    assert_eq!(kinds, [WarningKind::MixedValueTypes]);
+
+    // The normalized EXDATEs remove exactly the occurrences listed.
+    let exdates: Vec<&str> = checked.result.exdates.iter().map(String::as_str).collect();
+    let expanded = expand_rrule_with_exdates(
+        "FREQ=DAILY;COUNT=30",
+        "2026-03-02T09:00:00",
+        60,
+        "America/New_York",
+        None,
+        None,
+        &exdates,
+    )
+    .unwrap();
+    assert_eq!(expanded.len(), 26);
+}
+
+#[test]
+fn junk_exception_values_are_flagged_and_dropped() {
+    let checked = normalize_exception_dates(
+        "FREQ=WEEKLY;BYDAY=MO,WE;BYHOUR=9,15",
+        "2026-03-02T09:00:00",
+        "UTC",
+        &[
+            "2026-03-04",
+            "2026-03-04T15:00:00",
+            "2026-03-05",
+            "next tuesday",
+            "2026-03-02T10:00:00",
+        ],
+        &["2026-03-09T15:00:00", "2026-03-10", "20260310"],
+    )
+    .unwrap();
+    // A date EXDATE removes every occurrence that day.
+    assert_eq!(
+        checked.result.exdates,
+        ["2026-03-04T09:00:00", "2026-03-04T15:00:00"]
+    );
+    // A date RDATE takes DTSTART's time of day.
+    assert_eq!(checked.result.rdates, ["2026-03-10T09:00:00"]);
+
+    let kinds: Vec<_> = checked.warnings.iter().map(|w| w.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            WarningKind::InvalidDate,
+            WarningKind::MixedValueTypes,
+            WarningKind::MixedValueTypes,
+            WarningKind::DuplicateDate,
+            WarningKind::UnmatchedExdate,
+            WarningKind::UnmatchedExdate,
+            WarningKind::DuplicateDate,
+            WarningKind::DuplicateDate,
+        ]
+    );
+    assert!(checked.warnings[0].message.contains("\"next tuesday\""));
+    assert!(checked.warnings[1]
+        .message
+        .contains("mix dates and local date-times"));
+    assert!(checked.warnings[6]
+        .message
+        .contains("already an occurrence"));
+
+    assert!(matches!(
+        normalize_exception_dates("FREQ=SOMETIMES", "2026-03-02T09:00:00", "UTC", &[], &[]),
+        Err(truth_engine::TruthError::InvalidRule(_))
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Per-occurrence metadata
+// ---------------------------------------------------------------------------
+
+#[test]
+fn series_occurrences_carry_their_index_and_origin() {
+    let at = |d, h| Utc.with_ymd_and_hms(2026, 3, d, h, 0, 0).unwrap();
+    // Weekdays at 9:00 UTC from Monday March 2.
+    let series = RecurringSeries {
+        rrule: "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR".to_string(),
+        dtstart: "2026-03-02T09:00:00".to_string(),
+        duration_minutes: 60,
+        timezone: "UTC".to_string(),
+        exdates: vec!["2026-03-03T09:00:00".to_string()],
+        rdates: vec!["2026-03-07T10:00:00".to_string()],
+        overrides: vec![
+            OccurrenceOverride {
+                recurrence_id: "2026-03-04T09:00:00".to_string(),
+                event: Some(ExpandedEvent::new(at(4, 15), at(4, 16))),
+            },
+            OccurrenceOverride {
+                recurrence_id: "2026-03-05T09:00:00".to_string(),
+                event: None,
+            },
+            // Same time, new length: an override, but not moved.
+            OccurrenceOverride {
+                recurrence_id: "2026-03-06T09:00:00".to_string(),
+                event: Some(ExpandedEvent::new(at(6, 9), at(6, 11))),
+            },
+        ],
+    };
+    let occurrences = series.occurrences(at(2, 0), at(9, 0)).unwrap();
+    let plain = |index, start: chrono::DateTime<Utc>| ExpandedEvent {
+        occurrence_index: Some(index),
+        ..ExpandedEvent::new(start, start + chrono::Duration::hours(1))
+    };
+    assert_eq!(
+        occurrences,
+        vec![
+            plain(0, at(2, 9)),
+            // Index 1 (Tuesday) is an EXDATE and index 3 (Thursday) is
+            // cancelled; the rest keep their places.
+            ExpandedEvent {
+                start: at(4, 15),
+                end: at(4, 16),
+                is_override: true,
+                original_start: Some(at(4, 9)),
+                ..plain(2, at(4, 9))
+            },
+            ExpandedEvent {
+                end: at(6, 11),
+                is_override: true,
+                ..plain(4, at(6, 9))
+            },
+            ExpandedEvent {
+                is_rdate: true,
+                ..plain(5, at(7, 10))
+            },
+        ]
+    );
+
+    // An occurrence moved into the window from outside it keeps its index.
+    let moved_in = RecurringSeries {
+        exdates: vec![],
+        rdates: vec![],
+        overrides: vec![OccurrenceOverride {
+            recurrence_id: "2026-03-16T09:00:00".to_string(),
+            event: Some(ExpandedEvent::new(at(4, 12), at(4, 13))),
+        }],
+        ..series
+    };
+    let occurrences = moved_in.occurrences(at(4, 11), at(4, 14)).unwrap();
+    assert_eq!(occurrences.len(), 1);
+    assert_eq!(occurrences[0].occurrence_index, Some(10));
+    assert_eq!(occurrences[0].original_start, Some(at(16, 9)));
+}
+
+#[test]
+fn plain_expansion_numbers_occurrences_past_exdates() {
+    let exdates = ["2026-03-03T09:00:00", "2026-03-05T09:00:00"];
+    let events = expand_rrule_with_exdates(
+        "FREQ=DAILY;COUNT=5",
+        "2026-03-02T09:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+        &exdates,
+    )
+    .unwrap();
+    let indexes: Vec<_> = events.iter().map(|e| e.occurrence_index).collect();
+    assert_eq!(indexes, [Some(0), Some(2), Some(4)]);
+
+    // The general path numbers them the same way as the bitmask path.
+    let rule = CompiledRule::new(
+        "FREQ=DAILY;COUNT=5",
+        "2026-03-02T09:00:00",
+        "UTC",
+        None,
+        None,
+        &exdates,
+    )
+    .unwrap();
+    assert!(rule.is_accelerated());
+    assert_eq!(
+        rule.clone().without_acceleration().expand(30),
+        rule.expand(30)
+    );
+    assert_eq!(rule.expand(30), events);
+}
+
+#[test]
+fn series_batches_report_each_failure_in_place() {
+    let at = |d| Utc.with_ymd_and_hms(2026, 3, d, 0, 0, 0).unwrap();
+    let series = |dtstart: &str, timezone: &str| RecurringSeries {
+        rrule: "FREQ=DAILY".to_string(),
+        dtstart: dtstart.to_string(),
+        duration_minutes: 30,
+        timezone: timezone.to_string(),
+        exdates: vec![],
+        rdates: vec![],
+        overrides: vec![],
+    };
+    let results = expand_series_batch(
+        &[
+            series("2026-03-02T09:00:00", "UTC"),
+            series("2026-03-02T09:00:00", "Mars/Olympus"),
+            series("next monday", "UTC"),
+            series("2026-03-04T09:00:00", "Asia/Tokyo"),
+        ],
+        at(2),
+        at(9),
+    );
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().len(), 7);
+    assert!(matches!(
+        results[1],
+        Err(truth_engine::TruthError::InvalidTimezone(_))
+    ));
+    assert!(results[2].is_err());
+    assert_eq!(results[3].as_ref().unwrap().len(), 5);
+}
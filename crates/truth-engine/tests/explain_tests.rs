@@ -0,0 +1,170 @@
+//! Tests for explanation traces.
+
+use chrono::{DateTime, TimeZone, Utc};
+use truth_engine::explain::{explain_expansion, explain_free_slots};
+#[cfg(feature = "resolver")]
+use truth_engine::temporal::{resolve_relative_with_options, ResolveOptions};
+use truth_engine::ExpandedEvent;
+
+fn utc(d: u32, h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, d, h, m, 0).unwrap()
+}
+
+fn sources(trace: &[truth_engine::explain::TraceStep]) -> Vec<&str> {
+    trace.iter().map(|s| s.source.as_str()).collect()
+}
+
+#[test]
+fn expansion_names_the_rule_parts() {
+    let explained = explain_expansion(
+        "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH",
+        "2026-03-03T14:00:00",
+        60,
+        "America/Los_Angeles",
+        None,
+        Some(4),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(explained.occurrences.len(), 4);
+    assert!(explained.excluded.is_empty());
+
+    let first = &explained.occurrences[0];
+    assert_eq!(
+        sources(&first.trace),
+        vec!["DTSTART", "FREQ", "BYDAY", "COUNT"]
+    );
+    // The third occurrence is Tuesday of the next active week, two weeks on.
+    let third = &explained.occurrences[2];
+    assert_eq!(third.instance, 3);
+    assert_eq!(
+        third.trace[0].detail,
+        "WEEKLY: every 2 weeks; this is week 2 after DTSTART's"
+    );
+    assert_eq!(third.trace[1].detail, "TU: Tuesday");
+    assert_eq!(third.trace[2].detail, "occurrence 3 of 4");
+}
+
+#[test]
+fn expansion_reports_exdates_and_month_days() {
+    let explained = explain_expansion(
+        "FREQ=MONTHLY;BYMONTHDAY=-1",
+        "2026-01-31T09:00:00",
+        30,
+        "UTC",
+        Some("2026-04-30T23:59:59"),
+        None,
+        &["2026-02-28T09:00:00"],
+    )
+    .unwrap();
+    let starts: Vec<_> = explained
+        .occurrences
+        .iter()
+        .map(|o| (o.instance, o.event.start))
+        .collect();
+    assert_eq!(
+        starts,
+        vec![
+            (1, Utc.with_ymd_and_hms(2026, 1, 31, 9, 0, 0).unwrap()),
+            (3, Utc.with_ymd_and_hms(2026, 3, 31, 9, 0, 0).unwrap()),
+            (4, Utc.with_ymd_and_hms(2026, 4, 30, 9, 0, 0).unwrap()),
+        ]
+    );
+    let april = &explained.occurrences[2].trace;
+    assert!(april
+        .iter()
+        .any(|s| s.detail == "-1: day 30, counted from the end of the month"));
+    assert!(april.iter().any(|s| s.source == "UNTIL"));
+
+    assert_eq!(explained.excluded.len(), 1);
+    let excluded = &explained.excluded[0];
+    assert_eq!(excluded.instance, 2);
+    assert_eq!(
+        excluded.trace.last().unwrap().detail,
+        "removed by EXDATE 2026-02-28T09:00:00"
+    );
+}
+
+#[test]
+fn free_slots_name_their_busy_blocks() {
+    let events = [
+        ExpandedEvent::new(utc(2, 13, 0), utc(2, 14, 0)),
+        ExpandedEvent::new(utc(2, 9, 30), utc(2, 10, 0)),
+        // Touches the previous event, so they form one block.
+        ExpandedEvent::new(utc(2, 10, 0), utc(2, 11, 0)),
+        // Outside the window.
+        ExpandedEvent::new(utc(3, 9, 0), utc(3, 10, 0)),
+    ];
+    let explained = explain_free_slots(&events, utc(2, 9, 0), utc(2, 17, 0), 45);
+    let blocks: Vec<_> = explained
+        .busy
+        .iter()
+        .map(|b| (b.start, b.end, b.events.clone()))
+        .collect();
+    assert_eq!(
+        blocks,
+        vec![
+            (utc(2, 9, 30), utc(2, 11, 0), vec![1, 2]),
+            (utc(2, 13, 0), utc(2, 14, 0), vec![0]),
+        ]
+    );
+
+    let slots = &explained.slots;
+    assert_eq!(slots.len(), 3);
+    // 09:00–09:30: from the window start to the first block, too short.
+    assert!(!slots[0].fits);
+    assert_eq!(
+        sources(&slots[0].trace),
+        vec!["window", "busy", "min_duration"]
+    );
+    assert_eq!(slots[0].trace[1].refs, vec![1, 2]);
+    assert_eq!(
+        slots[0].trace[2].detail,
+        "30 minutes is shorter than the 45 required"
+    );
+    assert!(slots[1].fits);
+    assert_eq!(slots[1].trace[0].refs, vec![1, 2]);
+    assert_eq!(slots[1].trace[1].refs, vec![0]);
+    assert_eq!(
+        (slots[2].slot.start, slots[2].slot.end),
+        (utc(2, 14, 0), utc(2, 17, 0))
+    );
+    assert_eq!(slots[2].trace[1].source, "window");
+}
+
+#[cfg(feature = "resolver")]
+#[test]
+fn resolve_relative_traces_parsers() {
+    let anchor = utc(4, 15, 0);
+    let options = ResolveOptions {
+        explain: true,
+        lenient: true,
+        ..Default::default()
+    };
+    let r = resolve_relative_with_options(anchor, "  Next Business Day ", "UTC", &options).unwrap();
+    let trace = r.trace.unwrap();
+    assert_eq!(sources(&trace), vec!["normalize", "parser:business_day"]);
+    assert_eq!(trace[0].detail, "'Next Business Day' → 'next business day'");
+    assert_eq!(
+        trace[1].detail,
+        "'next business day' → 2026-03-05T09:00:00+00:00"
+    );
+
+    // The inner expression's parser comes before the offset's.
+    let r =
+        resolve_relative_with_options(anchor, "3 days after tommorow", "UTC", &options).unwrap();
+    let trace = r.trace.unwrap();
+    assert_eq!(
+        sources(&trace),
+        vec!["typo", "parser:anchored", "parser:relative_to"]
+    );
+    assert_eq!(trace[0].detail, "'tommorow' → 'tomorrow'");
+
+    let r = resolve_relative_with_options(anchor, "3pm tokyo time", "UTC", &options).unwrap();
+    assert_eq!(r.trace.unwrap()[0].source, "timezone");
+
+    // Off by default.
+    let r = resolve_relative_with_options(anchor, "tomorrow", "UTC", &ResolveOptions::default())
+        .unwrap();
+    assert_eq!(r.trace, None);
+}
@@ -0,0 +1,135 @@
+//! Tests for week-based fiscal calendars.
+
+use chrono::{Month, NaiveDate, Weekday};
+#[cfg(feature = "resolver")]
+use chrono::{TimeZone, Utc};
+use truth_engine::fiscal::{FiscalCalendar, FiscalYearEnd};
+#[cfg(feature = "resolver")]
+use truth_engine::temporal::{
+    resolve_relative_with_options, ResolutionGranularity, ResolveOptions,
+};
+use truth_engine::TruthError;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+const LAST_SAT_OF_JANUARY: FiscalYearEnd = FiscalYearEnd::LastWeekday {
+    weekday: Weekday::Sat,
+    month: Month::January,
+};
+
+#[test]
+fn four_four_five_periods() {
+    let calendar = FiscalCalendar::four_four_five(LAST_SAT_OF_JANUARY);
+    let periods = calendar.periods(2027).unwrap();
+    assert_eq!(periods.len(), 12);
+    assert_eq!(calendar.weeks_in_year(2027), Some(52));
+    let weeks: Vec<_> = periods.iter().map(|p| p.weeks()).collect();
+    assert_eq!(weeks, vec![4, 4, 5, 4, 4, 5, 4, 4, 5, 4, 4, 5]);
+    assert_eq!(periods[0].start, date(2026, 2, 1));
+    assert_eq!(
+        (periods[6].start, periods[6].end),
+        (date(2026, 8, 2), date(2026, 8, 29))
+    );
+    assert_eq!(periods[11].end, date(2027, 1, 30));
+    for pair in periods.windows(2) {
+        assert_eq!(pair[0].end.succ_opt(), Some(pair[1].start));
+    }
+
+    let weeks: Vec<_> = FiscalCalendar::four_five_four(LAST_SAT_OF_JANUARY)
+        .periods(2027)
+        .unwrap()
+        .iter()
+        .take(3)
+        .map(|p| p.weeks())
+        .collect();
+    assert_eq!(weeks, vec![4, 5, 4]);
+}
+
+#[test]
+fn long_years_extend_the_last_period() {
+    let calendar = FiscalCalendar::four_four_five(LAST_SAT_OF_JANUARY);
+    assert_eq!(calendar.weeks_in_year(2026), Some(53));
+    let last = calendar.period_of(date(2026, 1, 31)).unwrap();
+    assert_eq!((last.year, last.number, last.weeks()), (2026, 12, 6));
+}
+
+#[test]
+fn nearest_weekday_can_end_after_the_month() {
+    let calendar = FiscalCalendar::four_four_five(FiscalYearEnd::NearestWeekday {
+        weekday: Weekday::Sat,
+        month: Month::January,
+    });
+    // January 31, 2025 is a Friday; the nearest Saturday is February 1.
+    assert_eq!(calendar.year_end(2025), Some(date(2025, 2, 1)));
+    // January 31, 2027 is a Sunday; the nearest Saturday is the day before.
+    assert_eq!(calendar.year_end(2027), Some(date(2027, 1, 30)));
+    assert_eq!(calendar.year_of(date(2025, 2, 1)), Some(2025));
+    assert_eq!(calendar.year_of(date(2025, 2, 2)), Some(2026));
+}
+
+#[test]
+fn current_and_shifted_periods() {
+    let calendar = FiscalCalendar::four_four_five(LAST_SAT_OF_JANUARY);
+    let current = calendar.period_of(date(2026, 8, 10)).unwrap();
+    assert_eq!((current.year, current.number), (2027, 7));
+
+    let next_year = calendar.shift_period(date(2027, 1, 15), 1).unwrap();
+    assert_eq!((next_year.year, next_year.number), (2028, 1));
+    assert_eq!(next_year.start, date(2027, 1, 31));
+    let last_year = calendar.shift_period(date(2026, 8, 10), -12).unwrap();
+    assert_eq!((last_year.year, last_year.number), (2026, 7));
+    assert_eq!(calendar.period(2027, 13), None);
+    assert_eq!(calendar.period(2027, 0), None);
+}
+
+#[test]
+fn custom_layouts_must_cover_52_weeks() {
+    assert!(FiscalCalendar::new(LAST_SAT_OF_JANUARY, vec![4; 13]).is_ok());
+    assert!(matches!(
+        FiscalCalendar::new(LAST_SAT_OF_JANUARY, vec![4, 4]),
+        Err(TruthError::InvalidFiscalCalendar(_))
+    ));
+    assert!(matches!(
+        FiscalCalendar::new(LAST_SAT_OF_JANUARY, vec![0, 52]),
+        Err(TruthError::InvalidFiscalCalendar(_))
+    ));
+}
+
+#[cfg(feature = "resolver")]
+#[test]
+fn resolve_relative_understands_periods() {
+    let options = ResolveOptions {
+        fiscal_calendar: Some(FiscalCalendar::four_four_five(LAST_SAT_OF_JANUARY)),
+        ..Default::default()
+    };
+    let anchor = Utc.with_ymd_and_hms(2026, 8, 10, 15, 0, 0).unwrap();
+    let resolve = |expr: &str| resolve_relative_with_options(anchor, expr, "UTC", &options);
+
+    let r = resolve("end of P7").unwrap();
+    assert_eq!(r.resolved_utc, "2026-08-29T23:59:59+00:00");
+    assert_eq!(r.granularity, ResolutionGranularity::Date);
+    let r = resolve("P7").unwrap();
+    assert_eq!(r.resolved_utc, "2026-08-02T00:00:00+00:00");
+    assert_eq!(r.granularity, ResolutionGranularity::Period);
+    assert_eq!(
+        resolve("start of next period").unwrap().resolved_utc,
+        "2026-08-30T00:00:00+00:00"
+    );
+    assert_eq!(
+        resolve("end of last fiscal period").unwrap().resolved_utc,
+        "2026-08-01T23:59:59+00:00"
+    );
+    assert_eq!(
+        resolve("start of P1 FY2028").unwrap().resolved_utc,
+        "2027-01-31T00:00:00+00:00"
+    );
+    assert!(resolve("P13").is_err());
+
+    // Without a fiscal calendar, periods are not understood.
+    assert!(
+        resolve_relative_with_options(anchor, "end of P7", "UTC", &ResolveOptions::default())
+            .is_err()
+    );
+}
@@ -3,8 +3,13 @@
 //! All tests should compile but fail with `todo!()` panics until implementation.
 
 use chrono::{TimeZone, Utc};
+use truth_engine::conflict::find_conflicts;
 use truth_engine::expander::ExpandedEvent;
-use truth_engine::freebusy::{find_first_free_slot, find_free_slots};
+use truth_engine::freebusy::{
+    find_first_free_slot, find_free_slots, is_slot_free, label_slots, normalize_busy,
+    SlotLabelOptions,
+};
+use truth_engine::TruthError;
 
 /// Helper to create an ExpandedEvent from hour ranges on a given day.
 fn event(
@@ -16,14 +21,12 @@ fn event(
     end_hour: u32,
     end_min: u32,
 ) -> ExpandedEvent {
-    ExpandedEvent {
-        start: Utc
-            .with_ymd_and_hms(year, month, day, start_hour, start_min, 0)
+    ExpandedEvent::new(
+        Utc.with_ymd_and_hms(year, month, day, start_hour, start_min, 0)
             .unwrap(),
-        end: Utc
-            .with_ymd_and_hms(year, month, day, end_hour, end_min, 0)
+        Utc.with_ymd_and_hms(year, month, day, end_hour, end_min, 0)
             .unwrap(),
-    }
+    )
 }
 
 #[test]
@@ -196,3 +199,111 @@ fn multiple_gaps_between_events() {
     assert_eq!(slots[2].duration_minutes, 120); // 13:00-15:00
     assert_eq!(slots[3].duration_minutes, 120); // 16:00-18:00
 }
+
+#[test]
+fn normalize_busy_merges_near_adjacent_events_and_clips() {
+    let feed = vec![
+        event(2026, 3, 2, 13, 0, 14, 0),
+        event(2026, 3, 2, 7, 0, 9, 30),
+        event(2026, 3, 2, 9, 32, 10, 0),
+        event(2026, 3, 2, 9, 45, 10, 15),
+        event(2026, 3, 2, 16, 50, 18, 0),
+    ];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 2, 17, 0, 0).unwrap();
+
+    assert_eq!(
+        normalize_busy(&feed, window_start, window_end, 5),
+        vec![
+            event(2026, 3, 2, 9, 0, 10, 15),
+            event(2026, 3, 2, 13, 0, 14, 0),
+            event(2026, 3, 2, 16, 50, 17, 0),
+        ]
+    );
+    // Without a tolerance the two-minute gap stays free.
+    assert_eq!(normalize_busy(&feed, window_start, window_end, 0).len(), 4);
+    assert!(normalize_busy(&feed, window_end, window_start, 5).is_empty());
+}
+
+#[test]
+fn slot_check_matches_conflict_detection_with_the_slot() {
+    let events = vec![
+        event(2026, 3, 2, 13, 0, 14, 0),
+        event(2026, 3, 2, 9, 0, 10, 0),
+        event(2026, 3, 2, 9, 30, 11, 0),
+        event(2026, 3, 2, 15, 0, 15, 0),
+    ];
+    let slots = [
+        event(2026, 3, 2, 11, 0, 13, 0),
+        event(2026, 3, 2, 10, 30, 13, 30),
+        event(2026, 3, 2, 8, 0, 18, 0),
+        event(2026, 3, 2, 9, 45, 9, 45),
+    ];
+    for slot in &slots {
+        let check = is_slot_free(slot.start, slot.end, &events).unwrap();
+        let conflicts: Vec<ExpandedEvent> = find_conflicts(std::slice::from_ref(slot), &events)
+            .into_iter()
+            .map(|c| c.event_b)
+            .collect();
+        assert_eq!(check.free, conflicts.is_empty(), "{slot:?}");
+        let mut expected = conflicts;
+        expected.sort_by_key(|e| e.start);
+        assert_eq!(check.blocking, expected, "{slot:?}");
+    }
+
+    // Touching both neighbours is free; overlapping two blocks lists both by start.
+    assert!(
+        is_slot_free(slots[0].start, slots[0].end, &events)
+            .unwrap()
+            .free
+    );
+    let check = is_slot_free(slots[1].start, slots[1].end, &events).unwrap();
+    assert_eq!(check.blocking, vec![events[2].clone(), events[0].clone()]);
+
+    assert!(matches!(
+        is_slot_free(slots[0].end, slots[0].start, &events),
+        Err(TruthError::InvalidArguments(_))
+    ));
+}
+
+#[test]
+fn slots_are_labeled_in_each_display_timezone() {
+    let events = vec![event(2026, 3, 2, 15, 0, 16, 0)];
+    let free = find_free_slots(
+        &events,
+        Utc.with_ymd_and_hms(2026, 3, 2, 14, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2026, 3, 2, 17, 0, 0).unwrap(),
+    );
+    let options = SlotLabelOptions {
+        timezones: vec!["America/New_York".to_string(), "Asia/Tokyo".to_string()],
+        ..Default::default()
+    };
+    let labeled = label_slots(&free, &options).unwrap();
+    assert_eq!(labeled.len(), 2);
+    assert_eq!(labeled[0].slot, free[0]);
+    assert_eq!(labeled[0].summary, "9:00 AM EST / 11:00 PM JST");
+    assert_eq!(labeled[1].summary, "11:00 AM EST / 1:00 AM JST");
+
+    let tokyo = &labeled[0].labels[1];
+    assert_eq!(tokyo.timezone, "Asia/Tokyo");
+    assert_eq!(tokyo.start_local, "2026-03-02T23:00:00+09:00");
+    assert_eq!(tokyo.end_local, "2026-03-03T00:00:00+09:00");
+    // The end falls on the next local day, so it carries its own date.
+    assert_eq!(
+        tokyo.label,
+        "Mon, Mar 2 at 11:00 PM JST – Tue, Mar 3 at 12:00 AM JST"
+    );
+    assert_eq!(
+        labeled[0].labels[0].label,
+        "Mon, Mar 2 at 9:00 AM EST – 10:00 AM EST"
+    );
+
+    let unknown = SlotLabelOptions {
+        timezones: vec!["Mars/Olympus".to_string()],
+        ..Default::default()
+    };
+    assert!(matches!(
+        label_slots(&free, &unknown),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+}
@@ -0,0 +1,286 @@
+//! Tests for the Microsoft Graph event adapter (`serde` feature).
+
+#![cfg(feature = "serde")]
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use serde_json::json;
+use truth_engine::interop::graph::{
+    from_graph, recurrence_to_rrule, rrule_to_recurrence, to_graph, GraphEvent, GraphEventType,
+    PatternType, RangeType, ShowAs, WeekIndex,
+};
+use truth_engine::interop::ics::{parse_ics, EventStatus, Transparency};
+use truth_engine::TruthError;
+
+fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+/// A weekly series with one moved and one cancelled occurrence, as returned
+/// by `/me/events` plus `/instances`.
+fn series() -> Vec<GraphEvent> {
+    serde_json::from_value(json!([
+        {
+            "id": "AAMk-master",
+            "subject": "Standup",
+            "type": "seriesMaster",
+            "showAs": "busy",
+            "isAllDay": false,
+            "start": {"dateTime": "2026-03-02T09:00:00.0000000", "timeZone": "Pacific Standard Time"},
+            "end": {"dateTime": "2026-03-02T09:15:00.0000000", "timeZone": "Pacific Standard Time"},
+            "recurrence": {
+                "pattern": {"type": "weekly", "interval": 1, "daysOfWeek": ["monday", "wednesday"],
+                            "firstDayOfWeek": "sunday", "month": 0, "dayOfMonth": 0, "index": "first"},
+                "range": {"type": "endDate", "startDate": "2026-03-02", "endDate": "2026-03-16",
+                          "recurrenceTimeZone": "Pacific Standard Time", "numberOfOccurrences": 0}
+            },
+            "bodyPreview": "ignored"
+        },
+        {
+            "id": "AAMk-moved",
+            "type": "exception",
+            "seriesMasterId": "AAMk-master",
+            "originalStart": "2026-03-04T17:00:00Z",
+            "start": {"dateTime": "2026-03-04T11:00:00.0000000", "timeZone": "Pacific Standard Time"},
+            "end": {"dateTime": "2026-03-04T11:15:00.0000000", "timeZone": "Pacific Standard Time"}
+        },
+        {
+            "id": "AAMk-cancelled",
+            "type": "exception",
+            "seriesMasterId": "AAMk-master",
+            "isCancelled": true,
+            "originalStart": "2026-03-09T16:00:00Z",
+            "start": {"dateTime": "2026-03-09T09:00:00.0000000", "timeZone": "Pacific Standard Time"},
+            "end": {"dateTime": "2026-03-09T09:15:00.0000000", "timeZone": "Pacific Standard Time"}
+        },
+        {
+            "id": "AAMk-occ",
+            "type": "occurrence",
+            "seriesMasterId": "AAMk-master",
+            "start": {"dateTime": "2026-03-11T09:00:00.0000000", "timeZone": "Pacific Standard Time"},
+            "end": {"dateTime": "2026-03-11T09:15:00.0000000", "timeZone": "Pacific Standard Time"}
+        }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn series_with_exceptions_expands() {
+    let calendar = from_graph(&series()).unwrap();
+    // The generated occurrence is dropped; its master reproduces it.
+    assert_eq!(calendar.events.len(), 3);
+    let master = &calendar.events[0];
+    assert_eq!(
+        master.rrule.as_deref(),
+        Some("FREQ=WEEKLY;BYDAY=MO,WE;WKST=SU;UNTIL=20260317T065959Z")
+    );
+
+    let starts: Vec<_> = calendar
+        .expand(utc(2026, 12, 31, 0, 0), "UTC")
+        .unwrap()
+        .iter()
+        .map(|o| o.start)
+        .collect();
+    assert_eq!(
+        starts,
+        vec![
+            utc(2026, 3, 2, 17, 0),
+            utc(2026, 3, 4, 19, 0), // moved to 11:00
+            // 2026-03-09 cancelled; DST starts 2026-03-08
+            utc(2026, 3, 11, 16, 0),
+            utc(2026, 3, 16, 16, 0),
+        ]
+    );
+}
+
+#[test]
+fn show_as_maps_to_transparency_and_status() {
+    let events: Vec<GraphEvent> = serde_json::from_value(json!([
+        {"showAs": "free",
+         "start": {"dateTime": "2026-03-02T09:00:00", "timeZone": "UTC"},
+         "end": {"dateTime": "2026-03-02T10:00:00", "timeZone": "UTC"}},
+        {"showAs": "tentative",
+         "start": {"dateTime": "2026-03-02T09:00:00", "timeZone": "Europe/Berlin"},
+         "end": {"dateTime": "2026-03-02T10:00:00", "timeZone": "Europe/Berlin"}},
+        {"isAllDay": true, "showAs": "oof",
+         "start": {"dateTime": "2026-03-03T00:00:00", "timeZone": "UTC"},
+         "end": {"dateTime": "2026-03-04T00:00:00", "timeZone": "UTC"}}
+    ]))
+    .unwrap();
+    let calendar = from_graph(&events).unwrap();
+    assert_eq!(calendar.events[0].transparency, Transparency::Transparent);
+    assert_eq!(calendar.events[1].status, EventStatus::Tentative);
+    assert!(calendar.events[2].is_busy());
+
+    let busy = calendar.busy(utc(2026, 3, 5, 0, 0), "UTC").unwrap();
+    let spans: Vec<_> = busy.iter().map(|b| (b.start, b.end)).collect();
+    assert_eq!(
+        spans,
+        vec![
+            (utc(2026, 3, 2, 8, 0), utc(2026, 3, 2, 9, 0)),
+            (utc(2026, 3, 3, 0, 0), utc(2026, 3, 4, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn patterns_map_to_rrules() {
+    let cases = [
+        (
+            json!({"type": "daily", "interval": 3}),
+            json!({"type": "numbered", "startDate": "2026-03-02", "numberOfOccurrences": 5}),
+            "FREQ=DAILY;INTERVAL=3;COUNT=5",
+        ),
+        (
+            json!({"type": "absoluteMonthly", "interval": 1, "dayOfMonth": 15}),
+            json!({"type": "noEnd", "startDate": "2026-03-15"}),
+            "FREQ=MONTHLY;BYMONTHDAY=15",
+        ),
+        (
+            json!({"type": "relativeMonthly", "interval": 1, "daysOfWeek": ["monday", "tuesday", "wednesday", "thursday", "friday"], "index": "last"}),
+            json!({"type": "noEnd", "startDate": "2026-03-31"}),
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+        ),
+        (
+            json!({"type": "absoluteYearly", "interval": 1, "month": 7, "dayOfMonth": 4}),
+            json!({"type": "noEnd", "startDate": "2026-07-04"}),
+            "FREQ=YEARLY;BYMONTH=7;BYMONTHDAY=4",
+        ),
+        (
+            json!({"type": "relativeYearly", "interval": 1, "month": 11, "daysOfWeek": ["thursday"], "index": "fourth"}),
+            json!({"type": "noEnd", "startDate": "2026-11-26"}),
+            "FREQ=YEARLY;BYMONTH=11;BYDAY=TH;BYSETPOS=4",
+        ),
+    ];
+    for (pattern, range, expected) in cases {
+        let recurrence =
+            serde_json::from_value(json!({"pattern": pattern, "range": range})).unwrap();
+        assert_eq!(recurrence_to_rrule(&recurrence, "UTC").unwrap(), expected);
+    }
+}
+
+#[test]
+fn rrules_map_to_patterns() {
+    let start = date(2026, 3, 2);
+    let weekdays = rrule_to_recurrence("FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR", start, "UTC").unwrap();
+    assert_eq!(weekdays.pattern.pattern_type, PatternType::Weekly);
+    assert_eq!(weekdays.pattern.days_of_week.len(), 5);
+
+    let ordinal = rrule_to_recurrence("FREQ=MONTHLY;BYDAY=2TU", start, "UTC").unwrap();
+    assert_eq!(ordinal.pattern.pattern_type, PatternType::RelativeMonthly);
+    assert_eq!(ordinal.pattern.index, WeekIndex::Second);
+
+    // UNTIL becomes an inclusive end date in the series' zone.
+    let until = rrule_to_recurrence(
+        "FREQ=WEEKLY;UNTIL=20260401T040000Z",
+        start,
+        "America/New_York",
+    )
+    .unwrap();
+    assert_eq!(until.range.range_type, RangeType::EndDate);
+    assert_eq!(until.range.end_date, Some(date(2026, 4, 1)));
+    assert_eq!(
+        until.range.recurrence_time_zone.as_deref(),
+        Some("Eastern Standard Time")
+    );
+
+    let yearly = rrule_to_recurrence("FREQ=YEARLY", date(2026, 7, 4), "UTC").unwrap();
+    assert_eq!(yearly.pattern.pattern_type, PatternType::AbsoluteYearly);
+    assert_eq!((yearly.pattern.month, yearly.pattern.day_of_month), (7, 4));
+}
+
+#[test]
+fn inexpressible_rrules_are_rejected() {
+    let start = date(2026, 3, 2);
+    for rule in [
+        "FREQ=HOURLY",
+        "FREQ=DAILY;BYHOUR=9,17",
+        "FREQ=MONTHLY;BYMONTHDAY=1,15",
+        "FREQ=MONTHLY;BYDAY=5MO",
+        "FREQ=MONTHLY;BYDAY=1MO,3MO",
+        "FREQ=WEEKLY;BYDAY=MO;BYSETPOS=1",
+        "INTERVAL=2",
+    ] {
+        assert!(
+            matches!(
+                rrule_to_recurrence(rule, start, "UTC"),
+                Err(TruthError::InvalidRule(_))
+            ),
+            "{rule}"
+        );
+    }
+}
+
+#[test]
+fn calendar_round_trips_through_graph() {
+    let calendar = from_graph(&series()).unwrap();
+    let graph = to_graph(&calendar, "UTC").unwrap();
+    assert_eq!(graph[0].event_type, GraphEventType::SeriesMaster);
+    assert_eq!(graph[0].start.time_zone, "Pacific Standard Time");
+    assert_eq!(graph[1].series_master_id.as_deref(), Some("AAMk-master"));
+    assert_eq!(graph[1].original_start, Some(utc(2026, 3, 4, 17, 0)));
+    assert!(graph[2].is_cancelled);
+
+    let again = from_graph(&graph).unwrap();
+    assert_eq!(
+        again.expand(utc(2026, 12, 31, 0, 0), "UTC").unwrap(),
+        calendar.expand(utc(2026, 12, 31, 0, 0), "UTC").unwrap()
+    );
+}
+
+#[test]
+fn exdates_become_cancelled_exceptions() {
+    let calendar = parse_ics(
+        "BEGIN:VCALENDAR\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:s@example.com\r\n\
+         DTSTART;TZID=Europe/London:20260302T090000\r\n\
+         DURATION:PT1H\r\n\
+         RRULE:FREQ=WEEKLY;COUNT=3\r\n\
+         EXDATE;TZID=Europe/London:20260309T090000\r\n\
+         TRANSP:TRANSPARENT\r\n\
+         END:VEVENT\r\n\
+         END:VCALENDAR\r\n",
+    )
+    .unwrap();
+    let graph = to_graph(&calendar, "UTC").unwrap();
+    assert_eq!(graph.len(), 2);
+    assert_eq!(graph[0].show_as, ShowAs::Free);
+    assert_eq!(
+        graph[0].end.date_time,
+        date(2026, 3, 2).and_hms_opt(10, 0, 0).unwrap()
+    );
+    let recurrence = graph[0].recurrence.as_ref().unwrap();
+    assert_eq!(recurrence.pattern.pattern_type, PatternType::Weekly);
+    assert_eq!(recurrence.range.number_of_occurrences, 3);
+
+    assert_eq!(graph[1].event_type, GraphEventType::Exception);
+    assert!(graph[1].is_cancelled);
+    assert_eq!(graph[1].original_start, Some(utc(2026, 3, 9, 9, 0)));
+    assert_eq!(graph[1].start.time_zone, "GMT Standard Time");
+
+    let json = serde_json::to_value(&graph[1]).unwrap();
+    assert_eq!(json["type"], "exception");
+    assert_eq!(json["seriesMasterId"], "s@example.com");
+    assert_eq!(json["start"]["dateTime"], "2026-03-09T09:00:00");
+}
+
+#[test]
+fn exception_without_original_start_is_rejected() {
+    let mut events = series();
+    events[1].original_start = None;
+    assert!(matches!(
+        from_graph(&events),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+
+    let mut unknown_zone = series();
+    unknown_zone[0].start.time_zone = "Atlantis Standard Time".into();
+    assert!(matches!(
+        from_graph(&unknown_zone),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+}
@@ -0,0 +1,562 @@
+//! Tests for iCalendar parsing and expansion.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use truth_engine::interop::ics::{
+    parse_ics, write_freebusy, write_ics, AlarmAction, AlarmTrigger, Calendar, DateTimeValue,
+    EventStatus, Transparency, TriggerRelation,
+};
+use truth_engine::{expand_rrule, find_conflicts, BusyBlock, ExpandedEvent, TruthError};
+
+fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+}
+
+fn calendar(events: &str) -> String {
+    format!("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//test//EN\r\n{events}END:VCALENDAR\r\n")
+}
+
+fn until() -> DateTime<Utc> {
+    utc(2026, 12, 31, 0, 0)
+}
+
+#[test]
+fn parses_properties_with_folding_and_escapes() {
+    let ics = calendar(
+        "BEGIN:VEVENT\r\n\
+         UID:a@example.com\r\n\
+         SUMMARY:Planning\\, Q3 \r\n  review\r\n\
+         LOCATION;LANGUAGE=en:Room \"B\"\\; 2nd floor\r\n\
+         DTSTART;TZID=\"America/New_York\":20260302T090000\r\n\
+         DTEND;TZID=America/New_York:20260302T100000\r\n\
+         TRANSP:TRANSPARENT\r\n\
+         STATUS:TENTATIVE\r\n\
+         BEGIN:VALARM\r\n\
+         TRIGGER:-PT15M\r\n\
+         DESCRIPTION:ignored\r\n\
+         END:VALARM\r\n\
+         END:VEVENT\r\n",
+    );
+    let cal = parse_ics(&ics).unwrap();
+    assert_eq!(cal.events.len(), 1);
+    let event = &cal.events[0];
+    assert_eq!(event.uid.as_deref(), Some("a@example.com"));
+    assert_eq!(event.summary.as_deref(), Some("Planning, Q3  review"));
+    assert_eq!(event.location.as_deref(), Some("Room \"B\"; 2nd floor"));
+    assert_eq!(event.description, None);
+    assert_eq!(event.transparency, Transparency::Transparent);
+    assert_eq!(event.status, EventStatus::Tentative);
+    assert!(!event.is_busy());
+    assert!(matches!(
+        &event.start,
+        DateTimeValue::Local { tzid: Some(tz), .. } if tz == "America/New_York"
+    ));
+}
+
+#[test]
+fn accepts_bare_lf_line_endings() {
+    let ics =
+        "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20260302T140000Z\nEND:VEVENT\nEND:VCALENDAR\n";
+    let cal = parse_ics(ics).unwrap();
+    assert_eq!(
+        cal.events[0].start,
+        DateTimeValue::Utc(utc(2026, 3, 2, 14, 0))
+    );
+}
+
+#[test]
+fn expands_series_with_utc_until_and_exdate() {
+    // Weekly Monday 09:00 New York across the March 8 DST change, with the
+    // March 9 occurrence excluded.
+    let ics = calendar(
+        "BEGIN:VEVENT\r\n\
+         UID:weekly@example.com\r\n\
+         DTSTART;TZID=America/New_York:20260302T090000\r\n\
+         DURATION:PT30M\r\n\
+         RRULE:FREQ=WEEKLY;UNTIL=20260323T130000Z\r\n\
+         EXDATE;TZID=America/New_York:20260309T090000\r\n\
+         END:VEVENT\r\n",
+    );
+    let events = parse_ics(&ics).unwrap().expand(until(), "UTC").unwrap();
+    let starts: Vec<_> = events.iter().map(|e| e.start).collect();
+    assert_eq!(
+        starts,
+        vec![
+            utc(2026, 3, 2, 14, 0),
+            utc(2026, 3, 16, 13, 0),
+            utc(2026, 3, 23, 13, 0)
+        ]
+    );
+    assert!(events
+        .iter()
+        .all(|e| e.end - e.start == Duration::minutes(30)));
+}
+
+#[test]
+fn local_until_is_read_in_the_start_zone() {
+    let ics = calendar(
+        "BEGIN:VEVENT\r\n\
+         DTSTART;TZID=Europe/Berlin:20260302T090000\r\n\
+         RRULE:FREQ=DAILY;UNTIL=20260304T090000\r\n\
+         END:VEVENT\r\n",
+    );
+    let events = parse_ics(&ics).unwrap().expand(until(), "UTC").unwrap();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[2].start, utc(2026, 3, 4, 8, 0));
+}
+
+#[test]
+fn rdates_add_occurrences_and_exdates_apply_to_them() {
+    let ics = calendar(
+        "BEGIN:VEVENT\r\n\
+         UID:r@example.com\r\n\
+         DTSTART:20260302T140000Z\r\n\
+         DTEND:20260302T150000Z\r\n\
+         RDATE:20260305T140000Z,20260306T140000Z\r\n\
+         EXDATE:20260306T140000Z\r\n\
+         END:VEVENT\r\n",
+    );
+    let events = parse_ics(&ics).unwrap().expand(until(), "UTC").unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1].start, utc(2026, 3, 5, 14, 0));
+    assert_eq!(events[1].end, utc(2026, 3, 5, 15, 0));
+}
+
+#[test]
+fn overrides_replace_and_cancel_occurrences() {
+    let ics = calendar(
+        "BEGIN:VEVENT\r\n\
+         UID:s@example.com\r\n\
+         DTSTART:20260302T140000Z\r\n\
+         DTEND:20260302T150000Z\r\n\
+         RRULE:FREQ=DAILY;COUNT=4\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:s@example.com\r\n\
+         RECURRENCE-ID:20260303T140000Z\r\n\
+         DTSTART:20260303T170000Z\r\n\
+         DTEND:20260303T173000Z\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:s@example.com\r\n\
+         RECURRENCE-ID:20260304T140000Z\r\n\
+         DTSTART:20260304T140000Z\r\n\
+         STATUS:CANCELLED\r\n\
+         END:VEVENT\r\n",
+    );
+    let events = parse_ics(&ics).unwrap().expand(until(), "UTC").unwrap();
+    let spans: Vec<_> = events.iter().map(|e| (e.start, e.end)).collect();
+    assert_eq!(
+        spans,
+        vec![
+            (utc(2026, 3, 2, 14, 0), utc(2026, 3, 2, 15, 0)),
+            (utc(2026, 3, 3, 17, 0), utc(2026, 3, 3, 17, 30)),
+            (utc(2026, 3, 5, 14, 0), utc(2026, 3, 5, 15, 0)),
+        ]
+    );
+}
+
+#[test]
+fn busy_drops_transparent_and_cancelled_events() {
+    let ics = calendar(
+        "BEGIN:VEVENT\r\n\
+         DTSTART:20260302T140000Z\r\n\
+         DTEND:20260302T150000Z\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         DTSTART:20260302T160000Z\r\n\
+         DTEND:20260302T170000Z\r\n\
+         TRANSP:TRANSPARENT\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         DTSTART:20260302T180000Z\r\n\
+         DTEND:20260302T190000Z\r\n\
+         STATUS:CANCELLED\r\n\
+         END:VEVENT\r\n",
+    );
+    let cal = parse_ics(&ics).unwrap();
+    assert_eq!(cal.expand(until(), "UTC").unwrap().len(), 2);
+    let busy = cal.busy(until(), "UTC").unwrap();
+    assert_eq!(busy.len(), 1);
+    assert_eq!(busy[0].start, utc(2026, 3, 2, 14, 0));
+}
+
+#[test]
+fn all_day_and_floating_values_use_the_default_zone() {
+    let ics = calendar(
+        "BEGIN:VEVENT\r\n\
+         DTSTART;VALUE=DATE:20260302\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         DTSTART:20260303T090000\r\n\
+         DURATION:PT1H\r\n\
+         END:VEVENT\r\n",
+    );
+    let events = parse_ics(&ics)
+        .unwrap()
+        .expand(until(), "Asia/Tokyo")
+        .unwrap();
+    assert_eq!(events[0].start, utc(2026, 3, 1, 15, 0));
+    assert_eq!(events[0].end, utc(2026, 3, 2, 15, 0));
+    assert_eq!(events[1].start, utc(2026, 3, 3, 0, 0));
+}
+
+#[test]
+fn expansion_stops_at_until() {
+    let ics = calendar(
+        "BEGIN:VEVENT\r\n\
+         DTSTART:20260302T140000Z\r\n\
+         RRULE:FREQ=DAILY\r\n\
+         END:VEVENT\r\n",
+    );
+    let events = parse_ics(&ics)
+        .unwrap()
+        .expand(utc(2026, 3, 5, 14, 0), "UTC")
+        .unwrap();
+    assert_eq!(events.len(), 4);
+}
+
+#[test]
+fn expanded_events_feed_conflict_detection() {
+    let mine = parse_ics(&calendar(
+        "BEGIN:VEVENT\r\nDTSTART:20260302T140000Z\r\nDURATION:PT1H\r\nEND:VEVENT\r\n",
+    ))
+    .unwrap()
+    .busy(until(), "UTC")
+    .unwrap();
+    let theirs = parse_ics(&calendar(
+        "BEGIN:VEVENT\r\nDTSTART:20260302T143000Z\r\nDURATION:PT1H\r\nEND:VEVENT\r\n",
+    ))
+    .unwrap()
+    .busy(until(), "UTC")
+    .unwrap();
+    let conflicts = find_conflicts(&mine, &theirs);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].overlap_minutes, 30);
+}
+
+#[test]
+fn malformed_input_is_rejected() {
+    let no_start = calendar("BEGIN:VEVENT\r\nUID:x\r\nEND:VEVENT\r\n");
+    assert!(matches!(
+        parse_ics(&no_start),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+
+    let unclosed = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nDTSTART:20260302T140000Z\r\n";
+    assert!(matches!(
+        parse_ics(unclosed),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+
+    let bad_date = calendar("BEGIN:VEVENT\r\nDTSTART:2026-03-02\r\nEND:VEVENT\r\n");
+    assert!(matches!(
+        parse_ics(&bad_date),
+        Err(TruthError::InvalidDatetime(_))
+    ));
+
+    let bad_duration =
+        calendar("BEGIN:VEVENT\r\nDTSTART:20260302T140000Z\r\nDURATION:1H\r\nEND:VEVENT\r\n");
+    assert!(matches!(
+        parse_ics(&bad_duration),
+        Err(TruthError::InvalidDuration(_))
+    ));
+}
+
+#[test]
+fn unknown_tzid_is_reported_on_expansion() {
+    let ics =
+        calendar("BEGIN:VEVENT\r\nDTSTART;TZID=Mars/Olympus:20260302T090000\r\nEND:VEVENT\r\n");
+    let cal = parse_ics(&ics).unwrap();
+    assert!(matches!(
+        cal.expand(until(), "UTC"),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+}
+
+// ── Generation ──────────────────────────────────────────────────────────────
+
+fn stamp() -> DateTime<Utc> {
+    utc(2026, 2, 18, 12, 0)
+}
+
+#[test]
+fn written_calendar_round_trips() {
+    let ics = calendar(
+        "BEGIN:VEVENT\r\n\
+         UID:s@example.com\r\n\
+         SUMMARY:Design review\\, weekly\\; bring notes\r\n\
+         DESCRIPTION:Line one\\nLine two\r\n\
+         DTSTART;TZID=America/New_York:20260302T090000\r\n\
+         DURATION:PT1H30M\r\n\
+         RRULE:FREQ=WEEKLY;COUNT=6\r\n\
+         EXDATE;TZID=America/New_York:20260309T090000\r\n\
+         RDATE;VALUE=DATE:20260401\r\n\
+         STATUS:TENTATIVE\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:s@example.com\r\n\
+         RECURRENCE-ID;TZID=America/New_York:20260316T090000\r\n\
+         DTSTART:20260316T150000Z\r\n\
+         DTEND:20260316T160000Z\r\n\
+         TRANSP:TRANSPARENT\r\n\
+         END:VEVENT\r\n",
+    );
+    let original = parse_ics(&ics).unwrap();
+    let written = write_ics(&original, stamp()).unwrap();
+    assert!(written.ends_with("END:VCALENDAR\r\n"));
+    assert!(written.contains("DTSTAMP:20260218T120000Z\r\n"));
+    assert!(written.contains("SUMMARY:Design review\\, weekly\\; bring notes\r\n"));
+    assert_eq!(parse_ics(&written).unwrap(), original);
+}
+
+#[test]
+fn vtimezone_describes_referenced_zones() {
+    let ics = calendar(
+        "BEGIN:VEVENT\r\nUID:x\r\nDTSTART;TZID=America/New_York:20260302T090000\r\nEND:VEVENT\r\n\
+         BEGIN:VEVENT\r\nUID:y\r\nDTSTART;TZID=Asia/Tokyo:20260302T090000\r\nEND:VEVENT\r\n",
+    );
+    let written = write_ics(&parse_ics(&ics).unwrap(), stamp()).unwrap();
+    assert!(written.contains(
+        "BEGIN:DAYLIGHT\r\n\
+         DTSTART:20250309T020000\r\n\
+         RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r\n\
+         TZOFFSETFROM:-0500\r\n\
+         TZOFFSETTO:-0400\r\n\
+         TZNAME:EDT\r\n"
+    ));
+    assert!(written.contains("RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r\n"));
+    assert!(written.contains(
+        "TZID:Asia/Tokyo\r\n\
+         BEGIN:STANDARD\r\n\
+         DTSTART:19700101T000000\r\n\
+         TZOFFSETFROM:+0900\r\n\
+         TZOFFSETTO:+0900\r\n"
+    ));
+    assert_eq!(written.matches("BEGIN:VTIMEZONE").count(), 2);
+}
+
+#[test]
+fn long_lines_are_folded() {
+    let summary = "Quarterly planning — ".repeat(8);
+    let ics = calendar(&format!(
+        "BEGIN:VEVENT\r\nUID:x\r\nDTSTART:20260302T140000Z\r\nSUMMARY:{summary}\r\nEND:VEVENT\r\n"
+    ));
+    let written = write_ics(&parse_ics(&ics).unwrap(), stamp()).unwrap();
+    assert!(written.split("\r\n").all(|line| line.len() <= 75));
+    let parsed = parse_ics(&written).unwrap();
+    assert_eq!(parsed.events[0].summary.as_deref(), Some(summary.as_str()));
+}
+
+#[test]
+fn expanded_occurrences_are_written_in_their_zone() {
+    let occurrences = expand_rrule(
+        "FREQ=DAILY;COUNT=3",
+        "2026-03-07T09:00:00",
+        45,
+        "America/New_York",
+        None,
+        None,
+    )
+    .unwrap();
+    let cal = Calendar::from_occurrences(&occurrences, "America/New_York").unwrap();
+    let written = write_ics(&cal, stamp()).unwrap();
+    // 09:00 local on both sides of the March 8 DST change.
+    assert!(written.contains("DTSTART;TZID=America/New_York:20260307T090000\r\n"));
+    assert!(written.contains("DTSTART;TZID=America/New_York:20260308T090000\r\n"));
+    assert!(written.contains("UID:0-20260307T090000@truth-engine\r\n"));
+    let reparsed = parse_ics(&written).unwrap().expand(until(), "UTC").unwrap();
+    // Written one per VEVENT, the occurrences come back without their index.
+    let bounds =
+        |events: &[ExpandedEvent]| -> Vec<_> { events.iter().map(|e| (e.start, e.end)).collect() };
+    assert_eq!(bounds(&reparsed), bounds(&occurrences));
+}
+
+#[test]
+fn freebusy_lists_busy_blocks_in_utc() {
+    let busy = [
+        BusyBlock {
+            start: utc(2026, 3, 2, 14, 0),
+            end: utc(2026, 3, 2, 15, 0),
+            source_count: 1,
+        },
+        BusyBlock {
+            start: utc(2026, 3, 2, 16, 30),
+            end: utc(2026, 3, 2, 17, 0),
+            source_count: 2,
+        },
+    ];
+    let written = write_freebusy(&busy, utc(2026, 3, 2, 0, 0), utc(2026, 3, 3, 0, 0), stamp());
+    assert!(written.contains(
+        "BEGIN:VFREEBUSY\r\n\
+         UID:freebusy-20260302T000000Z-20260303T000000Z@truth-engine\r\n\
+         DTSTAMP:20260218T120000Z\r\n\
+         DTSTART:20260302T000000Z\r\n\
+         DTEND:20260303T000000Z\r\n\
+         FREEBUSY;FBTYPE=BUSY:20260302T140000Z/20260302T150000Z\r\n\
+         FREEBUSY;FBTYPE=BUSY:20260302T163000Z/20260302T170000Z\r\n\
+         END:VFREEBUSY\r\n"
+    ));
+}
+
+#[test]
+fn writing_rejects_unknown_tzid() {
+    let ics =
+        calendar("BEGIN:VEVENT\r\nDTSTART;TZID=Mars/Olympus:20260302T090000\r\nEND:VEVENT\r\n");
+    let cal = parse_ics(&ics).unwrap();
+    assert!(matches!(
+        write_ics(&cal, stamp()),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+}
+
+// ── VALARM ──────────────────────────────────────────────────────────────────
+
+/// A weekly series with a moved and a cancelled occurrence; the master
+/// alarms 15 minutes before start (repeating twice) and at each end.
+fn alarm_series() -> Calendar {
+    parse_ics(&calendar(
+        "BEGIN:VEVENT\r\n\
+         UID:review@example.com\r\n\
+         SUMMARY:Review\r\n\
+         DTSTART;TZID=America/New_York:20260302T090000\r\n\
+         DURATION:PT1H\r\n\
+         RRULE:FREQ=WEEKLY;COUNT=4\r\n\
+         EXDATE;TZID=America/New_York:20260316T090000\r\n\
+         BEGIN:VALARM\r\n\
+         ACTION:DISPLAY\r\n\
+         DESCRIPTION:Review soon\r\n\
+         TRIGGER:-PT15M\r\n\
+         DURATION:PT5M\r\n\
+         REPEAT:2\r\n\
+         END:VALARM\r\n\
+         BEGIN:VALARM\r\n\
+         ACTION:AUDIO\r\n\
+         TRIGGER;RELATED=END:PT0S\r\n\
+         END:VALARM\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:review@example.com\r\n\
+         RECURRENCE-ID;TZID=America/New_York:20260309T090000\r\n\
+         DTSTART;TZID=America/New_York:20260309T140000\r\n\
+         DURATION:PT1H\r\n\
+         BEGIN:VALARM\r\n\
+         ACTION:EMAIL\r\n\
+         TRIGGER:-P1D\r\n\
+         END:VALARM\r\n\
+         END:VEVENT\r\n",
+    ))
+    .unwrap()
+}
+
+#[test]
+fn parses_valarms() {
+    let calendar = alarm_series();
+    let alarms = &calendar.events[0].alarms;
+    assert_eq!(alarms.len(), 2);
+    assert_eq!(alarms[0].action, AlarmAction::Display);
+    assert_eq!(
+        alarms[0].trigger,
+        AlarmTrigger::Relative {
+            offset: Duration::minutes(-15),
+            related: TriggerRelation::Start
+        }
+    );
+    assert_eq!(
+        (alarms[0].repeat, alarms[0].repeat_interval),
+        (2, Some(Duration::minutes(5)))
+    );
+    assert_eq!(alarms[0].description.as_deref(), Some("Review soon"));
+    assert_eq!(
+        alarms[1].trigger,
+        AlarmTrigger::Relative {
+            offset: Duration::zero(),
+            related: TriggerRelation::End
+        }
+    );
+    // Alarm properties do not leak into the event.
+    assert_eq!(calendar.events[0].duration, Some(Duration::hours(1)));
+}
+
+#[test]
+fn alarms_fire_per_occurrence_with_exdates_and_overrides() {
+    let fires = alarm_series()
+        .alarms(utc(2026, 3, 1, 0, 0), utc(2026, 3, 24, 0, 0), "UTC")
+        .unwrap();
+    let times: Vec<_> = fires
+        .iter()
+        .map(|f| (f.at, f.action.clone(), f.repetition))
+        .collect();
+    assert_eq!(
+        times,
+        vec![
+            (utc(2026, 3, 2, 13, 45), AlarmAction::Display, 0),
+            (utc(2026, 3, 2, 13, 50), AlarmAction::Display, 1),
+            (utc(2026, 3, 2, 13, 55), AlarmAction::Display, 2),
+            (utc(2026, 3, 2, 15, 0), AlarmAction::Audio, 0),
+            // The moved occurrence uses its own alarm, a day ahead (EDT from 03-08).
+            (utc(2026, 3, 8, 18, 0), AlarmAction::Email, 0),
+            // 03-16 is excluded; 03-23 is after DST.
+            (utc(2026, 3, 23, 12, 45), AlarmAction::Display, 0),
+            (utc(2026, 3, 23, 12, 50), AlarmAction::Display, 1),
+            (utc(2026, 3, 23, 12, 55), AlarmAction::Display, 2),
+            (utc(2026, 3, 23, 14, 0), AlarmAction::Audio, 0),
+        ]
+    );
+    assert_eq!(fires[4].occurrence.start, utc(2026, 3, 9, 18, 0));
+    assert_eq!(fires[0].summary.as_deref(), Some("Review"));
+}
+
+#[test]
+fn alarms_before_the_window_edge_see_later_occurrences() {
+    // The EMAIL alarm for 03-09 fires on 03-08, inside a window that ends
+    // before its occurrence.
+    let fires = alarm_series()
+        .alarms(utc(2026, 3, 8, 0, 0), utc(2026, 3, 9, 0, 0), "UTC")
+        .unwrap();
+    assert_eq!(fires.len(), 1);
+    assert_eq!(fires[0].uid.as_deref(), Some("review@example.com"));
+}
+
+#[test]
+fn absolute_triggers_fire_once_per_series() {
+    let calendar = parse_ics(&calendar(
+        "BEGIN:VEVENT\r\n\
+         UID:daily@example.com\r\n\
+         DTSTART:20260302T140000Z\r\n\
+         RRULE:FREQ=DAILY;COUNT=5\r\n\
+         BEGIN:VALARM\r\n\
+         ACTION:DISPLAY\r\n\
+         TRIGGER;VALUE=DATE-TIME:20260301T120000Z\r\n\
+         END:VALARM\r\n\
+         END:VEVENT\r\n",
+    ))
+    .unwrap();
+    let fires = calendar
+        .alarms(utc(2026, 3, 1, 0, 0), utc(2026, 4, 1, 0, 0), "UTC")
+        .unwrap();
+    assert_eq!(fires.len(), 1);
+    assert_eq!(fires[0].at, utc(2026, 3, 1, 12, 0));
+    assert_eq!(fires[0].occurrence.start, utc(2026, 3, 2, 14, 0));
+}
+
+#[test]
+fn alarms_round_trip_through_writer() {
+    let calendar = alarm_series();
+    let text = write_ics(&calendar, utc(2026, 2, 18, 12, 0)).unwrap();
+    assert!(text.contains("TRIGGER;RELATED=END:PT0S\r\n"));
+    assert!(text.contains("DURATION:PT5M\r\nREPEAT:2\r\n"));
+    assert_eq!(parse_ics(&text).unwrap(), calendar);
+}
+
+#[test]
+fn valarm_without_trigger_is_rejected() {
+    let result = parse_ics(&calendar(
+        "BEGIN:VEVENT\r\n\
+         DTSTART:20260302T140000Z\r\n\
+         BEGIN:VALARM\r\n\
+         ACTION:DISPLAY\r\n\
+         END:VALARM\r\n\
+         END:VEVENT\r\n",
+    ));
+    assert!(matches!(result, Err(TruthError::InvalidCalendar(_))));
+}
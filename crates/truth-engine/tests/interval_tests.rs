@@ -0,0 +1,128 @@
+//! Tests for half-open interval arithmetic.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use truth_engine::temporal::interval::{merge, merge_within, TimeInterval};
+
+fn at(hour: u32, min: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 18, hour, min, 0).unwrap()
+}
+
+fn iv(start: (u32, u32), end: (u32, u32)) -> TimeInterval {
+    TimeInterval::new(at(start.0, start.1), at(end.0, end.1)).unwrap()
+}
+
+#[test]
+fn new_rejects_reversed_bounds() {
+    assert!(TimeInterval::new(at(10, 0), at(9, 0)).is_err());
+    assert!(TimeInterval::new(at(10, 0), at(10, 0)).unwrap().is_empty());
+}
+
+#[test]
+fn contains_is_half_open() {
+    let i = iv((9, 0), (10, 0));
+    assert!(i.contains(at(9, 0)));
+    assert!(i.contains(at(9, 59)));
+    assert!(!i.contains(at(10, 0)));
+    assert!(i.contains_interval(&iv((9, 15), (10, 0))));
+    assert!(!i.contains_interval(&iv((8, 59), (9, 30))));
+}
+
+#[test]
+fn shift_moves_both_bounds() {
+    let i = iv((9, 0), (10, 0)).shift(Duration::minutes(90));
+    assert_eq!(i, iv((10, 30), (11, 30)));
+    assert_eq!(i.shift(Duration::minutes(-90)), iv((9, 0), (10, 0)));
+}
+
+#[test]
+fn adjacent_intervals_touch_but_do_not_overlap() {
+    let a = iv((9, 0), (10, 0));
+    let b = iv((10, 0), (11, 0));
+    assert!(!a.overlaps(&b));
+    assert_eq!(a.intersect(&b), None);
+    assert_eq!(a.union(&b), Some(iv((9, 0), (11, 0))));
+}
+
+#[test]
+fn union_of_disjoint_intervals_is_none() {
+    assert_eq!(iv((9, 0), (10, 0)).union(&iv((10, 30), (11, 0))), None);
+}
+
+#[test]
+fn subtract_splits_around_the_hole() {
+    let day = iv((9, 0), (17, 0));
+    assert_eq!(
+        day.subtract(&iv((12, 0), (13, 0))),
+        vec![iv((9, 0), (12, 0)), iv((13, 0), (17, 0))]
+    );
+    assert_eq!(
+        day.subtract(&iv((8, 0), (10, 0))),
+        vec![iv((10, 0), (17, 0))]
+    );
+    assert!(day.subtract(&iv((8, 0), (18, 0))).is_empty());
+    assert_eq!(day.subtract(&iv((18, 0), (19, 0))), vec![day]);
+}
+
+#[test]
+fn subtract_all_returns_gaps_in_order() {
+    let day = iv((9, 0), (17, 0));
+    let busy = [
+        iv((15, 0), (16, 0)),
+        iv((8, 0), (9, 30)),
+        iv((11, 0), (12, 0)),
+        iv((11, 30), (12, 30)),
+    ];
+    assert_eq!(
+        day.subtract_all(&busy),
+        vec![
+            iv((9, 30), (11, 0)),
+            iv((12, 30), (15, 0)),
+            iv((16, 0), (17, 0))
+        ]
+    );
+}
+
+#[test]
+fn clamp_to_window() {
+    let window = iv((9, 0), (17, 0));
+    assert_eq!(
+        iv((8, 0), (10, 0)).clamp_to(&window),
+        Some(iv((9, 0), (10, 0)))
+    );
+    assert_eq!(iv((17, 0), (18, 0)).clamp_to(&window), None);
+}
+
+#[test]
+fn merge_sorts_joins_and_drops_empty() {
+    let merged = merge([
+        iv((14, 0), (15, 0)),
+        iv((9, 0), (10, 0)),
+        iv((12, 0), (12, 0)),
+        iv((9, 30), (11, 0)),
+        iv((11, 0), (11, 30)),
+    ]);
+    assert_eq!(merged, vec![iv((9, 0), (11, 30)), iv((14, 0), (15, 0))]);
+    assert!(merge([]).is_empty());
+}
+
+#[test]
+fn merge_within_closes_small_gaps() {
+    let intervals = [
+        iv((9, 0), (10, 0)),
+        iv((10, 5), (10, 30)),
+        iv((10, 36), (11, 0)),
+        iv((10, 40), (10, 50)),
+    ];
+    assert_eq!(
+        merge_within(intervals, Duration::minutes(5)),
+        vec![iv((9, 0), (10, 30)), iv((10, 36), (11, 0))]
+    );
+    assert_eq!(
+        merge_within(intervals, Duration::minutes(6)),
+        vec![iv((9, 0), (11, 0))]
+    );
+    assert_eq!(
+        merge_within(intervals, Duration::minutes(-5)),
+        merge(intervals)
+    );
+}
@@ -0,0 +1,95 @@
+//! Tests for the interval tree, against brute-force scans.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use proptest::prelude::*;
+use truth_engine::interval_tree::IntervalTree;
+use truth_engine::temporal::interval::TimeInterval;
+
+fn at(minute: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap() + Duration::minutes(minute)
+}
+
+fn span(start: i64, end: i64) -> TimeInterval {
+    TimeInterval::new(at(start), at(end)).unwrap()
+}
+
+fn values<T: Copy>(found: Vec<(&TimeInterval, &T)>) -> Vec<T> {
+    found.into_iter().map(|(_, &v)| v).collect()
+}
+
+#[test]
+fn empty_tree_matches_nothing() {
+    let tree: IntervalTree<()> = IntervalTree::default();
+    assert!(tree.is_empty());
+    assert!(tree.stabbing(at(0)).is_empty());
+    assert!(tree.overlapping(&span(0, 60)).is_empty());
+    assert!(!tree.overlaps_any(&span(0, 60)));
+}
+
+#[test]
+fn queries_are_half_open() {
+    let tree: IntervalTree<char> = [(span(60, 120), 'a'), (span(120, 180), 'b')]
+        .into_iter()
+        .collect();
+    assert_eq!(values(tree.stabbing(at(60))), ['a']);
+    assert_eq!(values(tree.stabbing(at(120))), ['b']);
+    assert!(tree.stabbing(at(180)).is_empty());
+    // Touching is not overlapping.
+    assert!(tree.overlapping(&span(0, 60)).is_empty());
+    assert!(!tree.overlaps_any(&span(180, 240)));
+    assert_eq!(values(tree.overlapping(&span(119, 121))), ['a', 'b']);
+}
+
+#[test]
+fn matches_come_in_start_order() {
+    let tree: IntervalTree<u8> = [
+        (span(300, 360), 3),
+        (span(0, 600), 0),
+        (span(120, 130), 1),
+        (span(120, 500), 2),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(tree.len(), 4);
+    assert_eq!(values(tree.overlapping(&span(125, 310))), [0, 1, 2, 3]);
+    assert_eq!(values(tree.stabbing(at(400))), [0, 2]);
+    assert_eq!(
+        tree.iter().map(|(_, &v)| v).collect::<Vec<_>>(),
+        [0, 1, 2, 3]
+    );
+}
+
+proptest! {
+    #[test]
+    fn queries_match_a_scan(
+        raw in prop::collection::vec((0i64..1000, 0i64..200), 0..60),
+        (q_start, q_len) in (0i64..1200, 0i64..300),
+    ) {
+        let entries: Vec<(TimeInterval, usize)> = raw
+            .iter()
+            .enumerate()
+            .map(|(n, &(start, len))| (span(start, start + len), n))
+            .collect();
+        let tree: IntervalTree<usize> = entries.iter().copied().collect();
+        let query = span(q_start, q_start + q_len);
+
+        let mut overlapping = values(tree.overlapping(&query));
+        overlapping.sort_unstable();
+        let expected: Vec<usize> = entries
+            .iter()
+            .filter(|(i, _)| i.overlaps(&query))
+            .map(|&(_, n)| n)
+            .collect();
+        prop_assert_eq!(&overlapping, &expected);
+        prop_assert_eq!(tree.overlaps_any(&query), !expected.is_empty());
+
+        let mut stabbed = values(tree.stabbing(at(q_start)));
+        stabbed.sort_unstable();
+        let expected: Vec<usize> = entries
+            .iter()
+            .filter(|(i, _)| i.contains(at(q_start)))
+            .map(|&(_, n)| n)
+            .collect();
+        prop_assert_eq!(stabbed, expected);
+    }
+}
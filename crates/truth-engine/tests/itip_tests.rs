@@ -0,0 +1,171 @@
+//! Tests for iTIP scheduling messages.
+
+use chrono::{DateTime, TimeZone, Utc};
+use truth_engine::interop::ics::{DateTimeValue, EventStatus};
+use truth_engine::interop::itip::{
+    invitation, parse_itip, write_itip, Method, PartStat, Participant, SchedulingMessage,
+};
+use truth_engine::{FreeSlot, TruthError};
+
+fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+}
+
+fn slot(start: DateTime<Utc>, end: DateTime<Utc>) -> FreeSlot {
+    FreeSlot {
+        start,
+        end,
+        duration_minutes: (end - start).num_minutes(),
+    }
+}
+
+fn request() -> SchedulingMessage {
+    invitation(
+        &slot(utc(2026, 3, 2, 14, 0), utc(2026, 3, 2, 16, 0)),
+        45,
+        "sync-42@example.com",
+        "Design sync",
+        Participant {
+            name: Some("Alice Chen".to_string()),
+            ..Participant::new("alice@example.com")
+        },
+        vec![
+            Participant {
+                name: Some("Smith, Bob".to_string()),
+                ..Participant::new("bob@example.com")
+            },
+            Participant::new("carol@example.com"),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn invitation_fills_the_start_of_the_slot() {
+    let msg = request();
+    assert_eq!(msg.method, Method::Request);
+    assert_eq!(msg.sequence, 0);
+    assert_eq!(msg.event.start, DateTimeValue::Utc(utc(2026, 3, 2, 14, 0)));
+    assert_eq!(
+        msg.event.end,
+        Some(DateTimeValue::Utc(utc(2026, 3, 2, 14, 45)))
+    );
+    assert!(msg
+        .attendees
+        .iter()
+        .all(|a| a.rsvp && a.partstat == PartStat::NeedsAction));
+}
+
+#[test]
+fn invitation_must_fit_the_slot() {
+    let short = slot(utc(2026, 3, 2, 14, 0), utc(2026, 3, 2, 14, 30));
+    let organizer = Participant::new("alice@example.com");
+    for minutes in [0, 31] {
+        assert!(matches!(
+            invitation(&short, minutes, "x", "x", organizer.clone(), vec![]),
+            Err(TruthError::InvalidDuration(_))
+        ));
+    }
+}
+
+#[test]
+fn request_serializes_with_method_and_participants() {
+    let text = write_itip(&request(), utc(2026, 2, 18, 12, 0)).unwrap();
+    assert!(text.contains("\r\nMETHOD:REQUEST\r\n"));
+    assert!(text.contains("\r\nSEQUENCE:0\r\n"));
+    assert!(text.contains("\r\nORGANIZER;CN=Alice Chen:mailto:alice@example.com\r\n"));
+    // Folded at 75 octets; the CN is quoted because it contains a comma.
+    assert!(text.contains(
+        "\r\nATTENDEE;CN=\"Smith, Bob\";PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:bob@example\r\n .com\r\n"
+    ));
+    assert!(
+        text.contains("\r\nATTENDEE;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:carol@example.com\r\n")
+    );
+    assert!(text.contains("\r\nDTSTART:20260302T140000Z\r\n"));
+}
+
+#[test]
+fn request_round_trips() {
+    let msg = request();
+    let parsed = parse_itip(&write_itip(&msg, utc(2026, 2, 18, 12, 0)).unwrap()).unwrap();
+    assert_eq!(parsed, msg);
+}
+
+#[test]
+fn reply_carries_only_the_responding_attendee() {
+    let reply = request()
+        .reply("BOB@example.com", PartStat::Accepted)
+        .unwrap();
+    assert_eq!(reply.method, Method::Reply);
+    assert_eq!(reply.attendees.len(), 1);
+    assert_eq!(reply.attendees[0].email, "bob@example.com");
+    assert_eq!(reply.attendees[0].partstat, PartStat::Accepted);
+    assert!(!reply.attendees[0].rsvp);
+    assert_eq!(reply.event.uid.as_deref(), Some("sync-42@example.com"));
+
+    let text = write_itip(&reply, utc(2026, 2, 18, 12, 5)).unwrap();
+    assert!(text.contains("\r\nMETHOD:REPLY\r\n"));
+    assert_eq!(parse_itip(&text).unwrap(), reply);
+
+    assert!(matches!(
+        request().reply("mallory@example.com", PartStat::Accepted),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+}
+
+#[test]
+fn cancel_bumps_the_sequence() {
+    let cancel = request().cancel();
+    assert_eq!(cancel.method, Method::Cancel);
+    assert_eq!(cancel.sequence, 1);
+    assert_eq!(cancel.event.status, EventStatus::Cancelled);
+    let text = write_itip(&cancel, utc(2026, 2, 18, 12, 0)).unwrap();
+    assert!(text.contains("\r\nSTATUS:CANCELLED\r\n"));
+    assert!(text.contains("\r\nSEQUENCE:1\r\n"));
+}
+
+#[test]
+fn counter_proposes_a_new_slot_with_the_same_length() {
+    let later = slot(utc(2026, 3, 3, 9, 0), utc(2026, 3, 3, 12, 0));
+    let counter = request()
+        .counter("carol@example.com", &later, Some("Mornings work better"))
+        .unwrap();
+    assert_eq!(counter.method, Method::Counter);
+    assert_eq!(counter.start().unwrap(), Some(utc(2026, 3, 3, 9, 0)));
+    assert_eq!(
+        counter.event.end,
+        Some(DateTimeValue::Utc(utc(2026, 3, 3, 9, 45)))
+    );
+    assert_eq!(counter.attendees.len(), 1);
+
+    let text = write_itip(&counter, utc(2026, 2, 18, 12, 0)).unwrap();
+    assert!(text.contains("\r\nCOMMENT:Mornings work better\r\n"));
+    assert_eq!(parse_itip(&text).unwrap(), counter);
+
+    let tiny = slot(utc(2026, 3, 3, 9, 0), utc(2026, 3, 3, 9, 30));
+    assert!(matches!(
+        request().counter("carol@example.com", &tiny, None),
+        Err(TruthError::InvalidDuration(_))
+    ));
+}
+
+#[test]
+fn parse_requires_method_and_organizer() {
+    let no_method = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:a\r\nDTSTART:20260302T140000Z\r\n\
+                     ORGANIZER:mailto:alice@example.com\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+    assert!(matches!(
+        parse_itip(no_method),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+    let no_organizer = "BEGIN:VCALENDAR\r\nMETHOD:REQUEST\r\nBEGIN:VEVENT\r\nUID:a\r\n\
+                        DTSTART:20260302T140000Z\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+    assert!(matches!(
+        parse_itip(no_organizer),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+    let publish = no_method.replace("BEGIN:VEVENT", "METHOD:PUBLISH\r\nBEGIN:VEVENT");
+    assert!(matches!(
+        parse_itip(&publish),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+}
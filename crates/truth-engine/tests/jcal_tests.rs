@@ -0,0 +1,193 @@
+//! Tests for jCal (RFC 7265) conversion (`serde` feature).
+
+#![cfg(feature = "serde")]
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde_json::json;
+use truth_engine::interop::ics::{parse_ics, write_ics};
+use truth_engine::interop::jcal::{
+    ics_to_jcal, jcal_to_ics, parse_freebusy_jcal, parse_jcal, write_freebusy_jcal, write_jcal,
+};
+use truth_engine::{BusyBlock, TruthError};
+
+fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+}
+
+const SERIES: &str = "BEGIN:VCALENDAR\r\n\
+    VERSION:2.0\r\n\
+    BEGIN:VEVENT\r\n\
+    UID:s@example.com\r\n\
+    DTSTART;TZID=America/New_York:20260302T090000\r\n\
+    DTEND;TZID=America/New_York:20260302T100000\r\n\
+    RRULE:FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20260401T040000Z\r\n\
+    EXDATE;TZID=America/New_York:20260304T090000,20260309T090000\r\n\
+    SUMMARY:Sync\\; agenda\\, notes\r\n\
+    GEO:37.386013;-122.082932\r\n\
+    SEQUENCE:2\r\n\
+    X-CUSTOM;X-PARAM=a:raw\\,value\r\n\
+    END:VEVENT\r\n\
+    END:VCALENDAR\r\n";
+
+#[test]
+fn ics_maps_to_typed_jcal_properties() {
+    let jcal = ics_to_jcal(SERIES).unwrap();
+    assert_eq!(
+        jcal,
+        json!(["vcalendar", [["version", {}, "text", "2.0"]], [["vevent", [
+            ["uid", {}, "text", "s@example.com"],
+            ["dtstart", {"tzid": "America/New_York"}, "date-time", "2026-03-02T09:00:00"],
+            ["dtend", {"tzid": "America/New_York"}, "date-time", "2026-03-02T10:00:00"],
+            ["rrule", {}, "recur", {
+                "freq": "WEEKLY",
+                "byday": ["MO", "WE"],
+                "until": "2026-04-01T04:00:00Z"
+            }],
+            ["exdate", {"tzid": "America/New_York"}, "date-time",
+                "2026-03-04T09:00:00", "2026-03-09T09:00:00"],
+            ["summary", {}, "text", "Sync; agenda, notes"],
+            ["geo", {}, "float", [37.386013, -122.082932]],
+            ["sequence", {}, "integer", 2],
+            ["x-custom", {"x-param": "a"}, "unknown", "raw\\,value"]
+        ], []]]])
+    );
+}
+
+#[test]
+fn jcal_maps_back_to_equivalent_ics() {
+    let text = jcal_to_ics(&ics_to_jcal(SERIES).unwrap()).unwrap();
+    assert!(text.contains("RRULE:FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20260401T040000Z\r\n"));
+    assert!(text.contains("GEO:37.386013;-122.082932\r\n"));
+    assert!(text.contains("X-CUSTOM;X-PARAM=a:raw\\,value\r\n"));
+    assert_eq!(parse_ics(&text).unwrap(), parse_ics(SERIES).unwrap());
+}
+
+#[test]
+fn date_values_carry_their_type() {
+    let jcal = json!([
+        "vcalendar",
+        [],
+        [[
+            "vevent",
+            [
+                ["dtstart", {}, "date", "2026-03-02"],
+                ["rdate", {}, "period", ["2026-03-05T14:00:00Z", "PT1H"]]
+            ],
+            []
+        ]]
+    ]);
+    let text = jcal_to_ics(&jcal).unwrap();
+    assert!(text.contains("DTSTART;VALUE=DATE:20260302\r\n"));
+    assert!(text.contains("RDATE;VALUE=PERIOD:20260305T140000Z/PT1H\r\n"));
+    assert_eq!(ics_to_jcal(&text).unwrap(), jcal);
+}
+
+#[test]
+fn recur_object_puts_freq_first() {
+    let jcal = json!(["vevent", [
+        ["dtstart", {}, "date-time", "2026-03-02T14:00:00Z"],
+        ["rrule", {}, "recur", {"interval": 2, "bymonthday": [1, 15], "freq": "MONTHLY"}]
+    ], []]);
+    let text = jcal_to_ics(&jcal).unwrap();
+    assert!(text.contains("RRULE:FREQ=MONTHLY;INTERVAL=2;BYMONTHDAY=1,15\r\n"));
+}
+
+#[test]
+fn calendar_round_trips_through_jcal() {
+    let calendar = parse_ics(SERIES).unwrap();
+    let stamp = utc(2026, 2, 18, 12, 0);
+    let jcal = write_jcal(&calendar, stamp).unwrap();
+    assert_eq!(parse_jcal(&jcal).unwrap(), calendar);
+    // The JSON and text writers agree.
+    assert_eq!(
+        jcal,
+        ics_to_jcal(&write_ics(&calendar, stamp).unwrap()).unwrap()
+    );
+    // The generated VTIMEZONE offsets use the extended form.
+    let zone = jcal[2]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c[0] == "vtimezone")
+        .unwrap();
+    assert!(zone[2][0][1].as_array().unwrap().contains(&json!([
+        "tzoffsetfrom",
+        {},
+        "utc-offset",
+        "-05:00"
+    ])));
+}
+
+#[test]
+fn freebusy_round_trips_through_jcal() {
+    let busy = [
+        BusyBlock {
+            start: utc(2026, 3, 2, 14, 0),
+            end: utc(2026, 3, 2, 15, 0),
+            source_count: 1,
+        },
+        BusyBlock {
+            start: utc(2026, 3, 2, 16, 0),
+            end: utc(2026, 3, 2, 16, 30),
+            source_count: 3,
+        },
+    ];
+    let jcal = write_freebusy_jcal(
+        &busy,
+        utc(2026, 3, 2, 0, 0),
+        utc(2026, 3, 3, 0, 0),
+        utc(2026, 2, 18, 12, 0),
+    )
+    .unwrap();
+    let properties = jcal[2][0][1].as_array().unwrap();
+    assert!(properties.contains(&json!([
+        "freebusy",
+        {"fbtype": "BUSY"},
+        "period",
+        ["2026-03-02T14:00:00Z", "2026-03-02T15:00:00Z"]
+    ])));
+
+    let intervals = parse_freebusy_jcal(&jcal).unwrap();
+    let spans: Vec<_> = intervals.iter().map(|i| (i.start, i.end)).collect();
+    assert_eq!(
+        spans,
+        vec![
+            (utc(2026, 3, 2, 14, 0), utc(2026, 3, 2, 15, 0)),
+            (utc(2026, 3, 2, 16, 0), utc(2026, 3, 2, 16, 30)),
+        ]
+    );
+}
+
+#[test]
+fn free_periods_are_not_busy() {
+    let jcal = json!(["vcalendar", [], [["vfreebusy", [
+        ["freebusy", {"fbtype": "FREE"}, "period", ["2026-03-02T09:00:00Z", "PT8H"]],
+        ["freebusy", {"fbtype": "BUSY-TENTATIVE"}, "period", ["2026-03-02T10:00:00Z", "PT30M"]]
+    ], []]]]);
+    let busy = parse_freebusy_jcal(&jcal).unwrap();
+    assert_eq!(busy.len(), 1);
+    assert_eq!(busy[0].start, utc(2026, 3, 2, 10, 0));
+}
+
+#[test]
+fn malformed_jcal_is_rejected() {
+    for bad in [
+        json!({"vcalendar": []}),
+        json!(["vcalendar", [["summary", {}, "text"]], []]),
+        json!(["vcalendar", [["summary", "text", "x"]], []]),
+        json!([
+            "vcalendar",
+            [],
+            [["vevent", [["rdate", {}, "period", ["only-one"]]], []]]
+        ]),
+    ] {
+        assert!(
+            matches!(jcal_to_ics(&bad), Err(TruthError::InvalidCalendar(_))),
+            "{bad}"
+        );
+    }
+    assert!(matches!(
+        ics_to_jcal("BEGIN:VCALENDAR\r\nEND:VEVENT\r\n"),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+}
@@ -0,0 +1,199 @@
+//! Tests for market calendars.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use truth_engine::markets::{is_market_open, MarketCalendar, Session};
+use truth_engine::TruthError;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+}
+
+/// Holidays falling on weekdays of `year`.
+fn holidays(market: &MarketCalendar, year: i32) -> Vec<NaiveDate> {
+    date(year, 1, 1)
+        .iter_days()
+        .take_while(|d| *d <= date(year, 12, 31))
+        .filter(|d| market.holiday(*d).is_some())
+        .collect()
+}
+
+#[test]
+fn nyse_holidays() {
+    let nyse = MarketCalendar::builtin("nyse").unwrap();
+    assert_eq!(
+        holidays(&nyse, 2026),
+        vec![
+            date(2026, 1, 1),
+            date(2026, 1, 19),
+            date(2026, 2, 16),
+            date(2026, 4, 3),
+            date(2026, 5, 25),
+            date(2026, 6, 19),
+            // Independence Day falls on a Saturday.
+            date(2026, 7, 3),
+            date(2026, 9, 7),
+            date(2026, 11, 26),
+            date(2026, 12, 25),
+        ]
+    );
+    assert_eq!(nyse.holiday(date(2027, 6, 18)), Some("Juneteenth"));
+    assert_eq!(nyse.holiday(date(2027, 12, 24)), Some("Christmas Day"));
+    // Juneteenth became a market holiday in 2022.
+    assert!(nyse.is_trading_day(date(2021, 6, 18)));
+    // A Saturday New Year's Day is not observed on the Friday before.
+    assert!(nyse.is_trading_day(date(2021, 12, 31)));
+    assert!(!nyse.is_trading_day(date(2025, 1, 9)));
+    assert!(!nyse.is_trading_day(date(2026, 10, 17)));
+}
+
+#[test]
+fn nyse_early_closes() {
+    let nyse = MarketCalendar::builtin("NYSE").unwrap();
+    let one_pm = NaiveTime::from_hms_opt(13, 0, 0);
+    assert_eq!(nyse.early_close(date(2026, 11, 27)), one_pm);
+    assert_eq!(nyse.early_close(date(2026, 12, 24)), one_pm);
+    assert_eq!(nyse.early_close(date(2025, 7, 3)), one_pm);
+    // July 3, 2026 is the observed holiday itself.
+    assert_eq!(nyse.early_close(date(2026, 7, 3)), None);
+    assert_eq!(nyse.early_close(date(2026, 12, 23)), None);
+
+    let sessions = nyse.sessions_on(date(2026, 12, 24)).unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(
+        (sessions[0].start, sessions[0].end),
+        (utc(2026, 12, 24, 14, 30), utc(2026, 12, 24, 18, 0))
+    );
+    assert!(nyse.sessions_on(date(2026, 12, 25)).unwrap().is_empty());
+}
+
+#[test]
+fn open_and_close_across_dst() {
+    // US clocks go forward on March 8, 2026.
+    assert!(!is_market_open(utc(2026, 3, 9, 13, 0), "NYSE").unwrap());
+    assert!(is_market_open(utc(2026, 3, 9, 13, 30), "NYSE").unwrap());
+    assert!(is_market_open(utc(2026, 3, 6, 20, 59), "NASDAQ").unwrap());
+    assert!(!is_market_open(utc(2026, 3, 9, 20, 0), "XNAS").unwrap());
+
+    let nyse = MarketCalendar::builtin("NYSE").unwrap();
+    // Friday evening: the next session is Monday, after the clock change.
+    assert_eq!(
+        nyse.next_open(utc(2026, 3, 6, 22, 0)).unwrap(),
+        Some(utc(2026, 3, 9, 13, 30))
+    );
+    assert_eq!(
+        nyse.next_close(utc(2026, 3, 6, 22, 0)).unwrap(),
+        Some(utc(2026, 3, 9, 20, 0))
+    );
+    // Thursday before Good Friday: the next open is Monday.
+    assert_eq!(
+        nyse.next_open(utc(2026, 4, 2, 15, 0)).unwrap(),
+        Some(utc(2026, 4, 6, 13, 30))
+    );
+    // At the open itself, the next open is the following day's.
+    assert_eq!(
+        nyse.next_open(utc(2026, 3, 9, 13, 30)).unwrap(),
+        Some(utc(2026, 3, 10, 13, 30))
+    );
+}
+
+#[test]
+fn london_substitute_days() {
+    let lse = MarketCalendar::builtin("XLON").unwrap();
+    assert_eq!(
+        holidays(&lse, 2026),
+        vec![
+            date(2026, 1, 1),
+            date(2026, 4, 3),
+            date(2026, 4, 6),
+            date(2026, 5, 4),
+            date(2026, 5, 25),
+            date(2026, 8, 31),
+            date(2026, 12, 25),
+            // Boxing Day falls on a Saturday.
+            date(2026, 12, 28),
+        ]
+    );
+    // Christmas on Saturday, Boxing Day on Sunday.
+    assert_eq!(lse.holiday(date(2027, 12, 27)), Some("Christmas Day"));
+    assert_eq!(lse.holiday(date(2027, 12, 28)), Some("Boxing Day"));
+    // Christmas on Sunday, Boxing Day on Monday.
+    assert_eq!(lse.holiday(date(2022, 12, 26)), Some("Christmas Day"));
+    assert_eq!(lse.holiday(date(2022, 12, 27)), Some("Boxing Day"));
+    assert_eq!(
+        lse.early_close(date(2026, 12, 31)),
+        NaiveTime::from_hms_opt(12, 30, 0)
+    );
+    // 08:00 BST.
+    assert!(lse.is_open(utc(2026, 3, 30, 7, 0)).unwrap());
+}
+
+#[test]
+fn trading_days_between_counts_half_open() {
+    let nyse = MarketCalendar::builtin("NYSE").unwrap();
+    assert_eq!(
+        nyse.trading_days_between(date(2026, 1, 1), date(2027, 1, 1)),
+        251
+    );
+    assert_eq!(
+        nyse.trading_days_between(date(2026, 12, 21), date(2026, 12, 28)),
+        4
+    );
+    assert_eq!(
+        nyse.trading_days_between(date(2026, 12, 28), date(2026, 12, 21)),
+        -4
+    );
+}
+
+#[test]
+fn custom_calendars_with_a_lunch_break() {
+    let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    let market = MarketCalendar {
+        code: "XTKS".to_string(),
+        timezone: "Asia/Tokyo".to_string(),
+        trading_days: vec![
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ],
+        sessions: vec![
+            Session {
+                open: time(9, 0),
+                close: time(11, 30),
+            },
+            Session {
+                open: time(12, 30),
+                close: time(15, 30),
+            },
+        ],
+        holidays: vec![],
+        early_closes: vec![],
+        closures: Default::default(),
+    };
+    // 11:45 in Tokyo is the lunch break; the afternoon session opens at 12:30.
+    assert!(!market.is_open(utc(2026, 10, 16, 2, 45)).unwrap());
+    assert_eq!(
+        market.next_open(utc(2026, 10, 16, 2, 45)).unwrap(),
+        Some(utc(2026, 10, 16, 3, 30))
+    );
+    let day = market
+        .trading_intervals(utc(2026, 10, 16, 0, 0), utc(2026, 10, 17, 0, 0))
+        .unwrap();
+    assert_eq!(day.len(), 2);
+
+    let mut overlapping = market.clone();
+    overlapping.sessions[1].open = time(11, 0);
+    assert!(matches!(
+        overlapping.is_open(utc(2026, 10, 16, 2, 45)),
+        Err(TruthError::InvalidMarket(_))
+    ));
+    assert!(matches!(
+        MarketCalendar::builtin("MOON"),
+        Err(TruthError::InvalidMarket(_))
+    ));
+}
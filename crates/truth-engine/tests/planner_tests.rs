@@ -0,0 +1,180 @@
+//! Tests for the time-blocking planner.
+
+use chrono::{DateTime, NaiveTime, TimeZone, Utc};
+use truth_engine::business::BusinessCalendar;
+use truth_engine::planner::{plan_tasks, Plan, PlanOptions, Task, UnplacedReason};
+use truth_engine::temporal::WorkingHours;
+use truth_engine::{ExpandedEvent, TruthError};
+
+/// March 2, 2026 is a Monday.
+fn utc(d: u32, h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, d, h, m, 0).unwrap()
+}
+
+fn task(id: &str, minutes: u32, priority: i32, deadline: Option<DateTime<Utc>>) -> Task {
+    Task {
+        id: id.to_string(),
+        duration_minutes: minutes,
+        deadline,
+        priority,
+        location: None,
+    }
+}
+
+fn spans(plan: &Plan) -> Vec<(&str, DateTime<Utc>, DateTime<Utc>)> {
+    plan.blocks
+        .iter()
+        .map(|b| (b.task_id.as_str(), b.start, b.end))
+        .collect()
+}
+
+#[test]
+fn higher_priority_tasks_take_earlier_gaps() {
+    let busy = [ExpandedEvent::new(utc(2, 10, 0), utc(2, 16, 0))];
+    let tasks = [task("low", 60, 0, None), task("high", 60, 5, None)];
+    let plan = plan_tasks(
+        &tasks,
+        &busy,
+        utc(2, 0, 0),
+        utc(3, 0, 0),
+        &PlanOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        spans(&plan),
+        vec![
+            ("high", utc(2, 9, 0), utc(2, 10, 0)),
+            ("low", utc(2, 16, 0), utc(2, 17, 0)),
+        ]
+    );
+    assert!(plan.unplaced.is_empty());
+}
+
+#[test]
+fn buffers_and_daily_caps_are_respected() {
+    let busy = [ExpandedEvent::new(utc(2, 9, 0), utc(2, 10, 0))];
+    let options = PlanOptions {
+        buffer_minutes: 15,
+        max_minutes_per_day: Some(240),
+        ..PlanOptions::default()
+    };
+    let tasks = [
+        task("a", 180, 2, None),
+        task("b", 90, 1, None),
+        task("c", 60, 0, None),
+    ];
+    let plan = plan_tasks(&tasks, &busy, utc(2, 0, 0), utc(4, 0, 0), &options).unwrap();
+    assert_eq!(
+        spans(&plan),
+        vec![
+            ("a", utc(2, 10, 15), utc(2, 13, 15)),
+            // c still fits under Monday's cap, 15 minutes after a...
+            ("c", utc(2, 13, 30), utc(2, 14, 30)),
+            // ...but 180 + 90 would not, so b moves to Tuesday.
+            ("b", utc(3, 9, 0), utc(3, 10, 30)),
+        ]
+    );
+}
+
+#[test]
+fn earlier_deadlines_go_first_and_missed_deadlines_are_reported() {
+    let tasks = [
+        task("later", 240, 0, Some(utc(3, 17, 0))),
+        task("sooner", 240, 0, Some(utc(2, 13, 0))),
+        task("impossible", 60, 0, Some(utc(2, 10, 0))),
+        task("huge", 600, 0, None),
+    ];
+    let plan = plan_tasks(
+        &tasks,
+        &[],
+        utc(2, 0, 0),
+        utc(4, 0, 0),
+        &PlanOptions::default(),
+    )
+    .unwrap();
+    // "impossible" has the earliest deadline, so it takes Monday 09:00, and
+    // "sooner" no longer fits before 13:00.
+    assert_eq!(
+        spans(&plan),
+        vec![
+            ("impossible", utc(2, 9, 0), utc(2, 10, 0)),
+            ("later", utc(2, 10, 0), utc(2, 14, 0)),
+        ]
+    );
+    let unplaced: Vec<_> = plan
+        .unplaced
+        .iter()
+        .map(|u| (u.task_id.as_str(), u.reason))
+        .collect();
+    assert_eq!(
+        unplaced,
+        vec![
+            ("sooner", UnplacedReason::DeadlineTooSoon),
+            ("huge", UnplacedReason::NoRoom),
+        ]
+    );
+}
+
+#[test]
+fn working_hours_follow_the_plan_timezone() {
+    let options = PlanOptions {
+        calendar: BusinessCalendar {
+            timezone: "America/New_York".to_string(),
+            ..BusinessCalendar::default()
+        },
+        ..PlanOptions::default()
+    };
+    // The weekend is skipped; Monday 09:00 EDT (DST began March 8) is 13:00 UTC.
+    let plan = plan_tasks(
+        &[task("a", 30, 0, None)],
+        &[],
+        utc(7, 0, 0),
+        utc(10, 0, 0),
+        &options,
+    )
+    .unwrap();
+    assert_eq!(spans(&plan), vec![("a", utc(9, 13, 0), utc(9, 13, 30))]);
+
+    // The window start clips the first working day.
+    let late = PlanOptions {
+        calendar: BusinessCalendar::from(WorkingHours {
+            start: NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            ..Default::default()
+        }),
+        ..PlanOptions::default()
+    };
+    let plan = plan_tasks(
+        &[task("a", 30, 0, None)],
+        &[],
+        utc(2, 16, 45),
+        utc(3, 0, 0),
+        &late,
+    )
+    .unwrap();
+    assert_eq!(plan.unplaced[0].reason, UnplacedReason::NoRoom);
+}
+
+#[test]
+fn invalid_inputs_are_rejected() {
+    let options = PlanOptions {
+        calendar: BusinessCalendar {
+            timezone: "Mars/Olympus".to_string(),
+            ..BusinessCalendar::default()
+        },
+        ..PlanOptions::default()
+    };
+    assert!(matches!(
+        plan_tasks(&[], &[], utc(2, 0, 0), utc(3, 0, 0), &options),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+    assert!(matches!(
+        plan_tasks(
+            &[task("a", 0, 0, None)],
+            &[],
+            utc(2, 0, 0),
+            utc(3, 0, 0),
+            &PlanOptions::default()
+        ),
+        Err(TruthError::InvalidDuration(_))
+    ));
+}
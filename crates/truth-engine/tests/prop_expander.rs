@@ -349,3 +349,131 @@ fn weekly_byday_produces_correct_weekday(
         }
     }
 }
+
+// ---------------------------------------------------------------------------
+// Property 9: The bitmask fast path matches the general rrule crate path
+// ---------------------------------------------------------------------------
+
+/// A BYDAY list of plain weekdays and, for MONTHLY rules, ordinals.
+fn arb_byday_list(ordinals: bool) -> impl Strategy<Value = String> {
+    let ordinal = if ordinals {
+        prop_oneof![Just(String::new()), (-5i32..=5).prop_map(|n| n.to_string())].boxed()
+    } else {
+        Just(String::new()).boxed()
+    };
+    prop::collection::vec((ordinal, arb_byday()), 1..=3).prop_map(|days| {
+        let days: Vec<String> = days
+            .into_iter()
+            .map(|(n, day)| if n == "0" { day } else { n + &day })
+            .collect();
+        days.join(",")
+    })
+}
+
+fn arb_fast_rule() -> impl Strategy<Value = String> {
+    let monthly = (
+        prop::option::of(arb_byday_list(true)),
+        prop::option::of(prop::collection::vec(
+            prop_oneof![1i32..=31, -31i32..=-1],
+            1..=3,
+        )),
+    )
+        .prop_map(|(byday, monthdays)| {
+            let mut rule = "FREQ=MONTHLY".to_string();
+            if let Some(byday) = byday {
+                rule += &format!(";BYDAY={byday}");
+            }
+            if let Some(days) = monthdays {
+                let days: Vec<String> = days.iter().map(i32::to_string).collect();
+                rule += &format!(";BYMONTHDAY={}", days.join(","));
+            }
+            rule
+        });
+    let daily_or_weekly = (
+        prop_oneof![Just("DAILY"), Just("WEEKLY")],
+        prop::option::of(arb_byday_list(false)),
+    )
+        .prop_map(|(freq, byday)| match byday {
+            Some(byday) => format!("FREQ={freq};BYDAY={byday}"),
+            None => format!("FREQ={freq}"),
+        });
+    (
+        prop_oneof![monthly, daily_or_weekly],
+        1u32..=4,
+        prop::option::of(arb_byday()),
+    )
+        .prop_map(|(rule, interval, wkst)| match wkst {
+            Some(wkst) => format!("{rule};INTERVAL={interval};WKST={wkst}"),
+            None => format!("{rule};INTERVAL={interval}"),
+        })
+}
+
+/// DTSTARTs around DST transitions, at times that fall in gaps and folds on
+/// transition days.
+fn arb_transition_dtstart() -> impl Strategy<Value = String> {
+    (
+        prop_oneof![
+            Just((2026, 3)),
+            Just((2026, 10)),
+            Just((2025, 11)),
+            Just((2026, 9))
+        ],
+        1u32..=28,
+        prop_oneof![Just(0u32), Just(1), Just(2), Just(9), Just(23)],
+        prop_oneof![Just(0u32), Just(30)],
+    )
+        .prop_map(|((y, m), d, h, min)| format!("{y:04}-{m:02}-{d:02}T{h:02}:{min:02}:00"))
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn accelerated_expansion_matches_general_path(
+        rrule in arb_fast_rule(),
+        dtstart in prop_oneof![arb_dtstart(), arb_transition_dtstart()],
+        tz in prop_oneof![
+            arb_timezone(),
+            Just("America/Santiago".to_string()),
+            Just("Australia/Lord_Howe".to_string()),
+        ],
+        count in prop::option::of(arb_count()),
+        until in prop::option::of((1i64..=800).prop_map(|days| days)),
+        exdate_picks in prop::collection::vec(0usize..60, 0..4),
+    ) {
+        use truth_engine::expander::{CompiledRule, OccurrenceBuffer};
+
+        // UNTIL must be UTC for the rrule crate; bound from DTSTART in days.
+        let until = until.and_then(|days| {
+            let start = chrono::NaiveDateTime::parse_from_str(&dtstart, "%Y-%m-%dT%H:%M:%S").ok()?;
+            Some((start + Duration::days(days)).format("%Y-%m-%dT%H:%M:%S").to_string())
+        });
+        let until = until.as_deref().filter(|_| tz == "UTC");
+        let Ok(rule) = CompiledRule::new(&rrule, &dtstart, &tz, until, count, &[]) else {
+            // DTSTART in a gap or fold; rejected the same way on both paths.
+            return Ok(());
+        };
+        prop_assert!(rule.is_accelerated(), "{} is not accelerated", rrule);
+
+        // Exclude some occurrences (local times, as EXDATEs are given).
+        let local_tz: chrono_tz::Tz = tz.parse().unwrap();
+        let events = rule.expand(30);
+        let exdates: Vec<String> = exdate_picks
+            .iter()
+            .filter_map(|&i| events.get(i))
+            .map(|e| e.start.with_timezone(&local_tz).format("%Y-%m-%dT%H:%M:%S").to_string())
+            .collect();
+        let exdates: Vec<&str> = exdates.iter().map(String::as_str).collect();
+        let Ok(rule) = CompiledRule::new(&rrule, &dtstart, &tz, until, count, &exdates) else {
+            // An occurrence in a fold or moved out of a gap is not a valid EXDATE.
+            return Ok(());
+        };
+        let general = rule.clone().without_acceleration();
+        prop_assert_eq!(rule.expand(30), general.expand(30), "{} from {} in {}", rrule, dtstart, tz);
+
+        let (mut fast, mut slow) = (OccurrenceBuffer::new(), OccurrenceBuffer::new());
+        rule.expand_into(&mut fast, 30);
+        general.expand_into(&mut slow, 30);
+        prop_assert_eq!(fast, slow);
+    }
+}
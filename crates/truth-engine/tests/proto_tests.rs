@@ -0,0 +1,500 @@
+//! Tests for the protobuf mirrors (`protobuf` feature).
+
+#![cfg(feature = "protobuf")]
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, TimeZone, Utc};
+use prost::encoding::{encode_key, encode_varint, WireType};
+use prost::Message;
+#[cfg(feature = "resolver")]
+use truth_engine::temporal::{compute_duration, resolve_relative_with_options, ResolveOptions};
+use truth_engine::{
+    find_conflicts, merge_availability, proto, EventStream, ExpandedEvent, PrivacyLevel, TruthError,
+};
+
+fn utc(h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap()
+}
+
+fn event(start: DateTime<Utc>, end: DateTime<Utc>) -> ExpandedEvent {
+    ExpandedEvent::new(start, end)
+}
+
+#[test]
+fn event_streams_round_trip_through_bytes() {
+    let stream = EventStream {
+        stream_id: "work".to_string(),
+        events: vec![event(utc(9, 0), utc(10, 0)), event(utc(13, 30), utc(14, 0))],
+    };
+    let bytes = proto::EventStream::from(stream.clone()).encode_to_vec();
+    let decoded = proto::EventStream::decode(bytes.as_slice()).unwrap();
+    assert_eq!(
+        decoded.events[1].start.unwrap().seconds,
+        utc(13, 30).timestamp()
+    );
+    assert_eq!(EventStream::try_from(decoded).unwrap(), stream);
+}
+
+#[test]
+fn occurrence_metadata_round_trips_through_bytes() {
+    let moved = ExpandedEvent {
+        occurrence_index: Some(4),
+        is_override: true,
+        original_start: Some(utc(9, 0)),
+        ..event(utc(14, 0), utc(15, 0))
+    };
+    let bytes = proto::ExpandedEvent::from(moved.clone()).encode_to_vec();
+    let decoded = proto::ExpandedEvent::decode(bytes.as_slice()).unwrap();
+    assert_eq!(decoded.occurrence_index, Some(4));
+    assert_eq!(ExpandedEvent::try_from(decoded).unwrap(), moved);
+}
+
+#[test]
+fn missing_or_invalid_timestamps_are_rejected() {
+    let missing = proto::ExpandedEvent {
+        start: None,
+        end: proto::ExpandedEvent::from(event(utc(9, 0), utc(10, 0))).end,
+        ..Default::default()
+    };
+    assert!(matches!(
+        ExpandedEvent::try_from(missing),
+        Err(TruthError::InvalidDatetime(_))
+    ));
+    let negative_nanos = proto::ExpandedEvent {
+        start: Some(prost_types::Timestamp {
+            seconds: 0,
+            nanos: -1,
+        }),
+        end: Some(prost_types::Timestamp::default()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        ExpandedEvent::try_from(negative_nanos),
+        Err(TruthError::InvalidDatetime(_))
+    ));
+}
+
+#[test]
+fn availability_and_conflicts_convert() {
+    let streams = [
+        EventStream {
+            stream_id: "a".to_string(),
+            events: vec![event(utc(9, 0), utc(10, 0))],
+        },
+        EventStream {
+            stream_id: "b".to_string(),
+            events: vec![event(utc(9, 30), utc(11, 0))],
+        },
+    ];
+    let availability = merge_availability(&streams, utc(8, 0), utc(12, 0), PrivacyLevel::Full);
+    let message = proto::UnifiedAvailability::from(availability);
+    assert_eq!(message.privacy(), proto::PrivacyLevel::Full);
+    assert_eq!(message.busy.len(), 1);
+    assert_eq!(message.busy[0].source_count, 2);
+    assert_eq!(message.free.len(), 2);
+    let decoded = proto::UnifiedAvailability::decode(message.encode_to_vec().as_slice()).unwrap();
+    assert_eq!(decoded, message);
+
+    let conflicts = find_conflicts(&streams[0].events, &streams[1].events);
+    let message = proto::Conflict::from(conflicts[0].clone());
+    assert_eq!(message.overlap_minutes, 30);
+    assert!(message.event_a.is_some() && message.event_b.is_some());
+}
+
+#[cfg(feature = "resolver")]
+#[test]
+fn temporal_results_convert() {
+    let duration = proto::DurationInfo::from(
+        compute_duration("2026-03-02T09:00:00Z", "2026-03-02T10:30:00Z").unwrap(),
+    );
+    assert_eq!(duration.total_seconds, 5400);
+    // Field 1 as a varint: tag byte 0x08, then 5400 in base-128.
+    assert_eq!(&duration.encode_to_vec()[..3], &[0x08, 0x98, 0x2A]);
+
+    let options = ResolveOptions {
+        lenient: true,
+        ..ResolveOptions::default()
+    };
+    let resolved =
+        resolve_relative_with_options(utc(14, 30), "tommorow at 3pm", "America/New_York", &options)
+            .unwrap();
+    let message = proto::ResolvedDatetime::from(resolved.clone());
+    assert_eq!(message.resolved_utc, resolved.resolved_utc);
+    assert_eq!(message.corrections[0].corrected, "tomorrow");
+    assert_eq!(
+        message.granularity(),
+        proto::ResolutionGranularity::DateTime
+    );
+}
+
+// ── Schema agreement ────────────────────────────────────────────────────────
+
+const SCHEMA: &str = include_str!("../proto/truth_engine.proto");
+
+/// One field of a schema message.
+struct SchemaField {
+    label: Option<String>,
+    ty: String,
+    name: String,
+    tag: u32,
+}
+
+/// The messages and enums of `proto/truth_engine.proto`, by name.
+#[derive(Default)]
+struct Schema {
+    messages: BTreeMap<String, Vec<SchemaField>>,
+    enums: BTreeMap<String, Vec<(String, i32)>>,
+}
+
+impl Schema {
+    fn parse(source: &str) -> Self {
+        let mut schema = Schema::default();
+        let mut current: Option<(bool, String)> = None;
+        for line in source.lines().map(str::trim) {
+            if line.starts_with("//") || line.is_empty() {
+                continue;
+            }
+            if let Some(rest) = line.strip_suffix('{') {
+                let mut words = rest.split_whitespace();
+                let kind = words.next().unwrap();
+                let name = words.next().unwrap().to_string();
+                match kind {
+                    "message" => {
+                        schema.messages.insert(name.clone(), Vec::new());
+                    }
+                    "enum" => {
+                        schema.enums.insert(name.clone(), Vec::new());
+                    }
+                    _ => panic!("unexpected block: {line}"),
+                }
+                current = Some((kind == "enum", name));
+                continue;
+            }
+            if line == "}" {
+                current = None;
+                continue;
+            }
+            let Some((is_enum, block)) = &current else {
+                continue;
+            };
+            let (decl, number) = line
+                .strip_suffix(';')
+                .and_then(|l| l.split_once(" = "))
+                .unwrap_or_else(|| panic!("unexpected line: {line}"));
+            if *is_enum {
+                let value = (decl.to_string(), number.parse().unwrap());
+                schema.enums.get_mut(block).unwrap().push(value);
+                continue;
+            }
+            let words: Vec<&str> = decl.split_whitespace().collect();
+            let (label, ty, name) = match words.as_slice() {
+                [ty, name] => (None, *ty, *name),
+                [label, ty, name] => (Some(label.to_string()), *ty, *name),
+                _ => panic!("unexpected field: {line}"),
+            };
+            schema.messages.get_mut(block).unwrap().push(SchemaField {
+                label,
+                ty: ty.to_string(),
+                name: name.to_string(),
+                tag: number.parse().unwrap(),
+            });
+        }
+        schema
+    }
+
+    /// Whether a field of type `ty` is a varint on the wire.
+    fn is_varint(&self, ty: &str) -> bool {
+        matches!(ty, "bool" | "int32" | "int64" | "uint32" | "uint64")
+            || self.enums.contains_key(ty)
+    }
+
+    /// Whether a field is told apart from its default when set to a zero
+    /// value: `optional` scalars and all messages.
+    fn has_presence(&self, field: &SchemaField) -> bool {
+        field.label.as_deref() == Some("optional")
+            || !(self.is_varint(&field.ty) || field.ty == "string")
+    }
+
+    /// The wire encoding of `field` alone, with a zero value (`zero`) or a
+    /// non-zero one. Messages are always empty.
+    fn encode_field(&self, field: &SchemaField, zero: bool) -> Vec<u8> {
+        let mut buf = Vec::new();
+        match field.ty.as_str() {
+            ty if self.is_varint(ty) => {
+                encode_key(field.tag, WireType::Varint, &mut buf);
+                encode_varint(u64::from(!zero), &mut buf);
+            }
+            "string" => {
+                let value: &[u8] = if zero { b"" } else { b"x" };
+                encode_key(field.tag, WireType::LengthDelimited, &mut buf);
+                encode_varint(value.len() as u64, &mut buf);
+                buf.extend_from_slice(value);
+            }
+            _ => {
+                encode_key(field.tag, WireType::LengthDelimited, &mut buf);
+                encode_varint(0, &mut buf);
+            }
+        }
+        buf
+    }
+}
+
+/// `PrivacyLevel` → `PRIVACY_LEVEL`.
+fn screaming_snake(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.push(c.to_ascii_uppercase());
+    }
+    out
+}
+
+/// Check a message against the schema field by field: each field decodes
+/// from its schema tag and wire type, re-encodes to the same bytes, and keeps
+/// a zero value exactly when the schema gives it presence.
+///
+/// The destructuring pattern fails to compile if the Rust struct gains a
+/// field the list does not name.
+macro_rules! check_message {
+    ($schema:expr, $checked:expr, $message:ident { $($field:ident),* $(,)? }) => {{
+        let proto::$message { $($field: _),* } = proto::$message::default();
+        let name = stringify!($message);
+        let fields = $schema
+            .messages
+            .get(name)
+            .unwrap_or_else(|| panic!("{name} is not in the schema"));
+        let names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, [$(stringify!($field)),*], "{name} fields");
+        $(
+            let field = fields.iter().find(|f| f.name == stringify!($field)).unwrap();
+            let bytes = $schema.encode_field(field, false);
+            let decoded = proto::$message::decode(bytes.as_slice())
+                .unwrap_or_else(|e| panic!("{name}.{}: {e}", field.name));
+            assert_ne!(
+                decoded.$field,
+                proto::$message::default().$field,
+                "{name}.{} is not tag {}",
+                field.name,
+                field.tag
+            );
+            assert_eq!(decoded.encode_to_vec(), bytes, "{name}.{}", field.name);
+
+            if field.label.as_deref() != Some("repeated") {
+                let zero = $schema.encode_field(field, true);
+                let decoded = proto::$message::decode(zero.as_slice()).unwrap();
+                let expected = if $schema.has_presence(field) { zero } else { Vec::new() };
+                assert_eq!(decoded.encode_to_vec(), expected, "{name}.{} presence", field.name);
+            }
+        )*
+        $checked.insert(name.to_string());
+    }};
+}
+
+/// Check an enum's variants against the schema's values, in order.
+macro_rules! check_enum {
+    ($schema:expr, $checked:expr, $enum:ident { $($variant:ident),* $(,)? }) => {{
+        let _exhaustive = |value: proto::$enum| match value {
+            $(proto::$enum::$variant => ()),*
+        };
+        let name = stringify!($enum);
+        let values = $schema
+            .enums
+            .get(name)
+            .unwrap_or_else(|| panic!("{name} is not in the schema"));
+        let prefix = screaming_snake(name);
+        let variants: Vec<(String, i32)> = vec![$((
+            format!("{prefix}_{}", screaming_snake(stringify!($variant))),
+            proto::$enum::$variant as i32,
+        )),*];
+        assert_eq!(&variants, values, "{name} values");
+        $checked.insert(name.to_string());
+    }};
+}
+
+#[test]
+fn rust_messages_match_the_schema() {
+    let schema = Schema::parse(SCHEMA);
+    let mut checked = BTreeSet::new();
+
+    check_message!(
+        schema,
+        checked,
+        ExpandedEvent {
+            start,
+            end,
+            occurrence_index,
+            is_rdate,
+            is_override,
+            original_start,
+        }
+    );
+    check_message!(
+        schema,
+        checked,
+        Conflict {
+            event_a,
+            event_b,
+            overlap_minutes
+        }
+    );
+    check_message!(
+        schema,
+        checked,
+        FreeSlot {
+            start,
+            end,
+            duration_minutes
+        }
+    );
+    check_message!(schema, checked, EventStream { stream_id, events });
+    check_message!(
+        schema,
+        checked,
+        BusyBlock {
+            start,
+            end,
+            source_count
+        }
+    );
+    check_enum!(
+        schema,
+        checked,
+        PrivacyLevel {
+            Unspecified,
+            Full,
+            Opaque
+        }
+    );
+    check_message!(
+        schema,
+        checked,
+        UnifiedAvailability {
+            busy,
+            free,
+            window_start,
+            window_end,
+            privacy,
+        }
+    );
+    check_message!(
+        schema,
+        checked,
+        Renderings {
+            custom,
+            rfc2822,
+            epoch_seconds
+        }
+    );
+    check_message!(
+        schema,
+        checked,
+        ConvertedDatetime {
+            utc,
+            local,
+            timezone,
+            utc_offset,
+            dst_active,
+            renderings,
+        }
+    );
+    check_message!(
+        schema,
+        checked,
+        DurationInfo {
+            total_seconds,
+            days,
+            hours,
+            minutes,
+            seconds,
+            human_readable,
+        }
+    );
+    check_message!(
+        schema,
+        checked,
+        AdjustedTimestamp {
+            original,
+            adjusted_utc,
+            adjusted_local,
+            adjustment_applied,
+            timezone,
+            renderings,
+        }
+    );
+    check_message!(
+        schema,
+        checked,
+        TypoCorrection {
+            original,
+            corrected
+        }
+    );
+    check_enum!(
+        schema,
+        checked,
+        DstWarningKind {
+            Unspecified,
+            NearTransition,
+            Ambiguous,
+            Shifted
+        }
+    );
+    check_message!(
+        schema,
+        checked,
+        DstWarning {
+            kind,
+            transition_utc,
+            offset_before,
+            offset_after,
+            message,
+        }
+    );
+    check_enum!(
+        schema,
+        checked,
+        ResolutionGranularity {
+            Unspecified,
+            Date,
+            DateTime,
+            Period
+        }
+    );
+    check_enum!(
+        schema,
+        checked,
+        WeekScheme {
+            Unspecified,
+            Iso,
+            Us
+        }
+    );
+    check_message!(schema, checked, WeekNumber { year, week, scheme });
+    check_message!(
+        schema,
+        checked,
+        ResolvedDatetime {
+            resolved_utc,
+            resolved_local,
+            timezone,
+            interpretation,
+            expression_timezone,
+            corrections,
+            warnings,
+            renderings,
+            granularity,
+            week,
+        }
+    );
+
+    let in_schema: BTreeSet<String> = schema
+        .messages
+        .keys()
+        .chain(schema.enums.keys())
+        .cloned()
+        .collect();
+    assert_eq!(checked, in_schema, "every schema type has a Rust mirror");
+}
@@ -0,0 +1,57 @@
+//! Tests for provenance metadata (`serde` feature).
+
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+use truth_engine::provenance::{options_hash, Provenance, Stamped, ENGINE_VERSION, TZDB_VERSION};
+use truth_engine::temporal::{ResolveOptions, WeekStartDay};
+
+#[test]
+fn hashes_are_stable_and_ignore_key_order() {
+    let a = json!({"week_start": "Sunday", "nested": {"y": [1, 2], "x": null}});
+    let b = json!({"nested": {"x": null, "y": [1, 2]}, "week_start": "Sunday"});
+    assert_eq!(options_hash(&a), options_hash(&b));
+    // Pinned: the hash must not change between runs, platforms, or releases.
+    assert_eq!(options_hash(&json!({"a": 1})), "fnv1a64:9c3e82dd6fcae8b1");
+    assert_ne!(
+        options_hash(&a),
+        options_hash(&json!({"week_start": "Monday"}))
+    );
+}
+
+#[test]
+fn options_change_the_hash() {
+    let sunday = ResolveOptions {
+        week_start: WeekStartDay::Sunday,
+        ..Default::default()
+    };
+    assert_eq!(
+        Provenance::for_options(&ResolveOptions::default()),
+        Provenance::for_options(&ResolveOptions::default())
+    );
+    assert_ne!(
+        Provenance::for_options(&sunday).options_hash,
+        Provenance::for_options(&ResolveOptions::default()).options_hash
+    );
+}
+
+#[test]
+fn stale_results_are_detected() {
+    let current = Provenance::current();
+    assert_eq!(current.engine_version, ENGINE_VERSION);
+    assert_eq!(current.tzdb_version, TZDB_VERSION);
+    assert!(current.is_current());
+
+    let cached: Stamped<Vec<u32>> = serde_json::from_value(json!({
+        "result": [1, 2, 3],
+        "provenance": {"engine_version": ENGINE_VERSION, "tzdb_version": "2019c"}
+    }))
+    .unwrap();
+    assert_eq!(cached.provenance.options_hash, None);
+    assert!(!cached.provenance.is_current());
+
+    let fresh = Stamped::new(cached.result.clone());
+    let round_trip: Stamped<Vec<u32>> =
+        serde_json::from_str(&serde_json::to_string(&fresh).unwrap()).unwrap();
+    assert_eq!(round_trip, fresh);
+}
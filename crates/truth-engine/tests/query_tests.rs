@@ -0,0 +1,362 @@
+//! Tests for `ScheduleQuery`.
+
+use chrono::{DateTime, TimeZone, Utc};
+use truth_engine::availability::merge_business_availability;
+use truth_engine::business::BusinessCalendar;
+use truth_engine::conflict::find_conflicts;
+use truth_engine::query::{UnplacedMeetingReason, DEFAULT_STREAM};
+use truth_engine::{
+    find_free_slots, merge_availability, EventStream, ExpandedEvent, MeetingRequest, PrivacyLevel,
+    RescheduleSuggestion, RestRule, ScheduleQuery, TruthError,
+};
+
+/// March 2, 2026 is a Monday.
+fn utc(d: u32, h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, d, h, m, 0).unwrap()
+}
+
+fn event(start: DateTime<Utc>, end: DateTime<Utc>) -> ExpandedEvent {
+    ExpandedEvent::new(start, end)
+}
+
+fn streams() -> Vec<EventStream> {
+    vec![
+        EventStream {
+            stream_id: "alice".to_string(),
+            events: vec![
+                event(utc(2, 9, 0), utc(2, 10, 0)),
+                event(utc(2, 18, 0), utc(2, 19, 0)),
+                event(utc(3, 9, 30), utc(3, 10, 0)),
+            ],
+        },
+        EventStream {
+            stream_id: "bob".to_string(),
+            events: vec![
+                event(utc(2, 9, 30), utc(2, 11, 0)),
+                event(utc(3, 10, 0), utc(3, 12, 0)),
+            ],
+        },
+    ]
+}
+
+#[test]
+fn without_options_matches_the_free_functions() {
+    let streams = streams();
+    let all: Vec<ExpandedEvent> = streams.iter().flat_map(|s| s.events.clone()).collect();
+    let query = ScheduleQuery::new()
+        .streams(&streams)
+        .window(utc(2, 0, 0), utc(4, 0, 0))
+        .privacy(PrivacyLevel::Full);
+
+    assert_eq!(
+        query.free_slots().unwrap(),
+        find_free_slots(&all, utc(2, 0, 0), utc(4, 0, 0))
+    );
+    assert_eq!(
+        query.availability().unwrap(),
+        merge_availability(&streams, utc(2, 0, 0), utc(4, 0, 0), PrivacyLevel::Full)
+    );
+
+    // Alice's and Bob's Monday mornings; Tuesday's meetings only touch.
+    let conflicts = query.conflicts();
+    let expected = find_conflicts(&streams[0].events, &streams[1].events);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(
+        (&conflicts[0].stream_a[..], &conflicts[0].stream_b[..]),
+        ("alice", "bob")
+    );
+    assert_eq!(conflicts[0].event_a, expected[0].event_a);
+    assert_eq!(conflicts[0].event_b, expected[0].event_b);
+    assert_eq!(conflicts[0].overlap_minutes, 30);
+}
+
+#[test]
+fn working_hours_apply_to_free_time_only() {
+    let streams = streams();
+    let calendar = BusinessCalendar::default();
+    let query = ScheduleQuery::new()
+        .streams(&streams)
+        .window(utc(2, 0, 0), utc(4, 0, 0))
+        .working_hours(calendar.clone());
+
+    assert_eq!(
+        query.availability().unwrap(),
+        merge_business_availability(
+            &streams,
+            utc(2, 0, 0),
+            utc(4, 0, 0),
+            PrivacyLevel::Opaque,
+            &calendar
+        )
+        .unwrap()
+    );
+    // Alice's evening event still blocks, and still counts for conflicts.
+    let availability = query.availability().unwrap();
+    assert!(availability.busy.iter().any(|b| b.start == utc(2, 18, 0)));
+    assert_eq!(query.conflicts().len(), 1);
+}
+
+#[test]
+fn buffers_shrink_free_time_and_flag_near_misses() {
+    let query = ScheduleQuery::new()
+        .streams(&streams())
+        .window(utc(3, 9, 0), utc(3, 17, 0))
+        .buffer_minutes(15);
+
+    // Tuesday's back-to-back meetings are now too close.
+    let conflicts = query.conflicts();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].event_a.start, utc(3, 9, 30));
+    assert_eq!(conflicts[0].overlap_minutes, 0);
+
+    let free: Vec<_> = query
+        .free_slots()
+        .unwrap()
+        .iter()
+        .map(|s| (s.start, s.end))
+        .collect();
+    assert_eq!(
+        free,
+        vec![
+            (utc(3, 9, 0), utc(3, 9, 15)),
+            (utc(3, 12, 15), utc(3, 17, 0))
+        ]
+    );
+    assert_eq!(
+        query.first_common_slot(30).unwrap().map(|s| s.start),
+        Some(utc(3, 12, 15))
+    );
+    assert_eq!(query.first_common_slot(24 * 60).unwrap(), None);
+}
+
+#[test]
+fn events_within_one_calendar_conflict_with_each_other() {
+    let query = ScheduleQuery::new()
+        .events(&[event(utc(2, 9, 0), utc(2, 10, 0))])
+        .events(&[
+            event(utc(2, 9, 45), utc(2, 10, 30)),
+            event(utc(5, 9, 0), utc(5, 10, 0)),
+        ])
+        .window(utc(2, 0, 0), utc(3, 0, 0));
+
+    let conflicts = query.conflicts();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].stream_a, DEFAULT_STREAM);
+    assert_eq!(conflicts[0].stream_b, DEFAULT_STREAM);
+    assert_eq!(conflicts[0].overlap_minutes, 15);
+}
+
+#[test]
+fn free_time_needs_a_window() {
+    let query = ScheduleQuery::new().streams(&streams());
+    assert!(matches!(
+        query.free_slots(),
+        Err(TruthError::InvalidArguments(_))
+    ));
+    assert!(matches!(
+        query.first_common_slot(30),
+        Err(TruthError::InvalidArguments(_))
+    ));
+    assert!(matches!(
+        query.availability(),
+        Err(TruthError::InvalidArguments(_))
+    ));
+    // Conflicts consider every event instead.
+    assert_eq!(query.conflicts().len(), 1);
+}
+
+#[test]
+fn reschedule_suggestions_keep_buffers_and_working_hours() {
+    // Alice's 9:00–10:00 clashes with Bob's 9:30–11:00; the default
+    // calendar works 9:00–17:00 UTC.
+    let conflicted = event(utc(2, 9, 0), utc(2, 10, 0));
+    let query = ScheduleQuery::new()
+        .streams(&streams())
+        .window(utc(2, 0, 0), utc(3, 0, 0))
+        .working_hours(BusinessCalendar::default())
+        .buffer_minutes(15);
+
+    let suggestions = query.reschedule(&conflicted, 10).unwrap();
+    let suggestion = |start, displacement_minutes| RescheduleSuggestion {
+        start,
+        end: start + chrono::Duration::hours(1),
+        displacement_minutes,
+    };
+    // Working hours leave nothing earlier; the only hour that fits starts
+    // after Bob's meeting and the buffer.
+    assert_eq!(suggestions, vec![suggestion(utc(2, 11, 15), 135)]);
+
+    // The event's own slot counts as free once it is left out.
+    let alone = ScheduleQuery::new()
+        .events(std::slice::from_ref(&conflicted))
+        .window(utc(2, 8, 0), utc(2, 12, 0));
+    assert_eq!(
+        alone.reschedule(&conflicted, 1).unwrap(),
+        vec![suggestion(utc(2, 9, 0), 0)]
+    );
+    assert!(matches!(
+        alone.reschedule(&event(utc(2, 10, 0), utc(2, 9, 0)), 1),
+        Err(TruthError::InvalidArguments(_))
+    ));
+}
+
+fn meeting(id: &str, minutes: u32, attendees: &[&str]) -> MeetingRequest {
+    MeetingRequest {
+        id: id.to_string(),
+        duration_minutes: minutes,
+        attendees: attendees.iter().map(|a| a.to_string()).collect(),
+        not_before: None,
+        deadline: None,
+    }
+}
+
+#[test]
+fn batches_place_every_meeting_clear_of_events_and_each_other() {
+    // Monday working hours: Alice is busy 9:00–10:00, Bob 9:30–11:00.
+    let query = ScheduleQuery::new()
+        .streams(&streams())
+        .window(utc(2, 0, 0), utc(3, 0, 0))
+        .working_hours(BusinessCalendar::default())
+        .buffer_minutes(15);
+    let mut late = meeting("late", 60, &["alice"]);
+    late.not_before = Some(utc(2, 16, 0));
+    let batch = query
+        .place_batch(
+            &[
+                meeting("all-hands", 120, &[]),
+                meeting("1:1", 60, &["alice", "bob"]),
+                meeting("focus", 30, &["bob"]),
+                late,
+            ],
+            15,
+        )
+        .unwrap();
+    assert!(batch.is_complete());
+    let placed: Vec<(&str, DateTime<Utc>, DateTime<Utc>)> = batch
+        .placed
+        .iter()
+        .map(|p| (p.meeting_id.as_str(), p.start, p.end))
+        .collect();
+    // The most constrained meetings go first; each keeps the buffer from
+    // the events and from the meetings it shares an attendee with.
+    assert_eq!(
+        placed,
+        vec![
+            ("all-hands", utc(2, 11, 15), utc(2, 13, 15)),
+            ("1:1", utc(2, 13, 30), utc(2, 14, 30)),
+            ("focus", utc(2, 14, 45), utc(2, 15, 15)),
+            ("late", utc(2, 16, 0), utc(2, 17, 0)),
+        ]
+    );
+}
+
+#[test]
+fn batches_that_cannot_all_fit_keep_the_largest_subset() {
+    let query = ScheduleQuery::new()
+        .streams(&streams())
+        .window(utc(2, 11, 0), utc(2, 14, 0));
+    let mut early = meeting("early", 30, &["bob"]);
+    early.deadline = Some(utc(2, 11, 0));
+    let batch = query
+        .place_batch(
+            &[
+                meeting("planning", 120, &["alice", "bob"]),
+                meeting("retro", 120, &["alice"]),
+                meeting("standup", 60, &["bob"]),
+                early,
+            ],
+            30,
+        )
+        .unwrap();
+    // Three free hours hold "planning" and "standup", or "retro" and
+    // "standup", but never both two-hour meetings with Alice.
+    assert!(!batch.is_complete());
+    assert_eq!(batch.placed.len(), 2);
+    let reasons: Vec<(&str, UnplacedMeetingReason, Vec<String>)> = batch
+        .unplaced
+        .iter()
+        .map(|u| (u.meeting_id.as_str(), u.reason, u.blocked_by.clone()))
+        .collect();
+    assert_eq!(
+        reasons,
+        vec![
+            (
+                "retro",
+                UnplacedMeetingReason::ConflictsWithBatch,
+                vec!["planning".to_string()]
+            ),
+            ("early", UnplacedMeetingReason::NoCommonTime, vec![]),
+        ]
+    );
+    assert_eq!(batch.placed[0].meeting_id, "planning");
+
+    assert!(matches!(
+        query.place_batch(&[meeting("x", 30, &["carol"])], 15),
+        Err(TruthError::InvalidArguments(_))
+    ));
+    assert!(matches!(
+        query.place_batch(&[meeting("x", 0, &[])], 15),
+        Err(TruthError::InvalidDuration(_))
+    ));
+    assert!(matches!(
+        query.place_batch(&[meeting("x", 30, &[])], 0),
+        Err(TruthError::InvalidDuration(_))
+    ));
+}
+
+#[test]
+fn rest_rules_keep_gaps_around_proposed_meetings() {
+    let query = ScheduleQuery::new()
+        .streams(&streams())
+        .window(utc(2, 0, 0), utc(3, 0, 0))
+        .working_hours(BusinessCalendar::default());
+    let rested = |daily_gap_minutes| {
+        query.clone().rest_rule(RestRule {
+            min_gap_minutes: 30,
+            daily_gap_minutes,
+        })
+    };
+
+    // Bob's 9:30 meeting moves half an hour clear of Alice's 9:00–10:00.
+    let bobs = event(utc(2, 9, 30), utc(2, 11, 0));
+    assert_eq!(query.reschedule(&bobs, 1).unwrap()[0].start, utc(2, 10, 0));
+    assert_eq!(
+        rested(0).reschedule(&bobs, 1).unwrap()[0].start,
+        utc(2, 10, 30)
+    );
+
+    // Alice is free 10:30–17:00 with the minimum gap: two two-hour blocks
+    // leave her 15:00–17:00, a third does not fit.
+    let blocks = [
+        meeting("a", 120, &["alice"]),
+        meeting("b", 120, &["alice"]),
+        meeting("c", 120, &["alice"]),
+    ];
+    let batch = rested(120).place_batch(&blocks, 30).unwrap();
+    let placed: Vec<(&str, DateTime<Utc>)> = batch
+        .placed
+        .iter()
+        .map(|p| (p.meeting_id.as_str(), p.start))
+        .collect();
+    assert_eq!(placed, vec![("a", utc(2, 10, 30)), ("b", utc(2, 13, 0))]);
+    assert_eq!(batch.unplaced[0].meeting_id, "c");
+
+    // Keeping three free hours leaves room for one block only.
+    let batch = rested(180).place_batch(&blocks[..2], 30).unwrap();
+    assert_eq!(batch.placed.len(), 1);
+    assert_eq!(
+        batch.unplaced[0].reason,
+        UnplacedMeetingReason::ConflictsWithBatch
+    );
+    assert_eq!(
+        batch.unplaced[0].blocked_by,
+        vec![batch.placed[0].meeting_id.clone()]
+    );
+
+    // With any one block, Alice's longest free stretch is five hours.
+    let batch = rested(330).place_batch(&blocks[..1], 30).unwrap();
+    assert_eq!(
+        batch.unplaced[0].reason,
+        UnplacedMeetingReason::NoCommonTime
+    );
+}
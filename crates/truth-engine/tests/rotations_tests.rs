@@ -0,0 +1,167 @@
+//! Tests for on-call rotations.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use truth_engine::rotations::{Override, Region, Rotation, RotationPattern};
+use truth_engine::TruthError;
+
+fn utc(mo: u32, d: u32, h: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, mo, d, h, 0, 0).unwrap()
+}
+
+fn time(h: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, 0, 0).unwrap()
+}
+
+fn weekly() -> Rotation {
+    Rotation::new(RotationPattern::Cycle {
+        participants: vec!["ana".to_string(), "ben".to_string()],
+        length_days: 7,
+        start_date: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+        handoff: time(9),
+        timezone: "America/New_York".to_string(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn cycle_hands_off_at_local_time_across_dst() {
+    let rotation = weekly();
+    assert_eq!(rotation.on_call_at(utc(3, 2, 13)).unwrap(), None);
+    // 09:00 EST is 14:00 UTC.
+    assert_eq!(
+        rotation.on_call_at(utc(3, 2, 14)).unwrap().as_deref(),
+        Some("ana")
+    );
+    // DST began March 8: the March 9 handoff is 09:00 EDT, 13:00 UTC.
+    assert_eq!(
+        rotation.on_call_at(utc(3, 9, 12)).unwrap().as_deref(),
+        Some("ana")
+    );
+    assert_eq!(
+        rotation.on_call_at(utc(3, 9, 13)).unwrap().as_deref(),
+        Some("ben")
+    );
+
+    let shifts = rotation.shifts(utc(3, 1, 0), utc(3, 17, 0)).unwrap();
+    let spans: Vec<_> = shifts
+        .iter()
+        .map(|s| (s.participant.as_str(), s.start, s.end))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            ("ana", utc(3, 2, 14), utc(3, 9, 13)),
+            ("ben", utc(3, 9, 13), utc(3, 16, 13)),
+            ("ana", utc(3, 16, 13), utc(3, 23, 13)),
+        ]
+    );
+    // The DST week is an hour short.
+    assert_eq!(shifts[0].end - shifts[0].start, Duration::hours(167));
+}
+
+#[test]
+fn eight_weeks_alternate_far_from_the_start() {
+    let rotation = weekly();
+    let start = utc(11, 2, 14);
+    let shifts = rotation.shifts(start, start + Duration::weeks(8)).unwrap();
+    assert_eq!(shifts.len(), 8);
+    for pair in shifts.windows(2) {
+        assert_eq!(pair[0].end, pair[1].start);
+        assert_ne!(pair[0].participant, pair[1].participant);
+    }
+}
+
+#[test]
+fn follow_the_sun_hands_off_between_regions() {
+    let rotation = Rotation::new(RotationPattern::FollowTheSun {
+        regions: vec![
+            Region {
+                participant: "sydney".to_string(),
+                timezone: "Australia/Sydney".to_string(),
+                start: time(9),
+            },
+            Region {
+                participant: "london".to_string(),
+                timezone: "Europe/London".to_string(),
+                start: time(9),
+            },
+            Region {
+                participant: "sf".to_string(),
+                timezone: "America/Los_Angeles".to_string(),
+                start: time(9),
+            },
+        ],
+    })
+    .unwrap();
+    // In January: Sydney 09:00 AEDT = 22:00 UTC, London 09:00 UTC, SF 17:00 UTC.
+    let shifts = rotation.shifts(utc(1, 15, 0), utc(1, 16, 0)).unwrap();
+    let spans: Vec<_> = shifts
+        .iter()
+        .map(|s| (s.participant.as_str(), s.start, s.end))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            ("sydney", utc(1, 14, 22), utc(1, 15, 9)),
+            ("london", utc(1, 15, 9), utc(1, 15, 17)),
+            ("sf", utc(1, 15, 17), utc(1, 15, 22)),
+            ("sydney", utc(1, 15, 22), utc(1, 16, 9)),
+        ]
+    );
+}
+
+#[test]
+fn overrides_split_shifts_and_swaps_exchange_them() {
+    let mut rotation = weekly();
+    rotation
+        .add_override(Override {
+            participant: "cy".to_string(),
+            start: utc(3, 4, 0),
+            end: utc(3, 5, 0),
+        })
+        .unwrap();
+    let shifts = rotation.shifts(utc(3, 3, 0), utc(3, 6, 0)).unwrap();
+    let spans: Vec<_> = shifts
+        .iter()
+        .map(|s| (s.participant.as_str(), s.is_override))
+        .collect();
+    assert_eq!(spans, vec![("ana", false), ("cy", true), ("ana", false)]);
+    assert_eq!(shifts[2].end, utc(3, 9, 13));
+
+    rotation.swap(utc(3, 10, 0), utc(3, 17, 0)).unwrap();
+    assert_eq!(
+        rotation.on_call_at(utc(3, 10, 0)).unwrap().as_deref(),
+        Some("ana")
+    );
+    assert_eq!(
+        rotation.on_call_at(utc(3, 17, 0)).unwrap().as_deref(),
+        Some("ben")
+    );
+}
+
+#[test]
+fn invalid_rotations_are_rejected() {
+    let empty = Rotation::new(RotationPattern::FollowTheSun { regions: vec![] });
+    assert!(matches!(empty, Err(TruthError::InvalidRotation(_))));
+    let zero = Rotation::new(RotationPattern::Cycle {
+        participants: vec!["ana".to_string()],
+        length_days: 0,
+        start_date: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+        handoff: time(9),
+        timezone: "UTC".to_string(),
+    });
+    assert!(matches!(zero, Err(TruthError::InvalidRotation(_))));
+    let mut rotation = weekly();
+    assert!(matches!(
+        rotation.swap(utc(1, 1, 0), utc(3, 10, 0)),
+        Err(TruthError::InvalidRotation(_))
+    ));
+    assert!(matches!(
+        rotation.add_override(Override {
+            participant: "cy".to_string(),
+            start: utc(3, 5, 0),
+            end: utc(3, 4, 0),
+        }),
+        Err(TruthError::InvalidRotation(_))
+    ));
+}
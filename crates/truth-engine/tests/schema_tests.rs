@@ -0,0 +1,92 @@
+//! Tests for JSON Schema generation (`schemars` feature).
+
+#![cfg(feature = "schemars")]
+
+use schemars::schema_for;
+#[cfg(feature = "resolver")]
+use schemars::JsonSchema;
+#[cfg(feature = "resolver")]
+use serde_json::{json, Value};
+use truth_engine::PrivacyLevel;
+#[cfg(feature = "resolver")]
+use truth_engine::{
+    compute_duration, convert_timezone, merge_availability, resolve_relative, ConvertedDatetime,
+    DurationInfo, EventStream, ExpandedEvent, ResolveOptions, ResolvedDatetime,
+    UnifiedAvailability,
+};
+
+/// Validate the top-level shape of `value` against `T`'s schema: every
+/// required property is present and no property is unknown.
+#[cfg(feature = "resolver")]
+fn assert_matches_schema<T: JsonSchema>(value: &Value) {
+    let schema = serde_json::to_value(schema_for!(T)).unwrap();
+    let properties = schema["properties"].as_object().unwrap();
+    let object = value.as_object().unwrap();
+    for required in schema["required"].as_array().into_iter().flatten() {
+        assert!(
+            object.contains_key(required.as_str().unwrap()),
+            "{required}"
+        );
+    }
+    for key in object.keys() {
+        assert!(properties.contains_key(key), "{key} not in schema");
+    }
+}
+
+#[cfg(feature = "resolver")]
+#[test]
+fn result_schemas_describe_serialized_results() {
+    let converted = convert_timezone("2026-03-08T07:30:00Z", "America/New_York").unwrap();
+    assert_matches_schema::<ConvertedDatetime>(&serde_json::to_value(converted).unwrap());
+
+    let duration = compute_duration("2026-02-18T09:00:00Z", "2026-02-20T17:30:00Z").unwrap();
+    assert_matches_schema::<DurationInfo>(&serde_json::to_value(duration).unwrap());
+
+    let anchor = "2026-02-18T14:30:00Z".parse().unwrap();
+    let resolved = resolve_relative(anchor, "next Tuesday at 2pm", "UTC").unwrap();
+    assert_matches_schema::<ResolvedDatetime>(&serde_json::to_value(resolved).unwrap());
+
+    let stream = EventStream {
+        stream_id: "work".to_string(),
+        events: vec![ExpandedEvent::new(
+            "2026-03-16T09:00:00Z".parse().unwrap(),
+            "2026-03-16T10:00:00Z".parse().unwrap(),
+        )],
+    };
+    let availability = merge_availability(
+        &[stream],
+        "2026-03-16T08:00:00Z".parse().unwrap(),
+        "2026-03-16T17:00:00Z".parse().unwrap(),
+        PrivacyLevel::Full,
+    );
+    assert_matches_schema::<UnifiedAvailability>(&serde_json::to_value(availability).unwrap());
+}
+
+#[cfg(feature = "resolver")]
+#[test]
+fn option_schemas_skip_code_and_default_everything() {
+    let schema = serde_json::to_value(schema_for!(ResolveOptions)).unwrap();
+    let properties = schema["properties"].as_object().unwrap();
+    assert!(!properties.contains_key("parsers"));
+    assert!(properties.contains_key("working_hours"));
+    assert!(schema.get("required").is_none());
+
+    // Every field is optional when deserializing.
+    let options: ResolveOptions =
+        serde_json::from_value(json!({"lenient": true, "fiscal_year_start": "October"})).unwrap();
+    assert!(options.lenient);
+    assert_eq!(options.fiscal_year_start, Some(chrono::Month::October));
+}
+
+#[test]
+fn enum_schemas_list_variants() {
+    let schema = serde_json::to_value(schema_for!(PrivacyLevel)).unwrap();
+    let variants: Vec<_> = schema["enum"]
+        .as_array()
+        .or_else(|| schema["oneOf"].as_array())
+        .unwrap()
+        .iter()
+        .map(|v| v.to_string())
+        .collect();
+    assert_eq!(variants.len(), 2, "{schema}");
+}
@@ -0,0 +1,142 @@
+//! Tests for SLA deadlines.
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use truth_engine::business::BusinessCalendar;
+use truth_engine::sla::{compute_deadline, remaining, Pause};
+use truth_engine::TruthError;
+
+/// March 2, 2026 is a Monday.
+fn utc(d: u32, h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, d, h, m, 0).unwrap()
+}
+
+fn calendar() -> BusinessCalendar {
+    BusinessCalendar::default()
+}
+
+#[test]
+fn deadlines_skip_weekends_and_holidays() {
+    // Friday 16:30 + 8 business hours: 30 minutes Friday, 7.5 hours Monday.
+    assert_eq!(
+        compute_deadline(utc(6, 16, 30), Duration::hours(8), &calendar(), &[]).unwrap(),
+        Some(utc(9, 16, 30))
+    );
+    // With Monday a holiday, the rest lands on Tuesday.
+    let with_holiday = BusinessCalendar {
+        holidays: [NaiveDate::from_ymd_opt(2026, 3, 9).unwrap()].into(),
+        ..calendar()
+    };
+    assert_eq!(
+        compute_deadline(utc(6, 16, 30), Duration::hours(8), &with_holiday, &[]).unwrap(),
+        Some(utc(10, 16, 30))
+    );
+    // Opened on Saturday: the clock starts Monday morning.
+    assert_eq!(
+        compute_deadline(utc(7, 10, 0), Duration::hours(1), &calendar(), &[]).unwrap(),
+        Some(utc(9, 10, 0))
+    );
+    // An allowance that ends with the working day ends at 17:00, not the next morning.
+    assert_eq!(
+        compute_deadline(utc(2, 9, 0), Duration::hours(8), &calendar(), &[]).unwrap(),
+        Some(utc(2, 17, 0))
+    );
+    assert_eq!(
+        compute_deadline(utc(7, 10, 0), Duration::zero(), &calendar(), &[]).unwrap(),
+        Some(utc(7, 10, 0))
+    );
+}
+
+#[test]
+fn pauses_stop_the_clock() {
+    let pauses = [
+        Pause {
+            start: utc(2, 10, 0),
+            end: Some(utc(2, 12, 0)),
+        },
+        // Overlapping pauses count once.
+        Pause {
+            start: utc(2, 11, 0),
+            end: Some(utc(2, 13, 0)),
+        },
+    ];
+    assert_eq!(
+        compute_deadline(utc(2, 9, 0), Duration::hours(4), &calendar(), &pauses).unwrap(),
+        Some(utc(2, 16, 0))
+    );
+
+    // Still waiting on the customer: no deadline yet, and the clock is frozen.
+    let open = [Pause {
+        start: utc(2, 10, 0),
+        end: None,
+    }];
+    assert_eq!(
+        compute_deadline(utc(2, 9, 0), Duration::hours(4), &calendar(), &open).unwrap(),
+        None
+    );
+    assert_eq!(
+        remaining(
+            utc(4, 12, 0),
+            utc(2, 9, 0),
+            Duration::hours(4),
+            &calendar(),
+            &open
+        )
+        .unwrap(),
+        Duration::hours(3)
+    );
+    // An open pause after the deadline does not matter.
+    let late = [Pause {
+        start: utc(3, 9, 0),
+        end: None,
+    }];
+    assert_eq!(
+        compute_deadline(utc(2, 9, 0), Duration::hours(4), &calendar(), &late).unwrap(),
+        Some(utc(2, 13, 0))
+    );
+}
+
+#[test]
+fn remaining_counts_down_and_goes_negative() {
+    let sla = Duration::hours(4);
+    let start = utc(6, 15, 0);
+    assert_eq!(
+        remaining(utc(6, 14, 0), start, sla, &calendar(), &[]).unwrap(),
+        sla
+    );
+    // Friday 15:00–17:00 used; the weekend is free.
+    assert_eq!(
+        remaining(utc(8, 12, 0), start, sla, &calendar(), &[]).unwrap(),
+        Duration::hours(2)
+    );
+    assert_eq!(
+        remaining(utc(9, 12, 0), start, sla, &calendar(), &[]).unwrap(),
+        Duration::hours(-1)
+    );
+}
+
+#[test]
+fn invalid_inputs_are_rejected() {
+    assert!(matches!(
+        compute_deadline(utc(2, 9, 0), Duration::hours(-1), &calendar(), &[]),
+        Err(TruthError::InvalidDuration(_))
+    ));
+    let bad = BusinessCalendar {
+        timezone: "Mars/Olympus".to_string(),
+        ..calendar()
+    };
+    assert!(matches!(
+        remaining(utc(3, 9, 0), utc(2, 9, 0), Duration::hours(1), &bad, &[]),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+    let never = BusinessCalendar {
+        working_hours: truth_engine::temporal::WorkingHours {
+            days: vec![],
+            ..Default::default()
+        },
+        ..calendar()
+    };
+    assert_eq!(
+        compute_deadline(utc(2, 9, 0), Duration::hours(1), &never, &[]).unwrap(),
+        None
+    );
+}
@@ -0,0 +1,158 @@
+//! Tests for the in-memory event store.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use truth_engine::store::{EventStore, Schedule, StoredEvent};
+use truth_engine::{ExpandedEvent, PrivacyLevel, TruthError};
+
+fn utc(d: u32, h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, d, h, m, 0).unwrap()
+}
+
+fn local(y: i32, mo: u32, d: u32, h: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, mo, d)
+        .unwrap()
+        .and_hms_opt(h, 0, 0)
+        .unwrap()
+}
+
+fn once(stream: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> StoredEvent {
+    StoredEvent {
+        stream_id: stream.to_string(),
+        schedule: Schedule::Once { start, end },
+    }
+}
+
+fn daily(stream: &str, dtstart: NaiveDateTime, exdates: Vec<NaiveDateTime>) -> StoredEvent {
+    StoredEvent {
+        stream_id: stream.to_string(),
+        schedule: Schedule::Recurring {
+            rrule: "FREQ=DAILY".to_string(),
+            dtstart,
+            duration_minutes: 30,
+            timezone: "UTC".to_string(),
+            exdates,
+        },
+    }
+}
+
+#[test]
+fn insert_update_and_remove() {
+    let mut store = EventStore::new();
+    assert!(store.is_empty());
+    let first = once("work", utc(2, 9, 0), utc(2, 10, 0));
+    assert_eq!(store.insert("a", first.clone()).unwrap(), None);
+    let moved = once("work", utc(3, 9, 0), utc(3, 10, 0));
+    assert_eq!(store.insert("a", moved.clone()).unwrap(), Some(first));
+    assert_eq!(store.len(), 1);
+
+    // The old start is no longer indexed.
+    assert!(store
+        .occurrences(utc(2, 0, 0), utc(3, 0, 0))
+        .unwrap()
+        .is_empty());
+    assert_eq!(store.get("a"), Some(&moved));
+    assert_eq!(store.remove("a"), Some(moved));
+    assert_eq!(store.remove("a"), None);
+    assert!(store
+        .occurrences(utc(1, 0, 0), utc(31, 0, 0))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn invalid_events_leave_the_store_unchanged() {
+    let mut store = EventStore::new();
+    store
+        .insert("a", once("work", utc(2, 9, 0), utc(2, 10, 0)))
+        .unwrap();
+    assert!(matches!(
+        store.insert("a", once("work", utc(2, 10, 0), utc(2, 9, 0))),
+        Err(TruthError::InvalidDatetime(_))
+    ));
+    let mut bad_zone = daily("work", local(2026, 3, 2, 9), vec![]);
+    if let Schedule::Recurring { timezone, .. } = &mut bad_zone.schedule {
+        *timezone = "Mars/Olympus".to_string();
+    }
+    assert!(matches!(
+        store.insert("a", bad_zone),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+    assert_eq!(
+        store.get("a"),
+        Some(&once("work", utc(2, 9, 0), utc(2, 10, 0)))
+    );
+}
+
+#[test]
+fn range_queries_include_overlapping_events_in_order() {
+    let mut store = EventStore::new();
+    // A long event starting before the window still overlaps it.
+    store
+        .insert("offsite", once("work", utc(1, 0, 0), utc(4, 0, 0)))
+        .unwrap();
+    store
+        .insert("lunch", once("personal", utc(2, 12, 0), utc(2, 13, 0)))
+        .unwrap();
+    store
+        .insert("later", once("work", utc(9, 9, 0), utc(9, 10, 0)))
+        .unwrap();
+    store
+        .insert(
+            "standup",
+            daily("work", local(2026, 1, 1, 9), vec![local(2026, 3, 3, 9)]),
+        )
+        .unwrap();
+
+    let found = store.occurrences(utc(2, 0, 0), utc(5, 0, 0)).unwrap();
+    let ids: Vec<_> = found.iter().map(|o| o.id.as_str()).collect();
+    // The March 3 standup is an exdate.
+    assert_eq!(ids, vec!["offsite", "standup", "lunch", "standup"]);
+    assert_eq!(found[3].occurrence.start, utc(4, 9, 0));
+    assert_eq!(found[2].stream_id, "personal");
+}
+
+#[test]
+fn series_are_queried_far_past_the_default_expansion_limit() {
+    let mut store = EventStore::new();
+    store
+        .insert("standup", daily("work", local(2020, 1, 1, 9), vec![]))
+        .unwrap();
+    let found = store.occurrences(utc(2, 0, 0), utc(9, 0, 0)).unwrap();
+    assert_eq!(found.len(), 7);
+    assert_eq!(found[0].occurrence.start, utc(2, 9, 0));
+}
+
+#[test]
+fn queries_feed_conflicts_free_slots_and_availability() {
+    let mut store = EventStore::new();
+    store
+        .insert("standup", daily("work", local(2026, 1, 1, 9), vec![]))
+        .unwrap();
+    store
+        .insert("dentist", once("personal", utc(2, 9, 15), utc(2, 10, 0)))
+        .unwrap();
+
+    let conflicts = store
+        .conflicts_with(&ExpandedEvent::new(utc(2, 9, 20), utc(2, 9, 40)))
+        .unwrap();
+    assert_eq!(conflicts.len(), 2);
+    let adjacent = store
+        .conflicts_with(&ExpandedEvent::new(utc(2, 10, 0), utc(2, 11, 0)))
+        .unwrap();
+    assert!(adjacent.is_empty());
+
+    let free = store.free_slots(utc(2, 8, 0), utc(2, 12, 0)).unwrap();
+    assert_eq!(free.len(), 2);
+    assert_eq!((free[0].start, free[0].end), (utc(2, 8, 0), utc(2, 9, 0)));
+    assert_eq!((free[1].start, free[1].end), (utc(2, 10, 0), utc(2, 12, 0)));
+
+    let streams = store.streams(utc(2, 8, 0), utc(2, 12, 0)).unwrap();
+    let ids: Vec<_> = streams.iter().map(|s| s.stream_id.as_str()).collect();
+    assert_eq!(ids, vec!["personal", "work"]);
+
+    let availability = store
+        .availability(utc(2, 8, 0), utc(2, 12, 0), PrivacyLevel::Full)
+        .unwrap();
+    assert_eq!(availability.busy.len(), 1);
+    assert_eq!(availability.busy[0].source_count, 2);
+}
@@ -0,0 +1,228 @@
+//! Tests for academic term calendars.
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc, Weekday};
+use truth_engine::availability::PrivacyLevel;
+#[cfg(feature = "resolver")]
+use truth_engine::temporal::{
+    resolve_relative_with_options, ResolutionGranularity, ResolveOptions,
+};
+use truth_engine::terms::{Blackout, BreakKind, Term, TermBreak, TermCalendar, BLACKOUTS_STREAM};
+use truth_engine::TruthError;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn utc(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+}
+
+fn term(name: &str, start: NaiveDate, end: NaiveDate, breaks: Vec<TermBreak>) -> Term {
+    Term {
+        name: name.to_string(),
+        start,
+        end,
+        breaks,
+    }
+}
+
+fn reading_week(start: NaiveDate) -> TermBreak {
+    TermBreak {
+        name: "Reading week".to_string(),
+        kind: BreakKind::ReadingWeek,
+        start,
+        end: start + chrono::Duration::days(4),
+    }
+}
+
+/// A London university year, listed out of order.
+fn london() -> TermCalendar {
+    TermCalendar::new(
+        "Europe/London",
+        vec![
+            term(
+                "Spring term",
+                date(2027, 1, 11),
+                date(2027, 3, 26),
+                vec![reading_week(date(2027, 2, 15))],
+            ),
+            term(
+                "Autumn term",
+                date(2026, 9, 28),
+                date(2026, 12, 11),
+                vec![reading_week(date(2026, 11, 2))],
+            ),
+            term(
+                "Summer term",
+                date(2027, 4, 26),
+                date(2027, 6, 25),
+                vec![TermBreak {
+                    name: "Bank holiday".to_string(),
+                    kind: BreakKind::Break,
+                    start: date(2027, 5, 31),
+                    end: date(2027, 5, 31),
+                }],
+            ),
+            term("Autumn term", date(2027, 9, 27), date(2027, 12, 10), vec![]),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn terms_breaks_and_teaching_days() {
+    let calendar = london();
+    assert_eq!(calendar.terms[0].start, date(2026, 9, 28));
+    assert_eq!(
+        calendar.term_of(date(2027, 2, 16)).map(|t| t.name.as_str()),
+        Some("Spring term")
+    );
+    assert_eq!(
+        calendar.break_of(date(2027, 2, 16)).map(|b| b.kind),
+        Some(BreakKind::ReadingWeek)
+    );
+    assert!(calendar.is_teaching_day(date(2027, 2, 12)));
+    assert!(!calendar.is_teaching_day(date(2027, 2, 16)));
+    assert!(!calendar.is_teaching_day(date(2027, 4, 1)));
+
+    let spring = calendar
+        .find_term("SPRING TERM", date(2026, 10, 1))
+        .unwrap();
+    assert_eq!(spring.nth_weekday(Weekday::Mon, 1), Some(date(2027, 1, 11)));
+    assert_eq!(
+        spring.nth_weekday(Weekday::Fri, -1),
+        Some(date(2027, 3, 26))
+    );
+    assert_eq!(spring.nth_weekday(Weekday::Mon, 20), None);
+
+    // The current autumn term, then the next one once it ends; a year picks one.
+    let autumn = |today| calendar.find_term("autumn term", today).unwrap().start;
+    assert_eq!(autumn(date(2026, 10, 1)), date(2026, 9, 28));
+    assert_eq!(autumn(date(2026, 12, 12)), date(2027, 9, 27));
+    assert_eq!(autumn(date(2028, 1, 1)), date(2027, 9, 27));
+    assert_eq!(
+        calendar
+            .find_term("autumn term 2027", date(2026, 10, 1))
+            .unwrap()
+            .start,
+        date(2027, 9, 27)
+    );
+    assert_eq!(
+        calendar
+            .find_break("reading week", date(2026, 11, 7))
+            .unwrap()
+            .start,
+        date(2027, 2, 15)
+    );
+}
+
+#[test]
+fn invalid_calendars_are_rejected() {
+    let overlap = TermCalendar::new(
+        "Europe/London",
+        vec![
+            term("A", date(2027, 1, 1), date(2027, 2, 1), vec![]),
+            term("B", date(2027, 2, 1), date(2027, 3, 1), vec![]),
+        ],
+    );
+    assert!(matches!(overlap, Err(TruthError::InvalidTermCalendar(_))));
+    let outside = TermCalendar::new(
+        "Europe/London",
+        vec![term(
+            "A",
+            date(2027, 1, 1),
+            date(2027, 2, 1),
+            vec![reading_week(date(2027, 1, 30))],
+        )],
+    );
+    assert!(matches!(outside, Err(TruthError::InvalidTermCalendar(_))));
+    assert!(matches!(
+        TermCalendar::new("Mars/Olympus", vec![]),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+}
+
+#[test]
+fn blackouts_cover_local_days() {
+    let calendar = london();
+    let breaks = calendar
+        .blackouts(utc(2027, 2, 1, 0), utc(2027, 3, 1, 0), Blackout::Breaks)
+        .unwrap();
+    assert_eq!(breaks.stream_id, BLACKOUTS_STREAM);
+    assert!(breaks.events.is_empty());
+    let all = calendar
+        .blackouts(utc(2027, 2, 1, 0), utc(2027, 3, 1, 0), Blackout::AllBreaks)
+        .unwrap();
+    let spans: Vec<_> = all.events.iter().map(|e| (e.start, e.end)).collect();
+    assert_eq!(spans, vec![(utc(2027, 2, 15, 0), utc(2027, 2, 20, 0))]);
+
+    // The Easter vacation ends at midnight BST on the first day of summer term.
+    let vacation = calendar
+        .blackouts(
+            utc(2027, 3, 20, 0),
+            utc(2027, 4, 30, 0),
+            Blackout::NonTeaching,
+        )
+        .unwrap();
+    let spans: Vec<_> = vacation.events.iter().map(|e| (e.start, e.end)).collect();
+    assert_eq!(spans, vec![(utc(2027, 3, 27, 0), utc(2027, 4, 25, 23))]);
+
+    let availability = calendar
+        .availability(
+            &[],
+            utc(2027, 3, 20, 0),
+            utc(2027, 4, 30, 0),
+            PrivacyLevel::Opaque,
+            Blackout::NonTeaching,
+        )
+        .unwrap();
+    let free: Vec<_> = availability.free.iter().map(|f| (f.start, f.end)).collect();
+    assert_eq!(
+        free,
+        vec![
+            (utc(2027, 3, 20, 0), utc(2027, 3, 27, 0)),
+            (utc(2027, 4, 25, 23), utc(2027, 4, 30, 0)),
+        ]
+    );
+}
+
+#[cfg(feature = "resolver")]
+#[test]
+fn resolve_relative_understands_terms() {
+    let options = ResolveOptions {
+        term_calendar: Some(london()),
+        ..Default::default()
+    };
+    let anchor = utc(2026, 10, 16, 12);
+    let resolve = |expr: &str| resolve_relative_with_options(anchor, expr, "UTC", &options);
+
+    assert_eq!(
+        resolve("first Monday of spring term").unwrap().resolved_utc,
+        "2027-01-11T00:00:00+00:00"
+    );
+    assert_eq!(
+        resolve("last Friday of spring term").unwrap().resolved_utc,
+        "2027-03-26T00:00:00+00:00"
+    );
+    // Midnight in London, during BST.
+    assert_eq!(
+        resolve("start of autumn term").unwrap().resolved_utc,
+        "2026-09-27T23:00:00+00:00"
+    );
+    assert_eq!(
+        resolve("end of reading week").unwrap().resolved_utc,
+        "2026-11-06T23:59:59+00:00"
+    );
+    let r = resolve("autumn term 2027").unwrap();
+    assert_eq!(r.resolved_utc, "2027-09-26T23:00:00+00:00");
+    assert_eq!(r.granularity, ResolutionGranularity::Period);
+    assert!(resolve("first Monday of winter term").is_err());
+
+    assert!(resolve_relative_with_options(
+        anchor,
+        "first Monday of spring term",
+        "UTC",
+        &ResolveOptions::default()
+    )
+    .is_err());
+}
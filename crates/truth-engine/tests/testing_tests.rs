@@ -0,0 +1,73 @@
+//! Tests for the `test-support` generators: what they generate is valid.
+#![cfg(feature = "test-support")]
+
+use proptest::prelude::*;
+#[cfg(feature = "resolver")]
+use truth_engine::resolve_relative;
+use truth_engine::temporal::interval::TimeInterval;
+#[cfg(feature = "resolver")]
+use truth_engine::testing::{datetime, expression, timezone};
+use truth_engine::testing::{event_stream, events, recurrence, window, SPAN_END, SPAN_START};
+use truth_engine::{merge_availability, EventStream, ExpandedEvent, PrivacyLevel};
+
+proptest! {
+    #[cfg(feature = "resolver")]
+    #[test]
+    fn expressions_resolve(expr in expression(), anchor in datetime(), tz in timezone()) {
+        prop_assert!(
+            resolve_relative(anchor, &expr, &tz).is_ok(),
+            "{expr:?} did not resolve in {tz}"
+        );
+    }
+
+    #[test]
+    fn recurrences_expand_within_count(spec in recurrence()) {
+        let events = spec.expand().unwrap();
+        let count: usize = spec.rrule.rsplit("COUNT=").next().unwrap().parse().unwrap();
+        prop_assert!(!events.is_empty());
+        prop_assert!(events.len() <= count);
+        for event in &events {
+            prop_assert!(event.start < event.end);
+        }
+    }
+
+    #[test]
+    fn events_and_windows_fall_within_the_span(
+        events in events(1..10),
+        (start, end) in window(),
+    ) {
+        prop_assert!(SPAN_START <= start && start < end && end <= SPAN_END);
+        for event in &events {
+            prop_assert!(event.start < event.end);
+            prop_assert!(SPAN_START <= event.start && event.start < SPAN_END);
+        }
+    }
+
+    #[test]
+    fn merged_busy_blocks_are_sorted_and_disjoint(
+        streams in prop::collection::vec(event_stream(), 1..4),
+        (start, end) in window(),
+        privacy in any::<PrivacyLevel>(),
+    ) {
+        let availability = merge_availability(&streams, start, end, privacy);
+        for pair in availability.busy.windows(2) {
+            prop_assert!(pair[0].end < pair[1].start);
+        }
+        for slot in &availability.free {
+            for block in &availability.busy {
+                prop_assert!(slot.end <= block.start || block.end <= slot.start);
+            }
+        }
+    }
+
+    #[test]
+    fn arbitrary_impls_are_valid(
+        event in any::<ExpandedEvent>(),
+        interval in any::<TimeInterval>(),
+        stream in any::<EventStream>(),
+    ) {
+        prop_assert!(event.start < event.end);
+        prop_assert!(!interval.is_empty());
+        prop_assert!(stream.events.len() <= 10);
+    }
+}
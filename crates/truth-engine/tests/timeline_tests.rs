@@ -0,0 +1,161 @@
+//! Tests for timeline layout.
+
+use chrono::{DateTime, TimeZone, Utc};
+use truth_engine::timeline::build_timeline;
+#[cfg(feature = "serde")]
+use truth_engine::timeline::Timeline;
+use truth_engine::{EventStream, ExpandedEvent};
+
+fn at(h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap()
+}
+
+fn event(start: DateTime<Utc>, end: DateTime<Utc>) -> ExpandedEvent {
+    ExpandedEvent::new(start, end)
+}
+
+fn stream(id: &str, events: Vec<ExpandedEvent>) -> EventStream {
+    EventStream {
+        stream_id: id.to_string(),
+        events,
+    }
+}
+
+#[test]
+fn overlapping_events_get_their_own_lanes() {
+    let work = stream(
+        "work",
+        vec![
+            event(at(9, 0), at(10, 0)),
+            event(at(9, 30), at(11, 0)),
+            event(at(10, 0), at(10, 30)),
+            event(at(9, 45), at(10, 15)),
+        ],
+    );
+    let personal = stream("personal", vec![event(at(12, 0), at(13, 0))]);
+    let timeline = build_timeline(&[work, personal], at(8, 0), at(18, 0));
+
+    let lanes: Vec<_> = timeline
+        .lanes
+        .iter()
+        .map(|l| (l.stream_id.as_str(), l.sub_lane))
+        .collect();
+    assert_eq!(
+        lanes,
+        vec![("work", 0), ("work", 1), ("work", 2), ("personal", 0)]
+    );
+    // 10:00–10:30 reuses the first lane, freed when 09:00–10:00 ends.
+    let placed: Vec<_> = timeline
+        .items
+        .iter()
+        .map(|i| (i.stream_id.as_str(), i.event_index, i.lane))
+        .collect();
+    assert_eq!(
+        placed,
+        vec![
+            ("work", 0, 0),
+            ("work", 1, 1),
+            ("work", 3, 2),
+            ("work", 2, 0),
+            ("personal", 0, 3),
+        ]
+    );
+}
+
+#[test]
+fn bars_are_clipped_to_the_window() {
+    let timeline = build_timeline(
+        &[stream(
+            "work",
+            vec![
+                event(at(7, 0), at(9, 0)),
+                event(at(17, 0), at(19, 0)),
+                event(at(5, 0), at(6, 0)),
+                event(at(12, 0), at(12, 0)),
+            ],
+        )],
+        at(8, 0),
+        at(18, 0),
+    );
+    assert_eq!(timeline.total_minutes, 600);
+    assert_eq!(timeline.items.len(), 2);
+
+    let morning = &timeline.items[0];
+    assert_eq!((morning.span.start, morning.span.end), (at(8, 0), at(9, 0)));
+    assert_eq!(morning.span.offset_minutes, 0);
+    assert_eq!(morning.span.duration_minutes, 60);
+    assert!(morning.clipped_start && !morning.clipped_end);
+
+    let evening = &timeline.items[1];
+    assert_eq!(evening.span.offset_minutes, 540);
+    assert_eq!(evening.span.duration_minutes, 60);
+    assert!(!evening.clipped_start && evening.clipped_end);
+    // Clipped bars share a lane when they no longer overlap.
+    assert_eq!(timeline.lanes.len(), 1);
+}
+
+#[test]
+fn conflicts_cover_streams_and_free_gaps_cover_the_rest() {
+    let timeline = build_timeline(
+        &[
+            stream("work", vec![event(at(9, 0), at(10, 30))]),
+            stream(
+                "personal",
+                vec![event(at(10, 0), at(11, 0)), event(at(10, 30), at(12, 0))],
+            ),
+        ],
+        at(9, 0),
+        at(13, 0),
+    );
+    let conflicts: Vec<_> = timeline
+        .conflicts
+        .iter()
+        .map(|c| {
+            (
+                c.item_a,
+                c.item_b,
+                c.span.offset_minutes,
+                c.span.duration_minutes,
+            )
+        })
+        .collect();
+    // Adjacent bars (work ends 10:30, personal starts 10:30) do not conflict.
+    assert_eq!(conflicts, vec![(0, 1, 60, 30), (1, 2, 90, 30)]);
+
+    let free: Vec<_> = timeline
+        .free
+        .iter()
+        .map(|f| (f.offset_minutes, f.duration_minutes))
+        .collect();
+    assert_eq!(free, vec![(180, 60)]);
+}
+
+#[test]
+fn empty_inputs() {
+    let timeline = build_timeline(&[], at(9, 0), at(10, 0));
+    assert!(timeline.items.is_empty());
+    assert_eq!(timeline.free.len(), 1);
+
+    let inverted = build_timeline(
+        &[stream("work", vec![event(at(9, 0), at(10, 0))])],
+        at(10, 0),
+        at(9, 0),
+    );
+    assert_eq!(inverted.total_minutes, 0);
+    assert!(inverted.items.is_empty() && inverted.free.is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serializes_round_trip() {
+    let timeline = build_timeline(
+        &[stream("work", vec![event(at(9, 0), at(10, 0))])],
+        at(8, 0),
+        at(12, 0),
+    );
+    let json = serde_json::to_value(&timeline).unwrap();
+    assert_eq!(json["items"][0]["span"]["offset_minutes"], 60);
+    assert_eq!(json["lanes"][0]["stream_id"], "work");
+    let back: Timeline = serde_json::from_value(json).unwrap();
+    assert_eq!(back, timeline);
+}
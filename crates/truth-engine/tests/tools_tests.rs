@@ -0,0 +1,155 @@
+//! Tests for the tool descriptors (`schemars` and `resolver` features).
+
+#![cfg(all(feature = "schemars", feature = "resolver"))]
+
+use std::collections::HashSet;
+
+use serde_json::json;
+use truth_engine::tools::{dispatch, find_tool, TOOLS};
+use truth_engine::TruthError;
+
+#[test]
+fn every_tool_has_a_unique_name_and_object_schema() {
+    let mut names = HashSet::new();
+    for tool in TOOLS {
+        assert!(names.insert(tool.name), "duplicate tool {}", tool.name);
+        assert!(!tool.description.is_empty());
+        let schema = tool.input_schema();
+        assert_eq!(schema["type"], "object", "{}", tool.name);
+        assert!(schema["properties"].is_object(), "{}", tool.name);
+    }
+    assert!(find_tool("expand_rrule").is_some());
+}
+
+#[test]
+fn schemas_mark_defaulted_arguments_optional() {
+    let schema = find_tool("convert_timezone").unwrap().input_schema();
+    let required: Vec<_> = schema["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v.as_str().unwrap())
+        .collect();
+    assert_eq!(required, vec!["datetime", "target_timezone"]);
+    assert!(schema["properties"]["input_mode"].is_object());
+}
+
+#[test]
+fn dispatch_calls_the_engine() {
+    let resolved = dispatch(
+        "resolve_relative",
+        json!({
+            "anchor": "2026-02-18T14:30:00Z",
+            "expression": "next Tuesday at 2pm",
+            "timezone": "America/New_York",
+        }),
+    )
+    .unwrap();
+    assert_eq!(resolved["resolved_utc"], "2026-02-24T19:00:00+00:00");
+
+    let lenient = dispatch(
+        "compute_duration",
+        json!({"start": "2026-02-18 09:00", "end": "2026-02-18T10:30:00Z", "input_mode": "Lenient"}),
+    )
+    .unwrap();
+    assert_eq!(lenient["total_seconds"], 5400);
+
+    let events = dispatch(
+        "expand_rrule",
+        json!({
+            "rrule": "FREQ=WEEKLY;BYDAY=TU,TH",
+            "dtstart": "2026-02-17T14:00:00",
+            "duration_minutes": 60,
+            "timezone": "America/Los_Angeles",
+            "count": 4,
+            "exdates": ["2026-02-19T14:00:00"],
+        }),
+    )
+    .unwrap();
+    assert_eq!(events.as_array().unwrap().len(), 3);
+
+    let availability = dispatch(
+        "merge_availability",
+        json!({
+            "streams": [{"stream_id": "work", "events": events}],
+            "window_start": "2026-02-17T00:00:00Z",
+            "window_end": "2026-02-18T00:00:00Z",
+        }),
+    )
+    .unwrap();
+    assert_eq!(availability["busy"].as_array().unwrap().len(), 1);
+
+    let matrix = dispatch(
+        "conflict_matrix",
+        json!({
+            "streams": [
+                {"stream_id": "work", "events": events},
+                {"stream_id": "gym", "events": [
+                    {"start": "2026-02-17T22:30:00Z", "end": "2026-02-17T23:30:00Z"},
+                ]},
+            ],
+            "window_start": "2026-02-17T00:00:00Z",
+            "window_end": "2026-02-18T00:00:00Z",
+        }),
+    )
+    .unwrap();
+    assert_eq!(matrix["overlap_minutes"], json!([[60, 30], [30, 60]]));
+
+    let labeled = dispatch(
+        "label_slots",
+        json!({
+            "slots": availability["free"],
+            "timezones": ["America/Los_Angeles", "Europe/Paris"],
+            "clock": "TwentyFourHour",
+        }),
+    )
+    .unwrap();
+    assert_eq!(labeled[0]["summary"], "16:00 PST / 01:00 CET");
+}
+
+#[test]
+fn bad_calls_are_rejected() {
+    assert!(matches!(
+        dispatch("no_such_tool", json!({})),
+        Err(TruthError::UnknownTool(_))
+    ));
+    assert!(matches!(
+        dispatch(
+            "convert_timezone",
+            json!({"datetime": "2026-02-18T14:30:00Z"})
+        ),
+        Err(TruthError::InvalidArguments(_))
+    ));
+    assert!(matches!(
+        dispatch(
+            "convert_timezone",
+            json!({"datetime": "2026-02-18T14:30:00Z", "target_timezone": "Mars/Olympus"})
+        ),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+    // All of them are the caller's to fix.
+    for name in ["no_such_tool", "convert_timezone"] {
+        let err = dispatch(name, json!({})).unwrap_err();
+        assert_eq!(err.class(), truth_engine::ErrorClass::Input, "{err}");
+    }
+}
+
+#[test]
+fn dispatch_with_provenance_wraps_results() {
+    use truth_engine::provenance::options_hash;
+    use truth_engine::tools::dispatch_with_provenance;
+
+    let arguments =
+        json!({"expression": "tomorrow", "timezone": "UTC", "anchor": "2026-02-18T14:30:00Z"});
+    let plain = dispatch("resolve_relative", arguments.clone()).unwrap();
+    let stamped = dispatch_with_provenance("resolve_relative", arguments.clone()).unwrap();
+    assert_eq!(stamped["result"], plain);
+    assert_eq!(
+        stamped["provenance"]["options_hash"],
+        options_hash(&arguments)
+    );
+    assert!(matches!(
+        dispatch_with_provenance("no_such_tool", json!({})),
+        Err(TruthError::UnknownTool(_))
+    ));
+}
@@ -0,0 +1,128 @@
+//! Tests for travel-time providers and their consumers.
+
+use chrono::{DateTime, Duration, TimeZone, Timelike, Utc};
+use truth_engine::planner::{plan_tasks_with_travel, PlanOptions, Task};
+use truth_engine::travel::{
+    find_travel_conflicts, travel_buffers, LocatedEvent, StaticTravelMatrix, TravelTimeProvider,
+};
+use truth_engine::ExpandedEvent;
+
+fn utc(h: u32, m: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 2, h, m, 0).unwrap()
+}
+
+fn at(start: DateTime<Utc>, end: DateTime<Utc>, location: Option<&str>) -> LocatedEvent {
+    LocatedEvent {
+        event: ExpandedEvent::new(start, end),
+        location: location.map(str::to_string),
+    }
+}
+
+fn matrix() -> StaticTravelMatrix {
+    let mut matrix = StaticTravelMatrix::new();
+    matrix
+        .insert_both("HQ", "Client", 30)
+        .insert("HQ", "Airport", 45);
+    matrix
+}
+
+#[test]
+fn static_matrix_lookups() {
+    let matrix = matrix();
+    assert_eq!(
+        matrix.travel_time("Client", "HQ", utc(9, 0)),
+        Some(Duration::minutes(30))
+    );
+    assert_eq!(
+        matrix.travel_time("HQ", "Airport", utc(9, 0)),
+        Some(Duration::minutes(45))
+    );
+    assert_eq!(matrix.travel_time("Airport", "HQ", utc(9, 0)), None);
+    assert_eq!(
+        matrix.travel_time("Lab", "Lab", utc(9, 0)),
+        Some(Duration::zero())
+    );
+}
+
+#[test]
+fn conflicts_skip_unlocated_events_and_unknown_routes() {
+    let events = [
+        at(utc(11, 0), utc(12, 0), Some("Client")),
+        at(utc(9, 0), utc(10, 0), Some("HQ")),
+        // A call in between does not break the HQ → Client trip.
+        at(utc(10, 0), utc(10, 45), None),
+        at(utc(12, 10), utc(13, 0), Some("HQ")),
+        at(utc(13, 5), utc(14, 0), Some("Lab")),
+    ];
+    let conflicts = find_travel_conflicts(&events, &matrix());
+    let pairs: Vec<_> = conflicts
+        .iter()
+        .map(|c| (c.from_index, c.to_index, c.gap_minutes, c.travel_minutes))
+        .collect();
+    // HQ → Client has an hour; Client → HQ has ten minutes; HQ → Lab is unknown.
+    assert_eq!(pairs, vec![(0, 3, 10, 30)]);
+}
+
+#[test]
+fn buffers_block_time_before_each_trip_destination() {
+    let events = [
+        at(utc(9, 0), utc(10, 0), Some("HQ")),
+        at(utc(11, 0), utc(12, 0), Some("Client")),
+        at(utc(13, 0), utc(14, 0), Some("Client")),
+    ];
+    assert_eq!(
+        travel_buffers(&events, &matrix()),
+        vec![ExpandedEvent::new(utc(10, 30), utc(11, 0))]
+    );
+}
+
+#[test]
+fn closures_are_providers() {
+    // Rush hour doubles the trip.
+    let rush = |_: &str, _: &str, departure: DateTime<Utc>| {
+        let minutes = if (16..18).contains(&departure.hour()) {
+            60
+        } else {
+            30
+        };
+        Some(Duration::minutes(minutes))
+    };
+    let events = [
+        at(utc(16, 0), utc(16, 30), Some("HQ")),
+        at(utc(17, 0), utc(17, 30), Some("Client")),
+    ];
+    assert_eq!(find_travel_conflicts(&events, &rush)[0].travel_minutes, 60);
+}
+
+#[test]
+fn planner_leaves_room_to_travel() {
+    let busy = [at(utc(9, 0), utc(10, 0), Some("Client"))];
+    let task = |id: &str, location: Option<&str>| Task {
+        id: id.to_string(),
+        duration_minutes: 60,
+        deadline: None,
+        priority: 0,
+        location: location.map(str::to_string),
+    };
+    let options = PlanOptions {
+        buffer_minutes: 5,
+        ..PlanOptions::default()
+    };
+    let plan = plan_tasks_with_travel(
+        &[task("desk", Some("HQ")), task("call", None)],
+        &busy,
+        utc(0, 0),
+        utc(23, 0),
+        &options,
+        &matrix(),
+    )
+    .unwrap();
+    let starts: Vec<_> = plan
+        .blocks
+        .iter()
+        .map(|b| (b.task_id.as_str(), b.start))
+        .collect();
+    // "call" sorts first by id and only needs the buffer; "desk" at HQ waits
+    // out the 30-minute trip back from the client.
+    assert_eq!(starts, vec![("call", utc(10, 5)), ("desk", utc(11, 10))]);
+}
@@ -0,0 +1,256 @@
+//! Tests for tzdist timezone data loading.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use truth_engine::interop::ics::{parse_ics, write_ics};
+#[cfg(feature = "serde")]
+use truth_engine::interop::tzdist::parse_zone_list;
+use truth_engine::interop::tzdist::{zone_path, zones_path, ZoneStore, WELL_KNOWN_PATH};
+use truth_engine::temporal::convert_timezone;
+use truth_engine::TruthError;
+
+fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+}
+
+fn local(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, mo, d)
+        .unwrap()
+        .and_hms_opt(h, mi, 0)
+        .unwrap()
+}
+
+/// America/New_York as published in RFC 5545 §3.6.5, with the 1967–2006
+/// rules ending in UNTIL and the current rules from 2007.
+const NEW_YORK: &str = "BEGIN:VCALENDAR\r\n\
+    BEGIN:VTIMEZONE\r\n\
+    TZID:America/New_York\r\n\
+    BEGIN:STANDARD\r\n\
+    DTSTART:19671029T020000\r\n\
+    RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=10;UNTIL=20061029T060000Z\r\n\
+    TZOFFSETFROM:-0400\r\n\
+    TZOFFSETTO:-0500\r\n\
+    TZNAME:EST\r\n\
+    END:STANDARD\r\n\
+    BEGIN:DAYLIGHT\r\n\
+    DTSTART:19870405T020000\r\n\
+    RRULE:FREQ=YEARLY;BYDAY=1SU;BYMONTH=4;UNTIL=20060402T070000Z\r\n\
+    TZOFFSETFROM:-0500\r\n\
+    TZOFFSETTO:-0400\r\n\
+    TZNAME:EDT\r\n\
+    END:DAYLIGHT\r\n\
+    BEGIN:DAYLIGHT\r\n\
+    DTSTART:20070311T020000\r\n\
+    RRULE:FREQ=YEARLY;BYDAY=2SU;BYMONTH=3\r\n\
+    TZOFFSETFROM:-0500\r\n\
+    TZOFFSETTO:-0400\r\n\
+    TZNAME:EDT\r\n\
+    END:DAYLIGHT\r\n\
+    BEGIN:STANDARD\r\n\
+    DTSTART:20071104T020000\r\n\
+    RRULE:FREQ=YEARLY;BYDAY=1SU;BYMONTH=11\r\n\
+    TZOFFSETFROM:-0400\r\n\
+    TZOFFSETTO:-0500\r\n\
+    TZNAME:EST\r\n\
+    END:STANDARD\r\n\
+    END:VTIMEZONE\r\n\
+    END:VCALENDAR\r\n";
+
+#[cfg(feature = "serde")]
+const LISTING: &str = r#"{
+  "synctoken": "2026-02-18T12:00:00Z",
+  "zones": [
+    {
+      "tzid": "America/New_York",
+      "etag": "\"2025b\"",
+      "last-modified": "2025-03-22T00:00:00Z",
+      "aliases": ["US/Eastern", "EST5EDT"]
+    },
+    {"tzid": "Europe/Berlin", "etag": "\"2025b\""}
+  ]
+}"#;
+
+#[test]
+fn request_paths() {
+    assert_eq!(WELL_KNOWN_PATH, "/.well-known/timezone");
+    assert_eq!(zones_path("/tz/", None), "/tz/zones");
+    assert_eq!(
+        zone_path("/tz", "America/Argentina/Buenos_Aires"),
+        "/tz/zones/America%2FArgentina%2FBuenos_Aires"
+    );
+}
+
+#[test]
+fn rules_follow_until_and_current_rules() {
+    let mut store = ZoneStore::new();
+    assert_eq!(
+        store.load(NEW_YORK, None).unwrap(),
+        vec!["America/New_York"]
+    );
+    let ny = store.get("America/New_York").unwrap();
+
+    // 2005: first Sunday in April to last Sunday in October.
+    assert_eq!(
+        ny.offset_at(utc(2005, 4, 3, 6, 59)).local_minus_utc(),
+        -5 * 3600
+    );
+    assert_eq!(
+        ny.offset_at(utc(2005, 4, 3, 7, 0)).local_minus_utc(),
+        -4 * 3600
+    );
+    assert_eq!(
+        ny.offset_at(utc(2005, 10, 30, 5, 59)).local_minus_utc(),
+        -4 * 3600
+    );
+    assert_eq!(
+        ny.offset_at(utc(2005, 10, 30, 6, 0)).local_minus_utc(),
+        -5 * 3600
+    );
+    // 2007 on: second Sunday in March to first Sunday in November.
+    assert_eq!(
+        ny.offset_at(utc(2026, 3, 8, 7, 0)).local_minus_utc(),
+        -4 * 3600
+    );
+    assert_eq!(
+        ny.offset_at(utc(2026, 11, 1, 6, 0)).local_minus_utc(),
+        -5 * 3600
+    );
+    // The old April rule no longer applies.
+    assert_eq!(
+        ny.offset_at(utc(2026, 4, 1, 12, 0)).local_minus_utc(),
+        -4 * 3600
+    );
+    assert_eq!(
+        ny.observance_at(utc(2026, 7, 1, 12, 0))
+            .abbreviation
+            .as_deref(),
+        Some("EDT")
+    );
+    assert!(ny.observance_at(utc(2026, 7, 1, 12, 0)).dst);
+}
+
+#[test]
+fn local_times_resolve_like_built_in_zones() {
+    let mut store = ZoneStore::new();
+    store.load(NEW_YORK, None).unwrap();
+    let ny = store.get("America/New_York").unwrap();
+
+    assert_eq!(ny.to_utc(local(2026, 7, 1, 9, 0)), utc(2026, 7, 1, 13, 0));
+    // Fall back: 01:30 happens twice; the earlier (EDT) instant wins.
+    assert_eq!(
+        ny.to_utc(local(2026, 11, 1, 1, 30)),
+        utc(2026, 11, 1, 5, 30)
+    );
+    // Spring forward: 02:30 does not exist and moves past the gap to 03:30 EDT.
+    assert_eq!(ny.to_utc(local(2026, 3, 8, 2, 30)), utc(2026, 3, 8, 7, 30));
+    assert_eq!(
+        ny.to_local(utc(2026, 3, 8, 7, 30)).to_rfc3339(),
+        "2026-03-08T03:30:00-04:00"
+    );
+}
+
+#[test]
+fn generated_vtimezones_match_the_tz_database() {
+    for tzid in [
+        "Europe/Berlin",
+        "Australia/Lord_Howe",
+        "Asia/Kolkata",
+        "America/Santiago",
+    ] {
+        let calendar = parse_ics(&format!(
+            "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:a\r\n\
+             DTSTART;TZID={}:20260615T090000\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n",
+            tzid
+        ))
+        .unwrap();
+        let text = write_ics(&calendar, utc(2026, 1, 1, 0, 0)).unwrap();
+        let mut store = ZoneStore::new();
+        store.load(&text, None).unwrap();
+        let rules = store.get(tzid).unwrap();
+        let tz: Tz = tzid.parse().unwrap();
+
+        let mut at = utc(2026, 1, 1, 0, 30);
+        while at < utc(2027, 1, 1, 0, 0) {
+            assert_eq!(
+                rules.offset_at(at),
+                tz.offset_from_utc_datetime(&at.naive_utc()).fix(),
+                "{} at {}",
+                tzid,
+                at
+            );
+            at += Duration::minutes(30);
+        }
+    }
+}
+
+#[test]
+fn convert_matches_convert_timezone() {
+    let mut store = ZoneStore::new();
+    store.load(NEW_YORK, None).unwrap();
+    for instant in ["2026-03-15T14:00:00Z", "2026-12-01T03:15:00+01:00"] {
+        assert_eq!(
+            store.convert(instant, "America/New_York").unwrap(),
+            convert_timezone(instant, "America/New_York").unwrap()
+        );
+    }
+    assert!(matches!(
+        store.convert("2026-03-15T14:00:00Z", "Europe/Berlin"),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+    assert!(matches!(
+        store.convert("yesterday", "America/New_York"),
+        Err(TruthError::InvalidDatetime(_))
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn listing_drives_aliases_and_refresh() {
+    let list = parse_zone_list(LISTING).unwrap();
+    assert_eq!(list.zones.len(), 2);
+    assert_eq!(
+        list.zones[0].last_modified.as_deref(),
+        Some("2025-03-22T00:00:00Z")
+    );
+    assert!(list.zones[1].aliases.is_empty());
+
+    let mut store = ZoneStore::new();
+    store.load(NEW_YORK, Some("\"2025a\"")).unwrap();
+    assert!(store.get("US/Eastern").is_none());
+    store.record_listing(&list);
+    assert_eq!(store.get("US/Eastern").unwrap().tzid, "America/New_York");
+    assert_eq!(store.synctoken(), Some("2026-02-18T12:00:00Z"));
+    assert_eq!(
+        zones_path("/tz", store.synctoken()),
+        "/tz/zones?changedsince=2026-02-18T12%3A00%3A00Z"
+    );
+
+    // Only loaded zones whose etag moved need fetching again.
+    assert_eq!(store.changed(&list), vec!["America/New_York"]);
+    store.load(NEW_YORK, Some("\"2025b\"")).unwrap();
+    assert!(store.changed(&list).is_empty());
+}
+
+#[test]
+fn malformed_data_is_rejected() {
+    #[cfg(feature = "serde")]
+    assert!(matches!(
+        parse_zone_list("[]"),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+    let mut store = ZoneStore::new();
+    assert!(matches!(
+        store.load("BEGIN:VCALENDAR\r\nEND:VCALENDAR\r\n", None),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+    let bad_offset = NEW_YORK.replace("TZOFFSETTO:-0500", "TZOFFSETTO:EST");
+    assert!(matches!(
+        store.load(&bad_offset, None),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+    let no_dtstart = NEW_YORK.replace("DTSTART:20070311T020000\r\n", "");
+    assert!(matches!(
+        store.load(&no_dtstart, None),
+        Err(TruthError::InvalidCalendar(_))
+    ));
+}